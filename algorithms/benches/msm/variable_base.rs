@@ -44,6 +44,24 @@ fn variable_base_bls12_377(c: &mut Criterion) {
     }
 }
 
+/// Compares the CPU and CUDA MSMs across input sizes, to locate the crossover point
+/// for `CUDA_MSM_THRESHOLD`. The CUDA window width is selected for each input size.
+#[cfg(feature = "cuda")]
+fn variable_base_bls12_377_cuda(c: &mut Criterion) {
+    use snarkvm_curves::bls12_377::{Fr, G1Affine};
+    let (bases, scalars) = create_scalar_bases::<G1Affine, Fr>(1_100_000);
+
+    for log_size in (8..=20).step_by(2) {
+        let size = 1 << log_size;
+        c.bench_function(&format!("VariableBase CPU MSM on BLS12-377 (2^{log_size})"), |b| {
+            b.iter(|| variable_base::batched::msm(&bases[..size], &scalars[..size]))
+        });
+        c.bench_function(&format!("VariableBase CUDA MSM on BLS12-377 (2^{log_size})"), |b| {
+            b.iter(|| VariableBase::msm(&bases[..size], &scalars[..size]))
+        });
+    }
+}
+
 fn variable_base_edwards_bls12(c: &mut Criterion) {
     use snarkvm_curves::edwards_bls12::{EdwardsAffine, Fr};
     let (bases, scalars) = create_scalar_bases::<EdwardsAffine, Fr>(1_000_000);
@@ -55,10 +73,18 @@ fn variable_base_edwards_bls12(c: &mut Criterion) {
     }
 }
 
+#[cfg(not(feature = "cuda"))]
 criterion_group! {
     name = variable_base_group;
     config = Criterion::default().sample_size(10);
     targets = variable_base_bls12_377, variable_base_edwards_bls12
 }
 
+#[cfg(feature = "cuda")]
+criterion_group! {
+    name = variable_base_group;
+    config = Criterion::default().sample_size(10);
+    targets = variable_base_bls12_377, variable_base_bls12_377_cuda, variable_base_edwards_bls12
+}
+
 criterion_main!(variable_base_group);
@@ -80,7 +80,24 @@ fn main() {
         let mut nvcc = cc::Build::new();
         nvcc.cuda(true);
         nvcc.flag("-g");
-        nvcc.flag("-arch=sm_70");
+        // Build a fatbinary for each of the selected architectures, with PTX for the newest one,
+        // which the driver compiles for devices that are newer still.
+        let archs = match env::var("SNARKVM_CUDA_ARCHS") {
+            Ok(archs) => archs
+                .split(',')
+                .map(|arch| match arch.trim().trim_start_matches("sm_").parse::<u32>() {
+                    Ok(arch @ 50..=99) => arch,
+                    _ => panic!("SNARKVM_CUDA_ARCHS must list architectures such as '70,80,90', found '{arch}'"),
+                })
+                .collect::<Vec<_>>(),
+            Err(_) => vec![70],
+        };
+        for arch in &archs {
+            nvcc.flag("-gencode").flag(&format!("arch=compute_{arch},code=sm_{arch}"));
+        }
+        let newest = archs.iter().max().unwrap();
+        nvcc.flag("-gencode").flag(&format!("arch=compute_{newest},code=compute_{newest}"));
+        nvcc.define("SNARKVM_MIN_SM", archs.iter().min().unwrap().to_string().as_str());
         nvcc.flag("-maxrregcount=255");
         nvcc.flag("-Xcompiler").flag("-Wno-unused-function");
         nvcc.flag("-Xcompiler").flag("-Wno-subobject-linkage");
@@ -102,6 +119,7 @@ fn main() {
         println!("cargo:rustc-cfg=feature=\"cuda\"");
         println!("cargo:rerun-if-changed=cuda");
         println!("cargo:rerun-if-env-changed=CXXFLAGS");
+        println!("cargo:rerun-if-env-changed=SNARKVM_CUDA_ARCHS");
     } else {
         println!("nvcc must be in the path. Consider adding /usr/local/cuda/bin.");
         // panic!();
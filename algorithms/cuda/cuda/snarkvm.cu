@@ -37,9 +37,18 @@ typedef xyzz_t<fp_t> bucket_t;
 typedef bucket_t::affine_inf_t affine_t;
 typedef fr_t scalar_t;
 
-#define WBITS 11 // Suitable for ~2^16
+#if defined(FEATURE_BLS12_377)
+# include <ff/bls12-377-fp2.hpp>
+
+// G2 points have their coordinates in the quadratic extension of the base field
+typedef jacobian_t<fp2_t> point_fp2_t;
+typedef xyzz_t<fp2_t> bucket_fp2_t;
+typedef bucket_fp2_t::affine_inf_t affine_fp2_t;
+#endif
+
+// The window width of the bucket method is chosen by each `msm_t` when it is constructed,
+// from the number of points it is given and the number of SMs of its GPU.
 #include <msm/pippenger.cuh>
-#undef WBITS
 
 // A simple way to allocate a host pointer without having to
 // care about freeing it.
@@ -66,6 +75,11 @@ public:
 #include <vector>
 #include <chrono>
 #include <atomic>
+#include <cstring>
+#include <list>
+#include <memory>
+#include <mutex>
+#include <thread>
 
 typedef std::chrono::high_resolution_clock Clock;
 
@@ -84,6 +98,47 @@ class snarkvm_t {
     };
     channel_t<resource_t*> resources;
     
+    // Bitmask of the GPUs used for MSM, where bit `i` enables device `i`
+    std::atomic<uint64_t> msm_device_mask{~(uint64_t)0};
+    // Round-robin counter used to spread small MSMs across the GPUs
+    std::atomic<size_t> msm_next_device{0};
+
+    // MSMs below this size do not saturate a single GPU, so are not sharded
+    static const size_t MSM_SHARD_THRESHOLD = (size_t)1 << 16;
+
+    // The number of base sets kept resident on each GPU, which bounds the device memory held by the cache
+    static const size_t MSM_BASES_CACHE_SIZE = 8;
+
+    // The bases of a recent MSM, which are kept on the GPU so that repeated MSMs over the
+    // same bases, such as those of a proving key, only transfer the scalars.
+    struct msm_bases_t {
+        const void* host;
+        size_t npoints;
+        size_t ffi_affine_size;
+        uint64_t fingerprint;
+        // The `msm_t` holding the bases in device memory
+        std::shared_ptr<void> msm;
+    };
+
+    // The MSM state of one GPU: a pinned host staging buffer for the scalars, which is grown on demand,
+    // and the most recently used bases first. Copies out of pinned memory are asynchronous, so they
+    // overlap with the MSM kernels.
+    struct msm_staging_t {
+        std::mutex mutex;
+        size_t scalars_size = 0;
+        std::unique_ptr<host_ptr_t<uint8_t>> scalars;
+        std::list<msm_bases_t> bases;
+
+        void reserve(size_t new_scalars_size) {
+            if (new_scalars_size > scalars_size) {
+                scalars.reset();
+                scalars.reset(new host_ptr_t<uint8_t>(new_scalars_size));
+                scalars_size = new_scalars_size;
+            }
+        }
+    };
+    std::unique_ptr<msm_staging_t[]> msm_staging;
+
     // Host and device storage
     size_t                         allocated_elements;
     std::vector<dev_ptr_t<fr_t>*>  d_mem;
@@ -128,6 +183,7 @@ public:
 
         d_mem.resize(ngpus());
         h_mem.resize(ngpus());
+        msm_staging.reset(new msm_staging_t[ngpus()]);
         for (size_t i = 0; i < ngpus(); i++) {
             d_mem[i] = nullptr;
             h_mem[i] = nullptr;
@@ -150,7 +206,9 @@ public:
             }
         }
     }
-    ~snarkvm_t() {}
+    ~snarkvm_t() {
+        free_memory();
+    }
 
     RustError NTT(fr_t* out, fr_t* in,
                   uint32_t lg_domain_size,
@@ -186,6 +244,41 @@ public:
         return RustError{cudaSuccess};
     }
 
+    // Computes the NTT of each of the `count` polynomials in place, spreading them over the GPUs and
+    // their streams, so that the transfers of one polynomial overlap with the transform of another.
+    // The outcome of each polynomial is written to `codes`, as a failed one is left unchanged.
+    RustError NTTBatch(fr_t** inouts, size_t count, int* codes,
+                       uint32_t lg_domain_size,
+                       NTT::InputOutputOrder ntt_order,
+                       NTT::Direction ntt_direction,
+                       NTT::Type ntt_type) {
+        std::atomic<size_t> next{0};
+        auto worker = [&]() {
+            for (size_t i = next.fetch_add(1); i < count; i = next.fetch_add(1)) {
+                codes[i] = NTT(inouts[i], inouts[i], lg_domain_size,
+                               ntt_order, ntt_direction, ntt_type).code;
+            }
+        };
+
+        // Each worker holds at most one GPU resource at a time
+        size_t nworkers = std::min(count, ngpus() * gpu_t::FLIP_FLOP);
+        std::vector<std::thread> workers;
+        for (size_t i = 1; i < nworkers; i++) {
+            workers.emplace_back(worker);
+        }
+        worker();
+        for (auto& thread : workers) {
+            thread.join();
+        }
+
+        for (size_t i = 0; i < count; i++) {
+            if (codes[i] != cudaSuccess) {
+                return RustError{codes[i]};
+            }
+        }
+        return RustError{cudaSuccess};
+    }
+
     RustError PolyMul(fr_t* out,
                       size_t pcount, fr_t** polynomials, size_t* plens,
                       size_t ecount, fr_t** evaluations, size_t* elens,
@@ -247,13 +340,78 @@ public:
         return RustError{cudaSuccess};
     }
 
-    RustError MSM(point_t* out, const affine_t points[], size_t npoints,
+    void SetMSMDevices(uint64_t mask) {
+        msm_device_mask.store(mask);
+    }
+
+    // Hashes a sample of the bases, so that new bases written at the address of evicted ones are not
+    // mistaken for them. Bases must not be modified in place while they are used for MSMs.
+    static uint64_t msm_bases_fingerprint(const void* points, size_t npoints, size_t ffi_affine_size) {
+        const size_t samples = 16;
+        uint64_t hash = 0xcbf29ce484222325; // FNV-1a
+        for (size_t i = 0; i <= samples; i++) {
+            const uint8_t* point = (const uint8_t*)points + (npoints - 1) * i / samples * ffi_affine_size;
+            for (size_t j = 0; j < ffi_affine_size; j++) {
+                hash = (hash ^ point[j]) * 0x100000001b3;
+            }
+        }
+        return hash;
+    }
+
+    // Returns the `msm_t` holding the given bases on the current GPU, which transfers them only if they are
+    // not among the recently used bases. The caller must hold the lock of the staging area.
+    template<class msm_T, class affine_T>
+    static msm_T& msm_bases(msm_staging_t& staging, int dev, const affine_T* points, size_t npoints,
+                            size_t ffi_affine_size) {
+        uint64_t fingerprint = msm_bases_fingerprint(points, npoints, ffi_affine_size);
+        for (auto it = staging.bases.begin(); it != staging.bases.end(); it++) {
+            if (it->host == points && it->npoints == npoints &&
+                it->ffi_affine_size == ffi_affine_size && it->fingerprint == fingerprint) {
+                staging.bases.splice(staging.bases.begin(), staging.bases, it);
+                return *static_cast<msm_T*>(it->msm.get());
+            }
+        }
+        if (staging.bases.size() >= MSM_BASES_CACHE_SIZE) {
+            staging.bases.pop_back();
+        }
+        std::shared_ptr<void> msm;
+        try {
+            msm = std::make_shared<msm_T>(points, npoints, ffi_affine_size, dev);
+        } catch (const cuda_error&) {
+            // Release the device memory held by the other bases, and retry
+            staging.bases.clear();
+            msm = std::make_shared<msm_T>(points, npoints, ffi_affine_size, dev);
+        }
+        staging.bases.push_front(msm_bases_t{points, npoints, ffi_affine_size, fingerprint, msm});
+        return *static_cast<msm_T*>(msm.get());
+    }
+
+    // Computes the MSM over the curve group given by the bucket, point, and affine types
+    template<class bucket_T, class point_T, class affine_T>
+    RustError MSM(point_T* out, const affine_T points[], size_t npoints,
                   const scalar_t scalars[], size_t ffi_affine_size) {
         // SNP TODO: cleanup
         // auto start = Clock::now();
 
-        size_t gpu_count = min(ngpus(), npoints);
-        point_t partial_sums[gpu_count];
+        // Collect the GPUs enabled by the device mask
+        std::vector<int> devices;
+        uint64_t mask = msm_device_mask.load();
+        for (size_t dev = 0; dev < ngpus() && dev < 64; dev++) {
+            if ((mask >> dev) & 1) {
+                devices.push_back(dev);
+            }
+        }
+        if (devices.empty()) {
+            return RustError{cudaErrorNoDevice};
+        }
+        // Run small MSMs on a single GPU, and balance concurrent requests across the GPUs
+        if (npoints < MSM_SHARD_THRESHOLD) {
+            int dev = devices[msm_next_device.fetch_add(1) % devices.size()];
+            devices = std::vector<int>{dev};
+        }
+
+        size_t gpu_count = min(devices.size(), npoints);
+        point_T partial_sums[gpu_count];
         size_t bases_per_gpu = (npoints + gpu_count - 1) / gpu_count;
         channel_t<size_t> ch;
         RustError error = RustError{cudaSuccess};
@@ -261,19 +419,27 @@ public:
         // Divide the MSM among the GPUs
         for (size_t i = 0; i < gpu_count; i++) {
             pool.spawn([&, i]() {
-                int dev = i;
+                int dev = devices[i];
                 select_gpu(dev);
                 size_t start = i * bases_per_gpu;
                 size_t sz = std::min(bases_per_gpu, npoints - start);
 
                 // This is ugly, but we only know the size of the affine points in bytes
-                const affine_t* pts = (affine_t*)(&((uint8_t*)points)[start * ffi_affine_size]);
+                const affine_T* pts = (affine_T*)(&((uint8_t*)points)[start * ffi_affine_size]);
                 
                 RustError ret;
                 try {
-                    msm_t<bucket_t, point_t, affine_t, scalar_t> msm(dev);
-                    ret = msm.invoke(partial_sums[i], slice_t<affine_t>{pts, sz},
-                                     &scalars[start], false, ffi_affine_size);
+                    // Stage the scalars in pinned memory, which is reused across calls on this GPU
+                    auto& staging = msm_staging[dev];
+                    std::lock_guard<std::mutex> lock(staging.mutex);
+                    staging.reserve(sz * sizeof(scalar_t));
+                    memcpy(*staging.scalars, &scalars[start], sz * sizeof(scalar_t));
+
+                    // The `msm_t` is sized for this shard, which selects its window width
+                    auto& msm = msm_bases<msm_t<bucket_T, point_T, affine_T, scalar_t>>(
+                        staging, dev, pts, sz, ffi_affine_size);
+                    ret = msm.invoke(partial_sums[i], nullptr, sz,
+                                     (const scalar_t*)(uint8_t*)*staging.scalars, false);
                 } catch (const cuda_error& e) {
                     out->inf();
 #ifdef TAKE_RESPONSIBILITY_FOR_ERROR_MESSAGE
@@ -292,7 +458,7 @@ public:
         *out = partial_sums[dev];
         for (size_t i = 0; i < gpu_count - 1; i++) {
             dev = ch.recv();
-            point_t::dadd(*out, *out, partial_sums[dev]);
+            point_T::dadd(*out, *out, partial_sums[dev]);
         }
         // auto end = Clock::now();
         // uint64_t dt = std::chrono::duration_cast<
@@ -20,31 +20,103 @@
 
 #ifndef __CUDA_ARCH__
 
-// Lazy instantiation of snarkvm_t
+#include <memory>
+#include <mutex>
+
+// Returns whether the error leaves the CUDA context unusable until the device is reset
+static bool is_sticky_error(int code) {
+    switch (code) {
+    case cudaErrorIllegalAddress:
+    case cudaErrorLaunchFailure:
+    case cudaErrorHardwareStackError:
+    case cudaErrorIllegalInstruction:
+    case cudaErrorMisalignedAddress:
+    case cudaErrorInvalidAddressSpace:
+    case cudaErrorInvalidPc:
+    case cudaErrorECCUncorrectable:
+        return true;
+    default:
+        return false;
+    }
+}
+
+// The oldest compute capability the kernels were compiled for, which is set by the build script
+#ifndef SNARKVM_MIN_SM
+# define SNARKVM_MIN_SM 70
+#endif
+
+// Ensures that every device can run the kernels, as an older device only fails at the first launch
+static int check_compute_capability() {
+    int count = 0;
+    if (cudaGetDeviceCount(&count) != cudaSuccess || count == 0) {
+        return cudaErrorNoDevice;
+    }
+    for (int device = 0; device < count; device++) {
+        int major = 0, minor = 0;
+        cudaDeviceGetAttribute(&major, cudaDevAttrComputeCapabilityMajor, device);
+        cudaDeviceGetAttribute(&minor, cudaDevAttrComputeCapabilityMinor, device);
+        if (major * 10 + minor < SNARKVM_MIN_SM) {
+            return cudaErrorNoKernelImageForDevice;
+        }
+    }
+    return cudaSuccess;
+}
+
+// The base-2 logarithm of the largest polynomial multiplication supported by the staging buffers,
+// each of which holds four polynomials. An NTT may be four times as large.
+// SNP TODO: max domain size?
+#define SNARKVM_MAX_LG_DOMAIN 17
+
+// Lazy instantiation of snarkvm_t, which is re-created after an unrecoverable error
 class snarkvm_singleton_t {
-    bool failed = false;
-    snarkvm_t *snarkvm = nullptr;
+    std::mutex mutex;
+    std::shared_ptr<snarkvm_t> snarkvm;
+    // The MSM device mask, which is restored when the context is re-created
+    uint64_t msm_device_mask = ~(uint64_t)0;
+    // The reason the context could not be created
+    int init_error = cudaSuccess;
 
 public:
     snarkvm_singleton_t() {}
-    ~snarkvm_singleton_t() {
-        delete snarkvm;
-        snarkvm = nullptr;
-    }
-    bool ok() {
-        if (!failed && snarkvm == nullptr) {
-            // SNP TODO: max domain size?
-            snarkvm = new snarkvm_t(17);
-            if (snarkvm == nullptr) {
-                failed = true;
+    // Returns the context, creating it if needed, or nullptr if it cannot be created
+    std::shared_ptr<snarkvm_t> get() {
+        std::lock_guard<std::mutex> lock(mutex);
+        if (snarkvm == nullptr) {
+            init_error = check_compute_capability();
+            if (init_error != cudaSuccess) {
+                return nullptr;
+            }
+            try {
+                snarkvm = std::make_shared<snarkvm_t>(SNARKVM_MAX_LG_DOMAIN);
+                snarkvm->SetMSMDevices(msm_device_mask);
+            } catch (...) {
+                init_error = cudaErrorMemoryAllocation;
+                snarkvm = nullptr;
             }
         }
-        return snarkvm != nullptr;
-    }
-    snarkvm_t* operator->() {
-        assert (ok());
         return snarkvm;
     }
+    // Returns the reason the last call to `get` failed
+    RustError error() {
+        std::lock_guard<std::mutex> lock(mutex);
+        return RustError{init_error};
+    }
+    // Drops the context after an unrecoverable error, so that the next request re-creates it.
+    // In-flight requests keep their reference until they complete.
+    RustError check(RustError err) {
+        if (is_sticky_error(err.code)) {
+            std::lock_guard<std::mutex> lock(mutex);
+            snarkvm = nullptr;
+        }
+        return err;
+    }
+    void set_msm_devices(uint64_t mask) {
+        std::lock_guard<std::mutex> lock(mutex);
+        msm_device_mask = mask;
+        if (snarkvm != nullptr) {
+            snarkvm->SetMSMDevices(mask);
+        }
+    }
 };
 snarkvm_singleton_t snarkvm_g;
                                          
@@ -55,32 +127,75 @@ extern "C" {
                           NTT::InputOutputOrder ntt_order, NTT::Direction ntt_direction,
                           NTT::Type ntt_type)
     {
-        if (!snarkvm_g.ok()) {
-            return RustError{cudaErrorMemoryAllocation};
+        auto snarkvm = snarkvm_g.get();
+        if (snarkvm == nullptr) {
+            return snarkvm_g.error();
         }
-        return snarkvm_g->NTT(inout, inout, lg_domain_size, ntt_order,
-                              ntt_direction, ntt_type);
+        return snarkvm_g.check(snarkvm->NTT(inout, inout, lg_domain_size, ntt_order,
+                                            ntt_direction, ntt_type));
+    }
+
+    RustError snarkvm_ntt_batch(fr_t** inouts, size_t count, int* codes, uint32_t lg_domain_size,
+                                NTT::InputOutputOrder ntt_order, NTT::Direction ntt_direction,
+                                NTT::Type ntt_type)
+    {
+        auto snarkvm = snarkvm_g.get();
+        if (snarkvm == nullptr) {
+            RustError err = snarkvm_g.error();
+            for (size_t i = 0; i < count; i++) {
+                codes[i] = err.code;
+            }
+            return err;
+        }
+        return snarkvm_g.check(snarkvm->NTTBatch(inouts, count, codes, lg_domain_size, ntt_order,
+                                                 ntt_direction, ntt_type));
+    }
+
+    uint32_t snarkvm_ntt_max_lg_domain_size() {
+        return SNARKVM_MAX_LG_DOMAIN + 2;
     }
 
     RustError snarkvm_polymul(fr_t* out,
                               size_t pcount, fr_t** polynomials, size_t* plens,
                               size_t ecount, fr_t** evaluations, size_t* elens,
                               uint32_t lg_domain_size) {
-        if (!snarkvm_g.ok()) {
-            return RustError{cudaErrorMemoryAllocation};
+        auto snarkvm = snarkvm_g.get();
+        if (snarkvm == nullptr) {
+            return snarkvm_g.error();
         }
-        return snarkvm_g->PolyMul(out,
-                                  pcount, polynomials, plens,
-                                  ecount, evaluations, elens,
-                                  lg_domain_size);
+        return snarkvm_g.check(snarkvm->PolyMul(out,
+                                                pcount, polynomials, plens,
+                                                ecount, evaluations, elens,
+                                                lg_domain_size));
     }
 
     RustError snarkvm_msm(point_t* out, const affine_t points[], size_t npoints,
                           const scalar_t scalars[], size_t ffi_affine_size) {
-        if (!snarkvm_g.ok()) {
-            return RustError{cudaErrorMemoryAllocation};
+        auto snarkvm = snarkvm_g.get();
+        if (snarkvm == nullptr) {
+            return snarkvm_g.error();
         }
-        return snarkvm_g->MSM(out, points, npoints, scalars, ffi_affine_size);
+        return snarkvm_g.check(snarkvm->MSM<bucket_t>(out, points, npoints, scalars, ffi_affine_size));
+    }
+
+#if defined(FEATURE_BLS12_377)
+    RustError snarkvm_msm_g2(point_fp2_t* out, const affine_fp2_t points[], size_t npoints,
+                             const scalar_t scalars[], size_t ffi_affine_size) {
+        auto snarkvm = snarkvm_g.get();
+        if (snarkvm == nullptr) {
+            return snarkvm_g.error();
+        }
+        return snarkvm_g.check(snarkvm->MSM<bucket_fp2_t>(out, points, npoints, scalars, ffi_affine_size));
+    }
+#endif
+
+    uint32_t snarkvm_min_compute_capability() {
+        return SNARKVM_MIN_SM;
+    }
+
+    RustError snarkvm_set_msm_devices(uint64_t mask) {
+        snarkvm_g.set_msm_devices(mask);
+        return RustError{cudaSuccess};
     }
 }
 #endif // __CUDA_ARCH__
@@ -47,6 +47,18 @@ extern "C" {
         ntt_type: NTTType,
     ) -> cuda::Error;
 
+    fn snarkvm_ntt_batch(
+        inouts: *const *mut core::ffi::c_void,
+        count: usize,
+        codes: *mut i32,
+        lg_domain_size: u32,
+        ntt_order: NTTInputOutputOrder,
+        ntt_direction: NTTDirection,
+        ntt_type: NTTType,
+    ) -> cuda::Error;
+
+    fn snarkvm_ntt_max_lg_domain_size() -> u32;
+
     fn snarkvm_polymul(
         out: *mut core::ffi::c_void,
         pcount: usize,
@@ -65,8 +77,23 @@ extern "C" {
         scalars: *const c_void,
         ffi_affine_sz: usize,
     ) -> cuda::Error;
+
+    fn snarkvm_msm_g2(
+        out: *mut c_void,
+        points_with_infinity: *const c_void,
+        npoints: usize,
+        scalars: *const c_void,
+        ffi_affine_sz: usize,
+    ) -> cuda::Error;
+
+    fn snarkvm_min_compute_capability() -> u32;
+
+    fn snarkvm_set_msm_devices(mask: u64) -> cuda::Error;
 }
 
+/// The error code returned when a GPU is older than the architectures the kernels were compiled for.
+pub const CUDA_ERROR_NO_KERNEL_IMAGE: i32 = 209;
+
 ///////////////////////////////////////////////////////////////////////////////
 // Rust functions
 ///////////////////////////////////////////////////////////////////////////////
@@ -95,6 +122,46 @@ pub fn NTT<T>(
     Ok(())
 }
 
+/// Computes an in-place NTT on each of the given polynomials, which must each have `domain_size` elements.
+/// The polynomials are spread over the GPUs and their streams.
+///
+/// Returns the error code of each polynomial, which is zero if it was transformed. A polynomial
+/// that failed is left unchanged.
+#[allow(non_snake_case)]
+pub fn NTT_batch<T>(
+    domain_size: usize,
+    inouts: &mut [&mut [T]],
+    ntt_order: NTTInputOutputOrder,
+    ntt_direction: NTTDirection,
+    ntt_type: NTTType,
+) -> Vec<i32> {
+    if (domain_size & (domain_size - 1)) != 0 {
+        panic!("domain_size is not power of 2");
+    }
+    assert!(inouts.iter().all(|inout| inout.len() == domain_size), "The polynomials must match the domain size");
+    let lg_domain_size = domain_size.trailing_zeros();
+
+    let pointers = inouts.iter_mut().map(|inout| inout.as_mut_ptr() as *mut core::ffi::c_void).collect::<Vec<_>>();
+    let mut codes = vec![0i32; pointers.len()];
+    unsafe {
+        snarkvm_ntt_batch(
+            pointers.as_ptr(),
+            pointers.len(),
+            codes.as_mut_ptr(),
+            lg_domain_size,
+            ntt_order,
+            ntt_direction,
+            ntt_type,
+        );
+    }
+    codes
+}
+
+/// Returns the size of the largest NTT that fits in the staging buffers of the GPU.
+pub fn ntt_max_domain_size() -> usize {
+    1 << unsafe { snarkvm_ntt_max_lg_domain_size() }
+}
+
 /// Compute a polynomial multiply
 pub fn polymul<T: std::clone::Clone>(
     domain: usize,
@@ -144,6 +211,10 @@ pub fn polymul<T: std::clone::Clone>(
 }
 
 /// Compute a multi-scalar multiplication
+///
+/// The points of recent calls are kept on the GPU, and are looked up by their address and a sample of their
+/// contents, so only the scalars are transferred when the same points are used again. Therefore, the points
+/// must not be modified in place between calls.
 pub fn msm<Affine, Projective, Scalar>(points: &[Affine], scalars: &[Scalar]) -> Result<Projective, cuda::Error> {
     let npoints = scalars.len();
     if npoints > points.len() {
@@ -165,3 +236,56 @@ pub fn msm<Affine, Projective, Scalar>(points: &[Affine], scalars: &[Scalar]) ->
     }
     Ok(ret)
 }
+
+/// Compute a multi-scalar multiplication over G2, whose coordinates are in the quadratic extension field
+///
+/// The points are kept on the GPU between calls, as for [`msm`].
+pub fn msm_g2<Affine, Projective, Scalar>(points: &[Affine], scalars: &[Scalar]) -> Result<Projective, cuda::Error> {
+    let npoints = scalars.len();
+    if npoints > points.len() {
+        panic!("length mismatch {} points < {} scalars", npoints, scalars.len())
+    }
+    #[allow(clippy::uninit_assumed_init)]
+    let mut ret: Projective = unsafe { std::mem::MaybeUninit::uninit().assume_init() };
+    let err = unsafe {
+        snarkvm_msm_g2(
+            &mut ret as *mut _ as *mut c_void,
+            points as *const _ as *const c_void,
+            npoints,
+            scalars as *const _ as *const c_void,
+            std::mem::size_of::<Affine>(),
+        )
+    };
+    if err.code != 0 {
+        return Err(err);
+    }
+    Ok(ret)
+}
+
+/// Restricts the multi-scalar multiplications to the GPUs in `mask`, where bit `i` enables device `i`.
+/// By default, all of the available GPUs are used. Large MSMs are sharded across the enabled GPUs,
+/// while smaller ones each run on a single GPU, assigned in round-robin order.
+pub fn set_msm_devices(mask: u64) -> Result<(), cuda::Error> {
+    let err = unsafe { snarkvm_set_msm_devices(mask) };
+    if err.code != 0 {
+        return Err(err);
+    }
+    Ok(())
+}
+
+/// Returns the oldest compute capability the kernels were compiled for, e.g. `70` for `sm_70`,
+/// which may be changed with the `SNARKVM_CUDA_ARCHS` environment variable when building.
+pub fn min_compute_capability() -> u32 {
+    unsafe { snarkvm_min_compute_capability() }
+}
+
+/// Returns a description of the error with the given code.
+pub fn error_description(code: i32) -> String {
+    match code {
+        CUDA_ERROR_NO_KERNEL_IMAGE => format!(
+            "the GPU is older than sm_{}, the oldest architecture the kernels were compiled for (see SNARKVM_CUDA_ARCHS)",
+            min_compute_capability()
+        ),
+        _ => format!("error code {code}"),
+    }
+}
@@ -0,0 +1,125 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::AlgebraicSponge;
+use snarkvm_fields::{PrimeField, ToConstraintField};
+use snarkvm_utilities::ToBytes;
+
+use core::marker::PhantomData;
+use smallvec::SmallVec;
+use tiny_keccak::{Hasher, Keccak};
+
+/// Returns the Keccak-256 digest of the given byte strings, concatenated.
+fn keccak256(inputs: &[&[u8]]) -> [u8; 32] {
+    let mut hasher = Keccak::v256();
+    for input in inputs {
+        hasher.update(input);
+    }
+    let mut output = [0u8; 32];
+    hasher.finalize(&mut output);
+    output
+}
+
+/// A Fiat-Shamir transcript built on Keccak-256, so that the challenges can be recomputed
+/// from the Keccak-256 primitive alone. This is only the transcript: snarkVM does not generate
+/// an on-chain verifier, as the EVM has no precompiles for BLS12-377.
+///
+/// Absorbed field elements are appended to a buffer as big-endian words, and each squeeze
+/// first ratchets the state as `state = keccak256(state || buffer)`, then outputs the blocks
+/// `keccak256(state || i)` for a big-endian `u32` counter `i`. A field element is read from
+/// 64 bytes of output (or 21 bytes, for a short element) and reduced modulo the field order.
+#[derive(Clone, Debug)]
+pub struct KeccakSponge<F: PrimeField> {
+    /// The current state.
+    state: [u8; 32],
+    /// The bytes absorbed since the last squeeze.
+    buffer: Vec<u8>,
+    _field: PhantomData<F>,
+}
+
+impl<F: PrimeField> KeccakSponge<F> {
+    /// Appends the big-endian encoding of the given field element to the buffer.
+    fn absorb_field_element<Target: PrimeField>(&mut self, element: &Target) {
+        // Note: This unwrap is safe, as the write is into a vector.
+        let mut bytes = element.to_bigint().to_bytes_le().unwrap();
+        bytes.reverse();
+        self.buffer.extend_from_slice(&bytes);
+    }
+
+    /// Ratchets the state with the buffer, and returns `num_bytes` bytes of output.
+    fn squeeze_bytes(&mut self, num_bytes: usize) -> Vec<u8> {
+        self.state = keccak256(&[&self.state, &self.buffer]);
+        self.buffer.clear();
+
+        let mut output = Vec::with_capacity(num_bytes + 32);
+        let mut counter = 0u32;
+        while output.len() < num_bytes {
+            output.extend_from_slice(&keccak256(&[&self.state, &counter.to_be_bytes()]));
+            counter += 1;
+        }
+        output.truncate(num_bytes);
+        output
+    }
+
+    /// Returns `num` field elements, each read from `bytes_per_element` bytes of output.
+    fn squeeze_field_elements<Target: PrimeField>(
+        &mut self,
+        num: usize,
+        bytes_per_element: usize,
+    ) -> SmallVec<[Target; 10]> {
+        let bytes = self.squeeze_bytes(num * bytes_per_element);
+        bytes.chunks_exact(bytes_per_element).map(Target::from_bytes_be_mod_order).collect()
+    }
+}
+
+impl<F: PrimeField, const RATE: usize> AlgebraicSponge<F, RATE> for KeccakSponge<F> {
+    type Parameters = ();
+
+    fn sample_parameters() -> Self::Parameters {}
+
+    fn new_with_parameters(_parameters: &Self::Parameters) -> Self {
+        Self { state: [0u8; 32], buffer: Vec::new(), _field: PhantomData }
+    }
+
+    fn absorb_native_field_elements<T: ToConstraintField<F>>(&mut self, elements: &[T]) {
+        for element in elements {
+            // Note: This unwrap is safe, as native field elements always convert.
+            for field_element in element.to_field_elements().unwrap() {
+                self.absorb_field_element(&field_element);
+            }
+        }
+    }
+
+    fn absorb_nonnative_field_elements<Target: PrimeField>(&mut self, elements: impl IntoIterator<Item = Target>) {
+        for element in elements {
+            self.absorb_field_element(&element);
+        }
+    }
+
+    fn absorb_bytes(&mut self, elements: &[u8]) {
+        self.buffer.extend_from_slice(elements);
+    }
+
+    fn squeeze_native_field_elements(&mut self, num: usize) -> SmallVec<[F; 10]> {
+        self.squeeze_field_elements(num, 64)
+    }
+
+    fn squeeze_nonnative_field_elements<Target: PrimeField>(&mut self, num: usize) -> SmallVec<[Target; 10]> {
+        self.squeeze_field_elements(num, 64)
+    }
+
+    fn squeeze_short_nonnative_field_elements<Target: PrimeField>(&mut self, num: usize) -> SmallVec<[Target; 10]> {
+        self.squeeze_field_elements(num, 21)
+    }
+}
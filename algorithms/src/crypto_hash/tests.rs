@@ -12,7 +12,11 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
-use crate::{crypto_hash::PoseidonSponge, AlgebraicSponge, DuplexSpongeMode};
+use crate::{
+    crypto_hash::{KeccakSponge, PoseidonSponge},
+    AlgebraicSponge,
+    DuplexSpongeMode,
+};
 use snarkvm_curves::bls12_377::Fr;
 use snarkvm_fields::{PoseidonDefaultField, PoseidonGrainLFSR};
 
@@ -86,3 +90,40 @@ fn bls12_377_fr_poseidon_default_parameters_test() {
     single_rate_test::<7>();
     single_rate_test::<8>();
 }
+
+#[test]
+fn test_keccak_sponge_consistency() {
+    use snarkvm_fields::PrimeField;
+    use tiny_keccak::{Hasher, Keccak};
+
+    let keccak256 = |input: &[u8]| {
+        let mut hasher = Keccak::v256();
+        hasher.update(input);
+        let mut output = [0u8; 32];
+        hasher.finalize(&mut output);
+        output
+    };
+
+    // Absorb the element `1`, which is encoded as a 32-byte big-endian word.
+    let mut sponge = <KeccakSponge<Fr> as AlgebraicSponge<Fr, 2>>::new();
+    AlgebraicSponge::<Fr, 2>::absorb_native_field_elements(&mut sponge, &[Fr::from(1u64)]);
+    let output = AlgebraicSponge::<Fr, 2>::squeeze_native_field_elements(&mut sponge, 1);
+
+    // Recompute the output from the Keccak-256 primitive directly.
+    let mut buffer = [0u8; 64];
+    buffer[63] = 1;
+    let state = keccak256(&buffer);
+    let mut block = [0u8; 36];
+    block[..32].copy_from_slice(&state);
+    let mut expected = keccak256(&block).to_vec();
+    block[35] = 1;
+    expected.extend_from_slice(&keccak256(&block));
+    assert_eq!(output[0], Fr::from_bytes_be_mod_order(&expected));
+
+    // Ensure consecutive squeezes differ, and the transcript is deterministic.
+    let mut other = <KeccakSponge<Fr> as AlgebraicSponge<Fr, 2>>::new();
+    AlgebraicSponge::<Fr, 2>::absorb_native_field_elements(&mut other, &[Fr::from(1u64)]);
+    assert_eq!(output, AlgebraicSponge::<Fr, 2>::squeeze_native_field_elements(&mut other, 1));
+    let next = AlgebraicSponge::<Fr, 2>::squeeze_native_field_elements(&mut sponge, 1);
+    assert_ne!(output, next);
+}
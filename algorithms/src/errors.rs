@@ -46,10 +46,29 @@ pub enum SNARKError {
 
     #[error("Circuit not found")]
     CircuitNotFound,
+
+    #[error("The prover requires an estimated {required} bytes, which exceeds the memory budget of {budget} bytes")]
+    MemoryBudgetExceeded { required: usize, budget: usize },
+
+    #[error("AHPError: {}", _0)]
+    AHPError(#[from] AHPError),
 }
 
-impl From<AHPError> for SNARKError {
-    fn from(err: AHPError) -> Self {
-        SNARKError::Crate("AHPError", format!("{err:?}"))
+impl snarkvm_utilities::ErrorCode for SNARKError {
+    fn error_code(&self) -> u32 {
+        match self {
+            Self::AnyhowError(..) => 2200,
+            Self::ConstraintFieldError(..) => 2201,
+            Self::Crate(..) => 2202,
+            Self::ExpectedCircuitSpecificSRS => 2203,
+            Self::Message(..) => 2204,
+            Self::SynthesisError(error) => error.error_code(),
+            Self::EmptyBatch => 2205,
+            Self::BatchSizeMismatch => 2206,
+            Self::PublicInputSizeMismatch => 2207,
+            Self::CircuitNotFound => 2208,
+            Self::MemoryBudgetExceeded { .. } => 2209,
+            Self::AHPError(error) => error.error_code(),
+        }
     }
 }
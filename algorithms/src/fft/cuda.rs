@@ -0,0 +1,75 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Dispatches FFTs over the BLS12-377 scalar field to the GPU, in natural order.
+
+use crate::fft::DomainCoeff;
+use snarkvm_curves::bls12_377::Fr;
+use snarkvm_fields::FftField;
+
+use core::{any::TypeId, mem::size_of};
+use snarkvm_algorithms_cuda::{error_description, NTTInputOutputOrder};
+pub(crate) use snarkvm_algorithms_cuda::{NTTDirection, NTTType};
+
+/// The minimum domain size for which an FFT is dispatched to the GPU.
+const CUDA_FFT_THRESHOLD: usize = 1 << 5;
+
+/// Returns `true` if an FFT of `size` elements of type `T` may be computed on the GPU.
+fn is_supported<F: FftField, T: DomainCoeff<F>>(size: usize) -> bool {
+    // The kernels are compiled for the BLS12-377 scalar field, and the coefficients
+    // must be field elements rather than, e.g., group elements.
+    TypeId::of::<F>() == TypeId::of::<Fr>()
+        && size_of::<T>() == size_of::<F>()
+        && size.is_power_of_two()
+        && size >= CUDA_FFT_THRESHOLD
+        && size <= snarkvm_algorithms_cuda::ntt_max_domain_size()
+}
+
+/// Computes the NTT of `x_s` in place on the GPU, returning `false` if it is not supported
+/// or the GPU fails, in which case `x_s` is unchanged.
+pub(crate) fn ntt<F: FftField, T: DomainCoeff<F>>(x_s: &mut [T], direction: NTTDirection, ntt_type: NTTType) -> bool {
+    if !is_supported::<F, T>(x_s.len()) {
+        return false;
+    }
+    match snarkvm_algorithms_cuda::NTT(x_s.len(), x_s, NTTInputOutputOrder::NN, direction, ntt_type) {
+        Ok(()) => true,
+        Err(error) => {
+            eprintln!("Warning: CUDA FFT failed ({}), retrying on the CPU", error_description(error.code));
+            false
+        }
+    }
+}
+
+/// Computes the NTT of each polynomial in place on the GPU, returning whether each one was transformed.
+/// The polynomials that are not transformed are unchanged.
+pub(crate) fn ntt_batch<F: FftField, T: DomainCoeff<F>>(
+    polys: &mut [Vec<T>],
+    direction: NTTDirection,
+    ntt_type: NTTType,
+) -> Vec<bool> {
+    let size = match polys.first() {
+        Some(poly) if polys.iter().all(|p| p.len() == poly.len()) => poly.len(),
+        _ => return vec![false; polys.len()],
+    };
+    if !is_supported::<F, T>(size) {
+        return vec![false; polys.len()];
+    }
+
+    let mut inouts = polys.iter_mut().map(|poly| poly.as_mut_slice()).collect::<Vec<_>>();
+    let codes = snarkvm_algorithms_cuda::NTT_batch(size, &mut inouts, NTTInputOutputOrder::NN, direction, ntt_type);
+    if let Some(&code) = codes.iter().find(|&&code| code != 0) {
+        eprintln!("Warning: CUDA FFT failed ({}), retrying on the CPU", error_description(code));
+    }
+    codes.into_iter().map(|code| code == 0).collect()
+}
@@ -24,6 +24,10 @@
 //! This allows us to perform polynomial operations in O(n)
 //! by performing an O(n log n) FFT over such a domain.
 
+#[cfg(all(feature = "cuda", target_arch = "x86_64"))]
+use crate::fft::cuda;
+#[cfg(feature = "out-of-core")]
+use crate::fft::out_of_core;
 use crate::{
     cfg_chunks_mut,
     cfg_into_iter,
@@ -31,7 +35,7 @@ use crate::{
     cfg_iter_mut,
     fft::{DomainCoeff, SparsePolynomial},
 };
-use snarkvm_fields::{batch_inversion, FftField, FftParameters, Field};
+use snarkvm_fields::{batch_inversion, FftField, Field};
 #[cfg(not(feature = "serial"))]
 use snarkvm_utilities::max_available_threads;
 use snarkvm_utilities::{execute_with_max_available_threads, serialize::*};
@@ -39,7 +43,7 @@ use snarkvm_utilities::{execute_with_max_available_threads, serialize::*};
 use rand::Rng;
 use std::{borrow::Cow, fmt};
 
-use anyhow::{ensure, Result};
+use anyhow::{anyhow, ensure, Result};
 
 #[cfg(not(feature = "serial"))]
 use rayon::prelude::*;
@@ -77,12 +81,13 @@ const MIN_PARALLEL_CHUNK_SIZE: usize = 1 << 7;
 
 /// Defines a domain over which finite field (I)FFTs can be performed. Works
 /// only for fields that have a large multiplicative subgroup of size that is
-/// a power-of-2.
-#[derive(Copy, Clone, Hash, Eq, PartialEq, CanonicalSerialize, CanonicalDeserialize)]
+/// a power-of-2, or, for mixed-radix domains, of size `2^i * q^j` for the small
+/// subgroup base `q` of the field.
+#[derive(Copy, Clone, Hash, Eq, PartialEq, CanonicalSerialize)]
 pub struct EvaluationDomain<F: FftField> {
     /// The size of the domain.
     pub size: u64,
-    /// `log_2(self.size)`.
+    /// `log_2(self.size)`, or the two-adicity of the size for mixed-radix domains.
     pub log_size_of_group: u32,
     /// Size of the domain as a field element.
     pub size_as_field_element: F,
@@ -102,6 +107,39 @@ impl<F: FftField> fmt::Debug for EvaluationDomain<F> {
     }
 }
 
+impl<F: FftField> Valid for EvaluationDomain<F> {
+    fn check(&self) -> Result<(), SerializationError> {
+        // A domain is determined by its size, so every other field must match the domain of that size.
+        let size = usize::try_from(self.size).map_err(|_| SerializationError::InvalidData)?;
+        match size > 0 && Self::from_size(size).as_ref() == Some(self) {
+            true => Ok(()),
+            false => Err(SerializationError::InvalidData),
+        }
+    }
+}
+
+impl<F: FftField> CanonicalDeserialize for EvaluationDomain<F> {
+    fn deserialize_with_mode<R: Read>(
+        mut reader: R,
+        compress: Compress,
+        validate: Validate,
+    ) -> Result<Self, SerializationError> {
+        let domain = Self {
+            size: CanonicalDeserialize::deserialize_with_mode(&mut reader, compress, validate)?,
+            log_size_of_group: CanonicalDeserialize::deserialize_with_mode(&mut reader, compress, validate)?,
+            size_as_field_element: CanonicalDeserialize::deserialize_with_mode(&mut reader, compress, validate)?,
+            size_inv: CanonicalDeserialize::deserialize_with_mode(&mut reader, compress, validate)?,
+            group_gen: CanonicalDeserialize::deserialize_with_mode(&mut reader, compress, validate)?,
+            group_gen_inv: CanonicalDeserialize::deserialize_with_mode(&mut reader, compress, validate)?,
+            generator_inv: CanonicalDeserialize::deserialize_with_mode(&mut reader, compress, validate)?,
+        };
+        if let Validate::Yes = validate {
+            domain.check()?;
+        }
+        Ok(domain)
+    }
+}
+
 impl<F: FftField> EvaluationDomain<F> {
     /// Sample an element that is *not* in the domain.
     pub fn sample_element_outside_domain<R: Rng>(&self, rng: &mut R) -> F {
@@ -116,19 +154,26 @@ impl<F: FftField> EvaluationDomain<F> {
     /// having `num_coeffs` coefficients.
     pub fn new(num_coeffs: usize) -> Option<Self> {
         // Compute the size of our evaluation domain
-        let size = num_coeffs.checked_next_power_of_two()? as u64;
-        let log_size_of_group = size.trailing_zeros();
+        Self::from_size(F::smallest_two_adic_domain_size(num_coeffs)?)
+    }
 
-        // libfqfft uses > https://github.com/scipr-lab/libfqfft/blob/e0183b2cef7d4c5deb21a6eaf3fe3b586d738fe0/libfqfft/evaluation_domain/domains/basic_radix2_domain.tcc#L33
-        if log_size_of_group > F::FftParameters::TWO_ADICITY {
-            return None;
-        }
+    /// Construct the smallest domain that is large enough for evaluations of a polynomial
+    /// having `num_coeffs` coefficients, which is a mixed-radix domain of size `2^i * q^j`
+    /// if the field defines a small subgroup base `q` and this is smaller than the radix-2 domain.
+    pub fn new_mixed_radix(num_coeffs: usize) -> Option<Self> {
+        Self::from_size(F::smallest_domain_size(num_coeffs)?)
+    }
+
+    /// Construct the domain of the given size, which must be supported by the field.
+    fn from_size(size: usize) -> Option<Self> {
+        let size = size as u64;
+        let log_size_of_group = size.trailing_zeros();
 
         // Compute the generator for the multiplicative subgroup.
-        // It should be the 2^(log_size_of_group) root of unity.
+        // It should be the `size`-th root of unity.
         let group_gen = F::get_root_of_unity(size as usize)?;
 
-        // Check that it is indeed the 2^(log_size_of_group) root of unity.
+        // Check that it is indeed the `size`-th root of unity.
         debug_assert_eq!(group_gen.pow([size]), F::one());
 
         let size_as_field_element = F::from(size);
@@ -148,8 +193,7 @@ impl<F: FftField> EvaluationDomain<F> {
     /// Return the size of a domain that is large enough for evaluations of a polynomial
     /// having `num_coeffs` coefficients.
     pub fn compute_size_of_domain(num_coeffs: usize) -> Option<usize> {
-        let size = num_coeffs.checked_next_power_of_two()?;
-        if size.trailing_zeros() <= F::FftParameters::TWO_ADICITY { Some(size) } else { None }
+        F::smallest_two_adic_domain_size(num_coeffs)
     }
 
     /// Return the size of `self`.
@@ -157,6 +201,11 @@ impl<F: FftField> EvaluationDomain<F> {
         self.size as usize
     }
 
+    /// Return `true` if the size of `self` is not a power of two.
+    pub fn is_mixed_radix(&self) -> bool {
+        !self.size.is_power_of_two()
+    }
+
     /// Compute an FFT.
     pub fn fft<T: DomainCoeff<F>>(&self, coeffs: &[T]) -> Vec<T> {
         let mut coeffs = coeffs.to_vec();
@@ -165,9 +214,14 @@ impl<F: FftField> EvaluationDomain<F> {
     }
 
     /// Compute an FFT, modifying the vector in place.
+    /// Above the memory budget of `fft::set_fft_memory_budget`, the FFT is computed out of core.
     pub fn fft_in_place<T: DomainCoeff<F>>(&self, coeffs: &mut Vec<T>) {
         execute_with_max_available_threads(|| {
             coeffs.resize(self.size(), T::zero());
+            #[cfg(feature = "out-of-core")]
+            if out_of_core::fft_in_place(self, coeffs, false) {
+                return;
+            }
             self.in_order_fft_in_place(&mut *coeffs);
         });
     }
@@ -184,6 +238,10 @@ impl<F: FftField> EvaluationDomain<F> {
     pub fn ifft_in_place<T: DomainCoeff<F>>(&self, evals: &mut Vec<T>) {
         execute_with_max_available_threads(|| {
             evals.resize(self.size(), T::zero());
+            #[cfg(feature = "out-of-core")]
+            if out_of_core::fft_in_place(self, evals, true) {
+                return;
+            }
             self.in_order_ifft_in_place(&mut *evals);
         });
     }
@@ -198,8 +256,14 @@ impl<F: FftField> EvaluationDomain<F> {
     /// Compute an FFT over a coset of the domain, modifying the input vector
     /// in place.
     pub fn coset_fft_in_place<T: DomainCoeff<F>>(&self, coeffs: &mut Vec<T>) {
+        self.coset_fft_in_place_with_shift(coeffs, F::multiplicative_generator());
+    }
+
+    /// Compute an FFT over the coset `shift * H` of the domain `H`, modifying the input vector
+    /// in place.
+    pub fn coset_fft_in_place_with_shift<T: DomainCoeff<F>>(&self, coeffs: &mut Vec<T>, shift: F) {
         execute_with_max_available_threads(|| {
-            Self::distribute_powers(coeffs, F::multiplicative_generator());
+            Self::distribute_powers(coeffs, shift);
             self.fft_in_place(coeffs);
         });
     }
@@ -215,10 +279,94 @@ impl<F: FftField> EvaluationDomain<F> {
     pub fn coset_ifft_in_place<T: DomainCoeff<F>>(&self, evals: &mut Vec<T>) {
         execute_with_max_available_threads(|| {
             evals.resize(self.size(), T::zero());
+            #[cfg(feature = "out-of-core")]
+            if out_of_core::fft_in_place(self, evals, true) {
+                return Self::distribute_powers(evals, self.generator_inv);
+            }
             self.in_order_coset_ifft_in_place(&mut *evals);
         });
     }
 
+    /// Compute an IFFT over the coset `shift * H` of the domain `H`, modifying the input vector
+    /// in place. The `shift` must be non-zero.
+    pub fn coset_ifft_in_place_with_shift<T: DomainCoeff<F>>(&self, evals: &mut Vec<T>, shift: F) {
+        execute_with_max_available_threads(|| {
+            self.ifft_in_place(evals);
+            Self::distribute_powers(evals, shift.inverse().expect("The coset shift must be non-zero"));
+        });
+    }
+
+    /// Return the domain `H'` of size `blowup * self.size()`, over whose coset `g * H'` the
+    /// low-degree extension is evaluated, or `None` if the field does not support its size.
+    pub fn lde_domain(&self, blowup: usize) -> Option<Self> {
+        Self::from_size(self.size().checked_mul(blowup)?)
+    }
+
+    /// Compute the low-degree extension of the polynomial with the given coefficients, i.e. its
+    /// evaluations over the coset `g * H'` of the domain `H'` returned by `Self::lde_domain`,
+    /// where `g` is the multiplicative generator of the field.
+    ///
+    /// The coset is disjoint from `H'`, so that the evaluations may be divided by the vanishing
+    /// polynomial of `self`, e.g. to compute a quotient polynomial.
+    pub fn lde<T: DomainCoeff<F>>(&self, coeffs: &[T], blowup: usize) -> Result<Vec<T>> {
+        ensure!(coeffs.len() <= self.size(), "The polynomial has more coefficients than the domain size");
+        Ok(self.lde_domain_or_err(blowup)?.coset_fft(coeffs))
+    }
+
+    /// Return the domain of `Self::lde_domain`, or an error if the field does not support its size.
+    fn lde_domain_or_err(&self, blowup: usize) -> Result<Self> {
+        self.lde_domain(blowup).ok_or_else(|| {
+            anyhow!("The field does not support a blowup of {blowup} over a domain of size {}", self.size)
+        })
+    }
+
+    /// Compute the FFT of each of the given polynomials, modifying the vectors in place.
+    ///
+    /// On the GPU, the polynomials are spread over the devices and their streams, so that the
+    /// transfers of one polynomial overlap with the transform of another.
+    pub fn fft_in_place_batch<T: DomainCoeff<F>>(&self, polys: &mut [Vec<T>]) {
+        execute_with_max_available_threads(|| {
+            cfg_iter_mut!(polys).for_each(|coeffs| coeffs.resize(self.size(), T::zero()));
+
+            #[cfg(all(feature = "cuda", target_arch = "x86_64"))]
+            let transformed = cuda::ntt_batch::<F, T>(polys, cuda::NTTDirection::Forward, cuda::NTTType::Standard);
+            #[cfg(not(all(feature = "cuda", target_arch = "x86_64")))]
+            let transformed = vec![false; polys.len()];
+
+            // Compute the remaining polynomials on the CPU, sharing the precomputation.
+            if transformed.iter().any(|transformed| !transformed) {
+                let pc = self.cached_fft_precomputation();
+                let remaining = cfg_iter_mut!(polys).zip(transformed).filter(|(_, done)| !done);
+                remaining.for_each(|(coeffs, _)| self.fft_helper_in_place_with_pc(coeffs, FFTOrder::II, &pc));
+            }
+        });
+    }
+
+    /// Compute the IFFT of each of the given evaluation vectors, modifying the vectors in place.
+    ///
+    /// On the GPU, the vectors are spread over the devices and their streams, so that the
+    /// transfers of one vector overlap with the transform of another.
+    pub fn ifft_in_place_batch<T: DomainCoeff<F>>(&self, evals: &mut [Vec<T>]) {
+        execute_with_max_available_threads(|| {
+            cfg_iter_mut!(evals).for_each(|evals| evals.resize(self.size(), T::zero()));
+
+            #[cfg(all(feature = "cuda", target_arch = "x86_64"))]
+            let transformed = cuda::ntt_batch::<F, T>(evals, cuda::NTTDirection::Inverse, cuda::NTTType::Standard);
+            #[cfg(not(all(feature = "cuda", target_arch = "x86_64")))]
+            let transformed = vec![false; evals.len()];
+
+            // Compute the remaining vectors on the CPU, sharing the precomputation.
+            if transformed.iter().any(|transformed| !transformed) {
+                let pc = self.cached_ifft_precomputation();
+                let remaining = cfg_iter_mut!(evals).zip(transformed).filter(|(_, done)| !done);
+                remaining.for_each(|(evals, _)| {
+                    self.ifft_helper_in_place_with_pc(evals, FFTOrder::II, &pc);
+                    cfg_iter_mut!(evals).for_each(|val| *val *= self.size_inv);
+                });
+            }
+        });
+    }
+
     /// Multiply the `i`-th element of `coeffs` with `g^i`.
     fn distribute_powers<T: DomainCoeff<F>>(coeffs: &mut [T], g: F) {
         Self::distribute_powers_and_mul_by_const(coeffs, g, F::one());
@@ -296,6 +444,14 @@ impl<F: FftField> EvaluationDomain<F> {
         SparsePolynomial::from_coefficients(coeffs)
     }
 
+    /// Return the sparse polynomial `v_self / v_other = 1 + X^m + X^2m + ... + X^(n - m)`,
+    /// for the subdomain `other` of size `m` of this domain of size `n`.
+    pub fn vanishing_polynomial_quotient(&self, other: &Self) -> Result<SparsePolynomial<F>> {
+        ensure!(self.size() % other.size() == 0, "other.size() must divide self.size()");
+        let coeffs = (0..self.size()).step_by(other.size()).map(|i| (i, F::one()));
+        Ok(SparsePolynomial::from_coefficients(coeffs))
+    }
+
     /// This evaluates the vanishing polynomial for this domain at tau.
     /// For multiplicative subgroups, this polynomial is `z(X) = X^self.size - 1`.
     pub fn evaluate_vanishing_polynomial(&self, tau: F) -> F {
@@ -316,10 +472,32 @@ impl<F: FftField> EvaluationDomain<F> {
         cfg_iter_mut!(evals).for_each(|eval| *eval *= &i);
     }
 
+    /// Evaluate the vanishing polynomial of this domain over the coset `g * H'` of `Self::lde`.
+    /// As `z(g * w^i) = g^n * w^(n * i) - 1` for the generator `w` of `H'`, the evaluations repeat
+    /// with a period of `blowup`, so only the first `blowup` evaluations are returned.
+    pub fn evaluate_vanishing_polynomial_over_lde_coset(&self, blowup: usize) -> Result<Vec<F>> {
+        let lde_domain = self.lde_domain_or_err(blowup)?;
+        let g_n = F::multiplicative_generator().pow([self.size]);
+        let w_n = lde_domain.group_gen.pow([self.size]);
+        Ok(compute_powers_and_mul_by_const_serial(blowup, w_n, g_n).into_iter().map(|z| z - F::one()).collect())
+    }
+
+    /// Divide the evaluations over the coset `g * H'` of `Self::lde` by the vanishing polynomial
+    /// of this domain, modifying the evaluations in place.
+    pub fn divide_by_vanishing_poly_over_lde_coset_in_place(&self, evals: &mut [F], blowup: usize) -> Result<()> {
+        let mut inverses = self.evaluate_vanishing_polynomial_over_lde_coset(blowup)?;
+        ensure!(evals.len() == self.size() * blowup, "The number of evaluations must be the size of the LDE domain");
+        batch_inversion(&mut inverses);
+
+        cfg_iter_mut!(evals).enumerate().for_each(|(i, eval)| *eval *= &inverses[i % blowup]);
+        Ok(())
+    }
+
     /// Given an index in the `other` subdomain, return an index into this domain `self`
     /// This assumes the `other`'s elements are also `self`'s first elements
     pub fn reindex_by_subdomain(&self, other: &Self, index: usize) -> Result<usize> {
         ensure!(self.size() > other.size(), "other.size() must be smaller than self.size()");
+        ensure!(self.size() % other.size() == 0, "other.size() must divide self.size()");
 
         // Let this subgroup be G, and the subgroup we're re-indexing by be S.
         // Since its a subgroup, the 0th element of S is at index 0 in G, the first element of S is at
@@ -372,21 +550,11 @@ impl<F: FftField> EvaluationDomain<F> {
 
     pub(crate) fn in_order_fft_in_place<T: DomainCoeff<F>>(&self, x_s: &mut [T]) {
         #[cfg(all(feature = "cuda", target_arch = "x86_64"))]
-        // SNP TODO: how to set threshold and check that the type is Fr
-        if self.size >= 32 && std::mem::size_of::<T>() == 32 {
-            let result = snarkvm_algorithms_cuda::NTT(
-                self.size as usize,
-                x_s,
-                snarkvm_algorithms_cuda::NTTInputOutputOrder::NN,
-                snarkvm_algorithms_cuda::NTTDirection::Forward,
-                snarkvm_algorithms_cuda::NTTType::Standard,
-            );
-            if result.is_ok() {
-                return;
-            }
+        if cuda::ntt::<F, T>(x_s, cuda::NTTDirection::Forward, cuda::NTTType::Standard) {
+            return;
         }
 
-        let pc = self.precompute_fft();
+        let pc = self.cached_fft_precomputation();
         self.fft_helper_in_place_with_pc(x_s, FFTOrder::II, &pc)
     }
 
@@ -401,42 +569,22 @@ impl<F: FftField> EvaluationDomain<F> {
 
     pub(crate) fn in_order_ifft_in_place<T: DomainCoeff<F>>(&self, x_s: &mut [T]) {
         #[cfg(all(feature = "cuda", target_arch = "x86_64"))]
-        // SNP TODO: how to set threshold
-        if self.size >= 32 && std::mem::size_of::<T>() == 32 {
-            let result = snarkvm_algorithms_cuda::NTT(
-                self.size as usize,
-                x_s,
-                snarkvm_algorithms_cuda::NTTInputOutputOrder::NN,
-                snarkvm_algorithms_cuda::NTTDirection::Inverse,
-                snarkvm_algorithms_cuda::NTTType::Standard,
-            );
-            if result.is_ok() {
-                return;
-            }
+        if cuda::ntt::<F, T>(x_s, cuda::NTTDirection::Inverse, cuda::NTTType::Standard) {
+            return;
         }
 
-        let pc = self.precompute_ifft();
+        let pc = self.cached_ifft_precomputation();
         self.ifft_helper_in_place_with_pc(x_s, FFTOrder::II, &pc);
         cfg_iter_mut!(x_s).for_each(|val| *val *= self.size_inv);
     }
 
     pub(crate) fn in_order_coset_ifft_in_place<T: DomainCoeff<F>>(&self, x_s: &mut [T]) {
         #[cfg(all(feature = "cuda", target_arch = "x86_64"))]
-        // SNP TODO: how to set threshold
-        if self.size >= 32 && std::mem::size_of::<T>() == 32 {
-            let result = snarkvm_algorithms_cuda::NTT(
-                self.size as usize,
-                x_s,
-                snarkvm_algorithms_cuda::NTTInputOutputOrder::NN,
-                snarkvm_algorithms_cuda::NTTDirection::Inverse,
-                snarkvm_algorithms_cuda::NTTType::Coset,
-            );
-            if result.is_ok() {
-                return;
-            }
+        if cuda::ntt::<F, T>(x_s, cuda::NTTDirection::Inverse, cuda::NTTType::Coset) {
+            return;
         }
 
-        let pc = self.precompute_ifft();
+        let pc = self.cached_ifft_precomputation();
         self.ifft_helper_in_place_with_pc(x_s, FFTOrder::II, &pc);
         let coset_shift = self.generator_inv;
         Self::distribute_powers_and_mul_by_const(x_s, coset_shift, self.size_inv);
@@ -449,18 +597,8 @@ impl<F: FftField> EvaluationDomain<F> {
         pre_comp: &FFTPrecomputation<F>,
     ) {
         #[cfg(all(feature = "cuda", target_arch = "x86_64"))]
-        // SNP TODO: how to set threshold
-        if self.size >= 32 && std::mem::size_of::<T>() == 32 {
-            let result = snarkvm_algorithms_cuda::NTT(
-                self.size as usize,
-                x_s,
-                snarkvm_algorithms_cuda::NTTInputOutputOrder::NN,
-                snarkvm_algorithms_cuda::NTTDirection::Forward,
-                snarkvm_algorithms_cuda::NTTType::Standard,
-            );
-            if result.is_ok() {
-                return;
-            }
+        if cuda::ntt::<F, T>(x_s, cuda::NTTDirection::Forward, cuda::NTTType::Standard) {
+            return;
         }
 
         self.fft_helper_in_place_with_pc(x_s, FFTOrder::II, pre_comp)
@@ -480,18 +618,8 @@ impl<F: FftField> EvaluationDomain<F> {
         pre_comp: &IFFTPrecomputation<F>,
     ) {
         #[cfg(all(feature = "cuda", target_arch = "x86_64"))]
-        // SNP TODO: how to set threshold
-        if self.size >= 32 && std::mem::size_of::<T>() == 32 {
-            let result = snarkvm_algorithms_cuda::NTT(
-                self.size as usize,
-                x_s,
-                snarkvm_algorithms_cuda::NTTInputOutputOrder::NN,
-                snarkvm_algorithms_cuda::NTTDirection::Inverse,
-                snarkvm_algorithms_cuda::NTTType::Standard,
-            );
-            if result.is_ok() {
-                return;
-            }
+        if cuda::ntt::<F, T>(x_s, cuda::NTTDirection::Inverse, cuda::NTTType::Standard) {
+            return;
         }
 
         self.ifft_helper_in_place_with_pc(x_s, FFTOrder::II, pre_comp);
@@ -514,18 +642,8 @@ impl<F: FftField> EvaluationDomain<F> {
         pre_comp: &IFFTPrecomputation<F>,
     ) {
         #[cfg(all(feature = "cuda", target_arch = "x86_64"))]
-        // SNP TODO: how to set threshold
-        if self.size >= 32 && std::mem::size_of::<T>() == 32 {
-            let result = snarkvm_algorithms_cuda::NTT(
-                self.size as usize,
-                x_s,
-                snarkvm_algorithms_cuda::NTTInputOutputOrder::NN,
-                snarkvm_algorithms_cuda::NTTDirection::Inverse,
-                snarkvm_algorithms_cuda::NTTType::Coset,
-            );
-            if result.is_ok() {
-                return;
-            }
+        if cuda::ntt::<F, T>(x_s, cuda::NTTDirection::Inverse, cuda::NTTType::Coset) {
+            return;
         }
 
         self.ifft_helper_in_place_with_pc(x_s, FFTOrder::II, pre_comp);
@@ -542,6 +660,11 @@ impl<F: FftField> EvaluationDomain<F> {
         use FFTOrder::*;
         let pc = pre_comp.precomputation_for_subdomain(self).unwrap();
 
+        // Mixed-radix domains always use the natural order for both the input and the output.
+        if self.is_mixed_radix() {
+            return self.mixed_radix_helper_with_roots(x_s, &pc.roots, 1);
+        }
+
         let log_len = log2(x_s.len());
 
         if ord == OI {
@@ -567,6 +690,11 @@ impl<F: FftField> EvaluationDomain<F> {
         use FFTOrder::*;
         let pc = pre_comp.precomputation_for_subdomain(self).unwrap();
 
+        // Mixed-radix domains always use the natural order for both the input and the output.
+        if self.is_mixed_radix() {
+            return self.mixed_radix_helper_with_roots(x_s, &pc.inverse_roots, 1);
+        }
+
         let log_len = log2(x_s.len());
 
         if ord == II {
@@ -583,14 +711,19 @@ impl<F: FftField> EvaluationDomain<F> {
     /// Computes the first `self.size / 2` roots of unity for the entire domain.
     /// e.g. for the domain [1, g, g^2, ..., g^{n - 1}], it computes
     // [1, g, g^2, ..., g^{(n/2) - 1}]
+    // For mixed-radix domains, it computes all `self.size` roots of unity.
     #[cfg(feature = "serial")]
     pub fn roots_of_unity(&self, root: F) -> Vec<F> {
-        compute_powers_serial((self.size as usize) / 2, root)
+        compute_powers_serial(self.num_roots_of_unity(), root)
     }
 
-    /// Computes the first `self.size / 2` roots of unity.
+    /// Computes the first `self.size / 2` roots of unity,
+    /// or all `self.size` roots of unity for mixed-radix domains.
     #[cfg(not(feature = "serial"))]
     pub fn roots_of_unity(&self, root: F) -> Vec<F> {
+        if self.is_mixed_radix() {
+            return compute_powers(self.num_roots_of_unity(), root);
+        }
         // TODO: check if this method can replace parallel compute powers.
         let log_size = log2(self.size as usize);
         // early exit for short inputs
@@ -614,6 +747,14 @@ impl<F: FftField> EvaluationDomain<F> {
         }
     }
 
+    /// Returns the number of roots of unity computed by `Self::roots_of_unity`.
+    fn num_roots_of_unity(&self) -> usize {
+        match self.is_mixed_radix() {
+            true => self.size(),
+            false => self.size() / 2,
+        }
+    }
+
     #[cfg(not(feature = "serial"))]
     fn roots_of_unity_recursive(out: &mut [F], log_powers: &[F]) {
         assert_eq!(out.len(), 1 << log_powers.len());
@@ -770,6 +911,47 @@ impl<F: FftField> EvaluationDomain<F> {
             gap *= 2;
         }
     }
+
+    /// Computes the in-order FFT of `xi` over the subgroup generated by `g^stride`, given the powers
+    /// `roots = [1, g, ..., g^{n - 1}]` of the generator `g` of a mixed-radix domain of size `n`.
+    ///
+    /// Each radix-3 step splits `xi` into three contiguous thirds, combines them with the cube roots of unity,
+    /// and twists them, so that the FFT of each third yields the outputs at the indices that are congruent
+    /// to `0`, `1` and `2` modulo 3. Once the length is a power of two, the radix-2 FFT is used.
+    fn mixed_radix_helper_with_roots<T: DomainCoeff<F>>(&self, xi: &mut [T], roots: &[F], stride: usize) {
+        let n = xi.len();
+        if n % 3 != 0 {
+            let roots = roots.iter().step_by(stride).take(n / 2).copied().collect::<Vec<_>>();
+            self.io_helper_with_roots(xi, &roots);
+            derange(xi);
+            return;
+        }
+
+        let m = n / 3;
+        // The primitive cube roots of unity, `zeta` and `zeta^2`.
+        let (zeta, zeta_sq) = (roots[stride * m], roots[2 * stride * m]);
+        let (lo, rest) = xi.split_at_mut(m);
+        let (mid, hi) = rest.split_at_mut(m);
+        cfg_iter_mut!(lo).zip(mid).zip(hi).enumerate().for_each(|(i, ((a, b), c))| {
+            let (x, mut y, mut z) = (*a, *b, *c);
+            let (mut y_sq, mut z_sq) = (y, z);
+            y *= zeta;
+            z *= zeta;
+            y_sq *= zeta_sq;
+            z_sq *= zeta_sq;
+
+            *a = x + *b + *c;
+            *b = x + y + z_sq;
+            *b *= roots[stride * i];
+            *c = x + y_sq + z;
+            *c *= roots[2 * stride * i];
+        });
+        cfg_chunks_mut!(xi, m).for_each(|third| self.mixed_radix_helper_with_roots(third, roots, 3 * stride));
+
+        // Interleave the thirds, so that the `k`-th output of the `j`-th third is at index `3 * k + j`.
+        let thirds = xi.to_vec();
+        cfg_iter_mut!(xi).enumerate().for_each(|(k, x)| *x = thirds[(k % 3) * m + k / 3]);
+    }
 }
 
 /// The minimum number of chunks at which root compaction
@@ -897,9 +1079,9 @@ impl<F: FftField> FFTPrecomputation<F> {
         }
         if &self.domain == domain {
             Some(Cow::Borrowed(self))
-        } else if domain.size() < self.domain.size() {
+        } else if domain.size() < self.domain.size() && self.domain.size() % domain.size() == 0 {
             let size_ratio = self.domain.size() / domain.size();
-            let roots = self.roots.iter().step_by(size_ratio).copied().collect();
+            let roots = self.roots.iter().step_by(size_ratio).take(domain.num_roots_of_unity()).copied().collect();
             Some(Cow::Owned(Self { roots, domain: *domain }))
         } else {
             None
@@ -921,9 +1103,10 @@ impl<F: FftField> IFFTPrecomputation<F> {
         }
         if &self.domain == domain {
             Some(Cow::Borrowed(self))
-        } else if domain.size() < self.domain.size() {
+        } else if domain.size() < self.domain.size() && self.domain.size() % domain.size() == 0 {
             let size_ratio = self.domain.size() / domain.size();
-            let inverse_roots = self.inverse_roots.iter().step_by(size_ratio).copied().collect();
+            let inverse_roots =
+                self.inverse_roots.iter().step_by(size_ratio).take(domain.num_roots_of_unity()).copied().collect();
             Some(Cow::Owned(Self { inverse_roots, domain: *domain }))
         } else {
             None
@@ -1095,6 +1278,93 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_mixed_radix_fft_correctness() {
+        let mut rng = TestRng::default();
+
+        for num_coeffs in [2, 3, 5, 13, 24, 25, 40, 97, 200] {
+            let domain = EvaluationDomain::<Fr>::new_mixed_radix(num_coeffs).unwrap();
+            let radix_2_domain = EvaluationDomain::<Fr>::new(num_coeffs).unwrap();
+            assert!(domain.size() >= num_coeffs && domain.size() <= radix_2_domain.size());
+            assert_eq!(domain.is_mixed_radix(), domain.size() % 3 == 0);
+
+            let random_polynomial = DensePolynomial::<Fr>::rand(num_coeffs - 1, &mut rng);
+            let polynomial_evaluations = domain.fft(&random_polynomial.coeffs);
+            let polynomial_coset_evaluations = domain.coset_fft(&random_polynomial.coeffs);
+            let z = domain.vanishing_polynomial();
+            for (i, x) in domain.elements().enumerate() {
+                let coset_x = Fr::multiplicative_generator() * x;
+
+                assert_eq!(polynomial_evaluations[i], random_polynomial.evaluate(x));
+                assert_eq!(polynomial_coset_evaluations[i], random_polynomial.evaluate(coset_x));
+                assert!(z.evaluate(x).is_zero());
+            }
+            assert_eq!(domain.elements().collect::<std::collections::HashSet<_>>().len(), domain.size());
+
+            let lagrange_coefficients = domain.evaluate_all_lagrange_coefficients(Fr::rand(&mut rng));
+            assert_eq!(lagrange_coefficients.iter().sum::<Fr>(), Fr::one());
+
+            assert_eq!(random_polynomial, DensePolynomial::from_coefficients_vec(domain.ifft(&polynomial_evaluations)));
+            assert_eq!(
+                random_polynomial,
+                DensePolynomial::from_coefficients_vec(domain.coset_ifft(&polynomial_coset_evaluations))
+            );
+        }
+    }
+
+    #[test]
+    fn test_coset_fft_with_shift() {
+        let mut rng = TestRng::default();
+
+        for num_coeffs in [1, 7, 24, 64] {
+            let domain = EvaluationDomain::<Fr>::new_mixed_radix(num_coeffs).unwrap();
+            let random_polynomial = DensePolynomial::<Fr>::rand(num_coeffs - 1, &mut rng);
+            let shift = Fr::rand(&mut rng);
+
+            let mut evaluations = random_polynomial.coeffs.clone();
+            domain.coset_fft_in_place_with_shift(&mut evaluations, shift);
+            for (i, x) in domain.elements().enumerate() {
+                assert_eq!(evaluations[i], random_polynomial.evaluate(shift * x));
+            }
+
+            domain.coset_ifft_in_place_with_shift(&mut evaluations, shift);
+            assert_eq!(random_polynomial, DensePolynomial::from_coefficients_vec(evaluations));
+        }
+    }
+
+    #[test]
+    fn test_lde() {
+        let mut rng = TestRng::default();
+
+        for num_coeffs in [1, 8, 24, 100] {
+            let domain = EvaluationDomain::<Fr>::new_mixed_radix(num_coeffs).unwrap();
+            let random_polynomial = DensePolynomial::<Fr>::rand(num_coeffs - 1, &mut rng);
+
+            for blowup in [1, 2, 4, 8] {
+                let lde_domain = domain.lde_domain(blowup).unwrap();
+                let lde = domain.lde(&random_polynomial.coeffs, blowup).unwrap();
+                let vanishing = domain.evaluate_vanishing_polynomial_over_lde_coset(blowup).unwrap();
+                assert_eq!(lde.len(), blowup * domain.size());
+                assert_eq!(vanishing.len(), blowup);
+                for (i, x) in lde_domain.elements().enumerate() {
+                    let coset_x = Fr::multiplicative_generator() * x;
+                    assert_eq!(lde[i], random_polynomial.evaluate(coset_x));
+                    assert_eq!(vanishing[i % blowup], domain.evaluate_vanishing_polynomial(coset_x));
+                }
+
+                // Dividing the evaluations of `p * z` by `z` recovers the evaluations of `p`.
+                let mut quotient =
+                    lde.iter().enumerate().map(|(i, eval)| *eval * vanishing[i % blowup]).collect::<Vec<_>>();
+                domain.divide_by_vanishing_poly_over_lde_coset_in_place(&mut quotient, blowup).unwrap();
+                assert_eq!(quotient, lde);
+            }
+
+            assert!(domain.lde(&random_polynomial.coeffs, 0).is_err());
+            assert!(domain.lde(&vec![Fr::one(); domain.size() + 1], 2).is_err());
+            assert!(domain.divide_by_vanishing_poly_over_lde_coset_in_place(&mut [Fr::one()], 2).is_err());
+        }
+    }
+
     /// Tests that FFT precomputation is correctly subdomained
     #[test]
     fn test_fft_precomputation() {
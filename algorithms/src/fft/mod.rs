@@ -15,15 +15,26 @@
 //! This crate implements functions for manipulating polynomials over finite fields,
 //! including FFTs.
 
+#[cfg(all(feature = "cuda", target_arch = "x86_64"))]
+mod cuda;
+
 pub mod domain;
 pub use domain::EvaluationDomain;
 
 pub mod evaluations;
 pub use evaluations::Evaluations;
 
+#[cfg(feature = "out-of-core")]
+mod out_of_core;
+#[cfg(feature = "out-of-core")]
+pub use out_of_core::{fft_memory_budget, set_fft_memory_budget, set_fft_memory_budget_on_thread};
+
 pub mod polynomial;
 pub use polynomial::{DensePolynomial, Polynomial, SparsePolynomial};
 
+mod precomputed;
+pub use precomputed::{clear_fft_precomputation_cache, PrecomputedDomain};
+
 #[cfg(test)]
 mod tests;
 
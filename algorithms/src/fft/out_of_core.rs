@@ -0,0 +1,265 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! An out-of-core FFT for domains whose working set exceeds the memory budget.
+//!
+//! The vector of length `n = n1 * n2` is viewed as an `n1 x n2` matrix in a memory-mapped temporary file,
+//! and transformed with the four-step algorithm: the FFTs of length `n1` of the columns, a multiplication
+//! by the twiddle factors, and the FFTs of length `n2` of the rows, which are transposed into a second file.
+//! Only a block of columns or rows is held in memory at a time, so the page cache may evict the rest.
+
+use crate::{
+    cfg_chunks_mut,
+    fft::{DomainCoeff, EvaluationDomain},
+};
+use snarkvm_fields::FftField;
+
+use anyhow::{ensure, Result};
+use core::{
+    cell::Cell,
+    marker::PhantomData,
+    mem::{align_of, size_of},
+    sync::atomic::{AtomicUsize, Ordering},
+};
+use memmap2::MmapMut;
+
+#[cfg(not(feature = "serial"))]
+use rayon::prelude::*;
+
+/// The memory budget of an FFT in bytes, above which it is computed out of core, or `usize::MAX` if unbounded.
+static MEMORY_BUDGET: AtomicUsize = AtomicUsize::new(usize::MAX);
+
+thread_local! {
+    /// The memory budget of an FFT computed on the current thread, overriding `MEMORY_BUDGET` if set.
+    static THREAD_MEMORY_BUDGET: Cell<Option<usize>> = Cell::new(None);
+}
+
+/// The smallest domain that is computed out of core.
+const MIN_OUT_OF_CORE_SIZE: usize = 1 << 4;
+
+/// Returns the memory budget of an FFT computed on the current thread in bytes, if any.
+pub fn fft_memory_budget() -> Option<usize> {
+    match THREAD_MEMORY_BUDGET.with(Cell::get).unwrap_or_else(|| MEMORY_BUDGET.load(Ordering::Relaxed)) {
+        usize::MAX => None,
+        budget => Some(budget),
+    }
+}
+
+/// Sets the memory budget of an FFT in bytes, above which a radix-2 FFT is computed out of core,
+/// in temporary files in `std::env::temp_dir()`. By default, there is no budget, and all FFTs are
+/// computed in memory.
+pub fn set_fft_memory_budget(budget: Option<usize>) {
+    MEMORY_BUDGET.store(budget.unwrap_or(usize::MAX), Ordering::Relaxed);
+}
+
+/// Sets the memory budget of the FFTs computed on the current thread in bytes, overriding the budget
+/// of the process, e.g. from the start handler of a dedicated thread pool. `None` removes the override.
+pub fn set_fft_memory_budget_on_thread(budget: Option<usize>) {
+    THREAD_MEMORY_BUDGET.with(|thread_budget| thread_budget.set(budget));
+}
+
+/// Computes the (inverse) FFT of `values` over `domain` out of core if it exceeds the memory budget,
+/// returning `false` if it does not or the computation fails, in which case `values` is unchanged.
+pub(super) fn fft_in_place<F: FftField, T: DomainCoeff<F>>(
+    domain: &EvaluationDomain<F>,
+    values: &mut Vec<T>,
+    inverse: bool,
+) -> bool {
+    let budget = fft_memory_budget().unwrap_or(usize::MAX);
+    if values.len().saturating_mul(size_of::<T>()) <= budget
+        || domain.is_mixed_radix()
+        || domain.size() < MIN_OUT_OF_CORE_SIZE
+    {
+        return false;
+    }
+    match fft_in_place_with_budget(domain, values, inverse, budget) {
+        Ok(()) => true,
+        Err(error) => {
+            eprintln!("Warning: out-of-core FFT failed ({error}), retrying in memory");
+            false
+        }
+    }
+}
+
+/// Computes the (inverse) FFT of `values` over the radix-2 `domain` with the four-step algorithm,
+/// holding blocks of at most `budget` bytes in memory, or as few rows or columns as possible.
+/// On error, `values` is unchanged.
+fn fft_in_place_with_budget<F: FftField, T: DomainCoeff<F>>(
+    domain: &EvaluationDomain<F>,
+    values: &mut Vec<T>,
+    inverse: bool,
+    budget: usize,
+) -> Result<()> {
+    let n = domain.size();
+    ensure!(values.len() == n, "Expected {n} values, found {}", values.len());
+    ensure!(!domain.is_mixed_radix() && n >= MIN_OUT_OF_CORE_SIZE, "Unsupported domain of size {n}");
+
+    // The columns have length `n1`, and the rows have length `n2`.
+    let n1 = 1 << domain.log_size_of_group.div_ceil(2);
+    let n2 = n / n1;
+    let column_domain = EvaluationDomain::<F>::new(n1).unwrap();
+    let row_domain = EvaluationDomain::<F>::new(n2).unwrap();
+    let omega = if inverse { domain.group_gen_inv } else { domain.group_gen };
+
+    let budget_len = (budget / size_of::<T>()).max(1);
+    let columns_per_block = floor_power_of_two((budget_len / n1).clamp(1, n2));
+    let rows_per_block = floor_power_of_two((budget_len / n2).clamp(1, n1));
+
+    let mut matrix = MappedBuffer::new(values)?;
+    let mut transposed = MappedBuffer::new(values)?;
+    // Nothing can fail from here on, so the input is released while the transform runs on disk.
+    values.clear();
+    values.shrink_to_fit();
+    let (matrix, transposed) = (matrix.as_mut_slice(), transposed.as_mut_slice());
+
+    // Step 1 and 2: transform each column, and multiply the `k1`-th entry of the `i2`-th column by `omega^(i2 * k1)`.
+    let mut block = vec![T::zero(); columns_per_block * n1];
+    for first_column in (0..n2).step_by(columns_per_block) {
+        // Gather the columns one row at a time, so that each one is contiguous in the block.
+        for (r, row) in matrix.chunks(n2).enumerate() {
+            for (c, value) in row[first_column..][..columns_per_block].iter().enumerate() {
+                block[c * n1 + r] = *value;
+            }
+        }
+
+        fft_chunks(&column_domain, &mut block, inverse);
+        cfg_chunks_mut!(block, n1).enumerate().for_each(|(c, column)| {
+            let twiddle = omega.pow([(first_column + c) as u64]);
+            let mut power = F::one();
+            for value in column {
+                *value *= power;
+                power *= twiddle;
+            }
+        });
+
+        for (r, row) in matrix.chunks_mut(n2).enumerate() {
+            for (c, value) in row[first_column..][..columns_per_block].iter_mut().enumerate() {
+                *value = block[c * n1 + r];
+            }
+        }
+    }
+    drop(block);
+
+    // Step 3 and 4: transform each row in place, and transpose the `k2`-th entry of the `k1`-th row to `k2 * n1 + k1`.
+    for (i, rows) in matrix.chunks_mut(rows_per_block * n2).enumerate() {
+        fft_chunks(&row_domain, rows, inverse);
+        let first_row = i * rows_per_block;
+        for (k2, column) in transposed.chunks_mut(n1).enumerate() {
+            for (r, value) in column[first_row..][..rows_per_block].iter_mut().enumerate() {
+                *value = rows[r * n2 + k2];
+            }
+        }
+    }
+
+    values.extend_from_slice(transposed);
+    Ok(())
+}
+
+/// Computes the (inverse) FFT over `domain` of each chunk of `values` of the size of `domain`.
+fn fft_chunks<F: FftField, T: DomainCoeff<F>>(domain: &EvaluationDomain<F>, values: &mut [T], inverse: bool) {
+    if inverse {
+        let pc = domain.precompute_ifft();
+        cfg_chunks_mut!(values, domain.size()).for_each(|chunk| domain.in_order_ifft_in_place_with_pc(chunk, &pc));
+    } else {
+        let pc = domain.precompute_fft();
+        cfg_chunks_mut!(values, domain.size()).for_each(|chunk| domain.in_order_fft_in_place_with_pc(chunk, &pc));
+    }
+}
+
+/// Returns the largest power of two that is at most `x`, which must be positive.
+fn floor_power_of_two(x: usize) -> usize {
+    1 << x.ilog2()
+}
+
+/// A temporary file, mapped into memory as a slice of values of type `T`.
+/// The file is deleted when it is dropped.
+struct MappedBuffer<T> {
+    mmap: MmapMut,
+    len: usize,
+    _marker: PhantomData<T>,
+}
+
+impl<T: Copy> MappedBuffer<T> {
+    /// Creates a temporary file holding a copy of `values`.
+    fn new(values: &[T]) -> Result<Self> {
+        ensure!(!values.is_empty() && size_of::<T>() > 0, "Cannot map an empty buffer");
+        let file = tempfile::tempfile()?;
+        file.set_len((values.len() * size_of::<T>()) as u64)?;
+        // Safety: the file is private to this process, and is not resized while it is mapped.
+        let mut mmap = unsafe { MmapMut::map_mut(&file)? };
+        // The mapping is page-aligned, which is sufficient for any `T`.
+        ensure!(mmap.as_ptr() as usize % align_of::<T>() == 0, "The memory map is not aligned");
+        // Safety: the mapping holds `values.len()` values of type `T`, and does not overlap `values`.
+        unsafe { core::ptr::copy_nonoverlapping(values.as_ptr(), mmap.as_mut_ptr() as *mut T, values.len()) };
+        Ok(Self { mmap, len: values.len(), _marker: PhantomData })
+    }
+
+    /// Returns the values in the file.
+    fn as_mut_slice(&mut self) -> &mut [T] {
+        // Safety: the mapping is aligned, and holds `self.len` values of type `T`, which were copied in `Self::new`.
+        unsafe { core::slice::from_raw_parts_mut(self.mmap.as_mut_ptr() as *mut T, self.len) }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_curves::bls12_377::Fr;
+    use snarkvm_utilities::{TestRng, Uniform};
+
+    #[test]
+    fn test_out_of_core_fft() {
+        let mut rng = TestRng::default();
+        for log_size in 4..=11 {
+            let domain = EvaluationDomain::<Fr>::new(1 << log_size).unwrap();
+            let coeffs = (0..domain.size()).map(|_| Fr::rand(&mut rng)).collect::<Vec<_>>();
+            let evals = domain.fft(&coeffs);
+
+            // Hold a single row or column, a few of them, and the entire vector in memory at a time.
+            for budget in [0, 4 * size_of::<Fr>() << (log_size / 2), usize::MAX] {
+                let mut values = coeffs.clone();
+                fft_in_place_with_budget(&domain, &mut values, false, budget).unwrap();
+                assert_eq!(values, evals, "log_size = {log_size}, budget = {budget}");
+
+                fft_in_place_with_budget(&domain, &mut values, true, budget).unwrap();
+                assert_eq!(values, coeffs, "log_size = {log_size}, budget = {budget}");
+            }
+        }
+    }
+
+    #[test]
+    fn test_out_of_core_fft_rejects_unsupported_domains() {
+        let domain = EvaluationDomain::<Fr>::new_mixed_radix(24).unwrap();
+        let mut values = vec![Fr::from(1u64); domain.size()];
+        assert!(fft_in_place_with_budget(&domain, &mut values, false, 0).is_err());
+        assert_eq!(values, vec![Fr::from(1u64); domain.size()]);
+
+        let domain = EvaluationDomain::<Fr>::new(16).unwrap();
+        let mut values = vec![Fr::from(1u64); 8];
+        assert!(fft_in_place_with_budget(&domain, &mut values, false, 0).is_err());
+    }
+
+    #[test]
+    fn test_thread_budget_overrides_process_budget() {
+        let budget = fft_memory_budget();
+        std::thread::spawn(|| {
+            set_fft_memory_budget_on_thread(Some(1024));
+            assert_eq!(fft_memory_budget(), Some(1024));
+        })
+        .join()
+        .unwrap();
+        // The budget of the other thread does not apply to this one.
+        assert_eq!(fft_memory_budget(), budget);
+    }
+}
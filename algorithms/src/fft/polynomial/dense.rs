@@ -14,10 +14,13 @@
 
 //! A polynomial represented in coefficient form.
 
-use super::PolyMultiplier;
+use super::{
+    multipoint::{SubproductTree, MIN_SUBPRODUCT_TREE_SIZE},
+    PolyMultiplier,
+};
 use crate::fft::{EvaluationDomain, Evaluations, Polynomial};
 use snarkvm_fields::{Field, PrimeField};
-use snarkvm_utilities::{cfg_iter_mut, serialize::*};
+use snarkvm_utilities::{cfg_chunks_mut, cfg_iter, cfg_iter_mut, serialize::*};
 
 use anyhow::Result;
 use num_traits::CheckedDiv;
@@ -32,8 +35,11 @@ use itertools::Itertools;
 #[cfg(not(feature = "serial"))]
 use rayon::prelude::*;
 
+/// The number of coefficients computed by each parallel task of a synthetic division.
+const CHUNK_SIZE: usize = 1 << 10;
+
 /// Stores a polynomial in coefficient form.
-#[derive(Clone, PartialEq, Eq, Hash, Default, CanonicalSerialize, CanonicalDeserialize)]
+#[derive(Clone, PartialEq, Eq, Hash, Default)]
 #[must_use]
 pub struct DensePolynomial<F: Field> {
     /// The coefficient of `x^i` is stored at location `i` in `self.coeffs`.
@@ -55,6 +61,41 @@ impl<F: Field> fmt::Debug for DensePolynomial<F> {
     }
 }
 
+impl<F: Field> CanonicalSerialize for DensePolynomial<F> {
+    fn serialize_with_mode<W: Write>(&self, writer: W, compress: Compress) -> Result<(), SerializationError> {
+        CanonicalSerialize::serialize_with_mode(&self.coeffs, writer, compress)
+    }
+
+    fn serialized_size(&self, mode: Compress) -> usize {
+        self.coeffs.serialized_size(mode)
+    }
+}
+
+impl<F: Field> Valid for DensePolynomial<F> {
+    fn check(&self) -> Result<(), SerializationError> {
+        // Fail if the trailing coefficient is zero, as the degree of the polynomial would be incorrect.
+        match self.coeffs.last().map(|c| c.is_zero()) {
+            Some(true) => Err(SerializationError::InvalidData),
+            Some(false) | None => Ok(()),
+        }
+    }
+}
+
+impl<F: Field> CanonicalDeserialize for DensePolynomial<F> {
+    fn deserialize_with_mode<R: Read>(
+        reader: R,
+        compress: Compress,
+        validate: Validate,
+    ) -> Result<Self, SerializationError> {
+        let coeffs = CanonicalDeserialize::deserialize_with_mode(reader, compress, validate)?;
+        let polynomial = Self { coeffs };
+        if let Validate::Yes = validate {
+            polynomial.check()?;
+        }
+        Ok(polynomial)
+    }
+}
+
 impl<F: Field> DensePolynomial<F> {
     /// Returns the zero polynomial.
     pub fn zero() -> Self {
@@ -162,9 +203,75 @@ impl<F: PrimeField> DensePolynomial<F> {
         &self,
         domain: EvaluationDomain<F>,
     ) -> Result<(DensePolynomial<F>, DensePolynomial<F>)> {
-        let self_poly = Polynomial::from(self);
-        let vanishing_poly = Polynomial::from(domain.vanishing_polynomial());
-        self_poly.divide_with_q_and_r(&vanishing_poly)
+        let size = domain.size();
+        if self.coeffs.len() <= size {
+            return Ok((DensePolynomial::zero(), self.clone()));
+        }
+
+        // As `X^n = 1` modulo `X^n - 1`, the `i`-th coefficient of the quotient is the sum of the coefficients
+        // of `self` at `i + n, i + 2n, ...`, which is computed in blocks of `n`, starting from the last one.
+        let mut quotient = self.coeffs[size..].to_vec();
+        let num_blocks = quotient.len().div_ceil(size);
+        for block in (0..num_blocks - 1).rev() {
+            let (lower, upper) = quotient.split_at_mut((block + 1) * size);
+            // Zip safety: `upper` may be shorter than the block.
+            cfg_iter_mut!(lower[block * size..]).zip(&upper[..size.min(upper.len())]).for_each(|(q, u)| *q += u);
+        }
+
+        // The `i`-th coefficient of the remainder is the sum of the coefficients of `self` at `i, i + n, ...`.
+        let mut remainder = self.coeffs[..size].to_vec();
+        // Zip safety: `quotient` may be shorter than `remainder`.
+        cfg_iter_mut!(remainder).zip(&quotient).for_each(|(r, q)| *r += q);
+        Ok((DensePolynomial::from_coefficients_vec(quotient), DensePolynomial::from_coefficients_vec(remainder)))
+    }
+
+    /// Divide `self` by `X - point` with synthetic division.
+    /// Returns the quotient and the remainder of the division, which is the evaluation of `self` at `point`.
+    pub fn divide_by_linear_polynomial(&self, point: F) -> (DensePolynomial<F>, F) {
+        if self.is_zero() {
+            return (DensePolynomial::zero(), F::zero());
+        }
+
+        // The sums `s_i = a_i + point * s_{i + 1}` of the coefficients `a_i` of `self` are the quotient for `i > 0`,
+        // and the remainder for `i = 0`. They are first computed in each chunk as if the later chunks were zero.
+        let mut sums = self.coeffs.clone();
+        cfg_chunks_mut!(sums, CHUNK_SIZE).for_each(|chunk| {
+            for i in (0..chunk.len() - 1).rev() {
+                let next = chunk[i + 1];
+                chunk[i] += next * point;
+            }
+        });
+
+        // The sum following each chunk is carried into it, scaled by `point^(j + 1)` for its `j`-th last coefficient.
+        let num_chunks = sums.len().div_ceil(CHUNK_SIZE);
+        let mut carries = vec![F::zero(); num_chunks];
+        for chunk in (0..num_chunks - 1).rev() {
+            let start = (chunk + 1) * CHUNK_SIZE;
+            let len = CHUNK_SIZE.min(sums.len() - start);
+            carries[chunk] = sums[start] + point.pow([len as u64]) * carries[chunk + 1];
+        }
+        cfg_chunks_mut!(sums, CHUNK_SIZE).zip(carries).for_each(|(chunk, mut carry)| {
+            for sum in chunk.iter_mut().rev() {
+                carry *= point;
+                *sum += carry;
+            }
+        });
+
+        let remainder = sums.remove(0);
+        (DensePolynomial::from_coefficients_vec(sums), remainder)
+    }
+
+    /// Evaluates `self` at each of the given `points`.
+    /// Many points are evaluated with the subproduct tree, in `O(M(n) log n)` for `n` points and a polynomial of
+    /// degree `O(n)`, where `M(n)` is the cost of a multiplication.
+    pub fn batch_evaluate(&self, points: &[F]) -> Vec<F> {
+        if self.is_zero() {
+            vec![F::zero(); points.len()]
+        } else if points.len() <= MIN_SUBPRODUCT_TREE_SIZE || self.degree() <= MIN_SUBPRODUCT_TREE_SIZE {
+            cfg_iter!(points).map(|point| self.evaluate(*point)).collect()
+        } else {
+            SubproductTree::new(points).evaluate(self)
+        }
     }
 
     /// Evaluate `self` over `domain`.
@@ -716,4 +823,53 @@ mod tests {
             }
         }
     }
+
+    #[test]
+    fn divide_by_vanishing_poly() {
+        let rng = &mut TestRng::default();
+        for size in 0..10 {
+            let domain = EvaluationDomain::new(1 << size).unwrap();
+            for degree in [0, 1, 10, 100, 1000, 3000] {
+                let p = DensePolynomial::<Fr>::rand(degree, rng);
+                let expected = Polynomial::from(&p).divide_with_q_and_r(&domain.vanishing_polynomial().into()).unwrap();
+                assert_eq!(p.divide_by_vanishing_poly(domain).unwrap(), expected);
+            }
+        }
+    }
+
+    #[test]
+    fn divide_by_linear_polynomial() {
+        let rng = &mut TestRng::default();
+        for degree in [0, 1, 10, 1023, 1024, 1025, 5000] {
+            let p = DensePolynomial::<Fr>::rand(degree, rng);
+            let point = Fr::rand(rng);
+            let (quotient, remainder) = p.divide_by_linear_polynomial(point);
+            let divisor = DensePolynomial::from_coefficients_vec(vec![-point, Fr::one()]);
+            assert_eq!(quotient, &p / &divisor);
+            assert_eq!(remainder, p.evaluate(point));
+        }
+        assert_eq!(
+            DensePolynomial::<Fr>::zero().divide_by_linear_polynomial(Fr::one()),
+            (DensePolynomial::zero(), Fr::zero())
+        );
+    }
+
+    #[test]
+    fn batch_evaluate() {
+        let rng = &mut TestRng::default();
+        for degree in [0, 10, 100, 1000] {
+            let p = DensePolynomial::<Fr>::rand(degree, rng);
+            for num_points in [0, 1, 64, 65, 300, 2000] {
+                let mut points = (0..num_points).map(|_| Fr::rand(rng)).collect::<Vec<_>>();
+                if num_points > 1 {
+                    // Include a repeated point.
+                    points[1] = points[0];
+                }
+                let expected = points.iter().map(|point| p.evaluate(*point)).collect::<Vec<_>>();
+                assert_eq!(p.batch_evaluate(&points), expected);
+                assert_eq!(Polynomial::from(&p).batch_evaluate(&points), expected);
+            }
+        }
+        assert_eq!(DensePolynomial::<Fr>::zero().batch_evaluate(&[Fr::one(); 100]), vec![Fr::zero(); 100]);
+    }
 }
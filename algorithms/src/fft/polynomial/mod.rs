@@ -16,7 +16,7 @@
 
 use crate::fft::{EvaluationDomain, Evaluations};
 use snarkvm_fields::{Field, PrimeField};
-use snarkvm_utilities::{cfg_iter_mut, serialize::*, SerializationError};
+use snarkvm_utilities::{cfg_iter, cfg_iter_mut, serialize::*, SerializationError};
 use Polynomial::*;
 
 use anyhow::{ensure, Result};
@@ -34,6 +34,8 @@ pub use sparse::SparsePolynomial;
 mod multiplier;
 pub use multiplier::*;
 
+mod multipoint;
+
 /// Represents either a sparse polynomial or a dense one.
 #[derive(Clone, Debug, PartialEq, Eq)]
 pub enum Polynomial<'a, F: Field> {
@@ -256,6 +258,14 @@ impl<'a, F: Field> Polynomial<'a, F> {
 }
 
 impl<F: PrimeField> Polynomial<'_, F> {
+    /// Evaluates `self` at each of the given `points`.
+    pub fn batch_evaluate(&self, points: &[F]) -> Vec<F> {
+        match self {
+            Sparse(p) => cfg_iter!(points).map(|point| p.evaluate(*point)).collect(),
+            Dense(p) => p.batch_evaluate(points),
+        }
+    }
+
     /// Construct `Evaluations` by evaluating a polynomial over the domain `domain`.
     pub fn evaluate_over_domain(poly: impl Into<Self>, domain: EvaluationDomain<F>) -> Evaluations<F> {
         let poly = poly.into();
@@ -264,14 +274,7 @@ impl<F: PrimeField> Polynomial<'_, F> {
 
     fn eval_over_domain_helper(self, domain: EvaluationDomain<F>) -> Evaluations<F> {
         match self {
-            Sparse(Cow::Borrowed(s)) => {
-                let evals = domain.elements().map(|elem| s.evaluate(elem)).collect();
-                Evaluations::from_vec_and_domain(evals, domain)
-            }
-            Sparse(Cow::Owned(s)) => {
-                let evals = domain.elements().map(|elem| s.evaluate(elem)).collect();
-                Evaluations::from_vec_and_domain(evals, domain)
-            }
+            Sparse(s) => s.evaluate_over_domain_by_ref(domain),
             Dense(Cow::Borrowed(d)) => {
                 if d.degree() >= domain.size() {
                     d.coeffs
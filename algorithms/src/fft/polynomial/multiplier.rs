@@ -93,15 +93,22 @@ impl<'a, F: PrimeField> PolyMultiplier<'a, F> {
                     }
                 }
 
-                if self.fft_precomputation.is_none() {
-                    self.fft_precomputation = Some(Cow::Owned(domain.precompute_fft()));
-                }
-                if self.ifft_precomputation.is_none() {
-                    self.ifft_precomputation =
-                        Some(Cow::Owned(self.fft_precomputation.as_ref().unwrap().to_ifft_precomputation()));
-                }
-                let fft_pc = &self.fft_precomputation.unwrap();
-                let ifft_pc = &self.ifft_precomputation.unwrap();
+                // Without a precomputation, the roots of unity are retrieved from the global cache.
+                let (cached_fft_pc, cached_ifft_pc);
+                let fft_pc = match &self.fft_precomputation {
+                    Some(pc) => pc.as_ref(),
+                    None => {
+                        cached_fft_pc = domain.cached_fft_precomputation();
+                        cached_fft_pc.as_ref()
+                    }
+                };
+                let ifft_pc = match &self.ifft_precomputation {
+                    Some(pc) => pc.as_ref(),
+                    None => {
+                        cached_ifft_pc = domain.cached_ifft_precomputation();
+                        cached_ifft_pc.as_ref()
+                    }
+                };
                 let mut pool = ExecutionPool::with_capacity(self.polynomials.len() + self.evaluations.len());
                 for (_, p) in self.polynomials {
                     pool.add_job(move || {
@@ -133,19 +140,27 @@ impl<'a, F: PrimeField> PolyMultiplier<'a, F> {
     }
 
     pub fn element_wise_arithmetic_4_over_domain<T: Borrow<str>>(
-        mut self,
+        self,
         domain: EvaluationDomain<F>,
         labels: [T; 4],
         f: impl Fn(F, F, F, F) -> F + Sync,
     ) -> Option<DensePolynomial<F>> {
-        if self.fft_precomputation.is_none() {
-            self.fft_precomputation = Some(Cow::Owned(domain.precompute_fft()));
-        }
-        if self.ifft_precomputation.is_none() {
-            self.ifft_precomputation =
-                Some(Cow::Owned(self.fft_precomputation.as_ref().unwrap().to_ifft_precomputation()));
-        }
-        let fft_pc = self.fft_precomputation.as_ref().unwrap();
+        // Without a precomputation, the roots of unity are retrieved from the global cache.
+        let (cached_fft_pc, cached_ifft_pc);
+        let fft_pc = match &self.fft_precomputation {
+            Some(pc) => pc.as_ref(),
+            None => {
+                cached_fft_pc = domain.cached_fft_precomputation();
+                cached_fft_pc.as_ref()
+            }
+        };
+        let ifft_pc = match &self.ifft_precomputation {
+            Some(pc) => pc.as_ref(),
+            None => {
+                cached_ifft_pc = domain.cached_ifft_precomputation();
+                cached_ifft_pc.as_ref()
+            }
+        };
         let mut pool = ExecutionPool::with_capacity(self.polynomials.len() + self.evaluations.len());
         for (l, p) in self.polynomials {
             pool.add_job(move || {
@@ -172,7 +187,7 @@ impl<'a, F: PrimeField> PolyMultiplier<'a, F> {
             .map(|(((a, b), c), d)| f(*a, *b, *c, *d))
             .collect::<Vec<_>>();
         drop(p);
-        domain.out_order_ifft_in_place_with_pc(&mut result, &self.ifft_precomputation.unwrap());
+        domain.out_order_ifft_in_place_with_pc(&mut result, ifft_pc);
         Some(DensePolynomial::from_coefficients_vec(result))
     }
 }
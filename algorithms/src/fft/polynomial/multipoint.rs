@@ -0,0 +1,183 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Multipoint evaluation with the subproduct tree of Moenck and Borodin.
+//!
+//! The points are split recursively in halves, and each node of the tree stores the product of `X - z`
+//! over the points `z` below it. A polynomial is evaluated at the points of a node by reducing it modulo
+//! the products of the two children, which are computed with fast multiplication and Newton iteration.
+
+use super::{DensePolynomial, Polynomial};
+use snarkvm_fields::PrimeField;
+
+/// The number of points below which a polynomial is evaluated at each point with Horner's rule,
+/// and the degree below which a polynomial is reduced with long division.
+pub(super) const MIN_SUBPRODUCT_TREE_SIZE: usize = 1 << 6;
+
+/// A node of the subproduct tree of a set of points.
+pub(super) struct SubproductTree<'a, F: PrimeField> {
+    /// The points below this node.
+    points: &'a [F],
+    /// The product of `X - z` over the `points`.
+    product: DensePolynomial<F>,
+    /// The subtrees of the two halves of the `points`, if there are enough of them.
+    children: Option<Box<(Self, Self)>>,
+}
+
+impl<'a, F: PrimeField> SubproductTree<'a, F> {
+    /// Constructs the subproduct tree of the non-empty set of `points`.
+    pub(super) fn new(points: &'a [F]) -> Self {
+        if points.len() <= MIN_SUBPRODUCT_TREE_SIZE {
+            let mut product = vec![F::one()];
+            for point in points {
+                // Multiply by `X - point`.
+                product.insert(0, F::zero());
+                for i in 0..product.len() - 1 {
+                    let next = product[i + 1];
+                    product[i] -= next * point;
+                }
+            }
+            return Self { points, product: DensePolynomial::from_coefficients_vec(product), children: None };
+        }
+
+        let (left, right) = points.split_at(points.len() / 2);
+        #[cfg(not(feature = "serial"))]
+        let (left, right) = rayon::join(|| Self::new(left), || Self::new(right));
+        #[cfg(feature = "serial")]
+        let (left, right) = (Self::new(left), Self::new(right));
+        let product = &left.product * &right.product;
+        Self { points, product, children: Some(Box::new((left, right))) }
+    }
+
+    /// Evaluates `poly` at each of the points of this node.
+    pub(super) fn evaluate(&self, poly: &DensePolynomial<F>) -> Vec<F> {
+        self.evaluate_reduced(&remainder(poly, &self.product))
+    }
+
+    /// Evaluates `poly`, of a degree lower than the number of points, at each of the points of this node.
+    fn evaluate_reduced(&self, poly: &DensePolynomial<F>) -> Vec<F> {
+        match &self.children {
+            None => self.points.iter().map(|point| horner(poly, *point)).collect(),
+            Some(children) => {
+                let (left, right) = children.as_ref();
+                #[cfg(not(feature = "serial"))]
+                let (mut left, right) = rayon::join(|| left.evaluate(poly), || right.evaluate(poly));
+                #[cfg(feature = "serial")]
+                let (mut left, right) = (left.evaluate(poly), right.evaluate(poly));
+                left.extend(right);
+                left
+            }
+        }
+    }
+}
+
+/// Evaluates `poly` at `point` with Horner's rule.
+fn horner<F: PrimeField>(poly: &DensePolynomial<F>, point: F) -> F {
+    poly.coeffs.iter().rev().fold(F::zero(), |result, coeff| result * point + coeff)
+}
+
+/// Returns the remainder of the division of `poly` by the monic polynomial `divisor`.
+fn remainder<F: PrimeField>(poly: &DensePolynomial<F>, divisor: &DensePolynomial<F>) -> DensePolynomial<F> {
+    if poly.coeffs.len() < divisor.coeffs.len() {
+        return poly.clone();
+    }
+    let divisor_degree = divisor.degree();
+    let quotient_len = poly.degree() - divisor_degree + 1;
+    if divisor_degree <= MIN_SUBPRODUCT_TREE_SIZE || quotient_len <= MIN_SUBPRODUCT_TREE_SIZE {
+        let (_, remainder) = Polynomial::from(poly).divide_with_q_and_r(&divisor.into()).unwrap();
+        return remainder;
+    }
+
+    // The reversed quotient is the product of the reversed `poly` and the inverse of the reversed `divisor`,
+    // modulo `X^quotient_len`.
+    let reversed_poly = poly.coeffs.iter().rev().take(quotient_len).copied().collect();
+    let reversed_divisor = divisor.coeffs.iter().rev().take(quotient_len).copied().collect();
+    let inverse = inverse_mod_x_power(reversed_divisor, quotient_len);
+    let mut quotient = truncate(&DensePolynomial::from_coefficients_vec(reversed_poly) * &inverse, quotient_len);
+    quotient.reverse();
+
+    // Only the coefficients of `poly - quotient * divisor` below the degree of `divisor` are non-zero.
+    let product = &DensePolynomial::from_coefficients_vec(quotient) * divisor;
+    let remainder = poly.coeffs.iter().zip(product.coeffs.iter()).take(divisor_degree).map(|(a, b)| *a - b).collect();
+    DensePolynomial::from_coefficients_vec(remainder)
+}
+
+/// Returns the inverse of `poly`, whose constant coefficient is one, modulo `X^precision`, with Newton iteration.
+fn inverse_mod_x_power<F: PrimeField>(poly: Vec<F>, precision: usize) -> DensePolynomial<F> {
+    debug_assert_eq!(poly.first(), Some(&F::one()));
+    let mut inverse = DensePolynomial::from_coefficients_vec(vec![F::one()]);
+    let mut current_precision = 1;
+    while current_precision < precision {
+        current_precision = (2 * current_precision).min(precision);
+        // Lift the inverse `h` modulo `X^k` to `h * (2 - poly * h)` modulo `X^(2k)`.
+        let truncated_poly = DensePolynomial::from_coefficients_slice(&poly[..current_precision.min(poly.len())]);
+        let mut error = truncate(&truncated_poly * &inverse, current_precision);
+        error.iter_mut().for_each(|coeff| *coeff = -*coeff);
+        error[0] += F::one() + F::one();
+        let error = DensePolynomial::from_coefficients_vec(error);
+        inverse = DensePolynomial::from_coefficients_vec(truncate(&inverse * &error, current_precision));
+    }
+    inverse
+}
+
+/// Returns the `len` lowest coefficients of `poly`, padded with zeros.
+fn truncate<F: PrimeField>(poly: DensePolynomial<F>, len: usize) -> Vec<F> {
+    let mut coeffs = poly.coeffs;
+    coeffs.resize(len, F::zero());
+    coeffs
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_curves::bls12_377::Fr;
+    use snarkvm_fields::{One, Zero};
+    use snarkvm_utilities::{TestRng, Uniform};
+
+    #[test]
+    fn test_remainder() {
+        let mut rng = TestRng::default();
+        for (degree, divisor_degree) in [(10, 20), (100, 20), (100, 70), (1000, 70), (1000, 700), (5000, 200)] {
+            let poly = DensePolynomial::<Fr>::rand(degree, &mut rng);
+            let mut divisor = DensePolynomial::<Fr>::rand(divisor_degree, &mut rng);
+            *divisor.coeffs.last_mut().unwrap() = Fr::one();
+
+            let (_, expected) = Polynomial::from(&poly).divide_with_q_and_r(&(&divisor).into()).unwrap();
+            assert_eq!(remainder(&poly, &divisor), expected);
+        }
+    }
+
+    #[test]
+    fn test_inverse_mod_x_power() {
+        let mut rng = TestRng::default();
+        for precision in [1, 2, 3, 100, 129] {
+            let mut poly = (0..70).map(|_| Fr::rand(&mut rng)).collect::<Vec<_>>();
+            poly[0] = Fr::one();
+            let inverse = inverse_mod_x_power(poly.clone(), precision);
+            let product = truncate(&DensePolynomial::from_coefficients_vec(poly) * &inverse, precision);
+            let mut expected = vec![Fr::zero(); precision];
+            expected[0] = Fr::one();
+            assert_eq!(product, expected);
+        }
+    }
+
+    #[test]
+    fn test_subproduct_tree() {
+        let mut rng = TestRng::default();
+        let points = (0..300).map(|_| Fr::rand(&mut rng)).collect::<Vec<_>>();
+        let tree = SubproductTree::new(&points);
+        assert_eq!(tree.product.degree(), points.len());
+        assert!(points.iter().all(|point| tree.product.evaluate(*point).is_zero()));
+    }
+}
@@ -14,12 +14,19 @@
 
 //! A sparse polynomial represented in coefficient form.
 
-use crate::fft::{EvaluationDomain, Evaluations, Polynomial};
+use crate::fft::{DensePolynomial, EvaluationDomain, Evaluations};
 use snarkvm_fields::{Field, PrimeField};
-use snarkvm_utilities::serialize::*;
+use snarkvm_utilities::{cfg_chunks_mut, cfg_iter_mut, serialize::*};
 
+use core::ops::{Add, Mul, Neg, Sub};
 use std::{collections::BTreeMap, fmt};
 
+#[cfg(not(feature = "serial"))]
+use rayon::prelude::*;
+
+/// The number of coefficients or evaluations computed by each parallel task.
+const CHUNK_SIZE: usize = 1 << 10;
+
 /// Stores a sparse polynomial in coefficient form.
 #[derive(Clone, PartialEq, Eq, Hash, Default, CanonicalSerialize, CanonicalDeserialize)]
 #[must_use]
@@ -111,18 +118,40 @@ impl<F: Field> SparsePolynomial<F> {
 }
 
 impl<F: PrimeField> SparsePolynomial<F> {
-    /// Evaluate `self` over `domain`.
+    /// Evaluate `self` over `domain`, in `O(k * n)` for `k` terms and a domain of size `n`.
     pub fn evaluate_over_domain_by_ref(&self, domain: EvaluationDomain<F>) -> Evaluations<F> {
-        let poly: Polynomial<'_, F> = self.into();
-        Polynomial::<F>::evaluate_over_domain(poly, domain)
-        // unimplemented!("current implementation does not produce evals in correct order")
+        // As `g^n = 1` for the generator `g` of the domain, the term `c * X^i` evaluates to `c * (g^(i mod n))^j` at `g^j`.
+        let terms = self
+            .coeffs
+            .iter()
+            .map(|(i, coeff)| (domain.group_gen.pow([(*i % domain.size()) as u64]), *coeff))
+            .collect::<Vec<_>>();
+
+        let mut evaluations = vec![F::zero(); domain.size()];
+        cfg_chunks_mut!(evaluations, CHUNK_SIZE).enumerate().for_each(|(chunk_index, chunk)| {
+            let offset = (chunk_index * CHUNK_SIZE) as u64;
+            for (root, coeff) in &terms {
+                let mut term = *coeff * root.pow([offset]);
+                for evaluation in chunk.iter_mut() {
+                    *evaluation += term;
+                    term *= root;
+                }
+            }
+        });
+        Evaluations::from_vec_and_domain(evaluations, domain)
     }
 
-    /// Evaluate `self` over `domain`.
+    /// Evaluate `self` over `domain`, in `O(k * n)` for `k` terms and a domain of size `n`.
     pub fn evaluate_over_domain(self, domain: EvaluationDomain<F>) -> Evaluations<F> {
-        let poly: Polynomial<'_, F> = self.into();
-        Polynomial::<F>::evaluate_over_domain(poly, domain)
-        // unimplemented!("current implementation does not produce evals in correct order")
+        self.evaluate_over_domain_by_ref(domain)
+    }
+
+    /// Evaluate the product of `self` and `other` over `domain`, without computing the product.
+    pub fn mul_dense_over_domain(&self, other: &DensePolynomial<F>, domain: EvaluationDomain<F>) -> Evaluations<F> {
+        let mut product = other.evaluate_over_domain_by_ref(domain);
+        let evaluations = self.evaluate_over_domain_by_ref(domain).evaluations;
+        cfg_iter_mut!(product.evaluations).zip(evaluations).for_each(|(a, b)| *a *= b);
+        product
     }
 }
 impl<F: PrimeField> core::ops::MulAssign<F> for SparsePolynomial<F> {
@@ -160,8 +189,8 @@ impl<'a, F: PrimeField> core::ops::AddAssign<&'a Self> for SparsePolynomial<F> {
 
 impl<'a, F: PrimeField> core::ops::AddAssign<(F, &'a Self)> for SparsePolynomial<F> {
     fn add_assign(&mut self, (f, other): (F, &'a Self)) {
-        let mut result = other.clone();
-        for (i, coeff) in self.coeffs.iter() {
+        let mut result = self.clone();
+        for (i, coeff) in other.coeffs.iter() {
             let cur_coeff = result.coeffs.entry(*i).or_insert_with(F::zero);
             *cur_coeff += f * coeff;
         }
@@ -169,11 +198,105 @@ impl<'a, F: PrimeField> core::ops::AddAssign<(F, &'a Self)> for SparsePolynomial
     }
 }
 
+impl<'a, 'b, F: PrimeField> Add<&'a SparsePolynomial<F>> for &'b SparsePolynomial<F> {
+    type Output = SparsePolynomial<F>;
+
+    fn add(self, other: &'a SparsePolynomial<F>) -> SparsePolynomial<F> {
+        let mut result = self.clone();
+        result += other;
+        result
+    }
+}
+
+impl<F: Field> Neg for SparsePolynomial<F> {
+    type Output = SparsePolynomial<F>;
+
+    fn neg(mut self) -> SparsePolynomial<F> {
+        self.coeffs.values_mut().for_each(|coeff| *coeff = -*coeff);
+        self
+    }
+}
+
+impl<'a, 'b, F: PrimeField> Sub<&'a SparsePolynomial<F>> for &'b SparsePolynomial<F> {
+    type Output = SparsePolynomial<F>;
+
+    fn sub(self, other: &'a SparsePolynomial<F>) -> SparsePolynomial<F> {
+        let mut result = self.clone();
+        result += (-F::one(), other);
+        result
+    }
+}
+
+impl<'a, 'b, F: Field> Mul<&'a SparsePolynomial<F>> for &'b SparsePolynomial<F> {
+    type Output = SparsePolynomial<F>;
+
+    fn mul(self, other: &'a SparsePolynomial<F>) -> SparsePolynomial<F> {
+        SparsePolynomial::mul(self, other)
+    }
+}
+
+impl<'a, 'b, F: Field> Add<&'a DensePolynomial<F>> for &'b SparsePolynomial<F> {
+    type Output = DensePolynomial<F>;
+
+    fn add(self, other: &'a DensePolynomial<F>) -> DensePolynomial<F> {
+        let mut result = other.clone();
+        result += self;
+        result
+    }
+}
+
+impl<'a, 'b, F: Field> Sub<&'a DensePolynomial<F>> for &'b SparsePolynomial<F> {
+    type Output = DensePolynomial<F>;
+
+    fn sub(self, other: &'a DensePolynomial<F>) -> DensePolynomial<F> {
+        -(other.clone() - self)
+    }
+}
+
+impl<'a, 'b, F: Field> Mul<&'a DensePolynomial<F>> for &'b SparsePolynomial<F> {
+    type Output = DensePolynomial<F>;
+
+    /// Multiplies `self` by `other` in `O(k * n)` for `k` terms and `n` coefficients.
+    fn mul(self, other: &'a DensePolynomial<F>) -> DensePolynomial<F> {
+        if self.is_zero() || other.is_zero() {
+            return DensePolynomial::zero();
+        }
+        // Each coefficient of the product is computed independently, from the terms of `self`
+        // whose shifted copy of `other` overlaps it.
+        let mut result = vec![F::zero(); self.degree() + other.degree() + 1];
+        cfg_chunks_mut!(result, CHUNK_SIZE).enumerate().for_each(|(chunk_index, chunk)| {
+            let start = chunk_index * CHUNK_SIZE;
+            for (i, coeff) in self.coeffs.iter().filter(|(_, coeff)| !coeff.is_zero()) {
+                let (lo, hi) = (start.max(*i), (start + chunk.len()).min(i + other.coeffs.len()));
+                for k in lo..hi {
+                    chunk[k - start] += *coeff * other.coeffs[k - i];
+                }
+            }
+        });
+        DensePolynomial::from_coefficients_vec(result)
+    }
+}
+
+impl<'a, 'b, F: Field> Mul<&'a SparsePolynomial<F>> for &'b DensePolynomial<F> {
+    type Output = DensePolynomial<F>;
+
+    fn mul(self, other: &'a SparsePolynomial<F>) -> DensePolynomial<F> {
+        other * self
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use crate::fft::{DensePolynomial, EvaluationDomain, SparsePolynomial};
     use snarkvm_curves::bls12_377::Fr;
     use snarkvm_fields::One;
+    use snarkvm_utilities::{TestRng, Uniform};
+
+    use rand::Rng;
+
+    fn rand_sparse_poly(num_terms: usize, max_degree: usize, rng: &mut TestRng) -> SparsePolynomial<Fr> {
+        SparsePolynomial::from_coefficients((0..num_terms).map(|_| (rng.gen_range(0..=max_degree), Fr::rand(rng))))
+    }
 
     #[test]
     fn evaluate_over_domain() {
@@ -191,4 +314,79 @@ mod tests {
             assert_eq!(evals2.interpolate(), dense_poly);
         }
     }
+
+    #[test]
+    fn evaluate_over_domain_random() {
+        let mut rng = TestRng::default();
+        for log_size in 0..12 {
+            for domain in [
+                EvaluationDomain::new(1 << log_size).unwrap(),
+                EvaluationDomain::new_mixed_radix(3 << log_size).unwrap(),
+            ] {
+                // Include terms of a degree above the size of the domain.
+                let sparse_poly = rand_sparse_poly(5, 3 * domain.size(), &mut rng);
+                let evals = sparse_poly.evaluate_over_domain_by_ref(domain).evaluations;
+                assert_eq!(evals, domain.elements().map(|x| sparse_poly.evaluate(x)).collect::<Vec<_>>());
+            }
+        }
+    }
+
+    #[test]
+    fn arithmetic_with_dense_polynomials() {
+        let mut rng = TestRng::default();
+        for degree in [0, 1, 10, 1000, 3000] {
+            let sparse_poly = rand_sparse_poly(4, degree, &mut rng);
+            let other_sparse_poly = rand_sparse_poly(3, degree, &mut rng);
+            let dense_poly = DensePolynomial::rand(degree, &mut rng);
+            let sparse_as_dense: DensePolynomial<Fr> = sparse_poly.clone().into();
+            let other_as_dense: DensePolynomial<Fr> = other_sparse_poly.clone().into();
+
+            assert_eq!(&sparse_poly + &dense_poly, &sparse_as_dense + &dense_poly);
+            assert_eq!(&sparse_poly - &dense_poly, &sparse_as_dense - &dense_poly);
+            assert_eq!(&sparse_poly * &dense_poly, &sparse_as_dense * &dense_poly);
+            assert_eq!(&dense_poly * &sparse_poly, &sparse_as_dense * &dense_poly);
+            assert_eq!(&sparse_poly * &DensePolynomial::zero(), DensePolynomial::zero());
+
+            let sum: DensePolynomial<Fr> = (&sparse_poly + &other_sparse_poly).into();
+            assert_eq!(sum, &sparse_as_dense + &other_as_dense);
+            let difference: DensePolynomial<Fr> = (&sparse_poly - &other_sparse_poly).into();
+            assert_eq!(difference, &sparse_as_dense - &other_as_dense);
+            let product: DensePolynomial<Fr> = (&sparse_poly * &other_sparse_poly).into();
+            assert_eq!(product, &sparse_as_dense * &other_as_dense);
+            assert!((&sparse_poly - &sparse_poly).is_zero());
+            assert_eq!(-sparse_poly.clone(), &SparsePolynomial::zero() - &sparse_poly);
+
+            let f = Fr::rand(&mut rng);
+            let mut scaled_sum = sparse_poly.clone();
+            scaled_sum += (f, &other_sparse_poly);
+            assert_eq!(DensePolynomial::from(scaled_sum), &sparse_as_dense + &(&other_as_dense * f));
+
+            let domain = EvaluationDomain::new(degree + 1).unwrap();
+            assert_eq!(
+                sparse_poly.mul_dense_over_domain(&dense_poly, domain),
+                (&sparse_poly * &dense_poly).evaluate_over_domain(domain)
+            );
+        }
+    }
+
+    #[test]
+    fn mul_by_vanishing_polynomial_quotient() {
+        let mut rng = TestRng::default();
+        let target_domain = EvaluationDomain::<Fr>::new(1 << 8).unwrap();
+        for log_size in 0..=8 {
+            let src_domain = EvaluationDomain::<Fr>::new(1 << log_size).unwrap();
+            let quotient = target_domain.vanishing_polynomial_quotient(&src_domain).unwrap();
+            assert_eq!(
+                &quotient * &DensePolynomial::from(src_domain.vanishing_polynomial()),
+                target_domain.vanishing_polynomial().into()
+            );
+
+            let poly = DensePolynomial::rand(100, &mut rng);
+            let (expected, remainder) =
+                poly.mul_by_vanishing_poly(target_domain).divide_by_vanishing_poly(src_domain).unwrap();
+            assert!(remainder.is_zero());
+            assert_eq!(&quotient * &poly, expected);
+        }
+        assert!(target_domain.vanishing_polynomial_quotient(&EvaluationDomain::new_mixed_radix(3).unwrap()).is_err());
+    }
 }
@@ -0,0 +1,193 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! A global cache of the roots of unity of the FFTs, keyed by the field and the domain size,
+//! and a domain that holds its roots of unity, so that they are computed once across proofs.
+
+use crate::fft::{
+    domain::{FFTPrecomputation, IFFTPrecomputation},
+    DomainCoeff,
+    EvaluationDomain,
+};
+use snarkvm_fields::FftField;
+use snarkvm_utilities::serialize::*;
+
+use core::any::{Any, TypeId};
+use parking_lot::RwLock;
+use std::{
+    collections::HashMap,
+    sync::{Arc, OnceLock},
+};
+
+/// The largest domain whose roots of unity are cached, which take 16 MiB per direction over a 256-bit field.
+const MAX_CACHED_DOMAIN_SIZE: usize = 1 << 20;
+
+/// The cached precomputations, keyed by their type and the size of their domain.
+type Cache = RwLock<HashMap<(TypeId, usize), Arc<dyn Any + Send + Sync>>>;
+
+/// Returns the global cache of precomputations.
+fn cache() -> &'static Cache {
+    static CACHE: OnceLock<Cache> = OnceLock::new();
+    CACHE.get_or_init(Default::default)
+}
+
+/// Returns the cached precomputation of type `P` for a domain of the given size,
+/// or computes it with `compute`, and caches it if the domain is small enough.
+fn get_or_compute<P: Any + Send + Sync>(domain_size: usize, compute: impl FnOnce() -> P) -> Arc<P> {
+    if domain_size > MAX_CACHED_DOMAIN_SIZE {
+        return Arc::new(compute());
+    }
+    let key = (TypeId::of::<P>(), domain_size);
+    if let Some(precomputation) = cache().read().get(&key) {
+        return precomputation.clone().downcast().expect("The cache is keyed by the type of the precomputation");
+    }
+    // The roots are computed without holding the lock, so another thread may have cached them in the meantime.
+    let precomputation: Arc<dyn Any + Send + Sync> = Arc::new(compute());
+    let precomputation = cache().write().entry(key).or_insert(precomputation).clone();
+    precomputation.downcast().expect("The cache is keyed by the type of the precomputation")
+}
+
+/// Clears the cache of the roots of unity of the FFTs.
+/// The precomputations that are still held, e.g. by a `PrecomputedDomain`, are not freed.
+pub fn clear_fft_precomputation_cache() {
+    cache().write().clear();
+}
+
+impl<F: FftField> EvaluationDomain<F> {
+    /// Returns the precomputed roots of unity for an FFT over this domain, from the global cache if possible.
+    pub fn cached_fft_precomputation(&self) -> Arc<FFTPrecomputation<F>> {
+        get_or_compute(self.size(), || self.precompute_fft())
+    }
+
+    /// Returns the precomputed roots of unity for an IFFT over this domain, from the global cache if possible.
+    pub fn cached_ifft_precomputation(&self) -> Arc<IFFTPrecomputation<F>> {
+        get_or_compute(self.size(), || self.precompute_ifft())
+    }
+}
+
+/// An evaluation domain along with its roots of unity, which may be held by a prover across proofs.
+/// Cloning it shares the roots of unity.
+#[derive(Clone, Debug)]
+pub struct PrecomputedDomain<F: FftField> {
+    domain: EvaluationDomain<F>,
+    fft_precomputation: Arc<FFTPrecomputation<F>>,
+    ifft_precomputation: Arc<IFFTPrecomputation<F>>,
+}
+
+/// Only the domain is serialized, and its roots of unity are recomputed, or retrieved from the cache, on deserialization.
+impl<F: FftField> CanonicalSerialize for PrecomputedDomain<F> {
+    fn serialize_with_mode<W: Write>(&self, writer: W, compress: Compress) -> Result<(), SerializationError> {
+        self.domain.serialize_with_mode(writer, compress)
+    }
+
+    fn serialized_size(&self, compress: Compress) -> usize {
+        self.domain.serialized_size(compress)
+    }
+}
+
+impl<F: FftField> Valid for PrecomputedDomain<F> {
+    fn check(&self) -> Result<(), SerializationError> {
+        self.domain.check()
+    }
+}
+
+impl<F: FftField> CanonicalDeserialize for PrecomputedDomain<F> {
+    fn deserialize_with_mode<R: Read>(
+        reader: R,
+        compress: Compress,
+        _validate: Validate,
+    ) -> Result<Self, SerializationError> {
+        // The domain is always validated, as the roots of unity are computed from it.
+        let domain = EvaluationDomain::deserialize_with_mode(reader, compress, Validate::Yes)?;
+        Ok(Self::new(domain))
+    }
+}
+
+impl<F: FftField> PrecomputedDomain<F> {
+    /// Precomputes the roots of unity of `domain`, or retrieves them from the global cache.
+    pub fn new(domain: EvaluationDomain<F>) -> Self {
+        Self {
+            domain,
+            fft_precomputation: domain.cached_fft_precomputation(),
+            ifft_precomputation: domain.cached_ifft_precomputation(),
+        }
+    }
+
+    /// Returns the domain.
+    pub fn domain(&self) -> &EvaluationDomain<F> {
+        &self.domain
+    }
+
+    /// Returns the roots of unity for an FFT over the domain or any of its subdomains.
+    pub fn fft_precomputation(&self) -> &FFTPrecomputation<F> {
+        &self.fft_precomputation
+    }
+
+    /// Returns the roots of unity for an IFFT over the domain or any of its subdomains.
+    pub fn ifft_precomputation(&self) -> &IFFTPrecomputation<F> {
+        &self.ifft_precomputation
+    }
+
+    /// Computes an FFT over the domain, modifying the vector in place.
+    pub fn fft_in_place<T: DomainCoeff<F>>(&self, coeffs: &mut Vec<T>) {
+        coeffs.resize(self.domain.size(), T::zero());
+        self.domain.in_order_fft_in_place_with_pc(coeffs, &self.fft_precomputation);
+    }
+
+    /// Computes an IFFT over the domain, modifying the vector in place.
+    pub fn ifft_in_place<T: DomainCoeff<F>>(&self, evals: &mut Vec<T>) {
+        evals.resize(self.domain.size(), T::zero());
+        self.domain.in_order_ifft_in_place_with_pc(evals, &self.ifft_precomputation);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_curves::bls12_377::Fr;
+    use snarkvm_utilities::{TestRng, Uniform};
+
+    #[test]
+    fn test_cached_precomputation() {
+        for domain in
+            [EvaluationDomain::<Fr>::new(1 << 10).unwrap(), EvaluationDomain::new_mixed_radix(3 << 5).unwrap()]
+        {
+            let fft_precomputation = domain.cached_fft_precomputation();
+            assert_eq!(*fft_precomputation, domain.precompute_fft());
+            assert!(Arc::ptr_eq(&fft_precomputation, &domain.cached_fft_precomputation()));
+            assert_eq!(*domain.cached_ifft_precomputation(), domain.precompute_ifft());
+        }
+
+        // The domains above the maximum size are not cached.
+        let domain = EvaluationDomain::<Fr>::new(MAX_CACHED_DOMAIN_SIZE * 2).unwrap();
+        let fft_precomputation = domain.cached_fft_precomputation();
+        assert!(!Arc::ptr_eq(&fft_precomputation, &domain.cached_fft_precomputation()));
+    }
+
+    #[test]
+    fn test_precomputed_domain() {
+        let mut rng = TestRng::default();
+        for log_size in 0..12 {
+            let domain = EvaluationDomain::<Fr>::new(1 << log_size).unwrap();
+            let precomputed_domain = PrecomputedDomain::new(domain);
+            let coeffs = (0..domain.size()).map(|_| Fr::rand(&mut rng)).collect::<Vec<_>>();
+
+            let mut evals = coeffs.clone();
+            precomputed_domain.fft_in_place(&mut evals);
+            assert_eq!(evals, domain.fft(&coeffs));
+            precomputed_domain.ifft_in_place(&mut evals);
+            assert_eq!(evals, coeffs);
+        }
+    }
+}
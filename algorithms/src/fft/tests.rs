@@ -12,11 +12,14 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
-use crate::fft::{domain::*, DensePolynomial};
+use crate::fft::{domain::*, DensePolynomial, Evaluations, PrecomputedDomain};
 use rand::Rng;
 use snarkvm_curves::bls12_377::{Fr, G1Projective};
 use snarkvm_fields::{FftField, Field, One, Zero};
-use snarkvm_utilities::rand::{TestRng, Uniform};
+use snarkvm_utilities::{
+    rand::{TestRng, Uniform},
+    serialize::*,
+};
 
 #[test]
 fn vanishing_polynomial_evaluation() {
@@ -147,6 +150,31 @@ fn test_fft_correctness() {
     }
 }
 
+#[test]
+fn test_fft_batch() {
+    let mut rng = TestRng::default();
+    for log_domain_size in [3, 5, 10] {
+        let domain = EvaluationDomain::<Fr>::new(1 << log_domain_size).unwrap();
+        let polys = (0..5)
+            .map(|i| DensePolynomial::<Fr>::rand((1 << log_domain_size) - i - 1, &mut rng).coeffs)
+            .collect::<Vec<_>>();
+
+        let mut evals = polys.clone();
+        domain.fft_in_place_batch(&mut evals);
+        for (poly, evals) in polys.iter().zip(&evals) {
+            assert_eq!(&domain.fft(poly), evals, "domain size = {}", domain.size());
+        }
+
+        domain.ifft_in_place_batch(&mut evals);
+        for (poly, coeffs) in polys.iter().zip(&evals) {
+            assert_eq!(
+                &DensePolynomial::from_coefficients_slice(coeffs),
+                &DensePolynomial::from_coefficients_slice(poly)
+            );
+        }
+    }
+}
+
 #[test]
 fn test_roots_of_unity() {
     // Tests that the roots of unity result is the same as domain.elements()
@@ -341,3 +369,51 @@ fn evaluate_over_domain() {
         }
     }
 }
+
+fn serialize<T: CanonicalSerialize>(value: &T) -> Vec<u8> {
+    let mut bytes = Vec::with_capacity(value.compressed_size());
+    value.serialize_compressed(&mut bytes).unwrap();
+    bytes
+}
+
+#[test]
+fn serialization_roundtrip() {
+    let rng = &mut TestRng::default();
+    for domain in [EvaluationDomain::<Fr>::new(1 << 10).unwrap(), EvaluationDomain::new_mixed_radix(3 << 8).unwrap()] {
+        let bytes = serialize(&domain);
+        assert_eq!(EvaluationDomain::<Fr>::deserialize_compressed(&*bytes).unwrap(), domain);
+
+        let precomputed_domain = PrecomputedDomain::new(domain);
+        let bytes = serialize(&precomputed_domain);
+        let candidate = PrecomputedDomain::<Fr>::deserialize_compressed(&*bytes).unwrap();
+        assert_eq!(candidate.domain(), &domain);
+        assert_eq!(candidate.fft_precomputation(), precomputed_domain.fft_precomputation());
+
+        let p = DensePolynomial::<Fr>::rand(domain.size() - 1, rng);
+        let bytes = serialize(&p);
+        assert_eq!(DensePolynomial::<Fr>::deserialize_compressed(&*bytes).unwrap(), p);
+
+        let evals = p.clone().evaluate_over_domain(domain);
+        let bytes = serialize(&evals);
+        let candidate = Evaluations::<Fr>::deserialize_compressed(&*bytes).unwrap();
+        assert_eq!(candidate, evals);
+        assert_eq!(candidate.interpolate(), p);
+    }
+}
+
+#[test]
+fn deserialization_rejects_invalid_data() {
+    // A domain whose generator does not match its size.
+    let mut domain = EvaluationDomain::<Fr>::new(1 << 10).unwrap();
+    domain.group_gen = domain.group_gen.square();
+    let bytes = serialize(&domain);
+    assert!(EvaluationDomain::<Fr>::deserialize_compressed(&*bytes).is_err());
+    assert!(PrecomputedDomain::<Fr>::deserialize_compressed(&*bytes).is_err());
+    assert!(EvaluationDomain::<Fr>::deserialize_compressed_unchecked(&*bytes).is_ok());
+
+    // A polynomial with a trailing zero coefficient.
+    let p = DensePolynomial { coeffs: vec![Fr::one(), Fr::zero()] };
+    let bytes = serialize(&p);
+    assert!(DensePolynomial::<Fr>::deserialize_compressed(&*bytes).is_err());
+    assert!(DensePolynomial::<Fr>::deserialize_compressed_unchecked(&*bytes).is_ok());
+}
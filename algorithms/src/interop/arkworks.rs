@@ -0,0 +1,228 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Conversions between snarkVM and arkworks types.
+//!
+//! snarkVM and arkworks share the same canonical encoding for BLS12-377 field elements, curve points,
+//! and KZG10 commitments, so values are converted by re-decoding their compressed encoding on the other side.
+
+use crate::r1cs::{ConstraintMatrices, SparseMatrix};
+use snarkvm_fields::PrimeField;
+use snarkvm_utilities::serialize::{CanonicalDeserialize, CanonicalSerialize, Compress, Validate};
+
+use anyhow::{anyhow, ensure, Result};
+
+/// Converts a snarkVM value into the arkworks value with the same canonical encoding,
+/// e.g. `bls12_377::Fr` into `ark_bls12_377::Fr`, or `KZGCommitment` into `ark_poly_commit::kzg10::Commitment`.
+pub fn to_arkworks<T: CanonicalSerialize, U: ark_serialize::CanonicalDeserialize>(value: &T) -> Result<U> {
+    let mut bytes = Vec::with_capacity(value.serialized_size(Compress::Yes));
+    value.serialize_with_mode(&mut bytes, Compress::Yes)?;
+
+    let mut reader = &bytes[..];
+    let output = U::deserialize_with_mode(&mut reader, ark_serialize::Compress::Yes, ark_serialize::Validate::Yes)
+        .map_err(|e| anyhow!("Failed to decode the arkworks value - {e}"))?;
+    ensure!(reader.is_empty(), "The snarkVM and arkworks encodings have different lengths");
+    Ok(output)
+}
+
+/// Converts an arkworks value into the snarkVM value with the same canonical encoding,
+/// e.g. `ark_bls12_377::G1Affine` into `bls12_377::G1Affine`.
+pub fn from_arkworks<U: ark_serialize::CanonicalSerialize, T: CanonicalDeserialize>(value: &U) -> Result<T> {
+    let mut bytes = Vec::with_capacity(value.serialized_size(ark_serialize::Compress::Yes));
+    value
+        .serialize_with_mode(&mut bytes, ark_serialize::Compress::Yes)
+        .map_err(|e| anyhow!("Failed to encode the arkworks value - {e}"))?;
+
+    let mut reader = &bytes[..];
+    let output = T::deserialize_with_mode(&mut reader, Compress::Yes, Validate::Yes)?;
+    ensure!(reader.is_empty(), "The snarkVM and arkworks encodings have different lengths");
+    Ok(output)
+}
+
+/// Converts the given R1CS matrices into arkworks' `ConstraintMatrices`.
+/// Both use the column order `[1, public variables..., private variables...]`.
+pub fn matrices_to_arkworks<F: PrimeField, G: ark_ff::PrimeField>(
+    matrices: &ConstraintMatrices<F>,
+) -> Result<ark_relations::r1cs::ConstraintMatrices<G>> {
+    ensure!(matrices.is_well_formed(), "The R1CS matrices are malformed");
+
+    let convert = |matrix: &SparseMatrix<F>| -> Result<ark_relations::r1cs::Matrix<G>> {
+        matrix
+            .iter()
+            .map(|row| row.iter().map(|(coeff, column)| Ok((to_arkworks(coeff)?, *column))).collect())
+            .collect()
+    };
+    let num_non_zero = |matrix: &SparseMatrix<F>| matrix.iter().map(Vec::len).sum();
+
+    Ok(ark_relations::r1cs::ConstraintMatrices {
+        num_instance_variables: matrices.num_public_variables,
+        num_witness_variables: matrices.num_private_variables,
+        num_constraints: matrices.num_constraints(),
+        a_num_non_zero: num_non_zero(&matrices.a),
+        b_num_non_zero: num_non_zero(&matrices.b),
+        c_num_non_zero: num_non_zero(&matrices.c),
+        a: convert(&matrices.a)?,
+        b: convert(&matrices.b)?,
+        c: convert(&matrices.c)?,
+    })
+}
+
+/// Converts arkworks' `ConstraintMatrices` into R1CS matrices.
+pub fn matrices_from_arkworks<F: PrimeField, G: ark_ff::PrimeField>(
+    matrices: &ark_relations::r1cs::ConstraintMatrices<G>,
+) -> Result<ConstraintMatrices<F>> {
+    let convert = |matrix: &ark_relations::r1cs::Matrix<G>| -> Result<SparseMatrix<F>> {
+        ensure!(matrix.len() == matrices.num_constraints, "Mismatching number of constraints in the arkworks matrices");
+        matrix
+            .iter()
+            .map(|row| {
+                let mut row = row
+                    .iter()
+                    .map(|(coeff, column)| Ok((from_arkworks(coeff)?, *column)))
+                    .collect::<Result<Vec<(F, usize)>>>()?;
+                row.sort_by_key(|(_, column)| *column);
+                Ok(row)
+            })
+            .collect()
+    };
+
+    let output = ConstraintMatrices {
+        num_public_variables: matrices.num_instance_variables,
+        num_private_variables: matrices.num_witness_variables,
+        a: convert(&matrices.a)?,
+        b: convert(&matrices.b)?,
+        c: convert(&matrices.c)?,
+    };
+    ensure!(output.is_well_formed(), "The arkworks matrices are malformed");
+    Ok(output)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        polycommit::kzg10::KZGCommitment,
+        r1cs::{ConstraintSynthesizer, ConstraintSystem, SynthesisError},
+    };
+    use snarkvm_curves::{
+        bls12_377::{Bls12_377, Fq, Fr, G1Affine, G1Projective, G2Affine, G2Projective},
+        AffineCurve,
+        ProjectiveCurve,
+    };
+    use snarkvm_fields::{Field, Zero};
+    use snarkvm_utilities::{TestRng, Uniform};
+
+    use ark_ec::AffineRepr;
+
+    const ITERATIONS: usize = 100;
+
+    #[test]
+    fn test_field_round_trip() {
+        let rng = &mut TestRng::default();
+        for _ in 0..ITERATIONS {
+            let (a, b) = (Fr::rand(rng), Fr::rand(rng));
+            let (ark_a, ark_b): (ark_bls12_377::Fr, ark_bls12_377::Fr) =
+                (to_arkworks(&a).unwrap(), to_arkworks(&b).unwrap());
+            // Ensure the conversion is a field homomorphism.
+            assert_eq!(a * b, from_arkworks::<_, Fr>(&(ark_a * ark_b)).unwrap());
+            assert_eq!(a + b, from_arkworks::<_, Fr>(&(ark_a + ark_b)).unwrap());
+
+            let c = Fq::rand(rng);
+            assert_eq!(c, from_arkworks::<_, Fq>(&to_arkworks::<_, ark_bls12_377::Fq>(&c).unwrap()).unwrap());
+        }
+        // Ensure mismatching types are rejected.
+        assert!(to_arkworks::<_, ark_bls12_377::Fq>(&Fr::rand(rng)).is_err());
+    }
+
+    #[test]
+    fn test_curve_round_trip() {
+        let rng = &mut TestRng::default();
+
+        let g1: ark_bls12_377::G1Affine = to_arkworks(&G1Affine::prime_subgroup_generator()).unwrap();
+        assert_eq!(g1, ark_bls12_377::G1Affine::generator());
+        let g2: ark_bls12_377::G2Affine = to_arkworks(&G2Affine::prime_subgroup_generator()).unwrap();
+        assert_eq!(g2, ark_bls12_377::G2Affine::generator());
+
+        for _ in 0..ITERATIONS {
+            let scalar = Fr::rand(rng);
+            let point = G1Projective::rand(rng).to_affine();
+            let ark_point: ark_bls12_377::G1Affine = to_arkworks(&point).unwrap();
+            let ark_scalar: ark_bls12_377::Fr = to_arkworks(&scalar).unwrap();
+            // Ensure the conversion commutes with scalar multiplication.
+            let ark_product: ark_bls12_377::G1Affine = (ark_point * ark_scalar).into();
+            assert_eq!((point * scalar).to_affine(), from_arkworks(&ark_product).unwrap());
+
+            let point = G2Projective::rand(rng).to_affine();
+            assert_eq!(point, from_arkworks(&to_arkworks::<_, ark_bls12_377::G2Affine>(&point).unwrap()).unwrap());
+        }
+
+        let zero: ark_bls12_377::G1Affine = to_arkworks(&G1Affine::zero()).unwrap();
+        assert!(zero.is_zero());
+        assert_eq!(G1Affine::zero(), from_arkworks(&zero).unwrap());
+    }
+
+    #[test]
+    fn test_commitment_round_trip() {
+        let rng = &mut TestRng::default();
+        let commitment = KZGCommitment::<Bls12_377>(G1Projective::rand(rng).to_affine());
+        let ark_commitment: ark_bls12_377::G1Affine = to_arkworks(&commitment).unwrap();
+        assert_eq!(commitment, from_arkworks(&ark_commitment).unwrap());
+    }
+
+    /// Enforces `x^3 + x + 5 = y`, with public `y` and private `x`.
+    struct CubicCircuit {
+        x: Fr,
+    }
+
+    impl ConstraintSynthesizer<Fr> for CubicCircuit {
+        fn generate_constraints<CS: ConstraintSystem<Fr>>(&self, cs: &mut CS) -> Result<(), SynthesisError> {
+            let x_squared_value = self.x.square();
+            let x_cubed_value = x_squared_value * self.x;
+            let five = Fr::from(5u64);
+
+            let y = cs.alloc_input(|| "y", || Ok(x_cubed_value + self.x + five))?;
+            let x = cs.alloc(|| "x", || Ok(self.x))?;
+            let x_squared = cs.alloc(|| "x^2", || Ok(x_squared_value))?;
+            let x_cubed = cs.alloc(|| "x^3", || Ok(x_cubed_value))?;
+            cs.enforce(|| "x * x", |lc| lc + x, |lc| lc + x, |lc| lc + x_squared);
+            cs.enforce(|| "x^2 * x", |lc| lc + x_squared, |lc| lc + x, |lc| lc + x_cubed);
+            cs.enforce(|| "y", |lc| lc + x_cubed + x + (five, CS::one()), |lc| lc + CS::one(), |lc| lc + y);
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn test_matrices_round_trip() {
+        let rng = &mut TestRng::default();
+        let (matrices, assignment) =
+            ConstraintMatrices::from_circuit_with_assignment(&CubicCircuit { x: Fr::rand(rng) }).unwrap();
+        assert!(matrices.is_satisfied(&assignment));
+
+        let ark_matrices = matrices_to_arkworks::<_, ark_bls12_377::Fr>(&matrices).unwrap();
+        assert_eq!(ark_matrices.num_constraints, 3);
+        assert_eq!(ark_matrices.num_instance_variables, 2);
+        assert_eq!(ark_matrices.num_witness_variables, 3);
+
+        // Ensure the converted assignment satisfies the arkworks matrices.
+        let ark_assignment = assignment.iter().map(to_arkworks).collect::<Result<Vec<ark_bls12_377::Fr>>>().unwrap();
+        let evaluate = |row: &[(ark_bls12_377::Fr, usize)]| {
+            row.iter().map(|(coeff, column)| *coeff * ark_assignment[*column]).sum::<ark_bls12_377::Fr>()
+        };
+        for ((a, b), c) in ark_matrices.a.iter().zip(&ark_matrices.b).zip(&ark_matrices.c) {
+            assert_eq!(evaluate(a) * evaluate(b), evaluate(c));
+        }
+
+        assert_eq!(matrices, matrices_from_arkworks(&ark_matrices).unwrap());
+    }
+}
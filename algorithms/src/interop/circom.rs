@@ -0,0 +1,519 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Loaders and writers for circom's binary `.r1cs` and `.wtns` files.
+//!
+//! The wires of a circom circuit are ordered as
+//! `[1, public outputs..., public inputs..., private inputs..., internal wires...]`,
+//! which matches the column order of `ConstraintMatrices`, so the constraints are imported as-is.
+//! The circuit must be compiled for the scalar field of the proving curve (e.g. `circom --prime bls12377`).
+
+use crate::r1cs::{
+    ConstraintMatrices,
+    ConstraintSynthesizer,
+    ConstraintSystem,
+    LinearCombination,
+    SparseMatrix,
+    SynthesisError,
+};
+use snarkvm_fields::PrimeField;
+use snarkvm_utilities::{BigInteger, FromBytes, ToBytes};
+
+use anyhow::{anyhow, bail, ensure, Result};
+
+/// The magic bytes of a `.r1cs` file.
+const R1CS_MAGIC: &[u8; 4] = b"r1cs";
+/// The magic bytes of a `.wtns` file.
+const WTNS_MAGIC: &[u8; 4] = b"wtns";
+
+/// The `.r1cs` section containing the header.
+const R1CS_HEADER_SECTION: u32 = 1;
+/// The `.r1cs` section containing the constraints.
+const R1CS_CONSTRAINTS_SECTION: u32 = 2;
+/// The `.r1cs` section mapping each wire to its label.
+const R1CS_WIRE_TO_LABEL_SECTION: u32 = 3;
+/// The `.wtns` section containing the header.
+const WTNS_HEADER_SECTION: u32 = 1;
+/// The `.wtns` section containing the witness values.
+const WTNS_VALUES_SECTION: u32 = 2;
+
+/// A circom constraint system, read from a `.r1cs` file.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct CircomR1CS<F: PrimeField> {
+    /// The number of public outputs.
+    pub num_public_outputs: usize,
+    /// The number of public inputs.
+    pub num_public_inputs: usize,
+    /// The number of private inputs.
+    pub num_private_inputs: usize,
+    /// The constraints, over all wires.
+    pub matrices: ConstraintMatrices<F>,
+}
+
+impl<F: PrimeField> CircomR1CS<F> {
+    /// Reads a circom constraint system from the contents of a `.r1cs` file.
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self> {
+        let sections = read_sections(bytes, R1CS_MAGIC)?;
+
+        // Read the header.
+        let mut header = find_section(&sections, R1CS_HEADER_SECTION)?;
+        read_prime::<F>(&mut header)?;
+        let num_wires = u32::read_le(&mut header)? as usize;
+        let num_public_outputs = u32::read_le(&mut header)? as usize;
+        let num_public_inputs = u32::read_le(&mut header)? as usize;
+        let num_private_inputs = u32::read_le(&mut header)? as usize;
+        let _num_labels = u64::read_le(&mut header)?;
+        let num_constraints = u32::read_le(&mut header)? as usize;
+        ensure!(header.is_empty(), "Invalid circom R1CS header");
+
+        let num_public_variables = 1 + num_public_outputs + num_public_inputs;
+        ensure!(num_public_variables + num_private_inputs <= num_wires, "Invalid number of circom wires");
+
+        // Read the constraints.
+        let mut constraints = find_section(&sections, R1CS_CONSTRAINTS_SECTION)?;
+        let (mut a, mut b, mut c) = (Vec::new(), Vec::new(), Vec::new());
+        for _ in 0..num_constraints {
+            a.push(read_linear_combination(&mut constraints)?);
+            b.push(read_linear_combination(&mut constraints)?);
+            c.push(read_linear_combination(&mut constraints)?);
+        }
+        ensure!(constraints.is_empty(), "Found trailing bytes after the circom constraints");
+
+        let matrices = ConstraintMatrices {
+            num_public_variables,
+            num_private_variables: num_wires - num_public_variables,
+            a,
+            b,
+            c,
+        };
+        ensure!(matrices.is_well_formed(), "The circom constraints reference an unknown wire");
+
+        Ok(Self { num_public_outputs, num_public_inputs, num_private_inputs, matrices })
+    }
+
+    /// Initializes a circom constraint system with the given constraints.
+    /// Every public variable besides the constant `1` is a public input, and every private variable is
+    /// an internal wire, as the matrices do not distinguish the private inputs from the intermediate values.
+    pub fn from_matrices(matrices: ConstraintMatrices<F>) -> Result<Self> {
+        ensure!(matrices.is_well_formed(), "The constraints reference an unknown variable");
+        Ok(Self {
+            num_public_outputs: 0,
+            num_public_inputs: matrices.num_public_variables - 1,
+            num_private_inputs: 0,
+            matrices,
+        })
+    }
+
+    /// Returns the number of wires, including the constant `1`.
+    pub fn num_wires(&self) -> usize {
+        self.matrices.num_variables()
+    }
+
+    /// Writes the constraint system in the format of a `.r1cs` file, with each wire labeled by its index.
+    pub fn to_bytes(&self) -> Result<Vec<u8>> {
+        let num_wires = u32::try_from(self.num_wires())?;
+
+        // Write the header.
+        let mut header = Vec::new();
+        write_prime::<F>(&mut header)?;
+        num_wires.write_le(&mut header)?;
+        u32::try_from(self.num_public_outputs)?.write_le(&mut header)?;
+        u32::try_from(self.num_public_inputs)?.write_le(&mut header)?;
+        u32::try_from(self.num_private_inputs)?.write_le(&mut header)?;
+        u64::from(num_wires).write_le(&mut header)?;
+        u32::try_from(self.matrices.num_constraints())?.write_le(&mut header)?;
+
+        // Write the constraints.
+        let mut constraints = Vec::new();
+        for ((a, b), c) in self.matrices.a.iter().zip(&self.matrices.b).zip(&self.matrices.c) {
+            write_linear_combination(&mut constraints, a)?;
+            write_linear_combination(&mut constraints, b)?;
+            write_linear_combination(&mut constraints, c)?;
+        }
+
+        // Write the labels.
+        let mut labels = Vec::new();
+        for wire in 0..u64::from(num_wires) {
+            wire.write_le(&mut labels)?;
+        }
+
+        write_sections(R1CS_MAGIC, 1, &[
+            (R1CS_HEADER_SECTION, header),
+            (R1CS_CONSTRAINTS_SECTION, constraints),
+            (R1CS_WIRE_TO_LABEL_SECTION, labels),
+        ])
+    }
+}
+
+/// Reads a circom witness from the contents of a `.wtns` file.
+/// The witness is the assignment of every wire, starting with the constant `1`.
+pub fn read_circom_witness<F: PrimeField>(bytes: &[u8]) -> Result<Vec<F>> {
+    let sections = read_sections(bytes, WTNS_MAGIC)?;
+
+    // Read the header.
+    let mut header = find_section(&sections, WTNS_HEADER_SECTION)?;
+    read_prime::<F>(&mut header)?;
+    let num_values = u32::read_le(&mut header)? as usize;
+    ensure!(header.is_empty(), "Invalid circom witness header");
+
+    // Read the values.
+    let mut values = find_section(&sections, WTNS_VALUES_SECTION)?;
+    let witness = (0..num_values).map(|_| read_field(&mut values)).collect::<Result<Vec<F>>>()?;
+    ensure!(values.is_empty(), "Found trailing bytes after the circom witness");
+    Ok(witness)
+}
+
+/// Writes a circom witness in the format of a `.wtns` file.
+/// The witness is the assignment of every wire, starting with the constant `1`.
+pub fn write_circom_witness<F: PrimeField>(witness: &[F]) -> Result<Vec<u8>> {
+    // Write the header.
+    let mut header = Vec::new();
+    write_prime::<F>(&mut header)?;
+    u32::try_from(witness.len())?.write_le(&mut header)?;
+
+    // Write the values.
+    let mut values = Vec::new();
+    for value in witness {
+        value.to_bigint().write_le(&mut values)?;
+    }
+
+    write_sections(WTNS_MAGIC, 2, &[(WTNS_HEADER_SECTION, header), (WTNS_VALUES_SECTION, values)])
+}
+
+/// A circom circuit, which can be indexed and proven with Varuna.
+#[derive(Clone, Debug)]
+pub struct CircomCircuit<F: PrimeField> {
+    /// The constraint system.
+    r1cs: CircomR1CS<F>,
+    /// The assignment of every wire, or `None` when the circuit is only used for indexing.
+    witness: Option<Vec<F>>,
+}
+
+impl<F: PrimeField> CircomCircuit<F> {
+    /// Initializes a new circom circuit with the given witness, ensuring it satisfies the constraints.
+    pub fn new(r1cs: CircomR1CS<F>, witness: Vec<F>) -> Result<Self> {
+        ensure!(
+            witness.len() == r1cs.num_wires(),
+            "Expected {} witness values, found {}",
+            r1cs.num_wires(),
+            witness.len()
+        );
+        ensure!(r1cs.matrices.is_satisfied(&witness), "The circom witness does not satisfy the constraints");
+        Ok(Self { r1cs, witness: Some(witness) })
+    }
+
+    /// Initializes a new circom circuit without a witness, to derive the proving and verifying keys.
+    pub fn without_witness(r1cs: CircomR1CS<F>) -> Self {
+        Self { r1cs, witness: None }
+    }
+
+    /// Returns the constraint system.
+    pub fn r1cs(&self) -> &CircomR1CS<F> {
+        &self.r1cs
+    }
+
+    /// Returns the public outputs and inputs (in that order), which are the verifier inputs of a proof.
+    pub fn public_inputs(&self) -> Option<&[F]> {
+        self.witness.as_ref().map(|witness| &witness[1..self.r1cs.matrices.num_public_variables])
+    }
+}
+
+impl<F: PrimeField> ConstraintSynthesizer<F> for CircomCircuit<F> {
+    fn generate_constraints<CS: ConstraintSystem<F>>(&self, cs: &mut CS) -> Result<(), SynthesisError> {
+        let matrices = &self.r1cs.matrices;
+        let value = |wire: usize| {
+            move || self.witness.as_ref().map(|witness| witness[wire]).ok_or(SynthesisError::AssignmentMissing)
+        };
+
+        // Allocate the wires, in order.
+        let mut variables = Vec::with_capacity(matrices.num_variables());
+        variables.push(CS::one());
+        for wire in 1..matrices.num_public_variables {
+            variables.push(cs.alloc_input(|| format!("wire_{wire}"), value(wire))?);
+        }
+        for wire in matrices.num_public_variables..matrices.num_variables() {
+            variables.push(cs.alloc(|| format!("wire_{wire}"), value(wire))?);
+        }
+
+        // Enforce the constraints.
+        let to_lc = |row: &[(F, usize)]| {
+            row.iter().fold(LinearCombination::zero(), |lc, (coeff, wire)| lc + (*coeff, variables[*wire]))
+        };
+        for (i, ((a, b), c)) in matrices.a.iter().zip(&matrices.b).zip(&matrices.c).enumerate() {
+            cs.enforce(|| format!("constraint_{i}"), |_| to_lc(a), |_| to_lc(b), |_| to_lc(c));
+        }
+        Ok(())
+    }
+}
+
+/// Returns the sections of a circom binary file, as `(type, contents)` pairs.
+fn read_sections<'a>(mut bytes: &'a [u8], magic: &[u8; 4]) -> Result<Vec<(u32, &'a [u8])>> {
+    ensure!(bytes.starts_with(magic), "Invalid circom file - expected the '{}' magic", String::from_utf8_lossy(magic));
+    bytes = &bytes[magic.len()..];
+
+    let _version = u32::read_le(&mut bytes)?;
+    let num_sections = u32::read_le(&mut bytes)?;
+
+    let mut sections = Vec::new();
+    for _ in 0..num_sections {
+        let section_type = u32::read_le(&mut bytes)?;
+        let size = usize::try_from(u64::read_le(&mut bytes)?)?;
+        ensure!(size <= bytes.len(), "Invalid circom file - section {section_type} is truncated");
+        let (section, remaining) = bytes.split_at(size);
+        sections.push((section_type, section));
+        bytes = remaining;
+    }
+    ensure!(bytes.is_empty(), "Found trailing bytes after the circom sections");
+    Ok(sections)
+}
+
+/// Returns a circom binary file with the given version and `(type, contents)` sections.
+fn write_sections(magic: &[u8; 4], version: u32, sections: &[(u32, Vec<u8>)]) -> Result<Vec<u8>> {
+    let mut bytes = magic.to_vec();
+    version.write_le(&mut bytes)?;
+    u32::try_from(sections.len())?.write_le(&mut bytes)?;
+    for (section_type, section) in sections {
+        section_type.write_le(&mut bytes)?;
+        u64::try_from(section.len())?.write_le(&mut bytes)?;
+        bytes.extend_from_slice(section);
+    }
+    Ok(bytes)
+}
+
+/// Returns the contents of the unique section of the given type.
+fn find_section<'a>(sections: &[(u32, &'a [u8])], section_type: u32) -> Result<&'a [u8]> {
+    let mut matches = sections.iter().filter(|(ty, _)| *ty == section_type);
+    match (matches.next(), matches.next()) {
+        (Some((_, section)), None) => Ok(section),
+        (None, _) => bail!("Missing circom section {section_type}"),
+        (Some(_), Some(_)) => bail!("Duplicate circom section {section_type}"),
+    }
+}
+
+/// Reads the field size and prime of a circom header, ensuring they match `F`.
+fn read_prime<F: PrimeField>(reader: &mut &[u8]) -> Result<()> {
+    let field_size = u32::read_le(&mut *reader)? as usize;
+    ensure!(field_size == F::BigInteger::NUM_LIMBS * 8, "Mismatching circom field size ({field_size} bytes)");
+    let prime = F::BigInteger::read_le(&mut *reader)?;
+    ensure!(prime == F::modulus(), "Mismatching circom prime - the circuit was compiled for a different field");
+    Ok(())
+}
+
+/// Writes the field size and prime of a circom header.
+fn write_prime<F: PrimeField>(writer: &mut Vec<u8>) -> Result<()> {
+    u32::try_from(F::BigInteger::NUM_LIMBS * 8)?.write_le(&mut *writer)?;
+    F::modulus().write_le(&mut *writer)?;
+    Ok(())
+}
+
+/// Reads a field element, which circom encodes in canonical (non-Montgomery) form.
+fn read_field<F: PrimeField>(reader: &mut &[u8]) -> Result<F> {
+    let bigint = F::BigInteger::read_le(&mut *reader)?;
+    F::from_bigint(bigint).ok_or_else(|| anyhow!("Invalid circom field element"))
+}
+
+/// Reads a sparse row, encoded as `num_terms || (wire || coefficient)*`.
+fn read_linear_combination<F: PrimeField>(reader: &mut &[u8]) -> Result<Vec<(F, usize)>> {
+    let num_terms = u32::read_le(&mut *reader)?;
+    let mut row: Vec<(F, usize)> = Vec::new();
+    for _ in 0..num_terms {
+        let wire = u32::read_le(&mut *reader)? as usize;
+        let coeff = read_field(reader)?;
+        match row.binary_search_by_key(&wire, |(_, w)| *w) {
+            Ok(position) => row[position].0 += coeff,
+            Err(position) => row.insert(position, (coeff, wire)),
+        }
+    }
+    row.retain(|(coeff, _)| !coeff.is_zero());
+    Ok(row)
+}
+
+/// Writes a sparse row, encoded as `num_terms || (wire || coefficient)*`.
+fn write_linear_combination<F: PrimeField>(writer: &mut Vec<u8>, row: &[(F, usize)]) -> Result<()> {
+    u32::try_from(row.len())?.write_le(&mut *writer)?;
+    for (coeff, wire) in row {
+        u32::try_from(*wire)?.write_le(&mut *writer)?;
+        coeff.to_bigint().write_le(&mut *writer)?;
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_curves::bls12_377::{Fq, Fr};
+    use snarkvm_fields::{Field, One, Zero};
+    use snarkvm_utilities::{TestRng, ToBytes, Uniform};
+
+    /// Encodes a circom binary file with the given sections.
+    fn encode_file(magic: &[u8; 4], version: u32, sections: &[(u32, Vec<u8>)]) -> Vec<u8> {
+        let mut bytes = magic.to_vec();
+        bytes.extend_from_slice(&version.to_le_bytes());
+        bytes.extend_from_slice(&(sections.len() as u32).to_le_bytes());
+        for (section_type, section) in sections {
+            bytes.extend_from_slice(&section_type.to_le_bytes());
+            bytes.extend_from_slice(&(section.len() as u64).to_le_bytes());
+            bytes.extend_from_slice(section);
+        }
+        bytes
+    }
+
+    /// Encodes the field size and prime of a circom header.
+    fn encode_prime() -> Vec<u8> {
+        let mut bytes = 32u32.to_le_bytes().to_vec();
+        bytes.extend(Fr::modulus().to_bytes_le().unwrap());
+        bytes
+    }
+
+    fn encode_row(bytes: &mut Vec<u8>, row: &[(usize, Fr)]) {
+        bytes.extend_from_slice(&(row.len() as u32).to_le_bytes());
+        for (wire, coeff) in row {
+            bytes.extend_from_slice(&(*wire as u32).to_le_bytes());
+            bytes.extend(coeff.to_bigint().to_bytes_le().unwrap());
+        }
+    }
+
+    /// Returns the `.r1cs` file of a circuit enforcing `x^3 + x + 5 = y`, as circom would compile it,
+    /// with the wires `[1, y, x, x^2, x^3]`.
+    fn sample_r1cs() -> Vec<u8> {
+        let mut header = encode_prime();
+        for value in [5u32, 1, 0, 1] {
+            header.extend_from_slice(&value.to_le_bytes());
+        }
+        header.extend_from_slice(&5u64.to_le_bytes());
+        header.extend_from_slice(&3u32.to_le_bytes());
+
+        let one = Fr::one();
+        let mut constraints = Vec::new();
+        // x * x = x^2
+        encode_row(&mut constraints, &[(2, one)]);
+        encode_row(&mut constraints, &[(2, one)]);
+        encode_row(&mut constraints, &[(3, one)]);
+        // x^2 * x = x^3
+        encode_row(&mut constraints, &[(3, one)]);
+        encode_row(&mut constraints, &[(2, one)]);
+        encode_row(&mut constraints, &[(4, one)]);
+        // 0 * 0 = y - x^3 - x - 5
+        encode_row(&mut constraints, &[]);
+        encode_row(&mut constraints, &[]);
+        encode_row(&mut constraints, &[(0, -Fr::from(5u64)), (1, one), (2, -one), (4, -one)]);
+
+        encode_file(R1CS_MAGIC, 1, &[(R1CS_HEADER_SECTION, header), (R1CS_CONSTRAINTS_SECTION, constraints)])
+    }
+
+    /// Returns the `.wtns` file for the given `x`.
+    fn sample_witness(x: Fr) -> Vec<u8> {
+        let mut header = encode_prime();
+        header.extend_from_slice(&5u32.to_le_bytes());
+
+        let mut values = Vec::new();
+        for value in [Fr::one(), x.square() * x + x + Fr::from(5u64), x, x.square(), x.square() * x] {
+            values.extend(value.to_bigint().to_bytes_le().unwrap());
+        }
+        encode_file(WTNS_MAGIC, 2, &[(WTNS_HEADER_SECTION, header), (WTNS_VALUES_SECTION, values)])
+    }
+
+    #[test]
+    fn test_read_circom_files() {
+        let rng = &mut TestRng::default();
+        let r1cs = CircomR1CS::<Fr>::from_bytes(&sample_r1cs()).unwrap();
+        assert_eq!(r1cs.num_wires(), 5);
+        assert_eq!(r1cs.num_public_outputs, 1);
+        assert_eq!(r1cs.num_private_inputs, 1);
+        assert_eq!(r1cs.matrices.num_constraints(), 3);
+        assert_eq!(r1cs.matrices.num_public_variables, 2);
+
+        let x = Fr::rand(rng);
+        let witness = read_circom_witness::<Fr>(&sample_witness(x)).unwrap();
+        assert_eq!(witness[2], x);
+
+        let circuit = CircomCircuit::new(r1cs.clone(), witness.clone()).unwrap();
+        assert_eq!(circuit.public_inputs().unwrap(), &witness[1..2]);
+        // Ensure the synthesized constraints are the imported ones.
+        let (matrices, assignment) = ConstraintMatrices::from_circuit_with_assignment(&circuit).unwrap();
+        assert_eq!(matrices, r1cs.matrices);
+        assert_eq!(assignment, witness);
+
+        // Ensure an invalid witness is rejected.
+        let mut invalid_witness = witness;
+        invalid_witness[1] += Fr::one();
+        assert!(CircomCircuit::new(r1cs, invalid_witness).is_err());
+    }
+
+    #[test]
+    fn test_write_circom_files() {
+        let rng = &mut TestRng::default();
+        let r1cs = CircomR1CS::<Fr>::from_bytes(&sample_r1cs()).unwrap();
+        assert_eq!(CircomR1CS::<Fr>::from_bytes(&r1cs.to_bytes().unwrap()).unwrap(), r1cs);
+
+        let witness = read_circom_witness::<Fr>(&sample_witness(Fr::rand(rng))).unwrap();
+        assert_eq!(write_circom_witness(&witness).unwrap(), sample_witness(witness[2]));
+        assert_eq!(read_circom_witness::<Fr>(&write_circom_witness(&witness).unwrap()).unwrap(), witness);
+
+        // The public variables of matrices are written as public inputs.
+        let exported = CircomR1CS::from_matrices(r1cs.matrices.clone()).unwrap();
+        let imported = CircomR1CS::<Fr>::from_bytes(&exported.to_bytes().unwrap()).unwrap();
+        assert_eq!(imported.num_public_outputs, 0);
+        assert_eq!(imported.num_public_inputs, 1);
+        assert_eq!(imported.num_private_inputs, 0);
+        assert_eq!(imported.matrices, r1cs.matrices);
+    }
+
+    #[test]
+    fn test_reject_malformed_circom_files() {
+        let bytes = sample_r1cs();
+        // Ensure a truncated file is rejected.
+        assert!(CircomR1CS::<Fr>::from_bytes(&bytes[..bytes.len() - 1]).is_err());
+        // Ensure a file for a different field is rejected.
+        assert!(CircomR1CS::<Fq>::from_bytes(&bytes).is_err());
+        // Ensure a witness is not read as a constraint system.
+        assert!(CircomR1CS::<Fr>::from_bytes(&sample_witness(Fr::zero())).is_err());
+    }
+
+    #[cfg(feature = "snark")]
+    #[test]
+    fn test_prove_circom_circuit() {
+        use crate::{
+            snark::varuna::{AHPForR1CS, VarunaHidingMode, VarunaSNARK},
+            traits::{AlgebraicSponge, SNARK},
+        };
+        use snarkvm_curves::bls12_377::Bls12_377;
+
+        type FS = crate::crypto_hash::PoseidonSponge<Fq, 2, 1>;
+        type VarunaInst = VarunaSNARK<Bls12_377, FS, VarunaHidingMode>;
+
+        let rng = &mut TestRng::default();
+        let r1cs = CircomR1CS::<Fr>::from_bytes(&sample_r1cs()).unwrap();
+        let witness = read_circom_witness::<Fr>(&sample_witness(Fr::rand(rng))).unwrap();
+        let circuit = CircomCircuit::new(r1cs.clone(), witness).unwrap();
+
+        let max_degree = AHPForR1CS::<Fr, VarunaHidingMode>::max_degree(100, 25, 300).unwrap();
+        let universal_srs = VarunaInst::universal_setup(max_degree).unwrap();
+        let universal_prover = &universal_srs.to_universal_prover().unwrap();
+        let universal_verifier = &universal_srs.to_universal_verifier().unwrap();
+        let fs_parameters = FS::sample_parameters();
+
+        let (index_pk, index_vk) =
+            VarunaInst::circuit_setup(&universal_srs, &CircomCircuit::without_witness(r1cs)).unwrap();
+        let proof = VarunaInst::prove(universal_prover, &fs_parameters, &index_pk, &circuit, rng).unwrap();
+
+        let public_inputs = circuit.public_inputs().unwrap().to_vec();
+        assert!(
+            VarunaInst::verify(universal_verifier, &fs_parameters, &index_vk, public_inputs.clone(), &proof).unwrap()
+        );
+
+        let invalid_inputs = vec![public_inputs[0] + Fr::one()];
+        assert!(!VarunaInst::verify(universal_verifier, &fs_parameters, &index_vk, invalid_inputs, &proof).unwrap());
+    }
+}
@@ -33,6 +33,8 @@ pub use snarkvm_utilities::{cfg_chunks, cfg_chunks_mut, cfg_into_iter, cfg_iter,
 pub mod crypto_hash;
 #[cfg(feature = "fft")]
 pub mod fft;
+#[cfg(feature = "r1cs")]
+pub mod interop;
 #[cfg(feature = "msm")]
 pub mod msm;
 #[cfg(feature = "polycommit")]
@@ -12,9 +12,11 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
-use snarkvm_curves::traits::ProjectiveCurve;
-use snarkvm_fields::{FieldParameters, PrimeField};
-use snarkvm_utilities::{cfg_into_iter, cfg_iter, cfg_iter_mut, ToBits};
+use snarkvm_curves::traits::{AffineCurve, ProjectiveCurve};
+use snarkvm_fields::{FieldParameters, PrimeField, Zero};
+use snarkvm_utilities::{cfg_chunks, cfg_into_iter, cfg_iter, cfg_iter_mut, error, FromBytes, ToBits, ToBytes};
+
+use std::io::{Read, Result as IoResult, Write};
 
 #[cfg(not(feature = "serial"))]
 use rayon::prelude::*;
@@ -94,4 +96,160 @@ impl FixedBase {
 
         cfg_iter!(v).map(|e| Self::windowed_mul::<T>(outerc, window, table, e)).collect::<Vec<_>>()
     }
+
+    /// Computes the MSM of the bases of `table` with `scalars`, where the `i`-th scalar multiplies the `i`-th base.
+    ///
+    /// As the table holds the multiples of each base for every window, all of the windows share a single
+    /// set of buckets, and no doublings are needed. This is faster than `VariableBase::msm` when many
+    /// MSMs are computed over the same bases, such as when committing to polynomials with the same SRS.
+    pub fn msm_with_table<G: AffineCurve>(
+        table: &FixedBaseMSMTable<G>,
+        scalars: &[<G::ScalarField as PrimeField>::BigInteger],
+    ) -> G::Projective {
+        assert!(
+            scalars.len() <= table.num_bases,
+            "The table has {} bases, but found {} scalars",
+            table.num_bases,
+            scalars.len()
+        );
+        let (window, num_windows) = (table.window, table.num_windows());
+
+        // Split the scalars among the threads, each of which accumulates its own buckets.
+        #[cfg(not(feature = "serial"))]
+        let num_threads = rayon::current_num_threads();
+        #[cfg(feature = "serial")]
+        let num_threads = 1;
+        let chunk_size = core::cmp::max(1, (scalars.len() + num_threads - 1) / num_threads);
+
+        cfg_chunks!(scalars, chunk_size)
+            .enumerate()
+            .map(|(chunk, scalars)| {
+                let mut buckets = vec![G::Projective::zero(); (1 << window) - 1];
+                let points = table.points[chunk * chunk_size * num_windows..].chunks(num_windows);
+                for (scalar, points) in scalars.iter().zip(points) {
+                    for (w, point) in points.iter().enumerate() {
+                        let digit = window_digit(scalar.as_ref(), w * window, window);
+                        if digit != 0 {
+                            buckets[digit - 1].add_assign_mixed(point);
+                        }
+                    }
+                }
+
+                // Compute the sum of `(i + 1) * buckets[i]` with running sums.
+                let mut running_sum = G::Projective::zero();
+                let mut sum = G::Projective::zero();
+                for bucket in buckets.into_iter().rev() {
+                    running_sum += bucket;
+                    sum += running_sum;
+                }
+                sum
+            })
+            .sum()
+    }
+}
+
+/// Returns the `window`-bit digit of the little-endian `limbs` starting at bit `start`.
+fn window_digit(limbs: &[u64], start: usize, window: usize) -> usize {
+    let (limb, shift) = (start / 64, start % 64);
+    let mut digit = limbs[limb] >> shift;
+    if shift + window > 64 && limb + 1 < limbs.len() {
+        digit |= limbs[limb + 1] << (64 - shift);
+    }
+    digit as usize & ((1 << window) - 1)
+}
+
+/// A table of the multiples `2^(window * w) * base` of each base, for every window `w` of the scalars,
+/// which is used by `FixedBase::msm_with_table` to compute MSMs over the same bases.
+///
+/// The table holds `ceil(num_bits / window)` points per base, so it may be computed once and stored to disk.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct FixedBaseMSMTable<G: AffineCurve> {
+    /// The window width, in bits.
+    window: usize,
+    /// The number of bases.
+    num_bases: usize,
+    /// The multiples of each base, where `points[i * num_windows + w]` is `2^(window * w) * bases[i]`.
+    points: Vec<G>,
+}
+
+impl<G: AffineCurve> FixedBaseMSMTable<G> {
+    /// The maximum window width, beyond which the buckets no longer fit in memory.
+    pub const MAX_WINDOW: usize = 24;
+
+    /// Precomputes the table for the given bases, with a window width of `window` bits.
+    pub fn new(bases: &[G], window: usize) -> Self {
+        assert!(
+            (1..=Self::MAX_WINDOW).contains(&window),
+            "The window width must be between 1 and {}",
+            Self::MAX_WINDOW
+        );
+        let num_windows = Self::num_windows_for(window);
+
+        let points = cfg_iter!(bases)
+            .flat_map(|base| {
+                let mut point = base.to_projective();
+                let mut multiples = Vec::with_capacity(num_windows);
+                for _ in 0..num_windows {
+                    multiples.push(point);
+                    for _ in 0..window {
+                        point.double_in_place();
+                    }
+                }
+                multiples
+            })
+            .collect::<Vec<_>>();
+        let points = G::Projective::batch_normalization_into_affine(points);
+
+        Self { window, num_bases: bases.len(), points }
+    }
+
+    /// Returns the window width, in bits.
+    pub const fn window(&self) -> usize {
+        self.window
+    }
+
+    /// Returns the number of bases.
+    pub const fn num_bases(&self) -> usize {
+        self.num_bases
+    }
+
+    /// Returns the number of windows of the scalars.
+    pub fn num_windows(&self) -> usize {
+        Self::num_windows_for(self.window)
+    }
+
+    fn num_windows_for(window: usize) -> usize {
+        let num_bits = <G::ScalarField as PrimeField>::size_in_bits();
+        (num_bits + window - 1) / window
+    }
+}
+
+impl<G: AffineCurve> FromBytes for FixedBaseMSMTable<G> {
+    fn read_le<R: Read>(mut reader: R) -> IoResult<Self> {
+        // Deserialize the window width.
+        let window = u32::read_le(&mut reader)? as usize;
+        if !(1..=Self::MAX_WINDOW).contains(&window) {
+            return Err(error("Invalid window width in the fixed-base MSM table"));
+        }
+
+        // Deserialize the points.
+        let num_bases = u64::read_le(&mut reader)? as usize;
+        let num_points = num_bases
+            .checked_mul(Self::num_windows_for(window))
+            .ok_or_else(|| error("Too many bases in the fixed-base MSM table"))?;
+        let points = (0..num_points).map(|_| G::read_le(&mut reader)).collect::<IoResult<Vec<_>>>()?;
+
+        Ok(Self { window, num_bases, points })
+    }
+}
+
+impl<G: AffineCurve> ToBytes for FixedBaseMSMTable<G> {
+    fn write_le<W: Write>(&self, mut writer: W) -> IoResult<()> {
+        // Serialize the window width.
+        (self.window as u32).write_le(&mut writer)?;
+
+        // Serialize the points.
+        (self.num_bases as u64).write_le(&mut writer)?;
+        self.points.write_le(&mut writer)
+    }
 }
@@ -64,3 +64,30 @@ fn variable_base_test_with_bls12_unequal_numbers() {
 
     assert_eq!(naive.to_affine(), fast.to_affine());
 }
+
+#[test]
+fn fixed_base_msm_with_table_test_with_bls12() {
+    use snarkvm_curves::bls12_377::G1Affine;
+    use snarkvm_utilities::{FromBytes, ToBytes};
+
+    const SAMPLES: usize = 1 << 10;
+
+    let mut rng = TestRng::default();
+
+    let v = (0..SAMPLES - 100).map(|_| Fr::rand(&mut rng).to_bigint()).collect::<Vec<_>>();
+    let g = (0..SAMPLES).map(|_| G1Projective::rand(&mut rng).to_affine()).collect::<Vec<_>>();
+    let naive = naive_variable_base_msm(g.as_slice(), v.as_slice());
+
+    for window in [1, 4, 11, 16] {
+        let table = FixedBaseMSMTable::new(&g, window);
+        let fast = FixedBase::msm_with_table(&table, v.as_slice());
+        assert_eq!(naive.to_affine(), fast.to_affine(), "Window: {window}");
+
+        // Ensure the table round-trips through its serialization.
+        let bytes = table.to_bytes_le().unwrap();
+        assert_eq!(FixedBaseMSMTable::<G1Affine>::from_bytes_le(&bytes).unwrap(), table);
+    }
+
+    let table = FixedBaseMSMTable::new(&g, 8);
+    assert!(FixedBase::msm_with_table(&table, &[]).is_zero());
+}
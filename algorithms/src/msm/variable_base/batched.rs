@@ -20,7 +20,10 @@ use snarkvm_utilities::{cfg_into_iter, BigInteger, BitIteratorBE};
 use rayon::prelude::*;
 
 #[cfg(target_arch = "x86_64")]
-use crate::{prefetch_slice, prefetch_slice_write};
+use crate::{msm::variable_base::prefetch::prefetch, prefetch_slice};
+
+/// The flag of a scalar index whose base is negated as it is added to its bucket.
+const NEGATED: u32 = 1 << 31;
 
 #[derive(Copy, Clone, Debug)]
 pub struct BucketPosition {
@@ -120,11 +123,21 @@ fn batch_add_in_place_same_slice<G: AffineCurve>(bases: &mut [G], index: &[(u32,
     }
 }
 
+/// Returns the base at `index`, negated if `index` has the `NEGATED` flag.
+#[inline]
+fn signed_base<G: AffineCurve>(bases: &[G], index: u32) -> G {
+    match index & NEGATED {
+        0 => bases[index as usize],
+        _ => -bases[(index & !NEGATED) as usize],
+    }
+}
+
 /// If `(j, k)` is the `i`-th entry in `index`, then this method performs one of
 /// two actions:
 /// * `addition_result[i] = bases[j] + bases[k]`
 /// * `addition_result[i] = bases[j];
 ///
+/// where a base is negated if its index has the `NEGATED` flag.
 /// It uses `scratch_space` to store intermediate values, and clears it after use.
 #[inline]
 fn batch_add_write<G: AffineCurve>(
@@ -144,13 +157,18 @@ fn batch_add_write<G: AffineCurve>(
     // We run two loops over the data separated by an inversion
     for (idx, idy) in index.iter() {
         #[cfg(target_arch = "x86_64")]
-        prefetch_slice_write!(G, bases, bases, prefetch_iter);
+        if let Some((idp_1, idp_2)) = prefetch_iter.next() {
+            prefetch::<G>(&bases[(idp_1 & !NEGATED) as usize]);
+            if *idp_2 != !0u32 {
+                prefetch::<G>(&bases[(idp_2 & !NEGATED) as usize]);
+            }
+        }
 
         if *idy == !0u32 {
-            addition_result.push(bases[*idx as usize]);
+            addition_result.push(signed_base(bases, *idx));
             scratch_space.push(None);
         } else {
-            let (mut a, mut b) = (bases[*idx as usize], bases[*idy as usize]);
+            let (mut a, mut b) = (signed_base(bases, *idx), signed_base(bases, *idy));
             G::batch_add_loop_1(&mut a, &mut b, &half, &mut inversion_tmp);
             addition_result.push(a);
             scratch_space.push(Some(b));
@@ -323,30 +341,58 @@ pub(super) fn batch_add<G: AffineCurve>(
     res
 }
 
+/// Returns the bits `[start, start + len)` of the little-endian `limbs`, for `len < 64`.
+#[inline]
+fn bits(limbs: &[u64], start: usize, len: usize) -> u64 {
+    let (limb, shift) = (start / 64, start % 64);
+    let mut bits = limbs.get(limb).map_or(0, |limb| limb >> shift);
+    if shift + len > 64 {
+        bits |= limbs.get(limb + 1).map_or(0, |limb| limb << (64 - shift));
+    }
+    bits & ((1 << len) - 1)
+}
+
+/// Returns the signed base-`2^c` digit of `limbs` in window `w`, which is in `[-2^(c - 1), 2^(c - 1)]`.
+///
+/// The bits of the window are read as a digit in `[0, 2^c)`, which is replaced by its difference with `2^c`
+/// if its top bit is set, and one is added if the top bit of the window below is set. As the carry is read from
+/// the bits of the scalar, each window is recoded on its own, without storing the digits of the other windows.
+#[inline]
+fn signed_digit(limbs: &[u64], w: usize, c: usize) -> i64 {
+    let digit = bits(limbs, w * c, c) as i64;
+    let carry = match w {
+        0 => 0,
+        _ => bits(limbs, w * c - 1, 1) as i64,
+    };
+    match digit >> (c - 1) {
+        0 => digit + carry,
+        _ => digit - (1 << c) + carry,
+    }
+}
+
 #[inline]
 fn batched_window<G: AffineCurve>(
     bases: &[G],
     scalars: &[<G::ScalarField as PrimeField>::BigInteger],
-    w_start: usize,
+    w: usize,
     c: usize,
-) -> (G::Projective, usize) {
-    // We don't need the "zero" bucket, so we only have 2^c - 1 buckets
-    let window_size = if (w_start % c) != 0 { w_start % c } else { c };
-    let num_buckets = (1 << window_size) - 1;
+) -> G::Projective {
+    // As the digits are signed, there is one bucket per magnitude in `[1, 2^(c - 1)]`.
+    let num_buckets = 1 << (c - 1);
 
     let mut bucket_positions: Vec<_> = scalars
         .iter()
         .enumerate()
-        .map(|(scalar_index, &scalar)| {
-            let mut scalar = scalar;
-
-            // We right-shift by w_start, thus getting rid of the lower bits.
-            scalar.divn(w_start as u32);
-
-            // We mod the remaining bits by the window size.
-            let scalar = (scalar.as_ref()[0] % (1 << c)) as i32;
-
-            BucketPosition { bucket_index: (scalar - 1) as u32, scalar_index: scalar_index as u32 }
+        .map(|(scalar_index, scalar)| {
+            let scalar_index = scalar_index as u32;
+            // A negative digit adds the negated base to the bucket of its magnitude,
+            // and a zero digit is in no bucket.
+            match signed_digit(scalar.as_ref(), w, c) {
+                digit if digit < 0 => {
+                    BucketPosition { bucket_index: (-digit - 1) as u32, scalar_index: scalar_index | NEGATED }
+                }
+                digit => BucketPosition { bucket_index: (digit - 1) as u32, scalar_index },
+            }
         })
         .collect();
 
@@ -358,8 +404,7 @@ fn batched_window<G: AffineCurve>(
         running_sum.add_assign_mixed(&b);
         res += &running_sum;
     }
-
-    (res, window_size)
+    res
 }
 
 pub fn msm<G: AffineCurve>(bases: &[G], scalars: &[<G::ScalarField as PrimeField>::BigInteger]) -> G::Projective {
@@ -391,24 +436,23 @@ pub fn msm<G: AffineCurve>(bases: &[G], scalars: &[<G::ScalarField as PrimeField
             false => crate::msm::ln_without_floats(scalars.len()) + 2,
         };
 
+        // The scalar indices leave their top bit for the `NEGATED` flag.
+        assert!(scalars.len() < NEGATED as usize, "Too many terms for a batched MSM");
+
+        // Each window is of size `c`, with one more window for the carry out of the highest bits.
         let num_bits = <G::ScalarField as PrimeField>::size_in_bits();
+        let num_windows = num_bits / c + 1;
 
-        // Each window is of size `c`.
-        // We divide up the bits 0..num_bits into windows of size `c`, and
-        // in parallel process each such window.
+        // We process each window in parallel.
         let window_sums: Vec<_> =
-            cfg_into_iter!(0..num_bits).step_by(c).map(|w_start| batched_window(bases, scalars, w_start, c)).collect();
-
-        // We store the sum for the lowest window.
-        let (lowest, window_sums) = window_sums.split_first().unwrap();
+            cfg_into_iter!(0..num_windows).map(|w| batched_window(bases, scalars, w, c)).collect();
 
         // We're traversing windows from high to low.
-        window_sums.iter().rev().fold(G::Projective::zero(), |mut total, (sum_i, window_size)| {
-            total += sum_i;
-            for _ in 0..*window_size {
+        window_sums.iter().rev().fold(G::Projective::zero(), |mut total, sum_i| {
+            for _ in 0..c {
                 total.double_in_place();
             }
-            total
-        }) + lowest.0
+            total + sum_i
+        })
     }
 }
@@ -0,0 +1,132 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! The host side of the bucket-method MSM kernels shared by the Metal and WebGPU backends.
+//!
+//! The host sorts the points of each window into buckets, the GPU sums each bucket and reduces
+//! them in segments, and the host then combines the segments and windows.
+
+use snarkvm_curves::{bls12_377::G1Projective, ProjectiveCurve};
+use snarkvm_fields::Zero;
+use snarkvm_utilities::{cfg_into_iter, BigInteger};
+
+#[cfg(not(feature = "serial"))]
+use rayon::prelude::*;
+
+/// The points of all windows sorted into buckets, as expected by the `msm_accumulate_buckets` kernels.
+pub(super) struct SortedBuckets {
+    /// The offsets of each bucket into the indices, where bucket `b` of window `w` is at `w * num_buckets + b`.
+    pub(super) offsets: Vec<u32>,
+    /// The indices of the points in each bucket. The indices of bucket zero are omitted.
+    pub(super) indices: Vec<u32>,
+    /// The number of points in the fullest bucket.
+    pub(super) max_occupancy: usize,
+}
+
+/// Returns the `c`-bit digit of `limbs` starting at bit `start`.
+fn digit(limbs: &[u64], start: usize, c: usize) -> usize {
+    let (limb, shift) = (start / 64, start % 64);
+    let mut digit = limbs[limb] >> shift;
+    if shift + c > 64 && limb + 1 < limbs.len() {
+        digit |= limbs[limb + 1] << (64 - shift);
+    }
+    digit as usize & ((1 << c) - 1)
+}
+
+/// Returns the indices of the scalars in each bucket of the window starting at bit `start`,
+/// as the offsets of each bucket into the indices. The indices of bucket zero are omitted.
+fn sort_window<B: BigInteger>(scalars: &[B], start: usize, c: usize) -> (Vec<u32>, Vec<u32>) {
+    let digits = scalars.iter().map(|scalar| digit(scalar.as_ref(), start, c)).collect::<Vec<_>>();
+
+    let mut offsets = vec![0u32; (1 << c) + 1];
+    for &digit in digits.iter().filter(|&&digit| digit != 0) {
+        offsets[digit + 1] += 1;
+    }
+    let mut sum = 0;
+    for offset in offsets.iter_mut() {
+        sum += *offset;
+        *offset = sum;
+    }
+
+    let mut cursors = offsets.clone();
+    let mut indices = vec![0u32; *offsets.last().unwrap() as usize];
+    for (index, &digit) in digits.iter().enumerate().filter(|(_, digit)| **digit != 0) {
+        indices[cursors[digit] as usize] = index as u32;
+        cursors[digit] += 1;
+    }
+    (offsets, indices)
+}
+
+/// Sorts the points of each of the `num_windows` windows of `c` bits into buckets, and concatenates the windows.
+/// The number of scalars, and the number of nonzero digits across all windows, must fit in a `u32`.
+pub(super) fn sort_windows<B: BigInteger>(scalars: &[B], num_windows: usize, c: usize) -> SortedBuckets {
+    let num_buckets = 1 << c;
+    let windows = cfg_into_iter!(0..num_windows).map(|w| sort_window(scalars, w * c, c)).collect::<Vec<_>>();
+    let max_occupancy = windows
+        .iter()
+        .flat_map(|(offsets, _)| offsets.windows(2).map(|bucket| (bucket[1] - bucket[0]) as usize))
+        .max()
+        .unwrap_or(0);
+
+    let total = windows.iter().map(|(_, indices)| indices.len()).sum::<usize>();
+    let mut offsets = Vec::with_capacity(num_windows * num_buckets + 1);
+    let mut indices = Vec::with_capacity(total);
+    for (window_offsets, window_indices) in &windows {
+        let base = indices.len() as u32;
+        offsets.extend(window_offsets[..num_buckets].iter().map(|offset| base + offset));
+        indices.extend_from_slice(window_indices);
+    }
+    offsets.push(indices.len() as u32);
+    SortedBuckets { offsets, indices, max_occupancy }
+}
+
+/// Combines the outputs of the `msm_reduce_segments` kernels into the MSM, for windows of `c` bits.
+///
+/// The segment starting at bucket `s * segment_size` holds the sum of its buckets, and their sum
+/// weighted by `b - s * segment_size + 1`, where `b` is the bucket index.
+pub(super) fn combine_segments(
+    sums: &[G1Projective],
+    weighted: &[G1Projective],
+    num_windows: usize,
+    segment_size: usize,
+    c: usize,
+) -> G1Projective {
+    let num_segments = sums.len() / num_windows;
+    let window_sums = cfg_into_iter!(0..num_windows)
+        .map(|w| {
+            let (mut sum, mut running, mut total) = (G1Projective::zero(), G1Projective::zero(), G1Projective::zero());
+            for s in (w * num_segments..(w + 1) * num_segments).rev() {
+                sum += weighted[s];
+                running += sums[s];
+                total += running;
+            }
+            // `total` is the sum of `(s + 1) * sums[s]`, from which the offsets of the segments are recovered.
+            let mut offsets = total - running;
+            for _ in 0..segment_size.trailing_zeros() {
+                offsets.double_in_place();
+            }
+            sum + offsets - running
+        })
+        .collect::<Vec<_>>();
+
+    // Combine the windows, from the highest to the lowest.
+    let mut result = G1Projective::zero();
+    for window_sum in window_sums.iter().rev() {
+        for _ in 0..c {
+            result.double_in_place();
+        }
+        result += window_sum;
+    }
+    result
+}
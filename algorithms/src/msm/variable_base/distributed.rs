@@ -0,0 +1,356 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! MSMs split across worker nodes. As an MSM is linear in its terms, the coordinator partitions
+//! the terms into contiguous chunks, sends each chunk to a worker, and sums the partial results.
+
+use super::batched;
+use snarkvm_curves::{
+    bls12_377::{Fr, G1Affine, G1Projective},
+    traits::AffineCurve,
+};
+use snarkvm_fields::{PrimeField, Zero};
+use snarkvm_utilities::cfg_into_iter;
+
+use anyhow::{anyhow, ensure, Result};
+use core::{any::Any, cell::RefCell};
+#[cfg(not(feature = "serial"))]
+use rayon::prelude::*;
+use std::sync::{Arc, RwLock};
+#[cfg(not(target_arch = "wasm32"))]
+use {
+    snarkvm_utilities::{FromBytes, ToBytes},
+    std::{
+        io::{BufReader, BufWriter, Write},
+        net::{SocketAddr, TcpListener, TcpStream},
+        time::Duration,
+    },
+};
+
+/// The scalars of a BLS12-377 G1 MSM.
+pub type MsmScalar = <Fr as PrimeField>::BigInteger;
+
+/// The minimum number of terms sent to each worker, below which the transfers outweigh the speedup.
+const DISTRIBUTED_MSM_MIN_CHUNK_SIZE: usize = 1 << 16;
+
+/// The maximum number of terms that a worker accepts in a single request.
+#[cfg(not(target_arch = "wasm32"))]
+const TCP_WORKER_MAX_TERMS: usize = 1 << 26;
+
+/// The distributed MSM installed with `install_distributed_msm`, if any.
+static DISTRIBUTED_MSM: RwLock<Option<Arc<DistributedMsm>>> = RwLock::new(None);
+
+thread_local! {
+    /// The distributed MSM of the current thread, overriding the installed one if set.
+    static DISTRIBUTED_MSM_ON_THREAD: RefCell<Option<Arc<DistributedMsm>>> = RefCell::new(None);
+}
+
+/// The channel between the coordinator and its workers, e.g. over RPC.
+///
+/// Each call is a self-contained request, so a transport may retry it or send it to another node.
+pub trait MsmTransport: Send + Sync {
+    /// Returns the number of workers reachable through this transport.
+    fn num_workers(&self) -> usize;
+
+    /// Computes the MSM of `bases` and `scalars` on the given worker.
+    fn msm(&self, worker: usize, bases: &[G1Affine], scalars: &[MsmScalar]) -> Result<G1Projective>;
+}
+
+/// A transport to workers in the current process, each computing its MSMs on the CPU.
+#[derive(Clone, Debug)]
+pub struct LocalTransport {
+    num_workers: usize,
+}
+
+impl LocalTransport {
+    /// Initializes a transport to the given number of in-process workers.
+    pub fn new(num_workers: usize) -> Self {
+        Self { num_workers }
+    }
+}
+
+impl MsmTransport for LocalTransport {
+    fn num_workers(&self) -> usize {
+        self.num_workers
+    }
+
+    fn msm(&self, worker: usize, bases: &[G1Affine], scalars: &[MsmScalar]) -> Result<G1Projective> {
+        ensure!(worker < self.num_workers, "Worker {worker} does not exist");
+        ensure!(bases.len() == scalars.len(), "The number of bases and scalars must match");
+        Ok(batched::msm(bases, scalars))
+    }
+}
+
+/// A transport to workers in other processes, possibly on other machines, each serving MSMs over TCP
+/// with `serve_msm_worker`.
+///
+/// Each MSM opens a new connection to its worker, so that a worker that restarts is reached again by the next MSM.
+/// The workers are trusted, as their partial results are summed without being checked.
+#[cfg(not(target_arch = "wasm32"))]
+#[derive(Clone, Debug)]
+pub struct TcpTransport {
+    workers: Vec<SocketAddr>,
+    timeout: Option<Duration>,
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+impl TcpTransport {
+    /// Initializes a transport to the workers listening on the given addresses.
+    pub fn new(workers: Vec<SocketAddr>) -> Self {
+        Self { workers, timeout: None }
+    }
+
+    /// Sets the timeout of each connection, read and write, after which the chunk is computed by the coordinator.
+    pub fn timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = Some(timeout);
+        self
+    }
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+impl MsmTransport for TcpTransport {
+    fn num_workers(&self) -> usize {
+        self.workers.len()
+    }
+
+    fn msm(&self, worker: usize, bases: &[G1Affine], scalars: &[MsmScalar]) -> Result<G1Projective> {
+        let address = self.workers.get(worker).ok_or_else(|| anyhow!("Worker {worker} does not exist"))?;
+        ensure!(bases.len() == scalars.len(), "The number of bases and scalars must match");
+        ensure!(scalars.len() <= TCP_WORKER_MAX_TERMS, "Too many terms for a single request");
+
+        let stream = match self.timeout {
+            Some(timeout) => TcpStream::connect_timeout(address, timeout)?,
+            None => TcpStream::connect(address)?,
+        };
+        stream.set_read_timeout(self.timeout)?;
+        stream.set_write_timeout(self.timeout)?;
+
+        // Send the number of terms, followed by the bases and the scalars.
+        let mut writer = BufWriter::new(&stream);
+        (scalars.len() as u64).write_le(&mut writer)?;
+        bases.iter().try_for_each(|base| base.write_le(&mut writer))?;
+        scalars.iter().try_for_each(|scalar| scalar.write_le(&mut writer))?;
+        writer.flush()?;
+        drop(writer);
+
+        // Receive the partial result.
+        Ok(G1Projective::read_le(BufReader::new(&stream))?)
+    }
+}
+
+/// Serves the MSMs requested by the `TcpTransport` of a coordinator on `listener`, until accepting a connection fails.
+/// The requests are served one at a time, as each MSM already uses all the threads of the worker.
+#[cfg(not(target_arch = "wasm32"))]
+pub fn serve_msm_worker(listener: TcpListener) -> Result<()> {
+    for stream in listener.incoming() {
+        // A failed request closes its connection, so the coordinator computes the chunk instead.
+        if let Err(error) = serve_msm_request(&stream?) {
+            eprintln!("Warning: failed to serve an MSM ({error})");
+        }
+    }
+    Ok(())
+}
+
+/// Reads an MSM request from `stream`, and writes its result back.
+#[cfg(not(target_arch = "wasm32"))]
+fn serve_msm_request(stream: &TcpStream) -> Result<()> {
+    let mut reader = BufReader::new(stream);
+    let num_terms = u64::read_le(&mut reader)? as usize;
+    ensure!(num_terms <= TCP_WORKER_MAX_TERMS, "Too many terms ({num_terms}) for a single request");
+    let bases = (0..num_terms).map(|_| G1Affine::read_le(&mut reader)).collect::<Result<Vec<_>, _>>()?;
+    let scalars = (0..num_terms).map(|_| MsmScalar::read_le(&mut reader)).collect::<Result<Vec<_>, _>>()?;
+
+    let mut writer = BufWriter::new(stream);
+    batched::msm(&bases, &scalars).write_le(&mut writer)?;
+    Ok(writer.flush()?)
+}
+
+/// The coordinator of MSMs split across the workers of a transport.
+#[derive(Clone)]
+pub struct DistributedMsm {
+    transport: Arc<dyn MsmTransport>,
+    min_chunk_size: usize,
+}
+
+impl DistributedMsm {
+    /// Initializes a coordinator for the workers of `transport`.
+    pub fn new(transport: Arc<dyn MsmTransport>) -> Self {
+        Self { transport, min_chunk_size: DISTRIBUTED_MSM_MIN_CHUNK_SIZE }
+    }
+
+    /// Sets the minimum number of terms sent to each worker.
+    pub fn min_chunk_size(mut self, min_chunk_size: usize) -> Self {
+        self.min_chunk_size = min_chunk_size.max(1);
+        self
+    }
+
+    /// Returns the number of workers that an MSM of `num_terms` terms is split across.
+    pub fn num_chunks(&self, num_terms: usize) -> usize {
+        self.transport.num_workers().min(num_terms / self.min_chunk_size)
+    }
+
+    /// Computes the MSM of `bases` and `scalars`, split across the workers.
+    /// The chunk of a worker that fails is computed by the coordinator instead.
+    pub fn msm(&self, bases: &[G1Affine], scalars: &[MsmScalar]) -> G1Projective {
+        let len = bases.len().min(scalars.len());
+        let (bases, scalars) = (&bases[..len], &scalars[..len]);
+        let num_chunks = self.num_chunks(scalars.len());
+        if num_chunks <= 1 {
+            return batched::msm(bases, scalars);
+        }
+        let chunk_size = (scalars.len() + num_chunks - 1) / num_chunks;
+        let chunks = bases.chunks(chunk_size).zip(scalars.chunks(chunk_size)).enumerate().collect::<Vec<_>>();
+        cfg_into_iter!(chunks)
+            .map(|(worker, (bases, scalars))| {
+                self.transport.msm(worker, bases, scalars).unwrap_or_else(|error| {
+                    eprintln!("Warning: the MSM on worker {worker} failed ({error}), retrying on the coordinator");
+                    batched::msm(bases, scalars)
+                })
+            })
+            .sum()
+    }
+
+    /// Computes the MSM if `G` is BLS12-377 G1, with the terms copied out of the generic representation.
+    pub(super) fn msm_generic<G: AffineCurve>(
+        &self,
+        bases: &[G],
+        scalars: &[<G::ScalarField as PrimeField>::BigInteger],
+    ) -> Result<G::Projective> {
+        let bases = bases
+            .iter()
+            .take(scalars.len())
+            .map(|base| (base as &dyn Any).downcast_ref::<G1Affine>().copied())
+            .collect::<Option<Vec<_>>>()
+            .ok_or_else(|| anyhow!("The distributed MSM only supports BLS12-377 G1"))?;
+        let scalars = scalars
+            .iter()
+            .map(|scalar| (scalar as &dyn Any).downcast_ref::<MsmScalar>().copied())
+            .collect::<Option<Vec<_>>>()
+            .ok_or_else(|| anyhow!("The distributed MSM only supports BLS12-377 G1"))?;
+        let result = if scalars.is_empty() { G1Projective::zero() } else { self.msm(&bases, &scalars) };
+        (&mut Some(result) as &mut dyn Any)
+            .downcast_mut::<Option<G::Projective>>()
+            .and_then(Option::take)
+            .ok_or_else(|| anyhow!("The distributed MSM only supports BLS12-377 G1"))
+    }
+}
+
+/// Installs `msm` as the coordinator of the large BLS12-377 G1 MSMs computed by `VariableBase::msm`,
+/// replacing any previously installed coordinator. This applies to all threads of the process.
+pub fn install_distributed_msm(msm: DistributedMsm) {
+    *DISTRIBUTED_MSM.write().unwrap_or_else(|e| e.into_inner()) = Some(Arc::new(msm));
+}
+
+/// Removes the installed coordinator, so that MSMs are computed locally again.
+pub fn uninstall_distributed_msm() {
+    *DISTRIBUTED_MSM.write().unwrap_or_else(|e| e.into_inner()) = None;
+}
+
+/// Returns the installed coordinator, if any.
+pub fn installed_distributed_msm() -> Option<Arc<DistributedMsm>> {
+    DISTRIBUTED_MSM.read().unwrap_or_else(|e| e.into_inner()).clone()
+}
+
+/// Sets the coordinator of the MSMs computed on the current thread, overriding the installed one,
+/// e.g. from the start handler of a dedicated thread pool. `None` removes the override.
+pub fn set_distributed_msm_on_thread(msm: Option<Arc<DistributedMsm>>) {
+    DISTRIBUTED_MSM_ON_THREAD.with(|thread_msm| *thread_msm.borrow_mut() = msm);
+}
+
+/// Returns the coordinator of the MSMs computed on the current thread, if any.
+pub fn current_distributed_msm() -> Option<Arc<DistributedMsm>> {
+    DISTRIBUTED_MSM_ON_THREAD.with(|thread_msm| thread_msm.borrow().clone()).or_else(installed_distributed_msm)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_curves::ProjectiveCurve;
+    use snarkvm_utilities::rand::{TestRng, Uniform};
+
+    /// A transport whose first worker always fails.
+    struct FaultyTransport(LocalTransport);
+
+    impl MsmTransport for FaultyTransport {
+        fn num_workers(&self) -> usize {
+            self.0.num_workers()
+        }
+
+        fn msm(&self, worker: usize, bases: &[G1Affine], scalars: &[MsmScalar]) -> Result<G1Projective> {
+            ensure!(worker != 0, "Worker 0 is unreachable");
+            self.0.msm(worker, bases, scalars)
+        }
+    }
+
+    #[test]
+    fn test_distributed_msm() {
+        let mut rng = TestRng::default();
+        let num_terms = 1000;
+        let bases = (0..num_terms).map(|_| G1Projective::rand(&mut rng).to_affine()).collect::<Vec<_>>();
+        let scalars = (0..num_terms).map(|_| Fr::rand(&mut rng).to_bigint()).collect::<Vec<_>>();
+        let expected = batched::msm(&bases, &scalars);
+
+        let msm = DistributedMsm::new(Arc::new(LocalTransport::new(3))).min_chunk_size(100);
+        assert_eq!(msm.num_chunks(num_terms), 3);
+        assert_eq!(msm.msm(&bases, &scalars), expected);
+
+        // The chunk of a failed worker is computed by the coordinator.
+        let msm = DistributedMsm::new(Arc::new(FaultyTransport(LocalTransport::new(4)))).min_chunk_size(100);
+        assert_eq!(msm.msm(&bases, &scalars), expected);
+    }
+
+    #[test]
+    fn test_tcp_transport() {
+        let mut rng = TestRng::default();
+        let num_terms = 1000;
+        let bases = (0..num_terms).map(|_| G1Projective::rand(&mut rng).to_affine()).collect::<Vec<_>>();
+        let scalars = (0..num_terms).map(|_| Fr::rand(&mut rng).to_bigint()).collect::<Vec<_>>();
+        let expected = batched::msm(&bases, &scalars);
+
+        // Start two workers, and add the address of a worker that is not listening.
+        let mut workers = (0..2)
+            .map(|_| {
+                let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+                let address = listener.local_addr().unwrap();
+                std::thread::spawn(move || serve_msm_worker(listener));
+                address
+            })
+            .collect::<Vec<_>>();
+        let transport = TcpTransport::new(workers.clone());
+        assert_eq!(transport.msm(1, &bases, &scalars).unwrap(), expected);
+
+        let msm = DistributedMsm::new(Arc::new(transport)).min_chunk_size(100);
+        assert_eq!(msm.msm(&bases, &scalars), expected);
+
+        // The chunk of an unreachable worker is computed by the coordinator.
+        workers.push(TcpListener::bind("127.0.0.1:0").unwrap().local_addr().unwrap());
+        let transport = TcpTransport::new(workers).timeout(Duration::from_secs(10));
+        assert!(transport.msm(2, &bases, &scalars).is_err());
+        let msm = DistributedMsm::new(Arc::new(transport)).min_chunk_size(100);
+        assert_eq!(msm.msm(&bases, &scalars), expected);
+    }
+
+    #[test]
+    fn test_coordinator_on_thread() {
+        let msm = Arc::new(DistributedMsm::new(Arc::new(LocalTransport::new(2))));
+        std::thread::spawn(move || {
+            set_distributed_msm_on_thread(Some(msm));
+            assert!(current_distributed_msm().is_some());
+        })
+        .join()
+        .unwrap();
+        // The coordinator of the other thread does not apply to this one.
+        assert!(DISTRIBUTED_MSM_ON_THREAD.with(|thread_msm| thread_msm.borrow().is_none()));
+    }
+}
@@ -0,0 +1,73 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::{batched, cuda_msm, VariableBase, CUDA_MSM_THRESHOLD};
+use snarkvm_curves::traits::AffineCurve;
+use snarkvm_fields::PrimeField;
+
+use core::sync::atomic::{AtomicU32, Ordering};
+use std::time::Instant;
+
+/// The fraction of each large G1 MSM that is computed on the GPU, in millionths.
+static GPU_SHARE: AtomicU32 = AtomicU32::new(GPU_SHARE_SCALE);
+const GPU_SHARE_SCALE: u32 = 1_000_000;
+
+/// Returns the number of leading terms of an MSM of `len` terms to compute on the GPU,
+/// with the remaining terms computed concurrently on the CPU.
+pub(super) fn gpu_len(len: usize) -> usize {
+    let gpu_len = (len as u128 * GPU_SHARE.load(Ordering::Relaxed) as u128 / GPU_SHARE_SCALE as u128) as usize;
+    // A share too small for the GPU is computed on the CPU instead.
+    if gpu_len > CUDA_MSM_THRESHOLD { gpu_len } else { 0 }
+}
+
+impl VariableBase {
+    /// Returns the fraction of each large BLS12-377 G1 MSM that is computed on the GPU.
+    pub fn gpu_share() -> f64 {
+        GPU_SHARE.load(Ordering::Relaxed) as f64 / GPU_SHARE_SCALE as f64
+    }
+
+    /// Sets the fraction of each large BLS12-377 G1 MSM that is computed on the GPU, with the rest
+    /// computed concurrently on the CPU, and the partial results added. By default, it is all on the GPU.
+    pub fn set_gpu_share(share: f64) {
+        assert!((0.0..=1.0).contains(&share), "The GPU share must be between 0 and 1, found {share}");
+        GPU_SHARE.store((share * GPU_SHARE_SCALE as f64).round() as u32, Ordering::Relaxed);
+    }
+
+    /// Times the given MSM on the GPU and on the CPU, and sets the GPU share so that both finish
+    /// at the same time, returning it. If the GPU fails, the share is left unchanged.
+    ///
+    /// The MSM should be representative of the sizes used when proving, as the ratio depends on it.
+    pub fn calibrate_gpu_share<G: AffineCurve>(
+        bases: &[G],
+        scalars: &[<G::ScalarField as PrimeField>::BigInteger],
+    ) -> f64 {
+        let start = Instant::now();
+        if cuda_msm(bases, scalars).is_none() {
+            return Self::gpu_share();
+        }
+        let gpu_time = start.elapsed().as_secs_f64();
+
+        let start = Instant::now();
+        batched::msm(bases, scalars);
+        let cpu_time = start.elapsed().as_secs_f64();
+
+        // The throughputs are inversely proportional to the times, so the GPU's share of the total is the CPU's time.
+        let share = match gpu_time + cpu_time > 0.0 {
+            true => cpu_time / (gpu_time + cpu_time),
+            false => 1.0,
+        };
+        Self::set_gpu_share(share);
+        share
+    }
+}
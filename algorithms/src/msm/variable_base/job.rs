@@ -0,0 +1,199 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::{current_distributed_msm, set_distributed_msm_on_thread, VariableBase};
+use snarkvm_curves::traits::AffineCurve;
+use snarkvm_fields::{PrimeField, Zero};
+
+use core::{
+    future::Future,
+    pin::Pin,
+    sync::atomic::{AtomicBool, Ordering},
+    task::{Context, Poll, Waker},
+};
+use parking_lot::{Condvar, Mutex};
+use std::sync::Arc;
+
+/// The number of terms computed between checks for cancellation.
+const MSM_JOB_CHUNK_SIZE: usize = 1 << 18;
+
+/// The state shared between a job and the thread computing it.
+struct MsmJobState<P> {
+    cancelled: AtomicBool,
+    /// The outcome, which is set once the job completes or is cancelled.
+    outcome: Mutex<Option<Option<P>>>,
+    /// The waker of the task awaiting the job, if any.
+    waker: Mutex<Option<Waker>>,
+    completed: Condvar,
+}
+
+impl<P> MsmJobState<P> {
+    fn complete(&self, outcome: Option<P>) {
+        *self.outcome.lock() = Some(outcome);
+        self.completed.notify_all();
+        if let Some(waker) = self.waker.lock().take() {
+            waker.wake();
+        }
+    }
+}
+
+/// A handle to an MSM running in the background, created by `VariableBase::msm_async`.
+///
+/// The job may be awaited as a future, or waited on from a blocking context. Once cancelled,
+/// the job stops at the next chunk boundary and resolves to `None`.
+pub struct MsmJob<P> {
+    state: Arc<MsmJobState<P>>,
+}
+
+impl<P> MsmJob<P> {
+    /// Requests the job to stop. The computation is abandoned at the next chunk boundary,
+    /// as a chunk already dispatched to the GPU cannot be interrupted.
+    pub fn cancel(&self) {
+        self.state.cancelled.store(true, Ordering::Relaxed);
+    }
+
+    /// Returns `true` if the job was cancelled.
+    pub fn is_cancelled(&self) -> bool {
+        self.state.cancelled.load(Ordering::Relaxed)
+    }
+
+    /// Returns `true` if the job has completed or was abandoned after a cancellation.
+    pub fn is_finished(&self) -> bool {
+        self.state.outcome.lock().is_some()
+    }
+
+    /// Blocks until the job finishes, returning the result, or `None` if it was cancelled.
+    pub fn wait(self) -> Option<P> {
+        let mut outcome = self.state.outcome.lock();
+        while outcome.is_none() {
+            self.state.completed.wait(&mut outcome);
+        }
+        outcome.take().flatten()
+    }
+}
+
+impl<P> Future for MsmJob<P> {
+    type Output = Option<P>;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        // Register the waker before checking the outcome, so that a completion in between is not missed.
+        *self.state.waker.lock() = Some(cx.waker().clone());
+        match self.state.outcome.lock().take() {
+            Some(outcome) => Poll::Ready(outcome),
+            None => Poll::Pending,
+        }
+    }
+}
+
+impl VariableBase {
+    /// Starts computing the MSM of `bases` and `scalars` on a background thread, returning a handle to the job.
+    ///
+    /// The bases and scalars are shared rather than copied, so that several jobs may use the same SRS.
+    pub fn msm_async<G: AffineCurve>(
+        bases: Arc<[G]>,
+        scalars: Arc<[<G::ScalarField as PrimeField>::BigInteger]>,
+    ) -> MsmJob<G::Projective> {
+        let state = Arc::new(MsmJobState {
+            cancelled: AtomicBool::new(false),
+            outcome: Mutex::new(None),
+            waker: Mutex::new(None),
+            completed: Condvar::new(),
+        });
+
+        let job_state = state.clone();
+        // The job inherits whether the GPU is enabled, and the coordinator, of the current thread.
+        let gpu_enabled_on_thread = VariableBase::gpu_enabled_on_thread();
+        let distributed_msm = current_distributed_msm();
+        std::thread::spawn(move || {
+            VariableBase::set_gpu_enabled_on_thread(gpu_enabled_on_thread);
+            set_distributed_msm_on_thread(distributed_msm);
+            let mut result = G::Projective::zero();
+            for (bases, scalars) in bases.chunks(MSM_JOB_CHUNK_SIZE).zip(scalars.chunks(MSM_JOB_CHUNK_SIZE)) {
+                if job_state.cancelled.load(Ordering::Relaxed) {
+                    return job_state.complete(None);
+                }
+                result += VariableBase::msm(bases, scalars);
+            }
+            let cancelled = job_state.cancelled.load(Ordering::Relaxed);
+            job_state.complete((!cancelled).then_some(result));
+        });
+
+        MsmJob { state }
+    }
+}
+
+/// Wakes the thread blocked in `block_on`.
+#[cfg(any(test, all(feature = "webgpu", not(target_arch = "wasm32"))))]
+struct ThreadWaker(std::thread::Thread);
+
+#[cfg(any(test, all(feature = "webgpu", not(target_arch = "wasm32"))))]
+impl std::task::Wake for ThreadWaker {
+    fn wake(self: Arc<Self>) {
+        self.0.unpark();
+    }
+}
+
+/// Polls the future until it is ready, parking the current thread in between.
+#[cfg(any(test, all(feature = "webgpu", not(target_arch = "wasm32"))))]
+pub(super) fn block_on<F: Future>(future: F) -> F::Output {
+    let waker = Waker::from(Arc::new(ThreadWaker(std::thread::current())));
+    let mut cx = Context::from_waker(&waker);
+    let mut future = Box::pin(future);
+    loop {
+        match future.as_mut().poll(&mut cx) {
+            Poll::Ready(output) => return output,
+            Poll::Pending => std::thread::park(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_curves::bls12_377::{Fr, G1Affine};
+    use snarkvm_utilities::{rand::TestRng, Uniform};
+
+    fn create_scalar_bases(size: usize) -> (Arc<[G1Affine]>, Arc<[<Fr as PrimeField>::BigInteger]>) {
+        let mut rng = TestRng::default();
+        let bases = (0..size).map(|_| G1Affine::rand(&mut rng)).collect::<Vec<_>>();
+        let scalars = (0..size).map(|_| Fr::rand(&mut rng).to_bigint()).collect::<Vec<_>>();
+        (bases.into(), scalars.into())
+    }
+
+    #[test]
+    fn test_msm_async() {
+        let (bases, scalars) = create_scalar_bases(1000);
+        let expected = VariableBase::msm(&bases, &scalars);
+
+        let job = VariableBase::msm_async(bases.clone(), scalars.clone());
+        assert_eq!(job.wait(), Some(expected));
+
+        let job = VariableBase::msm_async(bases, scalars);
+        assert_eq!(block_on(job), Some(expected));
+    }
+
+    #[test]
+    fn test_msm_async_cancel() {
+        let (bases, scalars) = create_scalar_bases(1000);
+
+        let job = VariableBase::msm_async(bases.clone(), scalars.clone());
+        job.cancel();
+        assert!(job.is_cancelled());
+        assert_eq!(job.wait(), None);
+
+        let job = VariableBase::msm_async(bases, scalars);
+        job.cancel();
+        assert_eq!(block_on(job), None);
+    }
+}
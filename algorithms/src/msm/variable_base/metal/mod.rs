@@ -0,0 +1,237 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! A bucket-method MSM for BLS12-377 G1 on Apple GPUs.
+//!
+//! The host sorts the points of each window into buckets, and the GPU sums each bucket
+//! and reduces them in segments of `SEGMENT_SIZE` buckets. The host then combines the
+//! segments and windows, which takes a few thousand additions.
+
+use super::buckets::{combine_segments, sort_windows, SortedBuckets};
+use snarkvm_curves::{
+    bls12_377::{Fq, G1Affine, G1Projective},
+    AffineCurve,
+    AffineLimbs,
+};
+use snarkvm_fields::{PrimeField, Zero};
+use snarkvm_utilities::{biginteger::BigInteger384, BigInteger};
+
+use ::metal::{
+    BufferRef,
+    CommandBufferRef,
+    CommandQueue,
+    CompileOptions,
+    ComputePipelineState,
+    Device,
+    MTLCommandBufferStatus,
+    MTLResourceOptions,
+    MTLSize,
+};
+use anyhow::{anyhow, bail, ensure, Result};
+use core::{any::Any, ffi::c_void, mem::size_of};
+use std::sync::OnceLock;
+
+/// The source of the compute kernels, which is compiled when the context is first loaded.
+const SOURCE: &str = include_str!("msm.metal");
+
+/// The number of buckets reduced by each thread. This must be a power of two,
+/// and at most the number of buckets of the smallest window.
+const SEGMENT_SIZE: usize = 64;
+/// The bounds on the window width, in bits.
+const MIN_WINDOW_BITS: usize = 8;
+const MAX_WINDOW_BITS: usize = 16;
+
+/// The layout of a BLS12-377 G1 affine point expected by the kernels,
+/// with the coordinates in Montgomery form. The point at infinity is not represented.
+#[repr(C)]
+struct MetalAffine {
+    x: [u64; 6],
+    y: [u64; 6],
+}
+
+/// The layout of a BLS12-377 G1 Jacobian point returned by the kernels,
+/// with the coordinates in Montgomery form.
+#[repr(C)]
+#[derive(Copy, Clone)]
+struct MetalJacobian {
+    x: [u64; 6],
+    y: [u64; 6],
+    z: [u64; 6],
+}
+
+impl MetalJacobian {
+    /// Unpacks the point, returning `None` if any of the limbs are not canonical.
+    fn to_projective(self) -> Option<G1Projective> {
+        let fq = |limbs: [u64; 6]| Fq::from_montgomery_bigint(BigInteger384(limbs));
+        Some(G1Projective::new(fq(self.x)?, fq(self.y)?, fq(self.z)?))
+    }
+}
+
+/// The parameters of the kernels, which must match `msm_params_t`.
+#[repr(C)]
+struct MetalParams {
+    num_windows: u32,
+    num_buckets: u32,
+    segment_size: u32,
+}
+
+/// The device, queue and compiled kernels, which are loaded once per process.
+struct MetalContext {
+    device: Device,
+    queue: CommandQueue,
+    accumulate: ComputePipelineState,
+    reduce: ComputePipelineState,
+}
+
+impl MetalContext {
+    /// Returns the context, loading it on the first call.
+    fn get() -> Result<&'static Self> {
+        static CONTEXT: OnceLock<Result<MetalContext, String>> = OnceLock::new();
+        CONTEXT
+            .get_or_init(|| Self::load().map_err(|error| error.to_string()))
+            .as_ref()
+            .map_err(|error| anyhow!("{error}"))
+    }
+
+    /// Selects the default device, and compiles the kernels for it.
+    fn load() -> Result<Self> {
+        let device = Device::system_default().ok_or_else(|| anyhow!("No Metal device is available"))?;
+        let library = device.new_library_with_source(SOURCE, &CompileOptions::new()).map_err(|error| anyhow!(error))?;
+        let pipeline = |name: &str| {
+            let function = library.get_function(name, None).map_err(|error| anyhow!(error))?;
+            device.new_compute_pipeline_state_with_function(&function).map_err(|error| anyhow!(error))
+        };
+        let accumulate = pipeline("msm_accumulate_buckets")?;
+        let reduce = pipeline("msm_reduce_segments")?;
+        let queue = device.new_command_queue();
+        Ok(Self { device, queue, accumulate, reduce })
+    }
+
+    /// Returns a buffer in shared memory holding a copy of `data`, which must be non-empty.
+    fn buffer_with_data<T>(&self, data: &[T]) -> ::metal::Buffer {
+        let length = (data.len() * size_of::<T>()) as u64;
+        self.device.new_buffer_with_data(data.as_ptr() as *const c_void, length, MTLResourceOptions::StorageModeShared)
+    }
+
+    /// Returns an uninitialized buffer in shared memory for `len` elements of type `T`.
+    fn buffer<T>(&self, len: usize) -> ::metal::Buffer {
+        self.device.new_buffer((len * size_of::<T>()) as u64, MTLResourceOptions::StorageModeShared)
+    }
+
+    /// Encodes a dispatch of `pipeline` over `num_threads` threads, with the given buffers and parameters.
+    fn dispatch(
+        &self,
+        command_buffer: &CommandBufferRef,
+        pipeline: &ComputePipelineState,
+        buffers: &[&BufferRef],
+        params: &MetalParams,
+        num_threads: usize,
+    ) {
+        let encoder = command_buffer.new_compute_command_encoder();
+        encoder.set_compute_pipeline_state(pipeline);
+        for (index, buffer) in buffers.iter().enumerate() {
+            encoder.set_buffer(index as u64, Some(*buffer), 0);
+        }
+        encoder.set_bytes(buffers.len() as u64, size_of::<MetalParams>() as u64, params as *const _ as *const c_void);
+        let width = pipeline.max_total_threads_per_threadgroup().min(256);
+        encoder.dispatch_threads(MTLSize::new(num_threads as u64, 1, 1), MTLSize::new(width, 1, 1));
+        encoder.end_encoding();
+    }
+}
+
+/// Computes the MSM on the default Metal device. The points must be BLS12-377 G1 affine points.
+pub(super) fn msm<G: AffineCurve>(
+    bases: &[G],
+    scalars: &[<G::ScalarField as PrimeField>::BigInteger],
+) -> Result<G::Projective> {
+    let context = MetalContext::get()?;
+
+    // Skip the terms that are zero, as the kernels do not represent the point at infinity.
+    let (points, scalars): (Vec<_>, Vec<_>) = bases
+        .iter()
+        .zip(scalars)
+        .map(|(base, scalar)| ((base as &dyn Any).downcast_ref::<G1Affine>().unwrap(), scalar))
+        .filter(|(base, scalar)| !base.is_zero() && !scalar.is_zero())
+        .map(|(base, scalar)| {
+            let (x, y) = base.to_xy_limbs();
+            (MetalAffine { x: x.0, y: y.0 }, *scalar)
+        })
+        .unzip();
+    ensure!(points.len() <= u32::MAX as usize, "The MSM of {} terms is too large for Metal", points.len());
+
+    let mut result = G1Projective::zero();
+    if !points.is_empty() {
+        // Determine the window width, so that each bucket holds a few points on average.
+        let c = (points.len().ilog2() as usize).saturating_sub(4).clamp(MIN_WINDOW_BITS, MAX_WINDOW_BITS);
+        let num_bits = <G::ScalarField as PrimeField>::size_in_bits();
+        let num_windows = (num_bits + c - 1) / c;
+        let num_buckets = 1 << c;
+        let num_segments = num_buckets / SEGMENT_SIZE;
+
+        // Sort the points of each window into buckets, and concatenate the windows.
+        let SortedBuckets { offsets, indices, max_occupancy } = sort_windows(&scalars, num_windows, c);
+        super::telemetry::observe_bucket_occupancy("metal", max_occupancy);
+        ensure!(indices.len() <= u32::MAX as usize, "The MSM of {} terms is too large for Metal", points.len());
+
+        let params = MetalParams {
+            num_windows: num_windows as u32,
+            num_buckets: num_buckets as u32,
+            segment_size: SEGMENT_SIZE as u32,
+        };
+        let points = context.buffer_with_data(&points);
+        let indices = context.buffer_with_data(&indices);
+        let offsets = context.buffer_with_data(&offsets);
+        let buckets = context.buffer::<MetalJacobian>(num_windows * num_buckets);
+        let sums = context.buffer::<MetalJacobian>(num_windows * num_segments);
+        let weighted = context.buffer::<MetalJacobian>(num_windows * num_segments);
+
+        // Sum the buckets, and then reduce them in segments.
+        let command_buffer = context.queue.new_command_buffer();
+        context.dispatch(
+            command_buffer,
+            &context.accumulate,
+            &[&points, &indices, &offsets, &buckets],
+            &params,
+            num_windows * num_buckets,
+        );
+        context.dispatch(
+            command_buffer,
+            &context.reduce,
+            &[&buckets, &sums, &weighted],
+            &params,
+            num_windows * num_segments,
+        );
+        command_buffer.commit();
+        command_buffer.wait_until_completed();
+        if matches!(command_buffer.status(), MTLCommandBufferStatus::Error) {
+            bail!("The Metal command buffer failed");
+        }
+
+        let read = |buffer: &::metal::Buffer| {
+            let len = num_windows * num_segments;
+            let points = unsafe { std::slice::from_raw_parts(buffer.contents() as *const MetalJacobian, len) };
+            points.iter().map(|point| point.to_projective()).collect::<Option<Vec<_>>>()
+        };
+        let (sums, weighted) = match (read(&sums), read(&weighted)) {
+            (Some(sums), Some(weighted)) => (sums, weighted),
+            _ => bail!("The Metal MSM returned a non-canonical point"),
+        };
+
+        result = combine_segments(&sums, &weighted, num_windows, SEGMENT_SIZE, c);
+    }
+    (&mut Some(result) as &mut dyn Any)
+        .downcast_mut::<Option<G::Projective>>()
+        .and_then(Option::take)
+        .ok_or_else(|| anyhow!("The Metal MSM only supports BLS12-377 G1"))
+}
@@ -0,0 +1,306 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+// Bucket-method MSM over the BLS12-377 G1 curve.
+//
+// The base field elements are stored in Montgomery form as 12 little-endian 32-bit limbs,
+// which matches the memory layout of the 6 little-endian 64-bit limbs used on the host.
+
+#include <metal_stdlib>
+using namespace metal;
+
+#define NLIMBS 12
+
+struct fq_t {
+    uint l[NLIMBS];
+};
+
+// An affine point, which is never the point at infinity.
+struct affine_t {
+    fq_t x;
+    fq_t y;
+};
+
+// A point in Jacobian coordinates, which is the point at infinity iff `z` is zero.
+struct jacobian_t {
+    fq_t x;
+    fq_t y;
+    fq_t z;
+};
+
+struct msm_params_t {
+    uint num_windows;
+    uint num_buckets;
+    uint segment_size;
+};
+
+// The base field modulus.
+constant uint MODULUS[NLIMBS] = {
+    0x00000001, 0x8508c000, 0x30000000, 0x170b5d44, 0xba094800, 0x1ef3622f,
+    0x00f5138f, 0x1a22d9f3, 0x6ca1493b, 0xc63b05c0, 0x17c510ea, 0x01ae3a46,
+};
+
+// -MODULUS^{-1} mod 2^32.
+constant uint INV = 0xffffffff;
+
+// R mod MODULUS, i.e. one in Montgomery form.
+constant uint ONE[NLIMBS] = {
+    0xffffff68, 0x02cdffff, 0x7fffffb1, 0x51409f83, 0x8a7d3ff2, 0x9f7db3a9,
+    0x6e7c6305, 0x7b4e97b7, 0x803c84e8, 0x4cf495bf, 0xe2fdf49a, 0x008d6661,
+};
+
+static fq_t fq_zero() {
+    fq_t r;
+    for (int i = 0; i < NLIMBS; i++) r.l[i] = 0;
+    return r;
+}
+
+static fq_t fq_one() {
+    fq_t r;
+    for (int i = 0; i < NLIMBS; i++) r.l[i] = ONE[i];
+    return r;
+}
+
+static bool fq_is_zero(fq_t a) {
+    uint acc = 0;
+    for (int i = 0; i < NLIMBS; i++) acc |= a.l[i];
+    return acc == 0;
+}
+
+static bool fq_eq(fq_t a, fq_t b) {
+    uint acc = 0;
+    for (int i = 0; i < NLIMBS; i++) acc |= a.l[i] ^ b.l[i];
+    return acc == 0;
+}
+
+// Returns whether `a` is at least the modulus.
+static bool fq_geq_modulus(fq_t a) {
+    for (int i = NLIMBS - 1; i >= 0; i--) {
+        if (a.l[i] != MODULUS[i]) return a.l[i] > MODULUS[i];
+    }
+    return true;
+}
+
+static fq_t fq_sub_modulus(fq_t a) {
+    ulong borrow = 0;
+    for (int i = 0; i < NLIMBS; i++) {
+        ulong t = (ulong)a.l[i] - MODULUS[i] - borrow;
+        a.l[i] = (uint)t;
+        borrow = (t >> 32) & 1;
+    }
+    return a;
+}
+
+// The modulus is less than 2^383, so the sum of two reduced elements does not overflow.
+static fq_t fq_add(fq_t a, fq_t b) {
+    ulong carry = 0;
+    for (int i = 0; i < NLIMBS; i++) {
+        ulong t = (ulong)a.l[i] + b.l[i] + carry;
+        a.l[i] = (uint)t;
+        carry = t >> 32;
+    }
+    return fq_geq_modulus(a) ? fq_sub_modulus(a) : a;
+}
+
+static fq_t fq_sub(fq_t a, fq_t b) {
+    ulong borrow = 0;
+    for (int i = 0; i < NLIMBS; i++) {
+        ulong t = (ulong)a.l[i] - b.l[i] - borrow;
+        a.l[i] = (uint)t;
+        borrow = (t >> 32) & 1;
+    }
+    if (borrow) {
+        ulong carry = 0;
+        for (int i = 0; i < NLIMBS; i++) {
+            ulong t = (ulong)a.l[i] + MODULUS[i] + carry;
+            a.l[i] = (uint)t;
+            carry = t >> 32;
+        }
+    }
+    return a;
+}
+
+static fq_t fq_double(fq_t a) {
+    return fq_add(a, a);
+}
+
+// Montgomery multiplication with the CIOS method.
+static fq_t fq_mul(fq_t a, fq_t b) {
+    uint t[NLIMBS + 2];
+    for (int i = 0; i < NLIMBS + 2; i++) t[i] = 0;
+
+    for (int i = 0; i < NLIMBS; i++) {
+        ulong c = 0;
+        for (int j = 0; j < NLIMBS; j++) {
+            c = (ulong)t[j] + (ulong)a.l[j] * b.l[i] + (c >> 32);
+            t[j] = (uint)c;
+        }
+        c = (ulong)t[NLIMBS] + (c >> 32);
+        t[NLIMBS] = (uint)c;
+        t[NLIMBS + 1] = (uint)(c >> 32);
+
+        uint m = t[0] * INV;
+        c = (ulong)t[0] + (ulong)m * MODULUS[0];
+        for (int j = 1; j < NLIMBS; j++) {
+            c = (ulong)t[j] + (ulong)m * MODULUS[j] + (c >> 32);
+            t[j - 1] = (uint)c;
+        }
+        c = (ulong)t[NLIMBS] + (c >> 32);
+        t[NLIMBS - 1] = (uint)c;
+        t[NLIMBS] = t[NLIMBS + 1] + (uint)(c >> 32);
+    }
+
+    fq_t r;
+    for (int i = 0; i < NLIMBS; i++) r.l[i] = t[i];
+    return fq_geq_modulus(r) ? fq_sub_modulus(r) : r;
+}
+
+static fq_t fq_square(fq_t a) {
+    return fq_mul(a, a);
+}
+
+static jacobian_t jacobian_zero() {
+    jacobian_t r;
+    r.x = fq_zero();
+    r.y = fq_one();
+    r.z = fq_zero();
+    return r;
+}
+
+static bool jacobian_is_zero(jacobian_t p) {
+    return fq_is_zero(p.z);
+}
+
+// http://www.hyperelliptic.org/EFD/g1p/auto-shortw-jacobian-0.html#doubling-dbl-2009-l
+static jacobian_t jacobian_double(jacobian_t p) {
+    if (jacobian_is_zero(p)) return p;
+
+    fq_t a = fq_square(p.x);
+    fq_t b = fq_square(p.y);
+    fq_t c = fq_square(b);
+    fq_t d = fq_double(fq_sub(fq_sub(fq_square(fq_add(p.x, b)), a), c));
+    fq_t e = fq_add(fq_double(a), a);
+    fq_t f = fq_square(e);
+
+    jacobian_t r;
+    r.z = fq_double(fq_mul(p.y, p.z));
+    r.x = fq_sub(f, fq_double(d));
+    r.y = fq_sub(fq_mul(e, fq_sub(d, r.x)), fq_double(fq_double(fq_double(c))));
+    return r;
+}
+
+// http://www.hyperelliptic.org/EFD/g1p/auto-shortw-jacobian-0.html#addition-madd-2007-bl
+static jacobian_t jacobian_add_affine(jacobian_t p, affine_t q) {
+    if (jacobian_is_zero(p)) {
+        jacobian_t r;
+        r.x = q.x;
+        r.y = q.y;
+        r.z = fq_one();
+        return r;
+    }
+
+    fq_t z1z1 = fq_square(p.z);
+    fq_t u2 = fq_mul(q.x, z1z1);
+    fq_t s2 = fq_mul(fq_mul(q.y, p.z), z1z1);
+    fq_t h = fq_sub(u2, p.x);
+    fq_t r = fq_double(fq_sub(s2, p.y));
+    if (fq_is_zero(h)) {
+        return fq_is_zero(r) ? jacobian_double(p) : jacobian_zero();
+    }
+
+    fq_t hh = fq_square(h);
+    fq_t i = fq_double(fq_double(hh));
+    fq_t j = fq_mul(h, i);
+    fq_t v = fq_mul(p.x, i);
+
+    jacobian_t out;
+    out.x = fq_sub(fq_sub(fq_square(r), j), fq_double(v));
+    out.y = fq_sub(fq_mul(r, fq_sub(v, out.x)), fq_double(fq_mul(p.y, j)));
+    out.z = fq_sub(fq_sub(fq_square(fq_add(p.z, h)), z1z1), hh);
+    return out;
+}
+
+// http://www.hyperelliptic.org/EFD/g1p/auto-shortw-jacobian-0.html#addition-add-2007-bl
+static jacobian_t jacobian_add(jacobian_t p, jacobian_t q) {
+    if (jacobian_is_zero(p)) return q;
+    if (jacobian_is_zero(q)) return p;
+
+    fq_t z1z1 = fq_square(p.z);
+    fq_t z2z2 = fq_square(q.z);
+    fq_t u1 = fq_mul(p.x, z2z2);
+    fq_t u2 = fq_mul(q.x, z1z1);
+    fq_t s1 = fq_mul(fq_mul(p.y, q.z), z2z2);
+    fq_t s2 = fq_mul(fq_mul(q.y, p.z), z1z1);
+    fq_t h = fq_sub(u2, u1);
+    fq_t r = fq_double(fq_sub(s2, s1));
+    if (fq_is_zero(h)) {
+        return fq_is_zero(r) ? jacobian_double(p) : jacobian_zero();
+    }
+
+    fq_t i = fq_square(fq_double(h));
+    fq_t j = fq_mul(h, i);
+    fq_t v = fq_mul(u1, i);
+
+    jacobian_t out;
+    out.x = fq_sub(fq_sub(fq_square(r), j), fq_double(v));
+    out.y = fq_sub(fq_mul(r, fq_sub(v, out.x)), fq_double(fq_mul(s1, j)));
+    out.z = fq_mul(fq_sub(fq_sub(fq_square(fq_add(p.z, q.z)), z1z1), z2z2), h);
+    return out;
+}
+
+// Sums the points in each bucket, with one thread per bucket.
+//
+// The points of bucket `b` of window `w` are `points[indices[offsets[k]..offsets[k + 1]]]`,
+// where `k = w * num_buckets + b`.
+kernel void msm_accumulate_buckets(
+    device const affine_t* points [[buffer(0)]],
+    device const uint* indices [[buffer(1)]],
+    device const uint* offsets [[buffer(2)]],
+    device jacobian_t* buckets [[buffer(3)]],
+    constant msm_params_t& params [[buffer(4)]],
+    uint gid [[thread_position_in_grid]]
+) {
+    if (gid >= params.num_windows * params.num_buckets) return;
+
+    jacobian_t acc = jacobian_zero();
+    for (uint i = offsets[gid]; i < offsets[gid + 1]; i++) {
+        acc = jacobian_add_affine(acc, points[indices[i]]);
+    }
+    buckets[gid] = acc;
+}
+
+// Reduces each segment of `segment_size` consecutive buckets of a window, with one thread per segment.
+//
+// For the segment starting at bucket `lo`, this computes `sum = sum_b buckets[b]` and
+// `weighted = sum_b (b - lo + 1) * buckets[b]`, from which the host recovers `sum_b b * buckets[b]`.
+kernel void msm_reduce_segments(
+    device const jacobian_t* buckets [[buffer(0)]],
+    device jacobian_t* sums [[buffer(1)]],
+    device jacobian_t* weighted [[buffer(2)]],
+    constant msm_params_t& params [[buffer(3)]],
+    uint gid [[thread_position_in_grid]]
+) {
+    uint num_segments = params.num_buckets / params.segment_size;
+    if (gid >= params.num_windows * num_segments) return;
+
+    uint start = gid * params.segment_size;
+    jacobian_t running = jacobian_zero();
+    jacobian_t acc = jacobian_zero();
+    for (uint i = params.segment_size; i > 0; i--) {
+        running = jacobian_add(running, buckets[start + i - 1]);
+        acc = jacobian_add(acc, running);
+    }
+    sums[gid] = running;
+    weighted[gid] = acc;
+}
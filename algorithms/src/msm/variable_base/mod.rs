@@ -15,35 +15,359 @@
 pub mod batched;
 pub mod standard;
 
+mod job;
+pub use job::*;
+
+pub mod distributed;
+pub use distributed::*;
+
+#[cfg(feature = "gpu-selftest")]
+mod selftest;
+#[cfg(feature = "gpu-selftest")]
+pub use selftest::*;
+
+#[cfg(all(feature = "cuda", target_arch = "x86_64"))]
+mod hybrid;
+
 #[cfg(target_arch = "x86_64")]
 pub mod prefetch;
 
-use snarkvm_curves::{bls12_377::G1Affine, traits::AffineCurve};
-use snarkvm_fields::PrimeField;
+#[cfg(all(feature = "metal", target_os = "macos"))]
+mod metal;
+
+#[cfg(feature = "webgpu")]
+mod webgpu;
+
+#[cfg(any(all(feature = "metal", target_os = "macos"), feature = "webgpu"))]
+mod buckets;
+
+#[cfg(any(
+    all(feature = "cuda", target_arch = "x86_64"),
+    all(feature = "metal", target_os = "macos"),
+    feature = "webgpu"
+))]
+mod telemetry;
+
+use snarkvm_curves::{
+    bls12_377::{G1Affine, G2Affine},
+    traits::AffineCurve,
+};
+use snarkvm_fields::{PrimeField, Zero};
+use snarkvm_utilities::{cfg_iter, execute_in_current_thread_pool};
+
+use core::{
+    any::TypeId,
+    cell::Cell,
+    sync::atomic::{AtomicBool, Ordering},
+};
+#[cfg(not(feature = "serial"))]
+use rayon::prelude::*;
+
+#[cfg(all(feature = "cuda", target_arch = "x86_64"))]
+use snarkvm_curves::bls12_377::{Fq, FqParameters};
+#[cfg(all(feature = "cuda", target_arch = "x86_64"))]
+use snarkvm_fields::{FieldParameters, Reduction};
+
+/// The layout of a BLS12-377 G1 affine point expected by the CUDA kernels,
+/// with the coordinates in Montgomery form.
+#[cfg(all(feature = "cuda", target_arch = "x86_64"))]
+#[repr(C)]
+struct CudaAffine {
+    x: [u64; 6],
+    y: [u64; 6],
+    infinity: bool,
+}
+
+/// Whether BLS12-377 G1 affine points are laid out in memory as `CudaAffine`, in which case the bases
+/// are passed to the kernels in place. This keeps their address stable, so the GPU keeps them resident.
+#[cfg(all(feature = "cuda", target_arch = "x86_64"))]
+const CUDA_AFFINE_IN_PLACE: bool = core::mem::size_of::<G1Affine>() == core::mem::size_of::<CudaAffine>()
+    && core::mem::align_of::<G1Affine>() == core::mem::align_of::<CudaAffine>()
+    && matches!(<FqParameters as FieldParameters>::REDUCTION, Reduction::Montgomery);
+
+#[cfg(all(feature = "cuda", target_arch = "x86_64"))]
+impl CudaAffine {
+    /// Packs the given point, which must be a BLS12-377 G1 affine point.
+    fn new<G: AffineCurve>(point: &G) -> Self {
+        use snarkvm_curves::AffineLimbs;
+
+        let point = (point as &dyn core::any::Any).downcast_ref::<G1Affine>().unwrap();
+        let (x, y) = point.to_xy_limbs();
+        Self { x: x.0, y: y.0, infinity: point.infinity }
+    }
+}
+
+/// The layout of a BLS12-377 G2 affine point expected by the CUDA kernels,
+/// with the `c0` and `c1` components of each coordinate in Montgomery form.
+#[cfg(all(feature = "cuda", target_arch = "x86_64"))]
+#[repr(C)]
+struct CudaAffineG2 {
+    x: [[u64; 6]; 2],
+    y: [[u64; 6]; 2],
+    infinity: bool,
+}
+
+#[cfg(all(feature = "cuda", target_arch = "x86_64"))]
+impl CudaAffineG2 {
+    /// Packs the given point, which must be a BLS12-377 G2 affine point.
+    fn new<G: AffineCurve>(point: &G) -> Self {
+        let point = (point as &dyn core::any::Any).downcast_ref::<G2Affine>().unwrap();
+        let limbs = |c: &Fq| c.to_montgomery_bigint().0;
+        Self {
+            x: [limbs(&point.x.c0), limbs(&point.x.c1)],
+            y: [limbs(&point.y.c0), limbs(&point.y.c1)],
+            infinity: point.infinity,
+        }
+    }
+}
+
+/// The layout of a BLS12-377 G2 Jacobian point returned by the CUDA kernels,
+/// with the `c0` and `c1` components of each coordinate in Montgomery form.
+#[cfg(all(feature = "cuda", target_arch = "x86_64"))]
+#[repr(C)]
+struct CudaProjectiveG2 {
+    x: [[u64; 6]; 2],
+    y: [[u64; 6]; 2],
+    z: [[u64; 6]; 2],
+}
+
+#[cfg(all(feature = "cuda", target_arch = "x86_64"))]
+impl CudaProjectiveG2 {
+    /// Unpacks the point, returning `None` if any of the limbs are not canonical.
+    fn into_projective<P: 'static>(self) -> Option<P> {
+        use snarkvm_curves::bls12_377::{Fq2, G2Projective};
+        use snarkvm_utilities::biginteger::BigInteger384;
+
+        let fq = |limbs: [u64; 6]| Fq::from_montgomery_bigint(BigInteger384(limbs));
+        let fq2 = |c: [[u64; 6]; 2]| Some(Fq2::new(fq(c[0])?, fq(c[1])?));
+        let point = G2Projective::new(fq2(self.x)?, fq2(self.y)?, fq2(self.z)?);
+        (&mut Some(point) as &mut dyn core::any::Any).downcast_mut::<Option<P>>().and_then(Option::take)
+    }
+}
+
+/// Computes the MSM on the GPU, where the points must be BLS12-377 G1 affine points.
+/// Returns `None` if the GPU fails, in which case the GPU context is re-created on the next request.
+#[cfg(all(feature = "cuda", target_arch = "x86_64"))]
+fn cuda_msm<G: AffineCurve>(
+    bases: &[G],
+    scalars: &[<G::ScalarField as PrimeField>::BigInteger],
+) -> Option<G::Projective> {
+    let transfer_bytes = bases.len() * core::mem::size_of::<CudaAffine>() + core::mem::size_of_val(scalars);
+    let result = telemetry::observe_gpu_msm("cuda", scalars.len(), transfer_bytes, || {
+        let result = if CUDA_AFFINE_IN_PLACE {
+            // `Affine` is `#[repr(C)]` and its coordinates are `#[repr(transparent)]` limbs,
+            // so the bases already have the layout of `CudaAffine`.
+            snarkvm_algorithms_cuda::msm::<G, G::Projective, <G::ScalarField as PrimeField>::BigInteger>(bases, scalars)
+        } else {
+            let bases = bases.iter().map(CudaAffine::new).collect::<Vec<_>>();
+            snarkvm_algorithms_cuda::msm::<CudaAffine, G::Projective, <G::ScalarField as PrimeField>::BigInteger>(
+                &bases, scalars,
+            )
+        };
+        result.map_err(|error| error.code)
+    });
+    match result {
+        Ok(result) => Some(result),
+        Err(code) => {
+            eprintln!(
+                "Warning: CUDA MSM failed ({}), retrying on the CPU",
+                snarkvm_algorithms_cuda::error_description(code)
+            );
+            None
+        }
+    }
+}
+
+/// The minimum number of terms for which an MSM is dispatched to the GPU, below which
+/// the transfers outweigh the speedup. See the `variable_base_bls12_377_cuda` benchmark.
+#[cfg(all(feature = "cuda", target_arch = "x86_64"))]
+const CUDA_MSM_THRESHOLD: usize = 1 << 10;
+
+/// The minimum number of terms for which an MSM is dispatched to the GPU on Apple Silicon,
+/// below which sorting the points into buckets outweighs the speedup.
+#[cfg(all(feature = "metal", target_os = "macos"))]
+const METAL_MSM_THRESHOLD: usize = 1 << 12;
+
+/// The minimum number of terms for which an MSM is dispatched to WebGPU, below which
+/// sorting the points into buckets outweighs the speedup.
+#[cfg(feature = "webgpu")]
+const WEBGPU_MSM_THRESHOLD: usize = 1 << 12;
 
-use core::any::TypeId;
+/// The number of terms buffered by `VariableBase::msm_streaming` for each partial MSM,
+/// which bounds its memory usage while remaining large enough for the GPU.
+const MSM_STREAMING_CHUNK_SIZE: usize = 1 << 20;
+
+/// Whether MSMs may be dispatched to the GPU, when snarkVM is built with GPU support.
+static GPU_ENABLED: AtomicBool = AtomicBool::new(true);
+
+thread_local! {
+    /// Whether MSMs computed on the current thread may be dispatched to the GPU, if it is enabled for the process.
+    static GPU_ENABLED_ON_THREAD: Cell<bool> = Cell::new(true);
+}
 
 pub struct VariableBase;
 
 impl VariableBase {
+    /// Returns `true` if MSMs computed on the current thread may be dispatched to the GPU.
+    pub fn gpu_enabled() -> bool {
+        GPU_ENABLED.load(Ordering::Relaxed) && Self::gpu_enabled_on_thread()
+    }
+
+    /// Sets whether MSMs may be dispatched to the GPU, for all threads of the process.
+    pub fn set_gpu_enabled(enabled: bool) {
+        GPU_ENABLED.store(enabled, Ordering::Relaxed);
+    }
+
+    /// Returns `true` unless the GPU was disabled for the current thread with `set_gpu_enabled_on_thread`.
+    pub fn gpu_enabled_on_thread() -> bool {
+        GPU_ENABLED_ON_THREAD.with(Cell::get)
+    }
+
+    /// Sets whether MSMs computed on the current thread may be dispatched to the GPU, e.g. from the start handler
+    /// of a dedicated thread pool, without affecting the other threads of the process.
+    pub fn set_gpu_enabled_on_thread(enabled: bool) {
+        GPU_ENABLED_ON_THREAD.with(|gpu_enabled| gpu_enabled.set(enabled));
+    }
+
+    /// Computes the MSM of `bases` and `scalars`. The dispatch to a coordinator or a GPU is decided on the current
+    /// thread, and the CPU work runs in the thread pool given by `execute_in_current_thread_pool`.
     pub fn msm<G: AffineCurve>(bases: &[G], scalars: &[<G::ScalarField as PrimeField>::BigInteger]) -> G::Projective {
         // For BLS12-377, we perform variable base MSM using a batched addition technique.
         if TypeId::of::<G>() == TypeId::of::<G1Affine>() {
+            // If a coordinator is set for this thread or installed, split the MSM across its workers.
+            if let Some(distributed) = current_distributed_msm() {
+                if distributed.num_chunks(scalars.len()) > 1 {
+                    match distributed.msm_generic(bases, scalars) {
+                        Ok(result) => return result,
+                        Err(error) => eprintln!("Warning: distributed MSM failed ({error}), retrying locally"),
+                    }
+                }
+            }
             #[cfg(all(feature = "cuda", target_arch = "x86_64"))]
-            // TODO SNP: where to set the threshold
-            if scalars.len() > 1024 {
-                let result = snarkvm_algorithms_cuda::msm::<G, G::Projective, <G::ScalarField as PrimeField>::BigInteger>(
-                    bases, scalars,
-                );
-                if let Ok(result) = result {
-                    return result;
+            if scalars.len() > CUDA_MSM_THRESHOLD && Self::gpu_enabled() {
+                // Split the MSM between the GPU and the CPU, according to the GPU share.
+                let gpu_len = hybrid::gpu_len(scalars.len());
+                if gpu_len > 0 {
+                    let (gpu, cpu) = execute_in_current_thread_pool(|| {
+                        rayon::join(
+                            || cuda_msm(&bases[..gpu_len], &scalars[..gpu_len]),
+                            || batched::msm(&bases[gpu_len..scalars.len()], &scalars[gpu_len..]),
+                        )
+                    });
+                    // If the GPU fails, retry its share on the CPU.
+                    return gpu.unwrap_or_else(|| {
+                        execute_in_current_thread_pool(|| batched::msm(&bases[..gpu_len], &scalars[..gpu_len]))
+                    }) + cpu;
                 }
             }
-            batched::msm(bases, scalars)
+            #[cfg(all(feature = "metal", target_os = "macos"))]
+            if scalars.len() > METAL_MSM_THRESHOLD && Self::gpu_enabled() {
+                let transfer_bytes =
+                    scalars.len() * (2 * core::mem::size_of::<G::BaseField>()) + core::mem::size_of_val(scalars);
+                let result =
+                    telemetry::observe_gpu_msm("metal", scalars.len(), transfer_bytes, || metal::msm(bases, scalars));
+                match result {
+                    Ok(result) => return result,
+                    Err(error) => eprintln!("Warning: Metal MSM failed ({error}), retrying on the CPU"),
+                }
+            }
+            execute_in_current_thread_pool(|| batched::msm(bases, scalars))
+        }
+        // For BLS12-377 G2, we perform variable base MSM on the GPU if available.
+        else if TypeId::of::<G>() == TypeId::of::<G2Affine>() {
+            #[cfg(all(feature = "cuda", target_arch = "x86_64"))]
+            if scalars.len() > CUDA_MSM_THRESHOLD && Self::gpu_enabled() {
+                let bases = bases.iter().map(CudaAffineG2::new).collect::<Vec<_>>();
+                let transfer_bytes = core::mem::size_of_val(bases.as_slice()) + core::mem::size_of_val(scalars);
+                let result = telemetry::observe_gpu_msm("cuda", scalars.len(), transfer_bytes, || {
+                    snarkvm_algorithms_cuda::msm_g2::<
+                        CudaAffineG2,
+                        CudaProjectiveG2,
+                        <G::ScalarField as PrimeField>::BigInteger,
+                    >(&bases, scalars)
+                    .map_err(|error| error.code)
+                });
+                // If the GPU fails, retry on the CPU, as the GPU context is re-created on the next request.
+                match result.map(CudaProjectiveG2::into_projective) {
+                    Ok(Some(result)) => return result,
+                    Ok(None) => eprintln!("Warning: CUDA MSM returned a non-canonical point, retrying on the CPU"),
+                    Err(code) => eprintln!(
+                        "Warning: CUDA MSM failed ({}), retrying on the CPU",
+                        snarkvm_algorithms_cuda::error_description(code)
+                    ),
+                }
+            }
+            execute_in_current_thread_pool(|| standard::msm(bases, scalars))
         }
         // For all other curves, we perform variable base MSM using Pippenger's algorithm.
         else {
-            standard::msm(bases, scalars)
+            execute_in_current_thread_pool(|| standard::msm(bases, scalars))
+        }
+    }
+
+    /// Computes the MSM on a WebGPU device if one is available, falling back to `VariableBase::msm` otherwise.
+    ///
+    /// This is the only entry point to the WebGPU backend, which is experimental and must be opted into
+    /// by calling this function: `VariableBase::msm`, and therefore the prover, never dispatches to WebGPU.
+    #[cfg(feature = "webgpu")]
+    pub async fn msm_webgpu<G: AffineCurve>(
+        bases: &[G],
+        scalars: &[<G::ScalarField as PrimeField>::BigInteger],
+    ) -> G::Projective {
+        if TypeId::of::<G>() == TypeId::of::<G1Affine>() && scalars.len() > WEBGPU_MSM_THRESHOLD && Self::gpu_enabled()
+        {
+            match webgpu::msm(bases, scalars).await {
+                Ok(result) => return result,
+                Err(error) => eprintln!("Warning: WebGPU MSM failed ({error}), retrying on the CPU"),
+            }
+        }
+        Self::msm(bases, scalars)
+    }
+
+    /// Computes several MSMs in one call, where the `i`-th result is the MSM of `bases[i]` and `scalars[i]`.
+    ///
+    /// The MSMs are computed concurrently rather than one after another, so that the host-side work
+    /// of each one overlaps with the GPU work of the others, and small MSMs share the thread pool.
+    pub fn msm_batch<G: AffineCurve>(
+        bases: &[&[G]],
+        scalars: &[&[<G::ScalarField as PrimeField>::BigInteger]],
+    ) -> Vec<G::Projective> {
+        assert_eq!(bases.len(), scalars.len(), "The number of bases and scalars in the batch must match");
+        cfg_iter!(bases).zip(scalars).map(|(bases, scalars)| Self::msm(bases, scalars)).collect()
+    }
+
+    /// Computes the MSM of the given terms, without materializing all of them at once.
+    ///
+    /// The terms are consumed in chunks of `MSM_STREAMING_CHUNK_SIZE`, and the MSM of each chunk
+    /// is accumulated into the result, so the bases and scalars may be read lazily, e.g. from disk.
+    pub fn msm_streaming<G: AffineCurve>(
+        terms: impl IntoIterator<Item = (G, <G::ScalarField as PrimeField>::BigInteger)>,
+    ) -> G::Projective {
+        Self::msm_streaming_with_chunk_size(terms, MSM_STREAMING_CHUNK_SIZE)
+    }
+
+    fn msm_streaming_with_chunk_size<G: AffineCurve>(
+        terms: impl IntoIterator<Item = (G, <G::ScalarField as PrimeField>::BigInteger)>,
+        chunk_size: usize,
+    ) -> G::Projective {
+        let mut terms = terms.into_iter();
+        // Avoid reserving a full chunk up front for short inputs, as the buffers grow on demand.
+        let capacity = terms.size_hint().0.min(chunk_size);
+        let mut bases = Vec::with_capacity(capacity);
+        let mut scalars = Vec::with_capacity(capacity);
+        let mut result = G::Projective::zero();
+        loop {
+            // Reuse the buffers for each chunk.
+            bases.clear();
+            scalars.clear();
+            for (base, scalar) in terms.by_ref().take(chunk_size) {
+                bases.push(base);
+                scalars.push(scalar);
+            }
+            if bases.is_empty() {
+                return result;
+            }
+            result += Self::msm(&bases, &scalars);
         }
     }
 
@@ -105,6 +429,69 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_msm_signed_digits() {
+        use snarkvm_curves::ProjectiveCurve;
+        use snarkvm_fields::{Field, One, Zero};
+
+        let mut rng = TestRng::default();
+        for msm_size in [1, 5, 50, 500] {
+            let (mut bases, mut scalars) = create_scalar_bases::<G1Affine, Fr>(&mut rng, msm_size);
+            // Ensure the carries between windows and the terms that are zero are handled.
+            let edge_cases = [Fr::zero(), Fr::one(), -Fr::one(), Fr::from(u64::MAX), -Fr::from(2u8).pow([200])];
+            for (scalar, edge_case) in scalars.iter_mut().zip(edge_cases) {
+                *scalar = edge_case.to_bigint();
+            }
+            bases[msm_size - 1] = G1Affine::zero();
+
+            let naive = VariableBase::msm_naive(bases.as_slice(), scalars.as_slice()).to_affine();
+            let candidate = standard::msm(bases.as_slice(), scalars.as_slice()).to_affine();
+            assert_eq!(naive, candidate, "MSM size: {msm_size}");
+            let candidate = batched::msm(bases.as_slice(), scalars.as_slice()).to_affine();
+            assert_eq!(naive, candidate, "MSM size: {msm_size}");
+        }
+
+        // Ensure a base is cancelled by its negation in the same bucket of the batched MSM,
+        // where the lowest digits of `3` and `2^c - 3` are `3` and `-3`.
+        let (mut bases, mut scalars) = create_scalar_bases::<G1Affine, Fr>(&mut rng, 50);
+        let c = crate::msm::ln_without_floats(50) + 2;
+        bases[1] = bases[0];
+        scalars[0] = Fr::from(3u8).to_bigint();
+        scalars[1] = Fr::from((1u64 << c) - 3).to_bigint();
+        let naive = VariableBase::msm_naive(bases.as_slice(), scalars.as_slice()).to_affine();
+        assert_eq!(naive, batched::msm(bases.as_slice(), scalars.as_slice()).to_affine());
+    }
+
+    #[test]
+    fn test_msm_batch() {
+        let mut rng = TestRng::default();
+        let batch =
+            [0, 1, 50, 1000, 3000].map(|msm_size| create_scalar_bases::<G1Affine, Fr>(&mut rng, msm_size)).to_vec();
+        let bases = batch.iter().map(|(bases, _)| bases.as_slice()).collect::<Vec<_>>();
+        let scalars = batch.iter().map(|(_, scalars)| scalars.as_slice()).collect::<Vec<_>>();
+
+        let candidates = VariableBase::msm_batch(&bases, &scalars);
+        assert_eq!(candidates.len(), batch.len());
+        for ((bases, scalars), candidate) in batch.iter().zip(candidates) {
+            assert_eq!(VariableBase::msm(bases, scalars), candidate, "MSM size: {}", bases.len());
+        }
+        assert!(VariableBase::msm_batch::<G1Affine>(&[], &[]).is_empty());
+    }
+
+    #[test]
+    fn test_msm_streaming() {
+        let mut rng = TestRng::default();
+        let (bases, scalars) = create_scalar_bases::<G1Affine, Fr>(&mut rng, 1000);
+        let expected = VariableBase::msm(&bases, &scalars);
+
+        let terms = || bases.iter().copied().zip(scalars.iter().copied());
+        assert_eq!(VariableBase::msm_streaming(terms()), expected);
+        for chunk_size in [1, 7, 256, 1000, 1001] {
+            assert_eq!(VariableBase::msm_streaming_with_chunk_size(terms(), chunk_size), expected);
+        }
+        assert!(VariableBase::msm_streaming::<G1Affine>(core::iter::empty()).is_zero());
+    }
+
     #[cfg(all(feature = "cuda", target_arch = "x86_64"))]
     #[test]
     fn test_msm_cuda() {
@@ -116,4 +503,71 @@ mod tests {
             assert_eq!(rust.to_affine(), cuda.to_affine());
         }
     }
+
+    #[cfg(all(feature = "metal", target_os = "macos"))]
+    #[test]
+    fn test_msm_metal() {
+        use snarkvm_curves::ProjectiveCurve;
+
+        let mut rng = TestRng::default();
+        for i in 2..17 {
+            let (mut bases, mut scalars) = create_scalar_bases::<G1Affine, Fr>(&mut rng, 1 << i);
+            // Ensure the terms that are zero are skipped.
+            bases[0] = G1Affine::zero();
+            scalars[1] = Default::default();
+            let rust = standard::msm(bases.as_slice(), scalars.as_slice());
+            let metal = metal::msm::<G1Affine>(bases.as_slice(), scalars.as_slice()).unwrap();
+            assert_eq!(rust.to_affine(), metal.to_affine());
+        }
+    }
+
+    #[cfg(all(feature = "webgpu", not(target_arch = "wasm32")))]
+    #[test]
+    fn test_msm_webgpu() {
+        use snarkvm_curves::ProjectiveCurve;
+
+        let mut rng = TestRng::default();
+        for i in 2..17 {
+            let (mut bases, mut scalars) = create_scalar_bases::<G1Affine, Fr>(&mut rng, 1 << i);
+            // Ensure the terms that are zero are skipped.
+            bases[0] = G1Affine::zero();
+            scalars[1] = Default::default();
+            let rust = standard::msm(bases.as_slice(), scalars.as_slice());
+            let webgpu = webgpu::msm_blocking::<G1Affine>(bases.as_slice(), scalars.as_slice()).unwrap();
+            assert_eq!(rust.to_affine(), webgpu.to_affine());
+        }
+    }
+
+    #[cfg(all(feature = "cuda", target_arch = "x86_64"))]
+    #[test]
+    fn test_msm_cuda_hybrid() {
+        let mut rng = TestRng::default();
+        let (bases, scalars) = create_scalar_bases::<G1Affine, Fr>(&mut rng, 1 << 14);
+        let rust = standard::msm(bases.as_slice(), scalars.as_slice());
+
+        let share = VariableBase::gpu_share();
+        for gpu_share in [0.0, 0.3, 0.5, 1.0] {
+            VariableBase::set_gpu_share(gpu_share);
+            let hybrid = VariableBase::msm::<G1Affine>(bases.as_slice(), scalars.as_slice());
+            assert_eq!(rust.to_affine(), hybrid.to_affine(), "GPU share: {gpu_share}");
+        }
+        VariableBase::set_gpu_share(share);
+
+        let share = VariableBase::calibrate_gpu_share(bases.as_slice(), scalars.as_slice());
+        assert!((0.0..=1.0).contains(&share));
+        assert_eq!(VariableBase::gpu_share(), (share * 1e6).round() / 1e6);
+        VariableBase::set_gpu_share(1.0);
+    }
+
+    #[cfg(all(feature = "cuda", target_arch = "x86_64"))]
+    #[test]
+    fn test_msm_cuda_g2() {
+        let mut rng = TestRng::default();
+        for i in 10..14 {
+            let (bases, scalars) = create_scalar_bases::<G2Affine, Fr>(&mut rng, 1 << i);
+            let rust = standard::msm(bases.as_slice(), scalars.as_slice());
+            let cuda = VariableBase::msm::<G2Affine>(bases.as_slice(), scalars.as_slice());
+            assert_eq!(rust.to_affine(), cuda.to_affine());
+        }
+    }
 }
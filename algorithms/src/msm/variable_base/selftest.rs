@@ -0,0 +1,168 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! A self-test of the GPU MSM backend, which validates it against the CPU,
+//! e.g. after a driver or toolkit upgrade, and measures its throughput.
+
+use super::{batched, VariableBase};
+use snarkvm_curves::{
+    bls12_377::{Fr, G1Affine, G1Projective},
+    ProjectiveCurve,
+};
+use snarkvm_fields::{PrimeField, Zero};
+use snarkvm_utilities::Uniform;
+
+use anyhow::{bail, Result};
+use core::{ops::RangeInclusive, time::Duration};
+use rand::Rng;
+use std::time::Instant;
+
+/// The number of random points from which the bases of each MSM are derived.
+const NUM_RANDOM_POINTS: usize = 64;
+
+/// The outcome of the self-test for one MSM size.
+#[derive(Clone, Debug)]
+pub struct GpuSelfTestResult {
+    /// The number of terms in the MSM.
+    pub num_terms: usize,
+    /// Whether the GPU returned the same result as the CPU.
+    pub passed: bool,
+    /// The error reported by the GPU backend, if it failed.
+    pub error: Option<String>,
+    /// The time taken by the GPU backend, including the transfers.
+    pub gpu_time: Duration,
+    /// The time taken by the CPU.
+    pub cpu_time: Duration,
+}
+
+impl GpuSelfTestResult {
+    /// Returns the throughput of the GPU backend, in terms per second.
+    pub fn gpu_throughput(&self) -> f64 {
+        self.num_terms as f64 / self.gpu_time.as_secs_f64()
+    }
+
+    /// Returns the throughput of the CPU, in terms per second.
+    pub fn cpu_throughput(&self) -> f64 {
+        self.num_terms as f64 / self.cpu_time.as_secs_f64()
+    }
+}
+
+/// Computes the MSM on the enabled GPU backend, without falling back to the CPU.
+#[allow(unreachable_code, unused_variables)]
+fn gpu_msm(bases: &[G1Affine], scalars: &[<Fr as PrimeField>::BigInteger]) -> Result<G1Projective> {
+    #[cfg(all(feature = "cuda", target_arch = "x86_64"))]
+    return super::cuda_msm(bases, scalars).ok_or_else(|| anyhow::anyhow!("The CUDA MSM failed"));
+    #[cfg(all(feature = "metal", target_os = "macos"))]
+    return super::metal::msm(bases, scalars);
+    #[cfg(all(feature = "webgpu", not(target_arch = "wasm32")))]
+    return super::webgpu::msm_blocking(bases, scalars);
+    bail!("No GPU backend is enabled")
+}
+
+/// Samples `num_terms` random terms, including the edge cases handled separately by the GPU backends:
+/// a base at infinity, a zero scalar, a repeated base, and a base with its negation.
+fn sample_terms<R: Rng>(num_terms: usize, rng: &mut R) -> (Vec<G1Affine>, Vec<<Fr as PrimeField>::BigInteger>) {
+    // Deriving the bases from a few random points is much faster than sampling each one,
+    // while still placing distinct points in each bucket.
+    let points = (0..NUM_RANDOM_POINTS).map(|_| G1Projective::rand(rng)).collect::<Vec<_>>();
+    let mut base = G1Projective::zero();
+    let bases = (0..num_terms)
+        .map(|_| {
+            base += points[rng.gen_range(0..NUM_RANDOM_POINTS)];
+            base
+        })
+        .collect::<Vec<_>>();
+    let mut bases = G1Projective::batch_normalization_into_affine(bases);
+    let mut scalars = (0..num_terms).map(|_| Fr::rand(rng).to_bigint()).collect::<Vec<_>>();
+
+    if num_terms >= 6 {
+        bases[0] = G1Affine::zero();
+        scalars[1] = Default::default();
+        bases[2] = bases[3];
+        scalars[2] = scalars[3];
+        bases[4] = -bases[5];
+    }
+    (bases, scalars)
+}
+
+impl VariableBase {
+    /// Returns the name of the GPU backend that BLS12-377 G1 MSMs are dispatched to, if any.
+    #[allow(unreachable_code)]
+    pub fn gpu_backend() -> Option<&'static str> {
+        #[cfg(all(feature = "cuda", target_arch = "x86_64"))]
+        return Some("cuda");
+        #[cfg(all(feature = "metal", target_os = "macos"))]
+        return Some("metal");
+        #[cfg(all(feature = "webgpu", not(target_arch = "wasm32")))]
+        return Some("webgpu");
+        None
+    }
+
+    /// Runs a randomized BLS12-377 G1 MSM of `2^i` terms for each `i` in `log_sizes` on the GPU backend,
+    /// and checks each one against the CPU, returning the outcome and throughput for each size.
+    ///
+    /// A failure of the GPU is reported in the outcome rather than retried on the CPU.
+    pub fn gpu_selftest<R: Rng>(log_sizes: RangeInclusive<u32>, rng: &mut R) -> Vec<GpuSelfTestResult> {
+        log_sizes
+            .map(|log_size| {
+                let num_terms = 1 << log_size;
+                let (bases, scalars) = sample_terms(num_terms, rng);
+
+                let start = Instant::now();
+                let gpu = gpu_msm(&bases, &scalars);
+                let gpu_time = start.elapsed();
+
+                let start = Instant::now();
+                let cpu = batched::msm(&bases, &scalars);
+                let cpu_time = start.elapsed();
+
+                let (passed, error) = match gpu {
+                    Ok(gpu) => (gpu == cpu, None),
+                    Err(error) => (false, Some(error.to_string())),
+                };
+                GpuSelfTestResult { num_terms, passed, error, gpu_time, cpu_time }
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_curves::AffineCurve;
+    use snarkvm_utilities::rand::TestRng;
+
+    #[test]
+    fn test_sample_terms() {
+        let mut rng = TestRng::default();
+        let (bases, scalars) = sample_terms(100, &mut rng);
+        assert_eq!(bases.len(), 100);
+        assert_eq!(scalars.len(), 100);
+        assert!(bases.iter().all(|base| base.is_on_curve() && base.is_in_correct_subgroup_assuming_on_curve()));
+        assert_eq!(batched::msm(&bases, &scalars), VariableBase::msm_naive(&bases, &scalars));
+    }
+
+    #[test]
+    fn test_gpu_selftest() {
+        let mut rng = TestRng::default();
+        let results = VariableBase::gpu_selftest(8..=12, &mut rng);
+        assert_eq!(results.iter().map(|result| result.num_terms).collect::<Vec<_>>(), [256, 512, 1024, 2048, 4096]);
+        for result in results {
+            match VariableBase::gpu_backend() {
+                Some(_) => assert!(result.passed, "The self-test failed for {} terms", result.num_terms),
+                None => assert!(!result.passed && result.error.is_some()),
+            }
+        }
+    }
+}
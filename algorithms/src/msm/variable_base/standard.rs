@@ -13,66 +13,54 @@
 // limitations under the License.
 
 use snarkvm_curves::{AffineCurve, ProjectiveCurve};
-use snarkvm_fields::{One, PrimeField, Zero};
-use snarkvm_utilities::{cfg_into_iter, BigInteger};
+use snarkvm_fields::{PrimeField, Zero};
+use snarkvm_utilities::{cfg_chunks_mut, cfg_into_iter, cfg_iter, BigInteger};
 
 #[cfg(not(feature = "serial"))]
 use rayon::prelude::*;
 
-fn update_buckets<G: AffineCurve>(
-    base: &G,
-    mut scalar: <G::ScalarField as PrimeField>::BigInteger,
-    w_start: usize,
-    c: usize,
-    buckets: &mut [G::Projective],
-) {
-    // We right-shift by w_start, thus getting rid of the lower bits.
-    scalar.divn(w_start as u32);
-
-    // We mod the remaining bits by the window size.
-    let scalar = scalar.as_ref()[0] % (1 << c);
-
-    // If the scalar is non-zero, we update the corresponding bucket.
-    // (Recall that `buckets` doesn't have a zero bucket.)
-    if scalar != 0 {
-        buckets[(scalar - 1) as usize].add_assign_mixed(base);
+/// Writes the signed base-`2^c` digits of `scalar` into `digits`, from the lowest window to the highest.
+///
+/// Each digit is in `[-2^(c - 1), 2^(c - 1)]`, where a digit above `2^(c - 1)` is replaced by
+/// its difference with `2^c`, carrying one into the next window.
+fn signed_digits(limbs: &[u64], c: usize, digits: &mut [i32]) {
+    let (radix, half) = (1i64 << c, 1i64 << (c - 1));
+    let mut carry = 0;
+    for (w, digit) in digits.iter_mut().enumerate() {
+        let (limb, shift) = (w * c / 64, w * c % 64);
+        let mut bits = limbs.get(limb).map_or(0, |limb| limb >> shift);
+        if shift + c > 64 {
+            bits |= limbs.get(limb + 1).map_or(0, |limb| limb << (64 - shift));
+        }
+        let value = (bits & ((1 << c) - 1)) as i64 + carry;
+        (*digit, carry) = match value > half {
+            true => ((value - radix) as i32, 1),
+            false => (value as i32, 0),
+        };
     }
 }
 
-fn standard_window<G: AffineCurve>(
-    bases: &[G],
-    scalars: &[<G::ScalarField as PrimeField>::BigInteger],
-    w_start: usize,
-    c: usize,
-) -> (G::Projective, usize) {
-    let mut res = G::Projective::zero();
-    let fr_one = G::ScalarField::one().to_bigint();
-
-    // We only process unit scalars once in the first window.
-    if w_start == 0 {
-        scalars.iter().zip(bases).filter(|(&s, _)| s == fr_one).for_each(|(_, base)| {
-            res.add_assign_mixed(base);
-        });
+/// Returns the sum of the bases weighted by their digits in window `w`.
+fn signed_window<G: AffineCurve>(bases: &[G], digits: &[i32], num_windows: usize, w: usize, c: usize) -> G::Projective {
+    // As the digits are signed, there is one bucket per magnitude in `[1, 2^(c - 1)]`.
+    let mut buckets = vec![G::Projective::zero(); 1 << (c - 1)];
+    for (base, digits) in bases.iter().zip(digits.chunks(num_windows)) {
+        let digit = digits[w];
+        if digit > 0 {
+            buckets[(digit - 1) as usize].add_assign_mixed(base);
+        } else if digit < 0 {
+            buckets[(-digit - 1) as usize].add_assign_mixed(&-*base);
+        }
     }
 
-    // We don't need the "zero" bucket, so we only have 2^c - 1 buckets
-    let window_size = if (w_start % c) != 0 { w_start % c } else { c };
-    let mut buckets = vec![G::Projective::zero(); (1 << window_size) - 1];
-    scalars
-        .iter()
-        .zip(bases)
-        .filter(|(&s, _)| s > fr_one)
-        .for_each(|(&scalar, base)| update_buckets(base, scalar, w_start, c, &mut buckets));
-    // G::Projective::batch_normalization(&mut buckets);
-
-    for running_sum in buckets.into_iter().rev().scan(G::Projective::zero(), |sum, b| {
-        *sum += b;
-        Some(*sum)
-    }) {
+    // Compute the sum of `(i + 1) * buckets[i]` with running sums.
+    let mut res = G::Projective::zero();
+    let mut running_sum = G::Projective::zero();
+    for bucket in buckets.into_iter().rev() {
+        running_sum += bucket;
         res += running_sum;
     }
-
-    (res, window_size)
+    res
 }
 
 pub fn msm<G: AffineCurve>(bases: &[G], scalars: &[<G::ScalarField as PrimeField>::BigInteger]) -> G::Projective {
@@ -82,23 +70,26 @@ pub fn msm<G: AffineCurve>(bases: &[G], scalars: &[<G::ScalarField as PrimeField
         false => crate::msm::ln_without_floats(scalars.len()) + 2,
     };
 
+    // Each window is of size `c`, with one more window for the carry out of the highest bits.
     let num_bits = <G::ScalarField as PrimeField>::size_in_bits();
+    let num_windows = num_bits / c + 1;
 
-    // Each window is of size `c`.
-    // We divide up the bits 0..num_bits into windows of size `c`, and
-    // in parallel process each such window.
-    let window_sums: Vec<_> =
-        cfg_into_iter!(0..num_bits).step_by(c).map(|w_start| standard_window(bases, scalars, w_start, c)).collect();
+    // Recode the scalars into signed digits. The zero scalars are skipped, as their digits are all zero.
+    let mut digits = vec![0i32; scalars.len() * num_windows];
+    cfg_chunks_mut!(digits, num_windows)
+        .zip(cfg_iter!(scalars))
+        .filter(|(_, scalar)| !scalar.is_zero())
+        .for_each(|(digits, scalar)| signed_digits(scalar.as_ref(), c, digits));
 
-    // We store the sum for the lowest window.
-    let (lowest, window_sums) = window_sums.split_first().unwrap();
+    // We process each window in parallel.
+    let window_sums: Vec<_> =
+        cfg_into_iter!(0..num_windows).map(|w| signed_window(bases, &digits, num_windows, w, c)).collect();
 
     // We're traversing windows from high to low.
-    window_sums.iter().rev().fold(G::Projective::zero(), |mut total, (sum_i, window_size)| {
-        total += sum_i;
-        for _ in 0..*window_size {
+    window_sums.iter().rev().fold(G::Projective::zero(), |mut total, sum_i| {
+        for _ in 0..c {
             total.double_in_place();
         }
-        total
-    }) + lowest.0
+        total + sum_i
+    })
 }
@@ -0,0 +1,76 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Reports the GPU MSMs to the `metrics` recorder and the `tracing` subscriber, when those features are enabled.
+
+use core::fmt::Display;
+use std::time::Instant;
+
+/// Runs the GPU MSM `f` over `num_terms` terms, which transfers `transfer_bytes` bytes to the device,
+/// and records its latency and outcome for the given backend.
+/// The WebGPU MSM is asynchronous and is not observed.
+#[allow(unused_variables)]
+#[cfg_attr(
+    not(any(all(feature = "cuda", target_arch = "x86_64"), all(feature = "metal", target_os = "macos"))),
+    allow(dead_code)
+)]
+pub(super) fn observe_gpu_msm<T, E: Display>(
+    backend: &'static str,
+    num_terms: usize,
+    transfer_bytes: usize,
+    f: impl FnOnce() -> Result<T, E>,
+) -> Result<T, E> {
+    #[cfg(feature = "instrument")]
+    let _span = tracing::debug_span!("msm::gpu", backend, num_terms, transfer_bytes).entered();
+
+    let start = Instant::now();
+    let result = f();
+    let elapsed = start.elapsed();
+
+    #[cfg(feature = "instrument")]
+    match &result {
+        Ok(_) => tracing::debug!("GPU MSM completed in {elapsed:?}"),
+        Err(error) => tracing::debug!("GPU MSM failed after {elapsed:?}: {error}"),
+    }
+
+    #[cfg(feature = "metrics")]
+    {
+        use snarkvm_metrics::{histogram_label, increment_counter, msm};
+
+        increment_counter(msm::GPU_MSMS);
+        if result.is_err() {
+            increment_counter(msm::GPU_MSM_FAILURES);
+        }
+        histogram_label(msm::GPU_MSM_LATENCY, "backend", backend.to_string(), elapsed.as_secs_f64());
+        histogram_label(msm::GPU_MSM_TERMS, "backend", backend.to_string(), num_terms as f64);
+        histogram_label(msm::GPU_MSM_TRANSFER_BYTES, "backend", backend.to_string(), transfer_bytes as f64);
+    }
+
+    result
+}
+
+/// Records the number of terms in the fullest bucket of a GPU MSM, which bounds the time of the bucket kernel.
+#[allow(unused_variables)]
+pub(super) fn observe_bucket_occupancy(backend: &'static str, max_occupancy: usize) {
+    #[cfg(feature = "instrument")]
+    tracing::trace!("GPU MSM has at most {max_occupancy} terms in a bucket");
+
+    #[cfg(feature = "metrics")]
+    snarkvm_metrics::histogram_label(
+        snarkvm_metrics::msm::GPU_MSM_BUCKET_OCCUPANCY,
+        "backend",
+        backend.to_string(),
+        max_occupancy as f64,
+    );
+}
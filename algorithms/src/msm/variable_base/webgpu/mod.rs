@@ -0,0 +1,353 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! An experimental bucket-method MSM for BLS12-377 G1 on WebGPU, for provers running in the browser.
+//!
+//! The kernels mirror those of the Metal backend, with the field arithmetic restricted to 32-bit integers.
+//! In the browser, the result is only available once the event loop has run, so the MSM is asynchronous.
+
+use super::buckets::{combine_segments, sort_windows, SortedBuckets};
+use snarkvm_curves::{
+    bls12_377::{Fq, G1Affine, G1Projective},
+    AffineCurve,
+    AffineLimbs,
+};
+use snarkvm_fields::{PrimeField, Zero};
+use snarkvm_utilities::{biginteger::BigInteger384, BigInteger};
+
+use anyhow::{anyhow, bail, ensure, Result};
+use core::{
+    any::Any,
+    future::Future,
+    pin::Pin,
+    task::{Context, Poll, Waker},
+};
+use parking_lot::Mutex;
+use std::sync::Arc;
+#[cfg(not(target_arch = "wasm32"))]
+use std::sync::OnceLock;
+#[cfg(target_arch = "wasm32")]
+use std::{cell::RefCell, rc::Rc};
+use wgpu::util::DeviceExt;
+
+/// The source of the compute kernels, which is compiled when the context is first loaded.
+const SOURCE: &str = include_str!("msm.wgsl");
+
+/// The number of buckets reduced by each thread. This must be a power of two,
+/// and at most the number of buckets of the smallest window.
+const SEGMENT_SIZE: usize = 64;
+/// The bounds on the window width, in bits. The upper bound keeps the buckets
+/// within the default storage buffer binding size of 128 MiB.
+const MIN_WINDOW_BITS: usize = 8;
+const MAX_WINDOW_BITS: usize = 14;
+/// The number of threads in a workgroup, which must match `@workgroup_size` in the kernels.
+const WORKGROUP_SIZE: usize = 64;
+
+/// The size of a BLS12-377 G1 affine point in the kernels, which is two base field elements.
+const AFFINE_SIZE: usize = 2 * 48;
+/// The size of a BLS12-377 G1 Jacobian point in the kernels, which is three base field elements.
+const JACOBIAN_SIZE: usize = 3 * 48;
+
+/// Unpacks a Jacobian point written by the kernels, with the coordinates in Montgomery form,
+/// returning `None` if any of the limbs are not canonical.
+fn read_jacobian(bytes: &[u8]) -> Option<G1Projective> {
+    let mut limbs = bytes.chunks_exact(8).map(|limb| u64::from_le_bytes(limb.try_into().unwrap()));
+    let mut fq = || {
+        let mut coordinate = [0u64; 6];
+        coordinate.iter_mut().zip(limbs.by_ref()).for_each(|(limb, value)| *limb = value);
+        Fq::from_montgomery_bigint(BigInteger384(coordinate))
+    };
+    Some(G1Projective::new(fq()?, fq()?, fq()?))
+}
+
+/// Returns the little-endian bytes of the given words.
+fn to_bytes(words: &[u32]) -> Vec<u8> {
+    words.iter().flat_map(|word| word.to_le_bytes()).collect()
+}
+
+/// The device, queue and compiled kernels.
+struct WebGpuContext {
+    device: wgpu::Device,
+    queue: wgpu::Queue,
+    accumulate: wgpu::ComputePipeline,
+    reduce: wgpu::ComputePipeline,
+}
+
+/// The context of the process, which is loaded on first use. A failure to load it is remembered as well.
+#[cfg(not(target_arch = "wasm32"))]
+static CONTEXT: OnceLock<Result<Arc<WebGpuContext>, String>> = OnceLock::new();
+
+#[cfg(target_arch = "wasm32")]
+thread_local! {
+    /// The context of this thread. The WebGPU objects of the browser cannot be shared between threads,
+    /// so the context is loaded once per thread, and a failure to load it is remembered as well.
+    static CONTEXT: RefCell<Option<Result<Rc<WebGpuContext>, String>>> = const { RefCell::new(None) };
+}
+
+impl WebGpuContext {
+    /// Returns the context of the process, loading it on the first call.
+    #[cfg(not(target_arch = "wasm32"))]
+    async fn get() -> Result<Arc<Self>> {
+        // Requesting the adapter and device completes immediately on native targets, so the context is
+        // loaded by blocking, which ensures every thread shares one device and one set of compiled kernels.
+        let context =
+            CONTEXT.get_or_init(|| super::job::block_on(Self::load()).map(Arc::new).map_err(|error| error.to_string()));
+        context.clone().map_err(|error| anyhow!("{error}"))
+    }
+
+    /// Returns the context of this thread, loading it on the first call on this thread.
+    #[cfg(target_arch = "wasm32")]
+    async fn get() -> Result<Rc<Self>> {
+        let context = match CONTEXT.with(|context| context.borrow().clone()) {
+            Some(context) => context,
+            None => {
+                let context = Self::load().await.map(Rc::new).map_err(|error| error.to_string());
+                CONTEXT.with(|cell| *cell.borrow_mut() = Some(context.clone()));
+                context
+            }
+        };
+        context.map_err(|error| anyhow!("{error}"))
+    }
+
+    /// Selects a high-performance adapter, and compiles the kernels for it.
+    async fn load() -> Result<Self> {
+        let instance = wgpu::Instance::default();
+        let options = wgpu::RequestAdapterOptions {
+            power_preference: wgpu::PowerPreference::HighPerformance,
+            ..Default::default()
+        };
+        let adapter =
+            instance.request_adapter(&options).await.ok_or_else(|| anyhow!("No WebGPU adapter is available"))?;
+        let descriptor = wgpu::DeviceDescriptor {
+            label: Some("snarkvm-msm"),
+            required_features: wgpu::Features::empty(),
+            required_limits: adapter.limits(),
+        };
+        let (device, queue) = adapter.request_device(&descriptor, None).await?;
+
+        let module = device.create_shader_module(wgpu::ShaderModuleDescriptor {
+            label: Some("msm.wgsl"),
+            source: wgpu::ShaderSource::Wgsl(SOURCE.into()),
+        });
+        let pipeline = |entry_point: &str| {
+            device.create_compute_pipeline(&wgpu::ComputePipelineDescriptor {
+                label: Some(entry_point),
+                layout: None,
+                module: &module,
+                entry_point,
+            })
+        };
+        let accumulate = pipeline("msm_accumulate_buckets");
+        let reduce = pipeline("msm_reduce_segments");
+        Ok(Self { device, queue, accumulate, reduce })
+    }
+
+    /// Returns a storage buffer holding a copy of `contents`, which must be non-empty.
+    fn buffer_with_data(&self, contents: &[u8], usage: wgpu::BufferUsages) -> Result<wgpu::Buffer> {
+        self.check_size(contents.len())?;
+        Ok(self.device.create_buffer_init(&wgpu::util::BufferInitDescriptor { label: None, contents, usage }))
+    }
+
+    /// Returns an uninitialized buffer of `size` bytes.
+    fn buffer(&self, size: usize, usage: wgpu::BufferUsages) -> Result<wgpu::Buffer> {
+        self.check_size(size)?;
+        Ok(self.device.create_buffer(&wgpu::BufferDescriptor {
+            label: None,
+            size: size as u64,
+            usage,
+            mapped_at_creation: false,
+        }))
+    }
+
+    /// Ensures a buffer of `size` bytes may be bound to the kernels.
+    fn check_size(&self, size: usize) -> Result<()> {
+        let limit = self.device.limits().max_storage_buffer_binding_size as usize;
+        ensure!(size <= limit, "The WebGPU buffer of {size} bytes exceeds the limit of {limit} bytes");
+        Ok(())
+    }
+
+    /// Encodes a dispatch of `pipeline` over `num_threads` threads, with the given buffers bound by their binding index.
+    fn dispatch(
+        &self,
+        encoder: &mut wgpu::CommandEncoder,
+        pipeline: &wgpu::ComputePipeline,
+        buffers: &[(u32, &wgpu::Buffer)],
+        num_threads: usize,
+    ) -> Result<()> {
+        let num_workgroups = (num_threads + WORKGROUP_SIZE - 1) / WORKGROUP_SIZE;
+        let limit = self.device.limits().max_compute_workgroups_per_dimension as usize;
+        ensure!(
+            num_workgroups <= limit,
+            "The WebGPU dispatch of {num_workgroups} workgroups exceeds the limit of {limit}"
+        );
+
+        let entries = buffers
+            .iter()
+            .map(|(binding, buffer)| wgpu::BindGroupEntry { binding: *binding, resource: buffer.as_entire_binding() })
+            .collect::<Vec<_>>();
+        let bind_group = self.device.create_bind_group(&wgpu::BindGroupDescriptor {
+            label: None,
+            layout: &pipeline.get_bind_group_layout(0),
+            entries: &entries,
+        });
+        let mut pass = encoder.begin_compute_pass(&wgpu::ComputePassDescriptor::default());
+        pass.set_pipeline(pipeline);
+        pass.set_bind_group(0, &bind_group, &[]);
+        pass.dispatch_workgroups(num_workgroups as u32, 1, 1);
+        Ok(())
+    }
+}
+
+/// The state shared between a `MapFuture` and the callback of `map_async`.
+#[derive(Default)]
+struct MapState {
+    result: Option<Result<(), wgpu::BufferAsyncError>>,
+    waker: Option<Waker>,
+}
+
+/// A future that resolves once a buffer is mapped for reading.
+///
+/// On native targets, the mapping completes when the device is polled, while in the browser it
+/// completes once control returns to the event loop.
+struct MapFuture(Arc<Mutex<MapState>>);
+
+impl MapFuture {
+    fn new(slice: wgpu::BufferSlice<'_>) -> Self {
+        let state = Arc::new(Mutex::new(MapState::default()));
+        let callback_state = state.clone();
+        slice.map_async(wgpu::MapMode::Read, move |result| {
+            let waker = {
+                let mut state = callback_state.lock();
+                state.result = Some(result);
+                state.waker.take()
+            };
+            if let Some(waker) = waker {
+                waker.wake();
+            }
+        });
+        Self(state)
+    }
+}
+
+impl Future for MapFuture {
+    type Output = Result<(), wgpu::BufferAsyncError>;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        let mut state = self.0.lock();
+        match state.result.take() {
+            Some(result) => Poll::Ready(result),
+            None => {
+                state.waker = Some(cx.waker().clone());
+                Poll::Pending
+            }
+        }
+    }
+}
+
+/// Computes the MSM on a WebGPU device. The points must be BLS12-377 G1 affine points.
+pub(super) async fn msm<G: AffineCurve>(
+    bases: &[G],
+    scalars: &[<G::ScalarField as PrimeField>::BigInteger],
+) -> Result<G::Projective> {
+    let context = WebGpuContext::get().await?;
+
+    // Skip the terms that are zero, as the kernels do not represent the point at infinity.
+    let mut points = Vec::with_capacity(bases.len() * AFFINE_SIZE);
+    let mut nonzero_scalars = Vec::new();
+    for (base, scalar) in bases.iter().zip(scalars) {
+        let base = (base as &dyn Any).downcast_ref::<G1Affine>().ok_or_else(|| anyhow!("Expected a G1 point"))?;
+        if !base.is_zero() && !scalar.is_zero() {
+            let (x, y) = base.to_xy_limbs();
+            points.extend(x.0.iter().chain(&y.0).flat_map(|limb| limb.to_le_bytes()));
+            nonzero_scalars.push(*scalar);
+        }
+    }
+    let num_points = nonzero_scalars.len();
+    ensure!(num_points <= u32::MAX as usize, "The MSM of {num_points} terms is too large for WebGPU");
+
+    let mut result = G1Projective::zero();
+    if num_points > 0 {
+        // Determine the window width, so that each bucket holds a few points on average.
+        let c = (num_points.ilog2() as usize).saturating_sub(4).clamp(MIN_WINDOW_BITS, MAX_WINDOW_BITS);
+        let num_bits = <G::ScalarField as PrimeField>::size_in_bits();
+        let num_windows = (num_bits + c - 1) / c;
+        let num_buckets = 1 << c;
+        let num_segments = num_windows * num_buckets / SEGMENT_SIZE;
+
+        // Sort the points of each window into buckets, and concatenate the windows.
+        let SortedBuckets { offsets, indices, max_occupancy } = sort_windows(&nonzero_scalars, num_windows, c);
+        super::telemetry::observe_bucket_occupancy("webgpu", max_occupancy);
+        ensure!(indices.len() <= u32::MAX as usize, "The MSM of {num_points} terms is too large for WebGPU");
+
+        let params = [num_windows as u32, num_buckets as u32, SEGMENT_SIZE as u32, 0];
+        let storage = wgpu::BufferUsages::STORAGE;
+        let points = context.buffer_with_data(&points, storage)?;
+        let indices = context.buffer_with_data(&to_bytes(&indices), storage)?;
+        let offsets = context.buffer_with_data(&to_bytes(&offsets), storage)?;
+        let params = context.buffer_with_data(&to_bytes(&params), wgpu::BufferUsages::UNIFORM)?;
+        let buckets = context.buffer(num_windows * num_buckets * JACOBIAN_SIZE, storage)?;
+        let output_size = num_segments * JACOBIAN_SIZE;
+        let sums = context.buffer(output_size, storage | wgpu::BufferUsages::COPY_SRC)?;
+        let weighted = context.buffer(output_size, storage | wgpu::BufferUsages::COPY_SRC)?;
+        let staging = context.buffer(2 * output_size, wgpu::BufferUsages::MAP_READ | wgpu::BufferUsages::COPY_DST)?;
+
+        // Sum the buckets, and then reduce them in segments.
+        let mut encoder = context.device.create_command_encoder(&wgpu::CommandEncoderDescriptor::default());
+        context.dispatch(
+            &mut encoder,
+            &context.accumulate,
+            &[(0, &points), (1, &indices), (2, &offsets), (3, &buckets), (4, &params)],
+            num_windows * num_buckets,
+        )?;
+        context.dispatch(
+            &mut encoder,
+            &context.reduce,
+            &[(3, &buckets), (4, &params), (5, &sums), (6, &weighted)],
+            num_segments,
+        )?;
+        encoder.copy_buffer_to_buffer(&sums, 0, &staging, 0, output_size as u64);
+        encoder.copy_buffer_to_buffer(&weighted, 0, &staging, output_size as u64, output_size as u64);
+        context.queue.submit(Some(encoder.finish()));
+
+        // Read back the segments. Polling the device is a no-op in the browser.
+        let slice = staging.slice(..);
+        let mapped = MapFuture::new(slice);
+        let _ = context.device.poll(wgpu::Maintain::Wait);
+        mapped.await?;
+        let segments =
+            slice.get_mapped_range().chunks_exact(JACOBIAN_SIZE).map(read_jacobian).collect::<Option<Vec<_>>>();
+        staging.unmap();
+        let Some(segments) = segments else {
+            bail!("The WebGPU MSM returned a non-canonical point");
+        };
+        let (sums, weighted) = segments.split_at(num_segments);
+
+        result = combine_segments(sums, weighted, num_windows, SEGMENT_SIZE, c);
+    }
+    (&mut Some(result) as &mut dyn Any)
+        .downcast_mut::<Option<G::Projective>>()
+        .and_then(Option::take)
+        .ok_or_else(|| anyhow!("The WebGPU MSM only supports BLS12-377 G1"))
+}
+
+/// Computes the MSM on a WebGPU device, blocking the current thread until it completes.
+///
+/// This is only available on native targets, as the browser requires control to return to its event loop.
+#[cfg(all(not(target_arch = "wasm32"), any(test, feature = "gpu-selftest")))]
+pub(super) fn msm_blocking<G: AffineCurve>(
+    bases: &[G],
+    scalars: &[<G::ScalarField as PrimeField>::BigInteger],
+) -> Result<G::Projective> {
+    super::job::block_on(msm(bases, scalars))
+}
@@ -0,0 +1,358 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+// Bucket-method MSM over the BLS12-377 G1 curve.
+//
+// The base field elements are stored in Montgomery form as 12 little-endian 32-bit limbs,
+// which matches the memory layout of the 6 little-endian 64-bit limbs used on the host.
+// WGSL has no 64-bit integers, so the limb products are computed from their 16-bit halves.
+
+struct Fq {
+    l: array<u32, 12>,
+}
+
+// An affine point, which is never the point at infinity.
+struct Affine {
+    x: Fq,
+    y: Fq,
+}
+
+// A point in Jacobian coordinates, which is the point at infinity iff `z` is zero.
+struct Jacobian {
+    x: Fq,
+    y: Fq,
+    z: Fq,
+}
+
+struct Params {
+    num_windows: u32,
+    num_buckets: u32,
+    segment_size: u32,
+    padding: u32,
+}
+
+@group(0) @binding(0) var<storage, read> points: array<Affine>;
+@group(0) @binding(1) var<storage, read> indices: array<u32>;
+@group(0) @binding(2) var<storage, read> offsets: array<u32>;
+@group(0) @binding(3) var<storage, read_write> buckets: array<Jacobian>;
+@group(0) @binding(4) var<uniform> params: Params;
+@group(0) @binding(5) var<storage, read_write> sums: array<Jacobian>;
+@group(0) @binding(6) var<storage, read_write> weighted: array<Jacobian>;
+
+// The base field modulus.
+var<private> MODULUS: array<u32, 12> = array<u32, 12>(
+    0x00000001u, 0x8508c000u, 0x30000000u, 0x170b5d44u, 0xba094800u, 0x1ef3622fu,
+    0x00f5138fu, 0x1a22d9f3u, 0x6ca1493bu, 0xc63b05c0u, 0x17c510eau, 0x01ae3a46u,
+);
+
+// -MODULUS^{-1} mod 2^32.
+const INV: u32 = 0xffffffffu;
+
+// R mod MODULUS, i.e. one in Montgomery form.
+var<private> ONE: array<u32, 12> = array<u32, 12>(
+    0xffffff68u, 0x02cdffffu, 0x7fffffb1u, 0x51409f83u, 0x8a7d3ff2u, 0x9f7db3a9u,
+    0x6e7c6305u, 0x7b4e97b7u, 0x803c84e8u, 0x4cf495bfu, 0xe2fdf49au, 0x008d6661u,
+);
+
+// Returns the low and high words of `a * b`.
+fn mul_wide(a: u32, b: u32) -> vec2<u32> {
+    let a0 = a & 0xffffu;
+    let a1 = a >> 16u;
+    let b0 = b & 0xffffu;
+    let b1 = b >> 16u;
+    let p00 = a0 * b0;
+    let p01 = a0 * b1;
+    let p10 = a1 * b0;
+    let p11 = a1 * b1;
+    let mid = p01 + p10;
+    let mid_carry = select(0u, 0x10000u, mid < p01);
+    let lo = p00 + (mid << 16u);
+    let lo_carry = select(0u, 1u, lo < p00);
+    return vec2<u32>(lo, p11 + (mid >> 16u) + mid_carry + lo_carry);
+}
+
+// Returns the low and high words of `t + a * b + carry`, which does not overflow 64 bits.
+fn mac(t: u32, a: u32, b: u32, carry: u32) -> vec2<u32> {
+    let product = mul_wide(a, b);
+    let lo = product.x + t;
+    let lo2 = lo + carry;
+    let hi = product.y + select(0u, 1u, lo < t) + select(0u, 1u, lo2 < lo);
+    return vec2<u32>(lo2, hi);
+}
+
+fn fq_zero() -> Fq {
+    var r: Fq;
+    for (var i = 0u; i < 12u; i++) {
+        r.l[i] = 0u;
+    }
+    return r;
+}
+
+fn fq_one() -> Fq {
+    var r: Fq;
+    for (var i = 0u; i < 12u; i++) {
+        r.l[i] = ONE[i];
+    }
+    return r;
+}
+
+fn fq_is_zero(a: Fq) -> bool {
+    var x = a;
+    var acc = 0u;
+    for (var i = 0u; i < 12u; i++) {
+        acc |= x.l[i];
+    }
+    return acc == 0u;
+}
+
+// Returns whether `a` is at least the modulus.
+fn fq_geq_modulus(a: Fq) -> bool {
+    var x = a;
+    for (var i = 11; i >= 0; i--) {
+        if (x.l[i] != MODULUS[i]) {
+            return x.l[i] > MODULUS[i];
+        }
+    }
+    return true;
+}
+
+fn fq_sub_modulus(a: Fq) -> Fq {
+    var r = a;
+    var borrow = 0u;
+    for (var i = 0u; i < 12u; i++) {
+        let t = r.l[i] - MODULUS[i];
+        let next = select(0u, 1u, r.l[i] < MODULUS[i]) + select(0u, 1u, t < borrow);
+        r.l[i] = t - borrow;
+        borrow = next;
+    }
+    return r;
+}
+
+// The modulus is less than 2^383, so the sum of two reduced elements does not overflow.
+fn fq_add(a: Fq, b: Fq) -> Fq {
+    var r = a;
+    var y = b;
+    var carry = 0u;
+    for (var i = 0u; i < 12u; i++) {
+        let t = r.l[i] + y.l[i];
+        let s = t + carry;
+        carry = select(0u, 1u, t < y.l[i]) + select(0u, 1u, s < t);
+        r.l[i] = s;
+    }
+    if (fq_geq_modulus(r)) {
+        return fq_sub_modulus(r);
+    }
+    return r;
+}
+
+fn fq_sub(a: Fq, b: Fq) -> Fq {
+    var r = a;
+    var y = b;
+    var borrow = 0u;
+    for (var i = 0u; i < 12u; i++) {
+        let t = r.l[i] - y.l[i];
+        let next = select(0u, 1u, r.l[i] < y.l[i]) + select(0u, 1u, t < borrow);
+        r.l[i] = t - borrow;
+        borrow = next;
+    }
+    if (borrow != 0u) {
+        var carry = 0u;
+        for (var i = 0u; i < 12u; i++) {
+            let t = r.l[i] + MODULUS[i];
+            let s = t + carry;
+            carry = select(0u, 1u, t < MODULUS[i]) + select(0u, 1u, s < t);
+            r.l[i] = s;
+        }
+    }
+    return r;
+}
+
+fn fq_double(a: Fq) -> Fq {
+    return fq_add(a, a);
+}
+
+// Montgomery multiplication with the CIOS method.
+fn fq_mul(a: Fq, b: Fq) -> Fq {
+    var x = a;
+    var y = b;
+    var t: array<u32, 14>;
+    for (var i = 0u; i < 14u; i++) {
+        t[i] = 0u;
+    }
+
+    for (var i = 0u; i < 12u; i++) {
+        var c = vec2<u32>(0u, 0u);
+        for (var j = 0u; j < 12u; j++) {
+            c = mac(t[j], x.l[j], y.l[i], c.y);
+            t[j] = c.x;
+        }
+        let s = t[12] + c.y;
+        t[13] = select(0u, 1u, s < c.y);
+        t[12] = s;
+
+        let m = t[0] * INV;
+        c = mac(t[0], m, MODULUS[0], 0u);
+        for (var j = 1u; j < 12u; j++) {
+            c = mac(t[j], m, MODULUS[j], c.y);
+            t[j - 1u] = c.x;
+        }
+        let u = t[12] + c.y;
+        t[11] = u;
+        t[12] = t[13] + select(0u, 1u, u < c.y);
+    }
+
+    var r: Fq;
+    for (var i = 0u; i < 12u; i++) {
+        r.l[i] = t[i];
+    }
+    if (fq_geq_modulus(r)) {
+        return fq_sub_modulus(r);
+    }
+    return r;
+}
+
+fn fq_square(a: Fq) -> Fq {
+    return fq_mul(a, a);
+}
+
+fn jacobian_zero() -> Jacobian {
+    return Jacobian(fq_zero(), fq_one(), fq_zero());
+}
+
+fn jacobian_is_zero(p: Jacobian) -> bool {
+    return fq_is_zero(p.z);
+}
+
+// http://www.hyperelliptic.org/EFD/g1p/auto-shortw-jacobian-0.html#doubling-dbl-2009-l
+fn jacobian_double(p: Jacobian) -> Jacobian {
+    if (jacobian_is_zero(p)) {
+        return p;
+    }
+
+    let a = fq_square(p.x);
+    let b = fq_square(p.y);
+    let c = fq_square(b);
+    let d = fq_double(fq_sub(fq_sub(fq_square(fq_add(p.x, b)), a), c));
+    let e = fq_add(fq_double(a), a);
+    let f = fq_square(e);
+
+    let z = fq_double(fq_mul(p.y, p.z));
+    let x = fq_sub(f, fq_double(d));
+    let y = fq_sub(fq_mul(e, fq_sub(d, x)), fq_double(fq_double(fq_double(c))));
+    return Jacobian(x, y, z);
+}
+
+// http://www.hyperelliptic.org/EFD/g1p/auto-shortw-jacobian-0.html#addition-madd-2007-bl
+fn jacobian_add_affine(p: Jacobian, q: Affine) -> Jacobian {
+    if (jacobian_is_zero(p)) {
+        return Jacobian(q.x, q.y, fq_one());
+    }
+
+    let z1z1 = fq_square(p.z);
+    let u2 = fq_mul(q.x, z1z1);
+    let s2 = fq_mul(fq_mul(q.y, p.z), z1z1);
+    let h = fq_sub(u2, p.x);
+    let r = fq_double(fq_sub(s2, p.y));
+    if (fq_is_zero(h)) {
+        if (fq_is_zero(r)) {
+            return jacobian_double(p);
+        }
+        return jacobian_zero();
+    }
+
+    let hh = fq_square(h);
+    let i = fq_double(fq_double(hh));
+    let j = fq_mul(h, i);
+    let v = fq_mul(p.x, i);
+
+    let x = fq_sub(fq_sub(fq_square(r), j), fq_double(v));
+    let y = fq_sub(fq_mul(r, fq_sub(v, x)), fq_double(fq_mul(p.y, j)));
+    let z = fq_sub(fq_sub(fq_square(fq_add(p.z, h)), z1z1), hh);
+    return Jacobian(x, y, z);
+}
+
+// http://www.hyperelliptic.org/EFD/g1p/auto-shortw-jacobian-0.html#addition-add-2007-bl
+fn jacobian_add(p: Jacobian, q: Jacobian) -> Jacobian {
+    if (jacobian_is_zero(p)) {
+        return q;
+    }
+    if (jacobian_is_zero(q)) {
+        return p;
+    }
+
+    let z1z1 = fq_square(p.z);
+    let z2z2 = fq_square(q.z);
+    let u1 = fq_mul(p.x, z2z2);
+    let u2 = fq_mul(q.x, z1z1);
+    let s1 = fq_mul(fq_mul(p.y, q.z), z2z2);
+    let s2 = fq_mul(fq_mul(q.y, p.z), z1z1);
+    let h = fq_sub(u2, u1);
+    let r = fq_double(fq_sub(s2, s1));
+    if (fq_is_zero(h)) {
+        if (fq_is_zero(r)) {
+            return jacobian_double(p);
+        }
+        return jacobian_zero();
+    }
+
+    let i = fq_square(fq_double(h));
+    let j = fq_mul(h, i);
+    let v = fq_mul(u1, i);
+
+    let x = fq_sub(fq_sub(fq_square(r), j), fq_double(v));
+    let y = fq_sub(fq_mul(r, fq_sub(v, x)), fq_double(fq_mul(s1, j)));
+    let z = fq_mul(fq_sub(fq_sub(fq_square(fq_add(p.z, q.z)), z1z1), z2z2), h);
+    return Jacobian(x, y, z);
+}
+
+// Sums the points in each bucket, with one thread per bucket.
+//
+// The points of bucket `b` of window `w` are `points[indices[offsets[k]..offsets[k + 1]]]`,
+// where `k = w * num_buckets + b`.
+@compute @workgroup_size(64)
+fn msm_accumulate_buckets(@builtin(global_invocation_id) id: vec3<u32>) {
+    let gid = id.x;
+    if (gid >= params.num_windows * params.num_buckets) {
+        return;
+    }
+
+    var acc = jacobian_zero();
+    for (var i = offsets[gid]; i < offsets[gid + 1u]; i++) {
+        acc = jacobian_add_affine(acc, points[indices[i]]);
+    }
+    buckets[gid] = acc;
+}
+
+// Reduces each segment of `segment_size` consecutive buckets of a window, with one thread per segment.
+//
+// For the segment starting at bucket `lo`, this computes `sum = sum_b buckets[b]` and
+// `weighted = sum_b (b - lo + 1) * buckets[b]`, from which the host recovers `sum_b b * buckets[b]`.
+@compute @workgroup_size(64)
+fn msm_reduce_segments(@builtin(global_invocation_id) id: vec3<u32>) {
+    let gid = id.x;
+    let num_segments = params.num_buckets / params.segment_size;
+    if (gid >= params.num_windows * num_segments) {
+        return;
+    }
+
+    let start = gid * params.segment_size;
+    var running = jacobian_zero();
+    var acc = jacobian_zero();
+    for (var i = params.segment_size; i > 0u; i--) {
+        running = jacobian_add(running, buckets[start + i - 1u]);
+        acc = jacobian_add(acc, running);
+    }
+    sums[gid] = running;
+    weighted[gid] = acc;
+}
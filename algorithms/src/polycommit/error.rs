@@ -93,3 +93,25 @@ pub enum PCError {
         label: String,
     },
 }
+
+impl snarkvm_utilities::ErrorCode for PCError {
+    fn error_code(&self) -> u32 {
+        match self {
+            Self::AnyhowError(..) => 2100,
+            Self::MissingPolynomial { .. } => 2101,
+            Self::MissingEvaluation { .. } => 2102,
+            Self::MissingRng => 2103,
+            Self::DegreeIsZero => 2104,
+            Self::TooManyCoefficients { .. } => 2105,
+            Self::HidingBoundIsZero => 2106,
+            Self::HidingBoundToolarge { .. } => 2107,
+            Self::LagrangeBasisSizeIsNotPowerOfTwo => 2108,
+            Self::LagrangeBasisSizeIsTooLarge => 2109,
+            Self::TrimmingDegreeTooLarge => 2110,
+            Self::EquationHasDegreeBounds(..) => 2111,
+            Self::UnsupportedDegreeBound(..) => 2112,
+            Self::UnsupportedLagrangeBasisSize(..) => 2113,
+            Self::IncorrectDegreeBound { .. } => 2114,
+        }
+    }
+}
@@ -0,0 +1,96 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use snarkvm_curves::AffineCurve;
+use snarkvm_fields::{ConstraintFieldError, Field, ToConstraintField};
+use snarkvm_utilities::{
+    error,
+    io::{Read, Write},
+    serialize::{CanonicalDeserialize, CanonicalSerialize, Compress, SerializationError, Valid, Validate},
+    FromBytes,
+    ToBytes,
+};
+
+use std::io;
+
+/// The public parameters of the IPA commitment scheme, which are derived from a public seed without a trusted setup.
+#[derive(Clone, Debug, PartialEq, Eq, CanonicalSerialize, CanonicalDeserialize)]
+pub struct IPAParameters<G: AffineCurve> {
+    /// The generators that the coefficients of a polynomial are committed to.
+    pub generators: Vec<G>,
+    /// The generator that the inner product is bound to during an opening.
+    pub u: G,
+}
+
+impl<G: AffineCurve> IPAParameters<G> {
+    /// Returns the maximum degree of the polynomials that can be committed to.
+    pub fn max_degree(&self) -> usize {
+        self.generators.len() - 1
+    }
+
+    /// Returns the number of folding rounds in an opening proof.
+    pub fn num_rounds(&self) -> usize {
+        self.generators.len().trailing_zeros() as usize
+    }
+}
+
+/// A commitment to a polynomial, which is the MSM of its coefficients and the generators.
+#[derive(Copy, Clone, Debug, Default, Hash, PartialEq, Eq, CanonicalSerialize, CanonicalDeserialize)]
+pub struct IPACommitment<G: AffineCurve>(
+    /// The commitment is a group element.
+    pub G,
+);
+
+impl<G: AffineCurve> FromBytes for IPACommitment<G> {
+    fn read_le<R: Read>(mut reader: R) -> io::Result<Self> {
+        CanonicalDeserialize::deserialize_compressed(&mut reader)
+            .map_err(|_| error("could not deserialize IPACommitment"))
+    }
+}
+
+impl<G: AffineCurve> ToBytes for IPACommitment<G> {
+    fn write_le<W: Write>(&self, mut writer: W) -> io::Result<()> {
+        CanonicalSerialize::serialize_compressed(self, &mut writer)
+            .map_err(|_| error("could not serialize IPACommitment"))
+    }
+}
+
+impl<G: AffineCurve + ToConstraintField<F>, F: Field> ToConstraintField<F> for IPACommitment<G> {
+    fn to_field_elements(&self) -> Result<Vec<F>, ConstraintFieldError> {
+        self.0.to_field_elements()
+    }
+}
+
+/// A proof that a committed polynomial evaluates to a given value at a given point.
+#[derive(Clone, Debug, PartialEq, Eq, CanonicalSerialize, CanonicalDeserialize)]
+pub struct IPAProof<G: AffineCurve> {
+    /// The cross terms `L_j` of each folding round.
+    pub l: Vec<G>,
+    /// The cross terms `R_j` of each folding round.
+    pub r: Vec<G>,
+    /// The last coefficient after all folding rounds.
+    pub a: G::ScalarField,
+}
+
+impl<G: AffineCurve> FromBytes for IPAProof<G> {
+    fn read_le<R: Read>(mut reader: R) -> io::Result<Self> {
+        CanonicalDeserialize::deserialize_compressed(&mut reader).map_err(|_| error("could not deserialize IPAProof"))
+    }
+}
+
+impl<G: AffineCurve> ToBytes for IPAProof<G> {
+    fn write_le<W: Write>(&self, mut writer: W) -> io::Result<()> {
+        CanonicalSerialize::serialize_compressed(self, &mut writer).map_err(|_| error("could not serialize IPAProof"))
+    }
+}
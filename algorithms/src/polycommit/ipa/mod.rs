@@ -0,0 +1,298 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+mod data_structures;
+pub use data_structures::*;
+
+use crate::{fft::DensePolynomial, msm::VariableBase, AlgebraicSponge};
+use snarkvm_curves::{AffineCurve, ProjectiveCurve};
+use snarkvm_fields::{batch_inversion, Field, One, PrimeField, ToConstraintField, Zero};
+use snarkvm_utilities::{cfg_into_iter, cfg_iter, Uniform};
+
+use anyhow::{anyhow, ensure, Result};
+use blake2::{Blake2s256, Digest};
+use core::marker::PhantomData;
+use rand::{Rng, SeedableRng};
+use rand_chacha::ChaChaRng;
+
+#[cfg(not(feature = "serial"))]
+use rayon::prelude::*;
+
+/// The domain separator from which the generators are derived.
+const IPA_GENERATORS_DST: &[u8] = b"snarkVM.IPA.generators";
+
+/// The inner-product argument polynomial commitment scheme over the curve `G`,
+/// whose openings are made non-interactive with the sponge `S`.
+#[derive(Clone, Debug)]
+pub struct IPAPC<G: AffineCurve, S> {
+    _curve: PhantomData<G>,
+    _sponge: PhantomData<S>,
+}
+
+impl<G, S> IPAPC<G, S>
+where
+    G: AffineCurve + ToConstraintField<G::BaseField>,
+    G::BaseField: PrimeField,
+    S: AlgebraicSponge<G::BaseField, 2>,
+{
+    /// Derives the parameters for polynomials of degree up to `max_degree`.
+    /// The number of generators is rounded up to a power of two.
+    pub fn setup(max_degree: usize) -> Result<IPAParameters<G>> {
+        let setup_time = start_timer!(|| format!("IPA setup for max degree {max_degree}"));
+        let num_generators = max_degree
+            .checked_add(1)
+            .and_then(usize::checked_next_power_of_two)
+            .ok_or_else(|| anyhow!("The maximum degree {max_degree} is too large"))?;
+
+        // Derive each generator from its own seed, so that the first generators do not depend on their number.
+        let mut generators = cfg_into_iter!(0..num_generators as u64).map(Self::hash_to_curve).collect::<Vec<_>>();
+        let u = Self::hash_to_curve(u64::MAX);
+        ensure!(!generators.contains(&u), "The generators of the IPA parameters must be distinct");
+        generators.shrink_to_fit();
+        end_timer!(setup_time);
+        Ok(IPAParameters { generators, u })
+    }
+
+    /// Commits to `polynomial`, which must have a degree of at most `pp.max_degree()`.
+    pub fn commit(pp: &IPAParameters<G>, polynomial: &DensePolynomial<G::ScalarField>) -> Result<IPACommitment<G>> {
+        ensure!(
+            polynomial.coeffs.len() <= pp.generators.len(),
+            "The polynomial has degree {}, but the parameters only support degree {}",
+            polynomial.degree(),
+            pp.max_degree()
+        );
+        let commit_time = start_timer!(|| format!("Committing to a polynomial of degree {}", polynomial.degree()));
+        let scalars = cfg_iter!(polynomial.coeffs).map(|c| c.to_bigint()).collect::<Vec<_>>();
+        let commitment = VariableBase::msm(&pp.generators, &scalars).to_affine();
+        end_timer!(commit_time);
+        Ok(IPACommitment(commitment))
+    }
+
+    /// Proves that the polynomial committed to in `commitment` evaluates to `polynomial(point)` at `point`.
+    pub fn open(
+        pp: &IPAParameters<G>,
+        polynomial: &DensePolynomial<G::ScalarField>,
+        commitment: &IPACommitment<G>,
+        point: G::ScalarField,
+        fs_rng: &mut S,
+    ) -> Result<IPAProof<G>> {
+        ensure!(
+            polynomial.coeffs.len() <= pp.generators.len(),
+            "The polynomial has degree {}, but the parameters only support degree {}",
+            polynomial.degree(),
+            pp.max_degree()
+        );
+        let open_time = start_timer!(|| format!("Opening a polynomial of degree {}", polynomial.degree()));
+        let n = pp.generators.len();
+        let value = polynomial.evaluate(point);
+        let u = Self::bind_statement(pp, commitment, point, value, fs_rng);
+
+        let mut a = polynomial.coeffs.clone();
+        a.resize(n, G::ScalarField::zero());
+        let mut b = Self::powers(point, n);
+        let mut generators = pp.generators.clone();
+
+        let (mut l, mut r) = (Vec::with_capacity(pp.num_rounds()), Vec::with_capacity(pp.num_rounds()));
+        while a.len() > 1 {
+            let half = a.len() / 2;
+            let (a_lo, a_hi) = a.split_at(half);
+            let (b_lo, b_hi) = b.split_at(half);
+            let (g_lo, g_hi) = generators.split_at(half);
+
+            let l_j = Self::cross_term(a_lo, b_hi, g_hi, u);
+            let r_j = Self::cross_term(a_hi, b_lo, g_lo, u);
+            let [l_j, r_j] = <[_; 2]>::try_from(G::Projective::batch_normalization_into_affine(vec![l_j, r_j]))
+                .map_err(|_| anyhow!("Failed to normalize the cross terms"))?;
+
+            fs_rng.absorb_native_field_elements(&[l_j, r_j]);
+            let x = fs_rng.squeeze_short_nonnative_field_element::<G::ScalarField>();
+            let x_inv = x.inverse().ok_or_else(|| anyhow!("The IPA challenge is zero"))?;
+            l.push(l_j);
+            r.push(r_j);
+
+            // Fold the halves, so that ⟨a', b'⟩ = ⟨a, b⟩ + x²⟨a_lo, b_hi⟩ + x⁻²⟨a_hi, b_lo⟩.
+            let folded_a = cfg_iter!(a_lo).zip(a_hi).map(|(lo, hi)| *lo * x + *hi * x_inv).collect();
+            let folded_b = cfg_iter!(b_lo).zip(b_hi).map(|(lo, hi)| *lo * x_inv + *hi * x).collect();
+            let folded_generators = cfg_iter!(g_lo).zip(g_hi).map(|(lo, hi)| *lo * x_inv + *hi * x).collect::<Vec<_>>();
+            a = folded_a;
+            b = folded_b;
+            generators = G::Projective::batch_normalization_into_affine(folded_generators);
+        }
+        end_timer!(open_time);
+        Ok(IPAProof { l, r, a: a[0] })
+    }
+
+    /// Checks that the polynomial committed to in `commitment` evaluates to `value` at `point`.
+    pub fn check(
+        pp: &IPAParameters<G>,
+        commitment: &IPACommitment<G>,
+        point: G::ScalarField,
+        value: G::ScalarField,
+        proof: &IPAProof<G>,
+        fs_rng: &mut S,
+    ) -> Result<bool> {
+        let num_rounds = pp.num_rounds();
+        ensure!(
+            proof.l.len() == num_rounds && proof.r.len() == num_rounds,
+            "The proof has {} rounds, but the parameters require {num_rounds}",
+            proof.l.len().max(proof.r.len())
+        );
+        let check_time = start_timer!(|| format!("Checking an IPA opening with {num_rounds} rounds"));
+        let u = Self::bind_statement(pp, commitment, point, value, fs_rng);
+
+        let mut challenges = Vec::with_capacity(num_rounds);
+        for (l_j, r_j) in proof.l.iter().zip(&proof.r) {
+            fs_rng.absorb_native_field_elements(&[*l_j, *r_j]);
+            challenges.push(fs_rng.squeeze_short_nonnative_field_element::<G::ScalarField>());
+        }
+        ensure!(!challenges.iter().any(|x| x.is_zero()), "The IPA challenge is zero");
+        let mut inverses = challenges.clone();
+        batch_inversion(&mut inverses);
+
+        // The folded generator is ⟨s, G⟩, where s_i is the product of x_j if bit j of i (from the top) is set,
+        // and of x_j⁻¹ otherwise.
+        let mut s = vec![G::ScalarField::one()];
+        for (x, x_inv) in challenges.iter().zip(&inverses) {
+            s = s.iter().flat_map(|s_i| [*s_i * x_inv, *s_i * x]).collect();
+        }
+        // The folded powers of the point are the product of (x_j⁻¹ + x_j · point^(2^(k-1-j))).
+        let mut b = G::ScalarField::one();
+        let mut power = point;
+        for (x, x_inv) in challenges.iter().zip(&inverses).rev() {
+            b *= *x_inv + *x * power;
+            power.square_in_place();
+        }
+
+        // Check that a · ⟨s, G⟩ + a · b · U' = C + v · U' + Σ (x_j² · L_j + x_j⁻² · R_j).
+        let bases = pp.generators.iter().chain(&proof.l).chain(&proof.r).chain([&commitment.0, &u]).copied();
+        let scalars = s
+            .into_iter()
+            .map(|s_i| s_i * proof.a)
+            .chain(challenges.iter().map(|x| -x.square()))
+            .chain(inverses.iter().map(|x_inv| -x_inv.square()))
+            .chain([-G::ScalarField::one(), proof.a * b - value])
+            .map(|scalar| scalar.to_bigint());
+        let (bases, scalars): (Vec<_>, Vec<_>) = bases.zip(scalars).unzip();
+        let result = VariableBase::msm(&bases, &scalars).is_zero();
+        end_timer!(check_time);
+        Ok(result)
+    }
+
+    /// Samples the generator with the given index, whose discrete logarithm is unknown.
+    fn hash_to_curve(index: u64) -> G {
+        let mut hasher = Blake2s256::new();
+        hasher.update(IPA_GENERATORS_DST);
+        hasher.update(index.to_le_bytes());
+        let mut rng = ChaChaRng::from_seed(hasher.finalize().into());
+        loop {
+            let x = G::BaseField::rand(&mut rng);
+            if let Some(point) = G::from_x_coordinate(x, rng.gen()) {
+                let point = point.mul_by_cofactor();
+                if !point.is_zero() {
+                    return point;
+                }
+            }
+        }
+    }
+
+    /// Absorbs the statement into the sponge, and returns the generator that the inner product is bound to.
+    fn bind_statement(
+        pp: &IPAParameters<G>,
+        commitment: &IPACommitment<G>,
+        point: G::ScalarField,
+        value: G::ScalarField,
+        fs_rng: &mut S,
+    ) -> G {
+        fs_rng.absorb_native_field_elements(&[commitment.0]);
+        fs_rng.absorb_nonnative_field_elements([point, value]);
+        let xi = fs_rng.squeeze_short_nonnative_field_element::<G::ScalarField>();
+        (pp.u * xi).to_affine()
+    }
+
+    /// Returns `⟨a, G⟩ + ⟨a, b⟩ · U`.
+    fn cross_term(a: &[G::ScalarField], b: &[G::ScalarField], generators: &[G], u: G) -> G::Projective {
+        let inner_product = cfg_iter!(a).zip(b).map(|(a_i, b_i)| *a_i * b_i).sum::<G::ScalarField>();
+        let scalars = cfg_iter!(a).map(|a_i| a_i.to_bigint()).collect::<Vec<_>>();
+        VariableBase::msm(generators, &scalars) + u * inner_product
+    }
+
+    /// Returns the first `n` powers of `point`.
+    fn powers(point: G::ScalarField, n: usize) -> Vec<G::ScalarField> {
+        let mut powers = Vec::with_capacity(n);
+        let mut current = G::ScalarField::one();
+        for _ in 0..n {
+            powers.push(current);
+            current *= point;
+        }
+        powers
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::crypto_hash::PoseidonSponge;
+    use snarkvm_curves::bls12_377::{Fq, Fr, G1Affine};
+    use snarkvm_utilities::{rand::TestRng, FromBytes, ToBytes};
+
+    type Sponge = PoseidonSponge<Fq, 2, 1>;
+    type IPA = IPAPC<G1Affine, Sponge>;
+
+    #[test]
+    fn test_ipa_setup_is_deterministic() {
+        let pp = IPA::setup(10).unwrap();
+        assert_eq!(pp.generators.len(), 16);
+        assert_eq!(pp.max_degree(), 15);
+        assert_eq!(pp.num_rounds(), 4);
+        assert_eq!(pp, IPA::setup(15).unwrap());
+        // Larger parameters extend smaller ones.
+        assert_eq!(pp.generators[..], IPA::setup(31).unwrap().generators[..16]);
+    }
+
+    #[test]
+    fn test_ipa_open_and_check() {
+        let rng = &mut TestRng::default();
+        let pp = IPA::setup(63).unwrap();
+        let sponge_parameters = Sponge::sample_parameters();
+
+        for degree in [0, 1, 17, 63] {
+            let polynomial = DensePolynomial::<Fr>::rand(degree, rng);
+            let commitment = IPA::commit(&pp, &polynomial).unwrap();
+            let point = Fr::rand(rng);
+            let value = polynomial.evaluate(point);
+
+            let mut fs_rng = Sponge::new_with_parameters(&sponge_parameters);
+            let proof = IPA::open(&pp, &polynomial, &commitment, point, &mut fs_rng).unwrap();
+            assert_eq!(proof.l.len(), pp.num_rounds());
+            let proof = IPAProof::read_le(&proof.to_bytes_le().unwrap()[..]).unwrap();
+
+            let mut fs_rng = Sponge::new_with_parameters(&sponge_parameters);
+            assert!(IPA::check(&pp, &commitment, point, value, &proof, &mut fs_rng).unwrap());
+
+            // The proof does not verify for another value or point.
+            let mut fs_rng = Sponge::new_with_parameters(&sponge_parameters);
+            assert!(!IPA::check(&pp, &commitment, point, value + Fr::one(), &proof, &mut fs_rng).unwrap());
+            let mut fs_rng = Sponge::new_with_parameters(&sponge_parameters);
+            assert!(!IPA::check(&pp, &commitment, point + Fr::one(), value, &proof, &mut fs_rng).unwrap());
+        }
+    }
+
+    #[test]
+    fn test_ipa_degree_too_large() {
+        let rng = &mut TestRng::default();
+        let pp = IPA::setup(7).unwrap();
+        let polynomial = DensePolynomial::<Fr>::rand(8, rng);
+        assert!(IPA::commit(&pp, &polynomial).is_err());
+    }
+}
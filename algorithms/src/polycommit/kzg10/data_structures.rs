@@ -23,7 +23,17 @@ use snarkvm_utilities::{
     borrow::Cow,
     error,
     io::{Read, Write},
-    serialize::{CanonicalDeserialize, CanonicalSerialize, Compress, SerializationError, Valid, Validate},
+    serialize::{
+        deserialize_zero_copy,
+        serialize_zero_copy,
+        CanonicalDeserialize,
+        CanonicalSerialize,
+        Compress,
+        SerializationError,
+        Valid,
+        Validate,
+        ZeroCopy,
+    },
     FromBytes,
     ToBytes,
 };
@@ -160,6 +170,27 @@ impl<E: PairingEngine> Powers<'_, E> {
         self.powers_of_beta_g.len()
     }
 }
+
+impl<'a, E: PairingEngine> Powers<'a, E>
+where
+    E::G1Affine: ZeroCopy,
+{
+    /// Borrows the powers from a zero-copy encoded buffer, without copying the group elements.
+    pub fn from_zero_copy_bytes(bytes: &'a [u8], validate: Validate) -> Result<Self, SerializationError> {
+        let (powers_of_beta_g, bytes) = deserialize_zero_copy(bytes, validate)?;
+        let (powers_of_beta_times_gamma_g, _) = deserialize_zero_copy(bytes, validate)?;
+        Ok(Self {
+            powers_of_beta_g: Cow::Borrowed(powers_of_beta_g),
+            powers_of_beta_times_gamma_g: Cow::Borrowed(powers_of_beta_times_gamma_g),
+        })
+    }
+
+    /// Writes the powers in the zero-copy encoding read by `Powers::from_zero_copy_bytes`.
+    pub fn write_zero_copy<W: Write>(&self, mut writer: W) -> Result<(), SerializationError> {
+        serialize_zero_copy(&self.powers_of_beta_g, &mut writer)?;
+        serialize_zero_copy(&self.powers_of_beta_times_gamma_g, &mut writer)
+    }
+}
 /// `LagrangeBasis` is used to commit to and create evaluation proofs for a given polynomial.
 #[derive(Clone, Debug, Hash)]
 pub struct LagrangeBasis<'a, E: PairingEngine> {
@@ -0,0 +1,150 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Powers of the SRS in a memory-mapped file, in the zero-copy encoding of `Powers::write_zero_copy`.
+//! The group elements are borrowed from the mapping, so the OS only pages in the powers that are used.
+
+use super::{Powers, UniversalParams};
+use snarkvm_curves::PairingEngine;
+use snarkvm_utilities::{
+    borrow::Cow,
+    serialize::{
+        deserialize_zero_copy,
+        deserialize_zero_copy_prefix,
+        zero_copy_len,
+        Validate,
+        ZeroCopy,
+        ZERO_COPY_HEADER_SIZE,
+    },
+};
+
+use anyhow::{ensure, Result};
+use core::{marker::PhantomData, mem::size_of};
+use memmap2::Mmap;
+use std::{
+    fs::File,
+    io::{BufWriter, Write},
+    path::Path,
+};
+
+/// The powers of beta G and of beta times gamma G, mapped from a file.
+#[derive(Debug)]
+pub struct MappedPowers<E: PairingEngine> {
+    mmap: Mmap,
+    /// The number of powers of beta G in the file.
+    num_powers: usize,
+    /// The offset of the encoding of the powers of beta times gamma G in the file.
+    gamma_offset: usize,
+    _engine: PhantomData<E>,
+}
+
+impl<E: PairingEngine> MappedPowers<E>
+where
+    E::G1Affine: ZeroCopy,
+{
+    /// Writes the first `num_powers` powers of beta G of `pp`, and its powers of beta times gamma G, to a file at `path`,
+    /// from which they may be mapped with `MappedPowers::open`.
+    pub fn write_universal_params(pp: &UniversalParams<E>, num_powers: usize, path: impl AsRef<Path>) -> Result<()> {
+        let powers = Powers::<E> {
+            powers_of_beta_g: pp.powers_of_beta_g(0, num_powers)?.into(),
+            powers_of_beta_times_gamma_g: pp
+                .powers_of_beta_times_gamma_g()
+                .values()
+                .copied()
+                .collect::<Vec<_>>()
+                .into(),
+        };
+        Self::write(&powers, path)
+    }
+
+    /// Writes `powers` to a file at `path`, from which they may be mapped with `MappedPowers::open`.
+    pub fn write(powers: &Powers<E>, path: impl AsRef<Path>) -> Result<()> {
+        let mut writer = BufWriter::new(File::create(path)?);
+        powers.write_zero_copy(&mut writer)?;
+        writer.flush()?;
+        Ok(())
+    }
+
+    /// Maps the powers in the file at `path`.
+    /// Only the layout of the file is checked here, and the powers are checked when they are borrowed.
+    pub fn open(path: impl AsRef<Path>) -> Result<Self> {
+        let file = File::open(path)?;
+        // Safety: the file must not be modified while it is mapped, which the caller ensures by not sharing it
+        // with writers, as with any file that is read; the contents are checked before they are reinterpreted.
+        let mmap = unsafe { Mmap::map(&file)? };
+
+        let num_powers = zero_copy_len::<E::G1Affine>(&mmap)?;
+        ensure!(num_powers > 0, "The mapped SRS has no powers of beta G");
+        let gamma_offset = ZERO_COPY_HEADER_SIZE + num_powers * size_of::<E::G1Affine>();
+        zero_copy_len::<E::G1Affine>(&mmap[gamma_offset..])?;
+        Ok(Self { mmap, num_powers, gamma_offset, _engine: PhantomData })
+    }
+
+    /// Returns the number of powers of beta G in the file.
+    pub fn num_powers(&self) -> usize {
+        self.num_powers
+    }
+
+    /// Returns the maximum degree supported by the powers in the file.
+    pub fn max_degree(&self) -> usize {
+        self.num_powers - 1
+    }
+
+    /// Borrows the powers of beta G up to `max_degree`, and the powers of beta times gamma G, from the mapping.
+    /// Only the pages that hold these powers are read.
+    pub fn powers(&self, max_degree: usize, validate: Validate) -> Result<Powers<'_, E>> {
+        ensure!(
+            max_degree < self.num_powers,
+            "The mapped SRS supports degree {}, but degree {max_degree} was requested",
+            self.max_degree()
+        );
+        let powers_of_beta_g = deserialize_zero_copy_prefix(&self.mmap, max_degree + 1, validate)?;
+        let (powers_of_beta_times_gamma_g, _) = deserialize_zero_copy(&self.mmap[self.gamma_offset..], validate)?;
+        Ok(Powers {
+            powers_of_beta_g: Cow::Borrowed(powers_of_beta_g),
+            powers_of_beta_times_gamma_g: Cow::Borrowed(powers_of_beta_times_gamma_g),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::polycommit::kzg10::KZG10;
+    use snarkvm_curves::bls12_377::Bls12_377;
+
+    #[test]
+    fn test_mapped_powers() {
+        let degree = 32;
+        let pp = KZG10::<Bls12_377>::load_srs(degree).unwrap();
+        let directory = tempfile::tempdir().unwrap();
+        let path = directory.path().join("powers");
+        MappedPowers::write_universal_params(&pp, degree + 1, &path).unwrap();
+
+        let mapped = MappedPowers::<Bls12_377>::open(&path).unwrap();
+        assert_eq!(mapped.max_degree(), degree);
+        for max_degree in [0, 7, degree] {
+            let powers = mapped.powers(max_degree, Validate::Yes).unwrap();
+            assert!(matches!(powers.powers_of_beta_g, Cow::Borrowed(_)));
+            assert_eq!(&*powers.powers_of_beta_g, pp.powers_of_beta_g(0, max_degree + 1).unwrap().as_slice());
+            assert!(powers.powers_of_beta_times_gamma_g.iter().eq(pp.powers_of_beta_times_gamma_g().values()));
+        }
+        assert!(mapped.powers(degree + 1, Validate::Yes).is_err());
+
+        // A truncated file is rejected.
+        let bytes = std::fs::read(&path).unwrap();
+        std::fs::write(&path, &bytes[..bytes.len() - 1]).unwrap();
+        assert!(MappedPowers::<Bls12_377>::open(&path).is_err());
+    }
+}
@@ -39,6 +39,11 @@ use rayon::prelude::*;
 mod data_structures;
 pub use data_structures::*;
 
+#[cfg(feature = "mmap")]
+mod mapped;
+#[cfg(feature = "mmap")]
+pub use mapped::*;
+
 use super::sonic_pc::LabeledPolynomialWithBasis;
 
 #[derive(Debug, PartialEq, Eq)]
@@ -154,6 +159,74 @@ impl<E: PairingEngine> KZG10<E> {
         Ok((KZGCommitment(commitment.into()), randomness))
     }
 
+    /// Outputs a commitment to `polynomial`, hidden by the given blinding polynomial rather than a sampled one.
+    pub(crate) fn commit_with_blinding_polynomial(
+        powers: &Powers<E>,
+        polynomial: &DensePolynomial<E::Fr>,
+        blinding_polynomial: &DensePolynomial<E::Fr>,
+    ) -> Result<KZGCommitment<E>, PCError> {
+        Self::check_degree_is_too_large(polynomial.degree(), powers.size())?;
+        Self::check_degree_is_too_large(blinding_polynomial.degree(), powers.powers_of_beta_times_gamma_g.len())?;
+
+        let (num_leading_zeros, plain_coeffs) = skip_leading_zeros_and_convert_to_bigints(polynomial);
+        let bases = &powers.powers_of_beta_g[num_leading_zeros..(num_leading_zeros + plain_coeffs.len())];
+        let mut commitment = VariableBase::msm(bases, &plain_coeffs);
+
+        let random_ints = convert_to_bigints(&blinding_polynomial.coeffs);
+        commitment += VariableBase::msm(&powers.powers_of_beta_times_gamma_g, &random_ints);
+        Ok(KZGCommitment(commitment.to_affine()))
+    }
+
+    /// Outputs a commitment to the polynomial with the given coefficients, in increasing order of degree.
+    ///
+    /// The coefficients are consumed in chunks by a streaming MSM, so they may be read lazily,
+    /// e.g. from disk, for polynomials that do not fit in memory.
+    pub fn commit_streaming(
+        powers: &Powers<E>,
+        coefficients: impl IntoIterator<Item = E::Fr>,
+        hiding_bound: Option<usize>,
+        rng: Option<&mut dyn RngCore>,
+    ) -> Result<(KZGCommitment<E>, KZGRandomness<E>), PCError> {
+        let commit_time =
+            start_timer!(|| format!("Committing to a streamed polynomial with hiding_bound: {hiding_bound:?}"));
+
+        let mut coefficients = coefficients.into_iter();
+        let msm_time = start_timer!(|| "Streaming MSM to compute commitment to plaintext poly");
+        let terms = powers.powers_of_beta_g.iter().zip(coefficients.by_ref()).map(|(base, c)| (*base, c.to_bigint()));
+        let mut commitment = VariableBase::msm_streaming(terms);
+        end_timer!(msm_time);
+        // The powers are exhausted, so any remaining coefficient exceeds the supported degree.
+        if coefficients.next().is_some() {
+            let num_powers = powers.size();
+            return Err(PCError::TooManyCoefficients {
+                num_coefficients: num_powers + 1 + coefficients.count(),
+                num_powers,
+            });
+        }
+
+        let mut randomness = KZGRandomness::empty();
+        if let Some(hiding_degree) = hiding_bound {
+            let mut rng = rng.ok_or(PCError::MissingRng)?;
+            let sample_random_poly_time =
+                start_timer!(|| format!("Sampling a random polynomial of degree {hiding_degree}"));
+
+            randomness = KZGRandomness::rand(hiding_degree, false, &mut rng);
+            Self::check_hiding_bound(
+                randomness.blinding_polynomial.degree(),
+                powers.powers_of_beta_times_gamma_g.len(),
+            )?;
+            end_timer!(sample_random_poly_time);
+        }
+
+        let random_ints = convert_to_bigints(&randomness.blinding_polynomial.coeffs);
+        let msm_time = start_timer!(|| "MSM to compute commitment to random poly");
+        commitment += VariableBase::msm(&powers.powers_of_beta_times_gamma_g, &random_ints);
+        end_timer!(msm_time);
+
+        end_timer!(commit_time);
+        Ok((KZGCommitment(commitment.to_affine()), randomness))
+    }
+
     /// Outputs a commitment to `polynomial`.
     pub fn commit_lagrange(
         lagrange_basis: &LagrangeBasis<E>,
@@ -214,17 +287,15 @@ impl<E: PairingEngine> KZG10<E> {
         point: E::Fr,
         randomness: &KZGRandomness<E>,
     ) -> Result<(DensePolynomial<E::Fr>, Option<DensePolynomial<E::Fr>>), PCError> {
-        let divisor = DensePolynomial::from_coefficients_vec(vec![-point, E::Fr::one()]);
-
         let witness_time = start_timer!(|| "Computing witness polynomial");
-        let witness_polynomial = polynomial / &divisor;
+        let (witness_polynomial, _) = polynomial.divide_by_linear_polynomial(point);
         end_timer!(witness_time);
 
         let random_witness_polynomial = if randomness.is_hiding() {
             let random_p = &randomness.blinding_polynomial;
 
             let witness_time = start_timer!(|| "Computing random witness polynomial");
-            let random_witness_polynomial = random_p / &divisor;
+            let (random_witness_polynomial, _) = random_p.divide_by_linear_polynomial(point);
             end_timer!(witness_time);
             Some(random_witness_polynomial)
         } else {
@@ -478,7 +549,7 @@ mod tests {
     #![allow(clippy::needless_borrow)]
     use super::*;
     use snarkvm_curves::bls12_377::{Bls12_377, Fr};
-    use snarkvm_utilities::{rand::TestRng, FromBytes, ToBytes};
+    use snarkvm_utilities::{rand::TestRng, FromBytes, ToBytes, Validate};
 
     use std::borrow::Cow;
 
@@ -531,6 +602,22 @@ mod tests {
         assert_eq!(&pp_bytes, &pp_recovered_bytes);
     }
 
+    #[test]
+    fn test_kzg10_powers_zero_copy() {
+        let degree = 16;
+        let pp = KZG_Bls12_377::load_srs(degree).unwrap();
+        let (powers, _) = KZG_Bls12_377::trim(&pp, degree, Some(1));
+
+        let mut bytes = Vec::new();
+        powers.write_zero_copy(&mut bytes).unwrap();
+
+        let buffer = snarkvm_utilities::serialize::AlignedBytes::from_bytes(&bytes);
+        let recovered = Powers::<Bls12_377>::from_zero_copy_bytes(buffer.as_bytes(), Validate::Yes).unwrap();
+        assert!(matches!(recovered.powers_of_beta_g, Cow::Borrowed(_)));
+        assert_eq!(powers.powers_of_beta_g, recovered.powers_of_beta_g);
+        assert_eq!(powers.powers_of_beta_times_gamma_g, recovered.powers_of_beta_times_gamma_g);
+    }
+
     fn end_to_end_test_template<E: PairingEngine>() -> Result<(), PCError> {
         let rng = &mut TestRng::default();
         for _ in 0..100 {
@@ -629,6 +716,34 @@ mod tests {
         batch_check_test_template::<Bls12_377>().expect("test failed for bls12-377");
     }
 
+    #[test]
+    fn test_commit_streaming() {
+        let rng = &mut TestRng::default();
+
+        let max_degree = 123;
+        let pp = KZG_Bls12_377::load_srs(max_degree).unwrap();
+        let hiding_bound = Some(1);
+        let (powers, vk) = KZG_Bls12_377::trim(&pp, max_degree, hiding_bound);
+
+        // The streamed commitment matches the in-memory one.
+        let p = DensePolynomial::<Fr>::rand(max_degree, rng);
+        let (expected, _) = KZG_Bls12_377::commit(&powers, &(&p).into(), None, None).unwrap();
+        let (comm, _) = KZG_Bls12_377::commit_streaming(&powers, p.coeffs.iter().copied(), None, None).unwrap();
+        assert_eq!(comm, expected);
+
+        // A hiding streamed commitment can be opened.
+        let (comm, rand) =
+            KZG_Bls12_377::commit_streaming(&powers, p.coeffs.iter().copied(), hiding_bound, Some(rng)).unwrap();
+        let point = Fr::rand(rng);
+        let proof = KZG_Bls12_377::open(&powers, &p, point, &rand).unwrap();
+        assert!(KZG_Bls12_377::check(&vk, &comm, point, p.evaluate(point), &proof).unwrap());
+
+        // The stream may not exceed the supported degree.
+        let p = DensePolynomial::<Fr>::rand(max_degree + 1, rng);
+        let result = KZG_Bls12_377::commit_streaming(&powers, p.coeffs.iter().copied(), None, None);
+        assert!(matches!(result, Err(PCError::TooManyCoefficients { num_coefficients: 125, num_powers: 124 })));
+    }
+
     #[test]
     fn test_degree_is_too_large() {
         let rng = &mut TestRng::default();
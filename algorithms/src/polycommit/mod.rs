@@ -33,6 +33,14 @@ pub mod kzg10;
 /// [al]: https://eprint.iacr.org/2019/601
 pub mod sonic_pc;
 
+/// Transparent polynomial commitment scheme based on the inner-product argument
+/// of [\[BCCGP16\]][bccgp] and [[BBBPWM18, “Bulletproofs”]][bp], which needs no trusted setup.
+/// Its commitments are not hiding, and its openings are not zero-knowledge.
+///
+/// [bccgp]: https://eprint.iacr.org/2016/263
+/// [bp]: https://eprint.iacr.org/2017/1066
+pub mod ipa;
+
 /// Errors pertaining to query sets.
 pub mod error;
 pub use error::*;
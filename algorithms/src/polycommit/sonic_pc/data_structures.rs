@@ -395,6 +395,35 @@ impl<E: PairingEngine> BatchProof<E> {
     }
 }
 
+/// Evaluation proof at a query set with several points, following [[BDFG20, “Shplonk”]][shplonk].
+///
+/// [shplonk]: https://eprint.iacr.org/2020/081
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Hash, CanonicalSerialize, CanonicalDeserialize)]
+pub struct MultiPointProof<E: PairingEngine> {
+    /// The commitment to the combination of the quotients of the polynomials by the vanishing polynomials of their points.
+    pub w: E::G1Affine,
+    /// The opening of the combination of the polynomials and the quotients at a single point.
+    pub proof: kzg10::KZGProof<E>,
+}
+
+impl<E: PairingEngine> MultiPointProof<E> {
+    pub fn is_hiding(&self) -> bool {
+        self.proof.is_hiding()
+    }
+}
+
+impl<E: PairingEngine> FromBytes for MultiPointProof<E> {
+    fn read_le<R: Read>(mut reader: R) -> io::Result<Self> {
+        CanonicalDeserialize::deserialize_compressed(&mut reader).map_err(|_| error("could not deserialize struct"))
+    }
+}
+
+impl<E: PairingEngine> ToBytes for MultiPointProof<E> {
+    fn write_le<W: Write>(&self, mut writer: W) -> io::Result<()> {
+        CanonicalSerialize::serialize_compressed(self, &mut writer).map_err(|_| error("could not serialize struct"))
+    }
+}
+
 /// Labels a `LabeledPolynomial` or a `LabeledCommitment`.
 pub type PolynomialLabel = String;
 
@@ -646,11 +675,16 @@ pub fn evaluate_query_set<'a, F: PrimeField>(
     query_set: &QuerySet<F>,
 ) -> Evaluations<F> {
     let polys: HashMap<_, _> = polys.into_iter().map(|p| (p.label(), p)).collect();
-    let mut evaluations = Evaluations::new();
+    // Group the points by polynomial, so that each polynomial is evaluated at all of its points at once.
+    let mut points_per_label = BTreeMap::<&str, Vec<F>>::new();
     for (label, (_point_name, point)) in query_set {
-        let poly = polys.get(label as &str).expect("polynomial in evaluated lc is not found");
-        let eval = poly.evaluate(*point);
-        evaluations.insert((label.clone(), *point), eval);
+        points_per_label.entry(label.as_str()).or_default().push(*point);
+    }
+    let mut evaluations = Evaluations::new();
+    for (label, points) in points_per_label {
+        let poly = polys.get(label).expect("polynomial in evaluated lc is not found");
+        let evals = poly.batch_evaluate(&points);
+        evaluations.extend(points.into_iter().zip(evals).map(|(point, eval)| ((label.to_string(), point), eval)));
     }
     evaluations
 }
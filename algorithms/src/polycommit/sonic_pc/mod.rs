@@ -35,6 +35,8 @@ use std::{
 mod data_structures;
 pub use data_structures::*;
 
+mod multi_point;
+
 mod polynomial;
 pub use polynomial::*;
 
@@ -53,6 +55,38 @@ pub struct SonicKZG10<E: PairingEngine, S: AlgebraicSponge<E::Fq, 2>> {
     _engine: PhantomData<(E, S)>,
 }
 
+/// The elements of the pairing check of a batch opening, which are accumulated but not yet paired,
+/// so that the checks of several openings under the same verifier key may be combined into one.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct PairingCheck<E: PairingEngine> {
+    combined_comms: BTreeMap<Option<usize>, E::G1Projective>,
+    combined_witness: E::G1Projective,
+    combined_adjusted_witness: E::G1Projective,
+}
+
+impl<E: PairingEngine> Default for PairingCheck<E> {
+    fn default() -> Self {
+        Self {
+            combined_comms: BTreeMap::new(),
+            combined_witness: E::G1Projective::zero(),
+            combined_adjusted_witness: E::G1Projective::zero(),
+        }
+    }
+}
+
+impl<E: PairingEngine> PairingCheck<E> {
+    /// Adds `other`, scaled by `randomizer`, to `self`.
+    /// If the randomizers are sampled independently and uniformly at random, the combined check
+    /// holds with overwhelming probability only if each of the checks holds.
+    pub fn add_scaled(&mut self, other: &Self, randomizer: E::Fr) {
+        for (degree_bound, comm) in &other.combined_comms {
+            *self.combined_comms.entry(*degree_bound).or_insert_with(E::G1Projective::zero) += comm.mul(randomizer);
+        }
+        self.combined_witness += other.combined_witness.mul(randomizer);
+        self.combined_adjusted_witness += other.combined_adjusted_witness.mul(randomizer);
+    }
+}
+
 impl<E: PairingEngine, S: AlgebraicSponge<E::Fq, 2>> SonicKZG10<E, S> {
     pub fn load_srs(max_degree: usize) -> Result<UniversalParams<E>, PCError> {
         kzg10::KZG10::load_srs(max_degree).map_err(Into::into)
@@ -173,6 +207,7 @@ impl<E: PairingEngine, S: AlgebraicSponge<E::Fq, 2>> SonicKZG10<E, S> {
     /// If for some `i`, `polynomials[i].degree_bound().is_some()`, then that
     /// polynomial will have the corresponding degree bound enforced.
     #[allow(clippy::format_push_string)]
+    #[cfg_attr(feature = "instrument", tracing::instrument(level = "debug", name = "sonic_pc::commit", skip_all))]
     pub fn commit<'b>(
         universal_prover: &UniversalProver<E>,
         ck: &CommitterUnionKey<E>,
@@ -255,6 +290,21 @@ impl<E: PairingEngine, S: AlgebraicSponge<E::Fq, 2>> SonicKZG10<E, S> {
         Ok((labeled_comms, randomness))
     }
 
+    /// Outputs a commitment to the polynomial labeled `label`, whose coefficients are streamed
+    /// in increasing order of degree rather than held in memory.
+    ///
+    /// Degree bounds are not enforced for streamed polynomials.
+    pub fn commit_streaming(
+        ck: &CommitterUnionKey<E>,
+        label: PolynomialLabel,
+        coefficients: impl IntoIterator<Item = E::Fr>,
+        hiding_bound: Option<usize>,
+        rng: Option<&mut dyn RngCore>,
+    ) -> Result<(LabeledCommitment<Commitment<E>>, Randomness<E>), PCError> {
+        let (comm, rand) = kzg10::KZG10::commit_streaming(&ck.powers(), coefficients, hiding_bound, rng)?;
+        Ok((LabeledCommitment::new(label, comm, None), rand))
+    }
+
     pub fn combine_for_open<'a>(
         universal_prover: &UniversalProver<E>,
         ck: &CommitterUnionKey<E>,
@@ -348,6 +398,22 @@ impl<E: PairingEngine, S: AlgebraicSponge<E::Fq, 2>> SonicKZG10<E, S> {
         proof: &BatchProof<E>,
         fs_rng: &mut S,
     ) -> Result<bool>
+    where
+        Commitment<E>: 'a,
+    {
+        let pairing_check = Self::batch_check_deferred(vk, commitments, query_set, values, proof, fs_rng)?;
+        Self::check_pairing(vk, pairing_check)
+    }
+
+    /// Performs `batch_check` up to its pairing check, which is returned rather than computed.
+    pub fn batch_check_deferred<'a>(
+        vk: &UniversalVerifier<E>,
+        commitments: impl IntoIterator<Item = &'a LabeledCommitment<Commitment<E>>>,
+        query_set: &QuerySet<E::Fr>,
+        values: &Evaluations<E::Fr>,
+        proof: &BatchProof<E>,
+        fs_rng: &mut S,
+    ) -> Result<PairingCheck<E>>
     where
         Commitment<E>: 'a,
     {
@@ -404,11 +470,20 @@ impl<E: PairingEngine, S: AlgebraicSponge<E::Fq, 2>> SonicKZG10<E, S> {
             randomizer = fs_rng.squeeze_short_nonnative_field_element::<E::Fr>();
         }
 
-        let result = Self::check_elems(vk, combined_comms, combined_witness, combined_adjusted_witness);
         end_timer!(batch_check_time);
-        result.map_err(Into::into)
+        Ok(PairingCheck { combined_comms, combined_witness, combined_adjusted_witness })
     }
 
+    /// Computes the pairing check, returning `true` if it holds.
+    pub fn check_pairing(vk: &UniversalVerifier<E>, pairing_check: PairingCheck<E>) -> Result<bool> {
+        let PairingCheck { combined_comms, combined_witness, combined_adjusted_witness } = pairing_check;
+        Self::check_elems(vk, combined_comms, combined_witness, combined_adjusted_witness)
+    }
+
+    #[cfg_attr(
+        feature = "instrument",
+        tracing::instrument(level = "debug", name = "sonic_pc::open_combinations", skip_all)
+    )]
     pub fn open_combinations<'a>(
         universal_prover: &UniversalProver<E>,
         ck: &CommitterUnionKey<E>,
@@ -421,13 +496,28 @@ impl<E: PairingEngine, S: AlgebraicSponge<E::Fq, 2>> SonicKZG10<E, S> {
     where
         Randomness<E>: 'a,
         Commitment<E>: 'a,
+    {
+        let (lc_polynomials, lc_randomness) = Self::combine_lc_polynomials(linear_combinations, polynomials, rands)?;
+        let proof =
+            Self::batch_open(universal_prover, ck, lc_polynomials.iter(), query_set, lc_randomness.iter(), fs_rng)?;
+
+        Ok(BatchLCProof { proof })
+    }
+
+    /// Returns the polynomials of `linear_combinations`, and their randomness.
+    fn combine_lc_polynomials<'a>(
+        linear_combinations: impl IntoIterator<Item = &'a LinearCombination<E::Fr>>,
+        polynomials: impl IntoIterator<Item = LabeledPolynomial<E::Fr>>,
+        rands: impl IntoIterator<Item = &'a Randomness<E>>,
+    ) -> Result<(Vec<LabeledPolynomial<E::Fr>>, Vec<Randomness<E>>)>
+    where
+        Randomness<E>: 'a,
     {
         let label_map =
             polynomials.into_iter().zip_eq(rands).map(|(p, r)| (p.to_label(), (p, r))).collect::<BTreeMap<_, _>>();
 
         let mut lc_polynomials = Vec::new();
         let mut lc_randomness = Vec::new();
-        let mut lc_info = Vec::new();
 
         for lc in linear_combinations {
             let lc_label = lc.label().to_string();
@@ -459,20 +549,19 @@ impl<E: PairingEngine, S: AlgebraicSponge<E::Fq, 2>> SonicKZG10<E, S> {
                 randomness += (*coeff, *cur_rand);
             }
 
-            let lc_poly = LabeledPolynomial::new(lc_label.clone(), poly, degree_bound, hiding_bound);
+            let lc_poly = LabeledPolynomial::new(lc_label, poly, degree_bound, hiding_bound);
             lc_polynomials.push(lc_poly);
             lc_randomness.push(randomness);
-            lc_info.push((lc_label, degree_bound));
         }
-
-        let proof =
-            Self::batch_open(universal_prover, ck, lc_polynomials.iter(), query_set, lc_randomness.iter(), fs_rng)?;
-
-        Ok(BatchLCProof { proof })
+        Ok((lc_polynomials, lc_randomness))
     }
 
     /// Checks that `values` are the true evaluations at `query_set` of the polynomials
     /// committed in `labeled_commitments`.
+    #[cfg_attr(
+        feature = "instrument",
+        tracing::instrument(level = "debug", name = "sonic_pc::check_combinations", skip_all)
+    )]
     pub fn check_combinations<'a>(
         vk: &UniversalVerifier<E>,
         linear_combinations: impl IntoIterator<Item = &'a LinearCombination<E::Fr>>,
@@ -482,10 +571,50 @@ impl<E: PairingEngine, S: AlgebraicSponge<E::Fq, 2>> SonicKZG10<E, S> {
         proof: &BatchLCProof<E>,
         fs_rng: &mut S,
     ) -> Result<bool>
+    where
+        Commitment<E>: 'a,
+    {
+        let pairing_check = Self::check_combinations_deferred(
+            vk,
+            linear_combinations,
+            commitments,
+            query_set,
+            evaluations,
+            proof,
+            fs_rng,
+        )?;
+        Self::check_pairing(vk, pairing_check)
+    }
+
+    /// Performs `check_combinations` up to its pairing check, which is returned rather than computed,
+    /// so that it may be combined with the pairing checks of other proofs.
+    pub fn check_combinations_deferred<'a>(
+        vk: &UniversalVerifier<E>,
+        linear_combinations: impl IntoIterator<Item = &'a LinearCombination<E::Fr>>,
+        commitments: impl IntoIterator<Item = &'a LabeledCommitment<Commitment<E>>>,
+        query_set: &QuerySet<E::Fr>,
+        evaluations: &Evaluations<E::Fr>,
+        proof: &BatchLCProof<E>,
+        fs_rng: &mut S,
+    ) -> Result<PairingCheck<E>>
     where
         Commitment<E>: 'a,
     {
         let BatchLCProof { proof } = proof;
+        let (lc_commitments, evaluations) =
+            Self::combine_lc_commitments(linear_combinations, commitments, evaluations)?;
+        Self::batch_check_deferred(vk, &lc_commitments, query_set, &evaluations, proof, fs_rng)
+    }
+
+    /// Returns the commitments of `linear_combinations`, and the evaluations of their non-constant terms.
+    fn combine_lc_commitments<'a>(
+        linear_combinations: impl IntoIterator<Item = &'a LinearCombination<E::Fr>>,
+        commitments: impl IntoIterator<Item = &'a LabeledCommitment<Commitment<E>>>,
+        evaluations: &Evaluations<E::Fr>,
+    ) -> Result<(Vec<LabeledCommitment<Commitment<E>>>, Evaluations<E::Fr>)>
+    where
+        Commitment<E>: 'a,
+    {
         let label_comm_map = commitments.into_iter().map(|c| (c.label(), c)).collect::<BTreeMap<_, _>>();
 
         let mut lc_commitments = Vec::new();
@@ -539,7 +668,7 @@ impl<E: PairingEngine, S: AlgebraicSponge<E::Fq, 2>> SonicKZG10<E, S> {
             .collect::<Vec<_>>();
         end_timer!(combined_comms_norm_time);
 
-        Self::batch_check(vk, &lc_commitments, query_set, &evaluations, proof, fs_rng)
+        Ok((lc_commitments, evaluations))
     }
 }
 
@@ -648,10 +777,9 @@ impl<E: PairingEngine, S: AlgebraicSponge<E::Fq, 2>> SonicKZG10<E, S> {
                 // Find the appropriate prepared shift for the degree bound.
                 vk.prepared_negative_powers_of_beta_h
                     .get(&degree_bound)
-                    .cloned()
                     .ok_or(PCError::UnsupportedDegreeBound(degree_bound))?
             } else {
-                vk.vk.prepared_h.clone()
+                &vk.vk.prepared_h
             };
 
             g1_projective_elems.push(comm);
@@ -659,10 +787,10 @@ impl<E: PairingEngine, S: AlgebraicSponge<E::Fq, 2>> SonicKZG10<E, S> {
         }
 
         g1_projective_elems.push(-combined_adjusted_witness);
-        g2_prepared_elems.push(vk.vk.prepared_h.clone());
+        g2_prepared_elems.push(&vk.vk.prepared_h);
 
         g1_projective_elems.push(-combined_witness);
-        g2_prepared_elems.push(vk.vk.prepared_beta_h.clone());
+        g2_prepared_elems.push(&vk.vk.prepared_beta_h);
 
         let g1_prepared_elems_iter = E::G1Projective::batch_normalization_into_affine(g1_projective_elems)
             .into_iter()
@@ -670,7 +798,7 @@ impl<E: PairingEngine, S: AlgebraicSponge<E::Fq, 2>> SonicKZG10<E, S> {
             .collect::<Vec<_>>();
 
         ensure!(g1_prepared_elems_iter.len() == g2_prepared_elems.len());
-        let g1_g2_prepared = g1_prepared_elems_iter.iter().zip_eq(g2_prepared_elems.iter());
+        let g1_g2_prepared = g1_prepared_elems_iter.iter().zip_eq(g2_prepared_elems);
         let is_one: bool = E::product_of_pairings(g1_g2_prepared).is_one();
         end_timer!(check_time);
         Ok(is_one)
@@ -770,6 +898,12 @@ mod tests {
         println!("Finished bls12-377");
     }
 
+    #[test]
+    fn test_multi_point() {
+        multi_point_test::<Bls12_377, Sponge>().expect("test failed for bls12-377");
+        println!("Finished bls12-377");
+    }
+
     #[test]
     #[should_panic]
     fn test_bad_degree_bound() {
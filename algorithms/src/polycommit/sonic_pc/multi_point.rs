@@ -0,0 +1,289 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Openings at several points with a single quotient, following [[BDFG20, “Shplonk”]][shplonk].
+//!
+//! Each polynomial `f_i` is opened at its points `S_i`, among all the points `T` of the query set.
+//! The prover commits to `h = sum_i c_i (f_i - r_i) / Z_{S_i}`, where `r_i` interpolates the evaluations of `f_i`
+//! on `S_i`, and `Z_S` is the vanishing polynomial of `S`. At a random point `z`, the polynomial
+//! `L = sum_i c_i Z_{T \ S_i}(z) (f_i - r_i(z)) - Z_T(z) h` vanishes, which is shown with a single KZG opening.
+//!
+//! [shplonk]: https://eprint.iacr.org/2020/081
+
+use super::{
+    Commitment,
+    CommitterUnionKey,
+    Evaluations,
+    LabeledCommitment,
+    LabeledPolynomial,
+    LinearCombination,
+    MultiPointProof,
+    PairingCheck,
+    QuerySet,
+    Randomness,
+    SonicKZG10,
+};
+use crate::{
+    fft::DensePolynomial,
+    msm::variable_base::VariableBase,
+    polycommit::{kzg10, PCError},
+    srs::{UniversalProver, UniversalVerifier},
+    AlgebraicSponge,
+};
+use snarkvm_curves::traits::{AffineCurve, PairingEngine};
+use snarkvm_fields::{Field, One, ToConstraintField, Zero};
+
+use anyhow::{anyhow, ensure, Result};
+use core::ops::Mul;
+use itertools::Itertools;
+use std::collections::{BTreeMap, BTreeSet};
+
+impl<E: PairingEngine, S: AlgebraicSponge<E::Fq, 2>> SonicKZG10<E, S> {
+    /// On input a list of labeled polynomials and a query set, outputs a proof of evaluation
+    /// of the polynomials at the points in the query set, whose size is independent of the number of points.
+    pub fn multi_point_open<'a>(
+        universal_prover: &UniversalProver<E>,
+        ck: &CommitterUnionKey<E>,
+        labeled_polynomials: impl ExactSizeIterator<Item = &'a LabeledPolynomial<E::Fr>>,
+        query_set: &QuerySet<E::Fr>,
+        rands: impl ExactSizeIterator<Item = &'a Randomness<E>>,
+        fs_rng: &mut S,
+    ) -> Result<MultiPointProof<E>>
+    where
+        Randomness<E>: 'a,
+    {
+        ensure!(labeled_polynomials.len() == rands.len());
+        let poly_rand: BTreeMap<_, _> =
+            labeled_polynomials.zip_eq(rands).map(|(poly, r)| (poly.label(), (poly, r))).collect();
+        let (points_per_label, all_points) = Self::points_per_label(query_set);
+
+        let open_time = start_timer!(|| format!(
+            "Opening {} polynomials at {} points with a single quotient",
+            points_per_label.len(),
+            all_points.len(),
+        ));
+
+        // Combine the polynomials that are opened at the same points.
+        let mut to_combine = BTreeMap::<_, Vec<_>>::new();
+        for (label, points) in points_per_label {
+            let (polynomial, rand) =
+                poly_rand.get(label.as_str()).ok_or(PCError::MissingPolynomial { label: label.to_string() })?;
+            kzg10::KZG10::<E>::check_degrees_and_bounds(
+                universal_prover.max_degree,
+                ck.enforced_degree_bounds.as_deref(),
+                *polynomial,
+            )?;
+            let challenge = fs_rng.squeeze_short_nonnative_field_element::<E::Fr>();
+            to_combine.entry(points).or_default().push((challenge, polynomial.polynomial().to_dense(), *rand));
+        }
+        let combined = to_combine
+            .into_iter()
+            .map(|(points, to_combine)| (points, Self::combine_polynomials(to_combine)))
+            .collect::<Vec<_>>();
+
+        // Commit to the combination of the quotients by the vanishing polynomials of their points.
+        let quotient_time = start_timer!(|| "Computing the quotient");
+        let mut quotient = DensePolynomial::zero();
+        let mut quotient_rand = Randomness::empty();
+        for (points, (polynomial, rand)) in &combined {
+            quotient += &Self::divide_by_vanishing_polynomial(polynomial, points);
+            quotient_rand.blinding_polynomial +=
+                &Self::divide_by_vanishing_polynomial(&rand.blinding_polynomial, points);
+        }
+        let powers = ck.powers();
+        let w =
+            kzg10::KZG10::commit_with_blinding_polynomial(&powers, &quotient, &quotient_rand.blinding_polynomial)?.0;
+        end_timer!(quotient_time);
+
+        fs_rng.absorb_native_field_elements(&w.to_field_elements()?);
+        let point = fs_rng.squeeze_nonnative_field_elements::<E::Fr>(1)[0];
+
+        // Open the linearization at the point, where the constant terms are omitted, as they do not change the witness.
+        let mut linearization =
+            vec![(-Self::evaluate_vanishing_polynomial(&all_points, point), quotient, &quotient_rand)];
+        for (points, (polynomial, rand)) in &combined {
+            let coeff = Self::evaluate_vanishing_polynomial(all_points.difference(points), point);
+            linearization.push((coeff, polynomial.clone(), rand));
+        }
+        let (linearization, linearization_rand) = Self::combine_polynomials(linearization);
+        let proof = kzg10::KZG10::open(&powers, &linearization, point, &linearization_rand)?;
+        end_timer!(open_time);
+
+        Ok(MultiPointProof { w, proof })
+    }
+
+    /// Checks that `values` are the true evaluations at `query_set` of the polynomials committed in `commitments`.
+    pub fn multi_point_check<'a>(
+        vk: &UniversalVerifier<E>,
+        commitments: impl IntoIterator<Item = &'a LabeledCommitment<Commitment<E>>>,
+        query_set: &QuerySet<E::Fr>,
+        values: &Evaluations<E::Fr>,
+        proof: &MultiPointProof<E>,
+        fs_rng: &mut S,
+    ) -> Result<bool>
+    where
+        Commitment<E>: 'a,
+    {
+        let pairing_check = Self::multi_point_check_deferred(vk, commitments, query_set, values, proof, fs_rng)?;
+        Self::check_pairing(vk, pairing_check)
+    }
+
+    /// Performs `multi_point_check` up to its pairing check, which is returned rather than computed.
+    pub fn multi_point_check_deferred<'a>(
+        vk: &UniversalVerifier<E>,
+        commitments: impl IntoIterator<Item = &'a LabeledCommitment<Commitment<E>>>,
+        query_set: &QuerySet<E::Fr>,
+        values: &Evaluations<E::Fr>,
+        proof: &MultiPointProof<E>,
+        fs_rng: &mut S,
+    ) -> Result<PairingCheck<E>>
+    where
+        Commitment<E>: 'a,
+    {
+        let commitments: BTreeMap<_, _> = commitments.into_iter().map(|c| (c.label(), c)).collect();
+        let (points_per_label, all_points) = Self::points_per_label(query_set);
+
+        let check_time = start_timer!(|| format!(
+            "Checking {} commitments at {} points with a single quotient",
+            points_per_label.len(),
+            all_points.len(),
+        ));
+
+        let mut to_combine = Vec::with_capacity(points_per_label.len());
+        for (label, points) in points_per_label {
+            let commitment =
+                commitments.get(label.as_str()).ok_or(PCError::MissingPolynomial { label: label.to_string() })?;
+            let challenge = fs_rng.squeeze_short_nonnative_field_element::<E::Fr>();
+            to_combine.push((label, points, *commitment, challenge));
+        }
+
+        fs_rng.absorb_native_field_elements(&proof.w.to_field_elements()?);
+        let point = fs_rng.squeeze_nonnative_field_elements::<E::Fr>(1)[0];
+
+        // Combine the commitments, and the evaluations of the interpolations at the point.
+        let mut combined_comms = BTreeMap::new();
+        let mut combined_value = E::Fr::zero();
+        for (label, points, commitment, challenge) in to_combine {
+            let coeff = challenge * Self::evaluate_vanishing_polynomial(all_points.difference(&points), point);
+            let evaluations = points
+                .iter()
+                .map(|p| {
+                    let value = values
+                        .get(&(label.clone(), *p))
+                        .ok_or(PCError::MissingEvaluation { label: label.to_string() })?;
+                    Ok((*p, *value))
+                })
+                .collect::<Result<Vec<_>>>()?;
+            combined_value += coeff * Self::evaluate_interpolation(&evaluations, point)?;
+            *combined_comms.entry(commitment.degree_bound()).or_insert_with(E::G1Projective::zero) +=
+                commitment.commitment().0.mul(coeff);
+        }
+
+        // The pairing check of the linearization is `e(C - v G - v' gamma G - Z_T(z) W + z W', H) = e(W', beta H)`.
+        let mut bases = vec![vk.vk.g, proof.w, proof.proof.w];
+        let mut coeffs = vec![combined_value, Self::evaluate_vanishing_polynomial(&all_points, point), -point];
+        if let Some(random_v) = proof.proof.random_v {
+            bases.push(vk.vk.gamma_g);
+            coeffs.push(random_v);
+        }
+        let coeffs = coeffs.into_iter().map(|c| c.into()).collect::<Vec<_>>();
+        let combined_adjusted_witness = VariableBase::msm(&bases, &coeffs);
+        end_timer!(check_time);
+
+        Ok(PairingCheck { combined_comms, combined_witness: proof.proof.w.to_projective(), combined_adjusted_witness })
+    }
+
+    /// Opens the linear combinations of the polynomials at the query set, as in `open_combinations`,
+    /// with a proof whose size is independent of the number of points.
+    pub fn open_combinations_multi_point<'a>(
+        universal_prover: &UniversalProver<E>,
+        ck: &CommitterUnionKey<E>,
+        linear_combinations: impl IntoIterator<Item = &'a LinearCombination<E::Fr>>,
+        polynomials: impl IntoIterator<Item = LabeledPolynomial<E::Fr>>,
+        rands: impl IntoIterator<Item = &'a Randomness<E>>,
+        query_set: &QuerySet<E::Fr>,
+        fs_rng: &mut S,
+    ) -> Result<MultiPointProof<E>>
+    where
+        Randomness<E>: 'a,
+    {
+        let (lc_polynomials, lc_randomness) = Self::combine_lc_polynomials(linear_combinations, polynomials, rands)?;
+        Self::multi_point_open(universal_prover, ck, lc_polynomials.iter(), query_set, lc_randomness.iter(), fs_rng)
+    }
+
+    /// Checks the evaluations of the linear combinations, as in `check_combinations`, with a `MultiPointProof`.
+    pub fn check_combinations_multi_point<'a>(
+        vk: &UniversalVerifier<E>,
+        linear_combinations: impl IntoIterator<Item = &'a LinearCombination<E::Fr>>,
+        commitments: impl IntoIterator<Item = &'a LabeledCommitment<Commitment<E>>>,
+        query_set: &QuerySet<E::Fr>,
+        evaluations: &Evaluations<E::Fr>,
+        proof: &MultiPointProof<E>,
+        fs_rng: &mut S,
+    ) -> Result<bool>
+    where
+        Commitment<E>: 'a,
+    {
+        let (lc_commitments, evaluations) =
+            Self::combine_lc_commitments(linear_combinations, commitments, evaluations)?;
+        let pairing_check =
+            Self::multi_point_check_deferred(vk, &lc_commitments, query_set, &evaluations, proof, fs_rng)?;
+        Self::check_pairing(vk, pairing_check)
+    }
+}
+
+impl<E: PairingEngine, S: AlgebraicSponge<E::Fq, 2>> SonicKZG10<E, S> {
+    /// Returns the points at which each polynomial of the query set is opened, and the set of all the points.
+    fn points_per_label(query_set: &QuerySet<E::Fr>) -> (BTreeMap<&String, BTreeSet<E::Fr>>, BTreeSet<E::Fr>) {
+        let mut points_per_label = BTreeMap::<_, BTreeSet<_>>::new();
+        let mut all_points = BTreeSet::new();
+        for (label, (_point_name, point)) in query_set {
+            points_per_label.entry(label).or_default().insert(*point);
+            all_points.insert(*point);
+        }
+        (points_per_label, all_points)
+    }
+
+    /// Returns the quotient of `polynomial` by the vanishing polynomial of `points`, discarding the remainder.
+    fn divide_by_vanishing_polynomial(
+        polynomial: &DensePolynomial<E::Fr>,
+        points: &BTreeSet<E::Fr>,
+    ) -> DensePolynomial<E::Fr> {
+        points.iter().fold(polynomial.clone(), |quotient, point| quotient.divide_by_linear_polynomial(*point).0)
+    }
+
+    /// Evaluates the vanishing polynomial of `points` at `point`.
+    fn evaluate_vanishing_polynomial<'a>(points: impl IntoIterator<Item = &'a E::Fr>, point: E::Fr) -> E::Fr {
+        points.into_iter().map(|p| point - p).product()
+    }
+
+    /// Evaluates at `point` the polynomial that interpolates `evaluations`.
+    fn evaluate_interpolation(evaluations: &[(E::Fr, E::Fr)], point: E::Fr) -> Result<E::Fr> {
+        let mut result = E::Fr::zero();
+        for (i, (x_i, y_i)) in evaluations.iter().enumerate() {
+            let mut numerator = E::Fr::one();
+            let mut denominator = E::Fr::one();
+            for (j, (x_j, _)) in evaluations.iter().enumerate() {
+                if i != j {
+                    numerator *= point - x_j;
+                    denominator *= *x_i - x_j;
+                }
+            }
+            let inverse =
+                denominator.inverse().ok_or_else(|| anyhow!("The points of a polynomial must be distinct"))?;
+            result += *y_i * numerator * inverse;
+        }
+        Ok(result)
+    }
+}
@@ -128,6 +128,67 @@ pub fn bad_degree_bound_test<E: PairingEngine, S: AlgebraicSponge<E::Fq, 2>>() -
     Ok(())
 }
 
+pub fn multi_point_test<E: PairingEngine, S: AlgebraicSponge<E::Fq, 2>>() -> Result<(), PCError> {
+    let rng = &mut TestRng::default();
+    let max_degree = 64;
+    let supported_degree = 32;
+    let num_points = 3;
+    let pp = SonicKZG10::<E, S>::load_srs(max_degree)?;
+    let universal_prover = &pp.to_universal_prover().unwrap();
+
+    for _ in 0..5 {
+        let mut polynomials = Vec::new();
+        for i in 0..5 {
+            let degree = distributions::Uniform::from(1..=supported_degree).sample(rng);
+            let poly = DensePolynomial::rand(degree, rng);
+            polynomials.push(LabeledPolynomial::new(format!("Test{i}"), poly, None, Some(1)));
+        }
+        let (ck, _) = SonicKZG10::<E, S>::trim(&pp, supported_degree, None, 1, None).unwrap();
+        let ck = CommitterUnionKey::union(std::iter::once(&ck));
+        let vk = pp.to_universal_verifier().unwrap();
+
+        let (comms, rands) =
+            SonicKZG10::<E, S>::commit(universal_prover, &ck, polynomials.iter().map(Into::into), Some(rng))?;
+
+        let points = (0..num_points).map(|_| E::Fr::rand(rng)).collect::<Vec<_>>();
+        // Each polynomial is opened at a random, non-empty subset of the points.
+        let mut query_set = QuerySet::new();
+        let mut values = Evaluations::new();
+        for polynomial in &polynomials {
+            let mut num_queries = 0;
+            for (point_id, point) in points.iter().enumerate() {
+                if rng.gen() || (num_queries == 0 && point_id == num_points - 1) {
+                    query_set.insert((polynomial.label().to_string(), (format!("rand_{point_id}"), *point)));
+                    values.insert((polynomial.label().to_string(), *point), polynomial.evaluate(*point));
+                    num_queries += 1;
+                }
+            }
+        }
+
+        let mut sponge_for_open = S::new();
+        let proof = SonicKZG10::multi_point_open(
+            universal_prover,
+            &ck,
+            polynomials.iter(),
+            &query_set,
+            rands.iter(),
+            &mut sponge_for_open,
+        )?;
+        assert!(proof.is_hiding());
+        let mut sponge_for_check = S::new();
+        let result = SonicKZG10::multi_point_check(&vk, &comms, &query_set, &values, &proof, &mut sponge_for_check)?;
+        assert!(result, "proof was incorrect, Query set: {query_set:#?}");
+
+        // A wrong evaluation is rejected.
+        let (key, value) = values.iter().next().map(|(key, value)| (key.clone(), *value)).unwrap();
+        values.insert(key, value + E::Fr::one());
+        let mut sponge_for_check = S::new();
+        let result = SonicKZG10::multi_point_check(&vk, &comms, &query_set, &values, &proof, &mut sponge_for_check)?;
+        assert!(!result, "proof was accepted for a wrong evaluation");
+    }
+    Ok(())
+}
+
 pub fn lagrange_test_template<E: PairingEngine, S: AlgebraicSponge<E::Fq, 2>>()
 -> Result<Vec<TestComponents<E, S>>, PCError> {
     let num_iters = 10usize;
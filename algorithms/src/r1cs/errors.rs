@@ -54,3 +54,20 @@ impl From<std::io::Error> for SynthesisError {
         SynthesisError::IoError(e)
     }
 }
+
+impl snarkvm_utilities::ErrorCode for SynthesisError {
+    fn error_code(&self) -> u32 {
+        match self {
+            Self::AnyhowError(..) => 2000,
+            Self::AssignmentMissing => 2001,
+            Self::ConstraintFieldError(..) => 2002,
+            Self::DivisionByZero => 2003,
+            Self::Unsatisfiable => 2004,
+            Self::PolyTooLarge => 2005,
+            Self::UnexpectedIdentity => 2006,
+            Self::IoError(..) => 2007,
+            Self::MalformedVerifyingKey(..) => 2008,
+            Self::UnconstrainedVariable => 2009,
+        }
+    }
+}
@@ -0,0 +1,282 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::r1cs::{
+    errors::SynthesisError,
+    ConstraintSynthesizer,
+    ConstraintSystem,
+    Index,
+    LinearCombination,
+    Variable,
+};
+use snarkvm_fields::Field;
+
+/// A sparse matrix, where each row is a list of `(coefficient, column)` pairs sorted by column.
+pub type SparseMatrix<F> = Vec<Vec<(F, usize)>>;
+
+/// An R1CS instance given by its explicit `A`, `B`, and `C` matrices.
+///
+/// The columns are ordered as `[1, public variables..., private variables...]`,
+/// so that an assignment `z` satisfies the instance if `(A z) * (B z) = (C z)` holds row-wise.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ConstraintMatrices<F: Field> {
+    /// The number of public variables, including the constant `1`.
+    pub num_public_variables: usize,
+    /// The number of private variables.
+    pub num_private_variables: usize,
+    /// The `A` matrix.
+    pub a: SparseMatrix<F>,
+    /// The `B` matrix.
+    pub b: SparseMatrix<F>,
+    /// The `C` matrix.
+    pub c: SparseMatrix<F>,
+}
+
+impl<F: Field> ConstraintMatrices<F> {
+    /// Synthesizes the constraints of the given circuit into matrices, without computing an assignment.
+    pub fn from_circuit<C: ConstraintSynthesizer<F>>(circuit: &C) -> Result<Self, SynthesisError> {
+        let mut builder = MatrixBuilder::new(true);
+        circuit.generate_constraints(&mut builder)?;
+        Ok(builder.into_matrices())
+    }
+
+    /// Synthesizes the constraints of the given circuit into matrices,
+    /// along with the full assignment `[1, public variables..., private variables...]`.
+    pub fn from_circuit_with_assignment<C: ConstraintSynthesizer<F>>(
+        circuit: &C,
+    ) -> Result<(Self, Vec<F>), SynthesisError> {
+        let mut builder = MatrixBuilder::new(false);
+        circuit.generate_constraints(&mut builder)?;
+        let assignment = builder.public_assignment.iter().chain(&builder.private_assignment).copied().collect();
+        Ok((builder.into_matrices(), assignment))
+    }
+
+    /// Returns the number of constraints.
+    pub fn num_constraints(&self) -> usize {
+        self.a.len()
+    }
+
+    /// Returns the number of variables, including the constant `1`.
+    pub fn num_variables(&self) -> usize {
+        self.num_public_variables + self.num_private_variables
+    }
+
+    /// Returns `true` if the matrices have one row per constraint, and every column is in range.
+    pub fn is_well_formed(&self) -> bool {
+        let num_variables = self.num_variables();
+        self.num_public_variables > 0
+            && self.b.len() == self.a.len()
+            && self.c.len() == self.a.len()
+            && [&self.a, &self.b, &self.c]
+                .iter()
+                .all(|matrix| matrix.iter().flatten().all(|(_, column)| *column < num_variables))
+    }
+
+    /// Returns `true` if the given full assignment `[1, public variables..., private variables...]`
+    /// satisfies every constraint.
+    pub fn is_satisfied(&self, assignment: &[F]) -> bool {
+        if !self.is_well_formed() || assignment.len() != self.num_variables() || assignment[0] != F::one() {
+            return false;
+        }
+        let evaluate = |row: &[(F, usize)]| row.iter().map(|(coeff, column)| *coeff * assignment[*column]).sum::<F>();
+        self.a.iter().zip(&self.b).zip(&self.c).all(|((a, b), c)| evaluate(a) * evaluate(b) == evaluate(c))
+    }
+}
+
+/// A constraint system that records constraints as matrix rows.
+struct MatrixBuilder<F: Field> {
+    is_setup: bool,
+    public_assignment: Vec<F>,
+    private_assignment: Vec<F>,
+    num_public_variables: usize,
+    num_private_variables: usize,
+    a: Vec<Vec<(F, Index)>>,
+    b: Vec<Vec<(F, Index)>>,
+    c: Vec<Vec<(F, Index)>>,
+}
+
+impl<F: Field> MatrixBuilder<F> {
+    fn new(is_setup: bool) -> Self {
+        Self {
+            is_setup,
+            public_assignment: vec![F::one()],
+            private_assignment: Vec::new(),
+            num_public_variables: 1,
+            num_private_variables: 0,
+            a: Vec::new(),
+            b: Vec::new(),
+            c: Vec::new(),
+        }
+    }
+
+    fn make_row(lc: &LinearCombination<F>) -> Vec<(F, Index)> {
+        lc.as_ref().iter().map(|(var, coeff)| (*coeff, var.get_unchecked())).collect()
+    }
+
+    /// Converts the recorded rows into sorted sparse rows, merging repeated columns and dropping zero coefficients.
+    fn into_matrices(self) -> ConstraintMatrices<F> {
+        let num_public_variables = self.num_public_variables;
+        let into_matrix = |rows: Vec<Vec<(F, Index)>>| -> SparseMatrix<F> {
+            rows.into_iter()
+                .map(|row| {
+                    let mut sparse_row: Vec<(F, usize)> = Vec::with_capacity(row.len());
+                    for (coeff, index) in row {
+                        let column = match index {
+                            Index::Public(i) => i,
+                            Index::Private(i) => num_public_variables + i,
+                        };
+                        match sparse_row.binary_search_by_key(&column, |(_, c)| *c) {
+                            Ok(position) => sparse_row[position].0 += coeff,
+                            Err(position) => sparse_row.insert(position, (coeff, column)),
+                        }
+                    }
+                    sparse_row.retain(|(coeff, _)| !coeff.is_zero());
+                    sparse_row
+                })
+                .collect()
+        };
+        ConstraintMatrices {
+            num_public_variables,
+            num_private_variables: self.num_private_variables,
+            a: into_matrix(self.a),
+            b: into_matrix(self.b),
+            c: into_matrix(self.c),
+        }
+    }
+}
+
+impl<F: Field> ConstraintSystem<F> for MatrixBuilder<F> {
+    type Root = Self;
+
+    fn alloc<FN, A, AR>(&mut self, _: A, f: FN) -> Result<Variable, SynthesisError>
+    where
+        FN: FnOnce() -> Result<F, SynthesisError>,
+        A: FnOnce() -> AR,
+        AR: AsRef<str>,
+    {
+        if !self.is_setup {
+            self.private_assignment.push(f()?);
+        }
+        let index = self.num_private_variables;
+        self.num_private_variables += 1;
+        Ok(Variable::new_unchecked(Index::Private(index)))
+    }
+
+    fn alloc_input<FN, A, AR>(&mut self, _: A, f: FN) -> Result<Variable, SynthesisError>
+    where
+        FN: FnOnce() -> Result<F, SynthesisError>,
+        A: FnOnce() -> AR,
+        AR: AsRef<str>,
+    {
+        if !self.is_setup {
+            self.public_assignment.push(f()?);
+        }
+        let index = self.num_public_variables;
+        self.num_public_variables += 1;
+        Ok(Variable::new_unchecked(Index::Public(index)))
+    }
+
+    fn enforce<A, AR, LA, LB, LC>(&mut self, _: A, a: LA, b: LB, c: LC)
+    where
+        A: FnOnce() -> AR,
+        AR: AsRef<str>,
+        LA: FnOnce(LinearCombination<F>) -> LinearCombination<F>,
+        LB: FnOnce(LinearCombination<F>) -> LinearCombination<F>,
+        LC: FnOnce(LinearCombination<F>) -> LinearCombination<F>,
+    {
+        self.a.push(Self::make_row(&a(LinearCombination::zero())));
+        self.b.push(Self::make_row(&b(LinearCombination::zero())));
+        self.c.push(Self::make_row(&c(LinearCombination::zero())));
+    }
+
+    fn push_namespace<NR, N>(&mut self, _: N)
+    where
+        NR: AsRef<str>,
+        N: FnOnce() -> NR,
+    {
+        // Do nothing; we don't care about namespaces in this context.
+    }
+
+    fn pop_namespace(&mut self) {
+        // Do nothing; we don't care about namespaces in this context.
+    }
+
+    fn get_root(&mut self) -> &mut Self::Root {
+        self
+    }
+
+    fn num_constraints(&self) -> usize {
+        self.a.len()
+    }
+
+    fn num_public_variables(&self) -> usize {
+        self.num_public_variables
+    }
+
+    fn num_private_variables(&self) -> usize {
+        self.num_private_variables
+    }
+
+    fn is_in_setup_mode(&self) -> bool {
+        self.is_setup
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_curves::bls12_377::Fr;
+    use snarkvm_fields::One;
+
+    /// Enforces `x * y = z`, with public `z` and private `x` and `y`.
+    struct MulCircuit {
+        x: Fr,
+        y: Fr,
+        z: Fr,
+    }
+
+    impl ConstraintSynthesizer<Fr> for MulCircuit {
+        fn generate_constraints<CS: ConstraintSystem<Fr>>(&self, cs: &mut CS) -> Result<(), SynthesisError> {
+            let z = cs.alloc_input(|| "z", || Ok(self.z))?;
+            let x = cs.alloc(|| "x", || Ok(self.x))?;
+            let y = cs.alloc(|| "y", || Ok(self.y))?;
+            cs.enforce(|| "x * y = z", |lc| lc + x, |lc| lc + y, |lc| lc + z);
+            // Enforce `(x + x - x) * 1 = x`, to exercise the merging of repeated columns.
+            cs.enforce(|| "x * 1 = x", |lc| lc + x + x - x, |lc| lc + CS::one(), |lc| lc + x);
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn test_matrices_from_circuit() {
+        let two = Fr::one() + Fr::one();
+        let three = two + Fr::one();
+        let circuit = MulCircuit { x: two, y: three, z: two * three };
+
+        let (matrices, assignment) = ConstraintMatrices::from_circuit_with_assignment(&circuit).unwrap();
+        assert_eq!(matrices, ConstraintMatrices::from_circuit(&circuit).unwrap());
+        assert_eq!(matrices.num_constraints(), 2);
+        assert_eq!(matrices.num_public_variables, 2);
+        assert_eq!(matrices.num_private_variables, 2);
+        assert_eq!(matrices.a[1], vec![(Fr::one(), 2)]);
+        assert_eq!(assignment, vec![Fr::one(), two * three, two, three]);
+        assert!(matrices.is_satisfied(&assignment));
+
+        // Ensure an incorrect assignment is rejected.
+        let mut assignment = assignment;
+        assignment[1] = three;
+        assert!(!matrices.is_satisfied(&assignment));
+        assert!(!matrices.is_satisfied(&assignment[..3]));
+    }
+}
@@ -27,6 +27,9 @@ pub use errors::*;
 mod linear_combination;
 pub use linear_combination::*;
 
+mod matrices;
+pub use matrices::*;
+
 mod namespace;
 pub use namespace::*;
 
@@ -0,0 +1,90 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::{
+    msm::{set_distributed_msm_on_thread, DistributedMsm, MsmTransport},
+    r1cs::ConstraintSynthesizer,
+    SNARK,
+};
+use snarkvm_utilities::{execute_in_thread_pool, ThreadPoolConfig};
+
+use anyhow::Result;
+use rand::{CryptoRng, Rng};
+use std::{collections::BTreeMap, sync::Arc};
+
+/// A prover that splits the MSMs of a single proof across the workers of a transport.
+///
+/// The commitments, and the evaluation proofs, are the MSMs of the prover. Each one is partitioned across
+/// the workers, and their partial results are combined by the coordinator, which runs the rest of the prover.
+/// As the combined commitments are the same as those computed locally, the Fiat-Shamir transcript is unchanged,
+/// and the proof is the same as the one computed by `SNARK::prove_batch_with_seed` with the same seed.
+#[derive(Clone)]
+pub struct DistributedProver {
+    msm: DistributedMsm,
+}
+
+impl DistributedProver {
+    /// Initializes a prover for the workers of `transport`.
+    pub fn new(transport: Arc<dyn MsmTransport>) -> Self {
+        Self { msm: DistributedMsm::new(transport) }
+    }
+
+    /// Initializes a prover with the given coordinator.
+    pub fn with_msm(msm: DistributedMsm) -> Self {
+        Self { msm }
+    }
+
+    /// Returns a proof, with its MSMs split across the workers.
+    pub fn prove<S: SNARK, C: ConstraintSynthesizer<S::ScalarField>, R: Rng + CryptoRng>(
+        &self,
+        universal_prover: &S::UniversalProver,
+        fs_parameters: &S::FSParameters,
+        proving_key: &S::ProvingKey,
+        constraints: &C,
+        rng: &mut R,
+    ) -> Result<S::Proof>
+    where
+        S::UniversalProver: Sync,
+        S::FSParameters: Sync,
+    {
+        let mut keys_to_constraints = BTreeMap::new();
+        keys_to_constraints.insert(proving_key, std::slice::from_ref(constraints));
+        self.prove_batch::<S, C, R>(universal_prover, fs_parameters, &keys_to_constraints, rng)
+    }
+
+    /// Returns a batch proof, with its MSMs split across the workers.
+    ///
+    /// The proof runs on a dedicated thread pool whose workers use this coordinator, so that the proofs computed
+    /// concurrently by the process are unaffected. The RNG may not be sent to the pool, so the proof is the one
+    /// of `SNARK::prove_batch_with_seed` with a seed sampled from `rng`.
+    pub fn prove_batch<S: SNARK, C: ConstraintSynthesizer<S::ScalarField>, R: Rng + CryptoRng>(
+        &self,
+        universal_prover: &S::UniversalProver,
+        fs_parameters: &S::FSParameters,
+        keys_to_constraints: &BTreeMap<&S::ProvingKey, &[C]>,
+        rng: &mut R,
+    ) -> Result<S::Proof>
+    where
+        S::UniversalProver: Sync,
+        S::FSParameters: Sync,
+    {
+        let msm = Arc::new(self.msm.clone());
+        let pool =
+            ThreadPoolConfig::new().start_handler(move |_| set_distributed_msm_on_thread(Some(msm.clone()))).build()?;
+        let seed = rng.gen();
+        execute_in_thread_pool(&pool, || {
+            S::prove_batch_with_seed(universal_prover, fs_parameters, keys_to_constraints, seed)
+        })
+    }
+}
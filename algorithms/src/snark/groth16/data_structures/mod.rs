@@ -0,0 +1,33 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+/// The Groth16 certificate.
+pub(super) mod certificate;
+pub use certificate::*;
+
+/// The Groth16 circuit proving key.
+pub(super) mod proving_key;
+pub use proving_key::*;
+
+/// The Groth16 circuit verifying key.
+pub(super) mod verifying_key;
+pub use verifying_key::*;
+
+/// The Groth16 prepared circuit verifying key.
+pub(super) mod prepared_verifying_key;
+pub use prepared_verifying_key::*;
+
+/// The Groth16 zkSNARK proof.
+pub(super) mod proof;
+pub use proof::*;
@@ -0,0 +1,116 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::VerifyingKey;
+use snarkvm_curves::{PairingCurve, PairingEngine};
+use snarkvm_utilities::{
+    error,
+    io::{self, Read, Write},
+    serialize::*,
+    FromBytes,
+    ToBytes,
+};
+
+use std::cmp::Ordering;
+
+/// A verifying key together with its G2 elements prepared for the Miller loop,
+/// so that the line evaluations are computed once rather than for every proof.
+///
+/// Deserializing with validation recomputes the prepared elements to check them, so a prepared key
+/// should be stored where it can be read back without validation, e.g. alongside the program.
+#[derive(Clone, Debug, PartialEq, Eq, CanonicalSerialize)]
+pub struct PreparedVerifyingKey<E: PairingEngine> {
+    /// The verifying key.
+    pub orig_vk: VerifyingKey<E>,
+    /// The prepared form of `beta * G2`.
+    pub prepared_beta_g2: <E::G2Affine as PairingCurve>::Prepared,
+    /// The prepared form of `gamma * G2`.
+    pub prepared_gamma_g2: <E::G2Affine as PairingCurve>::Prepared,
+    /// The prepared form of `delta * G2`.
+    pub prepared_delta_g2: <E::G2Affine as PairingCurve>::Prepared,
+}
+
+impl<E: PairingEngine> PreparedVerifyingKey<E> {
+    /// Prepares the given verifying key.
+    pub fn new(vk: VerifyingKey<E>) -> Self {
+        let prepared_beta_g2 = vk.beta_g2.prepare();
+        let prepared_gamma_g2 = vk.gamma_g2.prepare();
+        let prepared_delta_g2 = vk.delta_g2.prepare();
+        Self { orig_vk: vk, prepared_beta_g2, prepared_gamma_g2, prepared_delta_g2 }
+    }
+}
+
+impl<E: PairingEngine> VerifyingKey<E> {
+    /// Returns the prepared form of this verifying key.
+    pub fn prepare(&self) -> PreparedVerifyingKey<E> {
+        PreparedVerifyingKey::new(self.clone())
+    }
+}
+
+impl<E: PairingEngine> Valid for PreparedVerifyingKey<E> {
+    fn check(&self) -> Result<(), SerializationError> {
+        self.orig_vk.check()?;
+        // The verifier pairs against the prepared elements, so they must be those of the key.
+        match self.prepared_beta_g2 == self.orig_vk.beta_g2.prepare()
+            && self.prepared_gamma_g2 == self.orig_vk.gamma_g2.prepare()
+            && self.prepared_delta_g2 == self.orig_vk.delta_g2.prepare()
+        {
+            true => Ok(()),
+            false => Err(SerializationError::InvalidData),
+        }
+    }
+}
+
+impl<E: PairingEngine> CanonicalDeserialize for PreparedVerifyingKey<E> {
+    fn deserialize_with_mode<R: Read>(
+        mut reader: R,
+        compress: Compress,
+        validate: Validate,
+    ) -> Result<Self, SerializationError> {
+        let key = Self {
+            orig_vk: CanonicalDeserialize::deserialize_with_mode(&mut reader, compress, Validate::No)?,
+            prepared_beta_g2: CanonicalDeserialize::deserialize_with_mode(&mut reader, compress, Validate::No)?,
+            prepared_gamma_g2: CanonicalDeserialize::deserialize_with_mode(&mut reader, compress, Validate::No)?,
+            prepared_delta_g2: CanonicalDeserialize::deserialize_with_mode(&mut reader, compress, Validate::No)?,
+        };
+        if let Validate::Yes = validate {
+            key.check()?;
+        }
+        Ok(key)
+    }
+}
+
+impl<E: PairingEngine> FromBytes for PreparedVerifyingKey<E> {
+    fn read_le<R: Read>(r: R) -> io::Result<Self> {
+        Self::deserialize_compressed(r).map_err(|_| error("could not deserialize PreparedVerifyingKey"))
+    }
+}
+
+impl<E: PairingEngine> ToBytes for PreparedVerifyingKey<E> {
+    fn write_le<W: Write>(&self, w: W) -> io::Result<()> {
+        self.serialize_compressed(w).map_err(|_| error("could not serialize PreparedVerifyingKey"))
+    }
+}
+
+impl<E: PairingEngine> Ord for PreparedVerifyingKey<E> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.orig_vk.id.cmp(&other.orig_vk.id)
+    }
+}
+
+impl<E: PairingEngine> PartialOrd for PreparedVerifyingKey<E> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
@@ -0,0 +1,112 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::VerifyingKey;
+use crate::SNARKError;
+use snarkvm_curves::{AffineCurve, PairingEngine, ProjectiveCurve};
+use snarkvm_fields::Field;
+use snarkvm_utilities::{
+    error,
+    io::{self, Read, Write},
+    serialize::*,
+    FromBytes,
+    ToBytes,
+    Uniform,
+};
+
+use anyhow::{bail, Result};
+use rand::{CryptoRng, Rng};
+use std::collections::BTreeMap;
+
+/// A Groth16 proof for a single instance of a circuit.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, CanonicalSerialize, CanonicalDeserialize)]
+pub struct Proof<E: PairingEngine> {
+    pub a: E::G1Affine,
+    pub b: E::G2Affine,
+    pub c: E::G1Affine,
+}
+
+/// The Groth16 proofs of a batch, ordered by verifying key and then by instance.
+#[derive(Clone, Debug, PartialEq, Eq, CanonicalSerialize, CanonicalDeserialize)]
+pub struct BatchProof<E: PairingEngine> {
+    pub proofs: Vec<Proof<E>>,
+}
+
+impl<E: PairingEngine> Proof<E> {
+    /// Returns a fresh proof for the same statement, which cannot be linked to `self`.
+    ///
+    /// The proof `(A / r_1, r_1 * B + r_1 * r_2 * delta, C + r_2 * A)` satisfies the verifying equation,
+    /// and is distributed as a proof computed by the prover for random `r_1` and `r_2`.
+    pub fn rerandomize<R: Rng + CryptoRng>(&self, vk: &VerifyingKey<E>, rng: &mut R) -> Self {
+        let (r_1, r_1_inverse) = loop {
+            let r_1 = E::Fr::rand(rng);
+            if let Some(r_1_inverse) = r_1.inverse() {
+                break (r_1, r_1_inverse);
+            }
+        };
+        let r_2 = E::Fr::rand(rng);
+
+        let a = self.a * r_1_inverse;
+        let b = self.b * r_1 + vk.delta_g2 * (r_1 * r_2);
+        let c = self.c.to_projective() + self.a * r_2;
+        Self { a: a.to_affine(), b: b.to_affine(), c: c.to_affine() }
+    }
+}
+
+impl<E: PairingEngine> BatchProof<E> {
+    /// Returns the number of instances proven by `self`.
+    pub fn batch_size(&self) -> usize {
+        self.proofs.len()
+    }
+
+    /// Returns a fresh batch proof for the same statements, which cannot be linked to `self`.
+    /// The proofs are rerandomized with the verifying keys of their circuits, given with the number of
+    /// instances of each circuit, as in `SNARK::verify_batch`.
+    pub fn rerandomize<R: Rng + CryptoRng>(
+        &self,
+        keys_to_batch_sizes: &BTreeMap<&VerifyingKey<E>, usize>,
+        rng: &mut R,
+    ) -> Result<Self> {
+        if keys_to_batch_sizes.values().sum::<usize>() != self.batch_size() {
+            bail!(SNARKError::BatchSizeMismatch);
+        }
+        let keys = keys_to_batch_sizes.iter().flat_map(|(vk, batch_size)| core::iter::repeat(*vk).take(*batch_size));
+        let proofs = keys.zip(&self.proofs).map(|(vk, proof)| proof.rerandomize(vk, rng)).collect();
+        Ok(Self { proofs })
+    }
+}
+
+impl<E: PairingEngine> ToBytes for Proof<E> {
+    fn write_le<W: Write>(&self, mut w: W) -> io::Result<()> {
+        Self::serialize_compressed(self, &mut w).map_err(|_| error("Failed to serialize proof"))
+    }
+}
+
+impl<E: PairingEngine> FromBytes for Proof<E> {
+    fn read_le<R: Read>(mut r: R) -> io::Result<Self> {
+        Self::deserialize_compressed(&mut r).map_err(|_| error("Failed to deserialize proof"))
+    }
+}
+
+impl<E: PairingEngine> ToBytes for BatchProof<E> {
+    fn write_le<W: Write>(&self, mut w: W) -> io::Result<()> {
+        Self::serialize_compressed(self, &mut w).map_err(|_| error("Failed to serialize proof"))
+    }
+}
+
+impl<E: PairingEngine> FromBytes for BatchProof<E> {
+    fn read_le<R: Read>(mut r: R) -> io::Result<Self> {
+        Self::deserialize_compressed(&mut r).map_err(|_| error("Failed to deserialize proof"))
+    }
+}
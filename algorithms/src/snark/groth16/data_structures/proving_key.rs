@@ -0,0 +1,77 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::VerifyingKey;
+use snarkvm_curves::PairingEngine;
+use snarkvm_utilities::{
+    error,
+    io::{self, Read, Write},
+    serialize::*,
+    FromBytes,
+    ToBytes,
+};
+
+use std::cmp::Ordering;
+
+/// Proving key for a specific circuit.
+#[derive(Clone, Debug, PartialEq, Eq, CanonicalSerialize, CanonicalDeserialize)]
+pub struct ProvingKey<E: PairingEngine> {
+    /// The verifying key of the circuit.
+    pub vk: VerifyingKey<E>,
+    /// `beta * G1`.
+    pub beta_g1: E::G1Affine,
+    /// `delta * G1`.
+    pub delta_g1: E::G1Affine,
+    /// `A_i(tau) * G1` for each variable `i`.
+    pub a_query: Vec<E::G1Affine>,
+    /// `B_i(tau) * G1` for each variable `i`.
+    pub b_g1_query: Vec<E::G1Affine>,
+    /// `B_i(tau) * G2` for each variable `i`.
+    pub b_g2_query: Vec<E::G2Affine>,
+    /// `tau^i * Z(tau) / delta * G1` for each coefficient `i` of the quotient polynomial.
+    pub h_query: Vec<E::G1Affine>,
+    /// `(beta * A_i(tau) + alpha * B_i(tau) + C_i(tau)) / delta * G1` for each private variable `i`.
+    pub l_query: Vec<E::G1Affine>,
+}
+
+impl<E: PairingEngine> ProvingKey<E> {
+    /// Returns the number of variables of the circuit, including the constant `1`.
+    pub fn num_variables(&self) -> usize {
+        self.a_query.len()
+    }
+}
+
+impl<E: PairingEngine> FromBytes for ProvingKey<E> {
+    fn read_le<R: Read>(r: R) -> io::Result<Self> {
+        Self::deserialize_compressed(r).map_err(|_| error("could not deserialize ProvingKey"))
+    }
+}
+
+impl<E: PairingEngine> ToBytes for ProvingKey<E> {
+    fn write_le<W: Write>(&self, w: W) -> io::Result<()> {
+        self.serialize_compressed(w).map_err(|_| error("could not serialize ProvingKey"))
+    }
+}
+
+impl<E: PairingEngine> Ord for ProvingKey<E> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.vk.cmp(&other.vk)
+    }
+}
+
+impl<E: PairingEngine> PartialOrd for ProvingKey<E> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
@@ -0,0 +1,74 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::snark::varuna::CircuitId;
+use snarkvm_curves::PairingEngine;
+use snarkvm_utilities::{
+    error,
+    io::{self, Read, Write},
+    serialize::*,
+    FromBytes,
+    ToBytes,
+};
+
+use std::cmp::Ordering;
+
+/// Verification key for a specific circuit.
+#[derive(Clone, Debug, PartialEq, Eq, CanonicalSerialize, CanonicalDeserialize)]
+pub struct VerifyingKey<E: PairingEngine> {
+    /// The hash of the circuit and of the elements of this key.
+    pub id: CircuitId,
+    /// `alpha * G1`.
+    pub alpha_g1: E::G1Affine,
+    /// `beta * G2`.
+    pub beta_g2: E::G2Affine,
+    /// `gamma * G2`.
+    pub gamma_g2: E::G2Affine,
+    /// `delta * G2`.
+    pub delta_g2: E::G2Affine,
+    /// `(beta * A_i(tau) + alpha * B_i(tau) + C_i(tau)) / gamma * G1` for each public variable `i`,
+    /// including the constant `1`.
+    pub gamma_abc_g1: Vec<E::G1Affine>,
+}
+
+impl<E: PairingEngine> VerifyingKey<E> {
+    /// Returns the number of public inputs expected by this key, including the constant `1`.
+    pub fn num_public_inputs(&self) -> usize {
+        self.gamma_abc_g1.len()
+    }
+}
+
+impl<E: PairingEngine> FromBytes for VerifyingKey<E> {
+    fn read_le<R: Read>(r: R) -> io::Result<Self> {
+        Self::deserialize_compressed(r).map_err(|_| error("could not deserialize VerifyingKey"))
+    }
+}
+
+impl<E: PairingEngine> ToBytes for VerifyingKey<E> {
+    fn write_le<W: Write>(&self, w: W) -> io::Result<()> {
+        self.serialize_compressed(w).map_err(|_| error("could not serialize VerifyingKey"))
+    }
+}
+
+impl<E: PairingEngine> Ord for VerifyingKey<E> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.id.cmp(&other.id)
+    }
+}
+
+impl<E: PairingEngine> PartialOrd for VerifyingKey<E> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
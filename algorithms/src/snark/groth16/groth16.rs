@@ -0,0 +1,438 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::{BatchProof, Certificate, PreparedVerifyingKey, Proof, ProvingKey, VerifyingKey};
+use crate::{
+    fft::EvaluationDomain,
+    msm::{FixedBase, VariableBase},
+    r1cs::{ConstraintMatrices, ConstraintSynthesizer, SparseMatrix, SynthesisError},
+    snark::varuna::CircuitId,
+    AlgebraicSponge,
+    ProverOptions,
+    SNARKError,
+    SNARK,
+};
+use snarkvm_curves::{AffineCurve, PairingCurve, PairingEngine, ProjectiveCurve};
+use snarkvm_fields::{Field, One, PrimeField, Zero};
+use snarkvm_utilities::{cfg_iter, serialize::*, Uniform};
+
+use anyhow::{anyhow, bail, ensure, Result};
+use blake2::Digest;
+use core::marker::PhantomData;
+use itertools::Itertools;
+use rand::{CryptoRng, Rng};
+use std::{borrow::Borrow, collections::BTreeMap};
+
+#[cfg(not(feature = "serial"))]
+use rayon::prelude::*;
+
+/// The Groth16 proof system.
+///
+/// Unlike Varuna, Groth16 has no universal setup: the keys of each circuit are sampled by a trusted party,
+/// who must discard the trapdoor afterwards. In exchange, a proof is three group elements,
+/// and verifying it takes a single product of four pairings.
+#[derive(Clone, Debug)]
+pub struct Groth16<E: PairingEngine, FS: AlgebraicSponge<E::Fq, 2>>(#[doc(hidden)] PhantomData<(E, FS)>);
+
+impl<E: PairingEngine, FS: AlgebraicSponge<E::Fq, 2>> Groth16<E, FS> {
+    /// Samples the proving and verifying keys of the circuit, using a trapdoor drawn from `rng`.
+    /// Anyone who learns the trapdoor can forge proofs, so `rng` must not be reused or revealed.
+    pub fn circuit_setup_with_rng<C: ConstraintSynthesizer<E::Fr>, R: Rng + CryptoRng>(
+        circuit: &C,
+        rng: &mut R,
+    ) -> Result<(ProvingKey<E>, VerifyingKey<E>)> {
+        let setup_time = start_timer!(|| "Groth16::CircuitSetup");
+
+        let matrices = ConstraintMatrices::from_circuit(circuit)?;
+        let num_public = matrices.num_public_variables;
+        let domain = Self::domain(&matrices)?;
+
+        // Sample the trapdoor, with `tau` outside of the domain so that the Lagrange basis is defined at `tau`.
+        let alpha = E::Fr::rand(rng);
+        let beta = E::Fr::rand(rng);
+        let gamma = E::Fr::rand(rng);
+        let delta = E::Fr::rand(rng);
+        let mut tau = E::Fr::rand(rng);
+        while domain.evaluate_vanishing_polynomial(tau).is_zero() {
+            tau = E::Fr::rand(rng);
+        }
+        let gamma_inverse = gamma.inverse().ok_or_else(|| anyhow!("Sampled a zero gamma"))?;
+        let delta_inverse = delta.inverse().ok_or_else(|| anyhow!("Sampled a zero delta"))?;
+
+        // Evaluate the QAP polynomials of every variable at `tau`.
+        let (a, b, c) = Self::evaluate_qap(&matrices, &domain, tau);
+        let abc = |i: usize| beta * a[i] + alpha * b[i] + c[i];
+        let gamma_abc = (0..num_public).map(|i| abc(i) * gamma_inverse).collect::<Vec<_>>();
+        let l = (num_public..matrices.num_variables()).map(|i| abc(i) * delta_inverse).collect::<Vec<_>>();
+        // The quotient polynomial has degree at most `|domain| - 2`.
+        let z_over_delta = domain.evaluate_vanishing_polynomial(tau) * delta_inverse;
+        let h = core::iter::successors(Some(z_over_delta), |power| Some(*power * tau))
+            .take(domain.size() - 1)
+            .collect::<Vec<_>>();
+
+        let g1 = E::G1Projective::prime_subgroup_generator();
+        let g2 = E::G2Projective::prime_subgroup_generator();
+        let mut vk = VerifyingKey {
+            id: CircuitId([0u8; 32]),
+            alpha_g1: (g1 * alpha).to_affine(),
+            beta_g2: (g2 * beta).to_affine(),
+            gamma_g2: (g2 * gamma).to_affine(),
+            delta_g2: (g2 * delta).to_affine(),
+            gamma_abc_g1: Self::batch_mul(g1, &gamma_abc),
+        };
+        vk.id = Self::compute_id(&vk)?;
+
+        let pk = ProvingKey {
+            vk: vk.clone(),
+            beta_g1: (g1 * beta).to_affine(),
+            delta_g1: (g1 * delta).to_affine(),
+            a_query: Self::batch_mul(g1, &a),
+            b_g1_query: Self::batch_mul(g1, &b),
+            b_g2_query: Self::batch_mul(g2, &b),
+            h_query: Self::batch_mul(g1, &h),
+            l_query: Self::batch_mul(g1, &l),
+        };
+
+        end_timer!(setup_time);
+        Ok((pk, vk))
+    }
+
+    /// Returns the domain of the QAP, which has a row for each constraint and for each public variable.
+    fn domain(matrices: &ConstraintMatrices<E::Fr>) -> Result<EvaluationDomain<E::Fr>> {
+        let num_rows = matrices.num_constraints() + matrices.num_public_variables;
+        EvaluationDomain::new(num_rows).ok_or_else(|| SynthesisError::PolyTooLarge.into())
+    }
+
+    /// Returns the evaluations at `tau` of the `A`, `B`, and `C` polynomials of each variable.
+    /// The row of each public variable `i` enforces `z_i * 0 = 0`, which binds the proof to the public inputs.
+    fn evaluate_qap(
+        matrices: &ConstraintMatrices<E::Fr>,
+        domain: &EvaluationDomain<E::Fr>,
+        tau: E::Fr,
+    ) -> (Vec<E::Fr>, Vec<E::Fr>, Vec<E::Fr>) {
+        let lagrange = domain.evaluate_all_lagrange_coefficients(tau);
+        let evaluate = |matrix: &SparseMatrix<E::Fr>| {
+            let mut evaluations = vec![E::Fr::zero(); matrices.num_variables()];
+            for (row, lagrange) in matrix.iter().zip(&lagrange) {
+                for (coeff, column) in row {
+                    evaluations[*column] += *coeff * lagrange;
+                }
+            }
+            evaluations
+        };
+        let mut a = evaluate(&matrices.a);
+        let num_constraints = matrices.num_constraints();
+        for (i, a) in a.iter_mut().take(matrices.num_public_variables).enumerate() {
+            *a += lagrange[num_constraints + i];
+        }
+        (a, evaluate(&matrices.b), evaluate(&matrices.c))
+    }
+
+    /// Returns the product of `generator` with each of the `scalars`.
+    fn batch_mul<G: ProjectiveCurve>(generator: G, scalars: &[G::ScalarField]) -> Vec<G::Affine> {
+        let scalar_size = <G::ScalarField as PrimeField>::size_in_bits();
+        let window = FixedBase::get_mul_window_size(scalars.len());
+        let table = FixedBase::get_window_table(scalar_size, window, generator);
+        G::batch_normalization_into_affine(FixedBase::msm(scalar_size, window, &table, scalars))
+    }
+
+    /// Returns the hash of the elements of the verifying key.
+    fn compute_id(vk: &VerifyingKey<E>) -> Result<CircuitId> {
+        let mut blake2 = blake2::Blake2s256::new();
+        vk.alpha_g1.serialize_uncompressed(&mut blake2)?;
+        vk.beta_g2.serialize_uncompressed(&mut blake2)?;
+        vk.gamma_g2.serialize_uncompressed(&mut blake2)?;
+        vk.delta_g2.serialize_uncompressed(&mut blake2)?;
+        vk.gamma_abc_g1.serialize_uncompressed(&mut blake2)?;
+        Ok(CircuitId(blake2.finalize().into()))
+    }
+
+    /// Returns the proof of an instance of the circuit of `pk`, masked by `r` and `s`.
+    fn prove_instance<C: ConstraintSynthesizer<E::Fr>>(
+        pk: &ProvingKey<E>,
+        constraints: &C,
+        r: E::Fr,
+        s: E::Fr,
+    ) -> Result<Proof<E>> {
+        let (matrices, assignment) = ConstraintMatrices::from_circuit_with_assignment(constraints)?;
+        if matrices.num_variables() != pk.num_variables() || matrices.num_public_variables != pk.vk.num_public_inputs()
+        {
+            bail!(SNARKError::CircuitNotFound);
+        }
+        if !matrices.is_satisfied(&assignment) {
+            bail!(SynthesisError::Unsatisfiable);
+        }
+        let domain = Self::domain(&matrices)?;
+        ensure!(domain.size() == pk.h_query.len() + 1, "The circuit does not match the proving key");
+
+        // Compute the quotient `h = (a * b - c) / Z` of the QAP over a coset of the domain.
+        let evaluate = |matrix: &SparseMatrix<E::Fr>| {
+            let mut evaluations = cfg_iter!(matrix)
+                .map(|row| row.iter().map(|(coeff, column)| *coeff * assignment[*column]).sum::<E::Fr>())
+                .collect::<Vec<_>>();
+            evaluations.resize(domain.size(), E::Fr::zero());
+            evaluations
+        };
+        let mut a = evaluate(&matrices.a);
+        let num_constraints = matrices.num_constraints();
+        a[num_constraints..num_constraints + matrices.num_public_variables]
+            .copy_from_slice(&assignment[..matrices.num_public_variables]);
+        let mut b = evaluate(&matrices.b);
+        let mut c = evaluate(&matrices.c);
+        for evaluations in [&mut a, &mut b, &mut c] {
+            domain.ifft_in_place(evaluations);
+            domain.coset_fft_in_place(evaluations);
+        }
+        let mut h = cfg_iter!(a).zip(&b).zip(&c).map(|((a, b), c)| *a * b - c).collect::<Vec<_>>();
+        domain.divide_by_vanishing_poly_on_coset_in_place(&mut h);
+        domain.coset_ifft_in_place(&mut h);
+        h.truncate(domain.size() - 1);
+
+        let to_bigints = |scalars: &[E::Fr]| cfg_iter!(scalars).map(|s| s.to_bigint()).collect::<Vec<_>>();
+        let assignment = to_bigints(&assignment);
+        let h = to_bigints(&h);
+
+        let a = pk.vk.alpha_g1.to_projective() + VariableBase::msm(&pk.a_query, &assignment) + pk.delta_g1 * r;
+        let b_g1 = pk.beta_g1.to_projective() + VariableBase::msm(&pk.b_g1_query, &assignment) + pk.delta_g1 * s;
+        let b = pk.vk.beta_g2.to_projective() + VariableBase::msm(&pk.b_g2_query, &assignment) + pk.vk.delta_g2 * s;
+        let c = VariableBase::msm(&pk.l_query, &assignment[matrices.num_public_variables..])
+            + VariableBase::msm(&pk.h_query, &h)
+            + a * s
+            + b_g1 * r
+            - pk.delta_g1 * (r * s);
+
+        Ok(Proof { a: a.to_affine(), b: b.to_affine(), c: c.to_affine() })
+    }
+
+    /// Ensures the batch has at least one instance for each key, and one proof for each instance.
+    fn ensure_batch_matches<B>(keys_to_inputs: &BTreeMap<&VerifyingKey<E>, &[B]>, proof: &BatchProof<E>) -> Result<()> {
+        if keys_to_inputs.is_empty() || keys_to_inputs.values().any(|inputs| inputs.is_empty()) {
+            bail!(SNARKError::EmptyBatch);
+        }
+        if keys_to_inputs.values().map(|inputs| inputs.len()).sum::<usize>() != proof.batch_size() {
+            bail!(SNARKError::BatchSizeMismatch);
+        }
+        Ok(())
+    }
+
+    /// Returns `true` if `proof` is valid for the public `input`, which starts with the constant `1`.
+    pub fn verify_instance(pvk: &PreparedVerifyingKey<E>, input: &[E::Fr], proof: &Proof<E>) -> Result<bool> {
+        let vk = &pvk.orig_vk;
+        if input.len() != vk.num_public_inputs() {
+            bail!(SNARKError::PublicInputSizeMismatch);
+        }
+        ensure!(input[0] == E::Fr::one(), "The public input must start with the constant 1");
+
+        let input = cfg_iter!(input).map(|x| x.to_bigint()).collect::<Vec<_>>();
+        let input_commitment = VariableBase::msm(&vk.gamma_abc_g1, &input).to_affine();
+        // Check that `e(A, B) = e(alpha, beta) * e(input_commitment, gamma) * e(C, delta)`.
+        let g1_prepared = [proof.a, -vk.alpha_g1, -input_commitment, -proof.c].map(|g| g.prepare());
+        let g2_prepared = [&proof.b.prepare(), &pvk.prepared_beta_g2, &pvk.prepared_gamma_g2, &pvk.prepared_delta_g2];
+        let product = E::product_of_pairings(g1_prepared.iter().zip_eq(g2_prepared));
+        Ok(product.is_one())
+    }
+}
+
+impl<E: PairingEngine, FS> SNARK for Groth16<E, FS>
+where
+    E::Fr: PrimeField,
+    E::Fq: PrimeField,
+    FS: AlgebraicSponge<E::Fq, 2>,
+{
+    type BaseField = E::Fq;
+    type Certificate = Certificate<E>;
+    type FSParameters = FS::Parameters;
+    type FiatShamirRng = FS;
+    type Proof = BatchProof<E>;
+    type ProvingKey = ProvingKey<E>;
+    type ScalarField = E::Fr;
+    type UniversalProver = ();
+    type UniversalSRS = ();
+    type UniversalVerifier = ();
+    type VerifierInput = [E::Fr];
+    type VerifyingKey = VerifyingKey<E>;
+
+    /// Groth16 has no universal SRS, as the keys are sampled for each circuit.
+    fn universal_setup(_config: usize) -> Result<Self::UniversalSRS> {
+        Ok(())
+    }
+
+    /// Samples the circuit proving and verifying keys, with a trapdoor drawn from the thread-local RNG.
+    /// Use `Groth16::circuit_setup_with_rng` to run the setup with another source of randomness.
+    fn circuit_setup<C: ConstraintSynthesizer<E::Fr>>(
+        _srs: &Self::UniversalSRS,
+        circuit: &C,
+    ) -> Result<(Self::ProvingKey, Self::VerifyingKey)> {
+        Self::circuit_setup_with_rng(circuit, &mut rand::thread_rng())
+    }
+
+    /// Groth16 keys come from a trusted setup, so there is no certificate that they encode the circuit.
+    fn prove_vk(
+        _universal_prover: &Self::UniversalProver,
+        _fs_parameters: &Self::FSParameters,
+        _verifying_key: &Self::VerifyingKey,
+        _proving_key: &Self::ProvingKey,
+    ) -> Result<Self::Certificate> {
+        bail!(SNARKError::Message("Groth16 does not support verifying key certificates".to_string()))
+    }
+
+    /// Groth16 keys come from a trusted setup, so there is no certificate that they encode the circuit.
+    fn verify_vk<C: ConstraintSynthesizer<Self::ScalarField>>(
+        _universal_verifier: &Self::UniversalVerifier,
+        _fs_parameters: &Self::FSParameters,
+        _circuit: &C,
+        _verifying_key: &Self::VerifyingKey,
+        _certificate: &Self::Certificate,
+    ) -> Result<bool> {
+        bail!(SNARKError::Message("Groth16 does not support verifying key certificates".to_string()))
+    }
+
+    fn prove_batch_with_options<C: ConstraintSynthesizer<E::Fr>, R: Rng + CryptoRng>(
+        universal_prover: &Self::UniversalProver,
+        fs_parameters: &Self::FSParameters,
+        keys_to_constraints: &BTreeMap<&ProvingKey<E>, &[C]>,
+        options: &ProverOptions,
+        zk_rng: &mut R,
+    ) -> Result<Self::Proof> {
+        options.execute(
+            || Self::prover_memory_estimate(keys_to_constraints),
+            zk_rng,
+            |seed| Self::prove_batch_with_seed(universal_prover, fs_parameters, keys_to_constraints, seed),
+        )
+    }
+
+    /// Estimates the QAP evaluations and the scalars of the MSMs, as each instance is proven in turn.
+    fn prover_memory_estimate<C: ConstraintSynthesizer<E::Fr>>(
+        keys_to_constraints: &BTreeMap<&ProvingKey<E>, &[C]>,
+    ) -> Result<usize> {
+        let field_size = core::mem::size_of::<E::Fr>();
+        let instance_size = keys_to_constraints
+            .keys()
+            .map(|pk| (4 * (pk.h_query.len() + 1) + 2 * pk.num_variables()) * field_size)
+            .max()
+            .unwrap_or(0);
+        let num_proofs = keys_to_constraints.values().map(|constraints| constraints.len()).sum::<usize>();
+        Ok(instance_size + num_proofs * core::mem::size_of::<Proof<E>>())
+    }
+
+    fn prove_batch<C: ConstraintSynthesizer<E::Fr>, R: Rng + CryptoRng>(
+        _universal_prover: &Self::UniversalProver,
+        _fs_parameters: &Self::FSParameters,
+        keys_to_constraints: &BTreeMap<&ProvingKey<E>, &[C]>,
+        zk_rng: &mut R,
+    ) -> Result<Self::Proof> {
+        let prover_time = start_timer!(|| "Groth16::Prover");
+        if keys_to_constraints.is_empty() {
+            bail!(SNARKError::EmptyBatch);
+        }
+
+        let mut proofs = Vec::new();
+        for (pk, constraints) in keys_to_constraints {
+            if constraints.is_empty() {
+                bail!(SNARKError::EmptyBatch);
+            }
+            for constraints in constraints.iter() {
+                let (r, s) = (E::Fr::rand(zk_rng), E::Fr::rand(zk_rng));
+                proofs.push(Self::prove_instance(pk, constraints, r, s)?);
+            }
+        }
+
+        end_timer!(prover_time);
+        Ok(BatchProof { proofs })
+    }
+
+    fn verify_batch<B: Borrow<Self::VerifierInput>>(
+        _universal_verifier: &Self::UniversalVerifier,
+        _fs_parameters: &Self::FSParameters,
+        keys_to_inputs: &BTreeMap<&Self::VerifyingKey, &[B]>,
+        proof: &Self::Proof,
+    ) -> Result<bool> {
+        let verifier_time = start_timer!(|| "Groth16::Verifier");
+        Self::ensure_batch_matches(keys_to_inputs, proof)?;
+
+        // Prepare each key once for all of its instances.
+        let prepared_vks = keys_to_inputs.keys().map(|vk| vk.prepare()).collect::<Vec<_>>();
+        let instances = prepared_vks
+            .iter()
+            .zip_eq(keys_to_inputs.values())
+            .flat_map(|(pvk, inputs)| inputs.iter().map(move |input| (pvk, input)));
+        for ((pvk, input), proof) in instances.zip(&proof.proofs) {
+            if !Self::verify_instance(pvk, input.borrow(), proof)? {
+                end_timer!(verifier_time);
+                return Ok(false);
+            }
+        }
+
+        end_timer!(verifier_time);
+        Ok(true)
+    }
+
+    /// Verifies the proofs with a single product of pairings, by checking a random linear combination
+    /// of their verifying equations. The `alpha`, input and `C` terms of the instances of a verifying key
+    /// are combined before pairing, so the product has one pairing per proof and three per verifying key.
+    fn verify_proofs<B: Borrow<Self::VerifierInput>, R: Rng + CryptoRng>(
+        _universal_verifier: &Self::UniversalVerifier,
+        _fs_parameters: &Self::FSParameters,
+        proofs: &[(&BTreeMap<&Self::VerifyingKey, &[B]>, &Self::Proof)],
+        rng: &mut R,
+    ) -> Result<bool> {
+        let verifier_time = start_timer!(|| format!("Groth16::VerifyProofs for {} proofs", proofs.len()));
+        if proofs.is_empty() {
+            bail!(SNARKError::EmptyBatch);
+        }
+
+        // The `r * A` terms, which are paired with the `B` of their proof.
+        let mut a_terms = Vec::new();
+        let mut b_terms = Vec::new();
+        // For each verifying key, the sum of the `r`, and of the `r * input_commitment` and `r * C` terms.
+        let mut key_terms = BTreeMap::new();
+        for (keys_to_inputs, proof) in proofs {
+            Self::ensure_batch_matches(keys_to_inputs, proof)?;
+            let instances = keys_to_inputs.iter().flat_map(|(vk, inputs)| inputs.iter().map(move |input| (*vk, input)));
+            for ((vk, input), proof) in instances.zip_eq(&proof.proofs) {
+                let input = input.borrow();
+                if input.len() != vk.num_public_inputs() {
+                    bail!(SNARKError::PublicInputSizeMismatch);
+                }
+                ensure!(input[0] == E::Fr::one(), "The public input must start with the constant 1");
+
+                let input = cfg_iter!(input).map(|x| x.to_bigint()).collect::<Vec<_>>();
+                let input_commitment = VariableBase::msm(&vk.gamma_abc_g1, &input);
+                let r = E::Fr::rand(rng);
+                a_terms.push(proof.a * r);
+                b_terms.push(proof.b.prepare());
+                let (r_sum, input_term, c_term) =
+                    key_terms.entry(vk).or_insert((E::Fr::zero(), E::G1Projective::zero(), E::G1Projective::zero()));
+                *r_sum += r;
+                *input_term += input_commitment * r;
+                *c_term += proof.c * r;
+            }
+        }
+
+        // Check that `prod e(r * A, B) = prod e(r_sum * alpha, beta) * e(input_term, gamma) * e(c_term, delta)`.
+        let mut g1_terms = a_terms;
+        for (vk, (r_sum, input_term, c_term)) in &key_terms {
+            g1_terms.extend([-(vk.alpha_g1 * *r_sum), -*input_term, -*c_term]);
+            b_terms.extend([vk.beta_g2, vk.gamma_g2, vk.delta_g2].map(|g| g.prepare()));
+        }
+        let g1_prepared = E::G1Projective::batch_normalization_into_affine(g1_terms)
+            .into_iter()
+            .map(|g| g.prepare())
+            .collect::<Vec<_>>();
+        let is_valid = E::product_of_pairings(g1_prepared.iter().zip_eq(&b_terms)).is_one();
+
+        end_timer!(verifier_time);
+        Ok(is_valid)
+    }
+}
@@ -0,0 +1,195 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+mod groth16 {
+    use crate::{
+        snark::{
+            groth16::{BatchProof, Groth16, PreparedVerifyingKey, ProvingKey, VerifyingKey},
+            varuna::TestCircuit,
+        },
+        traits::{AlgebraicSponge, SNARK},
+        SNARKError,
+    };
+
+    use std::collections::BTreeMap;
+
+    use snarkvm_curves::bls12_377::{Bls12_377, Fq, Fr};
+    use snarkvm_utilities::{
+        rand::{TestRng, Uniform},
+        serialize::*,
+        FromBytes,
+        ToBytes,
+    };
+
+    type FS = crate::crypto_hash::PoseidonSponge<Fq, 2, 1>;
+    type Groth16Inst = Groth16<Bls12_377, FS>;
+
+    #[test]
+    fn prove_and_verify() {
+        let rng = &mut TestRng::default();
+        let fs_parameters = FS::sample_parameters();
+
+        for (mul_depth, num_constraints, num_variables) in [(1, 10, 10), (3, 25, 30), (5, 100, 60)] {
+            let (circuit, public_inputs) = TestCircuit::gen_rand(mul_depth, num_constraints, num_variables, rng);
+            let (pk, vk) = Groth16Inst::circuit_setup_with_rng(&circuit, rng).unwrap();
+            assert_eq!(vk.num_public_inputs(), public_inputs.len());
+
+            let proof = Groth16Inst::prove(&(), &fs_parameters, &pk, &circuit, rng).unwrap();
+            assert!(Groth16Inst::verify(&(), &fs_parameters, &vk, &public_inputs[..], &proof).unwrap());
+
+            // A proof does not verify for another public input.
+            let mut fake_inputs = public_inputs.clone();
+            *fake_inputs.last_mut().unwrap() = Fr::rand(rng);
+            assert!(!Groth16Inst::verify(&(), &fs_parameters, &vk, &fake_inputs[..], &proof).unwrap());
+
+            // A public input of the wrong length is rejected.
+            let error = Groth16Inst::verify(&(), &fs_parameters, &vk, &public_inputs[1..], &proof).unwrap_err();
+            assert!(matches!(error.downcast_ref(), Some(SNARKError::PublicInputSizeMismatch)));
+
+            // The keys and proofs round-trip through their byte encodings.
+            assert_eq!(pk, ProvingKey::read_le(&pk.to_bytes_le().unwrap()[..]).unwrap());
+            assert_eq!(vk, VerifyingKey::read_le(&vk.to_bytes_le().unwrap()[..]).unwrap());
+            assert_eq!(proof, BatchProof::read_le(&proof.to_bytes_le().unwrap()[..]).unwrap());
+            assert_eq!(192, proof.proofs[0].to_bytes_le().unwrap().len(), "Update me if serialization has changed");
+
+            // The prepared key verifies the same proof, and round-trips through its byte encoding.
+            let pvk = vk.prepare();
+            assert!(Groth16Inst::verify_instance(&pvk, &public_inputs, &proof.proofs[0]).unwrap());
+            assert!(!Groth16Inst::verify_instance(&pvk, &fake_inputs, &proof.proofs[0]).unwrap());
+            assert_eq!(pvk, PreparedVerifyingKey::read_le(&pvk.to_bytes_le().unwrap()[..]).unwrap());
+
+            // A prepared key whose prepared elements do not match the key is rejected.
+            let mut bad_pvk = pvk.clone();
+            bad_pvk.prepared_delta_g2 = pvk.prepared_gamma_g2.clone();
+            let bytes = bad_pvk.to_bytes_le().unwrap();
+            assert!(PreparedVerifyingKey::<Bls12_377>::read_le(&bytes[..]).is_err());
+            assert!(PreparedVerifyingKey::<Bls12_377>::deserialize_compressed_unchecked(&bytes[..]).is_ok());
+        }
+    }
+
+    #[test]
+    fn prove_and_verify_batch() {
+        let rng = &mut TestRng::default();
+        let fs_parameters = FS::sample_parameters();
+
+        let (circuit_1, inputs_1) = TestCircuit::gen_rand(2, 20, 20, rng);
+        let (circuit_2, inputs_2) = TestCircuit::gen_rand(2, 20, 20, rng);
+        let (circuit_3, inputs_3) = TestCircuit::gen_rand(4, 50, 40, rng);
+        let (pk_1, vk_1) = Groth16Inst::circuit_setup_with_rng(&circuit_1, rng).unwrap();
+        let (pk_2, vk_2) = Groth16Inst::circuit_setup_with_rng(&circuit_3, rng).unwrap();
+        assert_ne!(vk_1.id, vk_2.id);
+
+        let circuits_1 = [circuit_1, circuit_2];
+        let circuits_2 = [circuit_3];
+        let keys_to_constraints = BTreeMap::from_iter([(&pk_1, &circuits_1[..]), (&pk_2, &circuits_2[..])]);
+        let proof = Groth16Inst::prove_batch(&(), &fs_parameters, &keys_to_constraints, rng).unwrap();
+        assert_eq!(proof.batch_size(), 3);
+
+        let inputs_1 = [inputs_1, inputs_2];
+        let inputs_2 = [inputs_3];
+        let keys_to_inputs = BTreeMap::from_iter([(&vk_1, &inputs_1[..]), (&vk_2, &inputs_2[..])]);
+        assert!(Groth16Inst::verify_batch(&(), &fs_parameters, &keys_to_inputs, &proof).unwrap());
+
+        // The batch must have one input per proof.
+        let keys_to_inputs = BTreeMap::from_iter([(&vk_1, &inputs_1[..])]);
+        let error = Groth16Inst::verify_batch(&(), &fs_parameters, &keys_to_inputs, &proof).unwrap_err();
+        assert!(matches!(error.downcast_ref(), Some(SNARKError::BatchSizeMismatch)));
+    }
+
+    #[test]
+    fn verify_proofs() {
+        let rng = &mut TestRng::default();
+        let fs_parameters = FS::sample_parameters();
+
+        let (circuit_1, inputs_1) = TestCircuit::gen_rand(2, 20, 20, rng);
+        let (circuit_2, inputs_2) = TestCircuit::gen_rand(2, 20, 20, rng);
+        let (circuit_3, inputs_3) = TestCircuit::gen_rand(4, 50, 40, rng);
+        let (pk_1, vk_1) = Groth16Inst::circuit_setup_with_rng(&circuit_1, rng).unwrap();
+        let (pk_2, vk_2) = Groth16Inst::circuit_setup_with_rng(&circuit_3, rng).unwrap();
+
+        // Prove the first two instances in one batch, and the last one on its own.
+        let circuits_1 = [circuit_1, circuit_2];
+        let keys_to_constraints = BTreeMap::from_iter([(&pk_1, &circuits_1[..])]);
+        let proof_1 = Groth16Inst::prove_batch(&(), &fs_parameters, &keys_to_constraints, rng).unwrap();
+        let proof_2 = Groth16Inst::prove(&(), &fs_parameters, &pk_2, &circuit_3, rng).unwrap();
+
+        let inputs_1 = [inputs_1, inputs_2];
+        let inputs_2 = [inputs_3];
+        let keys_to_inputs_1 = BTreeMap::from_iter([(&vk_1, &inputs_1[..])]);
+        let keys_to_inputs_2 = BTreeMap::from_iter([(&vk_2, &inputs_2[..])]);
+        let mut proofs = vec![(&keys_to_inputs_1, &proof_1), (&keys_to_inputs_2, &proof_2)];
+        assert!(Groth16Inst::verify_proofs(&(), &fs_parameters, &proofs, rng).unwrap());
+
+        // A single tampered proof among valid ones makes the combined check fail.
+        let mut tampered_proof = proof_1.clone();
+        tampered_proof.proofs[1].c = proof_1.proofs[0].c;
+        proofs[0].1 = &tampered_proof;
+        assert!(!Groth16Inst::verify_proofs(&(), &fs_parameters, &proofs, rng).unwrap());
+
+        // So does a valid proof for another public input.
+        let mut fake_inputs = inputs_2.clone();
+        *fake_inputs[0].last_mut().unwrap() = Fr::rand(rng);
+        let keys_to_fake_inputs = BTreeMap::from_iter([(&vk_2, &fake_inputs[..])]);
+        proofs[0].1 = &proof_1;
+        proofs[1].0 = &keys_to_fake_inputs;
+        assert!(!Groth16Inst::verify_proofs(&(), &fs_parameters, &proofs, rng).unwrap());
+
+        // Each batch must have one input per proof.
+        proofs[1] = (&keys_to_inputs_2, &proof_1);
+        let error = Groth16Inst::verify_proofs(&(), &fs_parameters, &proofs, rng).unwrap_err();
+        assert!(matches!(error.downcast_ref(), Some(SNARKError::BatchSizeMismatch)));
+    }
+
+    #[test]
+    fn rerandomize() {
+        let rng = &mut TestRng::default();
+        let fs_parameters = FS::sample_parameters();
+
+        let (circuit_1, inputs_1) = TestCircuit::gen_rand(2, 20, 20, rng);
+        let (circuit_2, inputs_2) = TestCircuit::gen_rand(3, 30, 25, rng);
+        let (pk_1, vk_1) = Groth16Inst::circuit_setup_with_rng(&circuit_1, rng).unwrap();
+        let (pk_2, vk_2) = Groth16Inst::circuit_setup_with_rng(&circuit_2, rng).unwrap();
+
+        let circuits_1 = [circuit_1];
+        let circuits_2 = [circuit_2];
+        let keys_to_constraints = BTreeMap::from_iter([(&pk_1, &circuits_1[..]), (&pk_2, &circuits_2[..])]);
+        let proof = Groth16Inst::prove_batch(&(), &fs_parameters, &keys_to_constraints, rng).unwrap();
+
+        // The rerandomized proofs differ in every element, and are still valid.
+        let keys_to_batch_sizes = BTreeMap::from_iter([(&vk_1, 1), (&vk_2, 1)]);
+        let rerandomized = proof.rerandomize(&keys_to_batch_sizes, rng).unwrap();
+        for (proof, rerandomized) in proof.proofs.iter().zip(&rerandomized.proofs) {
+            assert_ne!(proof.a, rerandomized.a);
+            assert_ne!(proof.b, rerandomized.b);
+            assert_ne!(proof.c, rerandomized.c);
+        }
+        let inputs_1 = [inputs_1];
+        let inputs_2 = [inputs_2];
+        let keys_to_inputs = BTreeMap::from_iter([(&vk_1, &inputs_1[..]), (&vk_2, &inputs_2[..])]);
+        assert!(Groth16Inst::verify_batch(&(), &fs_parameters, &keys_to_inputs, &rerandomized).unwrap());
+
+        // A rerandomized proof is still bound to its public input.
+        let mut fake_inputs = inputs_1[0].clone();
+        *fake_inputs.last_mut().unwrap() = Fr::rand(rng);
+        let rerandomized = proof.proofs[0].rerandomize(&vk_1, rng);
+        let rerandomized = BatchProof { proofs: vec![rerandomized] };
+        assert!(Groth16Inst::verify(&(), &fs_parameters, &vk_1, &inputs_1[0][..], &rerandomized).unwrap());
+        assert!(!Groth16Inst::verify(&(), &fs_parameters, &vk_1, &fake_inputs[..], &rerandomized).unwrap());
+
+        // The batch sizes must cover every proof.
+        let keys_to_batch_sizes = BTreeMap::from_iter([(&vk_1, 1)]);
+        let error = proof.rerandomize(&keys_to_batch_sizes, rng).unwrap_err();
+        assert!(matches!(error.downcast_ref(), Some(SNARKError::BatchSizeMismatch)));
+    }
+}
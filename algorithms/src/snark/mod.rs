@@ -12,4 +12,9 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+pub mod distributed;
+pub use distributed::*;
+
+pub mod groth16;
+
 pub mod varuna;
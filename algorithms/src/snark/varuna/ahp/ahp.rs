@@ -13,10 +13,7 @@
 // limitations under the License.
 
 use crate::{
-    fft::{
-        domain::{FFTPrecomputation, IFFTPrecomputation},
-        EvaluationDomain,
-    },
+    fft::{EvaluationDomain, PrecomputedDomain},
     polycommit::sonic_pc::{LCTerm, LabeledPolynomial, LinearCombination},
     r1cs::SynthesisError,
     snark::varuna::{
@@ -105,6 +102,37 @@ impl<F: PrimeField, SM: SNARKMode> AHPForR1CS<F, SM> {
         .ok_or(anyhow!("Could not find max_degree"))
     }
 
+    /// Returns an estimate of the number of bytes allocated by the prover for the given circuits and batch sizes,
+    /// from the sizes of the vectors and polynomials that are held at the same time.
+    pub fn prover_memory_estimate<'a>(circuits: impl IntoIterator<Item = (&'a CircuitInfo, usize)>) -> Result<usize> {
+        let domain_size =
+            |size: usize| EvaluationDomain::<F>::compute_size_of_domain(size).ok_or(AHPError::PolyTooLarge);
+        let mut num_elements = 0usize;
+        let mut max_domain_size = 0;
+        for (info, batch_size) in circuits {
+            let constraint_domain_size = domain_size(info.num_constraints)?;
+            let variable_domain_size = domain_size(info.num_variables)?;
+            let non_zero = info.num_non_zero_a.max(info.num_non_zero_b).max(info.num_non_zero_c);
+            let non_zero_domain_size = domain_size(non_zero)?;
+            // Each instance holds its assignment, the evaluations of `Az`, `Bz` and `Cz`,
+            // and the witness polynomial along with its evaluations.
+            let instance = info.num_variables + 3 * constraint_domain_size + 2 * variable_domain_size;
+            // The fourth round holds the evaluations of a numerator and a denominator for each matrix,
+            // over the extended non-zero domain.
+            let circuit = 3 * 2 * 2 * non_zero_domain_size;
+            num_elements = num_elements
+                .checked_add(batch_size.checked_mul(instance).ok_or(AHPError::PolyTooLarge)?)
+                .and_then(|n| n.checked_add(circuit))
+                .ok_or(AHPError::PolyTooLarge)?;
+            max_domain_size =
+                max_domain_size.max(constraint_domain_size).max(variable_domain_size).max(non_zero_domain_size);
+        }
+        // The quotients are computed over twice the largest domain, as coefficients and evaluations,
+        // and the roots of unity of the largest domain are precomputed for the FFTs and IFFTs.
+        num_elements = num_elements.checked_add(6 * max_domain_size).ok_or(AHPError::PolyTooLarge)?;
+        Ok(num_elements.checked_mul(core::mem::size_of::<F>()).ok_or(AHPError::PolyTooLarge)?)
+    }
+
     /// Get all the strict degree bounds enforced in the AHP.
     pub fn get_degree_bounds(info: &CircuitInfo) -> Result<[usize; 4]> {
         let num_variables = info.num_variables;
@@ -139,13 +167,15 @@ impl<F: PrimeField, SM: SNARKMode> AHPForR1CS<F, SM> {
         Ok(NonZeroDomains { max_non_zero_domain, domain_a, domain_b, domain_c })
     }
 
-    pub fn fft_precomputation(
+    /// Returns the largest domain over which the polynomials of a circuit are multiplied, along with its roots of unity,
+    /// which are shared with the other circuits of the same size.
+    pub fn precomputed_domain(
         constraint_domain_size: usize,
         variable_domain_size: usize,
         non_zero_a_domain_size: usize,
         non_zero_b_domain_size: usize,
         non_zero_c_domain_size: usize,
-    ) -> Option<(FFTPrecomputation<F>, IFFTPrecomputation<F>)> {
+    ) -> Option<PrecomputedDomain<F>> {
         let largest_domain_size = [
             2 * constraint_domain_size,
             2 * variable_domain_size,
@@ -157,9 +187,7 @@ impl<F: PrimeField, SM: SNARKMode> AHPForR1CS<F, SM> {
         .max()?;
         let largest_mul_domain = EvaluationDomain::new(largest_domain_size)?;
 
-        let fft_precomputation = largest_mul_domain.precompute_fft();
-        let ifft_precomputation = fft_precomputation.to_ifft_precomputation();
-        Some((fft_precomputation, ifft_precomputation))
+        Some(PrecomputedDomain::new(largest_mul_domain))
     }
 
     /// Construct the linear combinations that are checked by the AHP.
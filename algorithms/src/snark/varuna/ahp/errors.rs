@@ -45,3 +45,18 @@ impl From<crate::r1cs::errors::SynthesisError> for AHPError {
         AHPError::ConstraintSystemError(other)
     }
 }
+
+impl snarkvm_utilities::ErrorCode for AHPError {
+    fn error_code(&self) -> u32 {
+        match self {
+            Self::AnyhowError(..) => 2300,
+            Self::BatchSizeIsZero => 2301,
+            Self::ConstraintSystemError(error) => error.error_code(),
+            Self::InstanceDoesNotMatchIndex => 2302,
+            Self::InvalidPublicInputLength => 2303,
+            Self::MissingEval(..) => 2304,
+            Self::NonSquareMatrix => 2305,
+            Self::PolyTooLarge => 2306,
+        }
+    }
+}
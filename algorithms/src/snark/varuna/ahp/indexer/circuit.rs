@@ -15,10 +15,7 @@
 use core::marker::PhantomData;
 
 use crate::{
-    fft::{
-        domain::{FFTPrecomputation, IFFTPrecomputation},
-        EvaluationDomain,
-    },
+    fft::{EvaluationDomain, PrecomputedDomain},
     polycommit::sonic_pc::LabeledPolynomial,
     snark::varuna::{
         ahp::matrices::MatrixEvals,
@@ -79,8 +76,8 @@ pub struct Circuit<F: PrimeField, SM: SNARKMode> {
     pub b_arith: MatrixEvals<F>,
     pub c_arith: MatrixEvals<F>,
 
-    pub fft_precomputation: FFTPrecomputation<F>,
-    pub ifft_precomputation: IFFTPrecomputation<F>,
+    /// The largest domain over which the polynomials are multiplied, along with its roots of unity.
+    pub precomputed_domain: PrecomputedDomain<F>,
     pub(crate) _mode: PhantomData<SM>,
     pub(crate) id: CircuitId,
 }
@@ -206,7 +203,7 @@ impl<F: PrimeField, SM: SNARKMode> CanonicalDeserialize for Circuit<F, SM> {
         let non_zero_c_domain_size = EvaluationDomain::<F>::compute_size_of_domain(index_info.num_non_zero_c)
             .ok_or(SerializationError::InvalidData)?;
 
-        let (fft_precomputation, ifft_precomputation) = AHPForR1CS::<F, SM>::fft_precomputation(
+        let precomputed_domain = AHPForR1CS::<F, SM>::precomputed_domain(
             variable_domain_size,
             constraint_domain_size,
             non_zero_a_domain_size,
@@ -226,8 +223,7 @@ impl<F: PrimeField, SM: SNARKMode> CanonicalDeserialize for Circuit<F, SM> {
             a_arith: CanonicalDeserialize::deserialize_with_mode(&mut reader, compress, validate)?,
             b_arith: CanonicalDeserialize::deserialize_with_mode(&mut reader, compress, validate)?,
             c_arith: CanonicalDeserialize::deserialize_with_mode(&mut reader, compress, validate)?,
-            fft_precomputation,
-            ifft_precomputation,
+            precomputed_domain,
             _mode: PhantomData,
             id,
         })
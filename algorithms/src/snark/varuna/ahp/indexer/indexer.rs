@@ -66,7 +66,7 @@ impl<F: PrimeField, SM: SNARKMode> AHPForR1CS<F, SM> {
 
         let fft_precomp_time = start_timer!(|| format!("Precomputing roots of unity {id}"));
 
-        let (fft_precomputation, ifft_precomputation) = Self::fft_precomputation(
+        let precomputed_domain = Self::precomputed_domain(
             constraint_domain.size(),
             variable_domain.size(),
             non_zero_a_domain.size(),
@@ -76,19 +76,7 @@ impl<F: PrimeField, SM: SNARKMode> AHPForR1CS<F, SM> {
         .ok_or(anyhow!("The polynomial degree is too large"))?;
         end_timer!(fft_precomp_time);
 
-        Ok(Circuit {
-            index_info,
-            a,
-            b,
-            c,
-            a_arith,
-            b_arith,
-            c_arith,
-            fft_precomputation,
-            ifft_precomputation,
-            id,
-            _mode: PhantomData,
-        })
+        Ok(Circuit { index_info, a, b, c, a_arith, b_arith, c_arith, precomputed_domain, id, _mode: PhantomData })
     }
 
     pub fn index_polynomial_info<'a>(
@@ -39,6 +39,10 @@ impl<F: PrimeField, SM: SNARKMode> AHPForR1CS<F, SM> {
     }
 
     /// Output the fifth round message and the next state.
+    #[cfg_attr(
+        feature = "instrument",
+        tracing::instrument(level = "debug", name = "varuna::prover_fifth_round", skip_all)
+    )]
     pub fn prover_fifth_round<R: RngCore>(
         verifier_message: verifier::FourthMessage<F>,
         state: prover::State<'_, F, SM>,
@@ -57,6 +57,10 @@ impl<F: PrimeField, SM: SNARKMode> AHPForR1CS<F, SM> {
     }
 
     /// Output the first round message and the next state.
+    #[cfg_attr(
+        feature = "instrument",
+        tracing::instrument(level = "debug", name = "varuna::prover_first_round", skip_all)
+    )]
     pub fn prover_first_round<'a, R: RngCore>(
         mut state: prover::State<'a, F, SM>,
         rng: &mut R,
@@ -138,7 +142,7 @@ impl<F: PrimeField, SM: SNARKMode> AHPForR1CS<F, SM> {
         let x_evals = {
             let mut coeffs = x_poly.coeffs;
             coeffs.resize(variable_domain.size(), F::zero());
-            variable_domain.in_order_fft_in_place_with_pc(&mut coeffs, &circuit.fft_precomputation);
+            variable_domain.in_order_fft_in_place_with_pc(&mut coeffs, circuit.precomputed_domain.fft_precomputation());
             coeffs
         };
 
@@ -150,7 +154,7 @@ impl<F: PrimeField, SM: SNARKMode> AHPForR1CS<F, SM> {
             })
             .collect();
         let w_poly = EvaluationsOnDomain::from_vec_and_domain(w_poly_evals, variable_domain)
-            .interpolate_with_pc(&circuit.ifft_precomputation);
+            .interpolate_with_pc(circuit.precomputed_domain.ifft_precomputation());
         let (w_poly, remainder) = w_poly.divide_by_vanishing_poly(input_domain).unwrap();
         assert!(remainder.is_zero());
 
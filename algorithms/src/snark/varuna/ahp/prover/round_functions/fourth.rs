@@ -75,6 +75,10 @@ impl<F: PrimeField, SM: SNARKMode> AHPForR1CS<F, SM> {
     }
 
     /// Output the fourth round message and the next state.
+    #[cfg_attr(
+        feature = "instrument",
+        tracing::instrument(level = "debug", name = "varuna::prover_fourth_round", skip_all)
+    )]
     pub fn prover_fourth_round<'a, R: RngCore>(
         second_message: &verifier::SecondMessage<F>,
         third_message: &verifier::ThirdMessage<F>,
@@ -111,8 +115,8 @@ impl<F: PrimeField, SM: SNARKMode> AHPForR1CS<F, SM> {
                         *beta,
                         v_R_i_alpha_v_C_i_beta,
                         max_non_zero_domain_size,
-                        &circuit.fft_precomputation,
-                        &circuit.ifft_precomputation,
+                        circuit.precomputed_domain.fft_precomputation(),
+                        circuit.precomputed_domain.ifft_precomputation(),
                     );
                     (circuit, result)
                 });
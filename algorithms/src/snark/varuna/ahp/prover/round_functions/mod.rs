@@ -28,13 +28,16 @@ use rand::Rng;
 use rand_core::CryptoRng;
 use std::collections::BTreeMap;
 
-use snarkvm_utilities::cfg_iter;
 #[cfg(not(feature = "std"))]
 use snarkvm_utilities::println;
+use snarkvm_utilities::{cfg_chunks, cfg_into_iter, execute_in_current_thread_pool};
 
 #[cfg(not(feature = "serial"))]
 use rayon::prelude::*;
 
+/// The number of rows of the matrices that are evaluated on an assignment by a single task.
+const ROW_BLOCK_SIZE: usize = 1 << 10;
+
 mod fifth;
 mod first;
 mod fourth;
@@ -43,6 +46,7 @@ mod third;
 
 impl<F: PrimeField, SM: SNARKMode> AHPForR1CS<F, SM> {
     /// Initialize the AHP prover.
+    #[cfg_attr(feature = "instrument", tracing::instrument(level = "debug", name = "varuna::init_prover", skip_all))]
     pub fn init_prover<'a, C: ConstraintSynthesizer<F>, R: Rng + CryptoRng>(
         circuits_to_constraints: &BTreeMap<&'a Circuit<F, SM>, &[C]>,
         rng: &mut R,
@@ -65,105 +69,120 @@ impl<F: PrimeField, SM: SNARKMode> AHPForR1CS<F, SM> {
             randomizing_assignments.push(circuit_assignments);
         }
 
-        let indices_and_assignments = circuits_to_constraints
+        // The instances are independent, so they are all synthesized concurrently, across circuits.
+        let instances = circuits_to_constraints
             .iter()
-            .zip_eq(randomizing_assignments.into_iter())
-            .map(|((circuit, constraints), circuit_rand_assignments)| {
-                let num_non_zero_a = circuit.index_info.num_non_zero_a;
-                let num_non_zero_b = circuit.index_info.num_non_zero_b;
-                let num_non_zero_c = circuit.index_info.num_non_zero_c;
-
-                let assignments = cfg_iter!(constraints)
-                    .zip(circuit_rand_assignments)
+            .zip_eq(randomizing_assignments)
+            .flat_map(|((circuit, constraints), circuit_rand_assignments)| {
+                constraints
+                    .iter()
+                    .zip_eq(circuit_rand_assignments)
                     .enumerate()
-                    .map(|(_i, (instance, rand_assignments))| {
-                        let constraint_time = start_timer!(|| format!(
-                            "Generating constraints and witnesses for {:?} and index {_i}",
-                            circuit.id
-                        ));
-                        let mut pcs = prover::ConstraintSystem::new();
-                        instance.generate_constraints(&mut pcs)?;
-                        end_timer!(constraint_time);
-
-                        let padding_time =
-                            start_timer!(|| format!("Padding matrices for {:?} and index {_i}", circuit.id));
-
-                        SM::ZK.then(|| {
-                            crate::snark::varuna::ahp::matrices::add_randomizing_variables::<_, _>(
-                                &mut pcs,
-                                rand_assignments,
-                            )
-                        });
-                        crate::snark::varuna::ahp::matrices::pad_input_for_indexer_and_prover(&mut pcs)?;
-
-                        end_timer!(padding_time);
-
-                        let prover::ConstraintSystem {
-                            public_variables: padded_public_variables,
-                            private_variables,
-                            num_constraints,
-                            num_public_variables,
-                            num_private_variables,
-                            ..
-                        } = pcs;
-
-                        assert_eq!(padded_public_variables.len(), num_public_variables);
-                        assert!(padded_public_variables[0].is_one());
-                        assert_eq!(private_variables.len(), num_private_variables);
-
-                        if cfg!(debug_assertions) {
-                            println!("Number of padded public variables in Prover::Init: {num_public_variables}");
-                            println!("Number of private variables: {num_private_variables}");
-                            println!("Number of constraints: {num_constraints}");
-                            println!("Number of non-zero entries in A: {num_non_zero_a}");
-                            println!("Number of non-zero entries in B: {num_non_zero_b}");
-                            println!("Number of non-zero entries in C: {num_non_zero_c}");
-                        }
-
-                        if circuit.index_info.num_constraints != num_constraints
-                            || circuit.index_info.num_variables != (num_public_variables + num_private_variables)
-                        {
-                            return Err(AHPError::InstanceDoesNotMatchIndex);
-                        }
-
-                        Self::formatted_public_input_is_admissible(&padded_public_variables)?;
-
-                        let eval_z_a_time = start_timer!(|| format!("For {:?}, evaluating z_A_{_i}", circuit.id));
-                        let z_a = cfg_iter!(circuit.a)
-                            .map(|row| {
-                                inner_product(&padded_public_variables, &private_variables, row, num_public_variables)
-                            })
-                            .collect();
-                        end_timer!(eval_z_a_time);
-
-                        let eval_z_b_time = start_timer!(|| format!("For {:?}, evaluating z_B_{_i}", circuit.id));
-                        let z_b = cfg_iter!(circuit.b)
-                            .map(|row| {
-                                inner_product(&padded_public_variables, &private_variables, row, num_public_variables)
-                            })
-                            .collect();
-                        end_timer!(eval_z_b_time);
-
-                        let eval_z_c_time = start_timer!(|| format!("For {:?}, evaluating z_C_{_i}", circuit.id));
-                        let z_c = cfg_iter!(circuit.c)
-                            .map(|row| {
-                                inner_product(&padded_public_variables, &private_variables, row, num_public_variables)
-                            })
-                            .collect();
-                        end_timer!(eval_z_c_time);
-
-                        Ok(prover::Assignments::<F>(padded_public_variables, private_variables, z_a, z_b, z_c))
-                    })
-                    .collect::<Result<Vec<prover::Assignments<F>>, AHPError>>()?;
-                Ok((*circuit, assignments))
+                    .map(move |(i, (instance, rand_assignments))| (*circuit, i, instance, rand_assignments))
             })
-            .collect::<Result<BTreeMap<&'a Circuit<F, SM>, Vec<prover::Assignments<F>>>, AHPError>>()?;
+            .collect::<Vec<_>>();
+        let assignments = execute_in_current_thread_pool(|| {
+            cfg_into_iter!(instances)
+                .map(|(circuit, i, instance, rand_assignments)| {
+                    Ok((circuit, Self::synthesize_instance(circuit, i, instance, rand_assignments)?))
+                })
+                .collect::<Result<Vec<_>, AHPError>>()
+        })?;
+
+        let mut indices_and_assignments = circuits_to_constraints
+            .iter()
+            .map(|(circuit, constraints)| (*circuit, Vec::with_capacity(constraints.len())))
+            .collect::<BTreeMap<_, _>>();
+        for (circuit, assignment) in assignments {
+            indices_and_assignments.entry(circuit).or_default().push(assignment);
+        }
 
         let state = prover::State::initialize(indices_and_assignments)?;
         end_timer!(init_time);
 
         Ok(state)
     }
+
+    /// Synthesizes the `_i`-th instance of `circuit`, and evaluates the matrices of `circuit` on its assignment.
+    fn synthesize_instance<C: ConstraintSynthesizer<F>>(
+        circuit: &Circuit<F, SM>,
+        _i: usize,
+        instance: &C,
+        rand_assignments: Option<[F; 3]>,
+    ) -> Result<prover::Assignments<F>, AHPError> {
+        let num_non_zero_a = circuit.index_info.num_non_zero_a;
+        let num_non_zero_b = circuit.index_info.num_non_zero_b;
+        let num_non_zero_c = circuit.index_info.num_non_zero_c;
+
+        let constraint_time =
+            start_timer!(|| format!("Generating constraints and witnesses for {:?} and index {_i}", circuit.id));
+        let mut pcs = prover::ConstraintSystem::new();
+        instance.generate_constraints(&mut pcs)?;
+        end_timer!(constraint_time);
+
+        let padding_time = start_timer!(|| format!("Padding matrices for {:?} and index {_i}", circuit.id));
+
+        SM::ZK.then(|| {
+            crate::snark::varuna::ahp::matrices::add_randomizing_variables::<_, _>(&mut pcs, rand_assignments)
+        });
+        crate::snark::varuna::ahp::matrices::pad_input_for_indexer_and_prover(&mut pcs)?;
+
+        end_timer!(padding_time);
+
+        let prover::ConstraintSystem {
+            public_variables: padded_public_variables,
+            private_variables,
+            num_constraints,
+            num_public_variables,
+            num_private_variables,
+            ..
+        } = pcs;
+
+        assert_eq!(padded_public_variables.len(), num_public_variables);
+        assert!(padded_public_variables[0].is_one());
+        assert_eq!(private_variables.len(), num_private_variables);
+
+        if cfg!(debug_assertions) {
+            println!("Number of padded public variables in Prover::Init: {num_public_variables}");
+            println!("Number of private variables: {num_private_variables}");
+            println!("Number of constraints: {num_constraints}");
+            println!("Number of non-zero entries in A: {num_non_zero_a}");
+            println!("Number of non-zero entries in B: {num_non_zero_b}");
+            println!("Number of non-zero entries in C: {num_non_zero_c}");
+        }
+
+        if circuit.index_info.num_constraints != num_constraints
+            || circuit.index_info.num_variables != (num_public_variables + num_private_variables)
+        {
+            return Err(AHPError::InstanceDoesNotMatchIndex);
+        }
+
+        Self::formatted_public_input_is_admissible(&padded_public_variables)?;
+
+        // Each block of rows is evaluated in `A`, `B` and `C` by the same task, in a single pass over the matrices.
+        let eval_z_time = start_timer!(|| format!("For {:?}, evaluating z_A_{_i}, z_B_{_i} and z_C_{_i}", circuit.id));
+        let evaluate = |rows: &[Vec<(F, usize)>]| {
+            rows.iter()
+                .map(|row| inner_product(&padded_public_variables, &private_variables, row, num_public_variables))
+                .collect::<Vec<_>>()
+        };
+        let blocks = cfg_chunks!(circuit.a, ROW_BLOCK_SIZE)
+            .zip(cfg_chunks!(circuit.b, ROW_BLOCK_SIZE))
+            .zip(cfg_chunks!(circuit.c, ROW_BLOCK_SIZE))
+            .map(|((a, b), c)| (evaluate(a), evaluate(b), evaluate(c)))
+            .collect::<Vec<_>>();
+        let mut z_a = Vec::with_capacity(num_constraints);
+        let mut z_b = Vec::with_capacity(num_constraints);
+        let mut z_c = Vec::with_capacity(num_constraints);
+        for (a, b, c) in blocks {
+            z_a.extend(a);
+            z_b.extend(b);
+            z_c.extend(c);
+        }
+        end_timer!(eval_z_time);
+
+        Ok(prover::Assignments::<F>(padded_public_variables, private_variables, z_a, z_b, z_c))
+    }
 }
 
 fn inner_product<F: PrimeField>(
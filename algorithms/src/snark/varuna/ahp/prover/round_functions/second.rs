@@ -47,6 +47,10 @@ impl<F: PrimeField, SM: SNARKMode> AHPForR1CS<F, SM> {
     }
 
     /// Output the second round message and the next state.
+    #[cfg_attr(
+        feature = "instrument",
+        tracing::instrument(level = "debug", name = "varuna::prover_second_round", skip_all)
+    )]
     pub fn prover_second_round<'a, R: RngCore>(
         verifier_message: &verifier::FirstMessage<F>,
         mut state: prover::State<'a, F, SM>,
@@ -87,8 +91,8 @@ impl<F: PrimeField, SM: SNARKMode> AHPForR1CS<F, SM> {
             let circuit_combiner = batch_combiners[&circuit.id].circuit_combiner;
             let instance_combiners = batch_combiners[&circuit.id].instance_combiners.clone();
             let constraint_domain = circuit_specific_state.constraint_domain;
-            let fft_precomputation = &circuit.fft_precomputation;
-            let ifft_precomputation = &circuit.ifft_precomputation;
+            let fft_precomputation = circuit.precomputed_domain.fft_precomputation();
+            let ifft_precomputation = circuit.precomputed_domain.ifft_precomputation();
 
             let _circuit_id = &circuit.id; // seems like a compiler bug marks this as unused
 
@@ -150,7 +154,7 @@ impl<F: PrimeField, SM: SNARKMode> AHPForR1CS<F, SM> {
         let poly_time = start_timer!(|| format!("Computing {label}"));
 
         let evals = EvaluationsOnDomain::from_vec_and_domain(evaluations, constraint_domain);
-        let poly = evals.interpolate_with_pc_by_ref(&circuit.ifft_precomputation);
+        let poly = evals.interpolate_with_pc_by_ref(circuit.precomputed_domain.ifft_precomputation());
 
         debug_assert!(
             poly.evaluate_over_domain_by_ref(constraint_domain)
@@ -66,6 +66,10 @@ impl<F: PrimeField, SM: SNARKMode> AHPForR1CS<F, SM> {
     }
 
     /// Output the third round message and the next state.
+    #[cfg_attr(
+        feature = "instrument",
+        tracing::instrument(level = "debug", name = "varuna::prover_third_round", skip_all)
+    )]
     pub fn prover_third_round<'a, R: RngCore>(
         verifier_message: &verifier::FirstMessage<F>,
         verifier_second_message: &verifier::SecondMessage<F>,
@@ -150,8 +154,8 @@ impl<F: PrimeField, SM: SNARKMode> AHPForR1CS<F, SM> {
             let instance_combiners = &batch_combiner.instance_combiners;
             let constraint_domain = &circuit_specific_state.constraint_domain;
             let variable_domain = &circuit_specific_state.variable_domain;
-            let fft_precomputation = &circuit.fft_precomputation;
-            let ifft_precomputation = &circuit.ifft_precomputation;
+            let fft_precomputation = circuit.precomputed_domain.fft_precomputation();
+            let ifft_precomputation = circuit.precomputed_domain.ifft_precomputation();
 
             for (&instance_combiner, assignment) in itertools::izip!(instance_combiners, assignments_i) {
                 for (label, matrix_combiner) in itertools::izip!(matrix_labels, matrix_combiners) {
@@ -110,11 +110,9 @@ pub(crate) fn apply_randomized_selector<F: PrimeField>(
         let multiplier = combiner * src_domain.size_as_field_element * target_domain.size_inv;
         cfg_iter_mut!(poly.coeffs).for_each(|c| *c *= multiplier);
 
-        let (h_i, mut xg_i) = poly.divide_by_vanishing_poly(*src_domain)?;
-        xg_i = xg_i.mul_by_vanishing_poly(*target_domain);
-
-        let (xg_i, remainder) = xg_i.divide_by_vanishing_poly(*src_domain)?;
-        ensure!(remainder.is_zero(), "Failed to divide by vanishing polynomial - non-zero remainder ({remainder:?})");
+        let (h_i, xg_i) = poly.divide_by_vanishing_poly(*src_domain)?;
+        // As H_i is a subgroup of H, v_H / v_H_i is sparse, so x_g_i * v_H / v_H_i is computed without a division.
+        let xg_i = &target_domain.vanishing_polynomial_quotient(src_domain)? * &xg_i;
 
         end_timer!(selector_time);
         Ok((h_i, Some(xg_i)))
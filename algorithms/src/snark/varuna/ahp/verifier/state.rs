@@ -17,14 +17,58 @@ use core::marker::PhantomData;
 use crate::{
     fft::EvaluationDomain,
     snark::varuna::{
-        ahp::verifier::{FirstMessage, FourthMessage, SecondMessage, ThirdMessage},
+        ahp::{
+            indexer::CircuitInfo,
+            verifier::{FirstMessage, FourthMessage, SecondMessage, ThirdMessage},
+            AHPError,
+        },
         CircuitId,
         SNARKMode,
     },
 };
 use snarkvm_fields::PrimeField;
+use snarkvm_utilities::serialize::*;
+
+use anyhow::Result;
 use std::collections::{BTreeMap, HashSet};
 
+/// The domains of a circuit, which the verifier derives from its `CircuitInfo`.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, CanonicalSerialize, CanonicalDeserialize)]
+pub struct CircuitDomains<F: PrimeField> {
+    pub(crate) input_domain: EvaluationDomain<F>,
+    pub(crate) variable_domain: EvaluationDomain<F>,
+    pub(crate) constraint_domain: EvaluationDomain<F>,
+    pub(crate) non_zero_a_domain: EvaluationDomain<F>,
+    pub(crate) non_zero_b_domain: EvaluationDomain<F>,
+    pub(crate) non_zero_c_domain: EvaluationDomain<F>,
+}
+
+impl<F: PrimeField> CircuitDomains<F> {
+    /// Constructs the domains of the circuit with the given info.
+    pub(crate) fn new(info: &CircuitInfo) -> Result<Self> {
+        let domain = |size| EvaluationDomain::new(size).ok_or(AHPError::PolyTooLarge);
+        Ok(Self {
+            input_domain: domain(info.num_public_inputs)?,
+            variable_domain: domain(info.num_variables)?,
+            constraint_domain: domain(info.num_constraints)?,
+            non_zero_a_domain: domain(info.num_non_zero_a)?,
+            non_zero_b_domain: domain(info.num_non_zero_b)?,
+            non_zero_c_domain: domain(info.num_non_zero_c)?,
+        })
+    }
+
+    /// Returns the largest of the non-zero domains.
+    pub(crate) fn max_non_zero_domain(&self) -> EvaluationDomain<F> {
+        [self.non_zero_b_domain, self.non_zero_c_domain].into_iter().fold(self.non_zero_a_domain, |max, d| {
+            if d.size() >= max.size() {
+                d
+            } else {
+                max
+            }
+        })
+    }
+}
+
 #[derive(Debug)]
 /// Circuit Specific State of the Verifier
 pub struct CircuitSpecificState<F: PrimeField> {
@@ -18,9 +18,17 @@ use crate::{
     fft::EvaluationDomain,
     snark::varuna::{
         ahp::{
-            indexer::{CircuitId, CircuitInfo},
-            verifier::{BatchCombiners, FirstMessage, FourthMessage, QuerySet, SecondMessage, State, ThirdMessage},
-            AHPError,
+            indexer::CircuitId,
+            verifier::{
+                BatchCombiners,
+                CircuitDomains,
+                FirstMessage,
+                FourthMessage,
+                QuerySet,
+                SecondMessage,
+                State,
+                ThirdMessage,
+            },
             AHPForR1CS,
         },
         verifier::CircuitSpecificState,
@@ -37,7 +45,7 @@ impl<TargetField: PrimeField, SM: SNARKMode> AHPForR1CS<TargetField, SM> {
     /// Output the first message and next round state.
     pub fn verifier_first_round<BaseField: PrimeField, R: AlgebraicSponge<BaseField, 2>>(
         batch_sizes: &BTreeMap<CircuitId, usize>,
-        circuit_infos: &BTreeMap<CircuitId, &CircuitInfo>,
+        circuit_domains: &BTreeMap<CircuitId, CircuitDomains<TargetField>>,
         max_constraint_domain: EvaluationDomain<TargetField>,
         max_variable_domain: EvaluationDomain<TargetField>,
         max_non_zero_domain: EvaluationDomain<TargetField>,
@@ -48,8 +56,8 @@ impl<TargetField: PrimeField, SM: SNARKMode> AHPForR1CS<TargetField, SM> {
         let mut num_circuit_combiners = vec![1; batch_sizes.len()];
         num_circuit_combiners[0] = 0; // the first circuit_combiner is TargetField::one() and needs no random sampling
 
-        for ((batch_size, (circuit_id, circuit_info)), num_c_combiner) in
-            batch_sizes.values().zip(circuit_infos).zip(num_circuit_combiners)
+        for ((batch_size, (circuit_id, domains)), num_c_combiner) in
+            batch_sizes.values().zip(circuit_domains).zip(num_circuit_combiners)
         {
             let squeeze_time = start_timer!(|| format!("Squeezing challenges for {circuit_id}"));
             let elems = fs_rng.squeeze_nonnative_field_elements(*batch_size - 1 + num_c_combiner);
@@ -65,38 +73,13 @@ impl<TargetField: PrimeField, SM: SNARKMode> AHPForR1CS<TargetField, SM> {
             combiners.instance_combiners.extend(instance_combiners);
             batch_combiners.insert(*circuit_id, combiners);
 
-            let constraint_domain_time = start_timer!(|| format!("Constructing constraint domain for {circuit_id}"));
-            let constraint_domain =
-                EvaluationDomain::new(circuit_info.num_constraints).ok_or(AHPError::PolyTooLarge)?;
-            end_timer!(constraint_domain_time);
-
-            let variable_domain_time = start_timer!(|| format!("Constructing constraint domain for {circuit_id}"));
-            let variable_domain = EvaluationDomain::new(circuit_info.num_variables).ok_or(AHPError::PolyTooLarge)?;
-            end_timer!(variable_domain_time);
-
-            let non_zero_a_time = start_timer!(|| format!("Constructing non-zero-a domain for {circuit_id}"));
-            let non_zero_a_domain = EvaluationDomain::new(circuit_info.num_non_zero_a).ok_or(AHPError::PolyTooLarge)?;
-            end_timer!(non_zero_a_time);
-
-            let non_zero_b_time = start_timer!(|| format!("Constructing non-zero-b domain {circuit_id}"));
-            let non_zero_b_domain = EvaluationDomain::new(circuit_info.num_non_zero_b).ok_or(AHPError::PolyTooLarge)?;
-            end_timer!(non_zero_b_time);
-
-            let non_zero_c_time = start_timer!(|| format!("Constructing non-zero-c domain for {circuit_id}"));
-            let non_zero_c_domain = EvaluationDomain::new(circuit_info.num_non_zero_c).ok_or(AHPError::PolyTooLarge)?;
-            end_timer!(non_zero_c_time);
-
-            let input_domain_time = start_timer!(|| format!("Constructing input domain {circuit_id}"));
-            let input_domain = EvaluationDomain::new(circuit_info.num_public_inputs).ok_or(AHPError::PolyTooLarge)?;
-            end_timer!(input_domain_time);
-
             let circuit_specific_state = CircuitSpecificState {
-                input_domain,
-                variable_domain,
-                constraint_domain,
-                non_zero_a_domain,
-                non_zero_b_domain,
-                non_zero_c_domain,
+                input_domain: domains.input_domain,
+                variable_domain: domains.variable_domain,
+                constraint_domain: domains.constraint_domain,
+                non_zero_a_domain: domains.non_zero_a_domain,
+                non_zero_b_domain: domains.non_zero_b_domain,
+                non_zero_c_domain: domains.non_zero_c_domain,
                 batch_size: *batch_size,
             };
             circuit_specific_states.insert(*circuit_id, circuit_specific_state);
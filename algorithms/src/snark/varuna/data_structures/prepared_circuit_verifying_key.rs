@@ -0,0 +1,106 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::snark::varuna::{ahp::verifier::CircuitDomains, CircuitVerifyingKey};
+use snarkvm_curves::PairingEngine;
+use snarkvm_utilities::{
+    error,
+    io::{self, Read, Write},
+    serialize::*,
+    FromBytes,
+    ToBytes,
+};
+
+use anyhow::Result;
+use std::cmp::Ordering;
+
+/// A verifying key together with the values that the verifier derives from it, so that they are
+/// computed once rather than for every proof. The universal verifier already holds the prepared G2 elements.
+///
+/// Deserializing with validation recomputes the derived values to check them, so a prepared key
+/// should be stored where it can be read back without validation, e.g. alongside the program.
+#[derive(Debug, Clone, PartialEq, Eq, CanonicalSerialize)]
+pub struct PreparedCircuitVerifyingKey<E: PairingEngine> {
+    /// The verifying key.
+    pub orig_vk: CircuitVerifyingKey<E>,
+    /// The domains of the circuit.
+    pub(crate) domains: CircuitDomains<E::Fr>,
+}
+
+impl<E: PairingEngine> PreparedCircuitVerifyingKey<E> {
+    /// Prepares the given verifying key.
+    pub fn new(vk: CircuitVerifyingKey<E>) -> Result<Self> {
+        let domains = CircuitDomains::new(&vk.circuit_info)?;
+        Ok(Self { orig_vk: vk, domains })
+    }
+}
+
+impl<E: PairingEngine> CircuitVerifyingKey<E> {
+    /// Returns the prepared form of this verifying key.
+    pub fn prepare(&self) -> Result<PreparedCircuitVerifyingKey<E>> {
+        PreparedCircuitVerifyingKey::new(self.clone())
+    }
+}
+
+impl<E: PairingEngine> Valid for PreparedCircuitVerifyingKey<E> {
+    fn check(&self) -> Result<(), SerializationError> {
+        self.orig_vk.check()?;
+        // The verifier relies on the domains, so they must be those of the circuit.
+        match CircuitDomains::new(&self.orig_vk.circuit_info) {
+            Ok(domains) if domains == self.domains => Ok(()),
+            _ => Err(SerializationError::InvalidData),
+        }
+    }
+}
+
+impl<E: PairingEngine> CanonicalDeserialize for PreparedCircuitVerifyingKey<E> {
+    fn deserialize_with_mode<R: Read>(
+        mut reader: R,
+        compress: Compress,
+        validate: Validate,
+    ) -> Result<Self, SerializationError> {
+        let key = Self {
+            orig_vk: CanonicalDeserialize::deserialize_with_mode(&mut reader, compress, Validate::No)?,
+            domains: CanonicalDeserialize::deserialize_with_mode(&mut reader, compress, Validate::No)?,
+        };
+        if let Validate::Yes = validate {
+            key.check()?;
+        }
+        Ok(key)
+    }
+}
+
+impl<E: PairingEngine> FromBytes for PreparedCircuitVerifyingKey<E> {
+    fn read_le<R: Read>(r: R) -> io::Result<Self> {
+        Self::deserialize_compressed(r).map_err(|_| error("could not deserialize PreparedCircuitVerifyingKey"))
+    }
+}
+
+impl<E: PairingEngine> ToBytes for PreparedCircuitVerifyingKey<E> {
+    fn write_le<W: Write>(&self, w: W) -> io::Result<()> {
+        self.serialize_compressed(w).map_err(|_| error("could not serialize PreparedCircuitVerifyingKey"))
+    }
+}
+
+impl<E: PairingEngine> Ord for PreparedCircuitVerifyingKey<E> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.orig_vk.id.cmp(&other.orig_vk.id)
+    }
+}
+
+impl<E: PairingEngine> PartialOrd for PreparedCircuitVerifyingKey<E> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
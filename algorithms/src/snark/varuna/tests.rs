@@ -15,23 +15,27 @@
 #[cfg(any(test, feature = "test"))]
 mod varuna {
     use crate::{
+        fft::EvaluationDomain,
         snark::varuna::{
             mode::SNARKMode,
             test_circuit::TestCircuit,
             AHPForR1CS,
             CircuitVerifyingKey,
+            PreparedCircuitVerifyingKey,
             VarunaHidingMode,
             VarunaNonHidingMode,
             VarunaSNARK,
         },
-        traits::{AlgebraicSponge, SNARK},
+        traits::{AlgebraicSponge, ProverOptions, SNARK},
     };
 
+    use rand::Rng;
     use std::collections::BTreeMap;
 
     use snarkvm_curves::bls12_377::{Bls12_377, Fq, Fr};
     use snarkvm_utilities::{
         rand::{TestRng, Uniform},
+        FromBytes,
         ToBytes,
     };
 
@@ -155,6 +159,197 @@ mod varuna {
                     }
                 }
 
+                pub(crate) fn test_verify_proofs(num_constraints: usize, num_variables: usize) {
+                    let rng = &mut TestRng::default();
+
+                    let max_degree = AHPForR1CS::<Fr, $snark_mode>::max_degree(100, 25, 300).unwrap();
+                    let universal_srs = $snark_inst::universal_setup(max_degree).unwrap();
+                    let universal_prover = &universal_srs.to_universal_prover().unwrap();
+                    let universal_verifier = &universal_srs.to_universal_verifier().unwrap();
+                    let fs_parameters = FS::sample_parameters();
+
+                    let mut index_vks = Vec::new();
+                    let mut inputs = Vec::new();
+                    let mut proofs = Vec::new();
+                    for i in 0..3 {
+                        let (circ, public_inputs) = TestCircuit::gen_rand(1 + i, num_constraints + i, num_variables, rng);
+                        let (index_pk, index_vk) = $snark_inst::circuit_setup(&universal_srs, &circ).unwrap();
+                        proofs.push($snark_inst::prove(universal_prover, &fs_parameters, &index_pk, &circ, rng).unwrap());
+                        index_vks.push(index_vk);
+                        inputs.push(vec![public_inputs]);
+                    }
+                    let vks_to_inputs = index_vks
+                        .iter()
+                        .zip(inputs.iter())
+                        .map(|(index_vk, inputs)| BTreeMap::from([(index_vk, inputs.as_slice())]))
+                        .collect::<Vec<_>>();
+                    let mut proofs_to_verify = vks_to_inputs.iter().zip(proofs.iter()).collect::<Vec<_>>();
+                    assert!($snark_inst::verify_proofs(universal_verifier, &fs_parameters, &proofs_to_verify, rng).unwrap());
+
+                    // A single tampered proof among valid ones makes the combined check fail.
+                    let mut tampered_proof = proofs[1].clone();
+                    tampered_proof.evaluations.g_1_eval += Fr::rand(rng);
+                    proofs_to_verify[1].1 = &tampered_proof;
+                    assert!(!$snark_inst::verify_proofs(universal_verifier, &fs_parameters, &proofs_to_verify, rng).unwrap());
+                    proofs_to_verify[1].1 = &proofs[1];
+                    assert!($snark_inst::verify_proofs(universal_verifier, &fs_parameters, &proofs_to_verify, rng).unwrap());
+
+                    // A single invalid proof makes the combined check fail.
+                    let mut fake_inputs = inputs[1][0].clone();
+                    *fake_inputs.last_mut().unwrap() = Fr::rand(rng);
+                    let fake_inputs = [fake_inputs];
+                    let vks_to_fake_inputs = BTreeMap::from([(&index_vks[1], &fake_inputs[..])]);
+                    proofs_to_verify[1].0 = &vks_to_fake_inputs;
+                    assert!(!$snark_inst::verify_proofs(universal_verifier, &fs_parameters, &proofs_to_verify, rng).unwrap());
+
+                    proofs_to_verify[1] = (&vks_to_inputs[1], &proofs[2]);
+                    assert!(!$snark_inst::verify_proofs(universal_verifier, &fs_parameters, &proofs_to_verify, rng).unwrap());
+                }
+
+                pub(crate) fn test_verify_prepared(num_constraints: usize, num_variables: usize) {
+                    let rng = &mut TestRng::default();
+
+                    let max_degree = AHPForR1CS::<Fr, $snark_mode>::max_degree(100, 25, 300).unwrap();
+                    let universal_srs = $snark_inst::universal_setup(max_degree).unwrap();
+                    let universal_prover = &universal_srs.to_universal_prover().unwrap();
+                    let universal_verifier = &universal_srs.to_universal_verifier().unwrap();
+                    let fs_parameters = FS::sample_parameters();
+
+                    let (circ, public_inputs) = TestCircuit::gen_rand(2, num_constraints, num_variables, rng);
+                    let (index_pk, index_vk) = $snark_inst::circuit_setup(&universal_srs, &circ).unwrap();
+                    let proof = $snark_inst::prove(universal_prover, &fs_parameters, &index_pk, &circ, rng).unwrap();
+
+                    // The prepared key survives serialization.
+                    let prepared_vk = index_vk.prepare().unwrap();
+                    let prepared_vk = PreparedCircuitVerifyingKey::from_bytes_le(&prepared_vk.to_bytes_le().unwrap()).unwrap();
+                    assert_eq!(prepared_vk.orig_vk, index_vk);
+
+                    assert!($snark_inst::verify_prepared(universal_verifier, &fs_parameters, &prepared_vk, &public_inputs[..], &proof).unwrap());
+                    let mut fake_inputs = public_inputs.clone();
+                    *fake_inputs.last_mut().unwrap() = Fr::rand(rng);
+                    assert!(!$snark_inst::verify_prepared(universal_verifier, &fs_parameters, &prepared_vk, &fake_inputs[..], &proof).unwrap());
+
+                    // A prepared key whose domains do not match its circuit is rejected.
+                    let mut tampered_vk = prepared_vk.clone();
+                    tampered_vk.domains.input_domain = EvaluationDomain::new(2 * prepared_vk.domains.input_domain.size()).unwrap();
+                    assert!(PreparedCircuitVerifyingKey::<Bls12_377>::from_bytes_le(&tampered_vk.to_bytes_le().unwrap()).is_err());
+                }
+
+                pub(crate) fn test_multiple_row_blocks(num_constraints: usize, num_variables: usize) {
+                    let rng = &mut TestRng::default();
+
+                    let max_degree = AHPForR1CS::<Fr, $snark_mode>::max_degree(2 * num_constraints, num_variables, 2 * num_constraints).unwrap();
+                    let universal_srs = $snark_inst::universal_setup(max_degree).unwrap();
+                    let universal_prover = &universal_srs.to_universal_prover().unwrap();
+                    let universal_verifier = &universal_srs.to_universal_verifier().unwrap();
+                    let fs_parameters = FS::sample_parameters();
+
+                    // The matrices of the large circuit span several blocks of rows, and both circuits are proven together.
+                    let (large_circ, large_inputs) = TestCircuit::gen_rand(2, num_constraints, num_variables, rng);
+                    let (small_circ, small_inputs) = TestCircuit::gen_rand(3, 25, 25, rng);
+                    let index_keys = $snark_inst::batch_circuit_setup(&universal_srs, &[&large_circ, &small_circ]).unwrap();
+
+                    let large_circ = [large_circ];
+                    let small_circ = [small_circ];
+                    let large_inputs = [large_inputs];
+                    let small_inputs = [small_inputs];
+                    let (large_pk, large_vk) = &index_keys[0];
+                    let (small_pk, small_vk) = &index_keys[1];
+                    let pks_to_constraints = BTreeMap::from_iter([(large_pk, &large_circ[..]), (small_pk, &small_circ[..])]);
+                    let vks_to_inputs = BTreeMap::from_iter([(large_vk, &large_inputs[..]), (small_vk, &small_inputs[..])]);
+
+                    let proof = $snark_inst::prove_batch(universal_prover, &fs_parameters, &pks_to_constraints, rng).unwrap();
+                    assert!($snark_inst::verify_batch(universal_verifier, &fs_parameters, &vks_to_inputs, &proof).unwrap());
+                }
+
+                pub(crate) fn test_prove_with_seed(num_constraints: usize, num_variables: usize) {
+                    let rng = &mut TestRng::default();
+
+                    let max_degree = AHPForR1CS::<Fr, $snark_mode>::max_degree(100, 25, 300).unwrap();
+                    let universal_srs = $snark_inst::universal_setup(max_degree).unwrap();
+                    let universal_prover = &universal_srs.to_universal_prover().unwrap();
+                    let universal_verifier = &universal_srs.to_universal_verifier().unwrap();
+                    let fs_parameters = FS::sample_parameters();
+
+                    let (circ, public_inputs) = TestCircuit::gen_rand(2, num_constraints, num_variables, rng);
+                    let (index_pk, index_vk) = $snark_inst::circuit_setup(&universal_srs, &circ).unwrap();
+
+                    let seed = rng.gen();
+                    let proof = $snark_inst::prove_with_seed(universal_prover, &fs_parameters, &index_pk, &circ, seed).unwrap();
+                    assert!($snark_inst::verify(universal_verifier, &fs_parameters, &index_vk, public_inputs.as_slice(), &proof).unwrap());
+
+                    // The same seed yields the same proof, and another seed yields another proof.
+                    let candidate = $snark_inst::prove_with_seed(universal_prover, &fs_parameters, &index_pk, &circ, seed).unwrap();
+                    assert_eq!(proof.to_bytes_le().unwrap(), candidate.to_bytes_le().unwrap());
+                    let candidate = $snark_inst::prove_with_seed(universal_prover, &fs_parameters, &index_pk, &circ, rng.gen()).unwrap();
+                    assert!($snark_inst::verify(universal_verifier, &fs_parameters, &index_vk, public_inputs.as_slice(), &candidate).unwrap());
+                    if $snark_mode::ZK {
+                        assert_ne!(proof, candidate);
+                    }
+                }
+
+                pub(crate) fn test_prove_with_options(num_constraints: usize, num_variables: usize) {
+                    let rng = &mut TestRng::default();
+
+                    let max_degree = AHPForR1CS::<Fr, $snark_mode>::max_degree(100, 25, 300).unwrap();
+                    let universal_srs = $snark_inst::universal_setup(max_degree).unwrap();
+                    let universal_prover = &universal_srs.to_universal_prover().unwrap();
+                    let universal_verifier = &universal_srs.to_universal_verifier().unwrap();
+                    let fs_parameters = FS::sample_parameters();
+
+                    let (circ, public_inputs) = TestCircuit::gen_rand(2, num_constraints, num_variables, rng);
+                    let (index_pk, index_vk) = $snark_inst::circuit_setup(&universal_srs, &circ).unwrap();
+                    let mut keys_to_constraints = BTreeMap::new();
+                    keys_to_constraints.insert(&index_pk, std::slice::from_ref(&circ));
+                    let required = $snark_inst::prover_memory_estimate(&keys_to_constraints).unwrap();
+                    assert!(required > 0);
+
+                    // A proof that does not fit in the budget computes its FFTs out of core, or fails early without them.
+                    let options = ProverOptions { max_memory_bytes: Some(required - 1), ..Default::default() };
+                    let result = $snark_inst::prove_with_options(universal_prover, &fs_parameters, &index_pk, &circ, &options, rng);
+                    #[cfg(feature = "out-of-core")]
+                    assert!($snark_inst::verify(universal_verifier, &fs_parameters, &index_vk, public_inputs.as_slice(), &result.unwrap()).unwrap());
+                    #[cfg(not(feature = "out-of-core"))]
+                    assert!(matches!(result.unwrap_err().downcast_ref::<crate::SNARKError>(), Some(crate::SNARKError::MemoryBudgetExceeded { .. })));
+
+                    // A proof within the budget, on a dedicated pool and without the GPU, is valid.
+                    let options = ProverOptions { max_memory_bytes: Some(required), num_threads: Some(2), gpu: false };
+                    let proof = $snark_inst::prove_with_options(universal_prover, &fs_parameters, &index_pk, &circ, &options, rng).unwrap();
+                    assert!($snark_inst::verify(universal_verifier, &fs_parameters, &index_vk, public_inputs.as_slice(), &proof).unwrap());
+
+                    // The GPU is disabled on the threads of that proof only.
+                    assert!(crate::msm::VariableBase::gpu_enabled_on_thread());
+                }
+
+                pub(crate) fn test_prove_distributed(num_constraints: usize, num_variables: usize) {
+                    use crate::{msm::{DistributedMsm, LocalTransport}, snark::DistributedProver};
+                    use rand::SeedableRng;
+                    use rand_chacha::ChaChaRng;
+                    use std::sync::Arc;
+
+                    let rng = &mut TestRng::default();
+
+                    let max_degree = AHPForR1CS::<Fr, $snark_mode>::max_degree(100, 25, 300).unwrap();
+                    let universal_srs = $snark_inst::universal_setup(max_degree).unwrap();
+                    let universal_prover = &universal_srs.to_universal_prover().unwrap();
+                    let universal_verifier = &universal_srs.to_universal_verifier().unwrap();
+                    let fs_parameters = FS::sample_parameters();
+
+                    let (circ, public_inputs) = TestCircuit::gen_rand(2, num_constraints, num_variables, rng);
+                    let (index_pk, index_vk) = $snark_inst::circuit_setup(&universal_srs, &circ).unwrap();
+
+                    // Split the MSMs of the proof across three workers.
+                    let msm = DistributedMsm::new(Arc::new(LocalTransport::new(3))).min_chunk_size(8);
+                    let prover = DistributedProver::with_msm(msm);
+                    let seed = rng.gen();
+                    let proof = prover.prove::<$snark_inst, _, _>(universal_prover, &fs_parameters, &index_pk, &circ, &mut ChaChaRng::from_seed(seed)).unwrap();
+                    assert!($snark_inst::verify(universal_verifier, &fs_parameters, &index_vk, public_inputs.as_slice(), &proof).unwrap());
+
+                    // The proof is the same as the one computed locally with the same randomness.
+                    let expected = $snark_inst::prove_with_seed(universal_prover, &fs_parameters, &index_pk, &circ, ChaChaRng::from_seed(seed).gen()).unwrap();
+                    assert_eq!(proof, expected);
+                }
+
                 pub(crate) fn test_serde_json(num_constraints: usize, num_variables: usize) {
                     use std::str::FromStr;
 
@@ -297,12 +492,66 @@ mod varuna {
         SonicPCTest::test_bincode(num_constraints, num_variables);
         SonicPCPoswTest::test_bincode(num_constraints, num_variables);
     }
+
+    #[test]
+    fn prove_with_seed() {
+        let num_constraints = 25;
+        let num_variables = 25;
+
+        SonicPCTest::test_prove_with_seed(num_constraints, num_variables);
+        SonicPCPoswTest::test_prove_with_seed(num_constraints, num_variables);
+    }
+
+    #[test]
+    fn prove_with_options() {
+        let num_constraints = 25;
+        let num_variables = 25;
+
+        SonicPCTest::test_prove_with_options(num_constraints, num_variables);
+        SonicPCPoswTest::test_prove_with_options(num_constraints, num_variables);
+    }
+
+    #[test]
+    fn prove_distributed() {
+        let num_constraints = 25;
+        let num_variables = 25;
+
+        SonicPCTest::test_prove_distributed(num_constraints, num_variables);
+        SonicPCPoswTest::test_prove_distributed(num_constraints, num_variables);
+    }
+
+    #[test]
+    fn verify_proofs() {
+        let num_constraints = 25;
+        let num_variables = 25;
+
+        SonicPCTest::test_verify_proofs(num_constraints, num_variables);
+        SonicPCPoswTest::test_verify_proofs(num_constraints, num_variables);
+    }
+
+    #[test]
+    fn verify_prepared() {
+        let num_constraints = 25;
+        let num_variables = 25;
+
+        SonicPCTest::test_verify_prepared(num_constraints, num_variables);
+        SonicPCPoswTest::test_verify_prepared(num_constraints, num_variables);
+    }
+
+    #[test]
+    fn prove_and_verify_with_multiple_row_blocks() {
+        let num_constraints = 2100;
+        let num_variables = 25;
+
+        SonicPCTest::test_multiple_row_blocks(num_constraints, num_variables);
+        SonicPCPoswTest::test_multiple_row_blocks(num_constraints, num_variables);
+    }
 }
 
 #[cfg(any(test, feature = "test"))]
 mod varuna_hiding {
     use crate::{
-        crypto_hash::PoseidonSponge,
+        crypto_hash::{KeccakSponge, PoseidonSponge},
         snark::varuna::{
             ahp::AHPForR1CS,
             test_circuit::TestCircuit,
@@ -459,6 +708,34 @@ mod varuna_hiding {
         test_circuit_n_times(num_constraints, num_variables, 1);
     }
 
+    #[test]
+    fn prove_and_verify_with_keccak_transcript() {
+        type KeccakInst = VarunaSNARK<Bls12_377, KeccakSponge<Fq>, VarunaHidingMode>;
+
+        let rng = &mut TestRng::default();
+
+        let max_degree = AHPForR1CS::<Fr, VarunaHidingMode>::max_degree(100, 25, 300).unwrap();
+        let universal_srs = KeccakInst::universal_setup(max_degree).unwrap();
+        let universal_prover = &universal_srs.to_universal_prover().unwrap();
+        let universal_verifier = &universal_srs.to_universal_verifier().unwrap();
+
+        let (circuit, public_inputs) = TestCircuit::gen_rand(2, 100, 25, rng);
+        let mut fake_inputs = public_inputs.clone();
+        fake_inputs[public_inputs.len() - 1] = Fr::rand(rng);
+
+        let (index_pk, index_vk) = KeccakInst::circuit_setup(&universal_srs, &circuit).unwrap();
+        let proof = KeccakInst::prove(universal_prover, &(), &index_pk, &circuit, rng).unwrap();
+        assert!(KeccakInst::verify(universal_verifier, &(), &index_vk, public_inputs, &proof).unwrap());
+        assert!(!KeccakInst::verify(universal_verifier, &(), &index_vk, fake_inputs, &proof).unwrap());
+
+        // Ensure a proof under one transcript does not verify under another.
+        let fs_parameters = FS::sample_parameters();
+        let (circuit, public_inputs) = TestCircuit::gen_rand(2, 100, 25, rng);
+        let (index_pk, index_vk) = VarunaInst::circuit_setup(&universal_srs, &circuit).unwrap();
+        let proof = VarunaInst::prove(universal_prover, &fs_parameters, &index_pk, &circuit, rng).unwrap();
+        assert!(!KeccakInst::verify(universal_verifier, &(), &index_vk, public_inputs, &proof).unwrap());
+    }
+
     #[test]
     fn check_indexing() {
         let rng = &mut TestRng::default();
@@ -20,31 +20,34 @@ use crate::{
         CommitterUnionKey,
         Evaluations,
         LabeledCommitment,
+        PairingCheck,
         QuerySet,
         Randomness,
         SonicKZG10,
     },
     r1cs::{ConstraintSynthesizer, SynthesisError},
     snark::varuna::{
-        ahp::{AHPError, AHPForR1CS, CircuitId, EvaluationsProvider},
+        ahp::{verifier::CircuitDomains, AHPError, AHPForR1CS, CircuitId, EvaluationsProvider},
         proof,
         prover,
         witness_label,
         CircuitProvingKey,
         CircuitVerifyingKey,
+        PreparedCircuitVerifyingKey,
         Proof,
         SNARKMode,
         UniversalSRS,
     },
     srs::UniversalVerifier,
     AlgebraicSponge,
+    ProverOptions,
     SNARKError,
     SNARK,
 };
 use rand::RngCore;
 use snarkvm_curves::PairingEngine;
 use snarkvm_fields::{One, PrimeField, ToConstraintField, Zero};
-use snarkvm_utilities::{to_bytes_le, ToBytes};
+use snarkvm_utilities::{to_bytes_le, ToBytes, Uniform};
 
 use anyhow::{anyhow, bail, ensure, Result};
 use core::marker::PhantomData;
@@ -330,9 +333,32 @@ where
         .map_err(Into::into)
     }
 
+    fn prove_batch_with_options<C: ConstraintSynthesizer<E::Fr>, R: Rng + CryptoRng>(
+        universal_prover: &Self::UniversalProver,
+        fs_parameters: &Self::FSParameters,
+        keys_to_constraints: &BTreeMap<&CircuitProvingKey<E, SM>, &[C]>,
+        options: &ProverOptions,
+        zk_rng: &mut R,
+    ) -> Result<Self::Proof> {
+        options.execute(
+            || Self::prover_memory_estimate(keys_to_constraints),
+            zk_rng,
+            |seed| Self::prove_batch_with_seed(universal_prover, fs_parameters, keys_to_constraints, seed),
+        )
+    }
+
+    fn prover_memory_estimate<C: ConstraintSynthesizer<E::Fr>>(
+        keys_to_constraints: &BTreeMap<&CircuitProvingKey<E, SM>, &[C]>,
+    ) -> Result<usize> {
+        AHPForR1CS::<E::Fr, SM>::prover_memory_estimate(
+            keys_to_constraints.iter().map(|(pk, constraints)| (&pk.circuit.index_info, constraints.len())),
+        )
+    }
+
     /// This is the main entrypoint for creating proofs.
     /// You can find a specification of the prover algorithm in:
     /// https://github.com/AleoHQ/protocol-docs
+    #[cfg_attr(feature = "instrument", tracing::instrument(level = "debug", name = "varuna::prove_batch", skip_all))]
     fn prove_batch<C: ConstraintSynthesizer<E::Fr>, R: Rng + CryptoRng>(
         universal_prover: &Self::UniversalProver,
         fs_parameters: &Self::FSParameters,
@@ -352,7 +378,7 @@ where
 
         // extract information from the prover key and state to consume in further calculations
         let mut batch_sizes = BTreeMap::new();
-        let mut circuit_infos = BTreeMap::new();
+        let mut circuit_domains = BTreeMap::new();
         let mut inputs_and_batch_sizes = BTreeMap::new();
         let mut total_instances = 0usize;
         let mut public_inputs = BTreeMap::new(); // inputs need to live longer than the rest of prover_state
@@ -365,7 +391,7 @@ where
                 prover_state.padded_public_inputs(&pk.circuit).ok_or(SNARKError::CircuitNotFound)?;
             let circuit_id = pk.circuit.id;
             batch_sizes.insert(circuit_id, batch_size);
-            circuit_infos.insert(circuit_id, &pk.circuit_verifying_key.circuit_info);
+            circuit_domains.insert(circuit_id, CircuitDomains::new(&pk.circuit_verifying_key.circuit_info)?);
             inputs_and_batch_sizes.insert(circuit_id, (batch_size, padded_public_input));
             public_inputs.insert(circuit_id, public_input);
             total_instances = total_instances.saturating_add(batch_size);
@@ -374,6 +400,13 @@ where
         }
         ensure!(prover_state.total_instances == total_instances);
 
+        // Count the constraints proven across all instances in the batch.
+        #[cfg(feature = "metrics")]
+        let num_constraints_proven: usize = keys_to_constraints
+            .keys()
+            .map(|pk| pk.circuit_verifying_key.circuit_info.num_constraints.saturating_mul(batch_sizes[&pk.circuit.id]))
+            .sum();
+
         let committer_key = CommitterUnionKey::union(keys_to_constraints.keys().map(|pk| pk.committer_key.deref()));
 
         let circuit_commitments =
@@ -402,7 +435,7 @@ where
 
         let (verifier_first_message, verifier_state) = AHPForR1CS::<_, SM>::verifier_first_round(
             &batch_sizes,
-            &circuit_infos,
+            &circuit_domains,
             prover_state.max_constraint_domain,
             prover_state.max_variable_domain,
             prover_state.max_non_zero_domain,
@@ -616,18 +649,78 @@ where
         ensure!(proof.pc_proof.is_hiding() == SM::ZK);
 
         end_timer!(prover_time);
+        #[cfg(feature = "metrics")]
+        snarkvm_metrics::increment_counter_by(
+            snarkvm_metrics::snark::CONSTRAINTS_PROVEN,
+            num_constraints_proven as u64,
+        );
         Ok(proof)
     }
 
     /// This is the main entrypoint for verifying proofs.
     /// You can find a specification of the verifier algorithm in:
     /// https://github.com/AleoHQ/protocol-docs
+    #[cfg_attr(feature = "instrument", tracing::instrument(level = "debug", name = "varuna::verify_batch", skip_all))]
     fn verify_batch<B: Borrow<Self::VerifierInput>>(
         universal_verifier: &Self::UniversalVerifier,
         fs_parameters: &Self::FSParameters,
         keys_to_inputs: &BTreeMap<&Self::VerifyingKey, &[B]>,
         proof: &Self::Proof,
     ) -> Result<bool> {
+        let prepared_keys = Self::prepare_keys(keys_to_inputs)?;
+        let keys_to_inputs = prepared_keys.iter().map(|(vk, inputs)| (vk, *inputs)).collect();
+        Self::verify_batch_prepared(universal_verifier, fs_parameters, &keys_to_inputs, proof)
+    }
+
+    /// Verifies the proofs by running the verifier of each of them up to its final pairing check,
+    /// and then computing a single product of pairings for a random linear combination of these checks.
+    #[cfg_attr(feature = "instrument", tracing::instrument(level = "debug", name = "varuna::verify_proofs", skip_all))]
+    fn verify_proofs<B: Borrow<Self::VerifierInput>, R: Rng + CryptoRng>(
+        universal_verifier: &Self::UniversalVerifier,
+        fs_parameters: &Self::FSParameters,
+        proofs: &[(&BTreeMap<&Self::VerifyingKey, &[B]>, &Self::Proof)],
+        rng: &mut R,
+    ) -> Result<bool> {
+        if proofs.is_empty() {
+            bail!(SNARKError::EmptyBatch);
+        }
+        let verifier_time = start_timer!(|| format!("Varuna::VerifyProofs for {} proofs", proofs.len()));
+
+        let mut combined_pairing_check = PairingCheck::default();
+        for (keys_to_inputs, proof) in proofs {
+            let prepared_keys = Self::prepare_keys(keys_to_inputs)?;
+            let keys_to_inputs = prepared_keys.iter().map(|(vk, inputs)| (vk, *inputs)).collect();
+            let Some(pairing_check) =
+                Self::prepare_pairing_check(universal_verifier, fs_parameters, &keys_to_inputs, proof)?
+            else {
+                end_timer!(verifier_time);
+                return Ok(false);
+            };
+            combined_pairing_check.add_scaled(&pairing_check, E::Fr::rand(rng));
+        }
+        let is_valid = SonicKZG10::<E, FS>::check_pairing(universal_verifier, combined_pairing_check)?;
+        end_timer!(verifier_time, || format!(" SonicKZG10::Check for the combined pairing checks: {is_valid}"));
+
+        #[cfg(feature = "metrics")]
+        if is_valid {
+            for _ in proofs {
+                snarkvm_metrics::increment_counter(snarkvm_metrics::snark::PROOFS_VERIFIED);
+            }
+        }
+        Ok(is_valid)
+    }
+}
+
+impl<E: PairingEngine, FS: AlgebraicSponge<E::Fq, 2>, SM: SNARKMode> VarunaSNARK<E, FS, SM> {
+    /// Runs the verifier of `verify_batch` up to the final pairing check of the polynomial commitment scheme,
+    /// which is returned so that it may be combined with those of other proofs.
+    /// Returns `None` if the proof does not match the hiding mode of `SM`.
+    fn prepare_pairing_check<B: Borrow<[E::Fr]>>(
+        universal_verifier: &UniversalVerifier<E>,
+        fs_parameters: &FS::Parameters,
+        keys_to_inputs: &BTreeMap<&PreparedCircuitVerifyingKey<E>, &[B]>,
+        proof: &Proof<E>,
+    ) -> Result<Option<PairingCheck<E>>> {
         if keys_to_inputs.is_empty() {
             bail!(SNARKError::EmptyBatch);
         }
@@ -636,7 +729,7 @@ where
         let batch_sizes_vec = proof.batch_sizes();
         let mut batch_sizes = BTreeMap::new();
         for (i, (vk, public_inputs_i)) in keys_to_inputs.iter().enumerate() {
-            batch_sizes.insert(vk.id, batch_sizes_vec[i]);
+            batch_sizes.insert(vk.orig_vk.id, batch_sizes_vec[i]);
 
             if public_inputs_i.is_empty() {
                 bail!(SNARKError::EmptyBatch);
@@ -648,25 +741,25 @@ where
         }
 
         // collect values into structures for our calculations
-        let mut max_num_constraints = 0;
-        let mut max_num_variables = 0;
-        let mut max_non_zero_domain = None;
+        let mut max_constraint_domain: Option<EvaluationDomain<E::Fr>> = None;
+        let mut max_variable_domain: Option<EvaluationDomain<E::Fr>> = None;
+        let mut max_non_zero_domain: Option<EvaluationDomain<E::Fr>> = None;
         let mut public_inputs = BTreeMap::new();
         let mut padded_public_vec = Vec::with_capacity(keys_to_inputs.len());
         let mut inputs_and_batch_sizes = BTreeMap::new();
-        let mut input_domains = BTreeMap::new();
         let mut circuit_infos = BTreeMap::new();
+        let mut circuit_domains = BTreeMap::new();
         let mut circuit_ids = Vec::with_capacity(keys_to_inputs.len());
+        let larger = |current: Option<EvaluationDomain<E::Fr>>, domain: EvaluationDomain<E::Fr>| {
+            Some(current.filter(|current| current.size() > domain.size()).unwrap_or(domain))
+        };
         for (&vk, &public_inputs_i) in keys_to_inputs.iter() {
-            max_num_constraints = max_num_constraints.max(vk.circuit_info.num_constraints);
-            max_num_variables = max_num_variables.max(vk.circuit_info.num_variables);
+            let domains = vk.domains;
+            max_constraint_domain = larger(max_constraint_domain, domains.constraint_domain);
+            max_variable_domain = larger(max_variable_domain, domains.variable_domain);
+            max_non_zero_domain = larger(max_non_zero_domain, domains.max_non_zero_domain());
 
-            let non_zero_domains = AHPForR1CS::<_, SM>::cmp_non_zero_domains(&vk.circuit_info, max_non_zero_domain)?;
-            max_non_zero_domain = non_zero_domains.max_non_zero_domain;
-
-            let input_domain = EvaluationDomain::<E::Fr>::new(vk.circuit_info.num_public_inputs)
-                .ok_or(anyhow!("Failed to create EvaluationDomain from num_public_inputs"))?;
-            input_domains.insert(vk.id, input_domain);
+            let input_domain = domains.input_domain;
 
             let input_fields = public_inputs_i
                 .iter()
@@ -697,19 +790,18 @@ where
                     })
                     .unzip()
             };
-            let circuit_id = vk.id;
+            let circuit_id = vk.orig_vk.id;
             public_inputs.insert(circuit_id, parsed_public_inputs_i);
             padded_public_vec.push(padded_public_inputs_i);
-            circuit_infos.insert(circuit_id, &vk.circuit_info);
+            circuit_infos.insert(circuit_id, &vk.orig_vk.circuit_info);
+            circuit_domains.insert(circuit_id, domains);
             circuit_ids.push(circuit_id);
         }
         for (i, (vk, &batch_size)) in keys_to_inputs.keys().zip(batch_sizes.values()).enumerate() {
-            inputs_and_batch_sizes.insert(vk.id, (batch_size, padded_public_vec[i].as_slice()));
+            inputs_and_batch_sizes.insert(vk.orig_vk.id, (batch_size, padded_public_vec[i].as_slice()));
         }
-        let max_constraint_domain =
-            EvaluationDomain::<E::Fr>::new(max_num_constraints).ok_or(SynthesisError::PolyTooLarge)?;
-        let max_variable_domain =
-            EvaluationDomain::<E::Fr>::new(max_num_variables).ok_or(SynthesisError::PolyTooLarge)?;
+        let max_constraint_domain = max_constraint_domain.ok_or(SynthesisError::PolyTooLarge)?;
+        let max_variable_domain = max_variable_domain.ok_or(SynthesisError::PolyTooLarge)?;
         let max_non_zero_domain = max_non_zero_domain.ok_or(SynthesisError::PolyTooLarge)?;
 
         let comms = &proof.commitments;
@@ -723,7 +815,7 @@ where
                 "Found `mask_poly` in the first round when not expected, or proof has incorrect hiding mode ({})",
                 proof.pc_proof.is_hiding()
             );
-            return Ok(false);
+            return Ok(None);
         }
 
         let verifier_time = start_timer!(|| format!("Varuna::Verify with batch sizes: {:?}", batch_sizes));
@@ -784,7 +876,7 @@ where
         let fifth_round_info = AHPForR1CS::<E::Fr, SM>::fifth_round_polynomial_info();
         let fifth_commitments = [LabeledCommitment::new_with_info(&fifth_round_info["h_2"], comms.h_2)];
 
-        let circuit_commitments = keys_to_inputs.keys().map(|vk| vk.circuit_commitments.as_slice());
+        let circuit_commitments = keys_to_inputs.keys().map(|vk| vk.orig_vk.circuit_commitments.as_slice());
         let mut sponge = Self::init_sponge(fs_parameters, &inputs_and_batch_sizes, circuit_commitments.clone());
 
         // --------------------------------------------------------------------
@@ -793,7 +885,7 @@ where
         Self::absorb_labeled(&first_commitments, &mut sponge);
         let (_, verifier_state) = AHPForR1CS::<_, SM>::verifier_first_round(
             &batch_sizes,
-            &circuit_infos,
+            &circuit_domains,
             max_constraint_domain,
             max_variable_domain,
             max_non_zero_domain,
@@ -896,8 +988,8 @@ where
         )?;
         end_timer!(lc_time);
 
-        let pc_time = start_timer!(|| "Checking linear combinations with PC");
-        let evaluations_are_correct = SonicKZG10::<E, FS>::check_combinations(
+        let pc_time = start_timer!(|| "Reducing linear combinations with PC to a pairing check");
+        let pairing_check = SonicKZG10::<E, FS>::check_combinations_deferred(
             universal_verifier,
             lc_s.values(),
             &commitments,
@@ -907,16 +999,56 @@ where
             &mut sponge,
         )?;
         end_timer!(pc_time);
+        end_timer!(verifier_time);
+        Ok(Some(pairing_check))
+    }
+
+    /// Prepares the verifying keys of a batch, for the verifiers that are given unprepared keys.
+    fn prepare_keys<'a, B>(
+        keys_to_inputs: &BTreeMap<&CircuitVerifyingKey<E>, &'a [B]>,
+    ) -> Result<Vec<(PreparedCircuitVerifyingKey<E>, &'a [B])>> {
+        keys_to_inputs.iter().map(|(vk, inputs)| Ok((vk.prepare()?, *inputs))).collect()
+    }
 
-        if !evaluations_are_correct {
+    /// Verifies a proof as in `verify`, with a verifying key that was prepared ahead of time.
+    pub fn verify_prepared<B: Borrow<[E::Fr]>>(
+        universal_verifier: &UniversalVerifier<E>,
+        fs_parameters: &FS::Parameters,
+        prepared_verifying_key: &PreparedCircuitVerifyingKey<E>,
+        input: B,
+        proof: &Proof<E>,
+    ) -> Result<bool> {
+        let mut keys_to_inputs = BTreeMap::new();
+        let inputs = [input];
+        keys_to_inputs.insert(prepared_verifying_key, &inputs[..]);
+        Self::verify_batch_prepared(universal_verifier, fs_parameters, &keys_to_inputs, proof)
+    }
+
+    /// Verifies a batch proof as in `verify_batch`, with verifying keys that were prepared ahead of time.
+    pub fn verify_batch_prepared<B: Borrow<[E::Fr]>>(
+        universal_verifier: &UniversalVerifier<E>,
+        fs_parameters: &FS::Parameters,
+        keys_to_inputs: &BTreeMap<&PreparedCircuitVerifyingKey<E>, &[B]>,
+        proof: &Proof<E>,
+    ) -> Result<bool> {
+        let Some(pairing_check) =
+            Self::prepare_pairing_check(universal_verifier, fs_parameters, keys_to_inputs, proof)?
+        else {
+            return Ok(false);
+        };
+
+        let pc_time = start_timer!(|| "Checking linear combinations with PC");
+        let is_valid = SonicKZG10::<E, FS>::check_pairing(universal_verifier, pairing_check)?;
+        end_timer!(pc_time, || format!(" SonicKZG10::Check for AHP Verifier linear equations: {is_valid}"));
+
+        if !is_valid {
             #[cfg(debug_assertions)]
-            eprintln!("SonicKZG10::Check failed using final challenge: {:?}", verifier_state.gamma);
+            eprintln!("SonicKZG10::Check failed");
         }
-
-        end_timer!(verifier_time, || format!(
-            " SonicKZG10::Check for AHP Verifier linear equations: {}",
-            evaluations_are_correct & proof_has_correct_zk_mode
-        ));
-        Ok(evaluations_are_correct & proof_has_correct_zk_mode)
+        #[cfg(feature = "metrics")]
+        if is_valid {
+            snarkvm_metrics::increment_counter(snarkvm_metrics::snark::PROOFS_VERIFIED);
+        }
+        Ok(is_valid)
     }
 }
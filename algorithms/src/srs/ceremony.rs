@@ -0,0 +1,387 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! A multi-party ceremony for the powers of the universal SRS.
+//!
+//! Each participant multiplies the secret `beta` of the current powers by a secret `tau` of its own,
+//! and the secret `gamma` of the hiding powers by a secret `delta`, and publishes an `UpdateProof`.
+//! The resulting powers are secure as long as a single participant has destroyed its secrets.
+
+use crate::msm::VariableBase;
+use snarkvm_curves::traits::{AffineCurve, PairingEngine, ProjectiveCurve};
+use snarkvm_fields::{Field, One, PrimeField, Zero};
+use snarkvm_utilities::{cfg_iter, cfg_iter_mut, serialize::*, Uniform};
+
+use anyhow::{ensure, Result};
+use blake2::{Blake2s256, Digest};
+use rand::{CryptoRng, Rng};
+use std::collections::BTreeMap;
+
+#[cfg(not(feature = "serial"))]
+use rayon::prelude::*;
+
+/// The personalization of the hashes of the ceremony.
+const CEREMONY_PERSONALIZATION: &[u8] = b"AleoSRSCeremony0";
+
+/// The powers of the universal SRS, as updated by each contribution to the ceremony.
+#[derive(Clone, Debug, PartialEq, Eq, CanonicalSerialize, CanonicalDeserialize)]
+pub struct CeremonyPowers<E: PairingEngine> {
+    /// Group elements of the form `{ \beta^i G }`, where `i` ranges from 0 to `max_degree`.
+    pub powers_of_beta_g: Vec<E::G1Affine>,
+    /// Group elements of the form `{ \beta^i \gamma G }`, where `i` ranges from 0 to `hiding_degree`.
+    pub powers_of_beta_times_gamma_g: Vec<E::G1Affine>,
+    /// `\beta H`, where `H` is the generator of G2.
+    pub beta_h: E::G2Affine,
+    /// Group elements of the form `{ \beta^{max_degree - d} H }`, for each supported degree bound `d`.
+    pub negative_powers_of_beta_h: BTreeMap<usize, E::G2Affine>,
+}
+
+/// A proof of knowledge of the discrete logarithm of a point of G1, bound to the transcript.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, CanonicalSerialize, CanonicalDeserialize)]
+pub struct SchnorrProof<E: PairingEngine> {
+    /// The commitment to the nonce.
+    pub commitment: E::G1Affine,
+    /// The response to the challenge.
+    pub response: E::Fr,
+}
+
+/// The proof that a contribution updates the previous powers with secrets known to the contributor.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, CanonicalSerialize, CanonicalDeserialize)]
+pub struct UpdateProof<E: PairingEngine> {
+    /// `\tau G`.
+    pub tau_g: E::G1Affine,
+    /// `\tau H`.
+    pub tau_h: E::G2Affine,
+    /// The proof of knowledge of `\tau`.
+    pub tau_proof: SchnorrProof<E>,
+    /// `\delta G`.
+    pub delta_g: E::G1Affine,
+    /// `\delta H`.
+    pub delta_h: E::G2Affine,
+    /// The proof of knowledge of `\delta`.
+    pub delta_proof: SchnorrProof<E>,
+}
+
+impl<E: PairingEngine> CeremonyPowers<E> {
+    /// Initializes the powers with `\beta = \gamma = 1`, which the first contribution re-randomizes.
+    pub fn new(max_degree: usize, hiding_degree: usize, supported_degree_bounds: &[usize]) -> Result<Self> {
+        ensure!(max_degree > 0, "The maximum degree must be positive");
+        ensure!(hiding_degree <= max_degree, "The hiding degree must not exceed the maximum degree");
+        ensure!(
+            supported_degree_bounds.iter().all(|bound| *bound <= max_degree),
+            "The degree bounds must not exceed the maximum degree"
+        );
+
+        let g = E::G1Affine::prime_subgroup_generator();
+        let h = E::G2Affine::prime_subgroup_generator();
+        Ok(Self {
+            powers_of_beta_g: vec![g; max_degree + 1],
+            powers_of_beta_times_gamma_g: vec![g; hiding_degree + 1],
+            beta_h: h,
+            negative_powers_of_beta_h: supported_degree_bounds.iter().map(|bound| (*bound, h)).collect(),
+        })
+    }
+
+    /// Returns the maximum degree supported by the powers.
+    pub fn max_degree(&self) -> usize {
+        self.powers_of_beta_g.len() - 1
+    }
+
+    /// Returns the hash of the powers, to which the next contribution is bound.
+    pub fn hash(&self) -> Result<[u8; 32]> {
+        let mut hasher = Blake2s256::new();
+        hasher.update(CEREMONY_PERSONALIZATION);
+        hasher.update((self.serialized_size(Compress::Yes) as u64).to_le_bytes());
+        self.serialize_compressed(HashWriter(&mut hasher))?;
+        Ok(hasher.finalize().into())
+    }
+
+    /// Updates the powers with fresh secrets sampled from `rng`, and returns the proof of the update.
+    /// The secrets are dropped on return, and must not be recoverable from `rng`.
+    pub fn contribute<R: Rng + CryptoRng>(&mut self, rng: &mut R) -> Result<UpdateProof<E>> {
+        let previous_hash = self.hash()?;
+        let tau = sample_nonzero::<E::Fr, _>(rng);
+        let delta = sample_nonzero::<E::Fr, _>(rng);
+
+        let g = E::G1Affine::prime_subgroup_generator();
+        let h = E::G2Affine::prime_subgroup_generator();
+        let (tau_g, delta_g) = ((g * tau).to_affine(), (g * delta).to_affine());
+        let proof = UpdateProof {
+            tau_g,
+            tau_h: (h * tau).to_affine(),
+            tau_proof: SchnorrProof::prove(tau, tau_g, &previous_hash, rng),
+            delta_g,
+            delta_h: (h * delta).to_affine(),
+            delta_proof: SchnorrProof::prove(delta, delta_g, &previous_hash, rng),
+        };
+
+        let powers_of_tau = powers(tau, self.powers_of_beta_g.len());
+        self.powers_of_beta_g = scale(&self.powers_of_beta_g, &powers_of_tau);
+        let mut gamma_scalars = powers_of_tau[..self.powers_of_beta_times_gamma_g.len()].to_vec();
+        cfg_iter_mut!(gamma_scalars).for_each(|scalar| *scalar *= delta);
+        self.powers_of_beta_times_gamma_g = scale(&self.powers_of_beta_times_gamma_g, &gamma_scalars);
+        self.beta_h = (self.beta_h * tau).to_affine();
+        let max_degree = self.max_degree();
+        for (bound, power) in self.negative_powers_of_beta_h.iter_mut() {
+            *power = (*power * powers_of_tau[max_degree - bound]).to_affine();
+        }
+        Ok(proof)
+    }
+
+    /// Checks that `next` is obtained from `self` by the contribution proven by `proof`, and that it is well-formed.
+    /// The randomness of `rng` is used to batch the checks of the powers.
+    pub fn verify_contribution<R: Rng + CryptoRng>(
+        &self,
+        next: &Self,
+        proof: &UpdateProof<E>,
+        rng: &mut R,
+    ) -> Result<()> {
+        ensure!(
+            self.powers_of_beta_g.len() == next.powers_of_beta_g.len()
+                && self.powers_of_beta_times_gamma_g.len() == next.powers_of_beta_times_gamma_g.len()
+                && self.negative_powers_of_beta_h.keys().eq(next.negative_powers_of_beta_h.keys()),
+            "The contribution changes the shape of the powers"
+        );
+        next.verify(rng)?;
+
+        // Check that the contributor knows the secrets, and that they are consistent across G1 and G2.
+        let previous_hash = self.hash()?;
+        let g = E::G1Affine::prime_subgroup_generator();
+        let h = E::G2Affine::prime_subgroup_generator();
+        for (secret_g, secret_h, secret_proof) in
+            [(proof.tau_g, proof.tau_h, &proof.tau_proof), (proof.delta_g, proof.delta_h, &proof.delta_proof)]
+        {
+            ensure!(!secret_g.is_zero() && !secret_h.is_zero(), "The contribution has a zero secret");
+            ensure!(same_ratio::<E>((secret_g, h), (g, secret_h)), "The secrets of the contribution are inconsistent");
+            ensure!(secret_proof.verify(secret_g, &previous_hash), "The proof of knowledge of a secret is invalid");
+        }
+
+        // Check that the contribution builds upon the previous powers.
+        ensure!(
+            same_ratio::<E>((next.powers_of_beta_g[1], h), (self.powers_of_beta_g[1], proof.tau_h)),
+            "The contribution does not update the powers of beta"
+        );
+        ensure!(
+            same_ratio::<E>(
+                (next.powers_of_beta_times_gamma_g[0], h),
+                (self.powers_of_beta_times_gamma_g[0], proof.delta_h)
+            ),
+            "The contribution does not update gamma"
+        );
+        Ok(())
+    }
+
+    /// Checks that the powers are well-formed, i.e. that they are the powers of a single `\beta`,
+    /// and that `\beta` and `\gamma` are non-zero.
+    pub fn verify<R: Rng + CryptoRng>(&self, rng: &mut R) -> Result<()> {
+        let g = E::G1Affine::prime_subgroup_generator();
+        let h = E::G2Affine::prime_subgroup_generator();
+        ensure!(self.powers_of_beta_g.len() > 1, "The powers of beta are missing");
+        ensure!(!self.powers_of_beta_times_gamma_g.is_empty(), "The powers of beta times gamma are missing");
+        ensure!(self.powers_of_beta_g[0] == g, "The zeroth power of beta is not the generator");
+        ensure!(!self.beta_h.is_zero(), "Beta is zero");
+        ensure!(!self.powers_of_beta_times_gamma_g[0].is_zero(), "Gamma is zero");
+        ensure!(
+            same_ratio::<E>((self.powers_of_beta_g[1], h), (g, self.beta_h)),
+            "The powers of beta in G1 and G2 are inconsistent"
+        );
+
+        // Each sequence of powers is checked at once with a random linear combination of its consecutive pairs.
+        for powers in [&self.powers_of_beta_g, &self.powers_of_beta_times_gamma_g] {
+            if powers.len() > 1 {
+                let (lower, upper) = random_combinations::<E, _>(powers, rng);
+                ensure!(same_ratio::<E>((upper, h), (lower, self.beta_h)), "The powers are not consecutive");
+            }
+        }
+
+        let max_degree = self.max_degree();
+        for (bound, power) in &self.negative_powers_of_beta_h {
+            ensure!(*bound <= max_degree, "The degree bound {bound} exceeds the maximum degree");
+            ensure!(
+                same_ratio::<E>((self.powers_of_beta_g[max_degree - bound], h), (g, *power)),
+                "The shifted power of beta for degree bound {bound} is incorrect"
+            );
+        }
+        Ok(())
+    }
+}
+
+impl<E: PairingEngine> SchnorrProof<E> {
+    /// Proves the knowledge of `secret`, such that `point = secret * G`, bound to `transcript_hash`.
+    fn prove<R: Rng + CryptoRng>(secret: E::Fr, point: E::G1Affine, transcript_hash: &[u8; 32], rng: &mut R) -> Self {
+        let nonce = E::Fr::rand(rng);
+        let commitment = (E::G1Affine::prime_subgroup_generator() * nonce).to_affine();
+        let challenge = Self::challenge(point, commitment, transcript_hash);
+        Self { commitment, response: nonce + challenge * secret }
+    }
+
+    /// Returns `true` if the proof shows the knowledge of the discrete logarithm of `point`, bound to `transcript_hash`.
+    fn verify(&self, point: E::G1Affine, transcript_hash: &[u8; 32]) -> bool {
+        let challenge = Self::challenge(point, self.commitment, transcript_hash);
+        E::G1Affine::prime_subgroup_generator() * self.response == self.commitment.to_projective() + point * challenge
+    }
+
+    /// Returns the Fiat-Shamir challenge of the proof.
+    fn challenge(point: E::G1Affine, commitment: E::G1Affine, transcript_hash: &[u8; 32]) -> E::Fr {
+        let mut hasher = Blake2s256::new();
+        hasher.update(CEREMONY_PERSONALIZATION);
+        hasher.update(transcript_hash);
+        let mut writer = HashWriter(&mut hasher);
+        // Writing to a hasher does not fail.
+        point.serialize_compressed(&mut writer).unwrap();
+        commitment.serialize_compressed(&mut writer).unwrap();
+        E::Fr::from_bytes_le_mod_order(&hasher.finalize())
+    }
+}
+
+/// A writer into a hasher, to hash a serialization without buffering it.
+struct HashWriter<'a>(&'a mut Blake2s256);
+
+impl Write for HashWriter<'_> {
+    fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+        self.0.update(buf);
+        Ok(buf.len())
+    }
+
+    fn flush(&mut self) -> std::io::Result<()> {
+        Ok(())
+    }
+}
+
+/// Returns `true` if `e(a.0, a.1) = e(b.0, b.1)`.
+fn same_ratio<E: PairingEngine>(a: (E::G1Affine, E::G2Affine), b: (E::G1Affine, E::G2Affine)) -> bool {
+    E::pairing(a.0, a.1) == E::pairing(b.0, b.1)
+}
+
+/// Samples a non-zero field element.
+fn sample_nonzero<F: Field, R: Rng>(rng: &mut R) -> F {
+    loop {
+        let element = F::rand(rng);
+        if !element.is_zero() {
+            return element;
+        }
+    }
+}
+
+/// Returns `[1, x, x^2, ..., x^(n - 1)]`.
+fn powers<F: Field>(x: F, n: usize) -> Vec<F> {
+    let mut powers = Vec::with_capacity(n);
+    let mut power = F::one();
+    for _ in 0..n {
+        powers.push(power);
+        power *= x;
+    }
+    powers
+}
+
+/// Returns `scalars[i] * bases[i]` for each `i`.
+fn scale<G: AffineCurve>(bases: &[G], scalars: &[G::ScalarField]) -> Vec<G> {
+    let scaled = cfg_iter!(bases).zip(scalars).map(|(base, scalar)| *base * *scalar).collect::<Vec<_>>();
+    G::Projective::batch_normalization_into_affine(scaled)
+}
+
+/// Returns `\sum_i r_i P_i` and `\sum_i r_i P_{i + 1}` for random `r_i`,
+/// which are in the same ratio as consecutive powers if and only if the powers are, with overwhelming probability.
+fn random_combinations<E: PairingEngine, R: Rng>(powers: &[E::G1Affine], rng: &mut R) -> (E::G1Affine, E::G1Affine) {
+    let randomizers =
+        (0..powers.len() - 1).map(|_| u128::rand(rng).into()).collect::<Vec<<E::Fr as PrimeField>::BigInteger>>();
+    let lower = VariableBase::msm(&powers[..powers.len() - 1], &randomizers);
+    let upper = VariableBase::msm(&powers[1..], &randomizers);
+    (lower.to_affine(), upper.to_affine())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_curves::bls12_377::{Bls12_377, Fr};
+    use snarkvm_utilities::TestRng;
+
+    type E = Bls12_377;
+
+    #[test]
+    fn test_ceremony() {
+        let rng = &mut TestRng::default();
+        let mut powers = CeremonyPowers::<E>::new(32, 4, &[7, 15, 31]).unwrap();
+        powers.verify(rng).unwrap();
+
+        for _ in 0..3 {
+            let previous = powers.clone();
+            let proof = powers.contribute(rng).unwrap();
+            previous.verify_contribution(&powers, &proof, rng).unwrap();
+        }
+
+        // The powers are the powers of a single beta.
+        let beta_g = powers.powers_of_beta_g[1];
+        assert!(same_ratio::<E>(
+            (powers.powers_of_beta_g[32], E::G2Affine::prime_subgroup_generator()),
+            (powers.powers_of_beta_g[31], powers.beta_h)
+        ));
+        assert_ne!(beta_g, E::G1Affine::prime_subgroup_generator());
+    }
+
+    #[test]
+    fn test_invalid_contributions() {
+        let rng = &mut TestRng::default();
+        let previous = CeremonyPowers::<E>::new(16, 2, &[3, 7]).unwrap();
+        let mut next = previous.clone();
+        let proof = next.contribute(rng).unwrap();
+        previous.verify_contribution(&next, &proof, rng).unwrap();
+
+        // A tampered power is rejected.
+        let mut tampered = next.clone();
+        tampered.powers_of_beta_g[9] = (tampered.powers_of_beta_g[9] * Fr::from(2u64)).to_affine();
+        assert!(previous.verify_contribution(&tampered, &proof, rng).is_err());
+        let mut tampered = next.clone();
+        tampered.powers_of_beta_times_gamma_g[2] = E::G1Affine::prime_subgroup_generator();
+        assert!(previous.verify_contribution(&tampered, &proof, rng).is_err());
+        let mut tampered = next.clone();
+        tampered.negative_powers_of_beta_h.insert(3, E::G2Affine::prime_subgroup_generator());
+        assert!(previous.verify_contribution(&tampered, &proof, rng).is_err());
+
+        // A proof is bound to the powers it updates.
+        let mut other = next.clone();
+        let other_proof = other.contribute(rng).unwrap();
+        assert!(previous.verify_contribution(&next, &other_proof, rng).is_err());
+        assert!(next.verify_contribution(&other, &proof, rng).is_err());
+
+        // A proof with an inconsistent secret is rejected.
+        let mut inconsistent_proof = proof;
+        inconsistent_proof.tau_h = E::G2Affine::prime_subgroup_generator();
+        assert!(previous.verify_contribution(&next, &inconsistent_proof, rng).is_err());
+
+        // Powers with zero secrets are rejected.
+        let zero = CeremonyPowers::<E> {
+            powers_of_beta_g: vec![E::G1Affine::prime_subgroup_generator(), E::G1Affine::zero()],
+            powers_of_beta_times_gamma_g: vec![E::G1Affine::zero()],
+            beta_h: E::G2Affine::zero(),
+            negative_powers_of_beta_h: BTreeMap::new(),
+        };
+        assert!(zero.verify(rng).is_err());
+    }
+
+    #[test]
+    fn test_serialization() {
+        let rng = &mut TestRng::default();
+        let mut powers = CeremonyPowers::<E>::new(8, 1, &[3]).unwrap();
+        let proof = powers.contribute(rng).unwrap();
+
+        let mut bytes = Vec::new();
+        powers.serialize_compressed(&mut bytes).unwrap();
+        assert_eq!(powers, CeremonyPowers::deserialize_compressed(&bytes[..]).unwrap());
+        let mut bytes = Vec::new();
+        proof.serialize_compressed(&mut bytes).unwrap();
+        assert_eq!(proof, UpdateProof::deserialize_compressed(&bytes[..]).unwrap());
+        assert_eq!(powers.hash().unwrap(), powers.clone().hash().unwrap());
+    }
+}
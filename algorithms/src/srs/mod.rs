@@ -12,6 +12,11 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+#[cfg(feature = "msm")]
+pub mod ceremony;
+#[cfg(feature = "msm")]
+pub use ceremony::*;
+
 pub mod universal_prover;
 pub use universal_prover::*;
 
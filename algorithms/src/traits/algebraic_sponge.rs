@@ -23,7 +23,7 @@ use core::fmt::Debug;
 /// The outputs are dependent on previous `absorb` and `squeeze` calls.
 pub trait AlgebraicSponge<F: PrimeField, const RATE: usize>: Clone + Debug {
     /// Parameters used by the sponge.
-    type Parameters;
+    type Parameters: Send + Sync;
 
     fn sample_parameters() -> Self::Parameters;
 
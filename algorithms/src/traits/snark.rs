@@ -12,14 +12,90 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
-use crate::{r1cs::ConstraintSynthesizer, AlgebraicSponge};
+use crate::{msm::VariableBase, r1cs::ConstraintSynthesizer, AlgebraicSponge};
 use snarkvm_fields::PrimeField;
-use snarkvm_utilities::{CanonicalDeserialize, CanonicalSerialize, FromBytes, ToBytes};
+use snarkvm_utilities::{
+    execute_in_thread_pool,
+    CanonicalDeserialize,
+    CanonicalSerialize,
+    FromBytes,
+    ThreadPoolConfig,
+    ToBytes,
+};
 
+#[cfg(not(feature = "out-of-core"))]
+use crate::SNARKError;
+
+#[cfg(not(feature = "out-of-core"))]
+use anyhow::bail;
 use anyhow::Result;
-use rand::{CryptoRng, Rng};
+use rand::{CryptoRng, Rng, SeedableRng};
+use rand_chacha::ChaChaRng;
 use std::{borrow::Borrow, collections::BTreeMap, fmt::Debug};
 
+/// The resources that a prover may use.
+///
+/// The options apply to a single proof, which runs on a dedicated thread pool whose workers carry them,
+/// so that the proofs computed concurrently by the process are unaffected.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ProverOptions {
+    /// The maximum number of bytes that the prover may allocate besides its keys, or `None` if unbounded.
+    /// A proof whose estimated memory exceeds it computes its FFTs out of core, each within an equal share
+    /// of the budget among the threads, or fails before any work is done if snarkVM is built without `out-of-core`.
+    pub max_memory_bytes: Option<usize>,
+    /// The number of threads of the prover, or `None` to use as many threads as there are CPUs.
+    pub num_threads: Option<usize>,
+    /// Whether the prover may compute MSMs on a GPU, if snarkVM is built with GPU support.
+    pub gpu: bool,
+}
+
+impl Default for ProverOptions {
+    fn default() -> Self {
+        Self { max_memory_bytes: None, num_threads: None, gpu: true }
+    }
+}
+
+impl ProverOptions {
+    /// Returns the result of `prove` within these options, given the estimated memory of the proof.
+    /// The RNG may not be sent to the thread pool, so `prove` derives its randomness from a seed sampled from `rng`.
+    pub(crate) fn execute<T: Send, R: Rng + CryptoRng>(
+        &self,
+        memory_estimate: impl FnOnce() -> Result<usize>,
+        rng: &mut R,
+        prove: impl FnOnce([u8; 32]) -> Result<T> + Send,
+    ) -> Result<T> {
+        let num_threads = match self.num_threads {
+            Some(num_threads) => num_threads.max(1),
+            None => std::thread::available_parallelism().map_or(1, |num_threads| num_threads.get()),
+        };
+        let fft_budget: Option<usize> = match self.max_memory_bytes {
+            Some(budget) => match memory_estimate()? {
+                required if required <= budget => None,
+                #[cfg(feature = "out-of-core")]
+                _ => Some(budget / num_threads),
+                #[cfg(not(feature = "out-of-core"))]
+                required => bail!(SNARKError::MemoryBudgetExceeded { required, budget }),
+            },
+            None => None,
+        };
+
+        let seed = rng.gen();
+        if self.num_threads.is_none() && self.gpu && fft_budget.is_none() {
+            return prove(seed);
+        }
+        let gpu = self.gpu;
+        let pool = ThreadPoolConfig::new()
+            .num_threads(num_threads)
+            .start_handler(move |_| {
+                VariableBase::set_gpu_enabled_on_thread(gpu);
+                #[cfg(feature = "out-of-core")]
+                crate::fft::set_fft_memory_budget_on_thread(fft_budget);
+            })
+            .build()?;
+        execute_in_thread_pool(&pool, || prove(seed))
+    }
+}
+
 /// Defines trait that describes preparing from an unprepared version to a prepare version.
 pub trait Prepare {
     type Prepared;
@@ -87,6 +163,58 @@ pub trait SNARK {
         rng: &mut R,
     ) -> Result<Self::Proof>;
 
+    /// Returns a proof computed within the resources of `options`.
+    fn prove_with_options<C: ConstraintSynthesizer<Self::ScalarField>, R: Rng + CryptoRng>(
+        universal_prover: &Self::UniversalProver,
+        fs_parameters: &Self::FSParameters,
+        proving_key: &Self::ProvingKey,
+        constraints: &C,
+        options: &ProverOptions,
+        rng: &mut R,
+    ) -> Result<Self::Proof> {
+        let mut keys_to_constraints = BTreeMap::new();
+        keys_to_constraints.insert(proving_key, std::slice::from_ref(constraints));
+        Self::prove_batch_with_options(universal_prover, fs_parameters, &keys_to_constraints, options, rng)
+    }
+
+    /// Returns a batch proof computed within the resources of `options`.
+    fn prove_batch_with_options<C: ConstraintSynthesizer<Self::ScalarField>, R: Rng + CryptoRng>(
+        universal_prover: &Self::UniversalProver,
+        fs_parameters: &Self::FSParameters,
+        keys_to_constraints: &BTreeMap<&Self::ProvingKey, &[C]>,
+        options: &ProverOptions,
+        rng: &mut R,
+    ) -> Result<Self::Proof>;
+
+    /// Returns an estimate of the number of bytes that `prove_batch` allocates besides the proving keys.
+    fn prover_memory_estimate<C: ConstraintSynthesizer<Self::ScalarField>>(
+        keys_to_constraints: &BTreeMap<&Self::ProvingKey, &[C]>,
+    ) -> Result<usize>;
+
+    /// Returns a proof whose randomness is derived from `seed`, so that proving the same constraints
+    /// with the same seed yields an identical proof, e.g. on another machine.
+    /// The proof is zero-knowledge as long as `seed` is sampled uniformly at random and kept secret.
+    /// A seed must not be reused for different constraints, as this would reuse the masks of the witnesses.
+    fn prove_with_seed<C: ConstraintSynthesizer<Self::ScalarField>>(
+        universal_prover: &Self::UniversalProver,
+        fs_parameters: &Self::FSParameters,
+        proving_key: &Self::ProvingKey,
+        constraints: &C,
+        seed: [u8; 32],
+    ) -> Result<Self::Proof> {
+        Self::prove(universal_prover, fs_parameters, proving_key, constraints, &mut ChaChaRng::from_seed(seed))
+    }
+
+    /// Returns a batch proof whose randomness is derived from `seed`, as in `prove_with_seed`.
+    fn prove_batch_with_seed<C: ConstraintSynthesizer<Self::ScalarField>>(
+        universal_prover: &Self::UniversalProver,
+        fs_parameters: &Self::FSParameters,
+        keys_to_constraints: &BTreeMap<&Self::ProvingKey, &[C]>,
+        seed: [u8; 32],
+    ) -> Result<Self::Proof> {
+        Self::prove_batch(universal_prover, fs_parameters, keys_to_constraints, &mut ChaChaRng::from_seed(seed))
+    }
+
     fn verify_vk<C: ConstraintSynthesizer<Self::ScalarField>>(
         universal_verifier: &Self::UniversalVerifier,
         fs_parameters: &Self::FSParameters,
@@ -114,4 +242,21 @@ pub trait SNARK {
         keys_to_inputs: &BTreeMap<&Self::VerifyingKey, &[B]>,
         proof: &Self::Proof,
     ) -> Result<bool>;
+
+    /// Verifies several proofs, each for the inputs of its verifying keys as in `verify_batch`,
+    /// and returns `true` if and only if all of them are valid.
+    /// Implementations may use `rng` to combine the checks of the proofs into a single randomized check.
+    fn verify_proofs<B: Borrow<Self::VerifierInput>, R: Rng + CryptoRng>(
+        universal_verifier: &Self::UniversalVerifier,
+        fs_parameters: &Self::FSParameters,
+        proofs: &[(&BTreeMap<&Self::VerifyingKey, &[B]>, &Self::Proof)],
+        _rng: &mut R,
+    ) -> Result<bool> {
+        for (keys_to_inputs, proof) in proofs {
+            if !Self::verify_batch(universal_verifier, fs_parameters, keys_to_inputs, proof)? {
+                return Ok(false);
+            }
+        }
+        Ok(true)
+    }
 }
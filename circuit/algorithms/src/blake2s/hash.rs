@@ -0,0 +1,257 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+use super::*;
+
+impl<E: Environment> Hash for Blake2s<E> {
+    type Input = Boolean<E>;
+    type Output = Vec<Boolean<E>>;
+
+    /// Returns the BLAKE2s hash of the given input as bits.
+    #[inline]
+    fn hash(&self, input: &[Self::Input]) -> Self::Output {
+        // The state `h` is initialized to the initial hash value.
+        let mut state = self.initial_state.clone();
+
+        // Pad the input, and record the message length in bytes.
+        let (padded_input, message_length) = Self::pad(input);
+        let num_blocks = padded_input.len() / BLOCK_SIZE_IN_BITS;
+
+        // Process each padded message block, where the counter `t` is the number of bytes processed so far,
+        // including the current block, and the final block is flagged.
+        for (i, block) in padded_input.chunks(BLOCK_SIZE_IN_BITS).enumerate() {
+            let is_final_block = i + 1 == num_blocks;
+            let counter = match is_final_block {
+                true => message_length,
+                false => (i as u64 + 1) * (BLOCK_SIZE_IN_BITS as u64 / 8),
+            };
+            state = Self::compress(&state, block, counter, is_final_block);
+        }
+
+        // Return the digest `h[0] || … || h[7]`, where each word is written as little-endian bytes.
+        state.iter().flat_map(|word| word.to_bits_le()).collect()
+    }
+}
+
+impl<E: Environment> Blake2s<E> {
+    /// In BLAKE2s, the input data is padded with zero bytes to a multiple of the block size (512 bits).
+    /// If the input data is empty, it is padded to a single block of zeros.
+    ///
+    /// The padded message is returned as bytes, where each byte is in little-endian bit order,
+    /// along with the length of the (unpadded) message in bytes.
+    fn pad(input: &[Boolean<E>]) -> (Vec<Boolean<E>>, u64) {
+        // Resize the input to a multiple of 8.
+        let mut padded_input = input.to_vec();
+        padded_input.resize((input.len() + 7) / 8 * 8, Boolean::constant(false));
+
+        // Record the message length in bytes.
+        let message_length = (padded_input.len() / 8) as u64;
+
+        // Append "0x00" bytes until the message is a non-empty multiple of 512 bits.
+        let num_blocks = padded_input.len().div_ceil(BLOCK_SIZE_IN_BITS).max(1);
+        padded_input.resize(num_blocks * BLOCK_SIZE_IN_BITS, Boolean::constant(false));
+
+        (padded_input, message_length)
+    }
+
+    /// The compression function `F` processes one 512-bit message block, and returns the updated state.
+    fn compress(state: &[U32<E>], block: &[Boolean<E>], counter: u64, is_final_block: bool) -> Vec<U32<E>> {
+        debug_assert_eq!(state.len(), 8, "The state must have 8 words");
+        debug_assert_eq!(block.len(), BLOCK_SIZE_IN_BITS, "The block must have {BLOCK_SIZE_IN_BITS} bits");
+
+        // Read the message block as 16 little-endian words.
+        let m = block.chunks(32).map(U32::from_bits_le).collect::<Vec<_>>();
+
+        /* Initialize the local work vector:
+         *
+         *   v[0..8] = h[0..8]
+         *   v[8..16] = IV[0..8]
+         *   v[12] = v[12] ^ (t mod 2^32)
+         *   v[13] = v[13] ^ (t >> 32)
+         *   v[14] = v[14] ^ 0xFFFFFFFF, if this is the final block
+         *
+         * Note: As the counter and final block flag are known, the last 8 words are constants.
+         */
+        let mut iv = Self::IV;
+        iv[4] ^= counter as u32;
+        iv[5] ^= (counter >> 32) as u32;
+        if is_final_block {
+            iv[6] ^= u32::MAX;
+        }
+        let mut v = state.to_vec();
+        v.extend(iv.into_iter().map(|e| U32::constant(console::U32::new(e))));
+
+        // Apply the mixing function `G` to the columns, and then to the diagonals, of the work vector.
+        for s in &Self::SIGMA {
+            Self::mix(&mut v, [0, 4, 8, 12], &m[s[0]], &m[s[1]]);
+            Self::mix(&mut v, [1, 5, 9, 13], &m[s[2]], &m[s[3]]);
+            Self::mix(&mut v, [2, 6, 10, 14], &m[s[4]], &m[s[5]]);
+            Self::mix(&mut v, [3, 7, 11, 15], &m[s[6]], &m[s[7]]);
+            Self::mix(&mut v, [0, 5, 10, 15], &m[s[8]], &m[s[9]]);
+            Self::mix(&mut v, [1, 6, 11, 12], &m[s[10]], &m[s[11]]);
+            Self::mix(&mut v, [2, 7, 8, 13], &m[s[12]], &m[s[13]]);
+            Self::mix(&mut v, [3, 4, 9, 14], &m[s[14]], &m[s[15]]);
+        }
+
+        // Compute the updated state `h[i] = h[i] ^ v[i] ^ v[i + 8]`.
+        state.iter().enumerate().map(|(i, h)| h ^ &v[i] ^ &v[i + 8]).collect()
+    }
+
+    /// The mixing function `G` mixes two message words `x` and `y` into the work vector `v`,
+    /// at the given indices `a`, `b`, `c`, and `d`.
+    fn mix(v: &mut [U32<E>], [a, b, c, d]: [usize; 4], x: &U32<E>, y: &U32<E>) {
+        v[a] = Self::add_chain(&[&v[a], &v[b], x]);
+        v[d] = Self::rotate_right(&(&v[d] ^ &v[a]), 16);
+        v[c] = Self::add_chain(&[&v[c], &v[d]]);
+        v[b] = Self::rotate_right(&(&v[b] ^ &v[c]), 12);
+        v[a] = Self::add_chain(&[&v[a], &v[b], y]);
+        v[d] = Self::rotate_right(&(&v[d] ^ &v[a]), 8);
+        v[c] = Self::add_chain(&[&v[c], &v[d]]);
+        v[b] = Self::rotate_right(&(&v[b] ^ &v[c]), 7);
+    }
+
+    /// Returns the sum of the given words modulo `2^32`.
+    ///
+    /// The words are summed as a linear combination in the base field, and the lower 32 bits of
+    /// the sum are extracted in a single bit decomposition.
+    fn add_chain(words: &[&U32<E>]) -> U32<E> {
+        debug_assert!(!words.is_empty(), "The addition chain must have at least one word");
+
+        // Sum the words in the base field.
+        let sum = words.iter().map(|word| word.to_field()).fold(Field::zero(), |sum, word| sum + word);
+
+        // If the sum is a constant, return the lower 32 bits directly.
+        if sum.is_constant() {
+            let bits_le =
+                sum.eject_value().to_bits_le().into_iter().take(32).map(Boolean::constant).collect::<Vec<_>>();
+            return U32::from_bits_le(&bits_le);
+        }
+
+        // Compute the number of bits needed to represent the sum (i.e. 32 bits plus the carry bits).
+        // As each word is at most `2^32 - 1`, the sum is strictly less than `n * 2^32`.
+        let num_carry_bits = (usize::BITS - (words.len() - 1).leading_zeros()) as usize;
+
+        // Extract the lower 32 bits of the sum, while enforcing that the carry bits are well-formed.
+        let bits_le = sum.to_lower_bits_le(32 + num_carry_bits);
+        U32::from_bits_le(&bits_le[..32])
+    }
+
+    /// Performs a rotate right operation on the given `u32` value.
+    fn rotate_right(value: &U32<E>, n: usize) -> U32<E> {
+        // Perform the rotation.
+        let mut bits_le = value.to_bits_le();
+        bits_le.rotate_left(n);
+        // Return the rotated value.
+        U32::from_bits_le(&bits_le)
+    }
+}
+
+#[cfg(all(test, console))]
+mod tests {
+    use super::*;
+    use console::Rng;
+    use snarkvm_circuit_types::environment::Circuit;
+
+    const ITERATIONS: usize = 3;
+
+    /// The maximum number of constraints (and private variables) per 512-bit block.
+    /// This bound is computed from the cost of each step of the compression function, without constant folding:
+    ///  - each mixing function costs at most `2 * 35 + 2 * 34 + 4 * 32 = 266`, and is applied `8 * 10 = 80` times,
+    ///  - the final state update costs at most `8 * 2 * 32 = 512`.
+    const MAX_CONSTRAINTS_PER_BLOCK: u64 = 80 * 266 + 512;
+
+    fn check_hash(mode: Mode, num_inputs: usize, rng: &mut TestRng) {
+        use console::Hash as H;
+
+        let native = console::Blake2s;
+        let blake2s = Blake2s::<Circuit>::new();
+
+        // Compute the number of padded blocks.
+        let num_blocks = ((num_inputs + 7) / 8).div_ceil(64).max(1);
+        let max_constraints = num_blocks as u64 * MAX_CONSTRAINTS_PER_BLOCK;
+
+        for i in 0..ITERATIONS {
+            // Prepare the preimage.
+            let native_input = (0..num_inputs).map(|_| Uniform::rand(rng)).collect::<Vec<bool>>();
+            let input = native_input.iter().map(|v| Boolean::<Circuit>::new(mode, *v)).collect::<Vec<_>>();
+
+            // Compute the native hash.
+            let expected = native.hash(&native_input).expect("Failed to hash native input");
+
+            // Compute the circuit hash.
+            Circuit::scope(format!("BLAKE2s {mode} {i}"), || {
+                let candidate = blake2s.hash(&input);
+                assert_eq!(expected, candidate.eject_value());
+                match mode {
+                    Mode::Constant => assert_scope!(0, 0, 0, 0),
+                    _ => assert_scope!(<=0, <=0, <=max_constraints, <=max_constraints),
+                }
+            });
+            Circuit::reset();
+        }
+    }
+
+    #[test]
+    fn test_blake2s_hash_constant() {
+        let mut rng = TestRng::default();
+
+        for num_inputs in [0, 1, 8, 255, 256, 511, 512, 513, 1024] {
+            check_hash(Mode::Constant, num_inputs, &mut rng);
+        }
+    }
+
+    #[test]
+    fn test_blake2s_hash_public() {
+        let mut rng = TestRng::default();
+
+        for num_inputs in [0, 1, 8, 255, 256, 511, 512, 513, 1024] {
+            check_hash(Mode::Public, num_inputs, &mut rng);
+        }
+    }
+
+    #[test]
+    fn test_blake2s_hash_private() {
+        let mut rng = TestRng::default();
+
+        for num_inputs in [0, 1, 8, 255, 256, 511, 512, 513, 1024] {
+            check_hash(Mode::Private, num_inputs, &mut rng);
+        }
+    }
+
+    #[test]
+    fn test_blake2s_equivalence() {
+        use console::Hash as H;
+
+        let rng = &mut TestRng::default();
+
+        let mut input_sizes = vec![0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 16, 32, 64, 128, 256, 512, 1024];
+        input_sizes.extend((0..5).map(|_| rng.gen_range(1..1024)));
+
+        for num_inputs in input_sizes {
+            println!("Checking equivalence for {num_inputs} inputs");
+
+            // Prepare the preimage.
+            let native_input = (0..num_inputs).map(|_| Uniform::rand(rng)).collect::<Vec<bool>>();
+            let input = native_input.iter().map(|v| Boolean::<Circuit>::new(Mode::Private, *v)).collect::<Vec<_>>();
+
+            // Compute the console hash.
+            let expected = console::Blake2s.hash(&native_input).expect("Failed to hash console input");
+
+            // Compute the circuit hash.
+            let candidate = Blake2s::<Circuit>::new().hash(&input);
+            assert_eq!(expected, candidate.eject_value());
+            assert!(Circuit::is_satisfied());
+            Circuit::reset();
+        }
+    }
+}
@@ -0,0 +1,119 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+use super::*;
+
+impl<E: Environment> Add<BlsG1<E>> for BlsG1<E> {
+    type Output = BlsG1<E>;
+
+    fn add(self, other: BlsG1<E>) -> Self::Output {
+        &self + &other
+    }
+}
+
+impl<E: Environment> Add<&BlsG1<E>> for BlsG1<E> {
+    type Output = BlsG1<E>;
+
+    fn add(self, other: &BlsG1<E>) -> Self::Output {
+        &self + other
+    }
+}
+
+impl<E: Environment> Add<&BlsG1<E>> for &BlsG1<E> {
+    type Output = BlsG1<E>;
+
+    /// Returns the sum of two points, using incomplete affine addition.
+    /// If the points have the same x-coordinate (i.e. they are equal or inverses), the circuit is unsatisfiable.
+    fn add(self, other: &BlsG1<E>) -> Self::Output {
+        // Compute the slope `(y2 - y1) / (x2 - x1)`.
+        let lambda = (&other.y - &self.y) * (&other.x - &self.x).inverse();
+        // Compute `x3 = lambda^2 - x1 - x2`.
+        let x = &lambda * &lambda - &self.x - &other.x;
+        // Compute `y3 = lambda * (x1 - x3) - y1`.
+        let y = &lambda * (&self.x - &x) - &self.y;
+        BlsG1 { x, y }
+    }
+}
+
+impl<E: Environment> BlsSignature<E> {
+    /// Returns the sum of the given signatures, which verifies for the sum of their public keys
+    /// if every signature is on the same message.
+    ///
+    /// The signatures are added with incomplete addition, so no partial sum may share
+    /// an x-coordinate with the next signature, which holds for distinct signers except with negligible probability.
+    pub fn aggregate(signatures: &[Self]) -> Self {
+        match signatures.split_first() {
+            Some((first, rest)) => Self {
+                signature: rest.iter().fold(first.signature.clone(), |sum, signature| &sum + &signature.signature),
+            },
+            None => E::halt("Cannot aggregate an empty list of BLS signatures"),
+        }
+    }
+}
+
+#[cfg(all(test, console))]
+mod tests {
+    use super::*;
+    use snarkvm_circuit_types::environment::Circuit;
+    use snarkvm_curves::{bls12_377::Fr, AffineCurve, ProjectiveCurve};
+
+    const ITERATIONS: usize = 10;
+
+    fn check_add(mode_a: Mode, mode_b: Mode) {
+        let rng = &mut TestRng::default();
+
+        for i in 0..ITERATIONS {
+            let a = console::BlsSignature::hash_message(format!("first {i}").as_bytes());
+            let b = console::BlsSignature::hash_message(format!("second {i}").as_bytes());
+            let expected = (a.to_projective() + b.to_projective()).to_affine();
+
+            Circuit::scope(format!("{mode_a} + {mode_b} {i}"), || {
+                let candidate = BlsG1::<Circuit>::new(mode_a, a) + BlsG1::new(mode_b, b);
+                assert_eq!(expected, candidate.eject_value());
+                assert!(Circuit::is_satisfied_in_scope(), "(is_satisfied_in_scope)");
+            });
+            Circuit::reset();
+        }
+
+        // Ensure adding a (non-constant) point to itself is unsatisfiable, as the addition is incomplete.
+        let a = console::BlsSignature::sign(&Fr::rand(rng), b"message");
+        let a = BlsG1::<Circuit>::new(mode_a, *a.signature());
+        let _candidate = &a + &a;
+        assert!(!Circuit::is_satisfied());
+        Circuit::reset();
+    }
+
+    #[test]
+    fn test_add() {
+        check_add(Mode::Public, Mode::Public);
+        check_add(Mode::Public, Mode::Private);
+        check_add(Mode::Private, Mode::Constant);
+        check_add(Mode::Private, Mode::Private);
+    }
+
+    #[test]
+    fn test_aggregate() {
+        let rng = &mut TestRng::default();
+        let message = b"Attestation for block 0";
+
+        let private_keys = (0..ITERATIONS).map(|_| Fr::rand(rng)).collect::<Vec<_>>();
+        let signatures = private_keys.iter().map(|key| console::BlsSignature::sign(key, message)).collect::<Vec<_>>();
+        let expected = console::BlsSignature::aggregate(&signatures);
+
+        let candidates = signatures.iter().map(|signature| BlsSignature::<Circuit>::new(Mode::Private, *signature));
+        let candidate = BlsSignature::aggregate(&candidates.collect::<Vec<_>>());
+        assert_eq!(expected, candidate.eject_value());
+        assert!(Circuit::is_satisfied());
+        Circuit::reset();
+    }
+}
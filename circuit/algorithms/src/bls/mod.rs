@@ -0,0 +1,195 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+mod aggregate;
+mod verify;
+
+pub use verify::BlsPairingCheck;
+
+#[cfg(test)]
+use snarkvm_utilities::{TestRng, Uniform};
+
+use snarkvm_circuit_types::{environment::prelude::*, Emulated};
+use snarkvm_curves::{
+    bls12_377::{Bls12_377G1Parameters, Fq, G1Affine, G2Affine},
+    ShortWeierstrassParameters,
+};
+
+/// An affine point in G1 of BLS12-377, with its coordinates emulated in the base field of the environment.
+///
+/// The point is enforced to be on the curve when it is injected, but it is not enforced to be
+/// in the prime-order subgroup, as the subgroup check is left to the deferred pairing check.
+#[derive(Clone)]
+pub struct BlsG1<E: Environment> {
+    /// The x-coordinate of the point.
+    x: Emulated<Fq, E>,
+    /// The y-coordinate of the point.
+    y: Emulated<Fq, E>,
+}
+
+impl<E: Environment> Inject for BlsG1<E> {
+    type Primitive = G1Affine;
+
+    /// Initializes a point in G1 from the given mode and native point, and enforces that it is on the curve.
+    fn new(mode: Mode, point: Self::Primitive) -> Self {
+        let point = Self { x: Emulated::new(mode, point.x), y: Emulated::new(mode, point.y) };
+        point.enforce_on_curve();
+        point
+    }
+}
+
+impl<E: Environment> BlsG1<E> {
+    /// Returns the x-coordinate of the point.
+    pub const fn x(&self) -> &Emulated<Fq, E> {
+        &self.x
+    }
+
+    /// Returns the y-coordinate of the point.
+    pub const fn y(&self) -> &Emulated<Fq, E> {
+        &self.y
+    }
+
+    /// Enforces that the point is equal to the given point.
+    pub fn assert_equal(&self, other: &Self) {
+        Self::assert_equal_coordinates(&self.x, &other.x);
+        Self::assert_equal_coordinates(&self.y, &other.y);
+    }
+
+    /// Enforces that the point satisfies the curve equation `y^2 = x^3 + b`.
+    fn enforce_on_curve(&self) {
+        let y_squared = &self.y * &self.y;
+        let x_cubed_plus_b = &(&self.x * &self.x) * &self.x + Emulated::constant(Bls12_377G1Parameters::WEIERSTRASS_B);
+        Self::assert_equal_coordinates(&y_squared, &x_cubed_plus_b);
+    }
+
+    /// Enforces that the given coordinates are equal, by comparing their limbs.
+    /// As both coordinates are reduced by an honest prover, this is complete,
+    /// and as equal limbs imply equal integers, this is sound.
+    fn assert_equal_coordinates(first: &Emulated<Fq, E>, second: &Emulated<Fq, E>) {
+        for (first_limb, second_limb) in first.limbs().iter().zip_eq(second.limbs()) {
+            E::assert_eq(first_limb, second_limb);
+        }
+    }
+}
+
+impl<E: Environment> Eject for BlsG1<E> {
+    type Primitive = G1Affine;
+
+    /// Ejects the mode of the point.
+    fn eject_mode(&self) -> Mode {
+        (&self.x, &self.y).eject_mode()
+    }
+
+    /// Ejects the point.
+    fn eject_value(&self) -> Self::Primitive {
+        G1Affine::new(self.x.eject_value(), self.y.eject_value(), false)
+    }
+}
+
+/// A BLS signature over BLS12-377, in the minimal-signature-size variant,
+/// where signatures and message hashes are in G1 and public keys are in G2.
+#[derive(Clone)]
+pub struct BlsSignature<E: Environment> {
+    /// The signature point, `private_key * H(message)`.
+    signature: BlsG1<E>,
+}
+
+#[cfg(console)]
+impl<E: Environment> Inject for BlsSignature<E> {
+    type Primitive = console::BlsSignature;
+
+    /// Initializes a BLS signature from the given mode and native signature.
+    fn new(mode: Mode, signature: Self::Primitive) -> Self {
+        Self { signature: BlsG1::new(mode, *signature.signature()) }
+    }
+}
+
+impl<E: Environment> BlsSignature<E> {
+    /// Returns the signature point.
+    pub const fn signature(&self) -> &BlsG1<E> {
+        &self.signature
+    }
+}
+
+#[cfg(console)]
+impl<E: Environment> Eject for BlsSignature<E> {
+    type Primitive = console::BlsSignature;
+
+    /// Ejects the mode of the signature.
+    fn eject_mode(&self) -> Mode {
+        self.signature.eject_mode()
+    }
+
+    /// Ejects the signature.
+    fn eject_value(&self) -> Self::Primitive {
+        console::BlsSignature::new(self.signature.eject_value())
+    }
+}
+
+#[cfg(all(test, console))]
+mod tests {
+    use super::*;
+    use snarkvm_circuit_types::environment::Circuit;
+    use snarkvm_curves::{bls12_377::Fr, AffineCurve};
+
+    const ITERATIONS: usize = 10;
+
+    #[test]
+    fn test_new() {
+        let rng = &mut TestRng::default();
+
+        for mode in [Mode::Constant, Mode::Public, Mode::Private] {
+            for i in 0..ITERATIONS {
+                let expected = console::BlsSignature::sign(&Fr::rand(rng), format!("message {i}").as_bytes());
+
+                Circuit::scope(format!("{mode} {i}"), || {
+                    let candidate = BlsSignature::<Circuit>::new(mode, expected);
+                    assert_eq!(expected, candidate.eject_value());
+                    assert_eq!(mode, candidate.eject_mode());
+                    assert!(Circuit::is_satisfied_in_scope(), "(is_satisfied_in_scope)");
+                });
+                Circuit::reset();
+            }
+        }
+    }
+
+    #[test]
+    fn test_new_fails_off_curve() {
+        let rng = &mut TestRng::default();
+
+        for mode in [Mode::Public, Mode::Private] {
+            let point = console::BlsSignature::hash_message(b"message");
+            let off_curve = G1Affine::new(point.x, point.y + Fq::rand(rng), false);
+            assert!(!off_curve.is_on_curve());
+
+            Circuit::scope(format!("{mode}"), || {
+                let _candidate = BlsG1::<Circuit>::new(mode, off_curve);
+                assert!(!Circuit::is_satisfied_in_scope(), "(!is_satisfied_in_scope)");
+            });
+            Circuit::reset();
+        }
+    }
+
+    #[test]
+    fn test_assert_equal() {
+        let point = console::BlsSignature::hash_message(b"message");
+        let other = console::BlsSignature::hash_message(b"other message");
+
+        let candidate = BlsG1::<Circuit>::new(Mode::Private, point);
+        candidate.assert_equal(&BlsG1::new(Mode::Public, point));
+        assert!(Circuit::is_satisfied());
+        candidate.assert_equal(&BlsG1::new(Mode::Public, other));
+        assert!(!Circuit::is_satisfied());
+        Circuit::reset();
+    }
+}
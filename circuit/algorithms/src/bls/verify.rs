@@ -0,0 +1,146 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+use super::*;
+
+/// The pairing equation of a BLS signature, `e(signature, G2) == e(message_hash, public_key)`,
+/// whose check is deferred to the verifier.
+///
+/// Computing a pairing over BLS12-377 with emulated arithmetic is prohibitively expensive,
+/// so the circuit only enforces that the signature and message hash are points on G1.
+/// The verifier must check the pairing equation outside of the circuit, in addition to verifying the proof,
+/// which requires the signature and message hash to be known to the verifier, e.g. as public inputs
+/// (see [`BlsG1::assert_equal`] to bind a computed point to a public input).
+#[derive(Clone)]
+pub struct BlsPairingCheck<E: Environment> {
+    /// The signature point.
+    signature: BlsG1<E>,
+    /// The hash of the message in G1.
+    message_hash: BlsG1<E>,
+    /// The public key in G2.
+    public_key: G2Affine,
+}
+
+impl<E: Environment> BlsSignature<E> {
+    /// Returns the pairing check of the signature, for the given public key and message hash.
+    /// The check must be performed by the verifier, as described in [`BlsPairingCheck`].
+    pub fn verify(&self, public_key: &G2Affine, message_hash: &BlsG1<E>) -> BlsPairingCheck<E> {
+        BlsPairingCheck {
+            signature: self.signature.clone(),
+            message_hash: message_hash.clone(),
+            public_key: *public_key,
+        }
+    }
+}
+
+impl<E: Environment> BlsPairingCheck<E> {
+    /// Returns the signature point.
+    pub const fn signature(&self) -> &BlsG1<E> {
+        &self.signature
+    }
+
+    /// Returns the hash of the message in G1.
+    pub const fn message_hash(&self) -> &BlsG1<E> {
+        &self.message_hash
+    }
+
+    /// Returns the public key in G2.
+    pub const fn public_key(&self) -> &G2Affine {
+        &self.public_key
+    }
+}
+
+#[cfg(console)]
+impl<E: Environment> BlsPairingCheck<E> {
+    /// Returns `true` if the pairing equation holds for the values of the points.
+    pub fn is_satisfied(&self) -> bool {
+        console::BlsSignature::new(self.signature.eject_value())
+            .verify_hashed(&self.public_key, &self.message_hash.eject_value())
+    }
+}
+
+#[cfg(all(test, console))]
+mod tests {
+    use super::*;
+    use snarkvm_circuit_types::environment::Circuit;
+    use snarkvm_curves::bls12_377::Fr;
+
+    const ITERATIONS: usize = 10;
+
+    fn check_verify(mode: Mode) {
+        let rng = &mut TestRng::default();
+
+        for i in 0..ITERATIONS {
+            let private_key = Fr::rand(rng);
+            let public_key = console::BlsSignature::public_key(&private_key);
+            let message = format!("Attestation for block {i}");
+            let signature = console::BlsSignature::sign(&private_key, message.as_bytes());
+            let message_hash = console::BlsSignature::hash_message(message.as_bytes());
+
+            Circuit::scope(format!("{mode} {i}"), || {
+                let signature = BlsSignature::<Circuit>::new(mode, signature);
+                let message_hash = BlsG1::new(mode, message_hash);
+
+                // Ensure the pairing check holds for the correct public key.
+                assert!(signature.verify(&public_key, &message_hash).is_satisfied());
+                // Ensure the pairing check fails for an incorrect public key or message.
+                let other_public_key = console::BlsSignature::public_key(&Fr::rand(rng));
+                assert!(!signature.verify(&other_public_key, &message_hash).is_satisfied());
+                let other_message_hash = BlsG1::new(mode, console::BlsSignature::hash_message(b"other"));
+                assert!(!signature.verify(&public_key, &other_message_hash).is_satisfied());
+
+                assert!(Circuit::is_satisfied_in_scope(), "(is_satisfied_in_scope)");
+            });
+            Circuit::reset();
+        }
+    }
+
+    #[test]
+    fn test_verify_constant() {
+        check_verify(Mode::Constant);
+    }
+
+    #[test]
+    fn test_verify_public() {
+        check_verify(Mode::Public);
+    }
+
+    #[test]
+    fn test_verify_private() {
+        check_verify(Mode::Private);
+    }
+
+    #[test]
+    fn test_verify_aggregate() {
+        let rng = &mut TestRng::default();
+        let message = b"Attestation for block 0";
+        let message_hash = console::BlsSignature::hash_message(message);
+
+        let private_keys = (0..ITERATIONS).map(|_| Fr::rand(rng)).collect::<Vec<_>>();
+        let public_key = console::BlsSignature::public_key(&private_keys.iter().sum());
+
+        // Aggregate the private signatures, and bind the aggregate to a public input.
+        let signatures = private_keys
+            .iter()
+            .map(|key| BlsSignature::<Circuit>::new(Mode::Private, console::BlsSignature::sign(key, message)))
+            .collect::<Vec<_>>();
+        let aggregate = BlsSignature::aggregate(&signatures);
+        let public_aggregate = BlsSignature::<Circuit>::new(Mode::Public, aggregate.eject_value());
+        public_aggregate.signature().assert_equal(aggregate.signature());
+
+        let check = public_aggregate.verify(&public_key, &BlsG1::new(Mode::Constant, message_hash));
+        assert!(check.is_satisfied());
+        assert!(Circuit::is_satisfied());
+        Circuit::reset();
+    }
+}
@@ -18,6 +18,12 @@
 pub mod bhp;
 pub use bhp::*;
 
+pub mod blake2s;
+pub use blake2s::*;
+
+pub mod bls;
+pub use bls::*;
+
 pub mod elligator2;
 pub use elligator2::Elligator2;
 
@@ -30,5 +36,8 @@ pub use pedersen::*;
 pub mod poseidon;
 pub use poseidon::*;
 
+pub mod sha256;
+pub use sha256::*;
+
 pub mod traits;
 pub use traits::*;
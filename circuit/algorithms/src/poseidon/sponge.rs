@@ -0,0 +1,144 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+use super::*;
+
+/// A Poseidon duplex sponge, which absorbs and squeezes field elements across any number of calls,
+/// for building transcripts and commitments over variable-length inputs.
+///
+/// The capacity element is initialized to the domain separator of the Poseidon instance,
+/// which separates the sponge from `hash` and `hash_many` (where the capacity element is initialized to zero).
+#[derive(Clone)]
+pub struct PoseidonSponge<'a, E: Environment, const RATE: usize> {
+    /// The Poseidon instance, which defines the permutation.
+    poseidon: &'a Poseidon<E, RATE>,
+    /// The state of the sponge.
+    state: Vec<Field<E>>,
+    /// The mode of the sponge.
+    mode: DuplexSpongeMode,
+}
+
+impl<E: Environment, const RATE: usize> Poseidon<E, RATE> {
+    /// Returns a new sponge, initialized with the domain separator of this Poseidon instance.
+    pub fn sponge(&self) -> PoseidonSponge<'_, E, RATE> {
+        // Initialize the capacity element to the domain separator.
+        let mut state = vec![Field::zero(); RATE + CAPACITY];
+        state[0] = self.domain.clone();
+
+        PoseidonSponge { poseidon: self, state, mode: DuplexSpongeMode::Absorbing { next_absorb_index: 0 } }
+    }
+}
+
+impl<E: Environment, const RATE: usize> PoseidonSponge<'_, E, RATE> {
+    /// Absorbs the given field elements into the sponge.
+    pub fn absorb(&mut self, input: &[Field<E>]) {
+        self.poseidon.absorb(&mut self.state, &mut self.mode, input)
+    }
+
+    /// Squeezes the given number of field elements from the sponge.
+    pub fn squeeze(&mut self, num_outputs: u16) -> Vec<Field<E>> {
+        self.poseidon.squeeze(&mut self.state, &mut self.mode, num_outputs)
+    }
+}
+
+#[cfg(all(test, console))]
+mod tests {
+    use super::*;
+    use snarkvm_circuit_types::environment::Circuit;
+
+    use anyhow::Result;
+
+    const DOMAIN: &str = "PoseidonSponge0";
+    const ITERATIONS: usize = 10;
+    const RATE: usize = 4;
+
+    /// An operation on the sponge.
+    #[derive(Copy, Clone)]
+    enum Operation {
+        /// Absorbs the given number of elements.
+        Absorb(usize),
+        /// Squeezes the given number of elements.
+        Squeeze(u16),
+    }
+
+    /// The sequence of operations to check, which interleaves absorbs and squeezes of varying lengths.
+    const OPERATIONS: [Operation; 8] = [
+        Operation::Absorb(3),
+        Operation::Squeeze(1),
+        Operation::Absorb(0),
+        Operation::Absorb(6),
+        Operation::Squeeze(5),
+        Operation::Squeeze(0),
+        Operation::Absorb(1),
+        Operation::Squeeze(2),
+    ];
+
+    fn check_sponge(mode: Mode, rng: &mut TestRng) -> Result<()> {
+        let native = console::Poseidon::<<Circuit as Environment>::Network, RATE>::setup(DOMAIN)?;
+        let poseidon = Poseidon::<Circuit, RATE>::constant(native.clone());
+
+        for i in 0..ITERATIONS {
+            let mut native_sponge = native.sponge();
+
+            Circuit::scope(format!("PoseidonSponge {mode} {i}"), || {
+                let mut sponge = poseidon.sponge();
+
+                for operation in OPERATIONS {
+                    match operation {
+                        // Absorb the same random inputs into both sponges.
+                        Operation::Absorb(num_inputs) => {
+                            let native_input = (0..num_inputs)
+                                .map(|_| console::Field::<<Circuit as Environment>::Network>::rand(rng))
+                                .collect::<Vec<_>>();
+                            let input =
+                                native_input.iter().map(|v| Field::<Circuit>::new(mode, *v)).collect::<Vec<_>>();
+                            native_sponge.absorb(&native_input);
+                            sponge.absorb(&input);
+                        }
+                        // Ensure both sponges squeeze the same outputs.
+                        Operation::Squeeze(num_outputs) => {
+                            let expected = native_sponge.squeeze(num_outputs);
+                            let candidate = sponge.squeeze(num_outputs);
+                            assert_eq!(expected, candidate.eject_value());
+                        }
+                    }
+                }
+
+                // Ensure a constant sponge does not allocate any variables or constraints.
+                if mode.is_constant() {
+                    assert_eq!(0, Circuit::num_public_in_scope());
+                    assert_eq!(0, Circuit::num_private_in_scope());
+                    assert_eq!(0, Circuit::num_constraints_in_scope());
+                }
+            });
+            assert!(Circuit::is_satisfied());
+            Circuit::reset();
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_sponge_constant() -> Result<()> {
+        check_sponge(Mode::Constant, &mut TestRng::default())
+    }
+
+    #[test]
+    fn test_sponge_public() -> Result<()> {
+        check_sponge(Mode::Public, &mut TestRng::default())
+    }
+
+    #[test]
+    fn test_sponge_private() -> Result<()> {
+        check_sponge(Mode::Private, &mut TestRng::default())
+    }
+}
@@ -0,0 +1,340 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+impl<E: Environment> Hash for Sha256<E> {
+    type Input = Boolean<E>;
+    type Output = Vec<Boolean<E>>;
+
+    /// Returns the SHA-256 hash of the given input as bits.
+    #[inline]
+    fn hash(&self, input: &[Self::Input]) -> Self::Output {
+        // The state `H` is initialized to `H(0)`.
+        let mut state = self.initial_state.clone();
+
+        // Process each padded message block `M(i)`.
+        for block in Self::pad(input).chunks(BLOCK_SIZE_IN_BITS) {
+            state = self.compress(&state, block);
+        }
+
+        // Return the digest `H(N)_0 || … || H(N)_7`, where each word is written as big-endian bytes.
+        let mut output = Vec::with_capacity(256);
+        for word in &state {
+            Self::write_word_be(word, &mut output);
+        }
+        output
+    }
+}
+
+impl<E: Environment> Sha256<E> {
+    /// In SHA-256, `pad` is defined as `pad(M) = M || 0x80 || 0x00…0x00 || len(M)`,
+    /// where `M` is the input data, and `len(M)` is the bit length of `M` as a 64-bit big-endian integer.
+    /// The padding extends the input data to a multiple of the block size (512 bits).
+    ///
+    /// The padded message is returned as bytes, where each byte is in little-endian bit order.
+    fn pad(input: &[Boolean<E>]) -> Vec<Boolean<E>> {
+        // Resize the input to a multiple of 8.
+        let mut padded_input = input.to_vec();
+        padded_input.resize((input.len() + 7) / 8 * 8, Boolean::constant(false));
+
+        // Record the message length in bits.
+        let message_length = padded_input.len() as u64;
+
+        // Step 1: Append the "0x80" byte to the message.
+        Self::write_byte(0x80, &mut padded_input);
+
+        // Step 2: Append "0x00" bytes until the length of the message is congruent to 448 mod 512.
+        while padded_input.len() % BLOCK_SIZE_IN_BITS != BLOCK_SIZE_IN_BITS - 64 {
+            Self::write_byte(0x00, &mut padded_input);
+        }
+
+        // Step 3: Append the message length as a 64-bit big-endian integer.
+        for byte in message_length.to_be_bytes() {
+            Self::write_byte(byte, &mut padded_input);
+        }
+
+        debug_assert_eq!(padded_input.len() % BLOCK_SIZE_IN_BITS, 0, "The padded input must be a multiple of 512 bits");
+        padded_input
+    }
+
+    /// The compression function processes one 512-bit message block, and returns the updated state.
+    fn compress(&self, state: &[U32<E>], block: &[Boolean<E>]) -> Vec<U32<E>> {
+        debug_assert_eq!(state.len(), 8, "The state must have 8 words");
+        debug_assert_eq!(block.len(), BLOCK_SIZE_IN_BITS, "The block must have {BLOCK_SIZE_IN_BITS} bits");
+
+        /* The message schedule:
+         *
+         * for t = 0 to 15 do
+         *   W[t] = M(i)_t
+         * end for
+         * for t = 16 to 63 do
+         *   W[t] = σ1(W[t-2]) + W[t-7] + σ0(W[t-15]) + W[t-16]
+         * end for
+         */
+        let mut w = block.chunks(32).map(Self::read_word_be).collect::<Vec<_>>();
+        for t in 16..NUM_ROUNDS {
+            let sigma_1 = Self::small_sigma_1(&w[t - 2]);
+            let sigma_0 = Self::small_sigma_0(&w[t - 15]);
+            w.push(Self::add_chain(&[&sigma_1, &w[t - 7], &sigma_0, &w[t - 16]]));
+        }
+
+        /* The compression rounds:
+         *
+         * for t = 0 to 63 do
+         *   T1 = h + Σ1(e) + Ch(e, f, g) + K[t] + W[t]
+         *   T2 = Σ0(a) + Maj(a, b, c)
+         *   (a, b, c, d, e, f, g, h) = (T1 + T2, a, b, c, d + T1, e, f, g)
+         * end for
+         *
+         * Note: `T1 + T2` and `d + T1` are each computed as a single addition chain.
+         */
+        let [mut a, mut b, mut c, mut d, mut e, mut f, mut g, mut h] =
+            [0, 1, 2, 3, 4, 5, 6, 7].map(|i| state[i].clone());
+        for (k, w) in self.round_constants.iter().zip_eq(&w) {
+            let sigma_1 = Self::big_sigma_1(&e);
+            let choose = Self::choose(&e, &f, &g);
+            let sigma_0 = Self::big_sigma_0(&a);
+            let majority = Self::majority(&a, &b, &c);
+
+            let new_e = Self::add_chain(&[&d, &h, &sigma_1, &choose, k, w]);
+            let new_a = Self::add_chain(&[&h, &sigma_1, &choose, k, w, &sigma_0, &majority]);
+
+            h = g;
+            g = f;
+            f = e;
+            e = new_e;
+            d = c;
+            c = b;
+            b = a;
+            a = new_a;
+        }
+
+        // Compute the intermediate hash value `H(i) = H(i-1) + (a, b, c, d, e, f, g, h)`.
+        [a, b, c, d, e, f, g, h]
+            .iter()
+            .zip_eq(state)
+            .map(|(word, previous)| Self::add_chain(&[previous, word]))
+            .collect()
+    }
+
+    /// Returns the sum of the given words modulo `2^32`.
+    ///
+    /// The words are summed as a linear combination in the base field, and the lower 32 bits of
+    /// the sum are extracted in a single bit decomposition, instead of performing one wrapped
+    /// addition (and one bit decomposition) for each pair of words.
+    fn add_chain(words: &[&U32<E>]) -> U32<E> {
+        debug_assert!(!words.is_empty(), "The addition chain must have at least one word");
+
+        // Sum the words in the base field.
+        let sum = words.iter().map(|word| word.to_field()).fold(Field::zero(), |sum, word| sum + word);
+
+        // If the sum is a constant, return the lower 32 bits directly.
+        if sum.is_constant() {
+            let bits_le =
+                sum.eject_value().to_bits_le().into_iter().take(32).map(Boolean::constant).collect::<Vec<_>>();
+            return U32::from_bits_le(&bits_le);
+        }
+
+        // Compute the number of bits needed to represent the sum (i.e. 32 bits plus the carry bits).
+        // As each word is at most `2^32 - 1`, the sum is strictly less than `n * 2^32`.
+        let num_carry_bits = (usize::BITS - (words.len() - 1).leading_zeros()) as usize;
+
+        // Extract the lower 32 bits of the sum, while enforcing that the carry bits are well-formed.
+        let bits_le = sum.to_lower_bits_le(32 + num_carry_bits);
+        U32::from_bits_le(&bits_le[..32])
+    }
+
+    /// Returns `Σ0(x) = ROTR^2(x) ⊕ ROTR^13(x) ⊕ ROTR^22(x)`.
+    fn big_sigma_0(x: &U32<E>) -> U32<E> {
+        Self::rotate_right(x, 2) ^ Self::rotate_right(x, 13) ^ Self::rotate_right(x, 22)
+    }
+
+    /// Returns `Σ1(x) = ROTR^6(x) ⊕ ROTR^11(x) ⊕ ROTR^25(x)`.
+    fn big_sigma_1(x: &U32<E>) -> U32<E> {
+        Self::rotate_right(x, 6) ^ Self::rotate_right(x, 11) ^ Self::rotate_right(x, 25)
+    }
+
+    /// Returns `σ0(x) = ROTR^7(x) ⊕ ROTR^18(x) ⊕ SHR^3(x)`.
+    fn small_sigma_0(x: &U32<E>) -> U32<E> {
+        Self::rotate_right(x, 7) ^ Self::rotate_right(x, 18) ^ Self::shift_right(x, 3)
+    }
+
+    /// Returns `σ1(x) = ROTR^17(x) ⊕ ROTR^19(x) ⊕ SHR^10(x)`.
+    fn small_sigma_1(x: &U32<E>) -> U32<E> {
+        Self::rotate_right(x, 17) ^ Self::rotate_right(x, 19) ^ Self::shift_right(x, 10)
+    }
+
+    /// Returns `Ch(x, y, z) = (x ∧ y) ⊕ (¬x ∧ z)`.
+    ///
+    /// This is computed as a bitwise ternary, which costs one constraint per bit.
+    fn choose(x: &U32<E>, y: &U32<E>, z: &U32<E>) -> U32<E> {
+        let bits_le = x
+            .to_bits_le()
+            .iter()
+            .zip_eq(y.to_bits_le())
+            .zip_eq(z.to_bits_le())
+            .map(|((x, y), z)| Boolean::ternary(x, &y, &z))
+            .collect::<Vec<_>>();
+        U32::from_bits_le(&bits_le)
+    }
+
+    /// Returns `Maj(x, y, z) = (x ∧ y) ⊕ (x ∧ z) ⊕ (y ∧ z)`.
+    ///
+    /// This is computed as `(x ⊕ y) ? z : x`, which costs two constraints per bit.
+    fn majority(x: &U32<E>, y: &U32<E>, z: &U32<E>) -> U32<E> {
+        let bits_le = x
+            .to_bits_le()
+            .iter()
+            .zip_eq(y.to_bits_le())
+            .zip_eq(z.to_bits_le())
+            .map(|((x, y), z)| Boolean::ternary(&(x ^ &y), &z, x))
+            .collect::<Vec<_>>();
+        U32::from_bits_le(&bits_le)
+    }
+
+    /// Performs a rotate right operation on the given `u32` value.
+    fn rotate_right(value: &U32<E>, n: usize) -> U32<E> {
+        // Perform the rotation.
+        let mut bits_le = value.to_bits_le();
+        bits_le.rotate_left(n);
+        // Return the rotated value.
+        U32::from_bits_le(&bits_le)
+    }
+
+    /// Performs a shift right operation on the given `u32` value.
+    fn shift_right(value: &U32<E>, n: usize) -> U32<E> {
+        // Perform the shift.
+        let bits_le = value.to_bits_le().into_iter().skip(n).collect::<Vec<_>>();
+        // Return the shifted value, where the upper bits are zero.
+        U32::from_bits_le(&bits_le)
+    }
+
+    /// Reads a 32-bit word from the given big-endian bytes, where each byte is in little-endian bit order.
+    fn read_word_be(bytes: &[Boolean<E>]) -> U32<E> {
+        debug_assert_eq!(bytes.len(), 32, "The word must have 32 bits");
+        U32::from_bits_le(&bytes.chunks(8).rev().flatten().cloned().collect::<Vec<_>>())
+    }
+
+    /// Writes the given 32-bit word as big-endian bytes, where each byte is in little-endian bit order.
+    fn write_word_be(word: &U32<E>, output: &mut Vec<Boolean<E>>) {
+        output.extend(word.to_bits_le().chunks(8).rev().flatten().cloned());
+    }
+
+    /// Writes the given constant byte in little-endian bit order.
+    fn write_byte(byte: u8, output: &mut Vec<Boolean<E>>) {
+        output.extend((0..8).map(|i| Boolean::constant((byte >> i) & 1 == 1)));
+    }
+}
+
+#[cfg(all(test, console))]
+mod tests {
+    use super::*;
+    use console::Rng;
+    use snarkvm_circuit_types::environment::Circuit;
+
+    const ITERATIONS: usize = 3;
+
+    /// The maximum number of constraints (and private variables) per 512-bit block.
+    /// This bound is computed from the cost of each step of the compression function, without constant folding:
+    ///  - the message schedule costs at most `48 * (64 + 64 + 35) = 7824`,
+    ///  - the compression rounds cost at most `64 * (64 + 32 + 64 + 64 + 36 + 36) = 18944`,
+    ///  - the final additions cost at most `8 * 34 = 272`.
+    const MAX_CONSTRAINTS_PER_BLOCK: u64 = 7824 + 18944 + 272;
+
+    fn check_hash(mode: Mode, num_inputs: usize, rng: &mut TestRng) {
+        use console::Hash as H;
+
+        let native = console::Sha256;
+        let sha256 = Sha256::<Circuit>::new();
+
+        // Compute the number of padded blocks.
+        let num_blocks = ((num_inputs + 7) / 8 + 9 + 63) / 64;
+        let max_constraints = num_blocks as u64 * MAX_CONSTRAINTS_PER_BLOCK;
+
+        for i in 0..ITERATIONS {
+            // Prepare the preimage.
+            let native_input = (0..num_inputs).map(|_| Uniform::rand(rng)).collect::<Vec<bool>>();
+            let input = native_input.iter().map(|v| Boolean::<Circuit>::new(mode, *v)).collect::<Vec<_>>();
+
+            // Compute the native hash.
+            let expected = native.hash(&native_input).expect("Failed to hash native input");
+
+            // Compute the circuit hash.
+            Circuit::scope(format!("SHA-256 {mode} {i}"), || {
+                let candidate = sha256.hash(&input);
+                assert_eq!(expected, candidate.eject_value());
+                match mode {
+                    Mode::Constant => assert_scope!(0, 0, 0, 0),
+                    _ => assert_scope!(<=0, <=0, <=max_constraints, <=max_constraints),
+                }
+            });
+            Circuit::reset();
+        }
+    }
+
+    #[test]
+    fn test_sha256_hash_constant() {
+        let mut rng = TestRng::default();
+
+        for num_inputs in [1, 8, 255, 256, 440, 447, 448, 512, 1024] {
+            check_hash(Mode::Constant, num_inputs, &mut rng);
+        }
+    }
+
+    #[test]
+    fn test_sha256_hash_public() {
+        let mut rng = TestRng::default();
+
+        for num_inputs in [1, 8, 255, 256, 440, 447, 448, 512, 1024] {
+            check_hash(Mode::Public, num_inputs, &mut rng);
+        }
+    }
+
+    #[test]
+    fn test_sha256_hash_private() {
+        let mut rng = TestRng::default();
+
+        for num_inputs in [1, 8, 255, 256, 440, 447, 448, 512, 1024] {
+            check_hash(Mode::Private, num_inputs, &mut rng);
+        }
+    }
+
+    #[test]
+    fn test_sha256_equivalence() {
+        use console::Hash as H;
+
+        let rng = &mut TestRng::default();
+
+        let mut input_sizes = vec![0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 16, 32, 64, 128, 256, 512, 1024];
+        input_sizes.extend((0..5).map(|_| rng.gen_range(1..1024)));
+
+        for num_inputs in input_sizes {
+            println!("Checking equivalence for {num_inputs} inputs");
+
+            // Prepare the preimage.
+            let native_input = (0..num_inputs).map(|_| Uniform::rand(rng)).collect::<Vec<bool>>();
+            let input = native_input.iter().map(|v| Boolean::<Circuit>::new(Mode::Private, *v)).collect::<Vec<_>>();
+
+            // Compute the console hash.
+            let expected = console::Sha256.hash(&native_input).expect("Failed to hash console input");
+
+            // Compute the circuit hash.
+            let candidate = Sha256::<Circuit>::new().hash(&input);
+            assert_eq!(expected, candidate.eject_value());
+            assert!(Circuit::is_satisfied());
+            Circuit::reset();
+        }
+    }
+}
@@ -18,13 +18,18 @@ use core::{
     cell::{Cell, RefCell},
     fmt,
 };
+use std::rc::Rc;
 
 type Field = <console::MainnetV0 as console::Environment>::Field;
 
+/// The index of the first variable in evaluation mode.
+const EVALUATION_START_INDEX: u64 = 1 << 63;
+
 thread_local! {
     pub(super) static CONSTRAINT_LIMIT: Cell<Option<u64>> = Cell::new(None);
     pub(super) static CIRCUIT: RefCell<R1CS<Field>> = RefCell::new(R1CS::new());
     pub(super) static IN_WITNESS: Cell<bool> = Cell::new(false);
+    pub(super) static EVALUATION_INDEX: Cell<Option<u64>> = Cell::new(None);
     pub(super) static ZERO: LinearCombination<Field> = LinearCombination::zero();
     pub(super) static ONE: LinearCombination<Field> = LinearCombination::one();
 }
@@ -53,6 +58,16 @@ impl Environment for Circuit {
         IN_WITNESS.with(|in_witness| {
             // Ensure we are not in witness mode.
             if !in_witness.get() {
+                // In evaluation mode, return a variable that is not allocated in the circuit.
+                if let Some(index) = EVALUATION_INDEX.with(|evaluation_index| evaluation_index.get()) {
+                    EVALUATION_INDEX.with(|evaluation_index| evaluation_index.set(Some(index + 1)));
+                    return match mode {
+                        Mode::Constant => Variable::Constant(Rc::new(value)),
+                        Mode::Public => Variable::Public(Rc::new((index, value))),
+                        Mode::Private => Variable::Private(Rc::new((index, value))),
+                    };
+                }
+
                 CIRCUIT.with(|circuit| match mode {
                     Mode::Constant => circuit.borrow_mut().new_constant(value),
                     Mode::Public => circuit.borrow_mut().new_public(value),
@@ -112,6 +127,11 @@ impl Environment for Circuit {
         IN_WITNESS.with(|in_witness| {
             // Ensure we are not in witness mode.
             if !in_witness.get() {
+                // In evaluation mode, scopes are not recorded.
+                if EVALUATION_INDEX.with(|evaluation_index| evaluation_index.get()).is_some() {
+                    return logic();
+                }
+
                 CIRCUIT.with(|circuit| {
                     // Set the entire environment to the new scope.
                     let name = name.into();
@@ -146,6 +166,11 @@ impl Environment for Circuit {
         IN_WITNESS.with(|in_witness| {
             // Ensure we are not in witness mode.
             if !in_witness.get() {
+                // In evaluation mode, constraints are neither constructed nor recorded.
+                if EVALUATION_INDEX.with(|evaluation_index| evaluation_index.get()).is_some() {
+                    return;
+                }
+
                 CIRCUIT.with(|circuit| {
                     // Ensure that we do not surpass the constraint limit for the circuit.
                     CONSTRAINT_LIMIT.with(|constraint_limit| {
@@ -181,7 +206,9 @@ impl Environment for Circuit {
                             // Construct the constraint object.
                             let constraint = Constraint(circuit.borrow().scope(), a, b, c);
                             // Append the constraint.
-                            circuit.borrow_mut().enforce(constraint)
+                            if let Err(error) = circuit.borrow_mut().enforce(constraint) {
+                                Self::halt(error)
+                            }
                         }
                     }
                 });
@@ -196,6 +223,11 @@ impl Environment for Circuit {
         CIRCUIT.with(|circuit| circuit.borrow().is_satisfied())
     }
 
+    /// Returns up to `limit` unsatisfied constraints in the environment, with their scopes and evaluated values.
+    fn unsatisfied_constraints(limit: usize) -> Vec<UnsatisfiedConstraint<Self::BaseField>> {
+        CIRCUIT.with(|circuit| circuit.borrow().unsatisfied_constraints(limit))
+    }
+
     /// Returns `true` if all constraints in the current scope are satisfied.
     fn is_satisfied_in_scope() -> bool {
         CIRCUIT.with(|circuit| circuit.borrow().is_satisfied_in_scope())
@@ -251,6 +283,19 @@ impl Environment for Circuit {
         CIRCUIT.with(|circuit| circuit.borrow().num_nonzeros_in_scope())
     }
 
+    /// Returns the profile of the constraint counts in each scope of the circuit.
+    fn profile() -> ScopeTree {
+        CIRCUIT.with(|circuit| circuit.borrow().profile())
+    }
+
+    /// Returns a canonical hash of the constraint structure of the circuit, which is independent of the values of its variables.
+    fn digest() -> [u8; 32] {
+        CIRCUIT.with(|circuit| match circuit.borrow().digest() {
+            Ok(digest) => digest,
+            Err(error) => Self::halt(format!("Failed to compute the circuit digest: {error}")),
+        })
+    }
+
     /// Halts the program from further synthesis, evaluation, and execution in the current environment.
     fn halt<S: Into<String>, T>(message: S) -> T {
         let error = message.into();
@@ -268,6 +313,40 @@ impl Environment for Circuit {
         CONSTRAINT_LIMIT.with(|current_limit| current_limit.replace(limit));
     }
 
+    /// Returns `true` if common subexpression elimination is enabled for the circuit.
+    fn is_cse_enabled() -> bool {
+        CIRCUIT.with(|circuit| circuit.borrow().is_cse_enabled())
+    }
+
+    /// Enables or disables common subexpression elimination for the circuit.
+    fn set_cse_enabled(enabled: bool) {
+        CIRCUIT.with(|circuit| circuit.borrow_mut().set_cse_enabled(enabled))
+    }
+
+    /// Returns the output of `logic` for the given input, reusing the output of an earlier call with the same key
+    /// and an identical input, if common subexpression elimination is enabled.
+    fn cse<T, Fn>(key: SubexpressionKey, input: &LinearCombination<Self::BaseField>, logic: Fn) -> T
+    where
+        T: Clone + 'static,
+        Fn: FnOnce() -> T,
+    {
+        // Subexpressions are only reused when synthesizing constraints on a non-constant input.
+        let is_synthesizing = !IN_WITNESS.with(|in_witness| in_witness.get())
+            && EVALUATION_INDEX.with(|evaluation_index| evaluation_index.get()).is_none();
+        if !is_synthesizing || input.is_constant() || !Self::is_cse_enabled() {
+            return logic();
+        }
+
+        // Reuse the output, if it was recorded.
+        if let Some(output) = CIRCUIT.with(|circuit| circuit.borrow().get_subexpression(key, input)) {
+            return output;
+        }
+        // Otherwise, compute the output and record it.
+        let output = logic();
+        CIRCUIT.with(|circuit| circuit.borrow_mut().insert_subexpression(key, input, output.clone()));
+        output
+    }
+
     /// Returns the R1CS circuit, resetting the circuit.
     fn inject_r1cs(r1cs: R1CS<Self::BaseField>) {
         CIRCUIT.with(|circuit| {
@@ -338,6 +417,66 @@ impl Environment for Circuit {
             assert_eq!(0, circuit.borrow().num_constraints());
         });
     }
+
+    /// Runs the given logic in a new, empty environment, and returns its output with the R1CS it synthesized.
+    /// The environment of the caller, including its witness mode and constraint limit, is restored afterwards,
+    /// even if the logic panics.
+    fn with_new_instance<Fn, Output>(logic: Fn) -> (Output, R1CS<Self::BaseField>)
+    where
+        Fn: FnOnce() -> Output,
+    {
+        // Save the current environment, and initialize a new one.
+        let _guard = SavedInstance(Some((
+            CIRCUIT.with(|circuit| circuit.replace(R1CS::new())),
+            IN_WITNESS.with(|in_witness| in_witness.replace(false)),
+            CONSTRAINT_LIMIT.with(|constraint_limit| constraint_limit.replace(None)),
+            EVALUATION_INDEX.with(|evaluation_index| evaluation_index.replace(None)),
+        )));
+        // Synthesize the logic in the new environment.
+        let output = logic();
+        // Eject the R1CS instance, before the saved environment is restored.
+        let r1cs = CIRCUIT.with(|circuit| circuit.replace(R1CS::new()));
+        (output, r1cs)
+    }
+
+    /// Runs the given logic in evaluation mode, and returns its output.
+    /// In evaluation mode, variables are not allocated in the circuit, and constraints and scopes are skipped,
+    /// so the values of the circuit are computed at a fraction of the cost of synthesis.
+    /// Note that the constraints are not checked, so the outputs are only meaningful for valid inputs.
+    fn evaluate<Fn, Output>(logic: Fn) -> Output
+    where
+        Fn: FnOnce() -> Output,
+    {
+        // Note: The indices of the variables start past those of any synthesized variable, to keep them distinct.
+        let _guard = SavedEvaluationIndex(EVALUATION_INDEX.with(|evaluation_index| {
+            let index = evaluation_index.get().unwrap_or(EVALUATION_START_INDEX);
+            evaluation_index.replace(Some(index))
+        }));
+        logic()
+    }
+}
+
+/// The saved environment of a thread, which is restored when this is dropped.
+struct SavedInstance(Option<(R1CS<Field>, bool, Option<u64>, Option<u64>)>);
+
+impl Drop for SavedInstance {
+    fn drop(&mut self) {
+        if let Some((r1cs, in_witness, constraint_limit, evaluation_index)) = self.0.take() {
+            CIRCUIT.with(|circuit| *circuit.borrow_mut() = r1cs);
+            IN_WITNESS.with(|cell| cell.set(in_witness));
+            CONSTRAINT_LIMIT.with(|cell| cell.set(constraint_limit));
+            EVALUATION_INDEX.with(|cell| cell.set(evaluation_index));
+        }
+    }
+}
+
+/// The evaluation mode of a thread before `evaluate` was called, which is restored when this is dropped.
+struct SavedEvaluationIndex(Option<u64>);
+
+impl Drop for SavedEvaluationIndex {
+    fn drop(&mut self) {
+        EVALUATION_INDEX.with(|evaluation_index| evaluation_index.set(self.0));
+    }
 }
 
 impl fmt::Display for Circuit {
@@ -395,4 +534,184 @@ mod tests {
             assert_eq!(0, Circuit::num_constraints_in_scope());
         })
     }
+    #[test]
+    fn test_circuit_profile() {
+        Circuit::reset();
+
+        let one = snarkvm_console_types::Field::<<Circuit as Environment>::Network>::one();
+        Circuit::scope("outer", || {
+            let a = Field::<Circuit>::new(Mode::Private, one);
+            for _ in 0..2 {
+                Circuit::scope("inner", || {
+                    let _ = &a * &a;
+                });
+            }
+            let _ = &a * &a;
+
+            // Ensure the open scopes are included in the profile.
+            let profile = Circuit::profile();
+            assert_eq!((0, 0, 2, 1, (1, 1, 1)), profile.child("outer").unwrap().count());
+        });
+
+        let profile = Circuit::profile();
+        assert_eq!("circuit", profile.name);
+        assert_eq!((0, 0, 0, 0, (0, 0, 0)), profile.count());
+        assert_eq!((0, 0, 4, 3, (3, 3, 3)), profile.total_count());
+
+        let outer = profile.child("outer").unwrap();
+        assert_eq!(1, outer.num_calls);
+        assert_eq!((0, 0, 2, 1, (1, 1, 1)), outer.count());
+
+        let inner = outer.child("inner").unwrap();
+        assert_eq!(2, inner.num_calls);
+        assert_eq!((0, 0, 2, 2, (2, 2, 2)), inner.count());
+
+        assert_eq!("circuit;outer 1\ncircuit;outer;inner 2\n", profile.to_collapsed_stacks());
+
+        Circuit::reset();
+        assert_eq!(0, Circuit::profile().children.len());
+    }
+    #[test]
+    fn test_circuit_unsatisfied_constraints() {
+        Circuit::reset();
+
+        let one = snarkvm_console_types::Field::<<Circuit as Environment>::Network>::one();
+        let two = one + one;
+        Circuit::scope("outer", || {
+            let a = Field::<Circuit>::new(Mode::Private, one);
+            let b = Field::<Circuit>::new(Mode::Private, two);
+            Circuit::enforce(|| (&a, &a, &a));
+            Circuit::scope("inner", || {
+                Circuit::enforce(|| (&a, &a, &b));
+                Circuit::enforce(|| (&b, &b, &a));
+            });
+        });
+        assert!(!Circuit::is_satisfied());
+
+        let unsatisfied = Circuit::unsatisfied_constraints(usize::MAX);
+        assert_eq!(2, unsatisfied.len());
+        assert_eq!(1, unsatisfied[0].index);
+        assert_eq!("outer.inner", unsatisfied[0].scope);
+        assert_eq!(*one, unsatisfied[0].lhs());
+        assert_eq!(*two, unsatisfied[0].rhs());
+        assert_eq!(2, unsatisfied[1].index);
+        assert_eq!(*(two * two), unsatisfied[1].lhs());
+        assert_eq!(*one, unsatisfied[1].rhs());
+
+        // Ensure the number of unsatisfied constraints is limited.
+        assert_eq!(unsatisfied[..1], Circuit::unsatisfied_constraints(1));
+
+        Circuit::reset();
+        assert!(Circuit::unsatisfied_constraints(usize::MAX).is_empty());
+    }
+    #[test]
+    fn test_circuit_with_new_instance() {
+        Circuit::reset();
+
+        let _candidate = create_example_circuit::<Circuit>();
+        let (num_constraints, r1cs) = Circuit::with_new_instance(|| {
+            // Ensure the new environment is empty.
+            assert_eq!(0, Circuit::num_private());
+            assert_eq!(0, Circuit::num_constraints());
+            let one = snarkvm_console_types::Field::<<Circuit as Environment>::Network>::one();
+            let (_, nested) = Circuit::with_new_instance(|| Field::<Circuit>::new(Mode::Private, one));
+            assert_eq!(1, nested.num_private());
+            let _candidate = create_example_circuit::<Circuit>();
+            Circuit::num_constraints()
+        });
+        assert_eq!(64, num_constraints);
+        assert_eq!(64, r1cs.num_constraints());
+
+        // Ensure the environment of the caller is restored.
+        assert_eq!(2 * 64 + 1, Circuit::num_private());
+        assert_eq!(64, Circuit::num_constraints());
+        assert!(Circuit::is_satisfied());
+
+        // Ensure the environment of the caller is restored after a panic.
+        let result = std::panic::catch_unwind(|| Circuit::with_new_instance(|| Circuit::halt::<_, ()>("halt")));
+        assert!(result.is_err());
+        assert_eq!(64, Circuit::num_constraints());
+
+        Circuit::reset();
+    }
+
+    #[test]
+    fn test_circuit_with_new_instance_concurrently() {
+        let num_constraints = std::thread::scope(|scope| {
+            let handles = (0..4)
+                .map(|_| {
+                    scope.spawn(|| {
+                        let ((), r1cs) = Circuit::with_new_instance(|| {
+                            let _candidate = create_example_circuit::<Circuit>();
+                        });
+                        r1cs.num_constraints()
+                    })
+                })
+                .collect::<Vec<_>>();
+            handles.into_iter().map(|handle| handle.join().unwrap()).collect::<Vec<_>>()
+        });
+        assert_eq!(vec![64; 4], num_constraints);
+    }
+
+    #[test]
+    fn test_circuit_evaluate() {
+        Circuit::reset();
+
+        let one = snarkvm_console_types::Field::<<Circuit as Environment>::Network>::one();
+        let two = one + one;
+
+        let candidate = Circuit::evaluate(|| {
+            let mut candidate = Field::<Circuit>::new(Mode::Public, one);
+            let mut accumulator = Field::new(Mode::Private, two);
+            for _ in 0..64 {
+                candidate += &accumulator;
+                accumulator *= Field::new(Mode::Private, two);
+            }
+            candidate.eject_value()
+        });
+
+        // Ensure no variables or constraints were allocated.
+        assert_eq!(0, Circuit::num_constants());
+        assert_eq!(1, Circuit::num_public());
+        assert_eq!(0, Circuit::num_private());
+        assert_eq!(0, Circuit::num_constraints());
+
+        // Ensure the evaluated value matches the synthesized value.
+        assert_eq!(create_example_circuit::<Circuit>().eject_value(), candidate);
+
+        Circuit::reset();
+    }
+
+    #[test]
+    fn test_circuit_cse() {
+        Circuit::reset();
+        Circuit::set_cse_enabled(true);
+
+        let one = snarkvm_console_types::Field::<<Circuit as Environment>::Network>::one();
+        let a = Field::<Circuit>::new(Mode::Private, one);
+
+        // Ensure identical constraints are only enforced once.
+        Circuit::enforce(|| (&a, &a, &a));
+        Circuit::enforce(|| (&a, &a, &a));
+        assert_eq!(1, Circuit::num_constraints());
+
+        // Ensure the bits of an identical field element are reused.
+        let bits = a.to_bits_le();
+        let (num_private, num_constraints) = (Circuit::num_private(), Circuit::num_constraints());
+        let candidate = (&a + Field::zero()).to_bits_le();
+        assert_eq!(bits.eject_value(), candidate.eject_value());
+        assert_eq!(num_private, Circuit::num_private());
+        assert_eq!(num_constraints, Circuit::num_constraints());
+        assert!(Circuit::is_satisfied());
+
+        // Ensure resetting the circuit disables common subexpression elimination.
+        Circuit::reset();
+        assert!(!Circuit::is_cse_enabled());
+        let a = Field::<Circuit>::new(Mode::Private, one);
+        Circuit::enforce(|| (&a, &a, &a));
+        Circuit::enforce(|| (&a, &a, &a));
+        assert_eq!(2, Circuit::num_constraints());
+
+        Circuit::reset();
+    }
 }
@@ -12,7 +12,18 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
-use crate::{witness_mode, Assignment, Inject, LinearCombination, Mode, Variable, R1CS};
+use crate::{
+    witness_mode,
+    Assignment,
+    Inject,
+    LinearCombination,
+    Mode,
+    ScopeTree,
+    SubexpressionKey,
+    UnsatisfiedConstraint,
+    Variable,
+    R1CS,
+};
 use snarkvm_curves::AffineCurve;
 use snarkvm_fields::traits::*;
 
@@ -107,6 +118,9 @@ pub trait Environment: 'static + Copy + Clone + fmt::Debug + fmt::Display + Eq +
     /// Returns `true` if all constraints in the environment are satisfied.
     fn is_satisfied() -> bool;
 
+    /// Returns up to `limit` unsatisfied constraints in the environment, with their scopes and evaluated values.
+    fn unsatisfied_constraints(limit: usize) -> Vec<UnsatisfiedConstraint<Self::BaseField>>;
+
     /// Returns `true` if all constraints in the current scope are satisfied.
     fn is_satisfied_in_scope() -> bool;
 
@@ -156,6 +170,12 @@ pub trait Environment: 'static + Copy + Clone + fmt::Debug + fmt::Display + Eq +
         )
     }
 
+    /// Returns the profile of the constraint counts in each scope of the circuit.
+    fn profile() -> ScopeTree;
+
+    /// Returns a canonical hash of the constraint structure of the circuit, which is independent of the values of its variables.
+    fn digest() -> [u8; 32];
+
     /// Halts the program from further synthesis, evaluation, and execution in the current environment.
     fn halt<S: Into<String>, T>(message: S) -> T {
         <Self::Network as console::Environment>::halt(message)
@@ -167,6 +187,22 @@ pub trait Environment: 'static + Copy + Clone + fmt::Debug + fmt::Display + Eq +
     /// Sets the constraint limit for the circuit.
     fn set_constraint_limit(limit: Option<u64>);
 
+    /// Returns `true` if common subexpression elimination is enabled for the circuit.
+    fn is_cse_enabled() -> bool;
+
+    /// Enables or disables common subexpression elimination for the circuit. When enabled, identical constraints
+    /// are only enforced once, and the outputs of `cse` are reused for identical inputs. Like the constraint limit,
+    /// it is disabled when the circuit is reset or ejected.
+    fn set_cse_enabled(enabled: bool);
+
+    /// Returns the output of `logic` for the given input, reusing the output of an earlier call with the same key
+    /// and an identical input, if common subexpression elimination is enabled.
+    /// Note: As a reused output is not recomputed, `logic` must constrain its output entirely in terms of the input.
+    fn cse<T, Fn>(key: SubexpressionKey, input: &LinearCombination<Self::BaseField>, logic: Fn) -> T
+    where
+        T: Clone + 'static,
+        Fn: FnOnce() -> T;
+
     /// Returns the R1CS circuit, resetting the circuit.
     fn inject_r1cs(r1cs: R1CS<Self::BaseField>);
 
@@ -178,4 +214,18 @@ pub trait Environment: 'static + Copy + Clone + fmt::Debug + fmt::Display + Eq +
 
     /// Clears and initializes an empty environment.
     fn reset();
+
+    /// Runs the given logic in a new, empty environment, and returns its output with the R1CS it synthesized.
+    /// The environment of the caller is restored afterwards, which allows circuits to be synthesized in nested
+    /// calls on the same thread, such as in tasks stolen by a thread pool.
+    fn with_new_instance<Fn, Output>(logic: Fn) -> (Output, R1CS<Self::BaseField>)
+    where
+        Fn: FnOnce() -> Output;
+
+    /// Runs the given logic in evaluation mode, and returns its output. In evaluation mode, variables are not
+    /// allocated in the circuit, and constraints are skipped, which computes the values of a circuit without
+    /// the cost of synthesis, such as to preview the outputs of a function.
+    fn evaluate<Fn, Output>(logic: Fn) -> Output
+    where
+        Fn: FnOnce() -> Output;
 }
@@ -12,8 +12,20 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
-use crate::Index;
+#[cfg(feature = "spill")]
+use crate::ConstraintSpill;
+use crate::{helpers::CircuitDigest, Index};
+use snarkvm_algorithms::{
+    interop::{write_circom_witness, CircomR1CS},
+    r1cs::{ConstraintMatrices, SynthesisError},
+};
 use snarkvm_fields::PrimeField;
+use snarkvm_utilities::{
+    error,
+    io::{Read, Result as IoResult, Write},
+    FromBytes,
+    ToBytes,
+};
 
 use indexmap::IndexMap;
 use std::sync::Arc;
@@ -42,6 +54,38 @@ impl<F: PrimeField> From<&crate::Variable<F>> for AssignmentVariable<F> {
     }
 }
 
+impl<F: PrimeField> ToBytes for AssignmentVariable<F> {
+    /// Writes the assignment variable to the writer.
+    fn write_le<W: Write>(&self, mut writer: W) -> IoResult<()> {
+        match self {
+            Self::Constant(value) => {
+                0u8.write_le(&mut writer)?;
+                value.write_le(&mut writer)
+            }
+            Self::Public(index) => {
+                1u8.write_le(&mut writer)?;
+                index.write_le(&mut writer)
+            }
+            Self::Private(index) => {
+                2u8.write_le(&mut writer)?;
+                index.write_le(&mut writer)
+            }
+        }
+    }
+}
+
+impl<F: PrimeField> FromBytes for AssignmentVariable<F> {
+    /// Reads the assignment variable from the reader.
+    fn read_le<R: Read>(mut reader: R) -> IoResult<Self> {
+        match u8::read_le(&mut reader)? {
+            0 => Ok(Self::Constant(F::read_le(&mut reader)?)),
+            1 => Ok(Self::Public(Index::read_le(&mut reader)?)),
+            2 => Ok(Self::Private(Index::read_le(&mut reader)?)),
+            _ => Err(error("Invalid assignment variable")),
+        }
+    }
+}
+
 #[derive(Clone, Debug)]
 pub struct AssignmentLC<F: PrimeField> {
     constant: F,
@@ -60,6 +104,33 @@ impl<F: PrimeField> From<&crate::LinearCombination<F>> for AssignmentLC<F> {
     }
 }
 
+impl<F: PrimeField> ToBytes for AssignmentLC<F> {
+    /// Writes the assignment linear combination to the writer.
+    fn write_le<W: Write>(&self, mut writer: W) -> IoResult<()> {
+        self.constant.write_le(&mut writer)?;
+        u32::try_from(self.terms.len())
+            .map_err(|_| error("Too many terms in the linear combination"))?
+            .write_le(&mut writer)?;
+        for (variable, coefficient) in &self.terms {
+            variable.write_le(&mut writer)?;
+            coefficient.write_le(&mut writer)?;
+        }
+        Ok(())
+    }
+}
+
+impl<F: PrimeField> FromBytes for AssignmentLC<F> {
+    /// Reads the assignment linear combination from the reader.
+    fn read_le<R: Read>(mut reader: R) -> IoResult<Self> {
+        let constant = F::read_le(&mut reader)?;
+        let num_terms = u32::read_le(&mut reader)?;
+        let terms = (0..num_terms)
+            .map(|_| Ok((AssignmentVariable::read_le(&mut reader)?, F::read_le(&mut reader)?)))
+            .collect::<IoResult<_>>()?;
+        Ok(Self { constant, terms })
+    }
+}
+
 impl<F: PrimeField> AssignmentLC<F> {
     /// Returns the constant term of the linear combination.
     pub const fn constant(&self) -> F {
@@ -71,6 +142,34 @@ impl<F: PrimeField> AssignmentLC<F> {
         &self.terms
     }
 
+    /// Returns the linear combination over the variables of a `snarkvm_algorithms::r1cs` constraint system,
+    /// in which the variables are allocated in the same order.
+    #[cfg(feature = "spill")]
+    pub(super) fn to_linear_combination(&self) -> snarkvm_algorithms::r1cs::LinearCombination<F> {
+        use snarkvm_algorithms::r1cs::{Index as CsIndex, Variable as CsVariable};
+
+        let mut linear_combination = snarkvm_algorithms::r1cs::LinearCombination::<F>::zero();
+        for (variable, coefficient) in self.terms.iter() {
+            match variable {
+                AssignmentVariable::Constant(_) => {
+                    unreachable!(
+                        "Failed during constraint translation. The first system by definition cannot have constant variables in the terms"
+                    )
+                }
+                AssignmentVariable::Public(index) => {
+                    linear_combination += (*coefficient, CsVariable::new_unchecked(CsIndex::Public(*index as usize)))
+                }
+                AssignmentVariable::Private(index) => {
+                    linear_combination += (*coefficient, CsVariable::new_unchecked(CsIndex::Private(*index as usize)))
+                }
+            }
+        }
+        if !self.constant.is_zero() {
+            linear_combination += (self.constant, CsVariable::new_unchecked(CsIndex::Public(0)));
+        }
+        linear_combination
+    }
+
     /// Returns the number of nonzeros in the linear combination.
     pub(super) fn num_nonzeros(&self) -> u64 {
         // Increment by one if the constant is nonzero.
@@ -88,11 +187,15 @@ pub struct Assignment<F: PrimeField> {
     public: Arc<[(Index, F)]>,
     private: Arc<[(Index, F)]>,
     constraints: Arc<[(AssignmentLC<F>, AssignmentLC<F>, AssignmentLC<F>)]>,
+    #[cfg(feature = "spill")]
+    spill: Option<Arc<ConstraintSpill<F>>>,
 }
 
 impl<F: PrimeField> From<crate::R1CS<F>> for Assignment<F> {
     /// Converts an R1CS to an assignment.
     fn from(r1cs: crate::R1CS<F>) -> Self {
+        #[cfg(feature = "spill")]
+        let mut r1cs = r1cs;
         Self {
             public: FromIterator::from_iter(
                 r1cs.to_public_variables().iter().map(|variable| (variable.index(), variable.value())),
@@ -104,6 +207,8 @@ impl<F: PrimeField> From<crate::R1CS<F>> for Assignment<F> {
                 let (a, b, c) = constraint.to_terms();
                 (a.into(), b.into(), c.into())
             })),
+            #[cfg(feature = "spill")]
+            spill: r1cs.take_spilled_constraints().map(Arc::new),
         }
     }
 }
@@ -120,6 +225,7 @@ impl<F: PrimeField> Assignment<F> {
     }
 
     /// Returns the constraints of the assignment.
+    /// With the `spill` feature, this excludes the constraints that were spilled to disk.
     pub const fn constraints(&self) -> &Arc<[(AssignmentLC<F>, AssignmentLC<F>, AssignmentLC<F>)]> {
         &self.constraints
     }
@@ -136,16 +242,74 @@ impl<F: PrimeField> Assignment<F> {
 
     /// Returns the number of constraints in the assignment.
     pub fn num_constraints(&self) -> u64 {
+        #[cfg(feature = "spill")]
+        if let Some(spill) = &self.spill {
+            return self.constraints.len() as u64 + spill.num_constraints();
+        }
         self.constraints.len() as u64
     }
 
+    /// Returns the constraints that were spilled to disk, which follow the constraints held in memory.
+    #[cfg(feature = "spill")]
+    pub fn spilled_constraints(&self) -> Option<&Arc<ConstraintSpill<F>>> {
+        self.spill.as_ref()
+    }
+
     /// Returns the number of nonzeros in the assignment.
     pub fn num_nonzeros(&self) -> (u64, u64, u64) {
         self.constraints
             .iter()
             .map(|(a, b, c)| (a.num_nonzeros(), b.num_nonzeros(), c.num_nonzeros()))
+            .chain(self.spilled_nonzeros())
             .fold((0, 0, 0), |(a, b, c), (x, y, z)| (a.saturating_add(x), b.saturating_add(y), c.saturating_add(z)))
     }
+
+    /// Returns the number of nonzeros in the constraints that were spilled to disk, if any.
+    fn spilled_nonzeros(&self) -> Option<(u64, u64, u64)> {
+        #[cfg(feature = "spill")]
+        return self.spill.as_ref().map(|spill| spill.num_nonzeros());
+        #[cfg(not(feature = "spill"))]
+        None
+    }
+
+    /// Returns a canonical hash of the constraint structure of the assignment, which is independent of the
+    /// values of its variables. This is equal to the digest of the circuit environment that the assignment
+    /// was ejected from.
+    pub fn digest(&self) -> Result<[u8; 32], SynthesisError> {
+        let mut digest = CircuitDigest::new(self.num_public(), self.num_private(), self.num_constraints())?;
+        for (a, b, c) in self.constraints.iter() {
+            digest.update(a, b, c)?;
+        }
+        #[cfg(feature = "spill")]
+        if let Some(spill) = &self.spill {
+            for constraint in spill.iter()? {
+                let (a, b, c) = constraint?;
+                digest.update(&a, &b, &c)?;
+            }
+        }
+        Ok(digest.finalize())
+    }
+
+    /// Returns the constraints as matrices over the columns `[1, public variables..., private variables...]`,
+    /// along with the value of every column. With the `arkworks` feature of `snarkvm-algorithms`,
+    /// `interop::matrices_to_arkworks` converts the matrices into the arkworks format.
+    pub fn to_matrices(&self) -> Result<(ConstraintMatrices<F>, Vec<F>), SynthesisError> {
+        ConstraintMatrices::from_circuit_with_assignment(self)
+    }
+
+    /// Returns the constraints in the format of a circom `.r1cs` file.
+    /// The public variables are written as public inputs, and the private variables as internal wires.
+    pub fn to_circom_r1cs(&self) -> Result<Vec<u8>, SynthesisError> {
+        let (matrices, _) = self.to_matrices()?;
+        Ok(CircomR1CS::from_matrices(matrices)?.to_bytes()?)
+    }
+
+    /// Returns the value of every variable in the format of a circom `.wtns` file,
+    /// in the order of the wires of `Self::to_circom_r1cs`.
+    pub fn to_circom_witness(&self) -> Result<Vec<u8>, SynthesisError> {
+        let (_, witness) = self.to_matrices()?;
+        Ok(write_circom_witness(&witness)?)
+    }
 }
 
 impl<F: PrimeField> snarkvm_algorithms::r1cs::ConstraintSynthesizer<F> for Assignment<F> {
@@ -261,6 +425,21 @@ impl<F: PrimeField> snarkvm_algorithms::r1cs::ConstraintSynthesizer<F> for Assig
             );
         }
 
+        // Enforce the constraints that were spilled to disk, one at a time.
+        #[cfg(feature = "spill")]
+        if let Some(spill) = &self.spill {
+            let offset = self.constraints.len();
+            for (i, constraint) in spill.iter()?.enumerate() {
+                let (a, b, c) = constraint?;
+                cs.enforce(
+                    || format!("Constraint {}", offset + i),
+                    |lc| lc + a.to_linear_combination(),
+                    |lc| lc + b.to_linear_combination(),
+                    |lc| lc + c.to_linear_combination(),
+                );
+            }
+        }
+
         // Ensure the given `cs` matches in size with the first system.
         assert_eq!(self.num_public(), cs.num_public_variables() as u64);
         assert_eq!(self.num_private(), cs.num_private_variables() as u64);
@@ -320,6 +499,46 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_export_circom() {
+        use snarkvm_algorithms::interop::{read_circom_witness, CircomR1CS};
+
+        let _candidate_output = create_example_circuit::<Circuit>();
+        let assignment = Circuit::eject_assignment_and_reset();
+
+        let r1cs = CircomR1CS::<Fr>::from_bytes(&assignment.to_circom_r1cs().unwrap()).unwrap();
+        let witness = read_circom_witness::<Fr>(&assignment.to_circom_witness().unwrap()).unwrap();
+        assert_eq!(assignment.num_public(), 1 + r1cs.num_public_inputs as u64);
+        assert_eq!(assignment.num_public() + assignment.num_private(), r1cs.num_wires() as u64);
+        assert_eq!(assignment.num_constraints(), r1cs.matrices.num_constraints() as u64);
+
+        // The exported constraints and witness are the synthesized ones.
+        let (matrices, values) = assignment.to_matrices().unwrap();
+        assert_eq!(r1cs.matrices, matrices);
+        assert_eq!(witness, values);
+        assert!(r1cs.matrices.is_satisfied(&witness));
+    }
+
+    #[test]
+    fn test_digest() {
+        // Squares the given value `num_squares` times, returning the digest of the circuit and of its assignment.
+        let synthesize = |value: u64, num_squares: usize| {
+            let mut candidate = Field::<Circuit>::new(Mode::Private, snarkvm_console_types::Field::from_u64(value));
+            for _ in 0..num_squares {
+                candidate = &candidate * &candidate;
+            }
+            let digest = Circuit::digest();
+            let assignment = Circuit::eject_assignment_and_reset();
+            assert_eq!(digest, assignment.digest().unwrap());
+            digest
+        };
+
+        // Ensure the digest is independent of the values of the variables.
+        assert_eq!(synthesize(2, 8), synthesize(3, 8));
+        // Ensure the digest depends on the constraints.
+        assert_ne!(synthesize(2, 8), synthesize(2, 9));
+    }
+
     #[test]
     fn test_varuna() {
         let _candidate_output = create_example_circuit::<Circuit>();
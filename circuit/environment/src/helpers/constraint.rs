@@ -46,6 +46,19 @@ impl<F: PrimeField> Constraint<F> {
         }
     }
 
+    /// Returns the evaluated constraint, if it is unsatisfied, given its index in the constraint system.
+    pub(crate) fn to_unsatisfied(&self, index: usize) -> Option<UnsatisfiedConstraint<F>> {
+        let (scope, a, b, c) = (&self.0, &self.1, &self.2, &self.3);
+        let a = a.value();
+        let b = b.value();
+        let c = c.value();
+
+        match a * b == c {
+            true => None,
+            false => Some(UnsatisfiedConstraint { index, scope: scope.clone(), a, b, c }),
+        }
+    }
+
     /// Returns a reference to the terms `(a, b, c)`.
     pub fn to_terms(&self) -> (&LinearCombination<F>, &LinearCombination<F>, &LinearCombination<F>) {
         (&self.1, &self.2, &self.3)
@@ -65,3 +78,37 @@ impl<F: PrimeField> Display for Constraint<F> {
         }
     }
 }
+
+/// A constraint `a * b == c` that is not satisfied by the current assignment.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct UnsatisfiedConstraint<F: PrimeField> {
+    /// The index of the constraint in the constraint system.
+    pub index: usize,
+    /// The scope in which the constraint was enforced.
+    pub scope: Scope,
+    /// The value of the linear combination `a`.
+    pub a: F,
+    /// The value of the linear combination `b`.
+    pub b: F,
+    /// The value of the linear combination `c`.
+    pub c: F,
+}
+
+impl<F: PrimeField> UnsatisfiedConstraint<F> {
+    /// Returns the value of the left-hand side, `a * b`.
+    pub fn lhs(&self) -> F {
+        self.a * self.b
+    }
+
+    /// Returns the value of the right-hand side, `c`.
+    pub fn rhs(&self) -> F {
+        self.c
+    }
+}
+
+impl<F: PrimeField> Display for UnsatisfiedConstraint<F> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let (index, scope, a, b, c) = (self.index, &self.scope, self.a, self.b, self.c);
+        write!(f, "Constraint {index} at {scope}:\n\t({a} * {b}) != {c}\n")
+    }
+}
@@ -179,6 +179,21 @@ impl<F: PrimeField> R1CS<F> {
             );
         }
 
+        // Enforce the constraints that were spilled to disk, one at a time.
+        #[cfg(feature = "spill")]
+        if let Some(spill) = self.to_spilled_constraints() {
+            let offset = self.to_constraints().len();
+            for (i, constraint) in spill.iter()?.enumerate() {
+                let (a, b, c) = constraint?;
+                cs.enforce(
+                    || format!("Constraint {}", offset + i),
+                    |lc| lc + a.to_linear_combination(),
+                    |lc| lc + b.to_linear_combination(),
+                    |lc| lc + c.to_linear_combination(),
+                );
+            }
+        }
+
         // Ensure the given `cs` matches in size with the first system.
         assert_eq!(self.num_public(), cs.num_public_variables() as u64);
         assert_eq!(self.num_private(), cs.num_private_variables() as u64);
@@ -15,12 +15,14 @@
 use core::{
     fmt::{Debug, Display, Formatter},
     ops::{Add, Mul, Sub},
+    str::FromStr,
 };
 
 pub type Constant = Measurement<u64>;
 pub type Public = Measurement<u64>;
 pub type Private = Measurement<u64>;
 pub type Constraints = Measurement<u64>;
+pub type NonZeros = Measurement<u64>;
 
 /// A helper struct for tracking the number of constants, public inputs, private inputs, and constraints.
 #[derive(Copy, Clone, Debug)]
@@ -94,6 +96,47 @@ impl Display for Count {
     }
 }
 
+/// The budget of an entire synthesized circuit, such as the circuit of an Aleo function.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct Budget(pub Constraints, pub Public, pub Private, pub NonZeros);
+
+impl Budget {
+    /// Returns a new `Budget` whose constituent metrics are all `Exact`.
+    pub const fn is(num_constraints: u64, num_public: u64, num_private: u64, num_nonzeros: u64) -> Self {
+        Budget(
+            Measurement::Exact(num_constraints),
+            Measurement::Exact(num_public),
+            Measurement::Exact(num_private),
+            Measurement::Exact(num_nonzeros),
+        )
+    }
+
+    /// Returns a new `Budget` whose constituent metrics are all inclusive `UpperBound`.
+    pub const fn less_than(num_constraints: u64, num_public: u64, num_private: u64, num_nonzeros: u64) -> Self {
+        Budget(
+            Measurement::UpperBound(num_constraints),
+            Measurement::UpperBound(num_public),
+            Measurement::UpperBound(num_private),
+            Measurement::UpperBound(num_nonzeros),
+        )
+    }
+
+    /// Returns `true` if all constituent metrics match.
+    /// Note: `num_nonzeros` is the total number of non-zero entries in the `A`, `B`, and `C` matrices.
+    pub fn matches(&self, num_constraints: u64, num_public: u64, num_private: u64, num_nonzeros: u64) -> bool {
+        self.0.matches(num_constraints)
+            && self.1.matches(num_public)
+            && self.2.matches(num_private)
+            && self.3.matches(num_nonzeros)
+    }
+}
+
+impl Display for Budget {
+    fn fmt(&self, f: &mut Formatter) -> core::fmt::Result {
+        write!(f, "Constraints: {}, Public: {}, Private: {}, NonZeros: {}", self.0, self.1, self.2, self.3)
+    }
+}
+
 /// A `Measurement` is a quantity that can be measured.
 /// The variants of the `Measurement` defines a condition associated with the measurable quantity.
 #[derive(Copy, Clone, Debug, PartialEq, Eq)]
@@ -183,6 +226,24 @@ impl<V: Copy + Debug + Display + Ord + Add<Output = V> + Sub<Output = V> + Mul<O
     }
 }
 
+impl FromStr for Measurement<u64> {
+    type Err = core::num::ParseIntError;
+
+    /// Parses a `Measurement` from its string representation, i.e. `5`, `[3, 5]`, or `<=5`.
+    fn from_str(string: &str) -> Result<Self, Self::Err> {
+        let string = string.trim();
+        if let Some(bound) = string.strip_prefix("<=") {
+            Ok(Measurement::UpperBound(bound.trim().parse()?))
+        } else if let Some((lower, upper)) =
+            string.strip_prefix('[').and_then(|string| string.strip_suffix(']')).and_then(|range| range.split_once(','))
+        {
+            Ok(Measurement::Range(lower.trim().parse()?, upper.trim().parse()?))
+        } else {
+            Ok(Measurement::Exact(string.parse()?))
+        }
+    }
+}
+
 #[cfg(test)]
 mod test {
     use super::*;
@@ -256,6 +317,37 @@ mod test {
         }
     }
 
+    #[test]
+    fn test_measurement_from_str() {
+        let mut rng = TestRng::default();
+
+        for _ in 0..ITERATIONS {
+            let first = u32::rand(&mut rng) as u64;
+            let second = u32::rand(&mut rng) as u64;
+
+            // Check that each variant round-trips through its string representation.
+            for metric in [Measurement::Exact(first), Measurement::Range(first, second), Measurement::UpperBound(first)]
+            {
+                assert_eq!(metric, Measurement::from_str(&metric.to_string()).unwrap());
+            }
+        }
+        assert!(Measurement::from_str("[1, 2").is_err());
+        assert!(Measurement::from_str("<=-1").is_err());
+    }
+
+    #[test]
+    fn test_budget_matches() {
+        let budget = Budget::less_than(10, 2, 20, 30);
+        assert!(budget.matches(10, 2, 20, 30));
+        assert!(budget.matches(0, 0, 0, 0));
+        assert!(!budget.matches(11, 2, 20, 30));
+        assert!(!budget.matches(10, 2, 20, 31));
+
+        let budget = Budget::is(10, 2, 20, 30);
+        assert!(budget.matches(10, 2, 20, 30));
+        assert!(!budget.matches(9, 2, 20, 30));
+    }
+
     // Test addition.
 
     #[test]
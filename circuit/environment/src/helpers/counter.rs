@@ -15,17 +15,19 @@
 use crate::*;
 use snarkvm_fields::PrimeField;
 
-use std::{mem, rc::Rc};
+use std::{mem, ops::Range, rc::Rc};
 
 #[derive(Debug, Default)]
 pub(crate) struct Counter<F: PrimeField> {
     scope: Scope,
     constraints: Vec<Rc<Constraint<F>>>,
+    spilled: Vec<Range<u64>>,
     constants: u64,
     public: u64,
     private: u64,
     nonzeros: (u64, u64, u64),
-    parents: Vec<(Scope, Vec<Rc<Constraint<F>>>, u64, u64, u64, (u64, u64, u64))>,
+    parents: Vec<(Scope, Vec<Rc<Constraint<F>>>, Vec<Range<u64>>, u64, u64, u64, (u64, u64, u64))>,
+    profiler: Profiler,
 }
 
 impl<F: PrimeField> Counter<F> {
@@ -35,6 +37,9 @@ impl<F: PrimeField> Counter<F> {
         match name.contains('.') {
             true => Err("Scope names cannot contain periods (\".\")".to_string()),
             false => {
+                // Enter the scope in the profile.
+                self.profiler.enter(&name);
+
                 // Construct the scope name.
                 let scope = match self.scope.is_empty() {
                     true => name,
@@ -45,6 +50,7 @@ impl<F: PrimeField> Counter<F> {
                 self.parents.push((
                     self.scope.clone(),
                     mem::take(&mut self.constraints),
+                    mem::take(&mut self.spilled),
                     self.constants,
                     self.public,
                     self.private,
@@ -74,9 +80,21 @@ impl<F: PrimeField> Counter<F> {
         // Ensure the current scope is the last pushed scope.
         match current_scope == name.into() {
             true => {
-                if let Some((scope, constraints, constants, public, private, nonzeros)) = self.parents.pop() {
+                // Exit the scope in the profile.
+                if !self.parents.is_empty() {
+                    self.profiler.exit((
+                        self.constants,
+                        self.public,
+                        self.private,
+                        self.num_constraints_in_scope(),
+                        self.nonzeros,
+                    ));
+                }
+
+                if let Some((scope, constraints, spilled, constants, public, private, nonzeros)) = self.parents.pop() {
                     self.scope = scope;
                     self.constraints = constraints;
+                    self.spilled = spilled;
                     self.constants = constants;
                     self.public = public;
                     self.private = private;
@@ -101,11 +119,33 @@ impl<F: PrimeField> Counter<F> {
         self.constraints.push(constraint);
     }
 
-    /// Returns `true` if all constraints in the scope are satisfied.
+    /// Increments the number of constraints by 1, for a constraint that was spilled to disk at the given index.
+    /// The constraint is not retained, so only its index in the spill is recorded for the scope.
+    #[cfg(feature = "spill")]
+    pub(crate) fn add_spilled_constraint(&mut self, index: u64, (a_nonzeros, b_nonzeros, c_nonzeros): (u64, u64, u64)) {
+        self.nonzeros.0 += a_nonzeros;
+        self.nonzeros.1 += b_nonzeros;
+        self.nonzeros.2 += c_nonzeros;
+
+        // Extend the last range of spilled constraints, if the constraint directly follows it.
+        match self.spilled.last_mut() {
+            Some(range) if range.end == index => range.end += 1,
+            _ => self.spilled.push(index..index + 1),
+        }
+    }
+
+    /// Returns `true` if all constraints held in memory for the scope are satisfied.
+    /// The constraints of the scope that were spilled to disk are checked by `R1CS::is_satisfied_in_scope`.
     pub(crate) fn is_satisfied_in_scope(&self) -> bool {
         self.constraints.iter().all(|constraint| constraint.is_satisfied())
     }
 
+    /// Returns the ranges of indices of the constraints in the scope that were spilled to disk.
+    #[cfg(feature = "spill")]
+    pub(crate) fn spilled_in_scope(&self) -> &[Range<u64>] {
+        &self.spilled
+    }
+
     /// Returns the current scope.
     pub(crate) fn scope(&self) -> Scope {
         self.scope.clone()
@@ -143,11 +183,30 @@ impl<F: PrimeField> Counter<F> {
 
     /// Returns the number of constraints in scope.
     pub(crate) fn num_constraints_in_scope(&self) -> u64 {
-        self.constraints.len() as u64
+        self.constraints.len() as u64 + num_spilled(&self.spilled)
     }
 
     /// Returns the number of nonzeros in scope.
     pub(crate) fn num_nonzeros_in_scope(&self) -> (u64, u64, u64) {
         self.nonzeros
     }
+
+    /// Returns the profile of all scopes entered so far, including the counts of the open scopes.
+    pub(crate) fn profile(&self) -> ScopeTree {
+        let parents = self.parents.iter().map(|(_, constraints, spilled, constants, public, private, nonzeros)| {
+            (*constants, *public, *private, constraints.len() as u64 + num_spilled(spilled), *nonzeros)
+        });
+        self.profiler.to_tree(parents.chain(std::iter::once((
+            self.constants,
+            self.public,
+            self.private,
+            self.num_constraints_in_scope(),
+            self.nonzeros,
+        ))))
+    }
+}
+
+/// Returns the number of spilled constraints in the given ranges of indices.
+fn num_spilled(spilled: &[Range<u64>]) -> u64 {
+    spilled.iter().map(|range| range.end - range.start).sum()
 }
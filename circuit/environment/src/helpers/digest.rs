@@ -0,0 +1,53 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+use crate::AssignmentLC;
+use snarkvm_fields::PrimeField;
+use snarkvm_utilities::{io::Result as IoResult, ToBytes};
+
+use blake2::{Blake2s256, Digest};
+
+/// The domain separator of a circuit digest.
+const DIGEST_DOMAIN: &[u8] = b"snarkVM circuit digest v1";
+
+/// A hasher of the constraint structure of a circuit, which is independent of the values of its variables.
+pub(crate) struct CircuitDigest(Blake2s256);
+
+impl CircuitDigest {
+    /// Initializes a digest of a circuit with the given number of public variables, private variables, and constraints.
+    pub(crate) fn new(num_public: u64, num_private: u64, num_constraints: u64) -> IoResult<Self> {
+        let mut hasher = Blake2s256::new();
+        hasher.update(DIGEST_DOMAIN);
+        num_public.write_le(&mut hasher)?;
+        num_private.write_le(&mut hasher)?;
+        num_constraints.write_le(&mut hasher)?;
+        Ok(Self(hasher))
+    }
+
+    /// Absorbs the next constraint `a * b == c` of the circuit.
+    pub(crate) fn update<F: PrimeField>(
+        &mut self,
+        a: &AssignmentLC<F>,
+        b: &AssignmentLC<F>,
+        c: &AssignmentLC<F>,
+    ) -> IoResult<()> {
+        a.write_le(&mut self.0)?;
+        b.write_le(&mut self.0)?;
+        c.write_le(&mut self.0)
+    }
+
+    /// Returns the digest of the circuit.
+    pub(crate) fn finalize(self) -> [u8; 32] {
+        self.0.finalize().into()
+    }
+}
@@ -23,6 +23,9 @@ pub use constraint::*;
 
 pub(super) mod converter;
 
+pub(super) mod digest;
+pub(super) use digest::*;
+
 pub mod count;
 pub use count::*;
 
@@ -35,11 +38,22 @@ pub use linear_combination::*;
 mod mode;
 pub use mode::*;
 
+pub mod profile;
+pub use profile::*;
+
 pub mod variable;
 pub use variable::*;
 
 pub mod r1cs;
 pub use r1cs::*;
 
+#[cfg(feature = "spill")]
+pub mod spill;
+#[cfg(feature = "spill")]
+pub use spill::*;
+
+pub mod subexpressions;
+pub use subexpressions::*;
+
 pub mod updatable_count;
 pub use updatable_count::*;
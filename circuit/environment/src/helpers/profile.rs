@@ -0,0 +1,165 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+use indexmap::IndexMap;
+
+use std::fmt::Write;
+
+/// The name of the root scope in a profile.
+const ROOT: &str = "circuit";
+
+/// The number of constants, public variables, private variables, constraints, and nonzeros in a scope.
+pub(crate) type ScopeCount = (u64, u64, u64, u64, (u64, u64, u64));
+
+/// A tree of the constraint counts in each scope of a circuit.
+///
+/// The counts of a node exclude those of its children, and every call to a scope
+/// with the same name under the same parent is accumulated into a single node.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct ScopeTree {
+    /// The name of the scope.
+    pub name: String,
+    /// The number of times the scope was entered.
+    pub num_calls: u64,
+    /// The number of constants allocated directly in the scope.
+    pub num_constants: u64,
+    /// The number of public variables allocated directly in the scope.
+    pub num_public: u64,
+    /// The number of private variables allocated directly in the scope.
+    pub num_private: u64,
+    /// The number of constraints enforced directly in the scope.
+    pub num_constraints: u64,
+    /// The number of nonzeros in the constraints enforced directly in the scope.
+    pub num_nonzeros: (u64, u64, u64),
+    /// The scopes nested in this scope, in the order they were first entered.
+    pub children: Vec<ScopeTree>,
+}
+
+impl ScopeTree {
+    /// Returns the number of constants, public variables, private variables, constraints, and nonzeros
+    /// allocated directly in the scope.
+    pub fn count(&self) -> (u64, u64, u64, u64, (u64, u64, u64)) {
+        (self.num_constants, self.num_public, self.num_private, self.num_constraints, self.num_nonzeros)
+    }
+
+    /// Returns the number of constants, public variables, private variables, constraints, and nonzeros
+    /// allocated in the scope and all of its nested scopes.
+    pub fn total_count(&self) -> (u64, u64, u64, u64, (u64, u64, u64)) {
+        self.children.iter().fold(self.count(), |total, child| add_counts(total, child.total_count()))
+    }
+
+    /// Returns the child scope with the given name, if it exists.
+    pub fn child(&self, name: &str) -> Option<&ScopeTree> {
+        self.children.iter().find(|child| child.name == name)
+    }
+
+    /// Returns the profile in the collapsed stack format, with one line per scope of the form
+    /// `circuit;outer;inner <num_constraints>`, for use with flamegraph tools.
+    /// Scopes that enforce no constraints directly are omitted.
+    pub fn to_collapsed_stacks(&self) -> String {
+        let mut output = String::new();
+        self.write_collapsed_stacks(&mut Vec::new(), &mut output);
+        output
+    }
+
+    /// Writes the collapsed stacks of this scope and its nested scopes to the given output.
+    fn write_collapsed_stacks<'a>(&'a self, stack: &mut Vec<&'a str>, output: &mut String) {
+        stack.push(&self.name);
+        if self.num_constraints > 0 {
+            // Writing to a `String` is infallible.
+            let _ = writeln!(output, "{} {}", stack.join(";"), self.num_constraints);
+        }
+        for child in &self.children {
+            child.write_collapsed_stacks(stack, output);
+        }
+        stack.pop();
+    }
+
+    /// Adds the given counts to the scope.
+    fn add(&mut self, count: ScopeCount) {
+        let (constants, public, private, constraints, nonzeros) = count;
+        self.num_constants += constants;
+        self.num_public += public;
+        self.num_private += private;
+        self.num_constraints += constraints;
+        self.num_nonzeros.0 += nonzeros.0;
+        self.num_nonzeros.1 += nonzeros.1;
+        self.num_nonzeros.2 += nonzeros.2;
+    }
+}
+
+/// Returns the sum of the given counts.
+fn add_counts(a: ScopeCount, b: ScopeCount) -> ScopeCount {
+    (a.0 + b.0, a.1 + b.1, a.2 + b.2, a.3 + b.3, (a.4 .0 + b.4 .0, a.4 .1 + b.4 .1, a.4 .2 + b.4 .2))
+}
+
+/// Records the counts of every scope entered in a circuit.
+#[derive(Clone, Debug)]
+pub(crate) struct Profiler {
+    /// The scopes entered so far, with the indices of their children, where the first is the root.
+    nodes: Vec<(ScopeTree, IndexMap<String, usize>)>,
+    /// The indices of the open scopes, from the root to the current scope.
+    stack: Vec<usize>,
+}
+
+impl Default for Profiler {
+    /// Returns a new profiler, in the root scope.
+    fn default() -> Self {
+        let root = ScopeTree { name: ROOT.to_string(), num_calls: 1, ..Default::default() };
+        Self { nodes: vec![(root, IndexMap::new())], stack: vec![0] }
+    }
+}
+
+impl Profiler {
+    /// Enters the child scope with the given name.
+    pub(crate) fn enter(&mut self, name: &str) {
+        // Note: The stack always contains the root.
+        let parent = self.stack[self.stack.len() - 1];
+        let index = match self.nodes[parent].1.get(name) {
+            Some(index) => *index,
+            None => {
+                let index = self.nodes.len();
+                self.nodes.push((ScopeTree { name: name.to_string(), ..Default::default() }, IndexMap::new()));
+                self.nodes[parent].1.insert(name.to_string(), index);
+                index
+            }
+        };
+        self.nodes[index].0.num_calls += 1;
+        self.stack.push(index);
+    }
+
+    /// Exits the current scope, accumulating the given counts of the scope.
+    pub(crate) fn exit(&mut self, count: ScopeCount) {
+        // Note: The root scope is never exited.
+        if self.stack.len() > 1 {
+            if let Some(index) = self.stack.pop() {
+                self.nodes[index].0.add(count);
+            }
+        }
+    }
+
+    /// Returns the profile, given the counts of the open scopes, from the root to the current scope.
+    pub(crate) fn to_tree(&self, open_counts: impl IntoIterator<Item = ScopeCount>) -> ScopeTree {
+        let mut nodes = self.nodes.clone();
+        for (index, count) in self.stack.iter().zip(open_counts) {
+            nodes[*index].0.add(count);
+        }
+        Self::build(&nodes, 0)
+    }
+
+    /// Returns the subtree rooted at the given index.
+    fn build(nodes: &[(ScopeTree, IndexMap<String, usize>)], index: usize) -> ScopeTree {
+        let (node, children) = &nodes[index];
+        ScopeTree { children: children.values().map(|child| Self::build(nodes, *child)).collect(), ..node.clone() }
+    }
+}
@@ -12,8 +12,19 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+#[cfg(feature = "spill")]
+use crate::helpers::{constraint_spill_threshold, AssignmentVariable, ConstraintSpill};
 use crate::{
-    helpers::{Constraint, Counter},
+    helpers::{
+        AssignmentLC,
+        CircuitDigest,
+        Constraint,
+        Counter,
+        ScopeTree,
+        SubexpressionKey,
+        Subexpressions,
+        UnsatisfiedConstraint,
+    },
     prelude::*,
 };
 use snarkvm_fields::PrimeField;
@@ -30,6 +41,9 @@ pub struct R1CS<F: PrimeField> {
     constraints: Vec<Rc<Constraint<F>>>,
     counter: Counter<F>,
     nonzeros: (u64, u64, u64),
+    subexpressions: Option<Subexpressions<F>>,
+    #[cfg(feature = "spill")]
+    spill: Option<ConstraintSpill<F>>,
 }
 
 impl<F: PrimeField> R1CS<F> {
@@ -42,6 +56,9 @@ impl<F: PrimeField> R1CS<F> {
             constraints: Default::default(),
             counter: Default::default(),
             nonzeros: (0, 0, 0),
+            subexpressions: None,
+            #[cfg(feature = "spill")]
+            spill: None,
         }
     }
 
@@ -79,16 +96,88 @@ impl<F: PrimeField> R1CS<F> {
         variable
     }
 
+    /// Returns `true` if common subexpression elimination is enabled.
+    pub(crate) fn is_cse_enabled(&self) -> bool {
+        self.subexpressions.is_some()
+    }
+
+    /// Enables or disables common subexpression elimination.
+    /// When enabled, identical constraints are only enforced once, and the outputs of
+    /// operations (such as bit decompositions) are reused for identical inputs.
+    pub(crate) fn set_cse_enabled(&mut self, enabled: bool) {
+        match enabled {
+            true => self.subexpressions = self.subexpressions.take().or_else(|| Some(Subexpressions::new())),
+            false => self.subexpressions = None,
+        }
+    }
+
+    /// Returns the recorded output of the operation for the given key and input, if it exists.
+    pub(crate) fn get_subexpression<T: Clone + 'static>(
+        &self,
+        key: SubexpressionKey,
+        input: &LinearCombination<F>,
+    ) -> Option<T> {
+        self.subexpressions.as_ref().and_then(|subexpressions| subexpressions.get(key, input))
+    }
+
+    /// Records the output of the operation for the given key and input, if common subexpression elimination is enabled.
+    pub(crate) fn insert_subexpression<T: Clone + 'static>(
+        &mut self,
+        key: SubexpressionKey,
+        input: &LinearCombination<F>,
+        output: T,
+    ) {
+        if let Some(subexpressions) = &mut self.subexpressions {
+            subexpressions.insert(key, input, output);
+        }
+    }
+
+    /// Returns the number of constraints eliminated by common subexpression elimination.
+    pub fn num_eliminated_constraints(&self) -> u64 {
+        self.subexpressions.as_ref().map_or(0, |subexpressions| subexpressions.num_eliminated())
+    }
+
     /// Adds one constraint enforcing that `(A * B) == C`.
-    pub(crate) fn enforce(&mut self, constraint: Constraint<F>) {
+    /// Returns an error if the constraint could not be spilled to disk.
+    pub(crate) fn enforce(&mut self, constraint: Constraint<F>) -> Result<(), String> {
+        // Skip the constraint, if an identical constraint was already enforced.
+        if let Some(subexpressions) = &mut self.subexpressions {
+            if !subexpressions.insert_constraint(&constraint) {
+                return Ok(());
+            }
+        }
+
         let (a_nonzeros, b_nonzeros, c_nonzeros) = constraint.num_nonzeros();
         self.nonzeros.0 += a_nonzeros;
         self.nonzeros.1 += b_nonzeros;
         self.nonzeros.2 += c_nonzeros;
 
+        // Start spilling constraints to disk, once the constraints held in memory reach the threshold.
+        #[cfg(feature = "spill")]
+        if constraint_spill_threshold().map_or(false, |threshold| self.constraints.len() as u64 >= threshold) {
+            if let Err(error) = self.start_spilling() {
+                return Err(format!("Failed to create the constraint spill in the temporary directory: {error}"));
+            }
+        }
+
+        // Spill the constraint to disk, if spilling has started.
+        #[cfg(feature = "spill")]
+        if let Some(spill) = &mut self.spill {
+            let index = spill.num_constraints();
+            if let Err(error) = spill.push(&constraint) {
+                return Err(format!(
+                    "Failed to spill constraint {} to disk: {error}",
+                    self.constraints.len() as u64 + index
+                ));
+            }
+            self.counter.add_spilled_constraint(index, (a_nonzeros, b_nonzeros, c_nonzeros));
+            return Ok(());
+        }
+
         let constraint = Rc::new(constraint);
         self.constraints.push(Rc::clone(&constraint));
         self.counter.add_constraint(constraint);
+        Ok(())
     }
 
     /// Returns `true` if all of the constraints are satisfied.
@@ -102,6 +191,12 @@ impl<F: PrimeField> R1CS<F> {
             return false;
         }
 
+        // Ensure all spilled constraints are satisfied.
+        #[cfg(feature = "spill")]
+        if !self.is_spill_satisfied(|_| true) {
+            return false;
+        }
+
         // In debug mode, ensure all constraints use variables corresponding to the declared variables.
         #[cfg(not(debug_assertions))]
         return true;
@@ -124,9 +219,34 @@ impl<F: PrimeField> R1CS<F> {
         })
     }
 
+    /// Returns up to `limit` unsatisfied constraints, in the order they were enforced.
+    /// Constraints spilled to disk are stored without their scope, and are returned with an empty scope.
+    pub fn unsatisfied_constraints(&self, limit: usize) -> Vec<UnsatisfiedConstraint<F>> {
+        let unsatisfied =
+            self.constraints.iter().enumerate().filter_map(|(index, constraint)| constraint.to_unsatisfied(index));
+        #[cfg(feature = "spill")]
+        let unsatisfied = unsatisfied.chain(self.unsatisfied_spilled_constraints());
+        unsatisfied.take(limit).collect()
+    }
+
     /// Returns `true` if all constraints in the current scope are satisfied.
     pub(crate) fn is_satisfied_in_scope(&self) -> bool {
-        self.counter.is_satisfied_in_scope()
+        if !self.counter.is_satisfied_in_scope() {
+            return false;
+        }
+
+        // Ensure the spilled constraints of the current scope are satisfied.
+        #[cfg(feature = "spill")]
+        {
+            let spilled = self.counter.spilled_in_scope();
+            if !spilled.is_empty()
+                && !self.is_spill_satisfied(|index| spilled.iter().any(|range| range.contains(&index)))
+            {
+                return false;
+            }
+        }
+
+        true
     }
 
     /// Returns the current scope.
@@ -151,6 +271,10 @@ impl<F: PrimeField> R1CS<F> {
 
     /// Returns the number of constraints in the constraint system.
     pub fn num_constraints(&self) -> u64 {
+        #[cfg(feature = "spill")]
+        if let Some(spill) = &self.spill {
+            return self.constraints.len() as u64 + spill.num_constraints();
+        }
         self.constraints.len() as u64
     }
 
@@ -184,6 +308,29 @@ impl<F: PrimeField> R1CS<F> {
         self.counter.num_nonzeros_in_scope()
     }
 
+    /// Returns a canonical hash of the constraint structure of the constraint system,
+    /// which is independent of the values of its variables.
+    pub fn digest(&self) -> std::io::Result<[u8; 32]> {
+        let mut digest = CircuitDigest::new(self.num_public(), self.num_private(), self.num_constraints())?;
+        for constraint in &self.constraints {
+            let (a, b, c) = constraint.to_terms();
+            digest.update(&AssignmentLC::from(a), &AssignmentLC::from(b), &AssignmentLC::from(c))?;
+        }
+        #[cfg(feature = "spill")]
+        if let Some(spill) = &self.spill {
+            for constraint in spill.iter()? {
+                let (a, b, c) = constraint?;
+                digest.update(&a, &b, &c)?;
+            }
+        }
+        Ok(digest.finalize())
+    }
+
+    /// Returns the profile of the constraint counts in each scope of the constraint system.
+    pub fn profile(&self) -> ScopeTree {
+        self.counter.profile()
+    }
+
     /// Returns the public variables in the constraint system.
     pub fn to_public_variables(&self) -> &Vec<Variable<F>> {
         &self.public
@@ -195,9 +342,80 @@ impl<F: PrimeField> R1CS<F> {
     }
 
     /// Returns the constraints in the constraint system.
+    /// With the `spill` feature, this excludes the constraints that were spilled to disk.
     pub fn to_constraints(&self) -> &Vec<Rc<Constraint<F>>> {
         &self.constraints
     }
+
+    /// Returns the constraints that were spilled to disk, which follow the constraints held in memory.
+    #[cfg(feature = "spill")]
+    pub fn to_spilled_constraints(&self) -> Option<&ConstraintSpill<F>> {
+        self.spill.as_ref()
+    }
+
+    /// Spills all further constraints to disk, so that the constraints remain in the order they were enforced.
+    #[cfg(feature = "spill")]
+    pub(crate) fn start_spilling(&mut self) -> std::io::Result<()> {
+        if self.spill.is_none() {
+            self.spill = Some(ConstraintSpill::new()?);
+        }
+        Ok(())
+    }
+
+    /// Removes and returns the constraints that were spilled to disk.
+    #[cfg(feature = "spill")]
+    pub(crate) fn take_spilled_constraints(&mut self) -> Option<ConstraintSpill<F>> {
+        self.spill.take()
+    }
+
+    /// Returns the value of the given spilled linear combination, or `None` if it references an unknown variable.
+    #[cfg(feature = "spill")]
+    fn evaluate_spilled(&self, lc: &AssignmentLC<F>) -> Option<F> {
+        lc.terms().iter().try_fold(lc.constant(), |sum, (variable, coefficient)| {
+            let value = match variable {
+                AssignmentVariable::Constant(value) => *value,
+                AssignmentVariable::Public(index) => self.public.get(*index as usize)?.value(),
+                AssignmentVariable::Private(index) => self.private.get(*index as usize)?.value(),
+            };
+            Some(sum + *coefficient * value)
+        })
+    }
+
+    /// Returns `true` if all of the spilled constraints whose index is selected by `filter` are satisfied.
+    #[cfg(feature = "spill")]
+    fn is_spill_satisfied(&self, filter: impl Fn(u64) -> bool) -> bool {
+        let Some(spill) = &self.spill else {
+            return true;
+        };
+        let Ok(constraints) = spill.iter() else {
+            return false;
+        };
+        constraints.enumerate().filter(|(index, _)| filter(*index as u64)).all(|(_, constraint)| {
+            let Ok((a, b, c)) = constraint else {
+                return false;
+            };
+            match (self.evaluate_spilled(&a), self.evaluate_spilled(&b), self.evaluate_spilled(&c)) {
+                (Some(a), Some(b), Some(c)) => a * b == c,
+                _ => false,
+            }
+        })
+    }
+
+    /// Returns the unsatisfied spilled constraints, with an empty scope.
+    #[cfg(feature = "spill")]
+    fn unsatisfied_spilled_constraints(&self) -> impl Iterator<Item = UnsatisfiedConstraint<F>> + '_ {
+        let offset = self.constraints.len();
+        self.spill.iter().filter_map(|spill| spill.iter().ok()).flatten().enumerate().filter_map(
+            move |(index, constraint)| {
+                let (a, b, c) = constraint.ok()?;
+                let (a, b, c) = (self.evaluate_spilled(&a)?, self.evaluate_spilled(&b)?, self.evaluate_spilled(&c)?);
+                match a * b == c {
+                    true => None,
+                    false => Some(UnsatisfiedConstraint { index: offset + index, scope: Scope::default(), a, b, c }),
+                }
+            },
+        )
+    }
 }
 
 impl<F: PrimeField> Display for R1CS<F> {
@@ -0,0 +1,256 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+//! Spilling of constraints to disk, for circuits whose constraints do not fit in memory.
+//!
+//! Once a constraint system holds more constraints in memory than the spill threshold, every further
+//! constraint is appended to a temporary file in the compact form of an assignment, which references
+//! variables by index. The spilled constraints are read back from a memory map of the file, one at a time,
+//! when the constraint system is checked, or synthesized into a constraint system of `snarkvm_algorithms`.
+
+use crate::{helpers::Constraint, AssignmentLC};
+use snarkvm_fields::PrimeField;
+use snarkvm_utilities::{FromBytes, ToBytes};
+
+use core::{
+    marker::PhantomData,
+    sync::atomic::{AtomicU64, Ordering},
+};
+use memmap2::Mmap;
+use std::{
+    fs::File,
+    io::{Result as IoResult, Write},
+};
+
+/// The number of constraints held in memory, above which constraints are spilled to disk, or `u64::MAX` if unbounded.
+static SPILL_THRESHOLD: AtomicU64 = AtomicU64::new(u64::MAX);
+
+/// The size of the buffer of encoded constraints in bytes, above which it is appended to the file.
+const BUFFER_SIZE: usize = 1 << 22;
+
+/// Returns the number of constraints held in memory, above which constraints are spilled to disk, if any.
+pub fn constraint_spill_threshold() -> Option<u64> {
+    match SPILL_THRESHOLD.load(Ordering::Relaxed) {
+        u64::MAX => None,
+        threshold => Some(threshold),
+    }
+}
+
+/// Sets the number of constraints held in memory by a constraint system, above which its remaining constraints
+/// are spilled to a temporary file in `std::env::temp_dir()`. By default, there is no threshold, and all
+/// constraints are held in memory.
+pub fn set_constraint_spill_threshold(threshold: Option<u64>) {
+    SPILL_THRESHOLD.store(threshold.unwrap_or(u64::MAX), Ordering::Relaxed);
+}
+
+/// The constraints of a constraint system that were spilled to a temporary file.
+/// The file is deleted when this is dropped.
+#[derive(Debug)]
+pub struct ConstraintSpill<F: PrimeField> {
+    /// The temporary file.
+    file: File,
+    /// The number of bytes appended to the file.
+    file_len: usize,
+    /// The encoded constraints that are not yet appended to the file.
+    buffer: Vec<u8>,
+    /// The number of spilled constraints.
+    num_constraints: u64,
+    /// The number of nonzeros in the spilled constraints.
+    nonzeros: (u64, u64, u64),
+    _field: PhantomData<F>,
+}
+
+impl<F: PrimeField> ConstraintSpill<F> {
+    /// Returns a new spill, backed by a new temporary file.
+    pub(crate) fn new() -> IoResult<Self> {
+        Ok(Self {
+            file: tempfile::tempfile()?,
+            file_len: 0,
+            buffer: Vec::with_capacity(BUFFER_SIZE),
+            num_constraints: 0,
+            nonzeros: (0, 0, 0),
+            _field: PhantomData,
+        })
+    }
+
+    /// Appends the given constraint to the spill.
+    pub(crate) fn push(&mut self, constraint: &Constraint<F>) -> IoResult<()> {
+        let (a, b, c) = constraint.to_terms();
+        let (a, b, c) = (AssignmentLC::from(a), AssignmentLC::from(b), AssignmentLC::from(c));
+        a.write_le(&mut self.buffer)?;
+        b.write_le(&mut self.buffer)?;
+        c.write_le(&mut self.buffer)?;
+
+        self.num_constraints += 1;
+        self.nonzeros.0 += a.num_nonzeros();
+        self.nonzeros.1 += b.num_nonzeros();
+        self.nonzeros.2 += c.num_nonzeros();
+
+        // Append the buffer to the file, once it is full.
+        if self.buffer.len() >= BUFFER_SIZE {
+            self.file.write_all(&self.buffer)?;
+            self.file_len += self.buffer.len();
+            self.buffer.clear();
+        }
+        Ok(())
+    }
+
+    /// Returns the number of spilled constraints.
+    pub fn num_constraints(&self) -> u64 {
+        self.num_constraints
+    }
+
+    /// Returns the number of nonzeros in the spilled constraints.
+    pub fn num_nonzeros(&self) -> (u64, u64, u64) {
+        self.nonzeros
+    }
+
+    /// Returns an iterator over the spilled constraints, in the order they were spilled.
+    pub fn iter(&self) -> IoResult<SpilledConstraints<'_, F>> {
+        let mmap = match self.file_len {
+            0 => None,
+            // Safety: the file is private to this process, and is only appended to through `&mut self`,
+            // so it is not modified while it is mapped.
+            _ => Some(unsafe { Mmap::map(&self.file)? }),
+        };
+        Ok(SpilledConstraints { mmap, buffer: &self.buffer, offset: 0, _field: PhantomData })
+    }
+}
+
+/// An iterator over the constraints in a spill, which decodes one constraint at a time.
+pub struct SpilledConstraints<'a, F: PrimeField> {
+    /// The memory map of the file, if it is not empty.
+    mmap: Option<Mmap>,
+    /// The encoded constraints that are not yet appended to the file.
+    buffer: &'a [u8],
+    /// The offset of the next constraint, in the file followed by the buffer.
+    offset: usize,
+    _field: PhantomData<F>,
+}
+
+impl<'a, F: PrimeField> Iterator for SpilledConstraints<'a, F> {
+    type Item = IoResult<(AssignmentLC<F>, AssignmentLC<F>, AssignmentLC<F>)>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let mapped = self.mmap.as_deref().unwrap_or_default();
+        let mut remaining = match self.offset < mapped.len() {
+            true => &mapped[self.offset..],
+            false => self.buffer.get(self.offset - mapped.len()..).unwrap_or_default(),
+        };
+        if remaining.is_empty() {
+            return None;
+        }
+
+        let length = remaining.len();
+        let constraint = read_constraint(&mut remaining);
+        self.offset = match constraint {
+            Ok(_) => self.offset + length - remaining.len(),
+            // Stop after a malformed constraint.
+            Err(_) => mapped.len() + self.buffer.len(),
+        };
+        Some(constraint)
+    }
+}
+
+/// Reads the linear combinations `(a, b, c)` of a constraint from the reader.
+fn read_constraint<F: PrimeField>(reader: &mut &[u8]) -> IoResult<(AssignmentLC<F>, AssignmentLC<F>, AssignmentLC<F>)> {
+    Ok((
+        AssignmentLC::read_le(&mut *reader)?,
+        AssignmentLC::read_le(&mut *reader)?,
+        AssignmentLC::read_le(&mut *reader)?,
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{helpers::Constraint, Assignment, LinearCombination, R1CS};
+    use snarkvm_algorithms::r1cs::{ConstraintSynthesizer, ConstraintSystem, TestConstraintSystem};
+    use snarkvm_curves::edwards_bls12::Fq;
+    use snarkvm_fields::{One, Zero};
+
+    /// Enforces that `next == 2 * previous`, for `num_constraints` powers of two,
+    /// and spills the constraints after the first `num_in_memory` to disk.
+    fn create_example_r1cs(num_constraints: usize, num_in_memory: usize) -> R1CS<Fq> {
+        let mut r1cs = R1CS::<Fq>::new();
+        let two = r1cs.new_constant(Fq::one() + Fq::one());
+        let mut previous = r1cs.new_private(Fq::one());
+        for i in 0..num_constraints {
+            if i == num_in_memory {
+                r1cs.start_spilling().unwrap();
+            }
+            let next = r1cs.new_private(previous.value() + previous.value());
+            r1cs.enforce(Constraint(r1cs.scope(), (&previous).into(), (&two).into(), (&next).into())).unwrap();
+            previous = next;
+        }
+        r1cs
+    }
+
+    #[test]
+    fn test_spill_constraints() {
+        // Spill enough constraints to append the buffer to the file more than once.
+        const NUM_CONSTRAINTS: usize = 50_000;
+        const NUM_IN_MEMORY: usize = 100;
+
+        let r1cs = create_example_r1cs(NUM_CONSTRAINTS, NUM_IN_MEMORY);
+        assert_eq!(NUM_CONSTRAINTS as u64, r1cs.num_constraints());
+        assert_eq!(NUM_IN_MEMORY, r1cs.to_constraints().len());
+        assert_eq!((NUM_CONSTRAINTS - NUM_IN_MEMORY) as u64, r1cs.to_spilled_constraints().unwrap().num_constraints());
+        assert_eq!(NUM_CONSTRAINTS, r1cs.to_spilled_constraints().unwrap().iter().unwrap().count() + NUM_IN_MEMORY);
+        assert!(r1cs.is_satisfied());
+
+        // Ensure the spilled constraints are synthesized in order.
+        let assignment = Assignment::from(r1cs);
+        assert_eq!(NUM_CONSTRAINTS as u64, assignment.num_constraints());
+        assert_eq!((NUM_CONSTRAINTS as u64, NUM_CONSTRAINTS as u64, NUM_CONSTRAINTS as u64), assignment.num_nonzeros());
+
+        let mut cs = TestConstraintSystem::new();
+        assignment.generate_constraints(&mut cs).unwrap();
+        assert_eq!(NUM_CONSTRAINTS, cs.num_constraints());
+        assert!(cs.is_satisfied());
+    }
+
+    #[test]
+    fn test_spill_unsatisfied_constraints() {
+        let mut r1cs = create_example_r1cs(10, 5);
+        assert!(r1cs.is_satisfied());
+
+        let one = r1cs.new_private(Fq::one());
+        r1cs.enforce(Constraint(r1cs.scope(), (&one).into(), (&one).into(), LinearCombination::zero())).unwrap();
+        assert!(!r1cs.is_satisfied());
+
+        let unsatisfied = r1cs.unsatisfied_constraints(usize::MAX);
+        assert_eq!(1, unsatisfied.len());
+        assert_eq!(10, unsatisfied[0].index);
+        assert_eq!(Fq::one(), unsatisfied[0].lhs());
+        assert_eq!(Fq::zero(), unsatisfied[0].rhs());
+    }
+
+    #[test]
+    fn test_spill_unsatisfied_constraints_in_scope() {
+        let mut r1cs = create_example_r1cs(10, 5);
+        assert!(r1cs.is_satisfied_in_scope());
+
+        // Enforce an unsatisfied constraint in a nested scope, which is spilled to disk.
+        r1cs.push_scope("unsatisfied").unwrap();
+        assert!(r1cs.is_satisfied_in_scope());
+        let one = r1cs.new_private(Fq::one());
+        r1cs.enforce(Constraint(r1cs.scope(), (&one).into(), (&one).into(), LinearCombination::zero())).unwrap();
+        assert!(r1cs.to_constraints().len() < r1cs.num_constraints() as usize);
+        assert!(!r1cs.is_satisfied_in_scope());
+
+        // The unsatisfied constraint does not belong to the parent scope.
+        r1cs.pop_scope("unsatisfied").unwrap();
+        assert!(r1cs.is_satisfied_in_scope());
+        assert!(!r1cs.is_satisfied());
+    }
+}
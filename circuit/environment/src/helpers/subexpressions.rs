@@ -0,0 +1,93 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::{helpers::Constraint, LinearCombination, Variable};
+use snarkvm_fields::PrimeField;
+
+use core::{any::Any, fmt};
+use std::collections::{HashMap, HashSet};
+
+/// The constant and the terms of a linear combination.
+type Terms<F> = (F, Vec<(Variable<F>, F)>);
+
+/// The key of an operation, as its name and a parameter (e.g. the number of bits).
+pub type SubexpressionKey = (&'static str, usize);
+
+/// The common subexpressions of a constraint system, which are reused when they reoccur.
+pub(crate) struct Subexpressions<F: PrimeField> {
+    /// The `(a, b, c)` terms of each constraint, where `a` and `b` are sorted as `a * b == b * a`.
+    constraints: HashSet<(Terms<F>, Terms<F>, Terms<F>)>,
+    /// The output of each operation, for the given key and input.
+    outputs: HashMap<(SubexpressionKey, Terms<F>), Box<dyn Any>>,
+    /// The number of constraints that were eliminated.
+    num_eliminated: u64,
+}
+
+impl<F: PrimeField> Subexpressions<F> {
+    /// Returns a new, empty set of subexpressions.
+    pub(crate) fn new() -> Self {
+        Self { constraints: Default::default(), outputs: Default::default(), num_eliminated: 0 }
+    }
+
+    /// Returns `true` if the constraint is new, and records it.
+    /// Returns `false` if an identical constraint was already recorded, in which case it can be eliminated.
+    pub(crate) fn insert_constraint(&mut self, constraint: &Constraint<F>) -> bool {
+        let (a, b, c) = constraint.to_terms();
+        let (a, b, c) = (to_terms(a), to_terms(b), to_terms(c));
+        let key = match a <= b {
+            true => (a, b, c),
+            false => (b, a, c),
+        };
+        let is_new = self.constraints.insert(key);
+        if !is_new {
+            self.num_eliminated += 1;
+        }
+        is_new
+    }
+
+    /// Returns the output of the operation for the given key and input, if it was recorded.
+    pub(crate) fn get<T: Clone + 'static>(&self, key: SubexpressionKey, input: &LinearCombination<F>) -> Option<T> {
+        self.outputs.get(&(key, to_terms(input))).and_then(|output| output.downcast_ref::<T>()).cloned()
+    }
+
+    /// Records the output of the operation for the given key and input.
+    pub(crate) fn insert<T: Clone + 'static>(
+        &mut self,
+        key: SubexpressionKey,
+        input: &LinearCombination<F>,
+        output: T,
+    ) {
+        self.outputs.insert((key, to_terms(input)), Box::new(output));
+    }
+
+    /// Returns the number of constraints that were eliminated.
+    pub(crate) const fn num_eliminated(&self) -> u64 {
+        self.num_eliminated
+    }
+}
+
+impl<F: PrimeField> fmt::Debug for Subexpressions<F> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("Subexpressions")
+            .field("num_constraints", &self.constraints.len())
+            .field("num_outputs", &self.outputs.len())
+            .field("num_eliminated", &self.num_eliminated)
+            .finish()
+    }
+}
+
+/// Returns the constant and the terms of the given linear combination.
+fn to_terms<F: PrimeField>(linear_combination: &LinearCombination<F>) -> Terms<F> {
+    (linear_combination.to_constant(), linear_combination.to_terms().to_vec())
+}
@@ -48,6 +48,7 @@ pub mod prelude {
         traits::*,
         witness,
         witness_mode,
+        Budget,
         CircuitType,
         Count,
         Environment,
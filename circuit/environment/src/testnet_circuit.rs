@@ -18,13 +18,18 @@ use core::{
     cell::{Cell, RefCell},
     fmt,
 };
+use std::rc::Rc;
 
 type Field = <console::TestnetV0 as console::Environment>::Field;
 
+/// The index of the first variable in evaluation mode.
+const EVALUATION_START_INDEX: u64 = 1 << 63;
+
 thread_local! {
     static CONSTRAINT_LIMIT: Cell<Option<u64>> = Cell::new(None);
     pub(super) static TESTNET_CIRCUIT: RefCell<R1CS<Field>> = RefCell::new(R1CS::new());
     static IN_WITNESS: Cell<bool> = Cell::new(false);
+    static EVALUATION_INDEX: Cell<Option<u64>> = Cell::new(None);
     static ZERO: LinearCombination<Field> = LinearCombination::zero();
     static ONE: LinearCombination<Field> = LinearCombination::one();
 }
@@ -53,6 +58,16 @@ impl Environment for TestnetCircuit {
         IN_WITNESS.with(|in_witness| {
             // Ensure we are not in witness mode.
             if !in_witness.get() {
+                // In evaluation mode, return a variable that is not allocated in the circuit.
+                if let Some(index) = EVALUATION_INDEX.with(|evaluation_index| evaluation_index.get()) {
+                    EVALUATION_INDEX.with(|evaluation_index| evaluation_index.set(Some(index + 1)));
+                    return match mode {
+                        Mode::Constant => Variable::Constant(Rc::new(value)),
+                        Mode::Public => Variable::Public(Rc::new((index, value))),
+                        Mode::Private => Variable::Private(Rc::new((index, value))),
+                    };
+                }
+
                 TESTNET_CIRCUIT.with(|circuit| match mode {
                     Mode::Constant => circuit.borrow_mut().new_constant(value),
                     Mode::Public => circuit.borrow_mut().new_public(value),
@@ -88,6 +103,11 @@ impl Environment for TestnetCircuit {
         IN_WITNESS.with(|in_witness| {
             // Ensure we are not in witness mode.
             if !in_witness.get() {
+                // In evaluation mode, scopes are not recorded.
+                if EVALUATION_INDEX.with(|evaluation_index| evaluation_index.get()).is_some() {
+                    return logic();
+                }
+
                 TESTNET_CIRCUIT.with(|circuit| {
                     // Set the entire environment to the new scope.
                     let name = name.into();
@@ -122,6 +142,11 @@ impl Environment for TestnetCircuit {
         IN_WITNESS.with(|in_witness| {
             // Ensure we are not in witness mode.
             if !in_witness.get() {
+                // In evaluation mode, constraints are neither constructed nor recorded.
+                if EVALUATION_INDEX.with(|evaluation_index| evaluation_index.get()).is_some() {
+                    return;
+                }
+
                 TESTNET_CIRCUIT.with(|circuit| {
                     // Ensure that we do not surpass the constraint limit for the circuit.
                     CONSTRAINT_LIMIT.with(|constraint_limit| {
@@ -172,6 +197,11 @@ impl Environment for TestnetCircuit {
         TESTNET_CIRCUIT.with(|circuit| circuit.borrow().is_satisfied())
     }
 
+    /// Returns up to `limit` unsatisfied constraints in the environment, with their scopes and evaluated values.
+    fn unsatisfied_constraints(limit: usize) -> Vec<UnsatisfiedConstraint<Self::BaseField>> {
+        TESTNET_CIRCUIT.with(|circuit| circuit.borrow().unsatisfied_constraints(limit))
+    }
+
     /// Returns `true` if all constraints in the current scope are satisfied.
     fn is_satisfied_in_scope() -> bool {
         TESTNET_CIRCUIT.with(|circuit| circuit.borrow().is_satisfied_in_scope())
@@ -227,6 +257,19 @@ impl Environment for TestnetCircuit {
         TESTNET_CIRCUIT.with(|circuit| circuit.borrow().num_nonzeros_in_scope())
     }
 
+    /// Returns the profile of the constraint counts in each scope of the circuit.
+    fn profile() -> ScopeTree {
+        TESTNET_CIRCUIT.with(|circuit| circuit.borrow().profile())
+    }
+
+    /// Returns a canonical hash of the constraint structure of the circuit, which is independent of the values of its variables.
+    fn digest() -> [u8; 32] {
+        TESTNET_CIRCUIT.with(|circuit| match circuit.borrow().digest() {
+            Ok(digest) => digest,
+            Err(error) => Self::halt(format!("Failed to compute the circuit digest: {error}")),
+        })
+    }
+
     /// Halts the program from further synthesis, evaluation, and execution in the current environment.
     fn halt<S: Into<String>, T>(message: S) -> T {
         let error = message.into();
@@ -244,6 +287,40 @@ impl Environment for TestnetCircuit {
         CONSTRAINT_LIMIT.with(|current_limit| current_limit.replace(limit));
     }
 
+    /// Returns `true` if common subexpression elimination is enabled for the circuit.
+    fn is_cse_enabled() -> bool {
+        TESTNET_CIRCUIT.with(|circuit| circuit.borrow().is_cse_enabled())
+    }
+
+    /// Enables or disables common subexpression elimination for the circuit.
+    fn set_cse_enabled(enabled: bool) {
+        TESTNET_CIRCUIT.with(|circuit| circuit.borrow_mut().set_cse_enabled(enabled))
+    }
+
+    /// Returns the output of `logic` for the given input, reusing the output of an earlier call with the same key
+    /// and an identical input, if common subexpression elimination is enabled.
+    fn cse<T, Fn>(key: SubexpressionKey, input: &LinearCombination<Self::BaseField>, logic: Fn) -> T
+    where
+        T: Clone + 'static,
+        Fn: FnOnce() -> T,
+    {
+        // Subexpressions are only reused when synthesizing constraints on a non-constant input.
+        let is_synthesizing = !IN_WITNESS.with(|in_witness| in_witness.get())
+            && EVALUATION_INDEX.with(|evaluation_index| evaluation_index.get()).is_none();
+        if !is_synthesizing || input.is_constant() || !Self::is_cse_enabled() {
+            return logic();
+        }
+
+        // Reuse the output, if it was recorded.
+        if let Some(output) = TESTNET_CIRCUIT.with(|circuit| circuit.borrow().get_subexpression(key, input)) {
+            return output;
+        }
+        // Otherwise, compute the output and record it.
+        let output = logic();
+        TESTNET_CIRCUIT.with(|circuit| circuit.borrow_mut().insert_subexpression(key, input, output.clone()));
+        output
+    }
+
     /// Returns the R1CS circuit, resetting the circuit.
     fn inject_r1cs(r1cs: R1CS<Self::BaseField>) {
         TESTNET_CIRCUIT.with(|circuit| {
@@ -314,6 +391,66 @@ impl Environment for TestnetCircuit {
             assert_eq!(0, circuit.borrow().num_constraints());
         });
     }
+
+    /// Runs the given logic in a new, empty environment, and returns its output with the R1CS it synthesized.
+    /// The environment of the caller, including its witness mode and constraint limit, is restored afterwards,
+    /// even if the logic panics.
+    fn with_new_instance<Fn, Output>(logic: Fn) -> (Output, R1CS<Self::BaseField>)
+    where
+        Fn: FnOnce() -> Output,
+    {
+        // Save the current environment, and initialize a new one.
+        let _guard = SavedInstance(Some((
+            TESTNET_CIRCUIT.with(|circuit| circuit.replace(R1CS::new())),
+            IN_WITNESS.with(|in_witness| in_witness.replace(false)),
+            CONSTRAINT_LIMIT.with(|constraint_limit| constraint_limit.replace(None)),
+            EVALUATION_INDEX.with(|evaluation_index| evaluation_index.replace(None)),
+        )));
+        // Synthesize the logic in the new environment.
+        let output = logic();
+        // Eject the R1CS instance, before the saved environment is restored.
+        let r1cs = TESTNET_CIRCUIT.with(|circuit| circuit.replace(R1CS::new()));
+        (output, r1cs)
+    }
+
+    /// Runs the given logic in evaluation mode, and returns its output.
+    /// In evaluation mode, variables are not allocated in the circuit, and constraints and scopes are skipped,
+    /// so the values of the circuit are computed at a fraction of the cost of synthesis.
+    /// Note that the constraints are not checked, so the outputs are only meaningful for valid inputs.
+    fn evaluate<Fn, Output>(logic: Fn) -> Output
+    where
+        Fn: FnOnce() -> Output,
+    {
+        // Note: The indices of the variables start past those of any synthesized variable, to keep them distinct.
+        let _guard = SavedEvaluationIndex(EVALUATION_INDEX.with(|evaluation_index| {
+            let index = evaluation_index.get().unwrap_or(EVALUATION_START_INDEX);
+            evaluation_index.replace(Some(index))
+        }));
+        logic()
+    }
+}
+
+/// The saved environment of a thread, which is restored when this is dropped.
+struct SavedInstance(Option<(R1CS<Field>, bool, Option<u64>, Option<u64>)>);
+
+impl Drop for SavedInstance {
+    fn drop(&mut self) {
+        if let Some((r1cs, in_witness, constraint_limit, evaluation_index)) = self.0.take() {
+            TESTNET_CIRCUIT.with(|circuit| *circuit.borrow_mut() = r1cs);
+            IN_WITNESS.with(|cell| cell.set(in_witness));
+            CONSTRAINT_LIMIT.with(|cell| cell.set(constraint_limit));
+            EVALUATION_INDEX.with(|cell| cell.set(evaluation_index));
+        }
+    }
+}
+
+/// The evaluation mode of a thread before `evaluate` was called, which is restored when this is dropped.
+struct SavedEvaluationIndex(Option<u64>);
+
+impl Drop for SavedEvaluationIndex {
+    fn drop(&mut self) {
+        EVALUATION_INDEX.with(|evaluation_index| evaluation_index.set(self.0));
+    }
 }
 
 impl fmt::Display for TestnetCircuit {
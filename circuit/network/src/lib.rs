@@ -89,6 +89,9 @@ pub trait Aleo: Environment {
     /// Returns the BHP hash with an input hasher of 1024-bits.
     fn hash_bhp1024(input: &[Boolean<Self>]) -> Field<Self>;
 
+    /// Returns the BLAKE2s hash with a 256-bit output.
+    fn hash_blake2s(input: &[Boolean<Self>]) -> Vec<Boolean<Self>>;
+
     /// Returns the Keccak hash with a 256-bit output.
     fn hash_keccak256(input: &[Boolean<Self>]) -> Vec<Boolean<Self>>;
 
@@ -113,6 +116,9 @@ pub trait Aleo: Environment {
     /// Returns the Poseidon hash with an input rate of 8.
     fn hash_psd8(input: &[Field<Self>]) -> Field<Self>;
 
+    /// Returns the SHA-256 hash with a 256-bit output.
+    fn hash_sha256(input: &[Boolean<Self>]) -> Vec<Boolean<Self>>;
+
     /// Returns the SHA-3 hash with a 256-bit output.
     fn hash_sha3_256(input: &[Boolean<Self>]) -> Vec<Boolean<Self>>;
 
@@ -14,6 +14,7 @@
 
 use crate::Aleo;
 use snarkvm_circuit_algorithms::{
+    Blake2s,
     Commit,
     CommitUncompressed,
     Hash,
@@ -29,6 +30,7 @@ use snarkvm_circuit_algorithms::{
     Poseidon2,
     Poseidon4,
     Poseidon8,
+    Sha256,
     Sha3_256,
     Sha3_384,
     Sha3_512,
@@ -39,7 +41,7 @@ use snarkvm_circuit_algorithms::{
 };
 use snarkvm_circuit_collections::merkle_tree::MerklePath;
 use snarkvm_circuit_types::{
-    environment::{prelude::*, Assignment, TestnetCircuit, R1CS},
+    environment::{prelude::*, Assignment, ScopeTree, SubexpressionKey, TestnetCircuit, UnsatisfiedConstraint, R1CS},
     Boolean,
     Field,
     Group,
@@ -70,6 +72,9 @@ thread_local! {
     /// The BHP hash function, which can take an input of up to 1024 bits.
     static BHP_1024: BHP1024<AleoTestnetV0> = BHP1024::<AleoTestnetV0>::constant(console::TESTNET_BHP_1024.clone());
 
+    /// The BLAKE2s hash function, which outputs 256 bits.
+    static BLAKE2S: Blake2s<AleoTestnetV0> = Blake2s::<AleoTestnetV0>::new();
+
     /// The Keccak hash function, which outputs 256 bits.
     static KECCAK_256: Keccak256<AleoTestnetV0> = Keccak256::<AleoTestnetV0>::new();
     /// The Keccak hash function, which outputs 384 bits.
@@ -89,6 +94,9 @@ thread_local! {
     /// The Poseidon hash function, using a rate of 8.
     static POSEIDON_8: Poseidon8<AleoTestnetV0> = Poseidon8::<AleoTestnetV0>::constant(console::TESTNET_POSEIDON_8.clone());
 
+    /// The SHA-256 hash function, which outputs 256 bits.
+    static SHA_256: Sha256<AleoTestnetV0> = Sha256::<AleoTestnetV0>::new();
+
     /// The SHA-3 hash function, which outputs 256 bits.
     static SHA3_256: Sha3_256<AleoTestnetV0> = Sha3_256::<AleoTestnetV0>::new();
     /// The SHA-3 hash function, which outputs 384 bits.
@@ -207,6 +215,11 @@ impl Aleo for AleoTestnetV0 {
         BHP_1024.with(|bhp| bhp.hash(input))
     }
 
+    /// Returns the BLAKE2s hash with a 256-bit output.
+    fn hash_blake2s(input: &[Boolean<Self>]) -> Vec<Boolean<Self>> {
+        BLAKE2S.with(|blake2s| blake2s.hash(input))
+    }
+
     /// Returns the Keccak hash with a 256-bit output.
     fn hash_keccak256(input: &[Boolean<Self>]) -> Vec<Boolean<Self>> {
         KECCAK_256.with(|keccak| keccak.hash(input))
@@ -247,6 +260,11 @@ impl Aleo for AleoTestnetV0 {
         POSEIDON_8.with(|poseidon| poseidon.hash(input))
     }
 
+    /// Returns the SHA-256 hash with a 256-bit output.
+    fn hash_sha256(input: &[Boolean<Self>]) -> Vec<Boolean<Self>> {
+        SHA_256.with(|sha256| sha256.hash(input))
+    }
+
     /// Returns the SHA-3 hash with a 256-bit output.
     fn hash_sha3_256(input: &[Boolean<Self>]) -> Vec<Boolean<Self>> {
         SHA3_256.with(|sha3| sha3.hash(input))
@@ -406,6 +424,11 @@ impl Environment for AleoTestnetV0 {
         E::is_satisfied()
     }
 
+    /// Returns up to `limit` unsatisfied constraints in the environment, with their scopes and evaluated values.
+    fn unsatisfied_constraints(limit: usize) -> Vec<UnsatisfiedConstraint<Self::BaseField>> {
+        E::unsatisfied_constraints(limit)
+    }
+
     /// Returns `true` if all constraints in the current scope are satisfied.
     fn is_satisfied_in_scope() -> bool {
         E::is_satisfied_in_scope()
@@ -461,6 +484,16 @@ impl Environment for AleoTestnetV0 {
         E::num_nonzeros_in_scope()
     }
 
+    /// Returns the profile of the constraint counts in each scope of the circuit.
+    fn profile() -> ScopeTree {
+        E::profile()
+    }
+
+    /// Returns a canonical hash of the constraint structure of the circuit, which is independent of the values of its variables.
+    fn digest() -> [u8; 32] {
+        E::digest()
+    }
+
     /// Halts the program from further synthesis, evaluation, and execution in the current environment.
     fn halt<S: Into<String>, T>(message: S) -> T {
         E::halt(message)
@@ -476,6 +509,26 @@ impl Environment for AleoTestnetV0 {
         E::set_constraint_limit(limit)
     }
 
+    /// Returns `true` if common subexpression elimination is enabled for the circuit.
+    fn is_cse_enabled() -> bool {
+        E::is_cse_enabled()
+    }
+
+    /// Enables or disables common subexpression elimination for the circuit.
+    fn set_cse_enabled(enabled: bool) {
+        E::set_cse_enabled(enabled)
+    }
+
+    /// Returns the output of `logic` for the given input, reusing the output of an earlier call with the same key
+    /// and an identical input, if common subexpression elimination is enabled.
+    fn cse<T, Fn>(key: SubexpressionKey, input: &LinearCombination<Self::BaseField>, logic: Fn) -> T
+    where
+        T: Clone + 'static,
+        Fn: FnOnce() -> T,
+    {
+        E::cse(key, input, logic)
+    }
+
     /// Returns the R1CS circuit, resetting the circuit.
     fn inject_r1cs(r1cs: R1CS<Self::BaseField>) {
         E::inject_r1cs(r1cs)
@@ -495,6 +548,22 @@ impl Environment for AleoTestnetV0 {
     fn reset() {
         E::reset()
     }
+
+    /// Runs the given logic in a new, empty environment, and returns its output with the R1CS it synthesized.
+    fn with_new_instance<Fn, Output>(logic: Fn) -> (Output, R1CS<Self::BaseField>)
+    where
+        Fn: FnOnce() -> Output,
+    {
+        E::with_new_instance(logic)
+    }
+
+    /// Runs the given logic in evaluation mode, and returns its output.
+    fn evaluate<Fn, Output>(logic: Fn) -> Output
+    where
+        Fn: FnOnce() -> Output,
+    {
+        E::evaluate(logic)
+    }
 }
 
 impl Display for AleoTestnetV0 {
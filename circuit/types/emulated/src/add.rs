@@ -0,0 +1,172 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+use super::*;
+
+impl<F: PrimeField, E: Environment> Add<Emulated<F, E>> for Emulated<F, E> {
+    type Output = Emulated<F, E>;
+
+    fn add(self, other: Emulated<F, E>) -> Self::Output {
+        &self + &other
+    }
+}
+
+impl<F: PrimeField, E: Environment> Add<&Emulated<F, E>> for Emulated<F, E> {
+    type Output = Emulated<F, E>;
+
+    fn add(self, other: &Emulated<F, E>) -> Self::Output {
+        &self + other
+    }
+}
+
+impl<F: PrimeField, E: Environment> Add<Emulated<F, E>> for &Emulated<F, E> {
+    type Output = Emulated<F, E>;
+
+    fn add(self, other: Emulated<F, E>) -> Self::Output {
+        self + &other
+    }
+}
+
+impl<F: PrimeField, E: Environment> Add<&Emulated<F, E>> for &Emulated<F, E> {
+    type Output = Emulated<F, E>;
+
+    fn add(self, other: &Emulated<F, E>) -> Self::Output {
+        // Add the limbs pairwise, without propagating the carries.
+        let columns = self
+            .columns()
+            .into_iter()
+            .zip_eq(other.columns())
+            .map(|((a, a_value), (b, b_value))| (a + b, a_value + b_value))
+            .collect();
+
+        // Each operand is less than `2^(64 * num_limbs)`, and each limb is less than `2^64`.
+        let max_limb = (BigUint::from(1u8) << LIMB_SIZE_IN_BITS) - 1u8;
+        let max_operand = (BigUint::from(1u8) << (Self::num_limbs() * LIMB_SIZE_IN_BITS)) - 1u8;
+        Emulated::reduce(columns, &(max_operand * 2u8), &(max_limb * 2u8))
+    }
+}
+
+impl<F: PrimeField, E: Environment> AddAssign<Emulated<F, E>> for Emulated<F, E> {
+    fn add_assign(&mut self, other: Emulated<F, E>) {
+        *self += &other;
+    }
+}
+
+impl<F: PrimeField, E: Environment> AddAssign<&Emulated<F, E>> for Emulated<F, E> {
+    fn add_assign(&mut self, other: &Emulated<F, E>) {
+        *self = &*self + other;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_circuit_environment::{assert_scope, Circuit};
+
+    type Fq = snarkvm_curves::bls12_377::Fq;
+
+    const ITERATIONS: u64 = 100;
+
+    fn check_add(name: &str, expected: &Fq, a: &Emulated<Fq, Circuit>, b: &Emulated<Fq, Circuit>) {
+        Circuit::scope(name, || {
+            let candidate = a + b;
+            assert_eq!(*expected, candidate.eject_value(), "({} + {})", a.eject_value(), b.eject_value());
+            assert!(Circuit::is_satisfied_in_scope(), "(is_satisfied_in_scope)");
+            if a.eject_mode().is_constant() && b.eject_mode().is_constant() {
+                assert_scope!(6, 0, 0, 0);
+            }
+        });
+    }
+
+    fn run_test(mode_a: Mode, mode_b: Mode) {
+        let mut rng = TestRng::default();
+
+        for i in 0..ITERATIONS {
+            let first: Fq = Uniform::rand(&mut rng);
+            let second: Fq = Uniform::rand(&mut rng);
+
+            let a = Emulated::<Fq, Circuit>::new(mode_a, first);
+            let b = Emulated::<Fq, Circuit>::new(mode_b, second);
+
+            let name = format!("Add: a + b {i}");
+            check_add(&name, &(first + second), &a, &b);
+
+            // Test identity.
+            let name = format!("Add: a + 0 {i}");
+            let zero = Emulated::<Fq, Circuit>::new(mode_b, Fq::zero());
+            check_add(&name, &first, &a, &zero);
+
+            // Test an unreduced operand.
+            let name = format!("Add: (a + b) + b {i}");
+            let sum = &a + &b;
+            check_add(&name, &(first + second + second), &sum, &b);
+
+            Circuit::reset();
+        }
+    }
+
+    #[test]
+    fn test_constant_plus_constant() {
+        run_test(Mode::Constant, Mode::Constant);
+    }
+
+    #[test]
+    fn test_constant_plus_public() {
+        run_test(Mode::Constant, Mode::Public);
+    }
+
+    #[test]
+    fn test_public_plus_constant() {
+        run_test(Mode::Public, Mode::Constant);
+    }
+
+    #[test]
+    fn test_constant_plus_private() {
+        run_test(Mode::Constant, Mode::Private);
+    }
+
+    #[test]
+    fn test_private_plus_constant() {
+        run_test(Mode::Private, Mode::Constant);
+    }
+
+    #[test]
+    fn test_public_plus_public() {
+        run_test(Mode::Public, Mode::Public);
+    }
+
+    #[test]
+    fn test_public_plus_private() {
+        run_test(Mode::Public, Mode::Private);
+    }
+
+    #[test]
+    fn test_private_plus_public() {
+        run_test(Mode::Private, Mode::Public);
+    }
+
+    #[test]
+    fn test_private_plus_private() {
+        run_test(Mode::Private, Mode::Private);
+    }
+
+    #[test]
+    fn test_add_matches_modular_addition_at_the_boundary() {
+        // Ensure that `(p - 1) + (p - 1)` is reduced correctly.
+        let minus_one = -Fq::one();
+        let a = Emulated::<Fq, Circuit>::new(Mode::Private, minus_one);
+        let b = Emulated::<Fq, Circuit>::new(Mode::Private, minus_one);
+        check_add("Add: -1 + -1", &(minus_one + minus_one), &a, &b);
+        Circuit::reset();
+    }
+}
@@ -0,0 +1,218 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+impl<F: PrimeField, E: Environment> Emulated<F, E> {
+    /// Returns the number of limbs in an emulated field element.
+    pub fn num_limbs() -> usize {
+        (F::size_in_bits() + LIMB_SIZE_IN_BITS - 1) / LIMB_SIZE_IN_BITS
+    }
+
+    /// Returns the limbs of the emulated field element, in little-endian order.
+    pub fn limbs(&self) -> &[Field<E>] {
+        &self.limbs
+    }
+
+    /// Initializes an emulated field element from the given limbs.
+    fn from_limbs(limbs: Vec<Field<E>>) -> Self {
+        debug_assert_eq!(limbs.len(), Self::num_limbs(), "Incorrect number of limbs for an emulated field element");
+        Self { limbs, _phantom: PhantomData }
+    }
+
+    /// Returns the modulus of the field `F`.
+    fn modulus() -> BigUint {
+        F::modulus().to_biguint()
+    }
+
+    /// Initializes a constant emulated field element from the given integer.
+    fn constant_from_integer(value: &BigUint) -> Self {
+        Self::from_limbs(
+            Self::to_u64_limbs(value, Self::num_limbs())
+                .into_iter()
+                .map(|limb| Field::constant(Self::to_base_field(&BigUint::from(limb))))
+                .collect(),
+        )
+    }
+
+    /// Returns the integer value of the emulated field element, which is not reduced modulo `F`.
+    fn integer_value(&self) -> BigUint {
+        self.columns()
+            .into_iter()
+            .rev()
+            .fold(BigUint::default(), |value, (_, limb)| (value << LIMB_SIZE_IN_BITS) + limb.magnitude())
+    }
+
+    /// Returns the limbs of the emulated field element, paired with their integer values.
+    fn columns(&self) -> Vec<(Field<E>, BigInt)> {
+        self.limbs
+            .iter()
+            .map(|limb| (limb.clone(), BigInt::from((*limb.eject_value()).to_bigint().to_biguint())))
+            .collect()
+    }
+
+    /// Returns the given integer as `num_limbs` 64-bit limbs in little-endian order.
+    /// If the integer does not fit in the given number of limbs, the upper limbs are discarded.
+    fn to_u64_limbs(value: &BigUint, num_limbs: usize) -> Vec<u64> {
+        let mut limbs = value.to_u64_digits();
+        limbs.resize(num_limbs, 0);
+        limbs
+    }
+
+    /// Returns the given integer as a base field element, reduced modulo the base field.
+    fn to_base_field(value: &BigUint) -> console::Field<E::Network> {
+        let two_to_the_64 = E::BaseField::from(1u128 << LIMB_SIZE_IN_BITS);
+        console::Field::new(
+            value
+                .to_u64_digits()
+                .into_iter()
+                .rev()
+                .fold(E::BaseField::zero(), |result, digit| result * two_to_the_64 + E::BaseField::from(digit as u128)),
+        )
+    }
+
+    /// Returns the given integer as limbs in the given mode, where each limb is range-checked,
+    /// such that the integer is enforced to be less than `2^num_bits`.
+    fn new_limbs(mode: Mode, value: &BigUint, num_bits: usize) -> Vec<Field<E>> {
+        let num_limbs = (num_bits + LIMB_SIZE_IN_BITS - 1) / LIMB_SIZE_IN_BITS;
+        Self::to_u64_limbs(value, num_limbs)
+            .into_iter()
+            .enumerate()
+            .map(|(i, limb)| {
+                let limb = Field::new(mode, Self::to_base_field(&BigUint::from(limb)));
+                // Ensure the limb is within its number of bits.
+                limb.to_lower_bits_le(LIMB_SIZE_IN_BITS.min(num_bits - i * LIMB_SIZE_IN_BITS));
+                limb
+            })
+            .collect()
+    }
+}
+
+impl<F: PrimeField, E: Environment> Emulated<F, E> {
+    /// Returns the emulated field element `x mod p`, where `p` is the modulus of the field `F`,
+    /// and the integer `x` is given as columns, such that `x = Σ columns[i] * 2^(64 * i)`.
+    /// Each column is a base field element, paired with its (possibly negative) integer value.
+    ///
+    /// The quotient `q` and remainder `r` of `x` divided by `p` are witnessed,
+    /// and the identity `x = q * p + r` is enforced over the integers, in a CRT-style manner:
+    ///   1. The identity is checked modulo the base field modulus `n`, in a single linear constraint.
+    ///   2. The identity is checked modulo `2^(64 * t)`, by propagating range-checked carries
+    ///      across the lowest `t` columns of `x - q * p - r`.
+    ///
+    /// As `n` is odd, the identity holds modulo `n * 2^(64 * t)`, and `t` is chosen such that this exceeds
+    /// twice the magnitude of both sides, so the identity holds over the integers.
+    ///
+    /// The `max_value` is an upper bound on `x`, and `max_column` is an upper bound on the magnitude of each column.
+    fn reduce(columns: Vec<(Field<E>, BigInt)>, max_value: &BigUint, max_column: &BigUint) -> Self {
+        let num_limbs = Self::num_limbs();
+        let modulus = Self::modulus();
+
+        // Compute the integer value of `x`.
+        let x = columns
+            .iter()
+            .rev()
+            .fold(BigInt::default(), |x, (_, column)| (x << LIMB_SIZE_IN_BITS) + column)
+            .to_biguint()
+            .unwrap_or_else(|| E::halt("The integer to reduce in an emulated field must be non-negative"));
+
+        // Compute the quotient and remainder of `x` divided by `p`.
+        let (quotient, remainder) = (&x / &modulus, &x % &modulus);
+
+        // If all columns are constant, return the remainder as a constant.
+        if columns.iter().all(|(column, _)| column.is_constant()) {
+            return Self::constant_from_integer(&remainder);
+        }
+
+        // Witness the quotient, which is at most `max_value / p`.
+        let max_quotient = max_value / &modulus;
+        let quotient_num_bits = max_quotient.bits() as usize;
+        let quotient_limbs = Self::new_limbs(Mode::Private, &quotient, quotient_num_bits);
+        let quotient_values = Self::to_u64_limbs(&quotient, quotient_limbs.len());
+
+        // Witness the remainder, which is less than `2^(64 * num_limbs)`.
+        let remainder_limbs = Self::new_limbs(Mode::Private, &remainder, num_limbs * LIMB_SIZE_IN_BITS);
+        let remainder_values = Self::to_u64_limbs(&remainder, num_limbs);
+
+        // Compute the columns of `d = x - q * p - r`.
+        let modulus_limbs = Self::to_u64_limbs(&modulus, num_limbs);
+        let num_columns = columns.len().max(quotient_limbs.len() + num_limbs - 1);
+        let mut differences = vec![(Field::<E>::zero(), BigInt::default()); num_columns];
+        for (i, (column, value)) in columns.into_iter().enumerate() {
+            differences[i] = (column, value);
+        }
+        for (j, (limb, value)) in quotient_limbs.iter().zip_eq(&quotient_values).enumerate() {
+            for (k, modulus_limb) in modulus_limbs.iter().enumerate() {
+                // Note: This multiplication is by a constant, and thus incurs no constraints.
+                let product = limb * Field::constant(Self::to_base_field(&BigUint::from(*modulus_limb)));
+                differences[j + k].0 -= product;
+                differences[j + k].1 -= BigInt::from(*value) * BigInt::from(*modulus_limb);
+            }
+        }
+        for (i, (limb, value)) in remainder_limbs.iter().zip_eq(&remainder_values).enumerate() {
+            differences[i].0 -= limb;
+            differences[i].1 -= BigInt::from(*value);
+        }
+
+        // Enforce `d = 0` modulo the base field modulus.
+        let two_to_the_64 = E::BaseField::from(1u128 << LIMB_SIZE_IN_BITS);
+        let mut coefficient = E::BaseField::one();
+        let mut native_difference = Field::<E>::zero();
+        for (difference, _) in &differences {
+            native_difference += difference * Field::constant(console::Field::new(coefficient));
+            coefficient *= two_to_the_64;
+        }
+        E::assert_eq(native_difference, Field::<E>::zero());
+
+        // Compute the number of columns `t`, such that `n * 2^(64 * t)` exceeds twice the magnitude of both sides.
+        let max_rhs = &max_quotient * &modulus + (BigUint::from(1u8) << (num_limbs * LIMB_SIZE_IN_BITS));
+        let bound_num_bits = max_value.max(&max_rhs).bits() as usize + 2;
+        let native_num_bits = E::BaseField::size_in_bits() - 1;
+        let num_checked_columns =
+            (bound_num_bits.saturating_sub(native_num_bits) + LIMB_SIZE_IN_BITS - 1) / LIMB_SIZE_IN_BITS;
+
+        // Compute the number of bits in each (shifted) carry.
+        // Each column of `d` is at most `max_column + (min(|q|, |p|) + 1) * 2^128` in magnitude,
+        // and the magnitude of each carry is at most `(max_column_of_d >> 64) + 2`.
+        let max_difference =
+            max_column + (BigUint::from(quotient_limbs.len().min(num_limbs) + 1) << (2 * LIMB_SIZE_IN_BITS));
+        let max_carry = (max_difference >> LIMB_SIZE_IN_BITS) + 2u8;
+        let carry_num_bits = max_carry.bits() as usize + 1;
+        debug_assert!(carry_num_bits + LIMB_SIZE_IN_BITS < native_num_bits, "The carries must not wrap the base field");
+        let carry_offset = BigInt::from(1u8) << (carry_num_bits - 1);
+        let carry_offset_field = Field::constant(Self::to_base_field(carry_offset.magnitude()));
+        let two_to_the_64 = Field::constant(console::Field::new(two_to_the_64));
+
+        // Enforce `d = 0` modulo `2^(64 * t)`, by propagating the carries across the lowest `t` columns.
+        let mut carry = Field::<E>::zero();
+        let mut carry_value = BigInt::default();
+        for i in 0..num_checked_columns {
+            let (difference, difference_value) =
+                differences.get(i).cloned().unwrap_or_else(|| (Field::zero(), BigInt::default()));
+            // Add the incoming carry to the column.
+            let column = difference + &carry;
+            let column_value = difference_value + &carry_value;
+            // Compute the outgoing carry, which is exact for an honest prover.
+            carry_value = column_value >> LIMB_SIZE_IN_BITS;
+            // Witness the shifted carry, and ensure it is within `carry_num_bits` bits.
+            let shifted_carry = (&carry_value + &carry_offset).to_biguint().unwrap_or_default();
+            let shifted_carry = Field::new(Mode::Private, Self::to_base_field(&shifted_carry));
+            shifted_carry.to_lower_bits_le(carry_num_bits);
+            carry = shifted_carry - &carry_offset_field;
+            // Ensure `column == carry * 2^64`.
+            E::assert_eq(column, &carry * &two_to_the_64);
+        }
+
+        Self::from_limbs(remainder_limbs)
+    }
+}
@@ -0,0 +1,110 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+use super::*;
+
+impl<F: PrimeField, E: Environment> Inv for Emulated<F, E> {
+    type Output = Self;
+
+    fn inv(self) -> Self::Output {
+        Self::inverse(&self)
+    }
+}
+
+impl<F: PrimeField, E: Environment> Inv for &Emulated<F, E> {
+    type Output = Emulated<F, E>;
+
+    fn inv(self) -> Self::Output {
+        self.inverse()
+    }
+}
+
+impl<F: PrimeField, E: Environment> Inverse for Emulated<F, E> {
+    type Output = Emulated<F, E>;
+
+    /// Returns the inverse of the emulated field element.
+    /// If the element is zero, the circuit is unsatisfiable.
+    fn inverse(&self) -> Self::Output {
+        let inverse = self.eject_value().inverse().unwrap_or_else(F::zero);
+
+        // If the element is constant, return the inverse as a constant.
+        if self.is_constant() {
+            return Self::constant(inverse);
+        }
+
+        // Witness the inverse.
+        let inverse = Self::new(Mode::Private, inverse);
+
+        // Ensure `self` * `self^(-1)` == 1. As the product is reduced, its limbs are compared directly.
+        let product = self * &inverse;
+        for (limb, one_limb) in product.limbs().iter().zip_eq(Self::constant(F::one()).limbs()) {
+            E::assert_eq(limb, one_limb);
+        }
+
+        inverse
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_circuit_environment::{assert_scope, Circuit};
+
+    type Fq = snarkvm_curves::bls12_377::Fq;
+
+    const ITERATIONS: u64 = 100;
+
+    fn check_inverse(name: &str, mode: Mode, rng: &mut TestRng) {
+        for _ in 0..ITERATIONS {
+            // Sample a random non-zero element.
+            let given: Fq = Uniform::rand(rng);
+            let expected = given.inverse().unwrap();
+            let candidate = Emulated::<Fq, Circuit>::new(mode, given);
+
+            Circuit::scope(name, || {
+                let result = candidate.inverse();
+                assert_eq!(expected, result.eject_value());
+                assert!(Circuit::is_satisfied_in_scope(), "(is_satisfied_in_scope)");
+                if mode.is_constant() {
+                    assert_scope!(6, 0, 0, 0);
+                }
+            });
+            Circuit::reset();
+        }
+    }
+
+    fn check_inverse_of_zero(name: &str, mode: Mode) {
+        let candidate = Emulated::<Fq, Circuit>::new(mode, Fq::zero());
+
+        Circuit::scope(name, || {
+            let _ = candidate.inverse();
+            assert!(!Circuit::is_satisfied_in_scope(), "(!is_satisfied_in_scope)");
+        });
+        Circuit::reset();
+    }
+
+    #[test]
+    fn test_inverse() {
+        let mut rng = TestRng::default();
+
+        check_inverse("Constant", Mode::Constant, &mut rng);
+        check_inverse("Public", Mode::Public, &mut rng);
+        check_inverse("Private", Mode::Private, &mut rng);
+    }
+
+    #[test]
+    fn test_inverse_of_zero_fails() {
+        check_inverse_of_zero("Public", Mode::Public);
+        check_inverse_of_zero("Private", Mode::Private);
+    }
+}
@@ -0,0 +1,151 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+#![forbid(unsafe_code)]
+#![allow(clippy::too_many_arguments)]
+#![cfg_attr(test, allow(clippy::assertions_on_result_states))]
+
+mod helpers;
+
+pub mod add;
+pub mod inverse;
+pub mod mul;
+pub mod sub;
+
+#[cfg(test)]
+use console::{TestRng, Uniform};
+
+use snarkvm_circuit_environment::prelude::*;
+use snarkvm_circuit_types_field::Field;
+use snarkvm_utilities::BigInteger;
+
+use core::marker::PhantomData;
+use num_bigint::{BigInt, BigUint};
+
+/// The number of bits in each limb of an emulated field element.
+const LIMB_SIZE_IN_BITS: usize = 64;
+
+/// An element of the prime field `F`, emulated in the base field of the environment `E`.
+///
+/// The element is represented as 64-bit limbs in little-endian order, using as many limbs as
+/// are needed to hold the modulus of `F`. Each limb is range-checked when it is allocated,
+/// so the represented integer is less than `2^(64 * num_limbs)`, but it is not necessarily
+/// reduced modulo `F`. The value is reduced modulo `F` when it is ejected.
+#[derive(Clone)]
+pub struct Emulated<F: PrimeField, E: Environment> {
+    /// The 64-bit limbs of the element, in little-endian order.
+    limbs: Vec<Field<E>>,
+    /// PhantomData.
+    _phantom: PhantomData<F>,
+}
+
+impl<F: PrimeField, E: Environment> Inject for Emulated<F, E> {
+    type Primitive = F;
+
+    /// Initializes an emulated field element from a primitive element of the field `F`.
+    fn new(mode: Mode, value: Self::Primitive) -> Self {
+        let value = value.to_bigint().to_biguint();
+        match mode.is_constant() {
+            true => Self::constant_from_integer(&value),
+            false => Self::from_limbs(Self::new_limbs(mode, &value, Self::num_limbs() * LIMB_SIZE_IN_BITS)),
+        }
+    }
+}
+
+impl<F: PrimeField, E: Environment> Eject for Emulated<F, E> {
+    type Primitive = F;
+
+    /// Ejects the mode of the emulated field element.
+    fn eject_mode(&self) -> Mode {
+        Mode::combine(Mode::Constant, self.limbs.iter().map(Eject::eject_mode))
+    }
+
+    /// Ejects the emulated field element as a primitive element of the field `F`.
+    fn eject_value(&self) -> Self::Primitive {
+        // Reduce the integer value modulo `F`.
+        let value = self.integer_value() % Self::modulus();
+        match F::from_str(&value.to_string()) {
+            Ok(value) => value,
+            Err(error) => E::halt(format!("Failed to eject an emulated field element: {error}")),
+        }
+    }
+}
+
+impl<F: PrimeField, E: Environment> Debug for Emulated<F, E> {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        Display::fmt(self, f)
+    }
+}
+
+impl<F: PrimeField, E: Environment> Display for Emulated<F, E> {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        write!(f, "{}.{}", self.eject_value(), self.eject_mode())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_circuit_environment::{assert_scope, Circuit};
+
+    type Fq = snarkvm_curves::bls12_377::Fq;
+
+    const ITERATIONS: u64 = 100;
+
+    fn check_new(name: &str, mode: Mode, num_constants: u64, num_public: u64, num_private: u64, num_constraints: u64) {
+        let rng = &mut TestRng::default();
+
+        for _ in 0..ITERATIONS {
+            let expected: Fq = Uniform::rand(rng);
+
+            Circuit::scope(name, || {
+                let candidate = Emulated::<Fq, Circuit>::new(mode, expected);
+                assert_eq!(expected, candidate.eject_value());
+                assert_eq!(mode, candidate.eject_mode());
+                assert_scope!(num_constants, num_public, num_private, num_constraints);
+            });
+            Circuit::reset();
+        }
+    }
+
+    #[test]
+    fn test_new_constant() {
+        // A 377-bit field element is represented as 6 limbs.
+        check_new("Constant", Mode::Constant, 6, 0, 0, 0);
+    }
+
+    #[test]
+    fn test_new_public() {
+        // Each limb is range-checked to 64 bits.
+        check_new("Public", Mode::Public, 0, 6, 384, 390);
+    }
+
+    #[test]
+    fn test_new_private() {
+        // Each limb is range-checked to 64 bits.
+        check_new("Private", Mode::Private, 0, 0, 390, 390);
+    }
+
+    #[test]
+    fn test_display() {
+        let rng = &mut TestRng::default();
+
+        for mode in [Mode::Constant, Mode::Public, Mode::Private] {
+            let expected: Fq = Uniform::rand(rng);
+            let candidate = Emulated::<Fq, Circuit>::new(mode, expected);
+            assert_eq!(format!("{expected}.{mode}"), format!("{candidate}"));
+        }
+        Circuit::reset();
+    }
+}
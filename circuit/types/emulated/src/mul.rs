@@ -0,0 +1,172 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+use super::*;
+
+impl<F: PrimeField, E: Environment> Mul<Emulated<F, E>> for Emulated<F, E> {
+    type Output = Emulated<F, E>;
+
+    fn mul(self, other: Emulated<F, E>) -> Self::Output {
+        &self * &other
+    }
+}
+
+impl<F: PrimeField, E: Environment> Mul<&Emulated<F, E>> for Emulated<F, E> {
+    type Output = Emulated<F, E>;
+
+    fn mul(self, other: &Emulated<F, E>) -> Self::Output {
+        &self * other
+    }
+}
+
+impl<F: PrimeField, E: Environment> Mul<Emulated<F, E>> for &Emulated<F, E> {
+    type Output = Emulated<F, E>;
+
+    fn mul(self, other: Emulated<F, E>) -> Self::Output {
+        self * &other
+    }
+}
+
+impl<F: PrimeField, E: Environment> Mul<&Emulated<F, E>> for &Emulated<F, E> {
+    type Output = Emulated<F, E>;
+
+    fn mul(self, other: &Emulated<F, E>) -> Self::Output {
+        let num_limbs = Emulated::<F, E>::num_limbs();
+
+        // Compute the columns of the schoolbook product, without propagating the carries.
+        let mut columns = vec![(Field::<E>::zero(), BigInt::default()); 2 * num_limbs - 1];
+        for (i, (a, a_value)) in self.columns().into_iter().enumerate() {
+            for (j, (b, b_value)) in other.columns().into_iter().enumerate() {
+                columns[i + j].0 += &a * &b;
+                columns[i + j].1 += &a_value * &b_value;
+            }
+        }
+
+        // Each operand is less than `2^(64 * num_limbs)`, and each column is a sum of at most
+        // `num_limbs` products of two limbs, each of which is less than `2^64`.
+        let max_limb = (BigUint::from(1u8) << LIMB_SIZE_IN_BITS) - 1u8;
+        let max_operand = (BigUint::from(1u8) << (num_limbs * LIMB_SIZE_IN_BITS)) - 1u8;
+        let max_column = &max_limb * &max_limb * num_limbs;
+        Emulated::reduce(columns, &(&max_operand * &max_operand), &max_column)
+    }
+}
+
+impl<F: PrimeField, E: Environment> MulAssign<Emulated<F, E>> for Emulated<F, E> {
+    fn mul_assign(&mut self, other: Emulated<F, E>) {
+        *self *= &other;
+    }
+}
+
+impl<F: PrimeField, E: Environment> MulAssign<&Emulated<F, E>> for Emulated<F, E> {
+    fn mul_assign(&mut self, other: &Emulated<F, E>) {
+        *self = &*self * other;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_circuit_environment::{assert_scope, Circuit};
+
+    type Fq = snarkvm_curves::bls12_377::Fq;
+
+    const ITERATIONS: u64 = 100;
+
+    fn check_mul(name: &str, expected: &Fq, a: &Emulated<Fq, Circuit>, b: &Emulated<Fq, Circuit>) {
+        Circuit::scope(name, || {
+            let candidate = a * b;
+            assert_eq!(*expected, candidate.eject_value(), "({} * {})", a.eject_value(), b.eject_value());
+            assert!(Circuit::is_satisfied_in_scope(), "(is_satisfied_in_scope)");
+            if a.eject_mode().is_constant() && b.eject_mode().is_constant() {
+                assert_scope!(6, 0, 0, 0);
+            }
+        });
+    }
+
+    fn run_test(mode_a: Mode, mode_b: Mode) {
+        let mut rng = TestRng::default();
+
+        for i in 0..ITERATIONS {
+            let first: Fq = Uniform::rand(&mut rng);
+            let second: Fq = Uniform::rand(&mut rng);
+
+            let a = Emulated::<Fq, Circuit>::new(mode_a, first);
+            let b = Emulated::<Fq, Circuit>::new(mode_b, second);
+
+            let name = format!("Mul: a * b {i}");
+            check_mul(&name, &(first * second), &a, &b);
+
+            // Test identity.
+            let name = format!("Mul: a * 1 {i}");
+            let one = Emulated::<Fq, Circuit>::new(mode_b, Fq::one());
+            check_mul(&name, &first, &a, &one);
+
+            // Test zero.
+            let name = format!("Mul: 0 * b {i}");
+            let zero = Emulated::<Fq, Circuit>::new(mode_a, Fq::zero());
+            check_mul(&name, &Fq::zero(), &zero, &b);
+
+            // Test an unreduced operand.
+            let name = format!("Mul: (a * b) * b {i}");
+            let product = &a * &b;
+            check_mul(&name, &(first * second * second), &product, &b);
+
+            Circuit::reset();
+        }
+    }
+
+    #[test]
+    fn test_constant_times_constant() {
+        run_test(Mode::Constant, Mode::Constant);
+    }
+
+    #[test]
+    fn test_constant_times_public() {
+        run_test(Mode::Constant, Mode::Public);
+    }
+
+    #[test]
+    fn test_public_times_constant() {
+        run_test(Mode::Public, Mode::Constant);
+    }
+
+    #[test]
+    fn test_constant_times_private() {
+        run_test(Mode::Constant, Mode::Private);
+    }
+
+    #[test]
+    fn test_private_times_constant() {
+        run_test(Mode::Private, Mode::Constant);
+    }
+
+    #[test]
+    fn test_public_times_public() {
+        run_test(Mode::Public, Mode::Public);
+    }
+
+    #[test]
+    fn test_public_times_private() {
+        run_test(Mode::Public, Mode::Private);
+    }
+
+    #[test]
+    fn test_private_times_public() {
+        run_test(Mode::Private, Mode::Public);
+    }
+
+    #[test]
+    fn test_private_times_private() {
+        run_test(Mode::Private, Mode::Private);
+    }
+}
@@ -0,0 +1,184 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+use super::*;
+
+impl<F: PrimeField, E: Environment> Sub<Emulated<F, E>> for Emulated<F, E> {
+    type Output = Emulated<F, E>;
+
+    fn sub(self, other: Emulated<F, E>) -> Self::Output {
+        &self - &other
+    }
+}
+
+impl<F: PrimeField, E: Environment> Sub<&Emulated<F, E>> for Emulated<F, E> {
+    type Output = Emulated<F, E>;
+
+    fn sub(self, other: &Emulated<F, E>) -> Self::Output {
+        &self - other
+    }
+}
+
+impl<F: PrimeField, E: Environment> Sub<Emulated<F, E>> for &Emulated<F, E> {
+    type Output = Emulated<F, E>;
+
+    fn sub(self, other: Emulated<F, E>) -> Self::Output {
+        self - &other
+    }
+}
+
+impl<F: PrimeField, E: Environment> Sub<&Emulated<F, E>> for &Emulated<F, E> {
+    type Output = Emulated<F, E>;
+
+    fn sub(self, other: &Emulated<F, E>) -> Self::Output {
+        let num_limbs = Emulated::<F, E>::num_limbs();
+
+        // Compute `k * p`, the smallest multiple of `p` that is at least `2^(64 * num_limbs) - 1`,
+        // such that `a - b + k * p` is non-negative for any `b`.
+        let modulus = Emulated::<F, E>::modulus();
+        let max_operand = (BigUint::from(1u8) << (num_limbs * LIMB_SIZE_IN_BITS)) - 1u8;
+        let multiple = (&max_operand + &modulus - 1u8) / &modulus * &modulus;
+        let multiple_limbs = Emulated::<F, E>::to_u64_limbs(&multiple, num_limbs + 1);
+
+        // Subtract the limbs pairwise and add the limbs of `k * p`, without propagating the carries.
+        let mut columns: Vec<_> = self
+            .columns()
+            .into_iter()
+            .zip_eq(other.columns())
+            .zip(&multiple_limbs)
+            .map(|(((a, a_value), (b, b_value)), multiple_limb)| {
+                let multiple_limb_field =
+                    Field::constant(Emulated::<F, E>::to_base_field(&BigUint::from(*multiple_limb)));
+                (a - b + multiple_limb_field, a_value - b_value + BigInt::from(*multiple_limb))
+            })
+            .collect();
+        // Append the most significant limb of `k * p`.
+        let top_limb = multiple_limbs[num_limbs];
+        columns
+            .push((Field::constant(Emulated::<F, E>::to_base_field(&BigUint::from(top_limb))), BigInt::from(top_limb)));
+
+        // Each column is less than `2^65` in magnitude.
+        let max_column = BigUint::from(1u8) << (LIMB_SIZE_IN_BITS + 1);
+        Emulated::reduce(columns, &(max_operand + multiple), &max_column)
+    }
+}
+
+impl<F: PrimeField, E: Environment> SubAssign<Emulated<F, E>> for Emulated<F, E> {
+    fn sub_assign(&mut self, other: Emulated<F, E>) {
+        *self -= &other;
+    }
+}
+
+impl<F: PrimeField, E: Environment> SubAssign<&Emulated<F, E>> for Emulated<F, E> {
+    fn sub_assign(&mut self, other: &Emulated<F, E>) {
+        *self = &*self - other;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_circuit_environment::{assert_scope, Circuit};
+
+    type Fq = snarkvm_curves::bls12_377::Fq;
+
+    const ITERATIONS: u64 = 100;
+
+    fn check_sub(name: &str, expected: &Fq, a: &Emulated<Fq, Circuit>, b: &Emulated<Fq, Circuit>) {
+        Circuit::scope(name, || {
+            let candidate = a - b;
+            assert_eq!(*expected, candidate.eject_value(), "({} - {})", a.eject_value(), b.eject_value());
+            assert!(Circuit::is_satisfied_in_scope(), "(is_satisfied_in_scope)");
+            if a.eject_mode().is_constant() && b.eject_mode().is_constant() {
+                assert_scope!(6, 0, 0, 0);
+            }
+        });
+    }
+
+    fn run_test(mode_a: Mode, mode_b: Mode) {
+        let mut rng = TestRng::default();
+
+        for i in 0..ITERATIONS {
+            let first: Fq = Uniform::rand(&mut rng);
+            let second: Fq = Uniform::rand(&mut rng);
+
+            let a = Emulated::<Fq, Circuit>::new(mode_a, first);
+            let b = Emulated::<Fq, Circuit>::new(mode_b, second);
+
+            let name = format!("Sub: a - b {i}");
+            check_sub(&name, &(first - second), &a, &b);
+
+            // Test identity.
+            let name = format!("Sub: a - 0 {i}");
+            let zero = Emulated::<Fq, Circuit>::new(mode_b, Fq::zero());
+            check_sub(&name, &first, &a, &zero);
+
+            // Test negation.
+            let name = format!("Sub: 0 - b {i}");
+            let zero = Emulated::<Fq, Circuit>::new(mode_a, Fq::zero());
+            check_sub(&name, &(-second), &zero, &b);
+
+            // Test an unreduced operand.
+            let name = format!("Sub: a - (a + b) {i}");
+            let sum = &a + &b;
+            check_sub(&name, &(-second), &a, &sum);
+
+            Circuit::reset();
+        }
+    }
+
+    #[test]
+    fn test_constant_minus_constant() {
+        run_test(Mode::Constant, Mode::Constant);
+    }
+
+    #[test]
+    fn test_constant_minus_public() {
+        run_test(Mode::Constant, Mode::Public);
+    }
+
+    #[test]
+    fn test_public_minus_constant() {
+        run_test(Mode::Public, Mode::Constant);
+    }
+
+    #[test]
+    fn test_constant_minus_private() {
+        run_test(Mode::Constant, Mode::Private);
+    }
+
+    #[test]
+    fn test_private_minus_constant() {
+        run_test(Mode::Private, Mode::Constant);
+    }
+
+    #[test]
+    fn test_public_minus_public() {
+        run_test(Mode::Public, Mode::Public);
+    }
+
+    #[test]
+    fn test_public_minus_private() {
+        run_test(Mode::Public, Mode::Private);
+    }
+
+    #[test]
+    fn test_private_minus_public() {
+        run_test(Mode::Private, Mode::Public);
+    }
+
+    #[test]
+    fn test_private_minus_private() {
+        run_test(Mode::Private, Mode::Private);
+    }
+}
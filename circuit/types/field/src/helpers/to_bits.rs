@@ -34,20 +34,23 @@ impl<E: Environment> ToBits for &Field<E> {
     /// Outputs the unique, minimal little-endian bit representation of `self` *without* trailing zeros.
     fn write_bits_le(&self, vec: &mut Vec<Self::Boolean>) {
         // Compute the bits of the field value.
+        // Note: If common subexpression elimination is enabled, the bits of an identical field element are reused.
         let bits = self.bits_le.get_or_init(|| {
-            // Extract a non-unique little-endian bit representation of `self`.
-            let bits_le = self.to_non_unique_bits_le();
-
-            // Ensure the bit representation is unique.
-            {
-                // Retrieve the modulus & subtract by 1 as we'll check `bits_le` is less than or *equal* to this value.
-                // (For advanced users) BaseField::MODULUS - 1 is equivalent to -1 in the field.
-                let modulus_minus_one = -E::BaseField::one();
-                // Assert `bits_le <= (BaseField::MODULUS - 1)`, which is equivalent to `bits_le < BaseField::MODULUS`.
-                Boolean::assert_less_than_or_equal_constant(&bits_le, &modulus_minus_one.to_bits_le())
-            }
+            E::cse(("to_bits_le", 0), &self.linear_combination, || {
+                // Extract a non-unique little-endian bit representation of `self`.
+                let bits_le = self.to_non_unique_bits_le();
+
+                // Ensure the bit representation is unique.
+                {
+                    // Retrieve the modulus & subtract by 1 as we'll check `bits_le` is less than or *equal* to this value.
+                    // (For advanced users) BaseField::MODULUS - 1 is equivalent to -1 in the field.
+                    let modulus_minus_one = -E::BaseField::one();
+                    // Assert `bits_le <= (BaseField::MODULUS - 1)`, which is equivalent to `bits_le < BaseField::MODULUS`.
+                    Boolean::assert_less_than_or_equal_constant(&bits_le, &modulus_minus_one.to_bits_le())
+                }
 
-            bits_le
+                bits_le
+            })
         });
         // Extend the vector with the bits of the field value.
         vec.extend_from_slice(bits)
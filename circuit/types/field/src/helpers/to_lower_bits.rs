@@ -30,22 +30,25 @@ impl<E: Environment> ToLowerBits for Field<E> {
             ))
         }
 
-        // Construct a vector of `Boolean`s comprising the bits of the field value.
-        let bits = witness!(|self| self.to_bits_le().into_iter().take(k).collect::<Vec<_>>());
-
-        // Reconstruct the bits as a linear combination representing the original field value.
-        let mut accumulator = Field::zero();
-        let mut coefficient = Field::one();
-        for bit in &bits {
-            accumulator += Field::from_boolean(bit) * &coefficient;
-            coefficient = coefficient.double();
-        }
-
-        // Ensure value * 1 == (2^k * b_k + ... + 2^0 * b_0)
-        // and ensures that b_n, ..., b_{n-k} are all equal to zero.
-        E::assert_eq(self, accumulator);
-
-        bits
+        // Note: If common subexpression elimination is enabled, the bits of an identical field element are reused.
+        E::cse(("to_lower_bits_le", k), &self.linear_combination, || {
+            // Construct a vector of `Boolean`s comprising the bits of the field value.
+            let bits = witness!(|self| self.to_bits_le().into_iter().take(k).collect::<Vec<_>>());
+
+            // Reconstruct the bits as a linear combination representing the original field value.
+            let mut accumulator = Field::zero();
+            let mut coefficient = Field::one();
+            for bit in &bits {
+                accumulator += Field::from_boolean(bit) * &coefficient;
+                coefficient = coefficient.double();
+            }
+
+            // Ensure value * 1 == (2^k * b_k + ... + 2^0 * b_0)
+            // and ensures that b_n, ..., b_{n-k} are all equal to zero.
+            E::assert_eq(self, accumulator);
+
+            bits
+        })
     }
 
     ///
@@ -23,7 +23,9 @@ use crate::ViewKey;
 use snarkvm_console_network::prelude::*;
 use snarkvm_console_types::Field;
 
-#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+use core::hash::{Hash, Hasher};
+
+#[derive(Copy, Clone, Debug, Eq)]
 pub struct GraphKey<N: Network> {
     /// The graph key `sk_tag` := Hash(view_key || ctr).
     sk_tag: Field<N>,
@@ -35,3 +37,16 @@ impl<N: Network> GraphKey<N> {
         self.sk_tag
     }
 }
+
+impl<N: Network> PartialEq for GraphKey<N> {
+    /// Compares the graph keys in constant time.
+    fn eq(&self, other: &Self) -> bool {
+        self.sk_tag.ct_eq(&other.sk_tag).into()
+    }
+}
+
+impl<N: Network> Hash for GraphKey<N> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.sk_tag.hash(state);
+    }
+}
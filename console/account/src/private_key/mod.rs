@@ -23,9 +23,10 @@ mod sign;
 use snarkvm_console_network::prelude::*;
 use snarkvm_console_types::{Field, Scalar};
 
+use core::hash::{Hash, Hasher};
 use zeroize::Zeroize;
 
-#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash, Zeroize)]
+#[derive(Copy, Clone, Debug, Eq, Zeroize)]
 pub struct PrivateKey<N: Network> {
     /// The account seed that derives the full private key.
     seed: Field<N>,
@@ -58,3 +59,19 @@ impl<N: Network> PrivateKey<N> {
         self.r_sig
     }
 }
+
+impl<N: Network> PartialEq for PrivateKey<N> {
+    /// Compares the private keys in constant time.
+    fn eq(&self, other: &Self) -> bool {
+        let is_equal = self.seed.ct_eq(&other.seed) & self.sk_sig.ct_eq(&other.sk_sig) & self.r_sig.ct_eq(&other.r_sig);
+        is_equal.into()
+    }
+}
+
+impl<N: Network> Hash for PrivateKey<N> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.seed.hash(state);
+        self.sk_sig.hash(state);
+        self.r_sig.hash(state);
+    }
+}
@@ -26,10 +26,11 @@ use crate::PrivateKey;
 use snarkvm_console_network::prelude::*;
 use snarkvm_console_types::{Address, Scalar};
 
+use core::hash::{Hash, Hasher};
 use zeroize::Zeroize;
 
 /// The account view key used to decrypt records and ciphertext.
-#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash, Zeroize)]
+#[derive(Copy, Clone, Debug, Eq, Zeroize)]
 pub struct ViewKey<N: Network>(Scalar<N>);
 
 impl<N: Network> ViewKey<N> {
@@ -39,6 +40,19 @@ impl<N: Network> ViewKey<N> {
     }
 }
 
+impl<N: Network> PartialEq for ViewKey<N> {
+    /// Compares the view keys in constant time.
+    fn eq(&self, other: &Self) -> bool {
+        self.0.ct_eq(&other.0).into()
+    }
+}
+
+impl<N: Network> Hash for ViewKey<N> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.0.hash(state);
+    }
+}
+
 impl<N: Network> Deref for ViewKey<N> {
     type Target = Scalar<N>;
 
@@ -0,0 +1,60 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+use super::*;
+use snarkvm_utilities::{bits_from_bytes_le, bytes_from_bits_le};
+
+impl Hash for Blake2s {
+    type Input = bool;
+    type Output = Vec<bool>;
+
+    /// Returns the BLAKE2s hash of the given input as bits.
+    #[inline]
+    fn hash(&self, input: &[Self::Input]) -> Result<Self::Output> {
+        Ok(bits_from_bytes_le(&blake2s_native(&bytes_from_bits_le(input))).collect())
+    }
+}
+
+fn blake2s_native(preimage: &[u8]) -> [u8; 32] {
+    let mut output = [0u8; 32];
+    output.copy_from_slice(blake2s_simd::Params::new().hash_length(32).hash(preimage).as_bytes());
+    output
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// The test vectors from RFC 7693 (Appendix B) and the reference implementation.
+    const TEST_VECTORS: [(&str, &str); 2] = [
+        ("", "69217a3079908094e11121d042354a7c1f55b6482ca1a51e1b250dfd1ed0eef9"),
+        ("abc", "508c5e8c327c14e2e1a72ba34eeb452f37458b209ed63a294d999b4c86675982"),
+    ];
+
+    #[test]
+    fn test_blake2s_test_vectors() {
+        for (preimage, expected) in TEST_VECTORS {
+            let input = bits_from_bytes_le(preimage.as_bytes()).collect::<Vec<_>>();
+            let candidate = Blake2s.hash(&input).unwrap();
+            assert_eq!(expected, hex::encode(bytes_from_bits_le(&candidate)));
+        }
+    }
+
+    #[test]
+    fn test_blake2s_pads_partial_bytes() {
+        // An input that is not a multiple of 8 bits is padded with zeros to the next byte.
+        let input = [true, false, true];
+        let expected = bits_from_bytes_le(&blake2s_native(&[0b0000_0101])).collect::<Vec<_>>();
+        assert_eq!(expected, Blake2s.hash(&input).unwrap());
+    }
+}
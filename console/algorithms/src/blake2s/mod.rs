@@ -0,0 +1,26 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+mod hash;
+
+use crate::Hash;
+use snarkvm_console_types::environment::prelude::*;
+
+/// The BLAKE2s hash function with a 256-bit digest, as specified in RFC 7693.
+///
+/// The input bits are packed into bytes in little-endian bit order (padding the final byte with zeros),
+/// and the 256-bit digest is returned as bits in little-endian bit order per byte,
+/// matching the conventions of the Keccak family of hash functions.
+#[derive(Copy, Clone, Debug, Default, PartialEq)]
+pub struct Blake2s;
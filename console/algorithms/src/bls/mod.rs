@@ -0,0 +1,126 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+mod sign;
+mod verify;
+
+use snarkvm_curves::{
+    bls12_377::{Bls12_377, Fr, G1Affine, G2Affine},
+    AffineCurve,
+    HashToCurve,
+    PairingEngine,
+    ProjectiveCurve,
+};
+use snarkvm_fields::{One, Zero};
+
+/// The domain separation tag for hashing messages to G1, following the naming convention of RFC 9380.
+pub const BLS_DST: &[u8] = b"BLS_SIG_BLS12377G1_XMD:SHA-256_SVDW_RO_NUL_";
+
+/// A BLS signature over BLS12-377, in the minimal-signature-size variant,
+/// where signatures and message hashes are in G1 and public keys are in G2.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct BlsSignature {
+    /// The signature point, `private_key * H(message)`.
+    signature: G1Affine,
+}
+
+impl BlsSignature {
+    /// Initializes a BLS signature from the given point in G1.
+    pub const fn new(signature: G1Affine) -> Self {
+        Self { signature }
+    }
+
+    /// Returns the signature point.
+    pub const fn signature(&self) -> &G1Affine {
+        &self.signature
+    }
+
+    /// Returns the public key for the given private key, `private_key * G2`.
+    pub fn public_key(private_key: &Fr) -> G2Affine {
+        (G2Affine::prime_subgroup_generator() * *private_key).to_affine()
+    }
+
+    /// Returns the hash of the given message in G1.
+    pub fn hash_message(message: &[u8]) -> G1Affine {
+        G1Affine::hash_to_curve(message, BLS_DST)
+    }
+
+    /// Returns the sum of the given signatures, which verifies for the sum of their public keys
+    /// if every signature is on the same message.
+    pub fn aggregate(signatures: &[Self]) -> Self {
+        Self::new(
+            signatures
+                .iter()
+                .fold(<G1Affine as AffineCurve>::Projective::zero(), |sum, signature| {
+                    sum.add_mixed(&signature.signature)
+                })
+                .to_affine(),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_utilities::{TestRng, Uniform};
+
+    const ITERATIONS: usize = 10;
+
+    #[test]
+    fn test_sign_and_verify() {
+        let rng = &mut TestRng::default();
+
+        for i in 0..ITERATIONS {
+            let private_key = Fr::rand(rng);
+            let public_key = BlsSignature::public_key(&private_key);
+            let message = format!("Attestation for block {i}");
+
+            let signature = BlsSignature::sign(&private_key, message.as_bytes());
+            assert!(signature.verify(&public_key, message.as_bytes()));
+
+            // Ensure the signature does not verify for a different message or public key.
+            assert!(!signature.verify(&public_key, b"A different message"));
+            assert!(!signature.verify(&BlsSignature::public_key(&Fr::rand(rng)), message.as_bytes()));
+        }
+    }
+
+    #[test]
+    fn test_aggregate() {
+        let rng = &mut TestRng::default();
+        let message = b"Attestation for block 0";
+
+        let private_keys = (0..ITERATIONS).map(|_| Fr::rand(rng)).collect::<Vec<_>>();
+        let signatures = private_keys.iter().map(|key| BlsSignature::sign(key, message)).collect::<Vec<_>>();
+        let public_key = BlsSignature::public_key(&private_keys.iter().sum());
+
+        assert!(BlsSignature::aggregate(&signatures).verify(&public_key, message));
+        assert!(!BlsSignature::aggregate(&signatures[1..]).verify(&public_key, message));
+    }
+
+    #[test]
+    fn test_verify_rejects_invalid_points() {
+        let rng = &mut TestRng::default();
+        let private_key = Fr::rand(rng);
+        let public_key = BlsSignature::public_key(&private_key);
+        let message_hash = BlsSignature::hash_message(b"message");
+
+        // Ensure the identity does not verify, for either the signature or the public key.
+        assert!(!BlsSignature::new(G1Affine::zero()).verify_hashed(&public_key, &message_hash));
+        assert!(!BlsSignature::new(message_hash).verify_hashed(&G2Affine::zero(), &G1Affine::zero()));
+
+        // Ensure a point on the curve but outside of the prime-order subgroup does not verify.
+        let low_order_point = G1Affine::new(Zero::zero(), One::one(), false);
+        assert!(low_order_point.is_on_curve());
+        assert!(!BlsSignature::new(low_order_point).verify_hashed(&public_key, &message_hash));
+    }
+}
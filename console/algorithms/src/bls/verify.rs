@@ -0,0 +1,43 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+use super::*;
+
+impl BlsSignature {
+    /// Returns `true` if the signature is valid for the given public key and message.
+    pub fn verify(&self, public_key: &G2Affine, message: &[u8]) -> bool {
+        self.verify_hashed(public_key, &Self::hash_message(message))
+    }
+
+    /// Returns `true` if the signature is valid for the given public key and message hash, i.e.
+    ///     e(signature, G2) == e(message_hash, public_key)
+    pub fn verify_hashed(&self, public_key: &G2Affine, message_hash: &G1Affine) -> bool {
+        // Ensure the points are non-zero and in the prime-order subgroups.
+        let is_valid_g1 = |point: &G1Affine| {
+            !point.is_zero() && point.is_on_curve() && point.is_in_correct_subgroup_assuming_on_curve()
+        };
+        let is_valid_g2 = |point: &G2Affine| {
+            !point.is_zero() && point.is_on_curve() && point.is_in_correct_subgroup_assuming_on_curve()
+        };
+        if !is_valid_g1(&self.signature) || !is_valid_g1(message_hash) || !is_valid_g2(public_key) {
+            return false;
+        }
+
+        // Check e(signature, -G2) * e(message_hash, public_key) == 1, with a single final exponentiation.
+        Bls12_377::multi_pairing(&[self.signature, *message_hash], &[
+            -G2Affine::prime_subgroup_generator(),
+            *public_key,
+        ])
+        .is_one()
+    }
+}
@@ -22,6 +22,12 @@ pub use snarkvm_console_types::prelude::*;
 pub mod bhp;
 pub use bhp::{BHP, BHP1024, BHP256, BHP512, BHP768};
 
+mod bls;
+pub use bls::{BlsSignature, BLS_DST};
+
+mod blake2s;
+pub use blake2s::Blake2s;
+
 mod blake2xs;
 pub use blake2xs::Blake2Xs;
 
@@ -35,4 +41,7 @@ mod pedersen;
 pub use pedersen::{Pedersen, Pedersen128, Pedersen64};
 
 mod poseidon;
-pub use poseidon::{Poseidon, Poseidon2, Poseidon4, Poseidon8};
+pub use poseidon::{Poseidon, Poseidon2, Poseidon4, Poseidon8, PoseidonSponge};
+
+mod sha256;
+pub use sha256::Sha256;
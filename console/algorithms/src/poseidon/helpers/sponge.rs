@@ -29,7 +29,7 @@ use std::{ops::DerefMut, sync::Arc};
 ///
 /// [cos]: https://eprint.iacr.org/2019/1076
 #[derive(Clone, Debug)]
-pub struct PoseidonSponge<E: Environment, const RATE: usize, const CAPACITY: usize> {
+pub struct DuplexSponge<E: Environment, const RATE: usize, const CAPACITY: usize> {
     /// Sponge Parameters
     parameters: Arc<PoseidonParameters<E::Field, RATE, CAPACITY>>,
     /// Current sponge's state (current elements in the permutation block)
@@ -39,7 +39,7 @@ pub struct PoseidonSponge<E: Environment, const RATE: usize, const CAPACITY: usi
 }
 
 impl<E: Environment, const RATE: usize, const CAPACITY: usize> AlgebraicSponge<E, RATE, CAPACITY>
-    for PoseidonSponge<E, RATE, CAPACITY>
+    for DuplexSponge<E, RATE, CAPACITY>
 {
     type Parameters = Arc<PoseidonParameters<E::Field, RATE, CAPACITY>>;
 
@@ -98,7 +98,17 @@ impl<E: Environment, const RATE: usize, const CAPACITY: usize> AlgebraicSponge<E
     }
 }
 
-impl<E: Environment, const RATE: usize, const CAPACITY: usize> PoseidonSponge<E, RATE, CAPACITY> {
+impl<E: Environment, const RATE: usize, const CAPACITY: usize> DuplexSponge<E, RATE, CAPACITY> {
+    /// Initializes a new sponge, where the first capacity element is set to the given domain separator.
+    pub(in crate::poseidon) fn new_with_domain(
+        parameters: &Arc<PoseidonParameters<E::Field, RATE, CAPACITY>>,
+        domain: Field<E>,
+    ) -> Self {
+        let mut sponge = Self::new(parameters);
+        sponge.state[0] = domain;
+        sponge
+    }
+
     #[inline]
     fn apply_ark(&mut self, round_number: usize) {
         for (state_elem, ark_elem) in self.state.iter_mut().zip(&self.parameters.ark[round_number]) {
@@ -19,6 +19,9 @@ mod hash_many;
 mod hash_to_group;
 mod hash_to_scalar;
 mod prf;
+mod sponge;
+
+pub use sponge::PoseidonSponge;
 
 use crate::{poseidon::helpers::*, Elligator2};
 use snarkvm_console_types::prelude::*;
@@ -137,7 +140,7 @@ mod tests {
             for squeeze in 0..10 {
                 let iteration = format!("absorb_{absorb}_squeeze_{squeeze}");
 
-                let mut sponge = PoseidonSponge::<CurrentEnvironment, RATE, CAPACITY>::new(&parameters);
+                let mut sponge = DuplexSponge::<CurrentEnvironment, RATE, CAPACITY>::new(&parameters);
                 sponge.absorb(&vec![Field::<CurrentEnvironment>::from_u64(1237812u64); absorb]);
 
                 let next_absorb_index = if absorb % RATE != 0 || absorb == 0 { absorb % RATE } else { RATE };
@@ -0,0 +1,132 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+use super::*;
+
+/// A Poseidon duplex sponge, which absorbs and squeezes field elements across any number of calls,
+/// for building transcripts and commitments over variable-length inputs.
+///
+/// The capacity element is initialized to the domain separator of the Poseidon instance,
+/// which separates the sponge from `hash` and `hash_many` (where the capacity element is initialized to zero).
+#[derive(Clone, Debug)]
+pub struct PoseidonSponge<E: Environment, const RATE: usize> {
+    /// The underlying duplex sponge.
+    sponge: DuplexSponge<E, RATE, CAPACITY>,
+}
+
+impl<E: Environment, const RATE: usize> Poseidon<E, RATE> {
+    /// Returns a new sponge, initialized with the domain separator of this Poseidon instance.
+    pub fn sponge(&self) -> PoseidonSponge<E, RATE> {
+        PoseidonSponge { sponge: DuplexSponge::new_with_domain(&self.parameters, self.domain) }
+    }
+}
+
+impl<E: Environment, const RATE: usize> PoseidonSponge<E, RATE> {
+    /// Absorbs the given field elements into the sponge.
+    pub fn absorb(&mut self, input: &[Field<E>]) {
+        self.sponge.absorb(input)
+    }
+
+    /// Squeezes the given number of field elements from the sponge.
+    pub fn squeeze(&mut self, num_outputs: u16) -> Vec<Field<E>> {
+        self.sponge.squeeze(num_outputs).into_vec()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_console_types::environment::Console;
+
+    type CurrentEnvironment = Console;
+
+    const ITERATIONS: usize = 10;
+
+    fn sample_inputs(num_inputs: usize, rng: &mut TestRng) -> Vec<Field<CurrentEnvironment>> {
+        (0..num_inputs).map(|_| Uniform::rand(rng)).collect()
+    }
+
+    #[test]
+    fn test_sponge_split_absorb_and_squeeze() {
+        let poseidon = Poseidon4::<CurrentEnvironment>::setup("PoseidonSponge").unwrap();
+        let mut rng = TestRng::default();
+
+        for _ in 0..ITERATIONS {
+            for num_inputs in 0..10 {
+                let input = sample_inputs(num_inputs, &mut rng);
+
+                // Absorb the input all at once, and squeeze the output all at once.
+                let mut sponge = poseidon.sponge();
+                sponge.absorb(&input);
+                let expected = sponge.squeeze(7);
+
+                // Absorb the input one element at a time, and squeeze the output one element at a time.
+                let mut sponge = poseidon.sponge();
+                input.iter().for_each(|element| sponge.absorb(&[*element]));
+                let candidate = (0..7).flat_map(|_| sponge.squeeze(1)).collect::<Vec<_>>();
+
+                assert_eq!(expected, candidate);
+            }
+        }
+    }
+
+    #[test]
+    fn test_sponge_is_domain_separated() {
+        let poseidon = Poseidon4::<CurrentEnvironment>::setup("PoseidonSponge").unwrap();
+        let other = Poseidon4::<CurrentEnvironment>::setup("OtherSponge").unwrap();
+        let mut rng = TestRng::default();
+
+        for _ in 0..ITERATIONS {
+            let input = sample_inputs(4, &mut rng);
+
+            // Construct the preimage of `hash_many`, and absorb it into the sponge.
+            let mut preimage =
+                vec![poseidon.domain(), Field::from_u128(input.len() as u128), Field::zero(), Field::zero()];
+            preimage.extend_from_slice(&input);
+            let mut sponge = poseidon.sponge();
+            sponge.absorb(&preimage);
+
+            // Ensure the sponge differs from `hash_many` on the same preimage.
+            assert_ne!(poseidon.hash_many(&input, 2), sponge.squeeze(2));
+
+            // Ensure the sponge differs across domains.
+            let mut sponge = poseidon.sponge();
+            sponge.absorb(&input);
+            let mut other_sponge = other.sponge();
+            other_sponge.absorb(&input);
+            assert_ne!(sponge.squeeze(2), other_sponge.squeeze(2));
+        }
+    }
+
+    #[test]
+    fn test_sponge_absorb_after_squeeze() {
+        let poseidon = Poseidon2::<CurrentEnvironment>::setup("PoseidonSponge").unwrap();
+        let mut rng = TestRng::default();
+
+        for _ in 0..ITERATIONS {
+            let (first, second) = (sample_inputs(3, &mut rng), sample_inputs(3, &mut rng));
+
+            // Ensure the output depends on the squeezes made between absorbs.
+            let mut sponge = poseidon.sponge();
+            sponge.absorb(&first);
+            let _ = sponge.squeeze(1);
+            sponge.absorb(&second);
+            let expected = sponge.squeeze(2);
+
+            let mut sponge = poseidon.sponge();
+            sponge.absorb(&first);
+            sponge.absorb(&second);
+            assert_ne!(expected, sponge.squeeze(2));
+        }
+    }
+}
@@ -58,7 +58,7 @@ pub mod prelude {
     };
 
     pub use snarkvm_curves::{AffineCurve, MontgomeryParameters, ProjectiveCurve, TwistedEdwardsParameters};
-    pub use snarkvm_fields::{Field as _, PrimeField as _, SquareRootField as _, Zero as _};
+    pub use snarkvm_fields::{ConstantTimeEq as _, Field as _, PrimeField as _, SquareRootField as _, Zero as _};
     pub use snarkvm_utilities::{
         cfg_chunks,
         cfg_find,
@@ -73,14 +73,18 @@ pub mod prelude {
         has_duplicates,
         io::{Read, Result as IoResult, Write},
         DeserializeExt,
+        ErrorCode,
         FromBits as _,
         FromBytes,
+        FromBytesChecked,
         FromBytesDeserializer,
+        Migrate,
         TestRng,
         ToBits as _,
         ToBytes,
         ToBytesSerializer,
         Uniform,
+        Versioned,
     };
 
     pub use core::{
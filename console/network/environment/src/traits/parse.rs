@@ -32,6 +32,59 @@ pub fn convert_result<'a, O>(result: ParserResult<'a, O>, input: &'a str) -> Str
     }
 }
 
+/// A failure to parse a string, with the 1-indexed line and column at which parsing failed.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ParseError {
+    /// The line at which parsing failed.
+    pub line: usize,
+    /// The column at which parsing failed.
+    pub column: usize,
+    /// The reason parsing failed.
+    pub message: String,
+}
+
+impl ParseError {
+    /// Initializes a new parse error from the given `nom` error, for the given input.
+    pub fn from_nom(input: &str, error: NomErr<VerboseError<&str>>) -> Self {
+        match error {
+            NomErr::Incomplete(_) => Self::at(input, "", "Parsing failed to consume the entire input.".to_string()),
+            NomErr::Error(err) | NomErr::Failure(err) => {
+                // The first error is the innermost location at which parsing failed.
+                let remainder = err.errors.first().map(|(remainder, _)| *remainder).unwrap_or(input);
+                Self::at(input, remainder, convert_error(input, err))
+            }
+        }
+    }
+
+    /// Initializes a new parse error for input that was left unparsed in `remainder`.
+    pub fn from_remainder(input: &str, remainder: &str) -> Self {
+        Self::at(input, remainder, format!("Remaining invalid string is: \"{remainder}\""))
+    }
+
+    /// Initializes a new parse error at the start of `remainder`, which must be a suffix of `input`.
+    fn at(input: &str, remainder: &str, message: String) -> Self {
+        let offset = input.len().saturating_sub(remainder.len());
+        let consumed = input.get(..offset).unwrap_or(input);
+        let line = consumed.matches('\n').count() + 1;
+        let column = consumed.rsplit('\n').next().map_or(0, |line| line.chars().count()) + 1;
+        Self { line, column, message }
+    }
+}
+
+impl core::fmt::Display for ParseError {
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+        write!(f, "Failed to parse string at line {}, column {}. {}", self.line, self.column, self.message)
+    }
+}
+
+impl std::error::Error for ParseError {}
+
+impl snarkvm_utilities::ErrorCode for ParseError {
+    fn error_code(&self) -> u32 {
+        3000
+    }
+}
+
 /// Operations to parse a string literal into an object.
 pub trait Parser: core::fmt::Display + core::str::FromStr {
     /// Parses a string literal into an object.
@@ -39,3 +92,30 @@ pub trait Parser: core::fmt::Display + core::str::FromStr {
     where
         Self: Sized;
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use nom::{bytes::complete::tag, character::complete::digit1, sequence::pair};
+
+    fn parse_pair(string: &str) -> ParserResult<(&str, &str)> {
+        pair(tag("x = "), digit1)(string)
+    }
+
+    #[test]
+    fn test_parse_error_position() {
+        let input = "x = 1\ny = 2";
+        let (remainder, _) = parse_pair(input).unwrap();
+
+        let error = ParseError::from_remainder(input, remainder);
+        assert_eq!((error.line, error.column), (1, 6));
+
+        let error = ParseError::from_nom(&input[6..], parse_pair(&input[6..]).unwrap_err());
+        assert_eq!((error.line, error.column), (1, 1));
+
+        let input = "x = 1\nx = a";
+        let error = ParseError::from_nom(&input[6..], parse_pair(&input[6..]).unwrap_err());
+        assert_eq!((error.line, error.column), (1, 5));
+    }
+}
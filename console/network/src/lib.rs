@@ -58,7 +58,7 @@ pub type PoseidonMerkleTree<N, const DEPTH: u8> = MerkleTree<N, Poseidon4<N>, Po
 
 /// Helper types for the Varuna parameters.
 type Fq<N> = <<N as Environment>::PairingCurve as PairingEngine>::Fq;
-pub type FiatShamir<N> = PoseidonSponge<Fq<N>, 2, 1>;
+pub type FiatShamir<N> = <N as Network>::FiatShamir;
 pub type FiatShamirParameters<N> = <FiatShamir<N> as AlgebraicSponge<Fq<N>, 2>>::Parameters;
 
 /// Helper types for the Varuna proving and verifying key.
@@ -203,6 +203,10 @@ pub trait Network:
     /// The transition ID type.
     type TransitionID: Bech32ID<Field<Self>>;
 
+    /// The Fiat-Shamir sponge of the Varuna SNARK, which derives the challenges of the prover and verifier.
+    /// Mainnet and testnet use Poseidon, while a Keccak sponge lets the challenges be recomputed without Poseidon.
+    type FiatShamir: AlgebraicSponge<Fq<Self>, 2>;
+
     /// Returns the genesis block bytes.
     fn genesis_bytes() -> &'static [u8];
 
@@ -290,6 +294,9 @@ pub trait Network:
     /// Returns the BHP hash with an input hasher of 1024-bits.
     fn hash_bhp1024(input: &[bool]) -> Result<Field<Self>>;
 
+    /// Returns the BLAKE2s hash with a 256-bit output.
+    fn hash_blake2s(input: &[bool]) -> Result<Vec<bool>>;
+
     /// Returns the Keccak hash with a 256-bit output.
     fn hash_keccak256(input: &[bool]) -> Result<Vec<bool>>;
 
@@ -314,6 +321,9 @@ pub trait Network:
     /// Returns the Poseidon hash with an input rate of 8.
     fn hash_psd8(input: &[Field<Self>]) -> Result<Field<Self>>;
 
+    /// Returns the SHA-256 hash with a 256-bit output.
+    fn hash_sha256(input: &[bool]) -> Result<Vec<bool>>;
+
     /// Returns the SHA-3 hash with a 256-bit output.
     fn hash_sha3_256(input: &[bool]) -> Result<Vec<bool>>;
 
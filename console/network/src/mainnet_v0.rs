@@ -15,6 +15,7 @@
 use super::*;
 use snarkvm_console_algorithms::{
     Blake2Xs,
+    Blake2s,
     Keccak256,
     Keccak384,
     Keccak512,
@@ -23,6 +24,7 @@ use snarkvm_console_algorithms::{
     Poseidon2,
     Poseidon4,
     Poseidon8,
+    Sha256,
     Sha3_256,
     Sha3_384,
     Sha3_512,
@@ -122,6 +124,8 @@ impl Environment for MainnetV0 {
 impl Network for MainnetV0 {
     /// The block hash type.
     type BlockHash = AleoID<Field<Self>, { hrp2!("ab") }>;
+    /// The Fiat-Shamir sponge of the Varuna SNARK.
+    type FiatShamir = PoseidonSponge<Fq<Self>, 2, 1>;
     /// The ratification ID type.
     type RatificationID = AleoID<Field<Self>, { hrp2!("ar") }>;
     /// The state root type.
@@ -324,6 +328,11 @@ impl Network for MainnetV0 {
         BHP_1024.hash(input)
     }
 
+    /// Returns the BLAKE2s hash with a 256-bit output.
+    fn hash_blake2s(input: &[bool]) -> Result<Vec<bool>> {
+        Blake2s.hash(input)
+    }
+
     /// Returns the Keccak hash with a 256-bit output.
     fn hash_keccak256(input: &[bool]) -> Result<Vec<bool>> {
         Keccak256::default().hash(input)
@@ -364,6 +373,11 @@ impl Network for MainnetV0 {
         POSEIDON_8.hash(input)
     }
 
+    /// Returns the SHA-256 hash with a 256-bit output.
+    fn hash_sha256(input: &[bool]) -> Result<Vec<bool>> {
+        Sha256.hash(input)
+    }
+
     /// Returns the SHA-3 hash with a 256-bit output.
     fn hash_sha3_256(input: &[bool]) -> Result<Vec<bool>> {
         Sha3_256::default().hash(input)
@@ -16,6 +16,7 @@ use super::*;
 use crate::TRANSACTION_PREFIX;
 use snarkvm_console_algorithms::{
     Blake2Xs,
+    Blake2s,
     Keccak256,
     Keccak384,
     Keccak512,
@@ -24,6 +25,7 @@ use snarkvm_console_algorithms::{
     Poseidon2,
     Poseidon4,
     Poseidon8,
+    Sha256,
     Sha3_256,
     Sha3_384,
     Sha3_512,
@@ -121,6 +123,8 @@ impl Environment for TestnetV0 {
 impl Network for TestnetV0 {
     /// The block hash type.
     type BlockHash = AleoID<Field<Self>, { hrp2!("ab") }>;
+    /// The Fiat-Shamir sponge of the Varuna SNARK.
+    type FiatShamir = PoseidonSponge<Fq<Self>, 2, 1>;
     /// The ratification ID type.
     type RatificationID = AleoID<Field<Self>, { hrp2!("ar") }>;
     /// The state root type.
@@ -315,6 +319,11 @@ impl Network for TestnetV0 {
         TESTNET_BHP_1024.hash(input)
     }
 
+    /// Returns the BLAKE2s hash with a 256-bit output.
+    fn hash_blake2s(input: &[bool]) -> Result<Vec<bool>> {
+        Blake2s.hash(input)
+    }
+
     /// Returns the Keccak hash with a 256-bit output.
     fn hash_keccak256(input: &[bool]) -> Result<Vec<bool>> {
         Keccak256::default().hash(input)
@@ -355,6 +364,11 @@ impl Network for TestnetV0 {
         TESTNET_POSEIDON_8.hash(input)
     }
 
+    /// Returns the SHA-256 hash with a 256-bit output.
+    fn hash_sha256(input: &[bool]) -> Result<Vec<bool>> {
+        Sha256.hash(input)
+    }
+
     /// Returns the SHA-3 hash with a 256-bit output.
     fn hash_sha3_256(input: &[bool]) -> Result<Vec<bool>> {
         Sha3_256::default().hash(input)
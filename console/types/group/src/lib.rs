@@ -80,11 +80,8 @@ impl<E: Environment> Group<E> {
 
     /// Returns `self * COFACTOR`.
     pub fn mul_by_cofactor(&self) -> Self {
-        // (For advanced users) The cofactor for this curve is `4`. Thus doubling is used to be performant.
-        // See unit tests below, which sanity check that this condition holds.
-        debug_assert!(E::Affine::cofactor().len() == 1 && E::Affine::cofactor()[0] == 4);
-
-        Self { group: self.group.double().double() }
+        // (For advanced users) The cofactor for this curve is `4`, which the curve clears by doubling.
+        Self { group: self.group.mul_by_cofactor() }
     }
 
     /// Returns `self / COFACTOR`.
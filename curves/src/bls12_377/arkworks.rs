@@ -0,0 +1,258 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Conversions between the BLS12-377 types of snarkVM and arkworks.
+//!
+//! Both libraries store field elements as Montgomery-form limbs with the same Montgomery radix,
+//! and curve points in the same Jacobian coordinates, so values are converted by copying limbs.
+//! Field elements are converted with [`ArkworksConversion`], as the orphan rules forbid `From`
+//! implementations between field types that are both defined outside of this crate.
+
+use crate::bls12_377::{Fq, Fq12, Fq2, Fq6, Fr, G1Affine, G1Projective, G2Affine, G2Projective};
+use snarkvm_fields::Zero;
+use snarkvm_utilities::biginteger::{BigInteger256, BigInteger384};
+
+use core::marker::PhantomData;
+
+/// A snarkVM type that shares its in-memory representation with an arkworks type.
+pub trait ArkworksConversion: Sized {
+    /// The arkworks counterpart of this type.
+    type Arkworks;
+
+    /// Returns the arkworks counterpart of this value.
+    fn to_arkworks(&self) -> Self::Arkworks;
+
+    /// Returns the snarkVM counterpart of the given arkworks value.
+    fn from_arkworks(value: &Self::Arkworks) -> Self;
+}
+
+macro_rules! impl_prime_field_conversion {
+    ($field: ty, $arkworks: ty, $bigint: ident) => {
+        impl ArkworksConversion for $field {
+            type Arkworks = $arkworks;
+
+            #[inline]
+            fn to_arkworks(&self) -> Self::Arkworks {
+                <$arkworks>::new_unchecked(ark_ff::BigInt((self.0).0))
+            }
+
+            #[inline]
+            fn from_arkworks(value: &Self::Arkworks) -> Self {
+                Self($bigint((value.0).0), PhantomData)
+            }
+        }
+    };
+}
+
+impl_prime_field_conversion!(Fr, ark_bls12_377::Fr, BigInteger256);
+impl_prime_field_conversion!(Fq, ark_bls12_377::Fq, BigInteger384);
+
+impl ArkworksConversion for Fq2 {
+    type Arkworks = ark_bls12_377::Fq2;
+
+    #[inline]
+    fn to_arkworks(&self) -> Self::Arkworks {
+        ark_bls12_377::Fq2::new(self.c0.to_arkworks(), self.c1.to_arkworks())
+    }
+
+    #[inline]
+    fn from_arkworks(value: &Self::Arkworks) -> Self {
+        Fq2::new(Fq::from_arkworks(&value.c0), Fq::from_arkworks(&value.c1))
+    }
+}
+
+impl ArkworksConversion for Fq6 {
+    type Arkworks = ark_bls12_377::Fq6;
+
+    #[inline]
+    fn to_arkworks(&self) -> Self::Arkworks {
+        ark_bls12_377::Fq6::new(self.c0.to_arkworks(), self.c1.to_arkworks(), self.c2.to_arkworks())
+    }
+
+    #[inline]
+    fn from_arkworks(value: &Self::Arkworks) -> Self {
+        Fq6::new(Fq2::from_arkworks(&value.c0), Fq2::from_arkworks(&value.c1), Fq2::from_arkworks(&value.c2))
+    }
+}
+
+impl ArkworksConversion for Fq12 {
+    type Arkworks = ark_bls12_377::Fq12;
+
+    #[inline]
+    fn to_arkworks(&self) -> Self::Arkworks {
+        ark_bls12_377::Fq12::new(self.c0.to_arkworks(), self.c1.to_arkworks())
+    }
+
+    #[inline]
+    fn from_arkworks(value: &Self::Arkworks) -> Self {
+        Fq12::new(Fq6::from_arkworks(&value.c0), Fq6::from_arkworks(&value.c1))
+    }
+}
+
+macro_rules! impl_curve_conversion {
+    ($affine: ty, $projective: ty, $ark_affine: ty, $ark_projective: ty) => {
+        impl ArkworksConversion for $affine {
+            type Arkworks = $ark_affine;
+
+            #[inline]
+            fn to_arkworks(&self) -> Self::Arkworks {
+                match self.infinity {
+                    true => <$ark_affine>::identity(),
+                    false => <$ark_affine>::new_unchecked(self.x.to_arkworks(), self.y.to_arkworks()),
+                }
+            }
+
+            #[inline]
+            fn from_arkworks(value: &Self::Arkworks) -> Self {
+                match value.infinity {
+                    true => Self::zero(),
+                    false => <$affine>::new(
+                        ArkworksConversion::from_arkworks(&value.x),
+                        ArkworksConversion::from_arkworks(&value.y),
+                        false,
+                    ),
+                }
+            }
+        }
+
+        impl ArkworksConversion for $projective {
+            type Arkworks = $ark_projective;
+
+            #[inline]
+            fn to_arkworks(&self) -> Self::Arkworks {
+                <$ark_projective>::new_unchecked(self.x.to_arkworks(), self.y.to_arkworks(), self.z.to_arkworks())
+            }
+
+            #[inline]
+            fn from_arkworks(value: &Self::Arkworks) -> Self {
+                <$projective>::new(
+                    ArkworksConversion::from_arkworks(&value.x),
+                    ArkworksConversion::from_arkworks(&value.y),
+                    ArkworksConversion::from_arkworks(&value.z),
+                )
+            }
+        }
+
+        impl From<$affine> for $ark_affine {
+            #[inline]
+            fn from(point: $affine) -> Self {
+                point.to_arkworks()
+            }
+        }
+
+        impl From<$ark_affine> for $affine {
+            #[inline]
+            fn from(point: $ark_affine) -> Self {
+                Self::from_arkworks(&point)
+            }
+        }
+
+        impl From<$projective> for $ark_projective {
+            #[inline]
+            fn from(point: $projective) -> Self {
+                point.to_arkworks()
+            }
+        }
+
+        impl From<$ark_projective> for $projective {
+            #[inline]
+            fn from(point: $ark_projective) -> Self {
+                Self::from_arkworks(&point)
+            }
+        }
+    };
+}
+
+impl_curve_conversion!(G1Affine, G1Projective, ark_bls12_377::G1Affine, ark_bls12_377::G1Projective);
+impl_curve_conversion!(G2Affine, G2Projective, ark_bls12_377::G2Affine, ark_bls12_377::G2Projective);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        bls12_377::Bls12_377,
+        traits::{AffineCurve, PairingEngine, ProjectiveCurve},
+    };
+    use snarkvm_fields::{Field, One};
+    use snarkvm_utilities::{TestRng, Uniform};
+
+    use ark_ec::{pairing::Pairing, AffineRepr, CurveGroup, Group};
+
+    const ITERATIONS: usize = 100;
+
+    #[test]
+    fn test_field_conversion() {
+        let rng = &mut TestRng::default();
+
+        assert_eq!(Fr::one().to_arkworks(), <ark_bls12_377::Fr as ark_ff::One>::one());
+        assert_eq!(Fq::one().to_arkworks(), <ark_bls12_377::Fq as ark_ff::One>::one());
+        assert_eq!(Fq12::one().to_arkworks(), <ark_bls12_377::Fq12 as ark_ff::One>::one());
+
+        for _ in 0..ITERATIONS {
+            let (a, b) = (Fr::rand(rng), Fr::rand(rng));
+            assert_eq!((a * b).to_arkworks(), a.to_arkworks() * b.to_arkworks());
+            assert_eq!(a, Fr::from_arkworks(&a.to_arkworks()));
+
+            let (a, b) = (Fq::rand(rng), Fq::rand(rng));
+            assert_eq!((a * b).to_arkworks(), a.to_arkworks() * b.to_arkworks());
+            assert_eq!(a, Fq::from_arkworks(&a.to_arkworks()));
+
+            // Ensure the towers use the same non-residues.
+            let (a, b) = (Fq12::rand(rng), Fq12::rand(rng));
+            assert_eq!((a * b).to_arkworks(), a.to_arkworks() * b.to_arkworks());
+            assert_eq!(a.inverse().unwrap().to_arkworks(), ark_ff::Field::inverse(&a.to_arkworks()).unwrap());
+            assert_eq!(a, Fq12::from_arkworks(&a.to_arkworks()));
+        }
+    }
+
+    #[test]
+    fn test_curve_conversion() {
+        let rng = &mut TestRng::default();
+
+        assert_eq!(
+            ark_bls12_377::G1Affine::from(G1Affine::prime_subgroup_generator()),
+            ark_bls12_377::G1Affine::generator()
+        );
+        assert_eq!(
+            ark_bls12_377::G2Affine::from(G2Affine::prime_subgroup_generator()),
+            ark_bls12_377::G2Affine::generator()
+        );
+
+        for _ in 0..ITERATIONS {
+            let point = G1Projective::rand(rng);
+            let scalar = Fr::rand(rng);
+            let ark_point = ark_bls12_377::G1Projective::from(point);
+            assert_eq!(point.to_affine().to_arkworks(), ark_point.into_affine());
+            assert_eq!(point * scalar, G1Projective::from(ark_point * scalar.to_arkworks()));
+
+            let point = G2Projective::rand(rng);
+            let ark_point = ark_bls12_377::G2Projective::from(point);
+            assert_eq!(point.to_affine().to_arkworks(), ark_point.into_affine());
+            assert_eq!(point.double(), G2Projective::from(ark_point.double()));
+        }
+
+        // Ensure the point at infinity is mapped to the point at infinity.
+        assert!(G1Affine::zero().to_arkworks().is_zero());
+        assert!(G1Affine::from(ark_bls12_377::G1Affine::identity()).is_zero());
+        assert!(G2Projective::from(ark_bls12_377::G2Projective::from(G2Projective::zero())).is_zero());
+    }
+
+    #[test]
+    fn test_pairing_conversion() {
+        let rng = &mut TestRng::default();
+        let (p, q) = (G1Projective::rand(rng).to_affine(), G2Projective::rand(rng).to_affine());
+        let expected = ark_bls12_377::Bls12_377::pairing(p.to_arkworks(), q.to_arkworks()).0;
+        assert_eq!(Bls12_377::pairing(p, q).to_arkworks(), expected);
+    }
+}
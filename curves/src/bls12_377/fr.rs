@@ -55,6 +55,19 @@ impl Fp256Parameters for FrParameters {}
 impl FftParameters for FrParameters {
     type BigInteger = BigInteger;
 
+    /// LARGE_SUBGROUP_ROOT_OF_UNITY = GENERATOR^((MODULUS - 1) / (2^47 * 3))
+    /// = 4745010758872139845238200295841730218141082559516036141034422680643841032105
+    /// Its cube is TWO_ADIC_ROOT_OF_UNITY, so the radix-2 roots of unity are unchanged.
+    /// Encoded in Montgomery form, the value is
+    /// (4745010758872139845238200295841730218141082559516036141034422680643841032105 * R % q) =
+    /// 6305670270485671394103200713230422010059347173612490824610048904823110729716
+    #[rustfmt::skip]
+    const LARGE_SUBGROUP_ROOT_OF_UNITY: Option<BigInteger> = Some(BigInteger([
+        16312050644684472308u64,
+        16226885886700552844u64,
+        8981803609415491252u64,
+        1004551230217910552u64,
+    ]));
     #[rustfmt::skip]
     const POWERS_OF_ROOTS_OF_UNITY: &'static [BigInteger] = &[
         BigInteger([12646347781564978760, 6783048705277173164, 268534165941069093, 1121515446318641358]),
@@ -105,6 +118,10 @@ impl FftParameters for FrParameters {
         BigInteger([5461406015399410446, 5014654494648953692, 8156709087178280082, 1299557346046566890]),
     ];
     #[rustfmt::skip]
+    const SMALL_SUBGROUP_BASE: Option<u32> = Some(3);
+    #[rustfmt::skip]
+    const SMALL_SUBGROUP_BASE_ADICITY: Option<u32> = Some(1);
+    #[rustfmt::skip]
     const TWO_ADICITY: u32 = 47;
     /// TWO_ADIC_ROOT_OF_UNITY = 8065159656716812877374967518403273466521432693661810619979959746626482506078
     /// Encoded in Montgomery form, the value is
@@ -15,20 +15,17 @@
 use snarkvm_fields::{field, Field, One, PrimeField, Zero};
 use snarkvm_utilities::{
     biginteger::{BigInteger256, BigInteger384},
-    BigInteger,
     BitIteratorBE,
 };
 
 use crate::{
     bls12_377::{Fq, Fr},
-    templates::bls12::Bls12Parameters,
+    templates::{bls12::Bls12Parameters, short_weierstrass_jacobian::glv::glv_mul},
     traits::{ModelParameters, ShortWeierstrassParameters},
     AffineCurve,
     ProjectiveCurve,
 };
 
-use std::ops::Neg;
-
 #[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
 pub struct Bls12_377G1Parameters;
 
@@ -115,103 +112,11 @@ impl ShortWeierstrassParameters for Bls12_377G1Parameters {
         p: crate::templates::short_weierstrass_jacobian::Projective<Self>,
         by: Self::ScalarField,
     ) -> crate::templates::short_weierstrass_jacobian::Projective<Self> {
-        type ScalarBigInt = <Fr as PrimeField>::BigInteger;
-
-        /// The scalar multiplication window size.
-        const GLV_WINDOW_SIZE: usize = 4;
-
-        /// The table size, used for w-ary NAF recoding.
-        const TABLE_SIZE: i64 = 1 << (GLV_WINDOW_SIZE + 1);
-        const HALF_TABLE_SIZE: i64 = 1 << (GLV_WINDOW_SIZE);
-        const MASK_FOR_MOD_TABLE_SIZE: u64 = (TABLE_SIZE as u64) - 1;
-        /// The GLV table length.
-        const L: usize = 1 << (GLV_WINDOW_SIZE - 1);
-
-        let decomposition = by.decompose(&Self::Q1, &Self::Q2, Self::B1, Self::B2, Self::R128, &Self::HALF_R);
-
-        // Prepare tables.
-        let mut t_1 = Vec::with_capacity(L);
-        let double = crate::templates::short_weierstrass_jacobian::Affine::<Self>::from(p.double());
-        t_1.push(p);
-        for i in 1..L {
-            t_1.push(t_1[i - 1].add_mixed(&double));
-        }
-        let t_1 =
-            crate::templates::short_weierstrass_jacobian::Projective::<Self>::batch_normalization_into_affine(t_1);
-
-        let t_2 = t_1.iter().copied().map(Self::glv_endomorphism).collect::<Vec<_>>();
-
-        let mod_signed = |d| {
-            let d_mod_window_size = i64::try_from(d & MASK_FOR_MOD_TABLE_SIZE).unwrap();
-            if d_mod_window_size >= HALF_TABLE_SIZE { d_mod_window_size - TABLE_SIZE } else { d_mod_window_size }
-        };
-        let to_wnaf = |e: Self::ScalarField| -> Vec<i32> {
-            let mut naf = vec![];
-            let mut e = e.to_bigint();
-            while !e.is_zero() {
-                let next = if e.is_odd() {
-                    let naf_sign = mod_signed(e.as_ref()[0]);
-                    if naf_sign < 0 {
-                        e.add_nocarry(&ScalarBigInt::from(-naf_sign as u64));
-                    } else {
-                        e.sub_noborrow(&ScalarBigInt::from(naf_sign as u64));
-                    }
-                    naf_sign.try_into().unwrap()
-                } else {
-                    0
-                };
-                naf.push(next);
-                e.div2();
-            }
-
-            naf
-        };
-
-        let wnaf = |k1: Self::ScalarField, k2: Self::ScalarField, s1: bool, s2: bool| -> (Vec<i32>, Vec<i32>) {
-            let mut wnaf_1 = to_wnaf(k1);
-            let mut wnaf_2 = to_wnaf(k2);
-
-            if s1 {
-                wnaf_1.iter_mut().for_each(|e| *e = -*e);
-            }
-            if !s2 {
-                wnaf_2.iter_mut().for_each(|e| *e = -*e);
-            }
-
-            (wnaf_1, wnaf_2)
-        };
-
-        let naf_add = |table: &[crate::templates::short_weierstrass_jacobian::Affine<Self>],
-                       naf: i32,
-                       acc: &mut crate::templates::short_weierstrass_jacobian::Projective<Self>| {
-            if naf != 0 {
-                let mut p_1 = table[(naf.abs() >> 1) as usize];
-                if naf < 0 {
-                    p_1 = p_1.neg();
-                }
-                acc.add_assign_mixed(&p_1);
-            }
-        };
-
-        // Recode scalars.
-        let (naf_1, naf_2) = wnaf(decomposition.0, decomposition.1, decomposition.2, decomposition.3);
-        let max_len = naf_1.len().max(naf_2.len());
-        let mut acc = crate::templates::short_weierstrass_jacobian::Projective::<Self>::zero();
-        for i in (0..max_len).rev() {
-            if i < naf_1.len() {
-                naf_add(&t_1, naf_1[i], &mut acc)
-            }
-
-            if i < naf_2.len() {
-                naf_add(&t_2, naf_2[i], &mut acc)
-            }
-
-            if i != 0 {
-                acc.double_in_place();
-            }
+        match by.decompose(&Self::Q1, &Self::Q2, Self::B1, Self::B2, Self::R128, &Self::HALF_R) {
+            Some(decomposition) => glv_mul(p, decomposition),
+            // Fall back to double-and-add if the scalar could not be decomposed.
+            None => p.to_affine().mul_bits(BitIteratorBE::new_without_leading_zeros(by.to_bigint())),
         }
-
-        acc
     }
 }
 
@@ -279,4 +184,37 @@ mod tests {
             }
         }
     }
+
+    #[test]
+    fn test_glv_decomposition() {
+        let rng = &mut TestRng::default();
+
+        // Ensure `lambda = B1` is the eigenvalue of the GLV endomorphism.
+        let lambda = Bls12_377G1Parameters::B1;
+        let generator = G1Affine::prime_subgroup_generator();
+        assert_eq!(
+            Bls12_377G1Parameters::glv_endomorphism(generator),
+            generator.mul_bits(BitIteratorBE::new(lambda.to_bigint())).to_affine()
+        );
+
+        let scalars = [Fr::zero(), Fr::one(), -Fr::one(), lambda, -lambda, Bls12_377G1Parameters::R128];
+        for k in scalars.into_iter().chain((0..1000).map(|_| Fr::rand(rng))) {
+            let decomposition = k
+                .decompose(
+                    &Bls12_377G1Parameters::Q1,
+                    &Bls12_377G1Parameters::Q2,
+                    Bls12_377G1Parameters::B1,
+                    Bls12_377G1Parameters::B2,
+                    Bls12_377G1Parameters::R128,
+                    &Bls12_377G1Parameters::HALF_R,
+                )
+                .unwrap();
+            assert_eq!(decomposition.recompose(lambda), k);
+            assert!(decomposition.k1 <= Bls12_377G1Parameters::R128);
+            assert!(decomposition.k2 <= Bls12_377G1Parameters::R128);
+
+            let p = G1Affine::rand(rng);
+            assert_eq!(p.to_projective() * k, p.mul_bits(BitIteratorBE::new(k.to_bigint())));
+        }
+    }
 }
@@ -15,19 +15,17 @@
 use snarkvm_fields::{field, Field, PrimeField, Zero};
 use snarkvm_utilities::{
     biginteger::{BigInteger256, BigInteger384},
-    BigInteger,
     BitIteratorBE,
 };
 
 use crate::{
-    bls12_377::{g1::Bls12_377G1Parameters, Fq, Fq2, Fr},
+    bls12_377::{g1::Bls12_377G1Parameters, Bls12_377Parameters, Fq, Fq2, Fr},
+    templates::bls12::Bls12Parameters,
     traits::{ModelParameters, ShortWeierstrassParameters},
     AffineCurve,
     ProjectiveCurve,
 };
 
-use std::ops::Neg;
-
 #[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
 pub struct Bls12_377G2Parameters;
 
@@ -129,103 +127,57 @@ impl ShortWeierstrassParameters for Bls12_377G2Parameters {
         p
     }
 
+    fn psi_endomorphism(
+        p: crate::templates::short_weierstrass_jacobian::Affine<Self>,
+    ) -> Option<crate::templates::short_weierstrass_jacobian::Affine<Self>> {
+        if p.is_zero() {
+            return Some(p);
+        }
+        // psi(x, y) = (conj(x) * PSI_COEFF_X, conj(y) * PSI_COEFF_Y)
+        let (mut x, mut y) = (p.x, p.y);
+        x.frobenius_map(1);
+        y.frobenius_map(1);
+        x.mul_by_fp(&PSI_COEFF_X);
+        y.mul_by_fp(&PSI_COEFF_Y);
+        Some(crate::templates::short_weierstrass_jacobian::Affine::new(x, y, false))
+    }
+
     fn mul_projective(
         p: crate::templates::short_weierstrass_jacobian::Projective<Self>,
         by: Self::ScalarField,
     ) -> crate::templates::short_weierstrass_jacobian::Projective<Self> {
-        type ScalarBigInt = <Fr as PrimeField>::BigInteger;
-
-        /// The scalar multiplication window size.
-        const GLV_WINDOW_SIZE: usize = 4;
+        /// The number of digits in the decomposition of a scalar.
+        const NUM_DIGITS: usize = 4;
+        /// The bit size of each digit.
+        const DIGIT_BITS: usize = 64;
 
-        /// The table size, used for w-ary NAF recoding.
-        const TABLE_SIZE: i64 = 1 << (GLV_WINDOW_SIZE + 1);
-        const HALF_TABLE_SIZE: i64 = 1 << (GLV_WINDOW_SIZE);
-        const MASK_FOR_MOD_TABLE_SIZE: u64 = (TABLE_SIZE as u64) - 1;
-        /// The GLV table length.
-        const L: usize = 1 << (GLV_WINDOW_SIZE - 1);
-
-        let decomposition = by.decompose(&Self::Q1, &Self::Q2, Self::B1, Self::B2, Self::R128, &Self::HALF_R);
-
-        // Prepare tables.
-        let mut t_1 = Vec::with_capacity(L);
-        let double = crate::templates::short_weierstrass_jacobian::Affine::<Self>::from(p.double());
-        t_1.push(p);
-        for i in 1..L {
-            t_1.push(t_1[i - 1].add_mixed(&double));
+        if p.is_zero() {
+            return p;
         }
-        let t_1 =
-            crate::templates::short_weierstrass_jacobian::Projective::<Self>::batch_normalization_into_affine(t_1);
 
-        let t_2 = t_1.iter().copied().map(Self::glv_endomorphism).collect::<Vec<_>>();
-
-        let mod_signed = |d| {
-            let d_mod_window_size = i64::try_from(d & MASK_FOR_MOD_TABLE_SIZE).unwrap();
-            if d_mod_window_size >= HALF_TABLE_SIZE { d_mod_window_size - TABLE_SIZE } else { d_mod_window_size }
-        };
-        let to_wnaf = |e: Self::ScalarField| -> Vec<i32> {
-            let mut naf = vec![];
-            let mut e = e.to_bigint();
-            while !e.is_zero() {
-                let next = if e.is_odd() {
-                    let naf_sign = mod_signed(e.as_ref()[0]);
-                    if naf_sign < 0 {
-                        e.add_nocarry(&ScalarBigInt::from(-naf_sign as u64));
-                    } else {
-                        e.sub_noborrow(&ScalarBigInt::from(naf_sign as u64));
-                    }
-                    naf_sign.try_into().unwrap()
-                } else {
-                    0
-                };
-                naf.push(next);
-                e.div2();
-            }
-
-            naf
-        };
-
-        let wnaf = |k1: Self::ScalarField, k2: Self::ScalarField, s1: bool, s2: bool| -> (Vec<i32>, Vec<i32>) {
-            let mut wnaf_1 = to_wnaf(k1);
-            let mut wnaf_2 = to_wnaf(k2);
-
-            if s1 {
-                wnaf_1.iter_mut().for_each(|e| *e = -*e);
-            }
-            if !s2 {
-                wnaf_2.iter_mut().for_each(|e| *e = -*e);
-            }
-
-            (wnaf_1, wnaf_2)
-        };
+        // Prepare the bases `(P, psi(P), psi^2(P), psi^3(P)) = (P, [x]P, [x^2]P, [x^3]P)`.
+        let mut bases = [p.to_affine(); NUM_DIGITS];
+        for i in 1..NUM_DIGITS {
+            // Note: This unwrap is safe, as G2 admits the `psi` endomorphism.
+            bases[i] = Self::psi_endomorphism(bases[i - 1]).unwrap();
+        }
 
-        let naf_add = |table: &[crate::templates::short_weierstrass_jacobian::Affine<Self>],
-                       naf: i32,
-                       acc: &mut crate::templates::short_weierstrass_jacobian::Projective<Self>| {
-            if naf != 0 {
-                let mut p_1 = table[(naf.abs() >> 1) as usize];
-                if naf < 0 {
-                    p_1 = p_1.neg();
-                }
-                acc.add_assign_mixed(&p_1);
-            }
-        };
+        // Prepare the table of all subset sums of the bases, indexed by a bitmask.
+        let mut table = vec![crate::templates::short_weierstrass_jacobian::Projective::<Self>::zero(); 1 << NUM_DIGITS];
+        for mask in 1..table.len() {
+            table[mask] = table[mask & (mask - 1)].add_mixed(&bases[mask.trailing_zeros() as usize]);
+        }
+        let table =
+            crate::templates::short_weierstrass_jacobian::Projective::<Self>::batch_normalization_into_affine(table);
 
-        // Recode scalars.
-        let (naf_1, naf_2) = wnaf(decomposition.0, decomposition.1, decomposition.2, decomposition.3);
-        let max_len = naf_1.len().max(naf_2.len());
+        // Compute `sum_i [k_i] psi^i(P)` with a joint double-and-add over the digits.
+        let digits = decompose_base_x(by);
         let mut acc = crate::templates::short_weierstrass_jacobian::Projective::<Self>::zero();
-        for i in (0..max_len).rev() {
-            if i < naf_1.len() {
-                naf_add(&t_1, naf_1[i], &mut acc)
-            }
-
-            if i < naf_2.len() {
-                naf_add(&t_2, naf_2[i], &mut acc)
-            }
-
-            if i != 0 {
-                acc.double_in_place();
+        for bit in (0..DIGIT_BITS).rev() {
+            acc.double_in_place();
+            let index = (0..NUM_DIGITS).fold(0, |index, i| index | ((((digits[i] >> bit) & 1) as usize) << i));
+            if index != 0 {
+                acc.add_assign_mixed(&table[index]);
             }
         }
 
@@ -233,6 +185,56 @@ impl ShortWeierstrassParameters for Bls12_377G2Parameters {
     }
 }
 
+/// Decomposes `scalar` into its four digits in base `x`, the BLS12-377 curve parameter,
+/// such that `scalar = k_0 + k_1 * x + k_2 * x^2 + k_3 * x^3`.
+fn decompose_base_x(scalar: Fr) -> [u64; 4] {
+    let x = Bls12_377Parameters::X[0] as u128;
+
+    let mut quotient = scalar.to_bigint().0;
+    let mut digits = [0u64; 4];
+    for digit in digits.iter_mut().take(3) {
+        let mut remainder = 0u128;
+        for limb in quotient.iter_mut().rev() {
+            let current = (remainder << 64) | *limb as u128;
+            *limb = (current / x) as u64;
+            remainder = current % x;
+        }
+        *digit = remainder as u64;
+    }
+    // The final quotient fits in a single limb, as `r < 2^64 * x^3`.
+    debug_assert!(quotient[1..].iter().all(|limb| *limb == 0));
+    digits[3] = quotient[0];
+    digits
+}
+
+/// PSI_COEFF_X = NONRESIDUE^((q - 1) / 6), where NONRESIDUE = -5
+///             = 80949648264912719408558363140637477264845294720710499478137287262712535938301461879813459410946
+const PSI_COEFF_X: Fq = field!(
+    Fq,
+    BigInteger384([
+        0x5892506da58478da,
+        0x133366940ac2a74b,
+        0x9b64a150cdf726cf,
+        0x5cc426090a9c587e,
+        0x5cf848adfdcd640c,
+        0x4702bf3ac02380,
+    ])
+);
+
+/// PSI_COEFF_Y = NONRESIDUE^((q - 1) / 4), where NONRESIDUE = -5
+///             = 216465761340224619389371505802605247630151569547285782856803747159100223055385581585702401816380679166954762214499
+const PSI_COEFF_Y: Fq = field!(
+    Fq,
+    BigInteger384([
+        0x982c13d9d084771f,
+        0xfd49de0c6da34a32,
+        0x61a530d183ab0e53,
+        0xdf8fe44106dd9879,
+        0x40f29b58d88472bc,
+        0x158723199046d5d,
+    ])
+);
+
 pub const G2_GENERATOR_X: Fq2 = field!(Fq2, G2_GENERATOR_X_C0, G2_GENERATOR_X_C1);
 pub const G2_GENERATOR_Y: Fq2 = field!(Fq2, G2_GENERATOR_Y_C0, G2_GENERATOR_Y_C1);
 
@@ -0,0 +1,359 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::{
+    bls12_377::{Bls12_377G1Parameters, Bls12_377G2Parameters, Fq, Fq2, G1Affine, G2Affine},
+    templates::short_weierstrass_jacobian::{Affine, Projective},
+    traits::{AffineCurve, HashToCurve, ProjectiveCurve, ShortWeierstrassParameters},
+};
+use snarkvm_fields::{Field, One, PrimeField, SquareRootField, Zero};
+use snarkvm_utilities::{bititerator::BitIteratorBE, BigInteger};
+
+use sha2::{Digest, Sha256};
+
+/// The output size of SHA-256, in bytes.
+const SHA256_OUTPUT_SIZE: usize = 32;
+/// The input block size of SHA-256, in bytes.
+const SHA256_BLOCK_SIZE: usize = 64;
+/// The number of uniform bytes reduced to each prime field element, `L = ceil((ceil(log2(q)) + k) / 8)` for `k = 128`.
+const BYTES_PER_ELEMENT: usize = 64;
+
+/// The scalar `h_eff = 3 * (x^2 - 1) * h_2` that clears the cofactor of G2, following RFC 9380, section 8.8.2,
+/// where `x` is the BLS12 parameter and `h_2` is the cofactor of G2. Multiplying by `h_eff` is equivalent to
+/// the endomorphism-based method of Budroni and Pintore.
+///
+/// H_EFF =
+/// 2184281851404362281745678896208201217529168937398889823122388972044920455466061193066642594716782535371685695462977913482353329054629394593897466405313745845350270778934587237043056714711040
+const G2_H_EFF: &[u64] = &[
+    0x1e34800000000000,
+    0xcf664765b0000003,
+    0x8e8e73ad8a538800,
+    0x78ba279637388559,
+    0xb85860aaaad29276,
+    0xf7ee7c4b03103b45,
+    0x8f6ade35a5c7d769,
+    0xa951764c46f4edd2,
+    0x53648d3d9502abfb,
+    0x001f60243677e306,
+];
+
+/// Expands `message` into `len_in_bytes` uniformly random bytes with SHA-256, following RFC 9380, section 5.3.1.
+/// Returns `None` if `len_in_bytes` exceeds the 8160 bytes supported by the construction.
+pub fn expand_message_xmd(message: &[u8], dst: &[u8], len_in_bytes: usize) -> Option<Vec<u8>> {
+    let ell = (len_in_bytes + SHA256_OUTPUT_SIZE - 1) / SHA256_OUTPUT_SIZE;
+    if ell > 255 || len_in_bytes > u16::MAX as usize {
+        return None;
+    }
+
+    // Hash an oversized domain separation tag, following RFC 9380, section 5.3.3.
+    let dst = match dst.len() > 255 {
+        true => Sha256::new().chain_update(b"H2C-OVERSIZE-DST-").chain_update(dst).finalize().to_vec(),
+        false => dst.to_vec(),
+    };
+    let dst_prime = [&dst[..], &[dst.len() as u8]].concat();
+
+    let b_0 = Sha256::new()
+        .chain_update([0u8; SHA256_BLOCK_SIZE])
+        .chain_update(message)
+        .chain_update((len_in_bytes as u16).to_be_bytes())
+        .chain_update([0u8])
+        .chain_update(&dst_prime)
+        .finalize();
+    let mut b_i = Sha256::new().chain_update(b_0).chain_update([1u8]).chain_update(&dst_prime).finalize();
+
+    let mut uniform_bytes = Vec::with_capacity(ell * SHA256_OUTPUT_SIZE);
+    uniform_bytes.extend_from_slice(&b_i);
+    for i in 2..=ell {
+        let chained: Vec<u8> = b_0.iter().zip(b_i.iter()).map(|(a, b)| a ^ b).collect();
+        b_i = Sha256::new().chain_update(chained).chain_update([i as u8]).chain_update(&dst_prime).finalize();
+        uniform_bytes.extend_from_slice(&b_i);
+    }
+    uniform_bytes.truncate(len_in_bytes);
+    Some(uniform_bytes)
+}
+
+/// A field that byte strings are hashed to, following RFC 9380, section 5.
+trait HashToField: SquareRootField {
+    /// The extension degree `m` of the field over its prime field.
+    const DEGREE: usize;
+
+    /// Reduces `DEGREE * BYTES_PER_ELEMENT` uniformly random bytes into a field element.
+    fn from_uniform_bytes(bytes: &[u8]) -> Self;
+
+    /// Returns the sign of the field element, following RFC 9380, section 4.1.
+    fn sgn0(&self) -> bool;
+}
+
+impl HashToField for Fq {
+    const DEGREE: usize = 1;
+
+    fn from_uniform_bytes(bytes: &[u8]) -> Self {
+        Fq::from_bytes_be_mod_order(bytes)
+    }
+
+    fn sgn0(&self) -> bool {
+        self.to_bigint().is_odd()
+    }
+}
+
+impl HashToField for Fq2 {
+    const DEGREE: usize = 2;
+
+    fn from_uniform_bytes(bytes: &[u8]) -> Self {
+        let (c0, c1) = bytes.split_at(BYTES_PER_ELEMENT);
+        Fq2::new(Fq::from_uniform_bytes(c0), Fq::from_uniform_bytes(c1))
+    }
+
+    fn sgn0(&self) -> bool {
+        self.c0.sgn0() || (self.c0.is_zero() && self.c1.sgn0())
+    }
+}
+
+/// Hashes `message` to `count` field elements, following RFC 9380, section 5.2.
+fn hash_to_field<F: HashToField>(message: &[u8], dst: &[u8], count: usize) -> Vec<F> {
+    let len_per_element = F::DEGREE * BYTES_PER_ELEMENT;
+    // Note: This unwrap is safe, as at most `2 * 2 * 64` bytes are requested.
+    let uniform_bytes = expand_message_xmd(message, dst, count * len_per_element).unwrap();
+    uniform_bytes.chunks_exact(len_per_element).map(F::from_uniform_bytes).collect()
+}
+
+/// The constants of the Shallue-van de Woestijne map for `y^2 = g(x) = x^3 + A * x + B`,
+/// following RFC 9380, section 6.6.1.
+struct SvdWConstants<F: HashToField> {
+    z: F,
+    c1: F,
+    c2: F,
+    c3: F,
+    c4: F,
+}
+
+impl<F: HashToField> SvdWConstants<F> {
+    /// Derives the constants for the curve with the given coefficients.
+    fn new(a: F, b: F) -> Self {
+        let g = |x: F| (x.square() + a) * x + b;
+        let is_square = |x: F| !x.legendre().is_qnr();
+        let (two, three, four) = (F::from(2u64), F::from(3u64), F::from(4u64));
+
+        // Select the first `Z` in the sequence 1, -1, 2, -2, ..., following RFC 9380, appendix H.1.
+        let z = (1u64..)
+            .flat_map(|counter| [F::from(counter), -F::from(counter)])
+            .find(|z| {
+                let g_z = g(*z);
+                let numerator = three * z.square() + four * a;
+                if g_z.is_zero() || numerator.is_zero() {
+                    return false;
+                }
+                let h_z = -numerator / (four * g_z);
+                is_square(h_z) && (is_square(g_z) || is_square(g(-*z / two)))
+            })
+            .unwrap();
+
+        let c1 = g(z);
+        let c2 = -z / two;
+        // Note: This unwrap is safe, as `-g(Z) * (3 * Z^2 + 4 * A)` is `4 * g(Z)^2 * h(Z)`, which is square.
+        let mut c3 = (-c1 * (three * z.square() + four * a)).sqrt().unwrap();
+        if c3.sgn0() {
+            c3 = -c3;
+        }
+        let c4 = -four * c1 / (three * z.square() + four * a);
+        Self { z, c1, c2, c3, c4 }
+    }
+}
+
+/// Maps a field element to a point on the curve with the Shallue-van de Woestijne method,
+/// following the straight-line implementation in RFC 9380, appendix F.1.
+/// Note: This method is not constant-time, and must not be used on secret inputs.
+fn map_to_curve<P: ShortWeierstrassParameters>(u: P::BaseField, constants: &SvdWConstants<P::BaseField>) -> Affine<P>
+where
+    P::BaseField: HashToField,
+{
+    let g = |x: P::BaseField| (x.square() + P::WEIERSTRASS_A) * x + P::WEIERSTRASS_B;
+    let is_square = |x: P::BaseField| !x.legendre().is_qnr();
+
+    let tv1 = u.square() * constants.c1;
+    let tv2 = P::BaseField::one() + tv1;
+    let tv1 = P::BaseField::one() - tv1;
+    // Compute `inv0(tv1 * tv2)`, which maps zero to zero.
+    let tv3 = (tv1 * tv2).inverse().unwrap_or_else(P::BaseField::zero);
+    let tv4 = u * tv1 * tv3 * constants.c3;
+
+    let x1 = constants.c2 - tv4;
+    let x2 = constants.c2 + tv4;
+    let x3 = (tv2.square() * tv3).square() * constants.c4 + constants.z;
+    let x = match (is_square(g(x1)), is_square(g(x2))) {
+        (true, _) => x1,
+        (false, true) => x2,
+        (false, false) => x3,
+    };
+
+    // Note: This unwrap is safe, as the choice of `Z` guarantees that `g(x3)` is square when `g(x1)` and `g(x2)` are not.
+    let mut y = g(x).sqrt().unwrap();
+    if u.sgn0() != y.sgn0() {
+        y = -y;
+    }
+    Affine::new(x, y, false)
+}
+
+/// Hashes `message` to `count` field elements, maps each of them to the curve,
+/// and clears the cofactor of their sum by multiplying it by `h_eff`, following RFC 9380, section 3.
+fn hash_with_svdw<P: ShortWeierstrassParameters>(message: &[u8], dst: &[u8], count: usize, h_eff: &[u64]) -> Affine<P>
+where
+    P::BaseField: HashToField,
+{
+    let constants = SvdWConstants::new(P::WEIERSTRASS_A, P::WEIERSTRASS_B);
+    hash_to_field::<P::BaseField>(message, dst, count)
+        .into_iter()
+        .map(|u| map_to_curve::<P>(u, &constants))
+        .fold(Projective::<P>::zero(), |sum, point| sum.add_mixed(&point))
+        .to_affine()
+        .mul_bits(BitIteratorBE::new_without_leading_zeros(h_eff))
+        .to_affine()
+}
+
+impl HashToCurve for G1Affine {
+    const ENCODE_SUITE_ID: &'static str = "BLS12377G1_XMD:SHA-256_SVDW_NU_";
+    const HASH_SUITE_ID: &'static str = "BLS12377G1_XMD:SHA-256_SVDW_RO_";
+
+    fn hash_to_curve(message: &[u8], dst: &[u8]) -> Self {
+        hash_with_svdw::<Bls12_377G1Parameters>(message, dst, 2, Bls12_377G1Parameters::COFACTOR)
+    }
+
+    fn encode_to_curve(message: &[u8], dst: &[u8]) -> Self {
+        hash_with_svdw::<Bls12_377G1Parameters>(message, dst, 1, Bls12_377G1Parameters::COFACTOR)
+    }
+}
+
+impl HashToCurve for G2Affine {
+    const ENCODE_SUITE_ID: &'static str = "BLS12377G2_XMD:SHA-256_SVDW_NU_";
+    const HASH_SUITE_ID: &'static str = "BLS12377G2_XMD:SHA-256_SVDW_RO_";
+
+    fn hash_to_curve(message: &[u8], dst: &[u8]) -> Self {
+        hash_with_svdw::<Bls12_377G2Parameters>(message, dst, 2, G2_H_EFF)
+    }
+
+    fn encode_to_curve(message: &[u8], dst: &[u8]) -> Self {
+        hash_with_svdw::<Bls12_377G2Parameters>(message, dst, 1, G2_H_EFF)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::bls12_377::Fr;
+
+    use core::str::FromStr;
+
+    const XMD_DST: &[u8] = b"QUUX-V01-CS02-with-expander-SHA256-128";
+    const G1_DST: &[u8] = b"QUUX-V01-CS02-with-BLS12377G1_XMD:SHA-256_SVDW_RO_";
+    const G2_DST: &[u8] = b"QUUX-V01-CS02-with-BLS12377G2_XMD:SHA-256_SVDW_RO_";
+
+    #[test]
+    fn test_suite_ids() {
+        assert!(G1_DST.ends_with(G1Affine::HASH_SUITE_ID.as_bytes()));
+        assert!(G2_DST.ends_with(G2Affine::HASH_SUITE_ID.as_bytes()));
+        assert_eq!(G1Affine::ENCODE_SUITE_ID, G1Affine::HASH_SUITE_ID.replace("_RO_", "_NU_"));
+        assert_eq!(G2Affine::ENCODE_SUITE_ID, G2Affine::HASH_SUITE_ID.replace("_RO_", "_NU_"));
+    }
+
+    fn hex(bytes: &[u8]) -> String {
+        bytes.iter().map(|byte| format!("{byte:02x}")).collect()
+    }
+
+    #[test]
+    fn test_expand_message_xmd() {
+        // The test vectors from RFC 9380, appendix K.1.
+        let output = expand_message_xmd(b"", XMD_DST, 0x20).unwrap();
+        assert_eq!(hex(&output), "68a985b87eb6b46952128911f2a4412bbc302a9d759667f87f7a21d803f07235");
+        let output = expand_message_xmd(b"abc", XMD_DST, 0x20).unwrap();
+        assert_eq!(hex(&output), "d8ccab23b5985ccea865c6c97b6e5b8350e794e603b4b97902f53a8a0d605615");
+        let output = expand_message_xmd(b"", XMD_DST, 0x80).unwrap();
+        assert_eq!(
+            hex(&output),
+            "af84c27ccfd45d41914fdff5df25293e221afc53d8ad2ac06d5e3e29485dadbee0d121587713a3e0dd4d5e69e93eb7cd4f5df4cd103e188cf60cb02edc3edf18eda8576c412b18ffb658e3dd6ec849469b979d444cf7b26911a08e63cf31f9dcc541708d3491184472c2c29bb749d4286b004ceb5ee6b9a7fa5b646c993f0ced"
+        );
+
+        // Ensure oversized outputs are rejected.
+        assert!(expand_message_xmd(b"", XMD_DST, 255 * SHA256_OUTPUT_SIZE).is_some());
+        assert!(expand_message_xmd(b"", XMD_DST, 255 * SHA256_OUTPUT_SIZE + 1).is_none());
+    }
+
+    #[test]
+    fn test_g1_hash_to_curve() {
+        let expected = [
+            (
+                b"".as_slice(),
+                "251337337623946727197918912240734475425817975904179632987228433087990246157222369002072235244483975718532162975111",
+                "173553133979398678439144093823329814464502085265521150186904682654121525965086280734306323478767677358381742515774",
+            ),
+            (
+                b"abc".as_slice(),
+                "115868840133496761296458638901199588397958029987242810661200183593901149567270482653357904005353457463642779050531",
+                "98382909079735478427619013607531652235083884131037892389793365001960234035143828370331992869801654427911281535710",
+            ),
+        ];
+        for (message, x, y) in expected {
+            let point = G1Affine::hash_to_curve(message, G1_DST);
+            assert!(point.is_on_curve());
+            assert!(point.is_in_correct_subgroup_assuming_on_curve());
+            assert_eq!(point.x, Fq::from_str(x).unwrap());
+            assert_eq!(point.y, Fq::from_str(y).unwrap());
+        }
+    }
+
+    #[test]
+    fn test_g1_encode_to_curve() {
+        let point = G1Affine::encode_to_curve(b"abc", G1_DST);
+        assert!(point.is_on_curve());
+        assert!(point.is_in_correct_subgroup_assuming_on_curve());
+        assert_ne!(point, G1Affine::hash_to_curve(b"abc", G1_DST));
+    }
+
+    #[test]
+    fn test_g2_hash_to_curve() {
+        for message in [b"".as_slice(), b"abc", b"abcdef0123456789", &[b'a'; 512]] {
+            let point = G2Affine::hash_to_curve(message, G2_DST);
+            assert!(point.is_on_curve());
+            assert!(point.is_in_correct_subgroup_assuming_on_curve());
+            assert!(!point.is_zero());
+            assert_eq!(point, G2Affine::hash_to_curve(message, G2_DST));
+
+            let point = G2Affine::encode_to_curve(message, G2_DST);
+            assert!(point.is_on_curve());
+            assert!(point.is_in_correct_subgroup_assuming_on_curve());
+        }
+    }
+
+    #[test]
+    fn test_g2_h_eff() {
+        // Multiplying by `h_eff` is multiplying by the cofactor `h_2`, then by `3 * (x^2 - 1)`.
+        let x = Fr::from(0x8508c00000000001u64);
+        let scalar = Fr::from(3u64) * (x.square() - Fr::one());
+        let constants = SvdWConstants::new(Bls12_377G2Parameters::WEIERSTRASS_A, Bls12_377G2Parameters::WEIERSTRASS_B);
+        for u in hash_to_field::<Fq2>(b"abc", G2_DST, 2) {
+            let point = map_to_curve::<Bls12_377G2Parameters>(u, &constants);
+            let cleared = point.mul_bits(BitIteratorBE::new_without_leading_zeros(G2_H_EFF)).to_affine();
+            assert!(cleared.is_in_correct_subgroup_assuming_on_curve());
+            assert_eq!(cleared, (point.mul_by_cofactor() * scalar).to_affine());
+        }
+    }
+
+    #[test]
+    fn test_domain_separation() {
+        assert_ne!(G1Affine::hash_to_curve(b"abc", G1_DST), G1Affine::hash_to_curve(b"abc", G2_DST));
+        assert_ne!(G2Affine::hash_to_curve(b"abc", G1_DST), G2Affine::hash_to_curve(b"abc", G2_DST));
+        // Ensure oversized domain separation tags are supported.
+        let point = G1Affine::hash_to_curve(b"abc", &[b'd'; 300]);
+        assert!(point.is_in_correct_subgroup_assuming_on_curve());
+    }
+}
@@ -14,6 +14,12 @@
 
 #![cfg_attr(nightly, doc = include_str!("../../documentation/the_aleo_curves/02_bls12-377.md"))]
 
+#[cfg(feature = "arkworks")]
+pub mod arkworks;
+#[cfg(feature = "arkworks")]
+#[doc(inline)]
+pub use arkworks::*;
+
 pub mod fr;
 #[doc(inline)]
 pub use fr::*;
@@ -42,6 +48,10 @@ pub mod g2;
 #[doc(inline)]
 pub use g2::*;
 
+pub mod hash_to_curve;
+#[doc(inline)]
+pub use hash_to_curve::*;
+
 pub mod parameters;
 #[doc(inline)]
 pub use parameters::*;
@@ -18,6 +18,7 @@ use crate::{
         g1::Bls12_377G1Parameters,
         g2::Bls12_377G2Parameters,
         Bls12_377,
+        Bls12_377Parameters,
         Fq,
         Fq12,
         Fq2,
@@ -32,7 +33,15 @@ use crate::{
         G2Affine,
         G2Projective,
     },
-    templates::{short_weierstrass_jacobian::tests::sw_tests, twisted_edwards_extended::tests::edwards_test},
+    templates::{
+        bls12::{Bls12Parameters, G2Prepared},
+        short_weierstrass_jacobian::{
+            tests::{sw_tests, sw_xy_limbs, sw_zero_copy_test},
+            Affine,
+            StdEncodedField,
+        },
+        twisted_edwards_extended::tests::edwards_test,
+    },
     traits::{
         tests_field::{
             bench_sqrt,
@@ -40,18 +49,21 @@ use crate::{
             field_test,
             frobenius_test,
             primefield_test,
+            random_legendre_tests,
             random_sqrt_tonelli_tests,
             sqrt_field_test,
         },
         tests_group::*,
         tests_projective::curve_tests,
         AffineCurve,
+        PairingCurve,
         PairingEngine,
         ProjectiveCurve,
         ShortWeierstrassParameters,
     },
 };
 use snarkvm_fields::{
+    const_field,
     fp6_3over2::Fp6Parameters,
     FftField,
     FftParameters,
@@ -68,6 +80,10 @@ use snarkvm_utilities::{
     biginteger::{BigInteger, BigInteger256, BigInteger384},
     rand::{TestRng, Uniform},
     BitIteratorBE,
+    CanonicalDeserialize,
+    CanonicalSerialize,
+    FromBytes,
+    ToBytes,
 };
 
 use rand::Rng;
@@ -195,6 +211,31 @@ fn test_fq_sum_of_products() {
     }
 }
 
+#[test]
+fn test_sum_of_products_slice() {
+    fn check<F: Field>(rng: &mut TestRng) {
+        for i in [0, 1, 3, 4, 5, 8, 9, 31, 64] {
+            let a = (0..i).map(|_| F::rand(rng)).collect::<Vec<_>>();
+            let b = (0..i).map(|_| F::rand(rng)).collect::<Vec<_>>();
+            let expected = a.iter().zip(&b).map(|(a, b)| *a * b).sum::<F>();
+            assert_eq!(F::sum_of_products_slice(&a, &b), expected);
+
+            let mut acc = F::rand(rng);
+            let initial = acc;
+            F::accumulate_products_into(&mut acc, &a, &b);
+            assert_eq!(acc, initial + expected);
+        }
+
+        // The largest elements maximize the unreduced sum in each chunk.
+        let a = vec![-F::one(); 16];
+        assert_eq!(F::sum_of_products_slice(&a, &a), F::from(16u64));
+    }
+
+    let mut rng = TestRng::default();
+    check::<Fq>(&mut rng);
+    check::<Fr>(&mut rng);
+}
+
 #[test]
 fn test_fq_repr_num_bits() {
     let mut a = BigInteger384::from(0);
@@ -435,6 +476,20 @@ fn test_fr_sqrt_tonelli() {
     random_sqrt_tonelli_tests::<Fr>(&mut rng);
 }
 
+#[test]
+fn test_fq_random_legendre() {
+    let mut rng = TestRng::default();
+
+    random_legendre_tests::<Fq>(&mut rng);
+}
+
+#[test]
+fn test_fr_random_legendre() {
+    let mut rng = TestRng::default();
+
+    random_legendre_tests::<Fr>(&mut rng);
+}
+
 #[test]
 fn test_fq_bench_sqrt() {
     let mut rng = TestRng::default();
@@ -473,6 +528,25 @@ fn test_fq_root_of_unity() {
     assert!(Fq::multiplicative_generator().sqrt().is_none());
 }
 
+#[test]
+fn test_fr_large_subgroup_root_of_unity() {
+    let root = Fr::large_subgroup_root_of_unity().unwrap();
+    assert_eq!(root.pow([3]), Fr::two_adic_root_of_unity());
+    assert_eq!(root.pow([3 << FrParameters::TWO_ADICITY]), Fr::one());
+    assert_ne!(root.pow([1 << FrParameters::TWO_ADICITY]), Fr::one());
+
+    // The radix-2 roots of unity must not depend on the mixed-radix subgroup.
+    for log_size in 0..=FrParameters::TWO_ADICITY {
+        let mut root = Fr::two_adic_root_of_unity();
+        (log_size..FrParameters::TWO_ADICITY).for_each(|_| root.square_in_place());
+        assert_eq!(Fr::get_root_of_unity(1 << log_size), Some(root));
+    }
+    assert!(Fr::supports_domain_size(3 << 20));
+    assert!(!Fr::supports_domain_size(9 << 20));
+    assert_eq!(Fr::smallest_domain_size((1 << 20) + 1), Some(3 << 19));
+    assert_eq!(Fr::smallest_domain_size((3 << 19) + 1), Some(1 << 21));
+}
+
 #[test]
 fn test_fq_ordering() {
     // BigInteger384's ordering is well-tested, but we still need to make sure the
@@ -521,6 +595,32 @@ fn test_fq2_basics() {
     assert!(!Fq2::new(Fq::zero(), Fq::one(),).is_zero());
 }
 
+#[test]
+fn test_const_field() {
+    const FQ_MINUS_ONE: Fq = const_field!(
+        Fq,
+        "258664426012969094010652733694893533536393512754914660539884262666720468348340822774968888139573360124440321458176"
+    );
+    const FR_MINUS_ONE: Fr =
+        const_field!(Fr, "8444461749428370424248824938781546531375899335154063827935233455917409239040");
+    assert_eq!(FQ_MINUS_ONE, -Fq::one());
+    assert_eq!(FR_MINUS_ONE, -Fr::one());
+
+    assert_eq!(const_field!(Fq, "0"), Fq::zero());
+    assert_eq!(const_field!(Fr, "1"), Fr::one());
+    assert_eq!(const_field!(Fq, "123456789"), Fq::from(123456789u64));
+    assert_eq!(Fq::from_canonical_limbs(Fq::one().to_bigint().0), Fq::one());
+    assert_eq!(Fr::from_montgomery_limbs(Fr::one().0 .0), Fr::one());
+
+    let mut rng = TestRng::default();
+    for _ in 0..ITERATIONS {
+        let a = Fq::rand(&mut rng);
+        assert_eq!(Fq::from_canonical_limbs(a.to_bigint().0), a);
+        let b = Fr::rand(&mut rng);
+        assert_eq!(Fr::from_canonical_limbs(b.to_bigint().0), b);
+    }
+}
+
 #[test]
 fn test_fq2_legendre() {
     assert_eq!(Zero, Fq2::zero().legendre());
@@ -617,6 +717,48 @@ fn test_fq12_mul_by_034() {
     }
 }
 
+#[test]
+fn test_fq12_cyclotomic_compressed() {
+    let mut rng = TestRng::default();
+
+    // Maps an element into the cyclotomic subgroup, with the easy part of the final exponentiation.
+    let to_cyclotomic = |f: Fq12| {
+        let mut f1 = f;
+        f1.conjugate();
+        let r = f1 * f.inverse().unwrap();
+        let mut r2 = r;
+        r2.frobenius_map(2);
+        r2 * r
+    };
+
+    for _ in 0..ITERATIONS {
+        let f = to_cyclotomic(Fq12::rand(&mut rng));
+
+        // Ensure the compressed squarings agree with the cyclotomic squarings.
+        let mut expected = f;
+        let mut compressed = vec![f];
+        for _ in 0..8 {
+            expected = expected.cyclotomic_square();
+            let mut next = *compressed.last().unwrap();
+            next.cyclotomic_square_compressed();
+            compressed.push(next);
+        }
+        Fq12::batch_cyclotomic_decompress(&mut compressed);
+        assert_eq!(compressed[0], f);
+        assert_eq!(compressed[8], expected);
+
+        // Ensure the compressed exponentiation agrees with the cyclotomic exponentiation.
+        let x = <Bls12_377Parameters as Bls12Parameters>::X;
+        assert_eq!(f.cyclotomic_exp_compressed(x), f.cyclotomic_exp(x));
+        let exp: Fr = rng.gen();
+        assert_eq!(f.cyclotomic_exp_compressed(exp.to_bigint()), f.cyclotomic_exp(exp.to_bigint()));
+        assert_eq!(f.cyclotomic_exp_compressed([0u64]), Fq12::one());
+    }
+
+    // Ensure the identity is handled.
+    assert_eq!(Fq12::one().cyclotomic_exp_compressed(<Bls12_377Parameters as Bls12Parameters>::X), Fq12::one());
+}
+
 #[test]
 fn test_g1_projective_glv() {
     let mut rng = TestRng::default();
@@ -634,6 +776,8 @@ fn test_g1_projective_curve() {
 
     curve_tests::<G1Projective>(&mut rng);
     sw_tests::<Bls12_377G1Parameters>(&mut rng);
+    sw_zero_copy_test::<Bls12_377G1Parameters>(&mut rng);
+    sw_xy_limbs::<Bls12_377G1Parameters>(&mut rng);
 }
 
 #[test]
@@ -702,3 +846,127 @@ fn test_bilinearity() {
     assert_eq!(ans2.pow(Fr::characteristic()), Fq12::one());
     assert_eq!(ans3.pow(Fr::characteristic()), Fq12::one());
 }
+
+#[test]
+fn test_multi_pairing() {
+    let mut rng = TestRng::default();
+
+    for num_pairs in [0, 1, 2, 5] {
+        let g1 = (0..num_pairs).map(|_| rng.gen::<G1Projective>().to_affine()).collect::<Vec<_>>();
+        let g2 = (0..num_pairs).map(|_| rng.gen::<G2Projective>().to_affine()).collect::<Vec<_>>();
+
+        let expected = g1.iter().zip(&g2).fold(Fq12::one(), |acc, (p, q)| acc * Bls12_377::pairing(*p, *q));
+        assert_eq!(Bls12_377::multi_pairing(&g1, &g2), expected);
+    }
+
+    // Ensure pairs involving the point at infinity are handled.
+    let a: G1Projective = rng.gen();
+    let b: G2Projective = rng.gen();
+    let g1 = [a.to_affine(), G1Affine::zero(), a.to_affine()];
+    let g2 = [b.to_affine(), b.to_affine(), G2Affine::zero()];
+    assert_eq!(Bls12_377::multi_pairing(&g1, &g2), Bls12_377::pairing(a, b));
+
+    // Ensure the pairing relation `e(s * a, b) * e(-a, s * b) = 1` holds.
+    let s: Fr = rng.gen();
+    let g1 = [(a * s).to_affine(), -a.to_affine()];
+    let g2 = [b.to_affine(), (b * s).to_affine()];
+    assert_eq!(Bls12_377::multi_pairing(&g1, &g2), Fq12::one());
+}
+
+#[test]
+fn test_g2_prepared_serialization() {
+    let mut rng = TestRng::default();
+
+    let a: G1Projective = rng.gen();
+    let b: G2Projective = rng.gen();
+    for q in [b.to_affine(), G2Affine::zero()] {
+        let prepared = q.prepare();
+
+        let mut bytes = vec![];
+        prepared.serialize_compressed(&mut bytes).unwrap();
+        let candidate = G2Prepared::<Bls12_377Parameters>::deserialize_compressed(&bytes[..]).unwrap();
+        assert_eq!(prepared, candidate);
+        assert_eq!(prepared, G2Prepared::<Bls12_377Parameters>::read_le(&prepared.to_bytes_le().unwrap()[..]).unwrap());
+
+        // Ensure the stored coefficients give the same pairing as re-preparing the point.
+        let p = a.to_affine().prepare();
+        assert_eq!(
+            Bls12_377::final_exponentiation(&Bls12_377::miller_loop([(&p, &candidate)].into_iter())).unwrap(),
+            Bls12_377::pairing(a, q)
+        );
+    }
+
+    // Ensure a truncated set of line coefficients is rejected instead of panicking in the Miller loop.
+    let mut prepared = b.to_affine().prepare();
+    assert_eq!(prepared.ell_coeffs.len(), G2Prepared::<Bls12_377Parameters>::num_ell_coeffs());
+    prepared.ell_coeffs.pop();
+    let mut bytes = vec![];
+    prepared.serialize_compressed(&mut bytes).unwrap();
+    assert!(G2Prepared::<Bls12_377Parameters>::deserialize_compressed(&bytes[..]).is_err());
+    assert!(G2Prepared::<Bls12_377Parameters>::deserialize_compressed_unchecked(&bytes[..]).is_err());
+    assert!(G2Prepared::<Bls12_377Parameters>::read_le(&prepared.to_bytes_le().unwrap()[..]).is_err());
+}
+
+#[test]
+fn test_g2_psi_endomorphism() {
+    let mut rng = TestRng::default();
+
+    let x = Fr::from(<Bls12_377Parameters as Bls12Parameters>::X[0]);
+    for _ in 0..ITERATIONS {
+        let p: G2Projective = rng.gen();
+        let p = p.to_affine();
+        let psi_p = Bls12_377G2Parameters::psi_endomorphism(p).unwrap();
+        assert!(psi_p.is_on_curve());
+        assert_eq!(psi_p, p.mul_bits(BitIteratorBE::new(x.to_bigint())).to_affine());
+
+        let scalar: Fr = rng.gen();
+        assert_eq!(p * scalar, p.mul_bits(BitIteratorBE::new(scalar.to_bigint())));
+    }
+    assert!(Bls12_377G1Parameters::psi_endomorphism(G1Affine::prime_subgroup_generator()).is_none());
+}
+
+#[test]
+fn test_compressed_std_serialization() {
+    let mut rng = TestRng::default();
+
+    fn check<P: ShortWeierstrassParameters>(point: Affine<P>)
+    where
+        P::BaseField: StdEncodedField,
+    {
+        let mut bytes = Vec::new();
+        point.serialize_compressed_std(&mut bytes).unwrap();
+        assert_eq!(bytes.len(), Affine::<P>::compressed_std_size());
+        assert_eq!(bytes[0] & 0x80, 0x80);
+        assert_eq!(bytes[0] & 0x40 != 0, point.is_zero());
+        assert_eq!(bytes[0] & 0x20 != 0, !point.is_zero() && point.y.is_lexicographically_largest());
+        assert_eq!(point, Affine::<P>::deserialize_compressed_std(&bytes[..]).unwrap());
+
+        // Ensure the compression flag is required.
+        bytes[0] &= 0x7f;
+        assert!(Affine::<P>::deserialize_compressed_std(&bytes[..]).is_err());
+    }
+
+    assert_eq!(G1Affine::compressed_std_size(), 48);
+    assert_eq!(G2Affine::compressed_std_size(), 96);
+    check(G1Affine::zero());
+    check(G2Affine::zero());
+    for _ in 0..ITERATIONS {
+        check(rng.gen::<G1Projective>().to_affine());
+        check(rng.gen::<G2Projective>().to_affine());
+    }
+
+    // Ensure the point at infinity has a unique encoding.
+    let mut bytes = vec![0u8; 48];
+    bytes[0] = 0xc0;
+    assert!(G1Affine::deserialize_compressed_std(&bytes[..]).unwrap().is_zero());
+    bytes[47] = 1;
+    assert!(G1Affine::deserialize_compressed_std(&bytes[..]).is_err());
+    bytes[47] = 0;
+    bytes[0] = 0xe0;
+    assert!(G1Affine::deserialize_compressed_std(&bytes[..]).is_err());
+
+    // Ensure non-canonical field elements are rejected.
+    let mut bytes = vec![0xffu8; 48];
+    bytes[0] = 0x9f;
+    assert!(G1Affine::deserialize_compressed_std(&bytes[..]).is_err());
+}
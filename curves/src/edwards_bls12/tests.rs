@@ -14,7 +14,10 @@
 
 use crate::{
     edwards_bls12::*,
-    templates::twisted_edwards_extended::tests::{edwards_test, montgomery_conversion_test},
+    templates::{
+        montgomery::tests::montgomery_test,
+        twisted_edwards_extended::tests::{edwards_test, montgomery_conversion_test},
+    },
     traits::{
         tests_field::{field_serialization_test, field_test, primefield_test},
         tests_group::*,
@@ -107,6 +110,12 @@ fn test_montgomery_conversion() {
     montgomery_conversion_test::<EdwardsParameters>();
 }
 
+#[test]
+fn test_montgomery_ladder() {
+    let mut rng = TestRng::default();
+    montgomery_test::<EdwardsParameters>(&mut rng);
+}
+
 #[test]
 #[allow(clippy::many_single_char_names)]
 fn test_edwards_to_montgomery_point() {
@@ -28,8 +28,15 @@ pub mod edwards_bls12;
 pub mod errors;
 pub use errors::*;
 
+pub mod pasta;
+
+pub mod secp256k1;
+
 pub mod templates;
 
 #[cfg_attr(test, macro_use)]
 pub mod traits;
 pub use traits::*;
+
+pub mod wnaf;
+pub use wnaf::*;
@@ -0,0 +1,222 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use snarkvm_fields::{
+    FftParameters,
+    FieldParameters,
+    Fp256,
+    Fp256Parameters,
+    PoseidonDefaultParameters,
+    PoseidonDefaultParametersEntry,
+};
+use snarkvm_utilities::biginteger::BigInteger256 as BigInteger;
+
+/// The base field of the Pallas curve, and the scalar field of the Vesta curve.
+///
+/// Roots of unity computed from modulus and R using this sage code:
+///
+/// ```ignore
+/// q = 28948022309329048855892746252171976963363056481941560715954676764349967630337
+/// R = 28948022309329048855892746252171976963180815219815881891593553714863226748925 # Montgomery R
+/// s = 32
+/// o = q - 1
+/// F = GF(q)
+/// g = F(5)
+/// assert g.multiplicative_order() == o
+/// g2 = g ** (o/2**s)
+/// assert g2.multiplicative_order() == 2**s
+/// def into_chunks(val, width, n):
+///     return [int(int(val) // (2 ** (width * i)) % 2 ** width) for i in range(n)]
+/// print("Gen (g % q): ", g % q)
+/// print("Gen (g * R % q): ", g * R % q)
+/// print("Gen into_chunks(g * R % q): ", into_chunks(g * R % q, 64, 4))
+/// print("2-adic gen (g2 % q): ", g2 % q)
+/// print("2-adic gen (g2 * R % q): ", g2 * R % q)
+/// print("2-adic gen into_chunks(g2 * R % q): ", into_chunks(g2 * R % q, 64, 4))
+/// ```
+pub type Fp = Fp256<FpParameters>;
+
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub struct FpParameters;
+
+impl Fp256Parameters for FpParameters {}
+
+impl FftParameters for FpParameters {
+    type BigInteger = BigInteger;
+
+    #[rustfmt::skip]
+    const POWERS_OF_ROOTS_OF_UNITY: &'static [BigInteger] = &[
+        BigInteger([11713220832667294704, 10413392179731184095, 18133385229535560846, 4524191781424318170]),
+        BigInteger([17563319808788077576, 11292711158458196539, 8551860854466045159, 4434106315460362630]),
+        BigInteger([15343270519448385177, 1416525331317355403, 5659974296668781259, 3486506316725416052]),
+        BigInteger([15951292809311531384, 12097912783233576893, 8749738432603209534, 4008132633417874277]),
+        BigInteger([14442867365424897681, 7088896126792481987, 10932763120042355652, 1993965424378390326]),
+        BigInteger([18078350324619735922, 1551147621840924520, 18077581118274808725, 1784306356766803629]),
+        BigInteger([1268218396305512551, 5455152366526146254, 13041272164917624874, 1432144485103665532]),
+        BigInteger([7450940128527461256, 1083477157265392715, 2662896246168930889, 2453376596785883283]),
+        BigInteger([3835411875661557915, 3102500424597856582, 2899273850078509445, 3393433794203275518]),
+        BigInteger([18003255235708971905, 364084707029373604, 6692724176560837901, 1316698828655984377]),
+        BigInteger([7925324236134827444, 6866334835320424982, 13682833251722533007, 2576669737158239541]),
+        BigInteger([11378055056185330047, 6063376504650214891, 598095751817748824, 2913923182598842358]),
+        BigInteger([74668034367324440, 2530091067748286078, 1509827962813568887, 1186368771637836584]),
+        BigInteger([222215981905288566, 5963161715857821434, 1775060858316039699, 769043702112022584]),
+        BigInteger([2337250669908993960, 9814008034267399810, 11335805289081793400, 1983791523574024149]),
+        BigInteger([8706412908140785198, 11734997014435103328, 627983198645871955, 524774241479264328]),
+        BigInteger([1762499886011628133, 17827858826216315699, 17827964166359594247, 1905817238367662803]),
+        BigInteger([2137054015935261351, 5502885013537247947, 9719553109069118351, 2239485291751870876]),
+        BigInteger([12297755483006183055, 7221668732989177039, 12124859883769654152, 3948279486009646911]),
+        BigInteger([12062003555670297368, 3854480718953511401, 16301387494465562476, 4417906977084652176]),
+        BigInteger([13145520471894472795, 16522543889500308049, 15447594273869829413, 3755985944030560459]),
+        BigInteger([5351624788447857731, 2682008127581830634, 5320876976874930810, 386250595329326869]),
+        BigInteger([4378823690486268480, 15415963875989301425, 15496075589539621626, 2801995690609998626]),
+        BigInteger([85362328438100211, 680988863370391747, 11255516915836237626, 3308106496346413274]),
+        BigInteger([1447303720031333885, 2448351426332539237, 10924298751013021736, 1902875982779088254]),
+        BigInteger([14464138147487749887, 3095454339171417552, 13955855833287177691, 2856506638657890869]),
+        BigInteger([7173197212285829661, 12724379073352215869, 9077428360428590815, 1159893738077473811]),
+        BigInteger([10504086579338045122, 11652615802702148100, 12884946906022887091, 4544266430884519349]),
+        BigInteger([10492504805470040199, 10505228045623216708, 4300328378270830794, 1422188040939187082]),
+        BigInteger([7890157522059215732, 12694360794035955510, 1363985471300441029, 3999669577642012903]),
+        BigInteger([16849627989319532162, 8445794543337781498, 5532319555919482809, 2186312362157397084]),
+    ];
+    #[rustfmt::skip]
+    const TWO_ADICITY: u32 = 32;
+    /// TWO_ADIC_ROOT_OF_UNITY = 19814229590243028906643993866117402072516588566294623396325693409366934201135
+    /// Encoded in Montgomery form, the value is
+    /// (19814229590243028906643993866117402072516588566294623396325693409366934201135 * R % q) =
+    /// 28398812082400746296050252412403405061164701788217750804166588481020022414320
+    #[rustfmt::skip]
+    const TWO_ADIC_ROOT_OF_UNITY: BigInteger = BigInteger([
+        11713220832667294704u64,
+        10413392179731184095u64,
+        18133385229535560846u64,
+        4524191781424318170u64,
+    ]);
+}
+
+impl FieldParameters for FpParameters {
+    #[rustfmt::skip]
+    const CAPACITY: u32 = Self::MODULUS_BITS - 1;
+    /// GENERATOR = 5
+    /// Encoded in Montgomery form, so the value is
+    /// (5 * R) % q = 28948022309329048855892746252171976962451850171313166594149061516916263223277
+    #[rustfmt::skip]
+    const GENERATOR: BigInteger = BigInteger([
+        11647819816328232941u64,
+        8413468796752855795u64,
+        18446744073709551613u64,
+        4611686018427387903u64,
+    ]);
+    #[rustfmt::skip]
+    const INV: u64 = 11037532056220336127u64;
+    /// MODULUS = 28948022309329048855892746252171976963363056481941560715954676764349967630337
+    #[rustfmt::skip]
+    const MODULUS: BigInteger = BigInteger([
+        0x992d30ed00000001,
+        0x224698fc094cf91b,
+        0x0,
+        0x4000000000000000,
+    ]);
+    #[rustfmt::skip]
+    const MODULUS_BITS: u32 = 255;
+    /// (r - 1) / 2 =
+    /// 14474011154664524427946373126085988481681528240970780357977338382174983815168
+    #[rustfmt::skip]
+    const MODULUS_MINUS_ONE_DIV_TWO: BigInteger = BigInteger([
+        0xcc96987680000000,
+        0x11234c7e04a67c8d,
+        0x0,
+        0x2000000000000000,
+    ]);
+    #[rustfmt::skip]
+    const R: BigInteger = BigInteger([
+        3780891978758094845u64,
+        11037255111966004397u64,
+        18446744073709551615u64,
+        4611686018427387903u64,
+    ]);
+    #[rustfmt::skip]
+    const R2: BigInteger = BigInteger([
+        10122100416058490895u64,
+        15551789045973377255u64,
+        8617542898466512152u64,
+        679271340751763220u64,
+    ]);
+    #[rustfmt::skip]
+    const REPR_SHAVE_BITS: u32 = 1;
+    // T and T_MINUS_ONE_DIV_TWO, where r - 1 = 2^s * t
+
+    /// t = (r - 1) / 2^s =
+    /// 6739986666787659948666753771754907668419893943225396963757154709741
+    #[rustfmt::skip]
+    const T: BigInteger = BigInteger([
+        0x94cf91b992d30ed,
+        0x224698fc,
+        0x0,
+        0x40000000,
+    ]);
+    /// (t - 1) / 2 =
+    /// 3369993333393829974333376885877453834209946971612698481878577354870
+    #[rustfmt::skip]
+    const T_MINUS_ONE_DIV_TWO: BigInteger = BigInteger([
+        0x4a67c8dcc969876,
+        0x11234c7e,
+        0x0,
+        0x20000000,
+    ]);
+}
+
+impl PoseidonDefaultParameters for FpParameters {
+    const PARAMS_OPT_FOR_CONSTRAINTS: [PoseidonDefaultParametersEntry; 7] = [
+        PoseidonDefaultParametersEntry::new(2, 5, 8, 56, 0),
+        PoseidonDefaultParametersEntry::new(3, 5, 8, 56, 0),
+        PoseidonDefaultParametersEntry::new(4, 5, 8, 56, 0),
+        PoseidonDefaultParametersEntry::new(5, 5, 8, 57, 0),
+        PoseidonDefaultParametersEntry::new(6, 5, 8, 57, 0),
+        PoseidonDefaultParametersEntry::new(7, 5, 8, 57, 0),
+        PoseidonDefaultParametersEntry::new(8, 5, 8, 57, 0),
+    ];
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_fields::{FftField, Field, PrimeField};
+
+    #[test]
+    fn test_powers_of_root_of_unity() {
+        let two = Fp::from(2u8);
+
+        // Compute the expected powers of root of unity.
+        let root_of_unity = Fp::two_adic_root_of_unity();
+        let powers = (0..FpParameters::TWO_ADICITY - 1)
+            .map(|i| root_of_unity.pow(two.pow(Fp::from(i as u64).to_bigint()).to_bigint()))
+            .collect::<Vec<_>>();
+        assert_eq!(powers[0], Fp::two_adic_root_of_unity());
+
+        // Ensure the correct number of powers of root of unity are present.
+        assert_eq!(FpParameters::POWERS_OF_ROOTS_OF_UNITY.len() as u64, (FpParameters::TWO_ADICITY - 1) as u64);
+        assert_eq!(FpParameters::POWERS_OF_ROOTS_OF_UNITY.len(), powers.len());
+
+        // Ensure the expected and candidate powers match.
+        for (expected, candidate) in powers.iter().zip(FpParameters::POWERS_OF_ROOTS_OF_UNITY) {
+            assert_eq!(&expected.0, candidate);
+        }
+    }
+
+    #[test]
+    fn test_two_adic_root_of_unity() {
+        let expected = Fp::multiplicative_generator().pow(FpParameters::T);
+        assert_eq!(expected, Fp::two_adic_root_of_unity());
+    }
+}
@@ -0,0 +1,222 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use snarkvm_fields::{
+    FftParameters,
+    FieldParameters,
+    Fp256,
+    Fp256Parameters,
+    PoseidonDefaultParameters,
+    PoseidonDefaultParametersEntry,
+};
+use snarkvm_utilities::biginteger::BigInteger256 as BigInteger;
+
+/// The base field of the Vesta curve, and the scalar field of the Pallas curve.
+///
+/// Roots of unity computed from modulus and R using this sage code:
+///
+/// ```ignore
+/// q = 28948022309329048855892746252171976963363056481941647379679742748393362948097
+/// R = 28948022309329048855892746252171976963180815219815621900418355762733040795645 # Montgomery R
+/// s = 32
+/// o = q - 1
+/// F = GF(q)
+/// g = F(5)
+/// assert g.multiplicative_order() == o
+/// g2 = g ** (o/2**s)
+/// assert g2.multiplicative_order() == 2**s
+/// def into_chunks(val, width, n):
+///     return [int(int(val) // (2 ** (width * i)) % 2 ** width) for i in range(n)]
+/// print("Gen (g % q): ", g % q)
+/// print("Gen (g * R % q): ", g * R % q)
+/// print("Gen into_chunks(g * R % q): ", into_chunks(g * R % q, 64, 4))
+/// print("2-adic gen (g2 % q): ", g2 % q)
+/// print("2-adic gen (g2 * R % q): ", g2 * R % q)
+/// print("2-adic gen into_chunks(g2 * R % q): ", into_chunks(g2 * R % q, 64, 4))
+/// ```
+pub type Fq = Fp256<FqParameters>;
+
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub struct FqParameters;
+
+impl Fp256Parameters for FqParameters {}
+
+impl FftParameters for FqParameters {
+    type BigInteger = BigInteger;
+
+    #[rustfmt::skip]
+    const POWERS_OF_ROOTS_OF_UNITY: &'static [BigInteger] = &[
+        BigInteger([2414060527980987102, 14720393103524889748, 12406956448539459298, 826967475050360918]),
+        BigInteger([13477138999080457339, 1008587620508911431, 3767516103000796698, 2356736190301248749]),
+        BigInteger([17518808478077657907, 14365987385993749548, 15796906873960569028, 4173411275806309072]),
+        BigInteger([17480951910584209110, 10528589036165274515, 2622552692048198707, 625684207932543667]),
+        BigInteger([5146319322101209861, 12786864984608602056, 12293995734003305002, 188583298145311902]),
+        BigInteger([6981116946467452996, 12912333807114367158, 17442930768434184683, 1031221320399617503]),
+        BigInteger([6363120203315315761, 8589561135013015504, 2306225993463584891, 1875445523442893759]),
+        BigInteger([12374721455918923691, 9312003208334387331, 9691185342751948339, 3757006714592045798]),
+        BigInteger([18309766206869408145, 7986424457399151113, 11566877529772253585, 3267905723229895312]),
+        BigInteger([13841343876506424440, 10218836766507873400, 10775362022323097527, 778889440248466127]),
+        BigInteger([7639791893666989594, 10475007924609603707, 77714658611044786, 7870175802950847]),
+        BigInteger([14709782615465381936, 14468204559029768450, 12012538213547027554, 1916830386318523617]),
+        BigInteger([14749965433724955784, 13305406049069973002, 14103252492924448076, 3684907226298433266]),
+        BigInteger([7468181409789761249, 1640605334567897365, 13306149814325843299, 370408652996214907]),
+        BigInteger([8575358496187847857, 11433910089986307227, 12752238743171363079, 1570306528829885253]),
+        BigInteger([5777981080413509716, 5977324626790834602, 15593093972149220265, 4175183195483423361]),
+        BigInteger([11499106550954523456, 7570581481237397936, 3393872299312777009, 3649061251565789237]),
+        BigInteger([9761044506682710481, 18143429364491733336, 17638385653295125804, 3400806102867543635]),
+        BigInteger([11785425556072398946, 6169824366069285408, 7177923038111589401, 2109037946603621620]),
+        BigInteger([6895902606495894125, 17618101350546418059, 16215680748301797502, 4580483794014145560]),
+        BigInteger([8398787961012689368, 10798856823141341587, 486923139907988777, 4446672482006276164]),
+        BigInteger([17875931159209286901, 9171079420038594364, 15350913192468064578, 2113406433786613513]),
+        BigInteger([7183757791848629208, 16297443670666587888, 5980738797857678659, 2518990937015775670]),
+        BigInteger([16128549511443973882, 17036428475853265113, 13318404930890720864, 4347162253719190613]),
+        BigInteger([10343713685789382181, 5171018205508601314, 12705794818183382992, 2598649375214020428]),
+        BigInteger([4470046045674478580, 6531058897881512254, 2122504019554140394, 1212112912466120261]),
+        BigInteger([1601940463647410576, 6306872039606505583, 12463040523266756641, 3934166091082169386]),
+        BigInteger([16719731476505327352, 13131124345184920764, 12849164371764707248, 2522905128733071161]),
+        BigInteger([12360435186837254548, 4992934242175250944, 10688947427539457149, 4375589852506146203]),
+        BigInteger([12835804832509266457, 909600395023447795, 15992934397294945624, 640986653242815478]),
+        BigInteger([11048090298361126029, 14042718475739573081, 11484152569071325067, 4591547988807713814]),
+    ];
+    #[rustfmt::skip]
+    const TWO_ADICITY: u32 = 32;
+    /// TWO_ADIC_ROOT_OF_UNITY = 20761624379169977859705911634190121761503565370703356079647768903521299517535
+    /// Encoded in Montgomery form, the value is
+    /// (20761624379169977859705911634190121761503565370703356079647768903521299517535 * R % q) =
+    /// 5190958972746962149895688928775469715904512015574928711273653649140790936286
+    #[rustfmt::skip]
+    const TWO_ADIC_ROOT_OF_UNITY: BigInteger = BigInteger([
+        2414060527980987102u64,
+        14720393103524889748u64,
+        12406956448539459298u64,
+        826967475050360918u64,
+    ]);
+}
+
+impl FieldParameters for FqParameters {
+    #[rustfmt::skip]
+    const CAPACITY: u32 = Self::MODULUS_BITS - 1;
+    /// GENERATOR = 5
+    /// Encoded in Montgomery form, so the value is
+    /// (5 * R) % q = 28948022309329048855892746252171976962451850171311519983372807820091752185837
+    #[rustfmt::skip]
+    const GENERATOR: BigInteger = BigInteger([
+        10861710938529071085u64,
+        8413468796663592846u64,
+        18446744073709551613u64,
+        4611686018427387903u64,
+    ]);
+    #[rustfmt::skip]
+    const INV: u64 = 10108024940646105087u64;
+    /// MODULUS = 28948022309329048855892746252171976963363056481941647379679742748393362948097
+    #[rustfmt::skip]
+    const MODULUS: BigInteger = BigInteger([
+        0x8c46eb2100000001,
+        0x224698fc0994a8dd,
+        0x0,
+        0x4000000000000000,
+    ]);
+    #[rustfmt::skip]
+    const MODULUS_BITS: u32 = 255;
+    /// (r - 1) / 2 =
+    /// 14474011154664524427946373126085988481681528240970823689839871374196681474048
+    #[rustfmt::skip]
+    const MODULUS_MINUS_ONE_DIV_TWO: BigInteger = BigInteger([
+        0xc623759080000000,
+        0x11234c7e04ca546e,
+        0x0,
+        0x2000000000000000,
+    ]);
+    #[rustfmt::skip]
+    const R: BigInteger = BigInteger([
+        6569413325480787965u64,
+        11037255111951910247u64,
+        18446744073709551615u64,
+        4611686018427387903u64,
+    ]);
+    #[rustfmt::skip]
+    const R2: BigInteger = BigInteger([
+        18200867980676431887u64,
+        7474641938123724515u64,
+        9200329640471491984u64,
+        679271340771891881u64,
+    ]);
+    #[rustfmt::skip]
+    const REPR_SHAVE_BITS: u32 = 1;
+    // T and T_MINUS_ONE_DIV_TWO, where r - 1 = 2^s * t
+
+    /// t = (r - 1) / 2^s =
+    /// 6739986666787659948666753771754907668419893943225417141728043264801
+    #[rustfmt::skip]
+    const T: BigInteger = BigInteger([
+        0x994a8dd8c46eb21,
+        0x224698fc,
+        0x0,
+        0x40000000,
+    ]);
+    /// (t - 1) / 2 =
+    /// 3369993333393829974333376885877453834209946971612708570864021632400
+    #[rustfmt::skip]
+    const T_MINUS_ONE_DIV_TWO: BigInteger = BigInteger([
+        0x4ca546ec6237590,
+        0x11234c7e,
+        0x0,
+        0x20000000,
+    ]);
+}
+
+impl PoseidonDefaultParameters for FqParameters {
+    const PARAMS_OPT_FOR_CONSTRAINTS: [PoseidonDefaultParametersEntry; 7] = [
+        PoseidonDefaultParametersEntry::new(2, 5, 8, 56, 0),
+        PoseidonDefaultParametersEntry::new(3, 5, 8, 56, 0),
+        PoseidonDefaultParametersEntry::new(4, 5, 8, 56, 0),
+        PoseidonDefaultParametersEntry::new(5, 5, 8, 57, 0),
+        PoseidonDefaultParametersEntry::new(6, 5, 8, 57, 0),
+        PoseidonDefaultParametersEntry::new(7, 5, 8, 57, 0),
+        PoseidonDefaultParametersEntry::new(8, 5, 8, 57, 0),
+    ];
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_fields::{FftField, Field, PrimeField};
+
+    #[test]
+    fn test_powers_of_root_of_unity() {
+        let two = Fq::from(2u8);
+
+        // Compute the expected powers of root of unity.
+        let root_of_unity = Fq::two_adic_root_of_unity();
+        let powers = (0..FqParameters::TWO_ADICITY - 1)
+            .map(|i| root_of_unity.pow(two.pow(Fq::from(i as u64).to_bigint()).to_bigint()))
+            .collect::<Vec<_>>();
+        assert_eq!(powers[0], Fq::two_adic_root_of_unity());
+
+        // Ensure the correct number of powers of root of unity are present.
+        assert_eq!(FqParameters::POWERS_OF_ROOTS_OF_UNITY.len() as u64, (FqParameters::TWO_ADICITY - 1) as u64);
+        assert_eq!(FqParameters::POWERS_OF_ROOTS_OF_UNITY.len(), powers.len());
+
+        // Ensure the expected and candidate powers match.
+        for (expected, candidate) in powers.iter().zip(FqParameters::POWERS_OF_ROOTS_OF_UNITY) {
+            assert_eq!(&expected.0, candidate);
+        }
+    }
+
+    #[test]
+    fn test_two_adic_root_of_unity() {
+        let expected = Fq::multiplicative_generator().pow(FqParameters::T);
+        assert_eq!(expected, Fq::two_adic_root_of_unity());
+    }
+}
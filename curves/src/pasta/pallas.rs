@@ -0,0 +1,107 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use snarkvm_fields::{field, Field, One, Zero};
+use snarkvm_utilities::biginteger::BigInteger256;
+
+use crate::{
+    pasta::{Fp, Fq},
+    templates::short_weierstrass_jacobian::{
+        glv::{decompose, glv_mul, GlvParameters},
+        Affine,
+        Projective,
+    },
+    traits::{ModelParameters, ShortWeierstrassParameters},
+};
+
+/// The Pallas curve `y^2 = x^3 + 5` over `Fp`, which has prime order `Fq::MODULUS`.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct PallasParameters;
+
+pub type PallasAffine = Affine<PallasParameters>;
+pub type PallasProjective = Projective<PallasParameters>;
+
+impl ModelParameters for PallasParameters {
+    type BaseField = Fp;
+    type ScalarField = Fq;
+}
+
+impl ShortWeierstrassParameters for PallasParameters {
+    /// AFFINE_GENERATOR_COEFFS = (PALLAS_GENERATOR_X, PALLAS_GENERATOR_Y)
+    const AFFINE_GENERATOR_COEFFS: (Self::BaseField, Self::BaseField) = (PALLAS_GENERATOR_X, PALLAS_GENERATOR_Y);
+    /// B1 = -b1 = 98231058071186745657228807397848383489
+    const B1: Fq =
+        field!(Fq, BigInteger256([0x5bffb780fffffffd, 0xc0d38969c435c275, 0xb1a27161e7e62937, 0x186bf7a9a0f9da22]));
+    /// B2 = -b2 = 28948022309329048855892746252171976963264825423870547297747579925038909882369
+    const B2: Fq =
+        field!(Fq, BigInteger256([0x32c49e4c00000000, 0x143c3bf8e412911a, 0xad5a9749a37a3893, 0x279408565edfc16a]));
+    /// COFACTOR = 1
+    const COFACTOR: &'static [u64] = &[0x1];
+    /// COFACTOR_INV = COFACTOR^{-1} mod r = 1
+    const COFACTOR_INV: Fq =
+        field!(Fq, BigInteger256([0x5b2b3e9cfffffffd, 0x992c350be3420567, 0xffffffffffffffff, 0x3fffffffffffffff]));
+    /// PHI = 20444556541222657078399132219657928148671392403212669005631716460534733845831
+    /// is a primitive cube root of unity, such that `(PHI * x, y) = [LAMBDA] (x, y)`.
+    const PHI: Fp =
+        field!(Fp, BigInteger256([0xfbdfd7aa9e65eac8, 0xcd4d654e50025fb, 0xd59892a33785b99a, 0x2a27fb62585e8789]));
+    /// R128 = 2^128 - 1
+    const R128: Fq =
+        field!(Fq, BigInteger256([0xd5f06de700000007, 0xefee2ee443109e0e, 0xcee4537bfffffffc, 0x36e59c0fd9ad5c89]));
+    /// WEIERSTRASS_A = 0
+    const WEIERSTRASS_A: Fp = field!(Fp, BigInteger256([0x0, 0x0, 0x0, 0x0]));
+    /// WEIERSTRASS_B = 5
+    const WEIERSTRASS_B: Fp =
+        field!(Fp, BigInteger256([0xa1a55e68ffffffed, 0x74c2a54b4f4982f3, 0xfffffffffffffffd, 0x3fffffffffffffff]));
+
+    #[inline(always)]
+    fn mul_by_a(_: &Self::BaseField) -> Self::BaseField {
+        Self::BaseField::zero()
+    }
+
+    fn is_in_correct_subgroup_assuming_on_curve(_: &Affine<Self>) -> bool {
+        // The curve has prime order.
+        true
+    }
+
+    fn glv_endomorphism(mut p: Affine<Self>) -> Affine<Self> {
+        debug_assert!(Self::PHI.pow([3]).is_one());
+        p.x *= &Self::PHI;
+        p
+    }
+
+    fn mul_projective(p: Projective<Self>, by: Self::ScalarField) -> Projective<Self> {
+        glv_mul(p, decompose(by, &GLV_PARAMETERS))
+    }
+}
+
+/// The GLV decomposition parameters, where `b1` and `b2` are the second coordinates of the lattice basis.
+const GLV_PARAMETERS: GlvParameters<Fq> = GlvParameters {
+    minus_b1: PallasParameters::B1,
+    minus_b2: PallasParameters::B2,
+    // LAMBDA = 26005156700822196841419187675678338661165322343552424574062261873906994770353
+    minus_lambda: field!(
+        Fq,
+        BigInteger256([0x4b386e008011111e, 0xb748a7ac309a8664, 0x2c275fb135b2b28, 0x121d29f888245b10])
+    ),
+    g1: [0xa54ad16cb90b8eda, 0xb0d7ef5342407d2a, 0x19624f25ffffffff, 0x93cd3a2c815132a7],
+    g2: [0x6344e2c3cfcc526f, 0xb0d7ef5341f3b445, 0xff95c38e00000001, 0x93cd3a2c81e0922a],
+    r128: PallasParameters::R128,
+};
+
+/// PALLAS_GENERATOR_X = -1
+pub const PALLAS_GENERATOR_X: Fp = field!(Fp, BigInteger256([0x64b4c3b400000004, 0x891a63f02533e46e, 0x0, 0x0]));
+
+/// PALLAS_GENERATOR_Y = 2
+pub const PALLAS_GENERATOR_Y: Fp =
+    field!(Fp, BigInteger256([0xcfc3a984fffffff9, 0x1011d11bbee5303e, 0xffffffffffffffff, 0x3fffffffffffffff]));
@@ -0,0 +1,164 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::{
+    pasta::{Fp, Fq, PallasAffine, PallasParameters, PallasProjective, VestaAffine, VestaParameters, VestaProjective},
+    templates::short_weierstrass_jacobian::tests::{sw_tests, sw_zero_copy_test},
+    traits::{
+        tests_field::{field_serialization_test, field_test, primefield_test, sqrt_field_test},
+        tests_group::*,
+        tests_projective::curve_tests,
+        AffineCurve,
+        ProjectiveCurve,
+        ShortWeierstrassParameters,
+    },
+};
+use snarkvm_fields::{Field, One, PrimeField, Zero};
+use snarkvm_utilities::{
+    rand::{TestRng, Uniform},
+    BitIteratorBE,
+};
+
+use rand::Rng;
+use std::{ops::Mul, str::FromStr};
+
+const ITERATIONS: usize = 10;
+
+#[test]
+fn test_pasta_fp() {
+    let mut rng = TestRng::default();
+
+    for _ in 0..ITERATIONS {
+        let a: Fp = rng.gen();
+        let b: Fp = rng.gen();
+        field_test(a, b, &mut rng);
+        primefield_test::<Fp>(&mut rng);
+        sqrt_field_test(b, &mut rng);
+        field_serialization_test::<Fp>(&mut rng);
+    }
+}
+
+#[test]
+fn test_pasta_fq() {
+    let mut rng = TestRng::default();
+
+    for _ in 0..ITERATIONS {
+        let a: Fq = rng.gen();
+        let b: Fq = rng.gen();
+        field_test(a, b, &mut rng);
+        primefield_test::<Fq>(&mut rng);
+        sqrt_field_test(a, &mut rng);
+        field_serialization_test::<Fq>(&mut rng);
+    }
+}
+
+#[test]
+fn test_pallas_projective_curve() {
+    let mut rng = TestRng::default();
+
+    curve_tests::<PallasProjective>(&mut rng);
+    sw_tests::<PallasParameters>(&mut rng);
+    sw_zero_copy_test::<PallasParameters>(&mut rng);
+}
+
+#[test]
+fn test_pallas_projective_group() {
+    let mut rng = TestRng::default();
+
+    let a: PallasProjective = rng.gen();
+    let b: PallasProjective = rng.gen();
+    projective_test(a, b, &mut rng);
+}
+
+#[test]
+fn test_pallas_glv() {
+    let mut rng = TestRng::default();
+
+    // Ensure the endomorphism acts as multiplication by `LAMBDA`.
+    let generator = PallasAffine::prime_subgroup_generator();
+    let lambda = Fq::from_str("26005156700822196841419187675678338661165322343552424574062261873906994770353").unwrap();
+    assert_eq!(
+        PallasParameters::glv_endomorphism(generator),
+        generator.mul_bits(BitIteratorBE::new_without_leading_zeros(lambda.to_bigint())).to_affine()
+    );
+
+    for _ in 0..ITERATIONS {
+        let point = PallasProjective::rand(&mut rng);
+        let scalar = Fq::rand(&mut rng);
+        let affine = point.to_affine();
+        assert_eq!(point.mul(scalar), affine.mul(scalar));
+        assert_eq!(affine.mul(scalar), affine.mul_bits(BitIteratorBE::new_without_leading_zeros(scalar.to_bigint())));
+    }
+
+    // Ensure the edge cases of the decomposition are handled.
+    let affine = PallasProjective::rand(&mut rng).to_affine();
+    for scalar in [Fq::zero(), Fq::one(), -Fq::one()] {
+        assert_eq!(affine.mul(scalar), affine.mul_bits(BitIteratorBE::new(scalar.to_bigint())));
+    }
+}
+
+#[test]
+fn test_pallas_generator() {
+    let generator = PallasAffine::prime_subgroup_generator();
+    assert!(generator.is_on_curve());
+    assert!(generator.is_in_correct_subgroup_assuming_on_curve());
+    assert!(generator.mul_bits(BitIteratorBE::new(Fq::characteristic())).is_zero());
+}
+
+#[test]
+fn test_vesta_projective_curve() {
+    let mut rng = TestRng::default();
+
+    curve_tests::<VestaProjective>(&mut rng);
+    sw_tests::<VestaParameters>(&mut rng);
+    sw_zero_copy_test::<VestaParameters>(&mut rng);
+}
+
+#[test]
+fn test_vesta_projective_group() {
+    let mut rng = TestRng::default();
+
+    let a: VestaProjective = rng.gen();
+    let b: VestaProjective = rng.gen();
+    projective_test(a, b, &mut rng);
+}
+
+#[test]
+fn test_vesta_glv() {
+    let mut rng = TestRng::default();
+
+    // Ensure the endomorphism acts as multiplication by `LAMBDA`.
+    let generator = VestaAffine::prime_subgroup_generator();
+    let lambda = Fp::from_str("20444556541222657078399132219657928148671392403212669005631716460534733845831").unwrap();
+    assert_eq!(
+        VestaParameters::glv_endomorphism(generator),
+        generator.mul_bits(BitIteratorBE::new_without_leading_zeros(lambda.to_bigint())).to_affine()
+    );
+
+    for _ in 0..ITERATIONS {
+        let point = VestaProjective::rand(&mut rng);
+        let scalar = Fp::rand(&mut rng);
+        let affine = point.to_affine();
+        assert_eq!(point.mul(scalar), affine.mul(scalar));
+        assert_eq!(affine.mul(scalar), affine.mul_bits(BitIteratorBE::new_without_leading_zeros(scalar.to_bigint())));
+    }
+}
+
+#[test]
+fn test_vesta_generator() {
+    let generator = VestaAffine::prime_subgroup_generator();
+    assert!(generator.is_on_curve());
+    assert!(generator.is_in_correct_subgroup_assuming_on_curve());
+    assert!(generator.mul_bits(BitIteratorBE::new(Fp::characteristic())).is_zero());
+}
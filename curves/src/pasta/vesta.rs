@@ -0,0 +1,107 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use snarkvm_fields::{field, Field, One, Zero};
+use snarkvm_utilities::biginteger::BigInteger256;
+
+use crate::{
+    pasta::{Fp, Fq},
+    templates::short_weierstrass_jacobian::{
+        glv::{decompose, glv_mul, GlvParameters},
+        Affine,
+        Projective,
+    },
+    traits::{ModelParameters, ShortWeierstrassParameters},
+};
+
+/// The Vesta curve `y^2 = x^3 + 5` over `Fq`, which has prime order `Fp::MODULUS`.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct VestaParameters;
+
+pub type VestaAffine = Affine<VestaParameters>;
+pub type VestaProjective = Projective<VestaParameters>;
+
+impl ModelParameters for VestaParameters {
+    type BaseField = Fq;
+    type ScalarField = Fp;
+}
+
+impl ShortWeierstrassParameters for VestaParameters {
+    /// AFFINE_GENERATOR_COEFFS = (VESTA_GENERATOR_X, VESTA_GENERATOR_Y)
+    const AFFINE_GENERATOR_COEFFS: (Self::BaseField, Self::BaseField) = (VESTA_GENERATOR_X, VESTA_GENERATOR_Y);
+    /// B1 = -b1 = 98231058071186745657228807397848383488
+    const B1: Fp =
+        field!(Fp, BigInteger256([0x9a01a9d100000001, 0x291d06d324fe7fd9, 0x80025e27f75d1eb8, 0x186bf7a9a14ca0eb]));
+    /// B2 = -b2 = 28948022309329048855892746252171976963264825423870460634022513940995514564608
+    const B2: Fp =
+        field!(Fp, BigInteger256([0x9779620000000004, 0x6266586244e71618, 0xdefafacf6d01995f, 0x279408565e8cfaa1]));
+    /// COFACTOR = 1
+    const COFACTOR: &'static [u64] = &[0x1];
+    /// COFACTOR_INV = COFACTOR^{-1} mod r = 1
+    const COFACTOR_INV: Fp =
+        field!(Fp, BigInteger256([0x34786d38fffffffd, 0x992c350be41914ad, 0xffffffffffffffff, 0x3fffffffffffffff]));
+    /// PHI = 26005156700822196841419187675678338661165322343552424574062261873906994770353
+    /// is a primitive cube root of unity, such that `(PHI * x, y) = [LAMBDA] (x, y)`.
+    const PHI: Fq =
+        field!(Fq, BigInteger256([0x410e7d207feeeee3, 0x6afdf14fd8fa2279, 0xfd3d8a04eca4d4d7, 0x2de2d60777dba4ef]));
+    /// R128 = 2^128 - 1
+    const R128: Fp =
+        field!(Fp, BigInteger256([0x303c567b00000007, 0xefee2ee4411acfc1, 0x9b4b3c4bfffffffc, 0x36e59c0fdacc1b91]));
+    /// WEIERSTRASS_A = 0
+    const WEIERSTRASS_A: Fq = field!(Fq, BigInteger256([0x0, 0x0, 0x0, 0x0]));
+    /// WEIERSTRASS_B = 5
+    const WEIERSTRASS_B: Fq =
+        field!(Fq, BigInteger256([0x96bc8c8cffffffed, 0x74c2a54b49f7778e, 0xfffffffffffffffd, 0x3fffffffffffffff]));
+
+    #[inline(always)]
+    fn mul_by_a(_: &Self::BaseField) -> Self::BaseField {
+        Self::BaseField::zero()
+    }
+
+    fn is_in_correct_subgroup_assuming_on_curve(_: &Affine<Self>) -> bool {
+        // The curve has prime order.
+        true
+    }
+
+    fn glv_endomorphism(mut p: Affine<Self>) -> Affine<Self> {
+        debug_assert!(Self::PHI.pow([3]).is_one());
+        p.x *= &Self::PHI;
+        p
+    }
+
+    fn mul_projective(p: Projective<Self>, by: Self::ScalarField) -> Projective<Self> {
+        glv_mul(p, decompose(by, &GLV_PARAMETERS))
+    }
+}
+
+/// The GLV decomposition parameters, where `b1` and `b2` are the second coordinates of the lattice basis.
+const GLV_PARAMETERS: GlvParameters<Fp> = GlvParameters {
+    minus_b1: VestaParameters::B1,
+    minus_b2: VestaParameters::B2,
+    // LAMBDA = 20444556541222657078399132219657928148671392403212669005631716460534733845831
+    minus_lambda: field!(
+        Fp,
+        BigInteger256([0x9d4d5942619a1539, 0x1571c2a7244cd31f, 0x2a676d5cc87a4666, 0x15d8049da7a17876])
+    ),
+    g1: [0x420a0a6125fccd41, 0xb0d7ef5342e60abc, 0x19624f2600000001, 0x93cd3a2c815132a7],
+    g2: [0x4bc4feeba3d70, 0xb0d7ef53429941d7, 0xff95c38dffffffff, 0x93cd3a2c81e0922a],
+    r128: VestaParameters::R128,
+};
+
+/// VESTA_GENERATOR_X = -1
+pub const VESTA_GENERATOR_X: Fq = field!(Fq, BigInteger256([0x311bac8400000004, 0x891a63f02652a376, 0x0, 0x0]));
+
+/// VESTA_GENERATOR_Y = 2
+pub const VESTA_GENERATOR_Y: Fq =
+    field!(Fq, BigInteger256([0x2a0f9218fffffff9, 0x1011d11bbcef61f1, 0xffffffffffffffff, 0x3fffffffffffffff]));
@@ -0,0 +1,175 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use snarkvm_fields::{
+    FftParameters,
+    FieldParameters,
+    Fp256,
+    Fp256Parameters,
+    PoseidonDefaultParameters,
+    PoseidonDefaultParametersEntry,
+    Reduction,
+};
+use snarkvm_utilities::biginteger::BigInteger256 as BigInteger;
+
+/// The base field of the secp256k1 curve.
+///
+/// The modulus `q = 2^256 - 2^32 - 977` is a pseudo-Mersenne prime, so the field uses Crandall reduction,
+/// and its elements and constants are stored in canonical form rather than in Montgomery form.
+///
+/// Roots of unity computed from modulus using this sage code:
+///
+/// ```ignore
+/// q = 115792089237316195423570985008687907853269984665640564039457584007908834671663
+/// s = 1
+/// o = q - 1
+/// F = GF(q)
+/// g = F(3)
+/// assert g.multiplicative_order() == o
+/// g2 = g ** (o/2**s)
+/// assert g2.multiplicative_order() == 2**s
+/// def into_chunks(val, width, n):
+///     return [int(int(val) // (2 ** (width * i)) % 2 ** width) for i in range(n)]
+/// print("Gen (g % q): ", g % q)
+/// print("2-adic gen (g2 % q): ", g2 % q)
+/// print("2-adic gen into_chunks(g2 % q): ", into_chunks(g2 % q, 64, 4))
+/// ```
+pub type Fq = Fp256<FqParameters>;
+
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub struct FqParameters;
+
+impl Fp256Parameters for FqParameters {}
+
+impl FftParameters for FqParameters {
+    type BigInteger = BigInteger;
+
+    /// The two-adicity is 1, so there are no further powers of the root of unity.
+    const POWERS_OF_ROOTS_OF_UNITY: &'static [BigInteger] = &[];
+    #[rustfmt::skip]
+    const TWO_ADICITY: u32 = 1;
+    /// TWO_ADIC_ROOT_OF_UNITY = 115792089237316195423570985008687907853269984665640564039457584007908834671662
+    #[rustfmt::skip]
+    const TWO_ADIC_ROOT_OF_UNITY: BigInteger = BigInteger([
+        0xfffffffefffffc2e,
+        18446744073709551615u64,
+        18446744073709551615u64,
+        18446744073709551615u64,
+    ]);
+}
+
+impl FieldParameters for FqParameters {
+    #[rustfmt::skip]
+    const CAPACITY: u32 = Self::MODULUS_BITS - 1;
+    /// GENERATOR = 3
+    #[rustfmt::skip]
+    const GENERATOR: BigInteger = BigInteger([
+        3u64,
+        0u64,
+        0u64,
+        0u64,
+    ]);
+    #[rustfmt::skip]
+    const INV: u64 = 15580212934572586289u64;
+    /// MODULUS = 115792089237316195423570985008687907853269984665640564039457584007908834671663
+    #[rustfmt::skip]
+    const MODULUS: BigInteger = BigInteger([
+        0xfffffffefffffc2f,
+        0xffffffffffffffff,
+        0xffffffffffffffff,
+        0xffffffffffffffff,
+    ]);
+    #[rustfmt::skip]
+    const MODULUS_BITS: u32 = 256;
+    /// (r - 1) / 2 =
+    /// 57896044618658097711785492504343953926634992332820282019728792003954417335831
+    #[rustfmt::skip]
+    const MODULUS_MINUS_ONE_DIV_TWO: BigInteger = BigInteger([
+        0xffffffff7ffffe17,
+        0xffffffffffffffff,
+        0xffffffffffffffff,
+        0x7fffffffffffffff,
+    ]);
+    /// R = 1, as the elements are stored in canonical form.
+    #[rustfmt::skip]
+    const R: BigInteger = BigInteger([
+        1u64,
+        0u64,
+        0u64,
+        0u64,
+    ]);
+    /// R2 = 1, as the elements are stored in canonical form.
+    #[rustfmt::skip]
+    const R2: BigInteger = BigInteger([
+        1u64,
+        0u64,
+        0u64,
+        0u64,
+    ]);
+    /// MODULUS = 2^256 - c, with c = 2^32 + 977.
+    const REDUCTION: Reduction = Reduction::Crandall { c: 0x1000003d1 };
+    #[rustfmt::skip]
+    const REPR_SHAVE_BITS: u32 = 0;
+    // T and T_MINUS_ONE_DIV_TWO, where r - 1 = 2^s * t
+
+    /// t = (r - 1) / 2^s =
+    /// 57896044618658097711785492504343953926634992332820282019728792003954417335831
+    #[rustfmt::skip]
+    const T: BigInteger = BigInteger([
+        0xffffffff7ffffe17,
+        0xffffffffffffffff,
+        0xffffffffffffffff,
+        0x7fffffffffffffff,
+    ]);
+    /// (t - 1) / 2 =
+    /// 28948022309329048855892746252171976963317496166410141009864396001977208667915
+    #[rustfmt::skip]
+    const T_MINUS_ONE_DIV_TWO: BigInteger = BigInteger([
+        0xffffffffbfffff0b,
+        0xffffffffffffffff,
+        0xffffffffffffffff,
+        0x3fffffffffffffff,
+    ]);
+}
+
+impl PoseidonDefaultParameters for FqParameters {
+    const PARAMS_OPT_FOR_CONSTRAINTS: [PoseidonDefaultParametersEntry; 7] = [
+        PoseidonDefaultParametersEntry::new(2, 5, 8, 56, 0),
+        PoseidonDefaultParametersEntry::new(3, 5, 8, 56, 0),
+        PoseidonDefaultParametersEntry::new(4, 5, 8, 56, 0),
+        PoseidonDefaultParametersEntry::new(5, 5, 8, 57, 0),
+        PoseidonDefaultParametersEntry::new(6, 5, 8, 57, 0),
+        PoseidonDefaultParametersEntry::new(7, 5, 8, 57, 0),
+        PoseidonDefaultParametersEntry::new(8, 5, 8, 57, 0),
+    ];
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_fields::{FftField, Field, One};
+
+    #[test]
+    fn test_powers_of_root_of_unity() {
+        // The two-adicity is 1, so the two-adic root of unity is -1.
+        assert!(FqParameters::POWERS_OF_ROOTS_OF_UNITY.is_empty());
+        assert_eq!(Fq::two_adic_root_of_unity(), -Fq::one());
+    }
+
+    #[test]
+    fn test_two_adic_root_of_unity() {
+        let expected = Fq::multiplicative_generator().pow(FqParameters::T);
+        assert_eq!(expected, Fq::two_adic_root_of_unity());
+    }
+}
@@ -0,0 +1,105 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use snarkvm_fields::{field, Field, One, Zero};
+use snarkvm_utilities::biginteger::BigInteger256;
+
+use crate::{
+    secp256k1::{Fq, Fr},
+    templates::short_weierstrass_jacobian::{
+        glv::{decompose, glv_mul, GlvParameters},
+        Affine,
+        Projective,
+    },
+    traits::{ModelParameters, ShortWeierstrassParameters},
+};
+
+/// The secp256k1 curve `y^2 = x^3 + 7` over `Fq`, which has prime order `Fr::MODULUS`.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct Secp256k1Parameters;
+
+pub type Secp256k1Affine = Affine<Secp256k1Parameters>;
+pub type Secp256k1Projective = Projective<Secp256k1Parameters>;
+
+impl ModelParameters for Secp256k1Parameters {
+    type BaseField = Fq;
+    type ScalarField = Fr;
+}
+
+impl ShortWeierstrassParameters for Secp256k1Parameters {
+    /// AFFINE_GENERATOR_COEFFS = (SECP256K1_GENERATOR_X, SECP256K1_GENERATOR_Y)
+    const AFFINE_GENERATOR_COEFFS: (Self::BaseField, Self::BaseField) = (SECP256K1_GENERATOR_X, SECP256K1_GENERATOR_Y);
+    /// B1 = -b1 = 303414439467246543595250775667605759171
+    const B1: Fr =
+        field!(Fr, BigInteger256([0xc50468d00ad9263c, 0x1b1c8205faa6ed42, 0x1571b4ae8ac47f71, 0x221208ac9df506c6]));
+    /// B2 = -b2 = 115792089237316195423570985008687907852773061305525697825976578096156627785260
+    const B2: Fr =
+        field!(Fr, BigInteger256([0xcac5e506a144696, 0x1e8a8dc5f3ba5939, 0x176cdf65ba244fce, 0xc25575eb8e173580]));
+    /// COFACTOR = 1
+    const COFACTOR: &'static [u64] = &[0x1];
+    /// COFACTOR_INV = COFACTOR^{-1} mod r = 1
+    const COFACTOR_INV: Fr = field!(Fr, BigInteger256([0x402da1732fc9bebf, 0x4551231950b75fc4, 0x1, 0x0]));
+    /// PHI = 55594575648329892869085402983802832744385952214688224221778511981742606582254
+    /// is a primitive cube root of unity, such that `(PHI * x, y) = [LAMBDA] (x, y)`.
+    const PHI: Fq =
+        field!(Fq, BigInteger256([0xc1396c28719501ee, 0x9cf0497512f58995, 0x6e64479eac3434e9, 0x7ae96a2b657c0710]));
+    /// R128 = 2^128 - 1
+    const R128: Fr = field!(Fr, BigInteger256([0x0, 0x0, 0x402da1732fc9bebf, 0x4551231950b75fc4]));
+    /// WEIERSTRASS_A = 0
+    const WEIERSTRASS_A: Fq = field!(Fq, BigInteger256([0x0, 0x0, 0x0, 0x0]));
+    /// WEIERSTRASS_B = 7
+    const WEIERSTRASS_B: Fq = field!(Fq, BigInteger256([0x7, 0x0, 0x0, 0x0]));
+
+    #[inline(always)]
+    fn mul_by_a(_: &Self::BaseField) -> Self::BaseField {
+        Self::BaseField::zero()
+    }
+
+    fn is_in_correct_subgroup_assuming_on_curve(_: &Affine<Self>) -> bool {
+        // The curve has prime order.
+        true
+    }
+
+    fn glv_endomorphism(mut p: Affine<Self>) -> Affine<Self> {
+        debug_assert!(Self::PHI.pow([3]).is_one());
+        p.x *= &Self::PHI;
+        p
+    }
+
+    fn mul_projective(p: Projective<Self>, by: Self::ScalarField) -> Projective<Self> {
+        glv_mul(p, decompose(by, &GLV_PARAMETERS))
+    }
+}
+
+/// The GLV decomposition parameters, where `b1` and `b2` are the second coordinates of the lattice basis.
+const GLV_PARAMETERS: GlvParameters<Fr> = GlvParameters {
+    minus_b1: Secp256k1Parameters::B1,
+    minus_b2: Secp256k1Parameters::B2,
+    // LAMBDA = 37718080363155996902926221483475020450927657555482586988616620542887997980018
+    minus_lambda: field!(
+        Fr,
+        BigInteger256([0xcf54734f06a3d4a3, 0x8e1af5392b820bee, 0x8c5699f9ad96826d, 0xacd7bfe87aa729c6])
+    ),
+    g1: [0xf449904d22edd818, 0x9ed5450a38f4653f, 0xf43648724942758a, 0x18436910d3ea35e6],
+    g2: [0xab8da5745623fb9, 0x110904564efa8363, 0x37aa3fd4855ff262, 0x7221bf6b00874414],
+    r128: Secp256k1Parameters::R128,
+};
+
+/// SECP256K1_GENERATOR_X = 55066263022277343669578718895168534326250603453777594175500187360389116729240
+pub const SECP256K1_GENERATOR_X: Fq =
+    field!(Fq, BigInteger256([0x59f2815b16f81798, 0x29bfcdb2dce28d9, 0x55a06295ce870b07, 0x79be667ef9dcbbac]));
+
+/// SECP256K1_GENERATOR_Y = 32670510020758816978083085130507043184471273380659243275938904335757337482424
+pub const SECP256K1_GENERATOR_Y: Fq =
+    field!(Fq, BigInteger256([0x9c47d08ffb10d4b8, 0xfd17b448a6855419, 0x5da4fbfc0e1108a8, 0x483ada7726a3c465]));
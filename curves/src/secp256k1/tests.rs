@@ -0,0 +1,191 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::{
+    secp256k1::{Fq, Fr, Secp256k1Affine, Secp256k1Parameters, Secp256k1Projective},
+    templates::short_weierstrass_jacobian::tests::{sw_tests, sw_zero_copy_test},
+    traits::{
+        tests_field::{field_serialization_test, field_test, primefield_test, sqrt_field_test},
+        tests_group::*,
+        tests_projective::curve_tests,
+        AffineCurve,
+        ProjectiveCurve,
+        ShortWeierstrassParameters,
+    },
+};
+use snarkvm_fields::{const_field, Field, One, PrimeField, Zero};
+use snarkvm_utilities::{
+    rand::{TestRng, Uniform},
+    BitIteratorBE,
+};
+
+use rand::Rng;
+use std::{ops::Mul, str::FromStr};
+
+const ITERATIONS: usize = 10;
+
+#[test]
+fn test_secp256k1_fq() {
+    let mut rng = TestRng::default();
+
+    for _ in 0..ITERATIONS {
+        let a: Fq = rng.gen();
+        let b: Fq = rng.gen();
+        field_test(a, b, &mut rng);
+        primefield_test::<Fq>(&mut rng);
+        sqrt_field_test(b, &mut rng);
+        field_serialization_test::<Fq>(&mut rng);
+    }
+}
+
+#[test]
+fn test_secp256k1_const_field() {
+    // The base field modulus leaves no spare bit, so this exercises the carry out of the top limb.
+    const FQ_MINUS_ONE: Fq =
+        const_field!(Fq, "115792089237316195423570985008687907853269984665640564039457584007908834671662");
+    assert_eq!(FQ_MINUS_ONE, -Fq::one());
+
+    let mut rng = TestRng::default();
+    for _ in 0..ITERATIONS {
+        let a: Fq = rng.gen();
+        assert_eq!(Fq::from_canonical_limbs(a.to_bigint().0), a);
+    }
+}
+
+#[test]
+fn test_secp256k1_fr() {
+    let mut rng = TestRng::default();
+
+    for _ in 0..ITERATIONS {
+        let a: Fr = rng.gen();
+        let b: Fr = rng.gen();
+        field_test(a, b, &mut rng);
+        primefield_test::<Fr>(&mut rng);
+        sqrt_field_test(a, &mut rng);
+        field_serialization_test::<Fr>(&mut rng);
+    }
+}
+
+#[test]
+fn test_secp256k1_fq_full_modulus() {
+    // The modulus occupies all 256 bits, so ensure the carries out of the top limb are handled.
+    let minus_one = -Fq::one();
+    assert_eq!(minus_one + minus_one, -Fq::from(2u8));
+    assert_eq!(minus_one.double(), -Fq::from(2u8));
+    assert_eq!(minus_one * minus_one, Fq::one());
+    assert_eq!(minus_one.square(), Fq::one());
+    assert_eq!(minus_one.inverse().unwrap(), minus_one);
+    assert_eq!(Fq::from(2u8).inverse().unwrap() * Fq::from(2u8), Fq::one());
+}
+
+#[test]
+fn test_secp256k1_fq_crandall() {
+    let mut rng = TestRng::default();
+
+    // The elements are stored in canonical form.
+    for _ in 0..ITERATIONS {
+        let a = Fq::rand(&mut rng);
+        assert_eq!(a.to_montgomery_bigint(), a.to_bigint());
+    }
+    // 2^256 = 2^32 + 977 (mod q).
+    assert_eq!(Fq::from(2u8).pow([256]), Fq::from(0x1000003d1u64));
+    // The largest product folds its high half twice.
+    let minus_one = -Fq::one();
+    assert_eq!(minus_one * Fq::from(2u8).pow([255]), -Fq::from(2u8).pow([255]));
+}
+
+#[test]
+fn test_secp256k1_projective_curve() {
+    let mut rng = TestRng::default();
+
+    curve_tests::<Secp256k1Projective>(&mut rng);
+    sw_tests::<Secp256k1Parameters>(&mut rng);
+    sw_zero_copy_test::<Secp256k1Parameters>(&mut rng);
+}
+
+#[test]
+fn test_secp256k1_projective_group() {
+    let mut rng = TestRng::default();
+
+    let a: Secp256k1Projective = rng.gen();
+    let b: Secp256k1Projective = rng.gen();
+    projective_test(a, b, &mut rng);
+}
+
+#[test]
+fn test_secp256k1_glv() {
+    let mut rng = TestRng::default();
+
+    // Ensure the endomorphism acts as multiplication by `LAMBDA`.
+    let generator = Secp256k1Affine::prime_subgroup_generator();
+    let lambda = Fr::from_str("37718080363155996902926221483475020450927657555482586988616620542887997980018").unwrap();
+    assert_eq!(
+        Secp256k1Parameters::glv_endomorphism(generator),
+        generator.mul_bits(BitIteratorBE::new_without_leading_zeros(lambda.to_bigint())).to_affine()
+    );
+
+    for _ in 0..ITERATIONS {
+        let point = Secp256k1Projective::rand(&mut rng);
+        let scalar = Fr::rand(&mut rng);
+        let affine = point.to_affine();
+        assert_eq!(point.mul(scalar), affine.mul(scalar));
+        assert_eq!(affine.mul(scalar), affine.mul_bits(BitIteratorBE::new_without_leading_zeros(scalar.to_bigint())));
+    }
+
+    // Ensure the edge cases of the decomposition are handled.
+    let affine = Secp256k1Projective::rand(&mut rng).to_affine();
+    for scalar in [Fr::zero(), Fr::one(), -Fr::one()] {
+        assert_eq!(affine.mul(scalar), affine.mul_bits(BitIteratorBE::new(scalar.to_bigint())));
+    }
+}
+
+#[test]
+fn test_secp256k1_generator() {
+    let generator = Secp256k1Affine::prime_subgroup_generator();
+    assert!(generator.is_on_curve());
+    assert!(generator.is_in_correct_subgroup_assuming_on_curve());
+    assert!(generator.mul_bits(BitIteratorBE::new(Fr::characteristic())).is_zero());
+}
+
+#[test]
+fn test_secp256k1_known_vectors() {
+    let point = |x: &str, y: &str| Secp256k1Affine::new(Fq::from_str(x).unwrap(), Fq::from_str(y).unwrap(), false);
+    let generator = Secp256k1Affine::prime_subgroup_generator();
+
+    // 2 * G
+    let expected = point(
+        "89565891926547004231252920425935692360644145829622209833684329913297188986597",
+        "12158399299693830322967808612713398636155367887041628176798871954788371653930",
+    );
+    assert_eq!(generator.mul(Fr::from(2u8)).to_affine(), expected);
+    assert_eq!(generator.to_projective().double().to_affine(), expected);
+
+    // 3 * G
+    let expected = point(
+        "112711660439710606056748659173929673102114977341539408544630613555209775888121",
+        "25583027980570883691656905877401976406448868254816295069919888960541586679410",
+    );
+    assert_eq!(generator.mul(Fr::from(3u8)).to_affine(), expected);
+
+    // k * G
+    let scalar = Fr::from_str("77059549740374936337596179780007572461065571555507600191520924336939429631266").unwrap();
+    let expected = point(
+        "23960696573610029253367988531088137163395307586261939660421638862381187549638",
+        "5176714262835066281222529495396963740342889891785920566957581938958806065714",
+    );
+    assert_eq!(generator.mul(scalar).to_affine(), expected);
+
+    // (r - 1) * G = -G
+    assert_eq!(generator.mul(-Fr::one()).to_affine(), -generator);
+}
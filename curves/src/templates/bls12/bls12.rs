@@ -95,7 +95,8 @@ impl<P: Bls12Parameters> Bls12<P> {
     }
 
     fn exp_by_x(mut f: Fp12<P::Fp12Params>) -> Fp12<P::Fp12Params> {
-        f = f.cyclotomic_exp(P::X);
+        // The parameter `x` is sparse, so use compressed squarings.
+        f = f.cyclotomic_exp_compressed(P::X);
         if P::X_IS_NEGATIVE {
             f.conjugate();
         }
@@ -20,7 +20,7 @@ use crate::{
     traits::{AffineCurve, ShortWeierstrassParameters},
 };
 use snarkvm_fields::{Field, Fp2, One, Zero};
-use snarkvm_utilities::{bititerator::BitIteratorBE, serialize::*, ToBytes};
+use snarkvm_utilities::{bititerator::BitIteratorBE, error, serialize::*, ToBytes};
 
 use std::io::{Result as IoResult, Write};
 
@@ -29,7 +29,7 @@ pub type G2Projective<P> = Projective<<P as Bls12Parameters>::G2Parameters>;
 type CoeffTriplet<T> = (Fp2<T>, Fp2<T>, Fp2<T>);
 
 #[allow(clippy::derive_partial_eq_without_eq)]
-#[derive(Clone, Debug, PartialEq, Eq, Hash, CanonicalSerialize, CanonicalDeserialize)]
+#[derive(Clone, Debug, PartialEq, Eq, Hash, CanonicalSerialize)]
 pub struct G2Prepared<P: Bls12Parameters> {
     // Stores the coefficients of the line evaluations as calculated in
     // https://eprint.iacr.org/2013/722.pdf
@@ -75,7 +75,34 @@ impl<P: Bls12Parameters> FromBytes for G2Prepared<P> {
 
         let infinity: bool = FromBytes::read_le(&mut reader)?;
 
-        Ok(Self { ell_coeffs, infinity })
+        let prepared = Self { ell_coeffs, infinity };
+        prepared.check().map_err(|_| error("Invalid number of line coefficients in G2Prepared"))?;
+        Ok(prepared)
+    }
+}
+
+impl<P: Bls12Parameters> CanonicalDeserialize for G2Prepared<P> {
+    fn deserialize_with_mode<R: Read>(
+        mut reader: R,
+        compress: Compress,
+        validate: Validate,
+    ) -> Result<Self, SerializationError> {
+        let ell_coeffs = CanonicalDeserialize::deserialize_with_mode(&mut reader, compress, validate)?;
+        let infinity = CanonicalDeserialize::deserialize_with_mode(&mut reader, compress, validate)?;
+        let prepared = Self { ell_coeffs, infinity };
+        // The Miller loop consumes exactly `num_ell_coeffs` coefficients, so the count is always enforced.
+        prepared.check()?;
+        Ok(prepared)
+    }
+}
+
+impl<P: Bls12Parameters> Valid for G2Prepared<P> {
+    fn check(&self) -> Result<(), SerializationError> {
+        let expected = if self.infinity { 0 } else { Self::num_ell_coeffs() };
+        match self.ell_coeffs.len() == expected {
+            true => Ok(()),
+            false => Err(SerializationError::InvalidData),
+        }
     }
 }
 
@@ -84,6 +111,12 @@ impl<P: Bls12Parameters> G2Prepared<P> {
         self.infinity
     }
 
+    /// Returns the number of line coefficients cached for a point other than the point at infinity,
+    /// which is one per doubling step and one per addition step of the Miller loop.
+    pub fn num_ell_coeffs() -> usize {
+        BitIteratorBE::new(P::X).skip(1).map(|bit| 1 + bit as usize).sum()
+    }
+
     pub fn from_affine(q: G2Affine<P>) -> Self {
         if q.is_zero() {
             return Self { ell_coeffs: vec![], infinity: true };
@@ -262,3 +262,55 @@ macro_rules! impl_edwards_curve_serializer {
         }
     };
 }
+
+#[macro_export]
+macro_rules! impl_affine_serde {
+    ($params: ident) => {
+        /// Serializes the point as the hex string of its compressed bytes in human-readable formats,
+        /// and as its compressed bytes otherwise.
+        impl<P: $params> serde::Serialize for Affine<P> {
+            fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+                use serde::ser::SerializeTuple;
+
+                let mut bytes = Vec::with_capacity(self.compressed_size());
+                self.serialize_compressed(&mut bytes).map_err(serde::ser::Error::custom)?;
+                match serializer.is_human_readable() {
+                    true => serializer.serialize_str(&hex::encode(bytes)),
+                    false => {
+                        let mut tuple = serializer.serialize_tuple(bytes.len())?;
+                        for byte in &bytes {
+                            tuple.serialize_element(byte)?;
+                        }
+                        tuple.end()
+                    }
+                }
+            }
+        }
+
+        /// Deserializes the point from the hex string of its compressed bytes in human-readable formats,
+        /// and from its compressed bytes otherwise, ensuring it is on the curve and in the prime-order subgroup.
+        impl<'de, P: $params> serde::Deserialize<'de> for Affine<P> {
+            fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+                let size = Self::zero().compressed_size();
+                let bytes = match deserializer.is_human_readable() {
+                    true => {
+                        let string: String = serde::Deserialize::deserialize(deserializer)?;
+                        hex::decode(string).map_err(serde::de::Error::custom)?
+                    }
+                    false => {
+                        let mut bytes = Vec::with_capacity(size);
+                        deserializer.deserialize_tuple(
+                            size,
+                            snarkvm_utilities::FromBytesVisitor::new(&mut bytes, "affine point"),
+                        )?;
+                        bytes
+                    }
+                };
+                if bytes.len() != size {
+                    return Err(serde::de::Error::custom("invalid length of the affine point"));
+                }
+                Self::deserialize_compressed(&*bytes).map_err(serde::de::Error::custom)
+            }
+        }
+    };
+}
@@ -13,6 +13,7 @@
 // limitations under the License.
 
 pub mod bls12;
+pub mod montgomery;
 pub mod short_weierstrass_jacobian;
 pub mod to_field_vec;
 pub mod twisted_edwards_extended;
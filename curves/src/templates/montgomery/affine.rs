@@ -0,0 +1,110 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::{templates::twisted_edwards_extended, traits::MontgomeryParameters as Parameters};
+use snarkvm_fields::{Field, One, Zero};
+
+use serde::{Deserialize, Serialize};
+
+/// The twisted Edwards curve that is birationally equivalent to the Montgomery curve.
+type EdwardsAffine<P> = twisted_edwards_extended::Affine<<P as Parameters>::TwistedEdwardsParameters>;
+
+/// A point `(x, y)` on the Montgomery curve `B * y^2 = x^3 + A * x^2 + x`, or the point at infinity.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct Affine<P: Parameters> {
+    pub x: P::BaseField,
+    pub y: P::BaseField,
+    pub infinity: bool,
+}
+
+impl<P: Parameters> Affine<P> {
+    #[inline]
+    pub const fn new(x: P::BaseField, y: P::BaseField, infinity: bool) -> Self {
+        Self { x, y, infinity }
+    }
+
+    /// Returns the point at infinity.
+    #[inline]
+    pub fn zero() -> Self {
+        Self::new(P::BaseField::zero(), P::BaseField::one(), true)
+    }
+
+    /// Returns `true` if the point is the point at infinity.
+    #[inline]
+    pub const fn is_zero(&self) -> bool {
+        self.infinity
+    }
+
+    /// Checks that the current point is on the elliptic curve.
+    pub fn is_on_curve(&self) -> bool {
+        if self.is_zero() {
+            true
+        } else {
+            let x2 = self.x.square();
+            let lhs = P::MONTGOMERY_B * self.y.square();
+            let rhs = x2 * self.x + P::MONTGOMERY_A * x2 + self.x;
+            lhs == rhs
+        }
+    }
+
+    /// Maps a point on the twisted Edwards curve to the Montgomery curve,
+    /// with `(x, y) -> ((1 + y) / (1 - y), (1 + y) / ((1 - y) * x))`.
+    ///
+    /// The identity `(0, 1)` maps to the point at infinity, and the point of order two `(0, -1)` maps to `(0, 0)`.
+    pub fn from_twisted_edwards(point: &EdwardsAffine<P>) -> Self {
+        let one = P::BaseField::one();
+        if point.x.is_zero() {
+            match point.y.is_one() {
+                true => Self::zero(),
+                false => Self::new(P::BaseField::zero(), P::BaseField::zero(), false),
+            }
+        } else {
+            // Note: The unwrap is safe, as `y = 1` implies `x = 0` for points on the curve.
+            let x = (one + point.y) * (one - point.y).inverse().unwrap();
+            let y = x * point.x.inverse().unwrap();
+            Self::new(x, y, false)
+        }
+    }
+
+    /// Maps the point to the twisted Edwards curve, with `(x, y) -> (x / y, (x - 1) / (x + 1))`.
+    ///
+    /// Returns `None` for the exceptional points with `y = 0` and `x != 0`, or with `x = -1`,
+    /// which have no twisted Edwards equivalent.
+    pub fn to_twisted_edwards(&self) -> Option<EdwardsAffine<P>> {
+        if self.is_zero() {
+            return Some(EdwardsAffine::<P>::zero());
+        }
+        if self.x.is_zero() {
+            return Some(EdwardsAffine::<P>::new(P::BaseField::zero(), -P::BaseField::one(), P::BaseField::zero()));
+        }
+
+        let one = P::BaseField::one();
+        let x = self.x * self.y.inverse()?;
+        let y = (self.x - one) * (self.x + one).inverse()?;
+        Some(EdwardsAffine::<P>::new(x, y, x * y))
+    }
+}
+
+impl<P: Parameters> Default for Affine<P> {
+    #[inline]
+    fn default() -> Self {
+        Self::zero()
+    }
+}
+
+impl<P: Parameters> From<EdwardsAffine<P>> for Affine<P> {
+    fn from(point: EdwardsAffine<P>) -> Self {
+        Self::from_twisted_edwards(&point)
+    }
+}
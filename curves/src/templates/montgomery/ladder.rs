@@ -0,0 +1,73 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::traits::MontgomeryParameters as Parameters;
+use snarkvm_fields::{Field, One, PrimeField, Zero};
+use snarkvm_utilities::BitIteratorBE;
+
+/// Swaps `a` and `b` if `swap` is set, without branching on `swap`.
+#[inline]
+fn conditional_swap<F: Field>(a: &mut F, b: &mut F, swap: bool) {
+    let t = F::from(swap as u8) * (*b - *a);
+    *a += t;
+    *b -= t;
+}
+
+/// Multiplies the point with `x`-coordinate `x` by the scalar with the given big-endian bits,
+/// using the `x`-only Montgomery ladder, and returns the `x`-coordinate of the result.
+///
+/// The ladder performs the same field operations for every bit, so its control flow only depends on the
+/// number of bits. The point at infinity is returned as `0`, as in X25519 (RFC 7748).
+pub fn x_only_mul_bits<P: Parameters>(x: P::BaseField, bits: impl Iterator<Item = bool>) -> P::BaseField {
+    // a24 = (A + 2) / 4
+    let two = P::BaseField::one().double();
+    let a24 = (P::MONTGOMERY_A + two) * two.double().inverse().unwrap();
+
+    // (x2 : z2) starts at infinity, and (x3 : z3) starts at the input point.
+    let (mut x2, mut z2) = (P::BaseField::one(), P::BaseField::zero());
+    let (mut x3, mut z3) = (x, P::BaseField::one());
+    let mut swap = false;
+
+    for bit in bits {
+        swap ^= bit;
+        conditional_swap(&mut x2, &mut x3, swap);
+        conditional_swap(&mut z2, &mut z3, swap);
+        swap = bit;
+
+        // Differential addition of (x2 : z2) and (x3 : z3), and doubling of (x2 : z2).
+        let a = x2 + z2;
+        let aa = a.square();
+        let b = x2 - z2;
+        let bb = b.square();
+        let e = aa - bb;
+        let c = x3 + z3;
+        let d = x3 - z3;
+        let da = d * a;
+        let cb = c * b;
+        x3 = (da + cb).square();
+        z3 = x * (da - cb).square();
+        x2 = aa * bb;
+        z2 = e * (bb + a24 * e);
+    }
+    conditional_swap(&mut x2, &mut x3, swap);
+    conditional_swap(&mut z2, &mut z3, swap);
+
+    x2 * z2.inverse().unwrap_or_else(P::BaseField::zero)
+}
+
+/// Multiplies the point with `x`-coordinate `x` by `scalar`, using the `x`-only Montgomery ladder,
+/// and returns the `x`-coordinate of the result. The ladder iterates over all bits of the scalar field.
+pub fn x_only_mul<P: Parameters>(x: P::BaseField, scalar: P::ScalarField) -> P::BaseField {
+    x_only_mul_bits::<P>(x, BitIteratorBE::new(scalar.to_bigint()))
+}
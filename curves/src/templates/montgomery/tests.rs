@@ -0,0 +1,83 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::{x_only_mul, Affine};
+use crate::{
+    templates::twisted_edwards_extended,
+    traits::{AffineCurve, MontgomeryParameters, ProjectiveCurve, TwistedEdwardsParameters},
+};
+use snarkvm_fields::{One, Zero};
+use snarkvm_utilities::{rand::Uniform, TestRng};
+
+pub const ITERATIONS: usize = 10;
+
+pub fn montgomery_test<P>(rng: &mut TestRng)
+where
+    P: TwistedEdwardsParameters + MontgomeryParameters<TwistedEdwardsParameters = P>,
+{
+    montgomery_birational_map_test::<P>(rng);
+    montgomery_ladder_test::<P>(rng);
+}
+
+pub fn montgomery_birational_map_test<P>(rng: &mut TestRng)
+where
+    P: TwistedEdwardsParameters + MontgomeryParameters<TwistedEdwardsParameters = P>,
+{
+    for _ in 0..ITERATIONS {
+        let point = twisted_edwards_extended::Projective::<P>::rand(rng).to_affine();
+        let montgomery = Affine::<P>::from_twisted_edwards(&point);
+        assert!(montgomery.is_on_curve());
+        assert_eq!(montgomery.to_twisted_edwards(), Some(point));
+    }
+
+    // Ensure the exceptional points are mapped.
+    let identity = twisted_edwards_extended::Affine::<P>::zero();
+    assert!(Affine::<P>::from_twisted_edwards(&identity).is_zero());
+    assert_eq!(Affine::<P>::zero().to_twisted_edwards(), Some(identity));
+
+    let order_two =
+        twisted_edwards_extended::Affine::<P>::new(P::BaseField::zero(), -P::BaseField::one(), P::BaseField::zero());
+    let montgomery = Affine::<P>::from_twisted_edwards(&order_two);
+    assert!(montgomery.is_on_curve());
+    assert_eq!(montgomery, Affine::<P>::new(P::BaseField::zero(), P::BaseField::zero(), false));
+    assert_eq!(montgomery.to_twisted_edwards(), Some(order_two));
+}
+
+pub fn montgomery_ladder_test<P>(rng: &mut TestRng)
+where
+    P: TwistedEdwardsParameters + MontgomeryParameters<TwistedEdwardsParameters = P>,
+{
+    let x_coordinate = |point: twisted_edwards_extended::Affine<P>| {
+        let montgomery = Affine::<P>::from_twisted_edwards(&point);
+        match montgomery.is_zero() {
+            true => P::BaseField::zero(),
+            false => montgomery.x,
+        }
+    };
+
+    for _ in 0..ITERATIONS {
+        let point = twisted_edwards_extended::Projective::<P>::rand(rng).to_affine();
+        let scalar = <P as TwistedEdwardsParameters>::ScalarField::rand(rng);
+
+        let expected = x_coordinate((point * scalar).to_affine());
+        assert_eq!(x_only_mul::<P>(x_coordinate(point), scalar), expected);
+    }
+
+    // Ensure the edge cases of the ladder are handled.
+    let point = twisted_edwards_extended::Projective::<P>::rand(rng).to_affine();
+    let x = x_coordinate(point);
+    assert_eq!(x_only_mul::<P>(x, Zero::zero()), P::BaseField::zero());
+    assert_eq!(x_only_mul::<P>(x, One::one()), x);
+    assert_eq!(x_only_mul::<P>(x, -<P as TwistedEdwardsParameters>::ScalarField::one()), x);
+}
@@ -13,16 +13,20 @@
 // limitations under the License.
 
 use crate::{
+    impl_affine_serde,
     impl_sw_curve_serializer,
     templates::short_weierstrass_jacobian::Projective,
-    traits::{AffineCurve, ProjectiveCurve, ShortWeierstrassParameters as Parameters},
+    traits::{AffineCurve, AffineLimbs, ProjectiveCurve, ShortWeierstrassParameters as Parameters},
 };
-use snarkvm_fields::{Field, One, SquareRootField, Zero};
+use snarkvm_fields::{Field, One, PrimeField, SquareRootField, Zero};
 use snarkvm_utilities::{
     bititerator::BitIteratorBE,
+    cfg_chunks,
+    cfg_iter,
     io::{Error, ErrorKind, Read, Result as IoResult, Write},
     rand::Uniform,
     serialize::*,
+    BigInteger,
     FromBytes,
     ToBytes,
 };
@@ -35,9 +39,11 @@ use rand::{
     distributions::{Distribution, Standard},
     Rng,
 };
-use serde::{Deserialize, Serialize};
+#[cfg(not(feature = "serial"))]
+use rayon::prelude::*;
 
-#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+#[repr(C)]
 pub struct Affine<P: Parameters> {
     pub x: P::BaseField,
     pub y: P::BaseField,
@@ -49,6 +55,57 @@ impl<P: Parameters> Affine<P> {
     pub const fn new(x: P::BaseField, y: P::BaseField, infinity: bool) -> Self {
         Self { x, y, infinity }
     }
+
+    /// Deserializes a batch of concatenated compressed points, ensuring each point is in the prime-order subgroup.
+    ///
+    /// Decompression and the subgroup checks are parallelized across the batch.
+    pub fn deserialize_batch(bytes: &[u8]) -> Result<Vec<Self>, SerializationError> {
+        let point_size = Self::zero().serialized_size(Compress::Yes);
+        if bytes.len() % point_size != 0 {
+            return Err(SerializationError::InvalidData);
+        }
+
+        // Decompress the points. Note: Decompression recovers `y` from the curve equation,
+        // which ensures that every point is on the curve.
+        let points = cfg_chunks!(bytes, point_size)
+            .map(|point| Self::deserialize_with_mode(point, Compress::Yes, Validate::No))
+            .collect::<Result<Vec<_>, _>>()?;
+
+        // Ensure the points are in the prime-order subgroup.
+        match cfg_iter!(points).all(|point| point.is_in_correct_subgroup_assuming_on_curve()) {
+            true => Ok(points),
+            false => Err(SerializationError::InvalidData),
+        }
+    }
+
+    /// Attempts to construct affine points from their x-coordinates, as in `from_x_coordinate`,
+    /// returning `None` for each x-coordinate that is not on the curve.
+    /// The points are not guaranteed to be in the prime order subgroup.
+    ///
+    /// The square roots, which dominate the cost, are computed together with `SquareRootField::sqrt_batch`,
+    /// which inverts their denominators with a single batch inversion when the base field is an extension field.
+    ///
+    /// # Panics
+    /// This method panics if `xs` and `greatest` have different lengths.
+    pub fn from_x_coordinates_batch(xs: &[P::BaseField], greatest: &[bool]) -> Vec<Option<Self>> {
+        assert_eq!(xs.len(), greatest.len(), "Mismatched lengths of x-coordinates and signs");
+
+        // Compute x^3 + ax + b
+        let x3b = cfg_iter!(xs).map(|x| P::add_b(&((x.square() * x) + P::mul_by_a(x)))).collect::<Vec<_>>();
+
+        P::BaseField::sqrt_batch(&x3b)
+            .into_iter()
+            .zip(xs)
+            .zip(greatest)
+            .map(|((y, x), greatest)| {
+                y.map(|y| {
+                    let negy = -y;
+                    let y = if (y < negy) ^ greatest { y } else { negy };
+                    Self::new(*x, y, false)
+                })
+            })
+            .collect()
+    }
 }
 
 impl<P: Parameters> Zero for Affine<P> {
@@ -272,6 +329,29 @@ impl<P: Parameters> AffineCurve for Affine<P> {
     }
 }
 
+impl<P: Parameters> AffineLimbs for Affine<P>
+where
+    P::BaseField: PrimeField,
+{
+    type Limbs = <P::BaseField as PrimeField>::BigInteger;
+
+    fn to_xy_limbs(&self) -> (Self::Limbs, Self::Limbs) {
+        match self.infinity {
+            true => (Self::Limbs::default(), Self::Limbs::default()),
+            false => (self.x.to_montgomery_bigint(), self.y.to_montgomery_bigint()),
+        }
+    }
+
+    fn from_xy_limbs(x: Self::Limbs, y: Self::Limbs) -> Option<Self> {
+        if x.is_zero() && y.is_zero() {
+            return Some(Self::zero());
+        }
+        let point =
+            Self::new(P::BaseField::from_montgomery_bigint(x)?, P::BaseField::from_montgomery_bigint(y)?, false);
+        (point.is_on_curve() && point.is_in_correct_subgroup_assuming_on_curve()).then_some(point)
+    }
+}
+
 impl<P: Parameters> Neg for Affine<P> {
     type Output = Self;
 
@@ -312,6 +392,30 @@ impl<P: Parameters> FromBytes for Affine<P> {
     }
 }
 
+// SAFETY: `Affine` is `#[repr(C)]`, so its layout is `x || y || infinity || padding`.
+// Both coordinates and the infinity flag are validated before the bytes are reinterpreted.
+unsafe impl<P: Parameters> ZeroCopy for Affine<P>
+where
+    P::BaseField: ZeroCopy,
+{
+    fn is_valid_raw(bytes: &[u8]) -> bool {
+        let field_size = core::mem::size_of::<P::BaseField>();
+        bytes.len() == core::mem::size_of::<Self>()
+            && P::BaseField::is_valid_raw(&bytes[..field_size])
+            && P::BaseField::is_valid_raw(&bytes[field_size..2 * field_size])
+            && bool::is_valid_raw(&bytes[2 * field_size..2 * field_size + 1])
+    }
+
+    fn write_raw<W: Write>(&self, mut writer: W) -> Result<(), SerializationError> {
+        self.x.write_raw(&mut writer)?;
+        self.y.write_raw(&mut writer)?;
+        self.infinity.write_raw(&mut writer)?;
+        // Zero the trailing padding.
+        let padding = core::mem::size_of::<Self>() - 2 * core::mem::size_of::<P::BaseField>() - 1;
+        Ok(writer.write_all(&vec![0u8; padding])?)
+    }
+}
+
 impl<P: Parameters> Distribution<Affine<P>> for Standard {
     #[inline]
     fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> Affine<P> {
@@ -352,3 +456,4 @@ impl<P: Parameters> From<Projective<P>> for Affine<P> {
 }
 
 impl_sw_curve_serializer!(Parameters);
+impl_affine_serde!(Parameters);
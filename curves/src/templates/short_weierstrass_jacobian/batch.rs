@@ -0,0 +1,55 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::{
+    templates::short_weierstrass_jacobian::Affine,
+    traits::{AffineCurve, ShortWeierstrassParameters as Parameters},
+};
+use snarkvm_fields::{Field, One, Zero};
+
+/// Sets `a[i] = a[i] + b[i]` for every `i`, in affine coordinates.
+///
+/// Montgomery's trick is used to share a single field inversion across all of the additions,
+/// which makes this considerably cheaper than Jacobian arithmetic when all inputs are affine,
+/// e.g. when accumulating MSM buckets or building tables of precomputed points.
+pub fn batch_add_affine<P: Parameters>(a: &mut [Affine<P>], b: &[Affine<P>]) {
+    assert_eq!(a.len(), b.len(), "Mismatched lengths in batch affine addition");
+
+    let half = P::BaseField::half();
+    let mut inversion_tmp = P::BaseField::one();
+    // Note: `batch_add_loop_1` clobbers its second operand, so the addends are copied.
+    let mut scratch = b.to_vec();
+
+    // Accumulate the product of the denominators, and the numerators scaled by the running product.
+    for (a, b) in a.iter_mut().zip(scratch.iter_mut()) {
+        // Doubling a point of order two yields the identity, and would contribute a zero denominator.
+        if !a.is_zero() && a == b && a.y.is_zero() {
+            *a = Affine::zero();
+            *b = Affine::zero();
+        }
+        Affine::batch_add_loop_1(a, b, &half, &mut inversion_tmp);
+    }
+
+    // Note: This unwrap is safe, as every denominator accumulated into `inversion_tmp` is nonzero.
+    inversion_tmp = inversion_tmp.inverse().unwrap();
+
+    // Unwind the running product in reverse, recovering each `lambda` and completing each addition.
+    for (a, b) in a.iter_mut().zip(scratch).rev() {
+        Affine::batch_add_loop_2(a, b, &mut inversion_tmp);
+        // Canonicalize the identity, as the coordinates of a cancelled sum are left unspecified.
+        if a.infinity {
+            *a = Affine::zero();
+        }
+    }
+}
@@ -0,0 +1,191 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::{
+    templates::short_weierstrass_jacobian::{Affine, Projective},
+    traits::{ProjectiveCurve, ShortWeierstrassParameters},
+};
+use snarkvm_fields::{GlvDecomposition, PrimeField, Zero};
+use snarkvm_utilities::{biginteger::BigInteger256, BigInteger};
+
+use std::ops::Neg;
+
+/// The GLV decomposition parameters of a curve with an efficient endomorphism, for the lattice basis
+/// `((a1, b1), (a2, b2))` of `{(a, b) : a + b * lambda = 0 mod r}`, where `lambda` is the eigenvalue of the endomorphism.
+pub(crate) struct GlvParameters<F: PrimeField<BigInteger = BigInteger256>> {
+    /// -b1
+    pub(crate) minus_b1: F,
+    /// -b2
+    pub(crate) minus_b2: F,
+    /// -lambda
+    pub(crate) minus_lambda: F,
+    /// g1 = round(2^SHIFT * b2 / r)
+    pub(crate) g1: [u64; 4],
+    /// g2 = round(2^SHIFT * -b1 / r)
+    pub(crate) g2: [u64; 4],
+    /// 2^128 - 1
+    pub(crate) r128: F,
+}
+
+/// The shift of the rounding constants `g1` and `g2`.
+pub(crate) const SHIFT: usize = 383;
+
+/// Returns `round(a * b / 2^SHIFT)`.
+fn mul_shift(a: &[u64; 4], b: &[u64; 4]) -> [u64; 4] {
+    // Schoolbook multiplication
+    let mut product = [0u64; 8];
+    for i in 0..4 {
+        let mut carry = 0u128;
+        for j in 0..4 {
+            let t = product[i + j] as u128 + (a[i] as u128) * (b[j] as u128) + carry;
+            product[i + j] = t as u64;
+            carry = t >> 64;
+        }
+        product[i + 4] = carry as u64;
+    }
+
+    // Shift the product, and round it with the highest discarded bit.
+    let (limb, bit) = (SHIFT / 64, SHIFT % 64);
+    let round = (product[(SHIFT - 1) / 64] >> ((SHIFT - 1) % 64)) & 1;
+    let mut result = [0u64; 4];
+    let mut carry = round as u128;
+    for (i, result) in result.iter_mut().enumerate() {
+        let low = product.get(limb + i).copied().unwrap_or(0) >> bit;
+        let high = match bit {
+            0 => 0,
+            _ => product.get(limb + i + 1).copied().unwrap_or(0) << (64 - bit),
+        };
+        let t = (low | high) as u128 + carry;
+        *result = t as u64;
+        carry = t >> 64;
+    }
+    result
+}
+
+/// Decomposes `k` such that `k = (-1)^k1_neg * k1 + (-1)^k2_neg * k2 * lambda`,
+/// where `k1` and `k2` are at most 128 bits.
+pub(crate) fn decompose<F: PrimeField<BigInteger = BigInteger256>>(
+    k: F,
+    parameters: &GlvParameters<F>,
+) -> GlvDecomposition<F> {
+    let k_bigint = k.to_bigint().0;
+    // Note: These unwraps are safe, as `c1` and `c2` are at most 130 bits.
+    let c1 = F::from_bigint(BigInteger256(mul_shift(&k_bigint, &parameters.g1))).unwrap();
+    let c2 = F::from_bigint(BigInteger256(mul_shift(&k_bigint, &parameters.g2))).unwrap();
+
+    let mut k2 = c1 * parameters.minus_b1 + c2 * parameters.minus_b2;
+    let mut k1 = k + k2 * parameters.minus_lambda;
+    let mut k1_neg = false;
+    let mut k2_neg = false;
+
+    if k1 > parameters.r128 {
+        k1 = -k1;
+        k1_neg = true;
+    }
+
+    if k2 > parameters.r128 {
+        k2 = -k2;
+        k2_neg = true;
+    }
+
+    GlvDecomposition { k1, k2, k1_neg, k2_neg }
+}
+
+/// Performs scalar multiplication with the GLV method, using w-ary NAF recoding of the decomposed scalar.
+pub(crate) fn glv_mul<P: ShortWeierstrassParameters>(
+    p: Projective<P>,
+    GlvDecomposition { k1, k2, k1_neg, k2_neg }: GlvDecomposition<P::ScalarField>,
+) -> Projective<P> {
+    /// The scalar multiplication window size.
+    const GLV_WINDOW_SIZE: usize = 4;
+
+    /// The table size, used for w-ary NAF recoding.
+    const TABLE_SIZE: i64 = 1 << (GLV_WINDOW_SIZE + 1);
+    const HALF_TABLE_SIZE: i64 = 1 << (GLV_WINDOW_SIZE);
+    const MASK_FOR_MOD_TABLE_SIZE: u64 = (TABLE_SIZE as u64) - 1;
+    /// The GLV table length.
+    const L: usize = 1 << (GLV_WINDOW_SIZE - 1);
+
+    // Prepare tables.
+    let mut t_1 = Vec::with_capacity(L);
+    let double = Affine::<P>::from(p.double());
+    t_1.push(p);
+    for i in 1..L {
+        t_1.push(t_1[i - 1].add_mixed(&double));
+    }
+    let t_1 = Projective::<P>::batch_normalization_into_affine(t_1);
+
+    let t_2 = t_1.iter().copied().map(P::glv_endomorphism).collect::<Vec<_>>();
+
+    let mod_signed = |d| {
+        let d_mod_window_size = i64::try_from(d & MASK_FOR_MOD_TABLE_SIZE).unwrap();
+        if d_mod_window_size >= HALF_TABLE_SIZE {
+            d_mod_window_size - TABLE_SIZE
+        } else {
+            d_mod_window_size
+        }
+    };
+    let to_wnaf = |e: P::ScalarField, negate: bool| -> Vec<i32> {
+        let mut naf = vec![];
+        let mut e = e.to_bigint();
+        while !e.is_zero() {
+            let next = if e.is_odd() {
+                let naf_sign = mod_signed(e.as_ref()[0]);
+                if naf_sign < 0 {
+                    e.add_nocarry(&From::from(-naf_sign as u64));
+                } else {
+                    e.sub_noborrow(&From::from(naf_sign as u64));
+                }
+                naf_sign.try_into().unwrap()
+            } else {
+                0
+            };
+            naf.push(if negate { -next } else { next });
+            e.div2();
+        }
+
+        naf
+    };
+
+    let naf_add = |table: &[Affine<P>], naf: i32, acc: &mut Projective<P>| {
+        if naf != 0 {
+            let mut p_1 = table[(naf.abs() >> 1) as usize];
+            if naf < 0 {
+                p_1 = p_1.neg();
+            }
+            acc.add_assign_mixed(&p_1);
+        }
+    };
+
+    // Recode scalars.
+    let naf_1 = to_wnaf(k1, k1_neg);
+    let naf_2 = to_wnaf(k2, k2_neg);
+    let max_len = naf_1.len().max(naf_2.len());
+    let mut acc = Projective::<P>::zero();
+    for i in (0..max_len).rev() {
+        if i < naf_1.len() {
+            naf_add(&t_1, naf_1[i], &mut acc)
+        }
+
+        if i < naf_2.len() {
+            naf_add(&t_2, naf_2[i], &mut acc)
+        }
+
+        if i != 0 {
+            acc.double_in_place();
+        }
+    }
+
+    acc
+}
@@ -15,8 +15,16 @@
 pub mod affine;
 pub use affine::*;
 
+pub mod batch;
+pub use batch::*;
+
+pub(crate) mod glv;
+
 pub mod projective;
 pub use projective::*;
 
+pub mod serialize_std;
+pub use serialize_std::*;
+
 #[cfg(test)]
 pub mod tests;
@@ -16,8 +16,15 @@ use crate::{
     templates::short_weierstrass_jacobian::Affine,
     traits::{AffineCurve, ProjectiveCurve, ShortWeierstrassParameters as Parameters},
 };
-use snarkvm_fields::{impl_add_sub_from_field_ref, Field, One, Zero};
-use snarkvm_utilities::{cfg_iter_mut, rand::Uniform, serialize::*, FromBytes, ToBytes};
+use snarkvm_fields::{impl_add_sub_from_field_ref, serial_batch_inversion, Field, One, Zero};
+use snarkvm_utilities::{
+    cfg_chunks_mut,
+    execute_in_current_thread_pool,
+    rand::Uniform,
+    serialize::*,
+    FromBytes,
+    ToBytes,
+};
 
 use core::{
     fmt::{Display, Formatter, Result as FmtResult},
@@ -43,6 +50,95 @@ impl<P: Parameters> Projective<P> {
     pub const fn new(x: P::BaseField, y: P::BaseField, z: P::BaseField) -> Self {
         Self { x, y, z }
     }
+
+    /// Adds two points with the complete addition formulas of Renes, Costello and Batina
+    /// (<https://eprint.iacr.org/2015/1060.pdf>, Algorithm 1). Unlike `add_assign`, these have
+    /// uniform control flow, as they do not branch on the points being equal, inverse, or zero.
+    ///
+    /// The formulas are complete for all points in a subgroup of odd order,
+    /// and in particular for all points of a prime-order curve.
+    pub fn add_complete(&self, other: &Self) -> Self {
+        // Convert to homogeneous coordinates, where (X, Y, Z) represents (X / Z, Y / Z).
+        let (x1, y1, z1) = (self.x * self.z, self.y, self.z.square() * self.z);
+        let (x2, y2, z2) = (other.x * other.z, other.y, other.z.square() * other.z);
+        let b3 = P::WEIERSTRASS_B.double() + P::WEIERSTRASS_B;
+
+        let mut t0 = x1 * x2;
+        let mut t1 = y1 * y2;
+        let mut t2 = z1 * z2;
+        let mut t3 = (x1 + y1) * (x2 + y2);
+        let mut t4 = t0 + t1;
+        t3 -= &t4;
+        t4 = (x1 + z1) * (x2 + z2);
+        let mut t5 = t0 + t2;
+        t4 -= &t5;
+        t5 = (y1 + z1) * (y2 + z2);
+        let mut x3 = t1 + t2;
+        t5 -= &x3;
+        let mut z3 = P::mul_by_a(&t4);
+        x3 = b3 * t2;
+        z3 += &x3;
+        x3 = t1 - z3;
+        z3 += &t1;
+        let mut y3 = x3 * z3;
+        t1 = t0.double() + t0;
+        t2 = P::mul_by_a(&t2);
+        t4 *= &b3;
+        t1 += &t2;
+        t2 = P::mul_by_a(&(t0 - t2));
+        t4 += &t2;
+        t0 = t1 * t4;
+        y3 += &t0;
+        t0 = t5 * t4;
+        x3 *= &t3;
+        x3 -= &t0;
+        t0 = t3 * t1;
+        z3 *= &t5;
+        z3 += &t0;
+
+        // Convert back to Jacobian coordinates. The point at infinity requires a non-zero `y`,
+        // which is ensured by adding one when `z` is zero, as `y` is otherwise unchanged.
+        let y3 = y3 * z3.square() + P::BaseField::from(z3.is_zero() as u8);
+        Self::new(x3 * z3, y3, z3)
+    }
+
+    /// Writes the point in its canonical compressed form, which normalizes it to affine coordinates,
+    /// and writes the `x`-coordinate with the sign of `y` and the infinity flag in its top bits.
+    ///
+    /// Unlike `write_le`, which writes the raw Jacobian coordinates, the encoding is unique per point.
+    pub fn write_le_compressed<W: Write>(&self, writer: W) -> IoResult<()> {
+        Ok(self.to_affine().serialize_compressed(writer)?)
+    }
+
+    /// Reads a point from its canonical compressed form,
+    /// ensuring it is on the curve and in the prime-order subgroup.
+    pub fn read_le_compressed<R: Read>(reader: R) -> IoResult<Self> {
+        Ok(Affine::<P>::deserialize_compressed(reader)?.into())
+    }
+
+    /// Reads a point from its canonical compressed form, ensuring it is on the curve,
+    /// but skipping the more expensive check that it is in the prime-order subgroup.
+    ///
+    /// This must only be used on trusted inputs, such as ones previously written by this node.
+    pub fn read_le_compressed_unchecked<R: Read>(reader: R) -> IoResult<Self> {
+        Ok(Affine::<P>::deserialize_compressed_unchecked(reader)?.into())
+    }
+
+    /// Normalizes the points with Montgomery's trick, using a single inversion.
+    /// This method is explicitly single-threaded.
+    fn serial_batch_normalization(v: &mut [Self]) {
+        // Invert the `z`-coordinates of the points that are not yet normalized.
+        let mut z_inverses = v.iter().filter(|g| !g.is_normalized()).map(|g| g.z).collect::<Vec<_>>();
+        serial_batch_inversion(&mut z_inverses);
+
+        // Perform affine transformations
+        for (g, z_inverse) in v.iter_mut().filter(|g| !g.is_normalized()).zip(z_inverses) {
+            let z2 = z_inverse.square(); // 1/z
+            g.x *= &z2; // x/z^2
+            g.y *= &(z2 * z_inverse); // y/z^3
+            g.z = P::BaseField::one(); // z = 1
+        }
+    }
 }
 
 impl<P: Parameters> Zero for Projective<P> {
@@ -151,6 +247,24 @@ impl<P: Parameters> FromBytes for Projective<P> {
     }
 }
 
+/// Serializes the point as its affine representation.
+impl<P: Parameters> serde::Serialize for Projective<P> {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serde::Serialize::serialize(&self.to_affine(), serializer)
+    }
+}
+
+/// Deserializes the point from its affine representation, ensuring it is on the curve and in the prime-order subgroup.
+impl<'de, P: Parameters> serde::Deserialize<'de> for Projective<P> {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let affine: Affine<P> = serde::Deserialize::deserialize(deserializer)?;
+        match affine.is_on_curve() && affine.is_in_correct_subgroup_assuming_on_curve() {
+            true => Ok(affine.into()),
+            false => Err(serde::de::Error::custom("the point is not on the curve or not in the prime-order subgroup")),
+        }
+    }
+}
+
 impl<P: Parameters> ProjectiveCurve for Projective<P> {
     type Affine = Affine<P>;
     type BaseField = P::BaseField;
@@ -166,55 +280,20 @@ impl<P: Parameters> ProjectiveCurve for Projective<P> {
         self.is_zero() || self.z.is_one()
     }
 
-    /// TODO (howardwu): This method can likely be sped up.
+    /// Normalizes the points with Montgomery's trick, splitting the slice into chunks that are inverted
+    /// in parallel in the current thread pool, unless the `serial` feature is enabled.
     #[inline]
     fn batch_normalization(v: &mut [Self]) {
-        // Montgomery’s Trick and Fast Implementation of Masked AES
-        // Genelle, Prouff and Quisquater
-        // Section 3.2
-
-        // First pass: compute [a, ab, abc, ...]
-        let mut prod = Vec::with_capacity(v.len());
-        let mut tmp = P::BaseField::one();
-        for g in v
-            .iter_mut()
-            // Ignore normalized elements
-            .filter(|g| !g.is_normalized())
-        {
-            tmp.mul_assign(&g.z);
-            prod.push(tmp);
-        }
-
-        // Invert `tmp`.
-        tmp = tmp.inverse().unwrap(); // Guaranteed to be nonzero.
-
-        // Second pass: iterate backwards to compute inverses
-        for (g, s) in v
-            .iter_mut()
-            // Backwards
-            .rev()
-            // Ignore normalized elements
-            .filter(|g| !g.is_normalized())
-            // Backwards, skip last element, fill in one for last term.
-            .zip(
-                prod.into_iter()
-                    .rev()
-                    .skip(1)
-                    .chain(Some(P::BaseField::one())),
-            )
-        {
-            // tmp := tmp * g.z; g.z := tmp * s = 1/z
-            let newtmp = tmp * g.z;
-            g.z = tmp * s;
-            tmp = newtmp;
-        }
-        cfg_iter_mut!(v).filter(|g| !g.is_normalized()).for_each(|g| {
-            // Perform affine transformations
-            let z2 = g.z.square(); // 1/z
-            g.x *= &z2; // x/z^2
-            g.y *= &(z2 * g.z); // y/z^3
-            g.z = P::BaseField::one(); // z = 1
-        });
+        execute_in_current_thread_pool(|| {
+            // Divide the slice evenly between all available cores.
+            #[cfg(not(feature = "serial"))]
+            let num_chunks = snarkvm_utilities::parallel::max_available_threads();
+            #[cfg(feature = "serial")]
+            let num_chunks = 1;
+
+            let chunk_size = v.len().div_ceil(num_chunks.max(1)).max(1);
+            cfg_chunks_mut!(v, chunk_size).for_each(Self::serial_batch_normalization);
+        })
     }
 
     #[allow(clippy::many_single_char_names)]
@@ -279,7 +358,7 @@ impl<P: Parameters> ProjectiveCurve for Projective<P> {
             self.x -= &v.double();
 
             // Y3 = r*(V-X3)-2*Y1*J
-            self.y = P::BaseField::sum_of_products([r, -self.y.double()].iter(), [(v - self.x), j].iter());
+            self.y = P::BaseField::sum_of_products_slice(&[r, -self.y.double()], &[(v - self.x), j]);
 
             // Z3 = (Z1+H)^2-Z1Z1-HH
             self.z += &h;
@@ -459,7 +538,7 @@ impl<'a, P: Parameters> AddAssign<&'a Self> for Projective<P> {
             self.x = r.square() - j - (v.double());
 
             // Y3 = r*(V - X3) - 2*S1*J
-            self.y = P::BaseField::sum_of_products([r, -s1.double()].iter(), [(v - self.x), j].iter());
+            self.y = P::BaseField::sum_of_products_slice(&[r, -s1.double()], &[(v - self.x), j]);
 
             // Z3 = ((Z1+Z2)^2 - Z1Z1 - Z2Z2)*H
             self.z = ((self.z + other.z).square() - z1z1 - z2z2) * h;
@@ -0,0 +1,191 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::{
+    templates::short_weierstrass_jacobian::Affine,
+    traits::{AffineCurve, ShortWeierstrassParameters as Parameters},
+};
+use snarkvm_fields::{Field, Fp2, Fp256, Fp256Parameters, Fp2Parameters, Fp384, Fp384Parameters, PrimeField, Zero};
+use snarkvm_utilities::{
+    io::{Read, Write},
+    serialize::SerializationError,
+};
+
+/// The flag in the top byte that marks a compressed encoding.
+const COMPRESSION_FLAG: u8 = 1 << 7;
+/// The flag in the top byte that marks the point at infinity.
+const INFINITY_FLAG: u8 = 1 << 6;
+/// The flag in the top byte that marks the lexicographically largest `y`-coordinate.
+const SIGN_FLAG: u8 = 1 << 5;
+/// The mask of all flags in the top byte.
+const FLAGS_MASK: u8 = COMPRESSION_FLAG | INFINITY_FLAG | SIGN_FLAG;
+
+/// A base field with the big-endian encoding used by the "flag bits in the top byte" point format,
+/// as popularized by the Zcash BLS12-381 serialization and used by zkcrypto and blst.
+pub trait StdEncodedField: Field {
+    /// The size of the encoding, in bytes.
+    const ENCODED_SIZE: usize;
+
+    /// Writes the big-endian encoding of the element into `bytes`, which is `ENCODED_SIZE` bytes long.
+    fn write_be(&self, bytes: &mut [u8]);
+
+    /// Reads an element from its big-endian encoding, returning `None` if the encoding is not canonical.
+    fn read_be(bytes: &[u8]) -> Option<Self>;
+
+    /// Returns `true` if the element is lexicographically larger than its negation.
+    fn is_lexicographically_largest(&self) -> bool;
+}
+
+/// Returns the size of the big-endian encoding of a prime field element, in bytes.
+const fn prime_encoded_size(modulus_bits: u32) -> usize {
+    (modulus_bits as usize + 7) / 8
+}
+
+/// Writes the big-endian encoding of a prime field element into `bytes`.
+fn write_prime_be<F: PrimeField>(element: &F, bytes: &mut [u8]) {
+    let bigint = element.to_bigint();
+    let le_bytes = bigint.as_ref().iter().flat_map(|limb| limb.to_le_bytes()).collect::<Vec<_>>();
+    for (byte, le_byte) in bytes.iter_mut().rev().zip(le_bytes) {
+        *byte = le_byte;
+    }
+}
+
+/// Reads a prime field element from its big-endian encoding, returning `None` if it is not less than the modulus.
+fn read_prime_be<F: PrimeField>(bytes: &[u8]) -> Option<F> {
+    let mut bigint = F::BigInteger::default();
+    for (i, byte) in bytes.iter().rev().enumerate() {
+        *bigint.as_mut().get_mut(i / 8)? |= (*byte as u64) << (8 * (i % 8));
+    }
+    F::from_bigint(bigint)
+}
+
+impl<P: Fp256Parameters> StdEncodedField for Fp256<P> {
+    const ENCODED_SIZE: usize = prime_encoded_size(P::MODULUS_BITS);
+
+    fn write_be(&self, bytes: &mut [u8]) {
+        write_prime_be(self, bytes)
+    }
+
+    fn read_be(bytes: &[u8]) -> Option<Self> {
+        read_prime_be(bytes)
+    }
+
+    fn is_lexicographically_largest(&self) -> bool {
+        *self > -*self
+    }
+}
+
+impl<P: Fp384Parameters> StdEncodedField for Fp384<P> {
+    const ENCODED_SIZE: usize = prime_encoded_size(P::MODULUS_BITS);
+
+    fn write_be(&self, bytes: &mut [u8]) {
+        write_prime_be(self, bytes)
+    }
+
+    fn read_be(bytes: &[u8]) -> Option<Self> {
+        read_prime_be(bytes)
+    }
+
+    fn is_lexicographically_largest(&self) -> bool {
+        *self > -*self
+    }
+}
+
+/// Quadratic extension elements `c0 + c1 * u` are encoded as `c1 || c0`, and ordered by `c1` first.
+impl<P: Fp2Parameters> StdEncodedField for Fp2<P> {
+    const ENCODED_SIZE: usize = 2 * prime_encoded_size(<P::Fp as PrimeField>::Parameters::MODULUS_BITS);
+
+    fn write_be(&self, bytes: &mut [u8]) {
+        let (c1, c0) = bytes.split_at_mut(Self::ENCODED_SIZE / 2);
+        write_prime_be(&self.c1, c1);
+        write_prime_be(&self.c0, c0);
+    }
+
+    fn read_be(bytes: &[u8]) -> Option<Self> {
+        let (c1, c0) = bytes.split_at(Self::ENCODED_SIZE / 2);
+        Some(Fp2::new(read_prime_be(c0)?, read_prime_be(c1)?))
+    }
+
+    fn is_lexicographically_largest(&self) -> bool {
+        match self.c1.is_zero() {
+            true => self.c0 > -self.c0,
+            false => self.c1 > -self.c1,
+        }
+    }
+}
+
+impl<P: Parameters> Affine<P>
+where
+    P::BaseField: StdEncodedField,
+{
+    /// Returns the size of the compressed "flag bits in the top byte" encoding, in bytes.
+    pub fn compressed_std_size() -> usize {
+        P::BaseField::ENCODED_SIZE
+    }
+
+    /// Serializes the point in the compressed "flag bits in the top byte" format, which is
+    /// the big-endian encoding of `x`, with the three most significant bits of the first byte set to:
+    /// - bit 7: the compression flag, which is always set;
+    /// - bit 6: the infinity flag, in which case the remaining bits are zero;
+    /// - bit 5: the sign flag, which is set if `y` is lexicographically larger than `-y`.
+    pub fn serialize_compressed_std<W: Write>(&self, mut writer: W) -> Result<(), SerializationError> {
+        let mut bytes = vec![0u8; Self::compressed_std_size()];
+        if self.is_zero() {
+            bytes[0] = COMPRESSION_FLAG | INFINITY_FLAG;
+        } else {
+            self.x.write_be(&mut bytes);
+            // Ensure the encoding of `x` leaves the top three bits free for the flags.
+            if bytes[0] & FLAGS_MASK != 0 {
+                return Err(SerializationError::NotEnoughSpace);
+            }
+            bytes[0] |= COMPRESSION_FLAG;
+            if self.y.is_lexicographically_largest() {
+                bytes[0] |= SIGN_FLAG;
+            }
+        }
+        Ok(writer.write_all(&bytes)?)
+    }
+
+    /// Deserializes a point from the compressed "flag bits in the top byte" format,
+    /// ensuring it is on the curve and in the prime-order subgroup.
+    pub fn deserialize_compressed_std<R: Read>(mut reader: R) -> Result<Self, SerializationError> {
+        let mut bytes = vec![0u8; Self::compressed_std_size()];
+        reader.read_exact(&mut bytes)?;
+
+        let flags = bytes[0] & FLAGS_MASK;
+        bytes[0] &= !FLAGS_MASK;
+        if flags & COMPRESSION_FLAG == 0 {
+            return Err(SerializationError::InvalidData);
+        }
+
+        // Ensure the point at infinity has a unique encoding.
+        if flags & INFINITY_FLAG != 0 {
+            return match flags & SIGN_FLAG == 0 && bytes.iter().all(|byte| *byte == 0) {
+                true => Ok(Self::zero()),
+                false => Err(SerializationError::InvalidData),
+            };
+        }
+
+        let x = P::BaseField::read_be(&bytes).ok_or(SerializationError::InvalidData)?;
+        let (p1, p2) = Self::pair_from_x_coordinate(x).ok_or(SerializationError::InvalidData)?;
+        let point = match p1.y.is_lexicographically_largest() == (flags & SIGN_FLAG != 0) {
+            true => p1,
+            false => p2,
+        };
+        match point.is_in_correct_subgroup_assuming_on_curve() {
+            true => Ok(point),
+            false => Err(SerializationError::InvalidData),
+        }
+    }
+}
@@ -12,13 +12,22 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
-use super::{Affine, Projective};
-use crate::{AffineCurve, ProjectiveCurve, ShortWeierstrassParameters};
-use snarkvm_fields::Zero;
+use super::{batch_add_affine, Affine, Projective};
+use crate::{AffineCurve, AffineLimbs, ProjectiveCurve, ShortWeierstrassParameters};
+use snarkvm_fields::{One, PrimeField, Zero};
 use snarkvm_utilities::{
     io::Cursor,
     rand::Uniform,
-    serialize::{CanonicalDeserialize, CanonicalSerialize},
+    serialize::{
+        deserialize_zero_copy,
+        serialize_zero_copy,
+        AlignedBytes,
+        CanonicalDeserialize,
+        CanonicalSerialize,
+        ZeroCopy,
+        ZERO_COPY_HEADER_SIZE,
+    },
+    BigInteger,
     Compress,
     TestRng,
     Validate,
@@ -30,6 +39,13 @@ pub fn sw_tests<P: ShortWeierstrassParameters>(rng: &mut TestRng) {
     sw_curve_serialization_test::<P>(rng);
     sw_from_random_bytes::<P>(rng);
     sw_from_x_coordinate::<P>(rng);
+    sw_batch_add_affine::<P>(rng);
+    sw_deserialize_batch::<P>(rng);
+    sw_add_complete::<P>(rng);
+    sw_batch_normalization::<P>(rng);
+    sw_compressed_bytes::<P>(rng);
+    sw_from_x_coordinates_batch::<P>(rng);
+    sw_projective_serde::<P>(rng);
 }
 
 pub fn sw_curve_serialization_test<P: ShortWeierstrassParameters>(rng: &mut TestRng) {
@@ -121,6 +137,29 @@ pub fn sw_curve_serialization_test<P: ShortWeierstrassParameters>(rng: &mut Test
     }
 }
 
+pub fn sw_zero_copy_test<P: ShortWeierstrassParameters>(rng: &mut TestRng)
+where
+    P::BaseField: ZeroCopy,
+{
+    let mut points = (0..ITERATIONS).map(|_| Projective::<P>::rand(rng).to_affine()).collect::<Vec<_>>();
+    points.push(Affine::<P>::zero());
+
+    let mut bytes = Vec::new();
+    serialize_zero_copy(&points, &mut bytes).unwrap();
+
+    // Ensure the points are borrowed in place, and match the originals.
+    let buffer = AlignedBytes::from_bytes(&bytes);
+    let (candidate, remainder) = deserialize_zero_copy::<Affine<P>>(buffer.as_bytes(), Validate::Yes).unwrap();
+    assert_eq!(points.as_slice(), candidate);
+    assert!(remainder.is_empty());
+
+    // Ensure an invalid infinity flag is rejected.
+    let mut invalid = bytes.clone();
+    invalid[ZERO_COPY_HEADER_SIZE + 2 * core::mem::size_of::<P::BaseField>()] = 2;
+    let buffer = AlignedBytes::from_bytes(&invalid);
+    assert!(deserialize_zero_copy::<Affine<P>>(buffer.as_bytes(), Validate::No).is_err());
+}
+
 pub fn sw_from_random_bytes<P: ShortWeierstrassParameters>(rng: &mut TestRng) {
     let buf_size = Affine::<P>::zero().compressed_size();
 
@@ -159,3 +198,181 @@ pub fn sw_from_x_coordinate<P: ShortWeierstrassParameters>(rng: &mut TestRng) {
         }
     }
 }
+
+pub fn sw_batch_add_affine<P: ShortWeierstrassParameters>(rng: &mut TestRng) {
+    let mut a = (0..ITERATIONS).map(|_| Projective::<P>::rand(rng).to_affine()).collect::<Vec<_>>();
+    let mut b = (0..ITERATIONS).map(|_| Projective::<P>::rand(rng).to_affine()).collect::<Vec<_>>();
+
+    // Cover the identity, doubling, and cancellation.
+    a.extend([Affine::zero(), a[0], Affine::zero(), a[1], a[2]]);
+    b.extend([b[0], Affine::zero(), Affine::zero(), a[1], -a[2]]);
+
+    let expected =
+        a.iter().zip(&b).map(|(a, b)| (a.to_projective() + b.to_projective()).to_affine()).collect::<Vec<_>>();
+    batch_add_affine(&mut a, &b);
+    assert_eq!(a, expected);
+
+    // Ensure an empty batch is a no-op.
+    batch_add_affine::<P>(&mut [], &[]);
+}
+
+pub fn sw_deserialize_batch<P: ShortWeierstrassParameters>(rng: &mut TestRng) {
+    let mut points = (0..ITERATIONS).map(|_| Projective::<P>::rand(rng).to_affine()).collect::<Vec<_>>();
+    points.push(Affine::zero());
+
+    let mut bytes = Vec::new();
+    for point in &points {
+        point.serialize_compressed(&mut bytes).unwrap();
+    }
+    assert_eq!(points, Affine::<P>::deserialize_batch(&bytes).unwrap());
+    assert!(Affine::<P>::deserialize_batch(&[]).unwrap().is_empty());
+
+    // Ensure a truncated batch is rejected.
+    assert!(Affine::<P>::deserialize_batch(&bytes[..bytes.len() - 1]).is_err());
+
+    // Ensure a batch with a point outside of the prime-order subgroup is rejected.
+    let outside = (0..100)
+        .filter_map(|_| Affine::<P>::from_x_coordinate(P::BaseField::rand(rng), true))
+        .find(|point| !point.is_in_correct_subgroup_assuming_on_curve());
+    if let Some(outside) = outside {
+        outside.serialize_compressed(&mut bytes).unwrap();
+        assert!(Affine::<P>::deserialize_batch(&bytes).is_err());
+    }
+}
+
+pub fn sw_add_complete<P: ShortWeierstrassParameters>(rng: &mut TestRng) {
+    for _ in 0..ITERATIONS {
+        let a = Projective::<P>::rand(rng);
+        let b = Projective::<P>::rand(rng);
+        let zero = Projective::<P>::zero();
+
+        // Cover the generic case, the identity, doubling, and cancellation.
+        assert_eq!(a.add_complete(&b), a + b);
+        assert_eq!(a.add_complete(&zero), a);
+        assert_eq!(zero.add_complete(&a), a);
+        assert_eq!(a.add_complete(&a), a.double());
+        assert!(a.add_complete(&-a).is_zero());
+        assert!(zero.add_complete(&zero).is_zero());
+
+        // Ensure the point at infinity produced by the formulas is a valid input.
+        let cancelled = a.add_complete(&-a);
+        assert_eq!(cancelled.add_complete(&b), b);
+        assert_eq!(b.add_complete(&cancelled), b);
+    }
+}
+
+pub fn sw_batch_normalization<P: ShortWeierstrassParameters>(rng: &mut TestRng) {
+    // Cover enough points to split the batch into several chunks.
+    let mut points = (0..100).map(|_| Projective::<P>::rand(rng).double()).collect::<Vec<_>>();
+    // Cover the identity, and points that are already normalized.
+    points.extend([Projective::zero(), Projective::rand(rng).to_affine().to_projective(), Projective::zero()]);
+    let expected = points.iter().map(|point| point.to_affine()).collect::<Vec<_>>();
+
+    let mut normalized = points.clone();
+    Projective::batch_normalization(&mut normalized);
+    assert!(normalized.iter().all(|point| point.is_normalized()));
+    assert_eq!(normalized, points);
+
+    assert_eq!(Projective::batch_normalization_into_affine(points.clone()), expected);
+
+    let mut output = vec![Affine::zero(); points.len()];
+    Projective::batch_normalization_into_affine_in_place(&mut points, &mut output);
+    assert_eq!(output, expected);
+
+    // Ensure an empty batch is a no-op.
+    Projective::<P>::batch_normalization(&mut []);
+}
+
+pub fn sw_compressed_bytes<P: ShortWeierstrassParameters>(rng: &mut TestRng) {
+    let size = Affine::<P>::zero().compressed_size();
+
+    for _ in 0..ITERATIONS {
+        // Ensure distinct Jacobian representations of the same point have the same encoding.
+        let a = Projective::<P>::rand(rng) + Projective::<P>::rand(rng);
+        let normalized = a.to_affine().to_projective();
+        assert!(!a.is_normalized() && normalized.is_normalized());
+
+        let mut bytes = vec![];
+        a.write_le_compressed(&mut bytes).unwrap();
+        assert_eq!(bytes.len(), size);
+
+        let mut expected = vec![];
+        normalized.write_le_compressed(&mut expected).unwrap();
+        assert_eq!(bytes, expected);
+
+        assert_eq!(Projective::<P>::read_le_compressed(&bytes[..]).unwrap(), a);
+        assert_eq!(Projective::<P>::read_le_compressed_unchecked(&bytes[..]).unwrap(), a);
+
+        // Ensure truncated encodings are rejected.
+        assert!(Projective::<P>::read_le_compressed(&bytes[..size - 1]).is_err());
+    }
+
+    let mut bytes = vec![];
+    Projective::<P>::zero().write_le_compressed(&mut bytes).unwrap();
+    assert_eq!(bytes.len(), size);
+    assert!(Projective::<P>::read_le_compressed(&bytes[..]).unwrap().is_zero());
+}
+
+pub fn sw_xy_limbs<P: ShortWeierstrassParameters>(rng: &mut TestRng)
+where
+    P::BaseField: PrimeField,
+{
+    for _ in 0..ITERATIONS {
+        let a = Projective::<P>::rand(rng).to_affine();
+        let (x, y) = a.to_xy_limbs();
+        assert_eq!(x, a.x.to_montgomery_bigint());
+        assert_eq!(y, a.y.to_montgomery_bigint());
+        assert_eq!(Affine::<P>::from_xy_limbs(x, y), Some(a));
+
+        // Ensure points off the curve are rejected.
+        assert!(Affine::<P>::from_xy_limbs(x, (a.y + P::BaseField::one()).to_montgomery_bigint()).is_none());
+        // Ensure non-canonical limbs are rejected.
+        assert!(Affine::<P>::from_xy_limbs(P::BaseField::modulus(), y).is_none());
+    }
+
+    // Ensure the point at infinity is represented by `(0, 0)`.
+    let (x, y) = Affine::<P>::zero().to_xy_limbs();
+    assert!(x.is_zero() && y.is_zero());
+    assert!(Affine::<P>::from_xy_limbs(x, y).unwrap().is_zero());
+}
+
+pub fn sw_projective_serde<P: ShortWeierstrassParameters>(rng: &mut TestRng) {
+    for a in [Projective::<P>::zero(), Projective::<P>::prime_subgroup_generator(), Projective::<P>::rand(rng)] {
+        let bytes = bincode::serialize(&a).unwrap();
+        assert_eq!(bytes, bincode::serialize(&a.to_affine()).unwrap());
+        assert_eq!(a, bincode::deserialize::<Projective<P>>(&bytes).unwrap());
+    }
+
+    // Ensure a point whose x-coordinate is not on the curve is rejected.
+    let x = loop {
+        let x = P::BaseField::rand(rng);
+        if Affine::<P>::from_x_coordinate(x, false).is_none() {
+            break x;
+        }
+    };
+    let invalid = Affine::<P>::new(x, P::BaseField::rand(rng), false);
+    assert!(bincode::deserialize::<Projective<P>>(&bincode::serialize(&invalid).unwrap()).is_err());
+    assert!(serde_json::from_str::<Projective<P>>(&serde_json::to_string(&invalid).unwrap()).is_err());
+
+    // Ensure the point is serialized as the hex string of its compressed bytes in human-readable formats.
+    let a = Projective::<P>::rand(rng).to_affine();
+    let mut bytes = Vec::new();
+    a.serialize_compressed(&mut bytes).unwrap();
+    assert_eq!(serde_json::to_string(&a).unwrap(), format!("\"{}\"", hex::encode(&bytes)));
+    assert_eq!(a, serde_json::from_str::<Affine<P>>(&serde_json::to_string(&a).unwrap()).unwrap());
+    assert_eq!(bytes, bincode::serialize(&a).unwrap());
+    assert_eq!(a, bincode::deserialize::<Affine<P>>(&bytes).unwrap());
+}
+
+pub fn sw_from_x_coordinates_batch<P: ShortWeierstrassParameters>(rng: &mut TestRng) {
+    // Random x-coordinates are on the curve about half of the time, so this covers both cases.
+    let mut xs = (0..4 * ITERATIONS).map(|_| P::BaseField::rand(rng)).collect::<Vec<_>>();
+    xs.push(Projective::<P>::rand(rng).to_affine().x);
+    let greatest = (0..xs.len()).map(|i| i % 2 == 0).collect::<Vec<_>>();
+    let expected = xs.iter().zip(&greatest).map(|(x, greatest)| Affine::<P>::from_x_coordinate(*x, *greatest));
+    let expected = expected.collect::<Vec<_>>();
+    assert!(expected.iter().any(Option::is_some) && expected.iter().any(Option::is_none));
+    assert_eq!(Affine::<P>::from_x_coordinates_batch(&xs, &greatest), expected);
+
+    assert!(Affine::<P>::from_x_coordinates_batch(&[], &[]).is_empty());
+}
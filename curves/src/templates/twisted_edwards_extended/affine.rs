@@ -13,13 +13,15 @@
 // limitations under the License.
 
 use crate::{
+    impl_affine_serde,
     impl_edwards_curve_serializer,
     templates::twisted_edwards_extended::Projective,
-    traits::{AffineCurve, ProjectiveCurve, TwistedEdwardsParameters as Parameters},
+    traits::{AffineCurve, AffineLimbs, ProjectiveCurve, TwistedEdwardsParameters as Parameters},
 };
-use snarkvm_fields::{Field, One, PrimeField, SquareRootField, Zero};
+use snarkvm_fields::{batch_inversion, Field, One, PrimeField, SquareRootField, Zero};
 use snarkvm_utilities::{
     bititerator::BitIteratorBE,
+    cfg_iter,
     io::{Read, Result as IoResult, Write},
     rand::Uniform,
     serialize::*,
@@ -35,9 +37,11 @@ use rand::{
     distributions::{Distribution, Standard},
     Rng,
 };
-use serde::{Deserialize, Serialize};
+#[cfg(not(feature = "serial"))]
+use rayon::prelude::*;
 
-#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+#[repr(C)]
 pub struct Affine<P: Parameters> {
     pub x: P::BaseField,
     pub y: P::BaseField,
@@ -49,6 +53,64 @@ impl<P: Parameters> Affine<P> {
     pub fn new(x: P::BaseField, y: P::BaseField, t: P::BaseField) -> Self {
         Self { x, y, t }
     }
+
+    /// Returns `true` if the point is in the small-order subgroup, i.e. if multiplying it
+    /// by the cofactor yields the identity. This only requires a few doublings.
+    pub fn is_small_order(&self) -> bool {
+        self.mul_by_cofactor_to_projective().is_zero()
+    }
+
+    /// Returns `true` if the point has prime order, i.e. if it is not the identity,
+    /// and has no component in the small-order subgroup.
+    ///
+    /// Points received from untrusted sources should pass this check before being used
+    /// in Diffie-Hellman style key agreement, to prevent small-subgroup attacks.
+    pub fn is_prime_order(&self) -> bool {
+        !self.is_zero() && self.is_in_correct_subgroup_assuming_on_curve()
+    }
+
+    /// Returns the point multiplied by the cofactor, which removes its small-order component.
+    pub fn clear_cofactor(&self) -> Self {
+        self.mul_by_cofactor_to_projective().into()
+    }
+
+    /// Attempts to construct affine points from their x-coordinates, as in `from_x_coordinate`,
+    /// returning `None` for each x-coordinate that is not on the curve.
+    /// The points are not guaranteed to be in the prime order subgroup.
+    ///
+    /// The denominators of the curve equation are inverted with a single batch inversion,
+    /// and the square roots are computed together with `SquareRootField::sqrt_batch`.
+    ///
+    /// # Panics
+    /// This method panics if `xs` and `greatest` have different lengths.
+    pub fn from_x_coordinates_batch(xs: &[P::BaseField], greatest: &[bool]) -> Vec<Option<Self>> {
+        assert_eq!(xs.len(), greatest.len(), "Mismatched lengths of x-coordinates and signs");
+
+        // y = sqrt( (a * x^2 - 1)  / (d * x^2 - 1) )
+        let one = P::BaseField::one();
+        let mut denominators = cfg_iter!(xs).map(|x| P::EDWARDS_D * x.square() - one).collect::<Vec<_>>();
+        // Note: The batch inversion leaves zeros in place, so they are tracked to reject their x-coordinates.
+        let is_invertible = cfg_iter!(denominators).map(|denominator| !denominator.is_zero()).collect::<Vec<_>>();
+        batch_inversion(&mut denominators);
+        let y2s = cfg_iter!(xs)
+            .zip(&denominators)
+            .map(|(x, inverse)| (P::mul_by_a(&x.square()) - one) * inverse)
+            .collect::<Vec<_>>();
+
+        P::BaseField::sqrt_batch(&y2s)
+            .into_iter()
+            .zip(xs)
+            .zip(greatest)
+            .zip(is_invertible)
+            .map(|(((y, x), greatest), is_invertible)| {
+                y.filter(|_| is_invertible).map(|y| {
+                    let negy = -y;
+                    let y = if (y < negy) ^ greatest { y } else { negy };
+                    Self::new(*x, y, *x * y)
+                })
+            })
+            .collect()
+    }
 }
 
 impl<P: Parameters> Zero for Affine<P> {
@@ -193,7 +255,11 @@ impl<P: Parameters> AffineCurve for Affine<P> {
     }
 
     fn mul_by_cofactor_to_projective(&self) -> Self::Projective {
-        self.mul_bits(BitIteratorBE::new(P::COFACTOR))
+        match P::COFACTOR {
+            // Cofactors that are powers of two, such as the cofactor 4 of Edwards-BLS12, are cleared by doubling.
+            [cofactor] if cofactor.is_power_of_two() => self.to_projective().mul_by_cofactor(),
+            _ => self.mul_bits(BitIteratorBE::new(P::COFACTOR)),
+        }
     }
 
     fn mul_by_cofactor_inv(&self) -> Self {
@@ -266,6 +332,23 @@ impl<P: Parameters> AffineCurve for Affine<P> {
     }
 }
 
+impl<P: Parameters> AffineLimbs for Affine<P>
+where
+    P::BaseField: PrimeField,
+{
+    type Limbs = <P::BaseField as PrimeField>::BigInteger;
+
+    fn to_xy_limbs(&self) -> (Self::Limbs, Self::Limbs) {
+        (self.x.to_montgomery_bigint(), self.y.to_montgomery_bigint())
+    }
+
+    fn from_xy_limbs(x: Self::Limbs, y: Self::Limbs) -> Option<Self> {
+        let (x, y) = (P::BaseField::from_montgomery_bigint(x)?, P::BaseField::from_montgomery_bigint(y)?);
+        let point = Self::new(x, y, x * y);
+        (point.is_on_curve() && point.is_in_correct_subgroup_assuming_on_curve()).then_some(point)
+    }
+}
+
 impl<P: Parameters> Neg for Affine<P> {
     type Output = Self;
 
@@ -299,6 +382,24 @@ impl<P: Parameters> FromBytes for Affine<P> {
     }
 }
 
+// SAFETY: `Affine` is `#[repr(C)]`, so its layout is `x || y || t`.
+// All three coordinates are validated before the bytes are reinterpreted.
+unsafe impl<P: Parameters> ZeroCopy for Affine<P>
+where
+    P::BaseField: ZeroCopy,
+{
+    fn is_valid_raw(bytes: &[u8]) -> bool {
+        let field_size = core::mem::size_of::<P::BaseField>();
+        bytes.len() == core::mem::size_of::<Self>() && bytes.chunks_exact(field_size).all(P::BaseField::is_valid_raw)
+    }
+
+    fn write_raw<W: Write>(&self, mut writer: W) -> Result<(), SerializationError> {
+        self.x.write_raw(&mut writer)?;
+        self.y.write_raw(&mut writer)?;
+        self.t.write_raw(&mut writer)
+    }
+}
+
 impl<P: Parameters> Distribution<Affine<P>> for Standard {
     #[inline]
     fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> Affine<P> {
@@ -333,3 +434,4 @@ impl<P: Parameters> From<Projective<P>> for Affine<P> {
 }
 
 impl_edwards_curve_serializer!(Parameters);
+impl_affine_serde!(Parameters);
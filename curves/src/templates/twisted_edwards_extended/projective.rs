@@ -141,6 +141,24 @@ impl<P: Parameters> FromBytes for Projective<P> {
     }
 }
 
+/// Serializes the point as its affine representation.
+impl<P: Parameters> serde::Serialize for Projective<P> {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serde::Serialize::serialize(&self.to_affine(), serializer)
+    }
+}
+
+/// Deserializes the point from its affine representation, ensuring it is on the curve and in the prime-order subgroup.
+impl<'de, P: Parameters> serde::Deserialize<'de> for Projective<P> {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let affine: Affine<P> = serde::Deserialize::deserialize(deserializer)?;
+        match affine.is_on_curve() && affine.is_in_correct_subgroup_assuming_on_curve() {
+            true => Ok(affine.into()),
+            false => Err(serde::de::Error::custom("the point is not on the curve or not in the prime-order subgroup")),
+        }
+    }
+}
+
 impl<P: Parameters> ProjectiveCurve for Projective<P> {
     type Affine = Affine<P>;
     type BaseField = P::BaseField;
@@ -232,6 +250,21 @@ impl<P: Parameters> ProjectiveCurve for Projective<P> {
         self.z = f * g;
     }
 
+    #[inline]
+    fn mul_by_cofactor(&self) -> Self {
+        match P::COFACTOR {
+            // Cofactors that are powers of two, such as the cofactor 4 of Edwards-BLS12, are cleared by doubling.
+            [cofactor] if cofactor.is_power_of_two() => {
+                let mut result = *self;
+                for _ in 0..cofactor.trailing_zeros() {
+                    result.double_in_place();
+                }
+                result
+            }
+            _ => self.to_affine().mul_bits(BitIteratorBE::new(P::COFACTOR)),
+        }
+    }
+
     #[inline]
     #[must_use]
     fn double(&self) -> Self {
@@ -25,7 +25,7 @@ use snarkvm_utilities::{
     Validate,
 };
 
-use crate::traits::{AffineCurve, MontgomeryParameters, ProjectiveCurve, TwistedEdwardsParameters};
+use crate::traits::{AffineCurve, AffineLimbs, MontgomeryParameters, ProjectiveCurve, TwistedEdwardsParameters};
 use snarkvm_fields::{Field, One, PrimeField, Zero};
 
 pub const ITERATIONS: usize = 10;
@@ -51,6 +51,10 @@ where
     edwards_curve_serialization_test::<P>(rng);
     edwards_from_random_bytes::<P>(rng);
     edwards_from_x_and_y_coordinates::<P>(rng);
+    edwards_small_order::<P>(rng);
+    edwards_xy_limbs::<P>(rng);
+    edwards_from_x_coordinates_batch::<P>(rng);
+    edwards_projective_serde::<P>(rng);
 }
 
 pub fn edwards_curve_serialization_test<P: TwistedEdwardsParameters>(rng: &mut TestRng) {
@@ -217,3 +221,94 @@ where
         }
     }
 }
+
+pub fn edwards_small_order<P: TwistedEdwardsParameters>(rng: &mut TestRng) {
+    // The point (0, -1) has order 2.
+    let torsion = Affine::<P>::new(P::BaseField::zero(), -P::BaseField::one(), P::BaseField::zero());
+    assert!(torsion.is_on_curve());
+    assert!(torsion.is_small_order());
+    assert!(!torsion.is_prime_order());
+    assert!(torsion.clear_cofactor().is_zero());
+
+    assert!(Affine::<P>::zero().is_small_order());
+    assert!(!Affine::<P>::zero().is_prime_order());
+
+    for _ in 0..ITERATIONS {
+        let point = Projective::<P>::rand(rng).to_affine();
+        assert!(!point.is_small_order());
+        assert!(point.is_prime_order());
+
+        // Ensure a point with a small-order component is detected, and that the component is cleared.
+        let mixed = (point.to_projective() + torsion.to_projective()).to_affine();
+        assert!(!mixed.is_small_order());
+        assert!(!mixed.is_prime_order());
+        assert!(mixed.clear_cofactor().is_prime_order());
+        assert_eq!(mixed.clear_cofactor(), point.clear_cofactor());
+    }
+}
+
+pub fn edwards_xy_limbs<P: TwistedEdwardsParameters>(rng: &mut TestRng)
+where
+    P::BaseField: PrimeField,
+{
+    for _ in 0..ITERATIONS {
+        let a = Projective::<P>::rand(rng).to_affine();
+        let (x, y) = a.to_xy_limbs();
+        assert_eq!(x, a.x.to_montgomery_bigint());
+        assert_eq!(y, a.y.to_montgomery_bigint());
+        assert_eq!(Affine::<P>::from_xy_limbs(x, y), Some(a));
+
+        // Ensure points off the curve are rejected.
+        assert!(Affine::<P>::from_xy_limbs(x, (a.y + P::BaseField::one()).to_montgomery_bigint()).is_none());
+        // Ensure non-canonical limbs are rejected.
+        assert!(Affine::<P>::from_xy_limbs(P::BaseField::modulus(), y).is_none());
+    }
+
+    let (x, y) = Affine::<P>::zero().to_xy_limbs();
+    assert_eq!(Affine::<P>::from_xy_limbs(x, y), Some(Affine::<P>::zero()));
+}
+
+pub fn edwards_from_x_coordinates_batch<P: TwistedEdwardsParameters>(rng: &mut TestRng)
+where
+    P::BaseField: PrimeField,
+{
+    // Random x-coordinates are on the curve about half of the time, so this covers both cases.
+    let mut xs = (0..4 * ITERATIONS).map(|_| P::BaseField::rand(rng)).collect::<Vec<_>>();
+    xs.push(Projective::<P>::rand(rng).to_affine().x);
+    let greatest = (0..xs.len()).map(|i| i % 2 == 0).collect::<Vec<_>>();
+    let expected = xs.iter().zip(&greatest).map(|(x, greatest)| Affine::<P>::from_x_coordinate(*x, *greatest));
+    let expected = expected.collect::<Vec<_>>();
+    assert!(expected.iter().any(Option::is_some) && expected.iter().any(Option::is_none));
+    assert_eq!(Affine::<P>::from_x_coordinates_batch(&xs, &greatest), expected);
+
+    assert!(Affine::<P>::from_x_coordinates_batch(&[], &[]).is_empty());
+}
+
+pub fn edwards_projective_serde<P: TwistedEdwardsParameters>(rng: &mut TestRng) {
+    for a in [Projective::<P>::zero(), Projective::<P>::prime_subgroup_generator(), Projective::<P>::rand(rng)] {
+        let bytes = bincode::serialize(&a).unwrap();
+        assert_eq!(bytes, bincode::serialize(&a.to_affine()).unwrap());
+        assert_eq!(a, bincode::deserialize::<Projective<P>>(&bytes).unwrap());
+    }
+
+    // Ensure a point whose x-coordinate is not on the curve is rejected.
+    let x = loop {
+        let x = P::BaseField::rand(rng);
+        if Affine::<P>::from_x_coordinate(x, false).is_none() {
+            break x;
+        }
+    };
+    let y = P::BaseField::rand(rng);
+    let invalid = Affine::<P>::new(x, y, x * y);
+    assert!(bincode::deserialize::<Projective<P>>(&bincode::serialize(&invalid).unwrap()).is_err());
+    assert!(serde_json::from_str::<Projective<P>>(&serde_json::to_string(&invalid).unwrap()).is_err());
+
+    // Ensure the point is serialized as the hex string of its compressed bytes in human-readable formats.
+    let a = Projective::<P>::rand(rng).to_affine();
+    let mut bytes = Vec::new();
+    a.serialize_compressed(&mut bytes).unwrap();
+    assert_eq!(serde_json::to_string(&a).unwrap(), format!("\"{}\"", hex::encode(&bytes)));
+    assert_eq!(a, serde_json::from_str::<Affine<P>>(&serde_json::to_string(&a).unwrap()).unwrap());
+    assert_eq!(bytes, bincode::serialize(&a).unwrap());
+    assert_eq!(a, bincode::deserialize::<Affine<P>>(&bytes).unwrap());
+}
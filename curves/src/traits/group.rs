@@ -14,7 +14,7 @@
 
 use crate::{templates::short_weierstrass_jacobian, PairingEngine};
 use snarkvm_fields::{Field, PrimeField, SquareRootField, Zero};
-use snarkvm_utilities::{rand::Uniform, serialize::*, FromBytes, ToBytes};
+use snarkvm_utilities::{rand::Uniform, serialize::*, BigInteger, FromBytes, ToBytes};
 
 use core::{
     fmt::{Debug, Display},
@@ -77,11 +77,28 @@ pub trait ProjectiveCurve:
         v.into_iter().map(|v| v.into()).collect()
     }
 
+    /// Normalizes a slice of projective elements, and writes their affine equivalents
+    /// into `output`, without allocating an intermediate vector.
+    ///
+    /// # Panics
+    /// This method panics if `v` and `output` have different lengths.
+    fn batch_normalization_into_affine_in_place(v: &mut [Self], output: &mut [Self::Affine]) {
+        assert_eq!(v.len(), output.len(), "Mismatched lengths in batch normalization");
+        Self::batch_normalization(v);
+        output.iter_mut().zip(v.iter()).for_each(|(output, v)| *output = (*v).into());
+    }
+
     /// Checks if the point is already "normalized" so that
     /// cheap affine conversion is possible.
     #[must_use]
     fn is_normalized(&self) -> bool;
 
+    /// Returns `self * COFACTOR`, which removes the small-order component of the point.
+    #[must_use]
+    fn mul_by_cofactor(&self) -> Self {
+        self.to_affine().mul_by_cofactor_to_projective()
+    }
+
     /// Adds an affine element to this element.
     fn add_assign_mixed(&mut self, other: &Self::Affine);
 
@@ -236,6 +253,24 @@ pub trait AffineCurve:
     fn batch_add_loop_2(a: &mut Self, b: Self, inversion_tmp: &mut Self::BaseField);
 }
 
+/// An affine point over a prime field, whose coordinates can be exchanged as the limbs of their internal
+/// representation, e.g. with GPU kernels, without relying on the in-memory layout of the point.
+/// The limbs are in Montgomery form, except for base fields with Crandall reduction, whose limbs are canonical
+/// (see `PrimeField::to_montgomery_bigint`).
+pub trait AffineLimbs: AffineCurve {
+    /// The internal limbs of a coordinate.
+    type Limbs: BigInteger;
+
+    /// Returns the internal limbs of the `x`- and `y`-coordinates of the point.
+    /// The point at infinity of a short Weierstrass curve is represented by `(0, 0)`.
+    #[must_use]
+    fn to_xy_limbs(&self) -> (Self::Limbs, Self::Limbs);
+
+    /// Returns the point with the given internal limbs of its `x`- and `y`-coordinates,
+    /// or `None` if the limbs are not canonical, or the point is not in the prime-order subgroup.
+    fn from_xy_limbs(x: Self::Limbs, y: Self::Limbs) -> Option<Self>;
+}
+
 pub trait PairingCurve: AffineCurve {
     type Engine: PairingEngine<Fr = Self::ScalarField>;
     type Prepared: CanonicalSerialize
@@ -313,6 +348,15 @@ pub trait ShortWeierstrassParameters: ModelParameters {
 
     fn glv_endomorphism(p: short_weierstrass_jacobian::Affine<Self>) -> short_weierstrass_jacobian::Affine<Self>;
 
+    /// Returns the image of `p` under the untwist-Frobenius-twist endomorphism `psi`, if the curve admits one.
+    /// On the prime-order subgroup of a BLS12 G2 curve, `psi` acts as multiplication by `q mod r = x`,
+    /// which allows `mul_projective` to decompose scalars into four `x`-sized digits.
+    fn psi_endomorphism(
+        _p: short_weierstrass_jacobian::Affine<Self>,
+    ) -> Option<short_weierstrass_jacobian::Affine<Self>> {
+        None
+    }
+
     fn mul_projective(
         p: short_weierstrass_jacobian::Projective<Self>,
         by: Self::ScalarField,
@@ -0,0 +1,35 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::AffineCurve;
+
+/// Deterministically maps byte strings to points in the prime-order subgroup,
+/// following RFC 9380 (Hashing to Elliptic Curves).
+///
+/// Callers must use a domain separation tag `dst` that is unique to their protocol, and should include
+/// the suite ID, following RFC 9380, section 3.1, e.g. `b"MYPROTOCOL-V01-CS01-with-BLS12377G1_XMD:SHA-256_SVDW_RO_"`.
+pub trait HashToCurve: AffineCurve {
+    /// The ID of the suite implemented by `hash_to_curve`, named following RFC 9380, section 8.10.
+    const HASH_SUITE_ID: &'static str;
+    /// The ID of the suite implemented by `encode_to_curve`, named following RFC 9380, section 8.10.
+    const ENCODE_SUITE_ID: &'static str;
+
+    /// Hashes `message` to a point that is indistinguishable from a random oracle output
+    /// (the `hash_to_curve` random-oracle encoding, with suite suffix `_RO_`).
+    fn hash_to_curve(message: &[u8], dst: &[u8]) -> Self;
+
+    /// Hashes `message` to a point with a single map to the curve, which is cheaper than `hash_to_curve`
+    /// but does not produce uniformly random points (the `encode_to_curve` nonuniform encoding, with suite suffix `_NU_`).
+    fn encode_to_curve(message: &[u8], dst: &[u8]) -> Self;
+}
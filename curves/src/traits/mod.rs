@@ -15,12 +15,18 @@
 pub mod group;
 pub use group::*;
 
+pub mod hash_to_curve;
+pub use hash_to_curve::*;
+
 pub mod pairing_engine;
 pub use pairing_engine::*;
 
 #[cfg(test)]
 pub mod tests_field;
 
+#[cfg(test)]
+mod tests_fp512;
+
 #[cfg(test)]
 pub mod tests_group;
 
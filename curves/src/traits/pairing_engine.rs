@@ -14,9 +14,13 @@
 
 use crate::traits::{AffineCurve, PairingCurve, ProjectiveCurve};
 use snarkvm_fields::{Field, PrimeField, SquareRootField, ToConstraintField};
+use snarkvm_utilities::cfg_iter;
 
 use core::{fmt::Debug, hash::Hash, iter};
 
+#[cfg(not(feature = "serial"))]
+use rayon::prelude::*;
+
 pub trait PairingEngine: Sized + 'static + Copy + Debug + PartialEq + Eq + Hash + Sync + Send {
     /// This is the scalar field of the G1/G2 groups.
     type Fr: PrimeField + SquareRootField + Into<<Self::Fr as PrimeField>::BigInteger>;
@@ -82,4 +86,16 @@ pub trait PairingEngine: Sized + 'static + Copy + Debug + PartialEq + Eq + Hash
     {
         Self::final_exponentiation(&Self::miller_loop(iter::once((&p.into().prepare(), &q.into().prepare())))).unwrap()
     }
+
+    /// Computes the product of the pairings of `g1[i]` and `g2[i]`, interleaving the Miller loops
+    /// of all pairs and sharing a single final exponentiation.
+    ///
+    /// # Panics
+    /// This method panics if `g1` and `g2` have different lengths.
+    #[must_use]
+    fn multi_pairing(g1: &[Self::G1Affine], g2: &[Self::G2Affine]) -> Self::Fqk {
+        assert_eq!(g1.len(), g2.len(), "Mismatched number of G1 and G2 elements in multi-pairing");
+        let prepared = cfg_iter!(g1).zip(g2).map(|(p, q)| (p.prepare(), q.prepare())).collect::<Vec<_>>();
+        Self::product_of_pairings(prepared.iter().map(|(p, q)| (p, q)))
+    }
 }
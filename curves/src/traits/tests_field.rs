@@ -14,6 +14,7 @@
 
 use snarkvm_fields::{
     traits::{FftParameters, FieldParameters},
+    ConstantTimeEq,
     FftField,
     Field,
     LegendreSymbol,
@@ -24,6 +25,8 @@ use snarkvm_utilities::{
     io::Cursor,
     rand::TestRng,
     serialize::{CanonicalDeserialize, Flags, SWFlags},
+    FromBytesChecked,
+    ToBytes,
 };
 
 use rand::Rng;
@@ -109,6 +112,26 @@ fn random_inversion_tests<F: Field, R: Rng>(rng: &mut R) {
     }
 }
 
+fn random_batch_inversion_tests<F: Field, R: Rng>(rng: &mut R) {
+    for num_elements in [0, 1, 2, 3, 100] {
+        let mut elements = (0..num_elements).map(|_| F::rand(rng)).collect::<Vec<_>>();
+        // Interleave zeros, which must be left unchanged.
+        for i in (0..num_elements).step_by(3) {
+            elements[i] = F::zero();
+        }
+        let expected = elements.iter().map(|element| element.inverse().unwrap_or_else(F::zero)).collect::<Vec<_>>();
+        let expected_zeros = (0..num_elements).step_by(3).collect::<Vec<_>>();
+
+        let mut candidate = elements.clone();
+        F::batch_inverse(&mut candidate);
+        assert_eq!(candidate, expected);
+
+        let mut candidate = elements.clone();
+        assert_eq!(F::batch_inverse_skip_zeros(&mut candidate), expected_zeros);
+        assert_eq!(candidate, expected);
+    }
+}
+
 fn random_doubling_tests<F: Field, R: Rng>(rng: &mut R) {
     for _ in 0..ITERATIONS {
         let mut a = F::rand(rng);
@@ -239,6 +262,19 @@ fn random_sqrt_tests<F: SquareRootField>(rng: &mut TestRng) {
 
         c += &F::one();
     }
+
+    let elements = (0..ITERATIONS).map(|_| F::rand(rng)).collect::<Vec<_>>();
+    let squares = elements.iter().map(|a| a.square()).collect::<Vec<_>>();
+    assert!(F::is_square_batch(&squares).into_iter().all(|is_square| is_square));
+    let expected = elements.iter().map(|a| a.sqrt().is_some()).collect::<Vec<_>>();
+    assert_eq!(F::is_square_batch(&elements), expected);
+    assert_eq!(F::is_square_batch(&[F::zero()]), vec![true]);
+
+    let expected = elements.iter().map(|a| a.sqrt()).collect::<Vec<_>>();
+    assert_eq!(F::sqrt_batch(&elements), expected);
+    let expected = squares.iter().map(|a| a.sqrt()).collect::<Vec<_>>();
+    assert_eq!(F::sqrt_batch(&squares), expected);
+    assert_eq!(F::sqrt_batch(&[F::zero(), F::one()]), vec![Some(F::zero()), F::one().sqrt()]);
 }
 
 pub fn random_sqrt_tonelli_tests<F: PrimeField + SquareRootField>(rng: &mut TestRng) {
@@ -424,6 +460,25 @@ fn check_tonelli_shanks_sqrt_for_testing<F: PrimeField + SquareRootField>(field:
     }
 }
 
+pub fn random_legendre_tests<F: PrimeField + SquareRootField>(rng: &mut TestRng) {
+    assert_eq!(F::zero().legendre(), LegendreSymbol::Zero);
+    assert_eq!(F::one().legendre(), LegendreSymbol::QuadraticResidue);
+    assert_eq!(F::multiplicative_generator().legendre(), LegendreSymbol::QuadraticNonResidue);
+    assert_eq!((-F::one()).legendre().is_qr(), F::characteristic()[0] % 4 == 1);
+
+    // Compare against Euler's criterion, a^((p - 1) / 2).
+    for _ in 0..1_000 {
+        let a = F::rand(rng);
+        let expected = match a.pow(F::modulus_minus_one_div_two()) {
+            s if s.is_zero() => LegendreSymbol::Zero,
+            s if s.is_one() => LegendreSymbol::QuadraticResidue,
+            _ => LegendreSymbol::QuadraticNonResidue,
+        };
+        assert_eq!(a.legendre(), expected);
+        assert_eq!(a.square().legendre(), LegendreSymbol::QuadraticResidue);
+    }
+}
+
 #[allow(clippy::eq_op)]
 pub fn field_test<F: Field>(a: F, b: F, rng: &mut TestRng) {
     let zero = F::zero();
@@ -494,6 +549,7 @@ pub fn field_test<F: Field>(a: F, b: F, rng: &mut TestRng) {
             a.push(F::rand(rng));
             b.push(F::rand(rng));
             assert_eq!(F::sum_of_products(a.iter(), b.iter()), a.iter().zip(b.iter()).map(|(x, y)| *x * y).sum());
+            assert_eq!(F::sum_of_products_slice(&a, &b), F::sum_of_products(a.iter(), b.iter()));
         }
     }
 
@@ -502,6 +558,7 @@ pub fn field_test<F: Field>(a: F, b: F, rng: &mut TestRng) {
     random_subtraction_tests::<F, _>(rng);
     random_multiplication_tests::<F, _>(rng);
     random_inversion_tests::<F, _>(rng);
+    random_batch_inversion_tests::<F, _>(rng);
     random_doubling_tests::<F, _>(rng);
     random_squaring_tests::<F, _>(rng);
     random_expansion_tests::<F, _>(rng);
@@ -554,6 +611,43 @@ pub fn fft_field_test<F: PrimeField + FftField>() {
     assert!(!two_adic_root_of_unity.is_zero());
     assert_eq!(two_adic_root_of_unity.pow([1 << two_adicity]), F::one());
     assert_eq!(generator.pow(trace.to_bigint().as_ref()), two_adic_root_of_unity);
+
+    // Ensure the domain size queries agree with `get_root_of_unity`.
+    assert_eq!(F::two_adicity(), two_adicity);
+    assert_eq!(F::max_two_adic_domain_size(), Some(1 << two_adicity));
+    for log_size in 0..=two_adicity.min(20) {
+        let size = 1usize << log_size;
+        assert!(F::supports_domain_size(size));
+        assert_eq!(F::get_root_of_unity(size).unwrap().pow([size as u64]), F::one());
+        assert_eq!(F::smallest_two_adic_domain_size(size), Some(size));
+        if size > 2 {
+            assert_eq!(F::smallest_two_adic_domain_size(size - 1), Some(size));
+        }
+    }
+    assert!(!F::supports_domain_size(0));
+    assert!(F::smallest_two_adic_domain_size(1 << (two_adicity + 1)).is_none());
+    match F::large_subgroup_root_of_unity() {
+        Some(_) => {
+            let q = F::FftParameters::SMALL_SUBGROUP_BASE.unwrap() as usize;
+            assert!(F::supports_domain_size(q));
+            let root = F::get_root_of_unity(q).unwrap();
+            assert_ne!(root, F::one());
+            assert_eq!(root.pow([q as u64]), F::one());
+        }
+        None => {
+            assert!(!F::supports_domain_size(3));
+            assert!(F::get_root_of_unity(3).is_none());
+        }
+    }
+    for n in 1..1000 {
+        if let Some(size) = F::smallest_domain_size(n) {
+            assert!(size >= n && F::supports_domain_size(size));
+            assert!((n..size).all(|smaller| !F::supports_domain_size(smaller)));
+        }
+        if let Some(two_adic_size) = F::smallest_two_adic_domain_size(n) {
+            assert!(F::smallest_domain_size(n).unwrap() <= two_adic_size);
+        }
+    }
 }
 
 pub fn primefield_test<F: PrimeField>(rng: &mut TestRng) {
@@ -567,6 +661,38 @@ pub fn primefield_test<F: PrimeField>(rng: &mut TestRng) {
     assert_eq!(F::from_str("2").ok().unwrap(), two);
     assert_eq!(F::from_str(&two.to_string()).ok().unwrap(), two);
 
+    // Ensure the Montgomery form round-trips, and rejects non-canonical representations.
+    let a = F::rand(rng);
+    assert_eq!(F::from_montgomery_bigint(a.to_montgomery_bigint()).unwrap(), a);
+    assert!(F::from_montgomery_bigint(F::modulus()).is_none());
+
+    // Ensure the generic Tonelli-Shanks square root is correct, and rejects non-residues.
+    let root = a.square().tonelli_shanks_sqrt().unwrap();
+    assert!(root == a || root == -a);
+    assert_eq!(F::zero().tonelli_shanks_sqrt(), Some(F::zero()));
+    assert!(F::multiplicative_generator().tonelli_shanks_sqrt().is_none());
+
+    // Ensure the checked deserialization rejects encodings of values that are not less than the modulus.
+    assert_eq!(F::from_bytes_le_checked(&a.to_bytes_le().unwrap()).unwrap(), a);
+    assert!(F::from_bytes_le_checked(&F::modulus().to_bytes_le().unwrap()).is_err());
+
+    // Ensure the constant-time inverse and equality agree with their variable-time counterparts.
+    if let Some(inverse) = a.inverse() {
+        assert_eq!(a.inverse_ct(), inverse);
+    }
+    assert_eq!(F::zero().inverse_ct(), F::zero());
+    assert!(bool::from(a.ct_eq(&a)));
+    assert!(!bool::from(a.ct_eq(&(a + F::one()))));
+
+    // Ensure the human-readable serde form is a hex string, and that the decimal form is still accepted.
+    let string = serde_json::to_string(&a).unwrap();
+    assert!(string.starts_with("\"0x"));
+    assert_eq!(serde_json::from_str::<F>(&string).unwrap(), a);
+    assert_eq!(serde_json::from_str::<F>(&format!("\"{a}\"")).unwrap(), a);
+    let mut modulus = F::modulus().to_bytes_le().unwrap();
+    modulus.reverse();
+    assert!(serde_json::from_str::<F>(&format!("\"0x{}\"", hex::encode(modulus))).is_err());
+
     random_string_tests::<F>(rng);
     fft_field_test::<F>();
 }
@@ -0,0 +1,201 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! A 511-bit prime field for testing `Fp512`, as no curve in this crate uses a 512-bit field.
+
+use crate::traits::tests_field::{
+    field_serialization_test,
+    field_test,
+    primefield_test,
+    random_legendre_tests,
+    sqrt_field_test,
+};
+use snarkvm_fields::{
+    FftParameters,
+    FieldParameters,
+    Fp512,
+    Fp512Parameters,
+    PoseidonDefaultParameters,
+    PoseidonDefaultParametersEntry,
+    PrimeField,
+};
+use snarkvm_utilities::{biginteger::BigInteger512 as BigInteger, rand::TestRng};
+
+use rand::Rng;
+
+/// The prime field of order `q = 2^32 * t + 1` for a prime `t`, with `MODULUS < 2^511 - 2^500`,
+/// which satisfies the bound on the top limb of `Fp512Parameters`.
+pub type Fq = Fp512<FqParameters>;
+
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub struct FqParameters;
+
+impl Fp512Parameters for FqParameters {}
+
+impl FftParameters for FqParameters {
+    type BigInteger = BigInteger;
+
+    #[rustfmt::skip]
+    const TWO_ADICITY: u32 = 32;
+    /// TWO_ADIC_ROOT_OF_UNITY = GENERATOR^t, in Montgomery form
+    #[rustfmt::skip]
+    const TWO_ADIC_ROOT_OF_UNITY: BigInteger = BigInteger([
+        0xbbc13a5cafded9c1,
+        0x73fbbf324be086af,
+        0x0800085640157919,
+        0x9b5095ef89e676f1,
+        0x06cda523cef11e4a,
+        0xaa4b974084f71cb8,
+        0x3bd6694945750f82,
+        0x00ec74baa4229081,
+    ]);
+}
+
+impl FieldParameters for FqParameters {
+    #[rustfmt::skip]
+    const CAPACITY: u32 = Self::MODULUS_BITS - 1;
+    /// GENERATOR = 3, in Montgomery form
+    #[rustfmt::skip]
+    const GENERATOR: BigInteger = BigInteger([
+        0x0017d779fffffffa,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0060000000000000,
+    ]);
+    #[rustfmt::skip]
+    const INV: u64 = 18445625599801163775u64;
+    /// MODULUS = 6700630574363402407916999309406095464587466268250153624072297430492785881240368813846053878991125809687865042409115465355867724149427399579045022067064833
+    #[rustfmt::skip]
+    const MODULUS: BigInteger = BigInteger([
+        0xfffc06c100000001,
+        0xffffffffffffffff,
+        0xffffffffffffffff,
+        0xffffffffffffffff,
+        0xffffffffffffffff,
+        0xffffffffffffffff,
+        0xffffffffffffffff,
+        0x7fefffffffffffff,
+    ]);
+    #[rustfmt::skip]
+    const MODULUS_BITS: u32 = 511;
+    /// (MODULUS - 1) / 2
+    #[rustfmt::skip]
+    const MODULUS_MINUS_ONE_DIV_TWO: BigInteger = BigInteger([
+        0xfffe036080000000,
+        0xffffffffffffffff,
+        0xffffffffffffffff,
+        0xffffffffffffffff,
+        0xffffffffffffffff,
+        0xffffffffffffffff,
+        0xffffffffffffffff,
+        0x3ff7ffffffffffff,
+    ]);
+    #[rustfmt::skip]
+    const R: BigInteger = BigInteger([
+        0x0007f27dfffffffe,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0020000000000000,
+    ]);
+    #[rustfmt::skip]
+    const R2: BigInteger = BigInteger([
+        0x7a445d9de2206094,
+        0x1bc378ae46454e3e,
+        0x6f0de1bc3786f0de,
+        0xbc3786f0de1bc378,
+        0xf0de1bc3786f0de1,
+        0xc3786f0de1bc3786,
+        0x0de1bc3786f0de1b,
+        0x2e46f0de1bc3786f,
+    ]);
+    #[rustfmt::skip]
+    const REPR_SHAVE_BITS: u32 = 1;
+    /// t = (MODULUS - 1) / 2^s
+    #[rustfmt::skip]
+    const T: BigInteger = BigInteger([
+        0xfffffffffffc06c1,
+        0xffffffffffffffff,
+        0xffffffffffffffff,
+        0xffffffffffffffff,
+        0xffffffffffffffff,
+        0xffffffffffffffff,
+        0xffffffffffffffff,
+        0x000000007fefffff,
+    ]);
+    /// (t - 1) / 2
+    #[rustfmt::skip]
+    const T_MINUS_ONE_DIV_TWO: BigInteger = BigInteger([
+        0xfffffffffffe0360,
+        0xffffffffffffffff,
+        0xffffffffffffffff,
+        0xffffffffffffffff,
+        0xffffffffffffffff,
+        0xffffffffffffffff,
+        0xffffffffffffffff,
+        0x000000003ff7ffff,
+    ]);
+}
+
+impl PoseidonDefaultParameters for FqParameters {
+    const PARAMS_OPT_FOR_CONSTRAINTS: [PoseidonDefaultParametersEntry; 7] = [
+        PoseidonDefaultParametersEntry::new(2, 5, 8, 56, 0),
+        PoseidonDefaultParametersEntry::new(3, 5, 8, 56, 0),
+        PoseidonDefaultParametersEntry::new(4, 5, 8, 56, 0),
+        PoseidonDefaultParametersEntry::new(5, 5, 8, 57, 0),
+        PoseidonDefaultParametersEntry::new(6, 5, 8, 57, 0),
+        PoseidonDefaultParametersEntry::new(7, 5, 8, 57, 0),
+        PoseidonDefaultParametersEntry::new(8, 5, 8, 57, 0),
+    ];
+}
+
+const ITERATIONS: usize = 10;
+
+#[test]
+fn test_fp512() {
+    let mut rng = TestRng::default();
+
+    for _ in 0..ITERATIONS {
+        let a: Fq = rng.gen();
+        let b: Fq = rng.gen();
+        field_test(a, b, &mut rng);
+        primefield_test::<Fq>(&mut rng);
+        sqrt_field_test(b, &mut rng);
+        field_serialization_test::<Fq>(&mut rng);
+    }
+}
+
+#[test]
+fn test_fp512_legendre() {
+    let mut rng = TestRng::default();
+
+    random_legendre_tests::<Fq>(&mut rng);
+}
+
+#[test]
+fn test_fp512_canonical_limbs() {
+    let mut rng = TestRng::default();
+
+    for _ in 0..ITERATIONS {
+        let a: Fq = rng.gen();
+        assert_eq!(Fq::from_canonical_limbs(a.to_bigint().0), a);
+    }
+}
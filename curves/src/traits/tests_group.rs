@@ -14,7 +14,10 @@
 
 use crate::{AffineCurve, ProjectiveCurve};
 use snarkvm_fields::{One, Zero};
-use snarkvm_utilities::rand::{TestRng, Uniform};
+use snarkvm_utilities::{
+    bititerator::BitIteratorBE,
+    rand::{TestRng, Uniform},
+};
 
 #[allow(clippy::eq_op)]
 pub fn affine_test<G: AffineCurve>(a: G) {
@@ -29,6 +32,8 @@ pub fn affine_test<G: AffineCurve>(a: G) {
     // a == a
     assert!(a == a);
     assert_eq!(a.mul_by_cofactor_to_projective(), a.mul_by_cofactor());
+    assert_eq!(a.mul_by_cofactor_to_projective(), a.mul_bits(BitIteratorBE::new(G::cofactor())));
+    assert_eq!(a.to_projective().mul_by_cofactor(), a.mul_by_cofactor());
     assert_eq!(a.mul_by_cofactor_inv().mul_by_cofactor(), a);
 }
 
@@ -0,0 +1,206 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::traits::ProjectiveCurve;
+use snarkvm_fields::{PrimeField, Zero};
+use snarkvm_utilities::BigInteger;
+
+/// A reusable context for scalar multiplication in windowed non-adjacent form (wNAF).
+///
+/// The context owns the table of precomputed odd multiples of the base, along with the scratch space
+/// for recoding the scalars, so that repeated multiplications do not allocate.
+#[derive(Clone, Debug)]
+pub struct WnafContext<G: ProjectiveCurve> {
+    /// The window size `w`, which yields digits that are zero or odd and less than `2^(w-1)` in absolute value.
+    window_size: usize,
+    /// The odd multiples `[P, 3P, 5P, ..., (2^(w-1) - 1)P]` of the base `P`.
+    table: Vec<G::Affine>,
+    /// The scratch space for computing the table in projective coordinates.
+    table_scratch: Vec<G>,
+    /// The scratch space for the wNAF digits of the scalar, least-significant digit first.
+    digits: Vec<i64>,
+}
+
+impl<G: ProjectiveCurve> WnafContext<G> {
+    /// The maximum supported window size.
+    pub const MAX_WINDOW_SIZE: usize = 16;
+    /// The minimum supported window size.
+    pub const MIN_WINDOW_SIZE: usize = 2;
+
+    /// Initializes a new context with the given window size, and the point at infinity as the base.
+    ///
+    /// # Panics
+    /// This method panics if the window size is not between `MIN_WINDOW_SIZE` and `MAX_WINDOW_SIZE`.
+    pub fn new(window_size: usize) -> Self {
+        assert!(
+            (Self::MIN_WINDOW_SIZE..=Self::MAX_WINDOW_SIZE).contains(&window_size),
+            "The wNAF window size must be between {} and {}",
+            Self::MIN_WINDOW_SIZE,
+            Self::MAX_WINDOW_SIZE
+        );
+        let table_size = 1 << (window_size - 2);
+        let num_digits = <G::ScalarField as PrimeField>::size_in_bits() + 1;
+        Self {
+            window_size,
+            table: vec![G::Affine::zero(); table_size],
+            table_scratch: Vec::with_capacity(table_size),
+            digits: Vec::with_capacity(num_digits),
+        }
+    }
+
+    /// Initializes a new context with the given window size and base.
+    pub fn with_base(window_size: usize, base: G) -> Self {
+        let mut context = Self::new(window_size);
+        context.set_base(base);
+        context
+    }
+
+    /// Returns the window size of this context.
+    pub const fn window_size(&self) -> usize {
+        self.window_size
+    }
+
+    /// Replaces the base of this context, recomputing the table in place.
+    pub fn set_base(&mut self, base: G) {
+        let double = base.double();
+        self.table_scratch.clear();
+        self.table_scratch.push(base);
+        for i in 1..self.table.len() {
+            let next = self.table_scratch[i - 1] + double;
+            self.table_scratch.push(next);
+        }
+        G::batch_normalization_into_affine_in_place(&mut self.table_scratch, &mut self.table);
+    }
+
+    /// Returns `scalar * base`, for the base of this context.
+    pub fn mul(&mut self, scalar: &G::ScalarField) -> G {
+        self.recode(scalar);
+
+        let mut result = G::zero();
+        for digit in self.digits.iter().rev() {
+            result.double_in_place();
+            match digit.signum() {
+                1 => result.add_assign_mixed(&self.table[(digit / 2) as usize]),
+                -1 => result.sub_assign_mixed(&self.table[(-digit / 2) as usize]),
+                _ => (),
+            }
+        }
+        result
+    }
+
+    /// Returns `scalar * base`, after replacing the base of this context.
+    pub fn mul_with_base(&mut self, base: G, scalar: &G::ScalarField) -> G {
+        self.set_base(base);
+        self.mul(scalar)
+    }
+
+    /// Writes the wNAF digits of the given scalar into the scratch space, least-significant digit first.
+    fn recode(&mut self, scalar: &G::ScalarField) {
+        let window = 1u64 << self.window_size;
+        let half_window = window >> 1;
+
+        let mut e = scalar.to_bigint();
+        // The carry out of the top limb, which only occurs for scalar fields that fill their limbs.
+        let mut carry = false;
+
+        self.digits.clear();
+        while !e.is_zero() {
+            let digit = match e.is_odd() {
+                true => {
+                    let digit = e.as_ref()[0] & (window - 1);
+                    match digit >= half_window {
+                        true => {
+                            carry |= e.add_nocarry(&(window - digit).into());
+                            digit as i64 - window as i64
+                        }
+                        false => {
+                            e.sub_noborrow(&digit.into());
+                            digit as i64
+                        }
+                    }
+                }
+                false => 0,
+            };
+            self.digits.push(digit);
+
+            e.div2();
+            if carry {
+                let top = e.as_ref().len() - 1;
+                e.as_mut()[top] |= 1 << 63;
+                carry = false;
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{bls12_377::G1Projective, edwards_bls12::EdwardsProjective, secp256k1::Secp256k1Projective};
+    use snarkvm_fields::{One, Zero};
+    use snarkvm_utilities::{TestRng, Uniform};
+
+    const ITERATIONS: usize = 20;
+
+    fn wnaf_test<G: ProjectiveCurve>(rng: &mut TestRng) {
+        for window_size in [2, 3, 4, 5, 8] {
+            let mut context = WnafContext::<G>::new(window_size);
+            assert_eq!(context.window_size(), window_size);
+
+            // The context is reused across bases and scalars.
+            for _ in 0..ITERATIONS {
+                let base = G::rand(rng);
+                context.set_base(base);
+                for _ in 0..4 {
+                    let scalar = G::ScalarField::rand(rng);
+                    assert_eq!(context.mul(&scalar), base * scalar);
+                }
+
+                assert!(context.mul(&G::ScalarField::zero()).is_zero());
+                assert_eq!(context.mul(&G::ScalarField::one()), base);
+                assert_eq!(context.mul(&-G::ScalarField::one()), -base);
+            }
+
+            let base = G::rand(rng);
+            let scalar = G::ScalarField::rand(rng);
+            assert_eq!(WnafContext::with_base(window_size, base).mul(&scalar), base * scalar);
+            assert!(context.mul_with_base(G::zero(), &scalar).is_zero());
+        }
+    }
+
+    #[test]
+    fn test_wnaf_bls12_377() {
+        let mut rng = TestRng::default();
+        wnaf_test::<G1Projective>(&mut rng);
+    }
+
+    #[test]
+    fn test_wnaf_edwards_bls12() {
+        let mut rng = TestRng::default();
+        wnaf_test::<EdwardsProjective>(&mut rng);
+    }
+
+    #[test]
+    fn test_wnaf_secp256k1() {
+        // The scalar field of secp256k1 fills all of its limbs, which exercises the carry in the recoding.
+        let mut rng = TestRng::default();
+        wnaf_test::<Secp256k1Projective>(&mut rng);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_wnaf_invalid_window_size() {
+        let _ = WnafContext::<G1Projective>::new(1);
+    }
+}
@@ -0,0 +1,142 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Differential tests of the BLS12-377 and Edwards-BLS12 groups against their arkworks implementations.
+//!
+//! Points are moved across by re-decoding their canonical encoding on the other side. The two libraries share
+//! the short Weierstrass encodings, but only the uncompressed twisted Edwards encoding, as snarkVM compresses
+//! the `x`-coordinate while arkworks compresses the `y`-coordinate.
+
+use ark_ec::{AffineRepr, CurveGroup, Group};
+use snarkvm_curves::{bls12_377, edwards_bls12, AffineCurve, ProjectiveCurve};
+use snarkvm_fields::Zero;
+use snarkvm_utilities::{
+    serialize::{CanonicalDeserialize, CanonicalSerialize, Compress},
+    TestRng,
+    Uniform,
+};
+
+const ITERATIONS: usize = 1000;
+
+fn to_arkworks_compress(compress: Compress) -> ark_serialize::Compress {
+    match compress {
+        Compress::Yes => ark_serialize::Compress::Yes,
+        Compress::No => ark_serialize::Compress::No,
+    }
+}
+
+/// Returns the encoding of the given snarkVM value.
+fn serialize<T: CanonicalSerialize>(value: &T, compress: Compress) -> Vec<u8> {
+    let mut bytes = Vec::new();
+    value.serialize_with_mode(&mut bytes, compress).unwrap();
+    bytes
+}
+
+/// Returns the encoding of the given arkworks value.
+fn serialize_arkworks<U: ark_serialize::CanonicalSerialize>(value: &U, compress: Compress) -> Vec<u8> {
+    let mut bytes = Vec::new();
+    value.serialize_with_mode(&mut bytes, to_arkworks_compress(compress)).unwrap();
+    bytes
+}
+
+/// Converts a snarkVM value into the arkworks value with the same encoding.
+fn to_arkworks<T: CanonicalSerialize, U: ark_serialize::CanonicalDeserialize>(value: &T, compress: Compress) -> U {
+    let bytes = serialize(value, compress);
+    U::deserialize_with_mode(&bytes[..], to_arkworks_compress(compress), ark_serialize::Validate::Yes).unwrap()
+}
+
+/// Converts an arkworks value into the snarkVM value with the same encoding.
+fn from_arkworks<U: ark_serialize::CanonicalSerialize, T: CanonicalDeserialize>(value: &U, compress: Compress) -> T {
+    let bytes = serialize_arkworks(value, compress);
+    T::deserialize_with_mode(&bytes[..], compress, snarkvm_utilities::serialize::Validate::Yes).unwrap()
+}
+
+/// Checks the group law, scalar multiplication, and the encoding of `G` against its arkworks counterpart `A`.
+fn differential_test<G: ProjectiveCurve, A: CurveGroup>(compress: Compress, rng: &mut TestRng) {
+    let to_ark = |point: &G| -> A { to_arkworks::<_, A::Affine>(&point.to_affine(), compress).into_group() };
+    let from_ark = |point: &A| -> G { from_arkworks::<_, G::Affine>(&point.into_affine(), compress).to_projective() };
+
+    for _ in 0..ITERATIONS {
+        let (a, b) = (G::rand(rng), G::rand(rng));
+        let (ark_a, ark_b) = (to_ark(&a), to_ark(&b));
+        let scalar = G::ScalarField::rand(rng);
+        let ark_scalar: A::ScalarField = to_arkworks(&scalar, Compress::Yes);
+
+        assert_eq!(a + b, from_ark(&(ark_a + ark_b)));
+        assert_eq!(a - b, from_ark(&(ark_a - ark_b)));
+        assert_eq!(a.add_mixed(&b.to_affine()), from_ark(&(ark_a + ark_b.into_affine())));
+        assert_eq!(a.double(), from_ark(&ark_a.double()));
+        assert_eq!(a * scalar, from_ark(&(ark_a * ark_scalar)));
+
+        // Ensure both libraries produce the same encodings.
+        assert_eq!(serialize(&a.to_affine(), compress), serialize_arkworks(&ark_a.into_affine(), compress));
+    }
+
+    // Ensure the edge cases of the addition formulas agree.
+    let a = G::rand(rng);
+    let ark_a = to_ark(&a);
+    let ark_zero = A::Affine::zero().into_group();
+
+    assert!((a + -a).is_zero());
+    assert!((ark_a + -ark_a).into_affine().is_zero());
+    assert!(from_ark(&(ark_a + -ark_a)).is_zero());
+    assert!(a.add_mixed(&(-a).to_affine()).is_zero());
+    assert!((ark_a + (-ark_a).into_affine()).into_affine().is_zero());
+
+    assert!(G::zero().double().is_zero());
+    assert!(ark_zero.double().into_affine().is_zero());
+    assert!(from_ark(&ark_zero.double()).is_zero());
+    assert!(to_ark(&G::zero().double()).into_affine().is_zero());
+
+    assert_eq!(a + a, from_ark(&(ark_a + ark_a)));
+    assert_eq!(a.add_mixed(&a.to_affine()), from_ark(&(ark_a + ark_a.into_affine())));
+    assert_eq!(a + G::zero(), from_ark(&(ark_a + ark_zero)));
+    assert_eq!(G::zero().add_mixed(&a.to_affine()), from_ark(&(ark_zero + ark_a.into_affine())));
+    assert!((a * G::ScalarField::zero()).is_zero());
+
+    // Ensure the point at infinity has the same encoding.
+    assert!(to_ark(&G::zero()).into_affine().is_zero());
+    assert!(from_ark(&ark_zero).to_affine().is_zero());
+}
+
+#[test]
+fn test_bls12_377_g1() {
+    let mut rng = TestRng::default();
+    differential_test::<bls12_377::G1Projective, ark_bls12_377::G1Projective>(Compress::Yes, &mut rng);
+    differential_test::<bls12_377::G1Projective, ark_bls12_377::G1Projective>(Compress::No, &mut rng);
+}
+
+#[test]
+fn test_bls12_377_g2() {
+    let mut rng = TestRng::default();
+    differential_test::<bls12_377::G2Projective, ark_bls12_377::G2Projective>(Compress::Yes, &mut rng);
+    differential_test::<bls12_377::G2Projective, ark_bls12_377::G2Projective>(Compress::No, &mut rng);
+}
+
+#[test]
+fn test_edwards_bls12() {
+    let mut rng = TestRng::default();
+    differential_test::<edwards_bls12::EdwardsProjective, ark_ed_on_bls12_377::EdwardsProjective>(
+        Compress::No,
+        &mut rng,
+    );
+}
+
+#[test]
+fn test_bls12_377_generators() {
+    let g1: ark_bls12_377::G1Affine = to_arkworks(&bls12_377::G1Affine::prime_subgroup_generator(), Compress::Yes);
+    assert_eq!(g1, ark_bls12_377::G1Affine::generator());
+    let g2: ark_bls12_377::G2Affine = to_arkworks(&bls12_377::G2Affine::prime_subgroup_generator(), Compress::Yes);
+    assert_eq!(g2, ark_bls12_377::G2Affine::generator());
+}
@@ -0,0 +1,129 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Montgomery multiplication on x86-64 with the `MULX`, `ADCX`, and `ADOX` instructions (BMI2 and ADX),
+//! which accumulate the low and high words of the partial products on two independent carry chains.
+//!
+//! Like the portable implementations, these routines require the modulus to leave a spare bit in its
+//! most significant limb, so that the intermediate result never exceeds `N` limbs. The result is in
+//! `[0, 2 * MODULUS)`, and must be conditionally reduced by the caller.
+
+#![allow(unsafe_code)]
+
+use core::arch::asm;
+
+/// Returns `true` if the CPU supports the BMI2 and ADX instruction set extensions.
+#[inline]
+pub(crate) fn is_supported() -> bool {
+    std::is_x86_feature_detected!("bmi2") && std::is_x86_feature_detected!("adx")
+}
+
+/// Emits `t += src * rdx`, where each tuple is `(byte offset of src[j], t[j], t[j + 1])`.
+/// The low words are added on the `CF` chain, and the high words on the `OF` chain,
+/// and the final `CF` carry is left for the caller to add into the top limb of `t`.
+macro_rules! mul_add {
+    ($src:literal; $(($offset:literal, $lo:literal, $hi:literal)),*) => {
+        concat!(
+            // Clear `CF` and `OF`.
+            "xor {lo:e}, {lo:e}\n",
+            $(
+                "mulx {hi}, {lo}, qword ptr [{", $src, "} + ", $offset, "]\n",
+                "adcx {", $lo, "}, {lo}\n",
+                "adox {", $hi, "}, {hi}\n",
+            )*
+        )
+    };
+}
+
+/// Returns `a * b * R^-1`, where `modulus` is `[MODULUS, INV]` and `R = 2^256`,
+/// or `None` if the CPU does not support BMI2 and ADX.
+#[inline]
+pub(crate) fn mul_4(a: &[u64; 4], b: &[u64; 4], modulus: &[u64; 5]) -> Option<[u64; 4]> {
+    if !is_supported() {
+        return None;
+    }
+
+    let (mut t0, mut t1, mut t2, mut t3, mut t4) = (0u64, 0u64, 0u64, 0u64, 0u64);
+    for &b in b {
+        // SAFETY: The CPU supports BMI2 and ADX, and the block only reads from `a` and `modulus`.
+        unsafe {
+            asm!(
+                // t += a * b[i]
+                mul_add!("a"; (0, "t0", "t1"), (8, "t1", "t2"), (16, "t2", "t3"), (24, "t3", "t4")),
+                "adc {t4}, 0",
+                // t += (t[0] * INV) * MODULUS, which clears t[0]
+                "mov rdx, {t0}",
+                "imul rdx, qword ptr [{m} + 32]",
+                mul_add!("m"; (0, "t0", "t1"), (8, "t1", "t2"), (16, "t2", "t3"), (24, "t3", "t4")),
+                "adc {t4}, 0",
+                a = in(reg) a.as_ptr(),
+                m = in(reg) modulus.as_ptr(),
+                t0 = inout(reg) t0,
+                t1 = inout(reg) t1,
+                t2 = inout(reg) t2,
+                t3 = inout(reg) t3,
+                t4 = inout(reg) t4,
+                lo = out(reg) _,
+                hi = out(reg) _,
+                inout("rdx") b => _,
+                options(pure, readonly, nostack),
+            );
+        }
+        // Shift `t` down by one limb, reusing the cleared `t[0]` as the new top limb.
+        (t0, t1, t2, t3, t4) = (t1, t2, t3, t4, t0);
+    }
+    Some([t0, t1, t2, t3])
+}
+
+/// Returns `a * b * R^-1`, where `modulus` is `[MODULUS, INV]` and `R = 2^384`,
+/// or `None` if the CPU does not support BMI2 and ADX.
+#[inline]
+pub(crate) fn mul_6(a: &[u64; 6], b: &[u64; 6], modulus: &[u64; 7]) -> Option<[u64; 6]> {
+    if !is_supported() {
+        return None;
+    }
+
+    let (mut t0, mut t1, mut t2, mut t3, mut t4, mut t5, mut t6) = (0u64, 0u64, 0u64, 0u64, 0u64, 0u64, 0u64);
+    for &b in b {
+        // SAFETY: The CPU supports BMI2 and ADX, and the block only reads from `a` and `modulus`.
+        unsafe {
+            asm!(
+                // t += a * b[i]
+                mul_add!("a"; (0, "t0", "t1"), (8, "t1", "t2"), (16, "t2", "t3"), (24, "t3", "t4"), (32, "t4", "t5"), (40, "t5", "t6")),
+                "adc {t6}, 0",
+                // t += (t[0] * INV) * MODULUS, which clears t[0]
+                "mov rdx, {t0}",
+                "imul rdx, qword ptr [{m} + 48]",
+                mul_add!("m"; (0, "t0", "t1"), (8, "t1", "t2"), (16, "t2", "t3"), (24, "t3", "t4"), (32, "t4", "t5"), (40, "t5", "t6")),
+                "adc {t6}, 0",
+                a = in(reg) a.as_ptr(),
+                m = in(reg) modulus.as_ptr(),
+                t0 = inout(reg) t0,
+                t1 = inout(reg) t1,
+                t2 = inout(reg) t2,
+                t3 = inout(reg) t3,
+                t4 = inout(reg) t4,
+                t5 = inout(reg) t5,
+                t6 = inout(reg) t6,
+                lo = out(reg) _,
+                hi = out(reg) _,
+                inout("rdx") b => _,
+                options(pure, readonly, nostack),
+            );
+        }
+        // Shift `t` down by one limb, reusing the cleared `t[0]` as the new top limb.
+        (t0, t1, t2, t3, t4, t5, t6) = (t1, t2, t3, t4, t5, t6, t0);
+    }
+    Some([t0, t1, t2, t3, t4, t5])
+}
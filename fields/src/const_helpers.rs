@@ -0,0 +1,106 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Helpers for constructing field elements in a `const` context, used by the `const_field!` macro.
+
+/// Returns `a * b * R^-1 mod modulus` on little-endian limbs, where `R = 2^(64 * N)`.
+pub const fn mont_mul<const N: usize>(a: &[u64; N], b: &[u64; N], modulus: &[u64; N], inv: u64) -> [u64; N] {
+    // The coarsely integrated operand scanning (CIOS) method, with `t_hi` holding the limb above `t`.
+    let mut t = [0u64; N];
+    let mut t_hi = 0u64;
+    let mut i = 0;
+    while i < N {
+        // t += a * b[i]
+        let mut carry = 0u64;
+        let mut j = 0;
+        while j < N {
+            let tmp = t[j] as u128 + a[j] as u128 * b[i] as u128 + carry as u128;
+            t[j] = tmp as u64;
+            carry = (tmp >> 64) as u64;
+            j += 1;
+        }
+        let (hi, overflow) = t_hi.overflowing_add(carry);
+
+        // t = (t + k * modulus) / 2^64, where k clears the lowest limb.
+        let k = t[0].wrapping_mul(inv);
+        let tmp = t[0] as u128 + k as u128 * modulus[0] as u128;
+        let mut carry = (tmp >> 64) as u64;
+        let mut j = 1;
+        while j < N {
+            let tmp = t[j] as u128 + k as u128 * modulus[j] as u128 + carry as u128;
+            t[j - 1] = tmp as u64;
+            carry = (tmp >> 64) as u64;
+            j += 1;
+        }
+        let tmp = hi as u128 + carry as u128;
+        t[N - 1] = tmp as u64;
+        t_hi = overflow as u64 + (tmp >> 64) as u64;
+        i += 1;
+    }
+
+    if t_hi != 0 || !lt(&t, modulus) {
+        sub(&mut t, modulus);
+    }
+    t
+}
+
+/// Returns `true` if `a < b`, comparing little-endian limbs.
+pub const fn lt<const N: usize>(a: &[u64; N], b: &[u64; N]) -> bool {
+    let mut i = N;
+    while i > 0 {
+        i -= 1;
+        if a[i] != b[i] {
+            return a[i] < b[i];
+        }
+    }
+    false
+}
+
+/// Sets `a = a - b`, wrapping on underflow.
+const fn sub<const N: usize>(a: &mut [u64; N], b: &[u64; N]) {
+    let mut borrow = 0u64;
+    let mut i = 0;
+    while i < N {
+        let (difference, borrow_a) = a[i].overflowing_sub(b[i]);
+        let (difference, borrow_b) = difference.overflowing_sub(borrow);
+        a[i] = difference;
+        borrow = (borrow_a | borrow_b) as u64;
+        i += 1;
+    }
+}
+
+/// Returns the little-endian limbs of the given decimal string.
+/// Panics if the string is empty, contains a non-digit, or does not fit in `N` limbs.
+pub const fn parse_decimal<const N: usize>(string: &str) -> [u64; N] {
+    let bytes = string.as_bytes();
+    assert!(!bytes.is_empty(), "The decimal string is empty");
+
+    let mut limbs = [0u64; N];
+    let mut i = 0;
+    while i < bytes.len() {
+        assert!(bytes[i].is_ascii_digit(), "The decimal string contains a non-digit character");
+        // limbs = limbs * 10 + digit
+        let mut carry = (bytes[i] - b'0') as u64;
+        let mut j = 0;
+        while j < N {
+            let tmp = limbs[j] as u128 * 10 + carry as u128;
+            limbs[j] = tmp as u64;
+            carry = (tmp >> 64) as u64;
+            j += 1;
+        }
+        assert!(carry == 0, "The decimal string does not fit in the integer");
+        i += 1;
+    }
+    limbs
+}
@@ -12,8 +12,15 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
-use crate::{fp6_3over2::*, Field, Fp2, Fp2Parameters, One, Zero};
-use snarkvm_utilities::{bititerator::BitIteratorBE, rand::Uniform, serialize::*, FromBytes, ToBits, ToBytes};
+use crate::{batch_inversion, fp6_3over2::*, Field, Fp2, Fp2Parameters, One, Zero};
+use snarkvm_utilities::{
+    bititerator::{BitIteratorBE, BitIteratorLE},
+    rand::Uniform,
+    serialize::*,
+    FromBytes,
+    ToBits,
+    ToBytes,
+};
 
 use rand::{
     distributions::{Distribution, Standard},
@@ -190,6 +197,101 @@ impl<P: Fp12Parameters> Fp12<P> {
         }
         res
     }
+
+    /// Squares an element of the cyclotomic subgroup in the compressed representation of
+    /// Karabina (<https://eprint.iacr.org/2010/542.pdf>), which only updates the coefficients
+    /// `c0.c1`, `c0.c2`, `c1.c0` and `c1.c2`. The remaining coefficients are left unchanged,
+    /// and are recovered with `batch_cyclotomic_decompress`.
+    pub fn cyclotomic_square_compressed(&mut self) {
+        let fp2_nr = <P::Fp6Params as Fp6Parameters>::mul_fp2_by_nonresidue;
+
+        let z4 = self.c0.c1;
+        let z3 = self.c0.c2;
+        let z2 = self.c1.c0;
+        let z5 = self.c1.c2;
+
+        // t2 + t3*y = (z2 + z3*y)^2
+        let t2 = z2.square() + fp2_nr(&z3.square());
+        let t3 = (z2 * z3).double();
+
+        // t4 + t5*y = (z4 + z5*y)^2
+        let t4 = z4.square() + fp2_nr(&z5.square());
+        let t5 = (z4 * z5).double();
+
+        // z2 = 3 * (xi * t5) + 2 * z2
+        let tmp = fp2_nr(&t5);
+        self.c1.c0 = tmp.double() + tmp + z2.double();
+
+        // z3 = 3 * t4 - 2 * z3
+        self.c0.c2 = t4.double() + t4 - z3.double();
+
+        // z4 = 3 * t2 - 2 * z4
+        self.c0.c1 = t2.double() + t2 - z4.double();
+
+        // z5 = 3 * t3 + 2 * z5
+        self.c1.c2 = t3.double() + t3 + z5.double();
+    }
+
+    /// Recovers the coefficients `c0.c0` and `c1.c1` of elements of the cyclotomic subgroup
+    /// from their compressed representation, with a single batched inversion.
+    pub fn batch_cyclotomic_decompress(elements: &mut [Self]) {
+        let fp2_nr = <P::Fp6Params as Fp6Parameters>::mul_fp2_by_nonresidue;
+
+        // The denominator of `c1.c1` is `4 * c1.c0`, or `c0.c2` if `c1.c0` is zero.
+        let mut denominators = elements
+            .iter()
+            .map(|e| match e.c1.c0.is_zero() {
+                true => e.c0.c2,
+                false => e.c1.c0.double().double(),
+            })
+            .collect::<Vec<_>>();
+        // Note: The denominator is only zero for the identity, which is handled below.
+        batch_inversion(&mut denominators);
+
+        for (e, inverse) in elements.iter_mut().zip(denominators) {
+            let (c01, c02, c10, c12) = (e.c0.c1, e.c0.c2, e.c1.c0, e.c1.c2);
+
+            // c1.c1 = (xi * c1.c2^2 + 3 * c0.c1^2 - 2 * c0.c2) / (4 * c1.c0), if c1.c0 is non-zero,
+            // c1.c1 = (2 * c0.c1 * c1.c2) / c0.c2, otherwise.
+            let numerator = match c10.is_zero() {
+                true => (c01 * c12).double(),
+                false => {
+                    let c01_squared = c01.square();
+                    fp2_nr(&c12.square()) + c01_squared.double() + c01_squared - c02.double()
+                }
+            };
+            let c11 = numerator * inverse;
+
+            // c0.c0 = xi * (2 * c1.c1^2 + c1.c0 * c1.c2 - 3 * c0.c1 * c0.c2) + 1
+            let c01_c02 = c01 * c02;
+            e.c0.c0 = fp2_nr(&(c11.square().double() + c10 * c12 - c01_c02.double() - c01_c02));
+            e.c0.c0 += Fp2::one();
+            e.c1.c1 = c11;
+        }
+    }
+
+    /// Exponentiates an element of the cyclotomic subgroup, using compressed squarings.
+    /// This is faster than `cyclotomic_exp` for sparse exponents, such as the curve parameter `x`.
+    pub fn cyclotomic_exp_compressed<S: AsRef<[u64]>>(&self, exp: S) -> Self {
+        // Compute `self^(2^i)` in compressed form, for each set bit `i` of the exponent.
+        let bits = BitIteratorLE::new(exp).collect::<Vec<_>>();
+        let num_bits = bits.iter().rposition(|bit| *bit).map_or(0, |i| i + 1);
+
+        let mut powers = Vec::new();
+        let mut compressed = *self;
+        for (i, bit) in bits.into_iter().take(num_bits).enumerate() {
+            if i != 0 {
+                compressed.cyclotomic_square_compressed();
+            }
+            if bit {
+                powers.push(compressed);
+            }
+        }
+
+        // Decompress the powers, and multiply them together.
+        Self::batch_cyclotomic_decompress(&mut powers);
+        powers.iter().fold(Self::one(), |product, power| product * power)
+    }
 }
 
 impl<P: Fp12Parameters> std::fmt::Display for Fp12<P> {
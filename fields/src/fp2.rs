@@ -12,8 +12,10 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
-use crate::{Field, LegendreSymbol, One, PrimeField, SquareRootField, Zero};
+use crate::{batch_inversion_and_mul, Field, LegendreSymbol, One, PrimeField, SquareRootField, Zero};
 use snarkvm_utilities::{
+    cfg_iter,
+    cfg_iter_mut,
     rand::Uniform,
     serialize::{SerializationError, *},
     FromBytes,
@@ -25,6 +27,8 @@ use rand::{
     distributions::{Distribution, Standard},
     Rng,
 };
+#[cfg(not(feature = "serial"))]
+use rayon::prelude::*;
 use serde::{Deserialize, Serialize};
 use std::{
     cmp::{Ord, Ordering, PartialOrd},
@@ -234,6 +238,46 @@ where
             self
         })
     }
+
+    /// Computes the square roots as in `sqrt`, but inverts the first coefficients of all of the
+    /// square roots with a single batch inversion, and reads the Legendre symbol of each element
+    /// off the square root of its norm rather than computing it separately.
+    fn sqrt_batch(elements: &[Self]) -> Vec<Option<Self>> {
+        let two_inv = P::Fp::half();
+        // Compute the first coefficient of each square root.
+        let mut roots = cfg_iter!(elements)
+            .map(|element| match element.c1.is_zero() {
+                true => element.sqrt(),
+                // An element is a square if and only if its norm is.
+                false => element.norm().sqrt().map(|alpha| {
+                    let delta = (alpha + element.c0) * two_inv;
+                    // Note: This expect is safe, as either `delta` or `delta - alpha` is a nonzero square.
+                    let c0 = delta.sqrt().or_else(|| (delta - alpha).sqrt()).expect("Delta must have a square root");
+                    Self::new(c0, P::Fp::zero())
+                }),
+            })
+            .collect::<Vec<_>>();
+
+        // Compute `1 / (2 * c0)` for each square root that still needs its second coefficient.
+        // Zeros are skipped by the batch inversion, and mark the square roots that are already complete.
+        let mut denominators = cfg_iter!(roots)
+            .zip(elements)
+            .map(|(root, element)| match (root, element.c1.is_zero()) {
+                (Some(root), false) => root.c0,
+                _ => P::Fp::zero(),
+            })
+            .collect::<Vec<_>>();
+        batch_inversion_and_mul(&mut denominators, &two_inv);
+
+        cfg_iter_mut!(roots).zip(elements).zip(denominators).for_each(|((root, element), inverse)| {
+            if let Some(root) = root {
+                if !inverse.is_zero() {
+                    root.c1 = element.c1 * inverse;
+                }
+            }
+        });
+        roots
+    }
 }
 
 /// `Fp2` elements are ordered lexicographically.
@@ -402,8 +446,8 @@ impl<'a, P: Fp2Parameters> MulAssign<&'a Self> for Fp2<P> {
     #[allow(clippy::suspicious_op_assign_impl)]
     fn mul_assign(&mut self, other: &Self) {
         *self = Self::new(
-            P::Fp::sum_of_products([self.c0, P::mul_fp_by_nonresidue(&self.c1)].iter(), [other.c0, other.c1].iter()),
-            P::Fp::sum_of_products([self.c0, self.c1].iter(), [other.c1, other.c0].iter()),
+            P::Fp::sum_of_products_slice(&[self.c0, P::mul_fp_by_nonresidue(&self.c1)], &[other.c0, other.c1]),
+            P::Fp::sum_of_products_slice(&[self.c0, self.c1], &[other.c1, other.c0]),
         )
     }
 }
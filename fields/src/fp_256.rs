@@ -19,11 +19,13 @@ use crate::{
     Field,
     FieldError,
     FieldParameters,
+    GlvDecomposition,
     LegendreSymbol,
     One,
     PoseidonDefaultField,
     PoseidonDefaultParameters,
     PrimeField,
+    Reduction,
     SquareRootField,
     Zero,
 };
@@ -43,14 +45,46 @@ use std::{
     ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Sub, SubAssign},
     str::FromStr,
 };
+use subtle::{Choice, ConstantTimeEq};
 use zeroize::Zeroize;
 
 pub trait Fp256Parameters: FieldParameters<BigInteger = BigInteger> {}
 
 #[derive(Copy, Clone, Default, PartialEq, Eq, Hash, Zeroize)]
+#[repr(transparent)]
 pub struct Fp256<P: Fp256Parameters>(pub BigInteger, #[doc(hidden)] pub PhantomData<P>);
 
 impl<P: Fp256Parameters> Fp256<P> {
+    /// Whether the modulus leaves no spare bit in the top limb, as is the case for e.g. secp256k1.
+    /// Such moduli require the arithmetic to track the carries out of the top limb.
+    const FULL_MODULUS: bool = P::MODULUS.0[3] >= (u64::MAX >> 1);
+
+    /// Whether the field elements are stored in Montgomery form, which the interleaved arithmetic relies on.
+    const MONTGOMERY: bool = matches!(P::REDUCTION, Reduction::Montgomery);
+
+    /// Whether the modulus leaves two spare bits in the top limb, which bounds the unreduced
+    /// sum of four products in `sum_of_four_products` below `2 * MODULUS`.
+    const TWO_SPARE_BITS: bool = P::MODULUS.0[3] < (1 << 62);
+
+    /// Returns the field element with the given limbs in Montgomery form, least significant limb first.
+    /// The limbs must be less than `MODULUS`, which is not checked.
+    #[inline]
+    pub const fn from_montgomery_limbs(limbs: [u64; 4]) -> Self {
+        Self(BigInteger(limbs), PhantomData)
+    }
+
+    /// Returns the field element for the given canonical integer limbs, least significant limb first.
+    /// Panics if the integer is not less than `MODULUS`.
+    pub const fn from_canonical_limbs(limbs: [u64; 4]) -> Self {
+        assert!(crate::const_helpers::lt(&limbs, &P::MODULUS.0), "The integer is not less than the modulus");
+        match P::REDUCTION {
+            Reduction::Montgomery => {
+                Self::from_montgomery_limbs(crate::const_helpers::mont_mul(&limbs, &P::R2.0, &P::MODULUS.0, P::INV))
+            }
+            Reduction::Crandall { .. } => Self::from_montgomery_limbs(limbs),
+        }
+    }
+
     #[inline]
     fn is_valid(&self) -> bool {
         self.0 < P::MODULUS
@@ -63,6 +97,52 @@ impl<P: Fp256Parameters> Fp256<P> {
         }
     }
 
+    /// Returns the sum of up to four pairwise products of `a` and `b`, with a single final reduction.
+    #[inline(always)]
+    fn sum_of_four_products(a: &[Self], b: &[Self]) -> Self {
+        debug_assert!(a.len() <= 4 && a.len() == b.len());
+
+        // This follows the interleaved operand scanning in `sum_of_products`, on a fixed number of terms.
+        let mut u = [0u64; 4];
+        for j in 0..4 {
+            let mut t = u;
+            let mut t4 = 0;
+            for (a, b) in a.iter().zip(b) {
+                let mut carry = 0;
+                for (l, t) in t.iter_mut().enumerate() {
+                    *t = fa::mac_with_carry(*t, a.0.0[j], b.0.0[l], &mut carry);
+                }
+                let _ = fa::adc(&mut t4, 0, carry);
+            }
+
+            let k = t[0].wrapping_mul(P::INV);
+            let mut carry = 0;
+            let _ = fa::mac_with_carry(t[0], k, P::MODULUS.0[0], &mut carry);
+            for l in 1..4 {
+                u[l - 1] = fa::mac_with_carry(t[l], k, P::MODULUS.0[l], &mut carry);
+            }
+            let _ = fa::adc(&mut t4, 0, carry);
+            u[3] = t4;
+        }
+
+        let mut result = Self(BigInteger(u), PhantomData);
+        result.reduce();
+        result
+    }
+
+    /// Returns `a * b` using the x86-64 assembly backend, or `None` if it is unavailable on this CPU.
+    #[cfg(all(feature = "asm", target_arch = "x86_64"))]
+    #[inline(always)]
+    fn mul_asm(a: &Self, b: &Self) -> Option<Self> {
+        if Self::FULL_MODULUS || !Self::MONTGOMERY {
+            return None;
+        }
+        let modulus = [P::MODULUS.0[0], P::MODULUS.0[1], P::MODULUS.0[2], P::MODULUS.0[3], P::INV];
+        let mut result = Self(BigInteger(crate::asm::mul_4(&(a.0).0, &(b.0).0, &modulus)?), PhantomData);
+        result.reduce();
+        Some(result)
+    }
+
     #[inline(always)]
     #[allow(clippy::too_many_arguments)]
     fn mont_reduce(
@@ -76,6 +156,11 @@ impl<P: Fp256Parameters> Fp256<P> {
         mut r6: u64,
         mut r7: u64,
     ) {
+        if let Reduction::Crandall { c } = P::REDUCTION {
+            self.crandall_reduce(c, [r0, r1, r2, r3, r4, r5, r6, r7]);
+            return;
+        }
+
         // The Montgomery reduction here is based on Algorithm 14.32 in
         // Handbook of Applied Cryptography
         // <http://cacr.uwaterloo.ca/hac/about/chap14.pdf>.
@@ -110,11 +195,41 @@ impl<P: Fp256Parameters> Fp256<P> {
         r4 = fa::mac_with_carry(r4, k, P::MODULUS.0[1], &mut carry);
         r5 = fa::mac_with_carry(r5, k, P::MODULUS.0[2], &mut carry);
         r6 = fa::mac_with_carry(r6, k, P::MODULUS.0[3], &mut carry);
-        fa::adc(&mut r7, carry2, carry);
+        let carry = fa::adc(&mut r7, carry2, carry);
         (self.0).0[0] = r4;
         (self.0).0[1] = r5;
         (self.0).0[2] = r6;
         (self.0).0[3] = r7;
+        // The carry is only ever set for a modulus without a spare bit.
+        if carry != 0 {
+            self.0.sub_noborrow(&P::MODULUS);
+        } else {
+            self.reduce();
+        }
+    }
+
+    /// Sets `self` to the reduction of the 512-bit integer `r` modulo `MODULUS = 2^256 - c`,
+    /// by repeatedly folding the high limbs with `2^256 = c (mod MODULUS)`.
+    #[inline(always)]
+    fn crandall_reduce(&mut self, c: u64, r: [u64; 8]) {
+        // Fold the high half into the low half, leaving at most one limb of carry.
+        let mut carry = 0;
+        for i in 0..4 {
+            (self.0).0[i] = fa::mac_with_carry(r[i], r[i + 4], c, &mut carry);
+        }
+        // Fold the carry limb, which leaves at most a single bit of carry.
+        let mut carry2 = 0;
+        (self.0).0[0] = fa::mac_with_carry((self.0).0[0], carry, c, &mut carry2);
+        for i in 1..4 {
+            carry2 = fa::adc(&mut (self.0).0[i], 0, carry2);
+        }
+        // If the carry bit is set, the low half is small, so adding `c` once more cannot overflow.
+        if carry2 != 0 {
+            let mut carry3 = fa::adc(&mut (self.0).0[0], c, 0);
+            for i in 1..4 {
+                carry3 = fa::adc(&mut (self.0).0[i], 0, carry3);
+            }
+        }
         self.reduce();
     }
 }
@@ -167,6 +282,11 @@ impl<P: Fp256Parameters> Field for Fp256<P> {
         a: impl Iterator<Item = &'a Self> + Clone,
         b: impl Iterator<Item = &'a Self> + Clone,
     ) -> Self {
+        // The interleaved reduction below relies on the spare bit of the modulus, and on Montgomery form.
+        if Self::FULL_MODULUS || !Self::MONTGOMERY {
+            return a.zip(b).fold(Self::zero(), |sum, (a, b)| sum + *a * b);
+        }
+
         // For a single `a x b` multiplication, operand scanning (schoolbook) takes each
         // limb of `a` in turn, and multiplies it by all of the limbs of `b` to compute
         // the result as a double-width intermediate representation, which is then fully
@@ -220,6 +340,14 @@ impl<P: Fp256Parameters> Field for Fp256<P> {
         result
     }
 
+    fn sum_of_products_slice(a: &[Self], b: &[Self]) -> Self {
+        assert_eq!(a.len(), b.len(), "The slices must have the same length");
+        if !Self::TWO_SPARE_BITS || !Self::MONTGOMERY {
+            return a.iter().zip(b).map(|(a, b)| *a * b).sum();
+        }
+        a.chunks(4).zip(b.chunks(4)).map(|(a, b)| Self::sum_of_four_products(a, b)).sum()
+    }
+
     #[inline]
     fn double(&self) -> Self {
         let mut temp = *self;
@@ -229,10 +357,15 @@ impl<P: Fp256Parameters> Field for Fp256<P> {
 
     #[inline]
     fn double_in_place(&mut self) {
-        // This cannot exceed the backing capacity.
+        // This can only exceed the backing capacity for a modulus without a spare bit.
+        let carry = (self.0).0[3] >> 63 == 1;
         self.0.mul2();
-        // However, it may need to be reduced.
-        self.reduce();
+        // In either case, it may need to be reduced.
+        if carry {
+            self.0.sub_noborrow(&P::MODULUS);
+        } else {
+            self.reduce();
+        }
     }
 
     #[inline]
@@ -249,6 +382,12 @@ impl<P: Fp256Parameters> Field for Fp256<P> {
 
     #[inline]
     fn square_in_place(&mut self) -> &mut Self {
+        #[cfg(all(feature = "asm", target_arch = "x86_64"))]
+        if let Some(result) = Self::mul_asm(self, self) {
+            *self = result;
+            return self;
+        }
+
         // i = 0
         let mut carry = 0;
         let r1 = fa::mac_with_carry(0, (self.0).0[0], (self.0).0[1], &mut carry);
@@ -309,8 +448,10 @@ impl<P: Fp256Parameters> Field for Fp256<P> {
                     if b.0.is_even() {
                         b.0.div2();
                     } else {
-                        b.0.add_nocarry(&P::MODULUS);
+                        let carry = b.0.add_nocarry(&P::MODULUS);
                         b.0.div2();
+                        // Restore the carry for a modulus without a spare bit.
+                        (b.0).0[3] |= (carry as u64) << 63;
                     }
                 }
 
@@ -320,8 +461,10 @@ impl<P: Fp256Parameters> Field for Fp256<P> {
                     if c.0.is_even() {
                         c.0.div2();
                     } else {
-                        c.0.add_nocarry(&P::MODULUS);
+                        let carry = c.0.add_nocarry(&P::MODULUS);
                         c.0.div2();
+                        // Restore the carry for a modulus without a spare bit.
+                        (c.0).0[3] |= (carry as u64) << 63;
                     }
                 }
 
@@ -370,8 +513,22 @@ impl<P: Fp256Parameters> PrimeField for Fp256<P> {
         }
     }
 
+    #[inline]
+    fn from_montgomery_bigint(r: BigInteger) -> Option<Self> {
+        let r = Fp256(r, PhantomData);
+        r.is_valid().then_some(r)
+    }
+
+    #[inline]
+    fn to_montgomery_bigint(&self) -> BigInteger {
+        self.0
+    }
+
     #[inline]
     fn to_bigint(&self) -> BigInteger {
+        if !Self::MONTGOMERY {
+            return self.0;
+        }
         let mut tmp = self.0;
         let mut r = tmp.0;
         // Montgomery Reduction
@@ -420,7 +577,7 @@ impl<P: Fp256Parameters> PrimeField for Fp256<P> {
         b2: Self,
         r128: Self,
         half_r: &[u64; 8],
-    ) -> (Self, Self, bool, bool) {
+    ) -> Option<GlvDecomposition<Self>> {
         let mul_short = |a: &[u64; 4], b: &[u64; 4]| -> [u64; 8] {
             // Schoolbook multiplication
             let mut carry = 0;
@@ -450,7 +607,7 @@ impl<P: Fp256Parameters> PrimeField for Fp256<P> {
             [r0, r1, r2, r3, r4, r5, r6, 0]
         };
 
-        let round = |a: &mut [u64; 8]| -> Self {
+        let round = |a: &mut [u64; 8]| -> Option<Self> {
             let mut carry = 0;
             // NOTE: can the first 4 be omitted?
             carry = fa::adc(&mut a[0], half_r[0], carry);
@@ -461,23 +618,24 @@ impl<P: Fp256Parameters> PrimeField for Fp256<P> {
             carry = fa::adc(&mut a[5], half_r[5], carry);
             carry = fa::adc(&mut a[6], half_r[6], carry);
             _ = fa::adc(&mut a[7], half_r[7], carry);
-            Self::from_bigint(BigInteger([a[4], a[5], a[6], a[7]])).unwrap()
+            Self::from_bigint(BigInteger([a[4], a[5], a[6], a[7]]))
         };
 
-        let alpha = |x: &Self, q: &[u64; 4]| -> Self {
+        let alpha = |x: &Self, q: &[u64; 4]| -> Option<Self> {
             let mut a = mul_short(&x.to_bigint().0, q);
             round(&mut a)
         };
 
-        let alpha1 = alpha(self, q1);
-        let alpha2 = alpha(self, q2);
+        let alpha1 = alpha(self, q1)?;
+        let alpha2 = alpha(self, q2)?;
         let z1 = alpha1 * b1;
         let z2 = alpha2 * b2;
 
+        // Note: `k = k1 - k2 * b1`, so `k2` starts out negated.
         let mut k1 = *self - z1 - alpha2;
         let mut k2 = z2 - alpha1;
         let mut k1_neg = false;
-        let mut k2_neg = false;
+        let mut k2_neg = true;
 
         if k1 > r128 {
             k1 = -k1;
@@ -486,10 +644,10 @@ impl<P: Fp256Parameters> PrimeField for Fp256<P> {
 
         if k2 > r128 {
             k2 = -k2;
-            k2_neg = true;
+            k2_neg = false;
         }
 
-        (k1, k2, k1_neg, k2_neg)
+        Some(GlvDecomposition { k1, k2, k1_neg, k2_neg })
     }
 }
 
@@ -515,19 +673,7 @@ impl<P: Fp256Parameters> FftField for Fp256<P> {
 impl<P: Fp256Parameters> SquareRootField for Fp256<P> {
     #[inline]
     fn legendre(&self) -> LegendreSymbol {
-        use crate::LegendreSymbol::*;
-
-        // s = self^((MODULUS - 1) // 2)
-        let mut s = self.pow(P::MODULUS_MINUS_ONE_DIV_TWO);
-        s.reduce();
-
-        if s.is_zero() {
-            Zero
-        } else if s.is_one() {
-            QuadraticResidue
-        } else {
-            QuadraticNonResidue
-        }
+        crate::legendre_symbol(self.to_bigint(), P::MODULUS)
     }
 
     #[inline]
@@ -543,6 +689,14 @@ impl<P: Fp256Parameters> SquareRootField for Fp256<P> {
     }
 }
 
+/// The Montgomery representation is unique, so the limbs are compared directly.
+impl<P: Fp256Parameters> ConstantTimeEq for Fp256<P> {
+    #[inline]
+    fn ct_eq(&self, other: &Self) -> Choice {
+        (self.0).0[..].ct_eq(&(other.0).0[..])
+    }
+}
+
 /// `Fp` elements are ordered lexicographically.
 impl<P: Fp256Parameters> Ord for Fp256<P> {
     #[inline(always)]
@@ -729,10 +883,14 @@ impl<'a, P: Fp256Parameters> Div<&'a Fp256<P>> for Fp256<P> {
 impl<'a, P: Fp256Parameters> AddAssign<&'a Self> for Fp256<P> {
     #[inline]
     fn add_assign(&mut self, other: &Self) {
-        // This cannot exceed the backing capacity.
-        self.0.add_nocarry(&other.0);
-        // However, it may need to be reduced.
-        self.reduce();
+        // This can only exceed the backing capacity for a modulus without a spare bit.
+        let carry = self.0.add_nocarry(&other.0);
+        // In either case, it may need to be reduced.
+        if carry {
+            self.0.sub_noborrow(&P::MODULUS);
+        } else {
+            self.reduce();
+        }
     }
 }
 
@@ -751,6 +909,27 @@ impl<'a, P: Fp256Parameters> SubAssign<&'a Self> for Fp256<P> {
 impl<'a, P: Fp256Parameters> MulAssign<&'a Self> for Fp256<P> {
     #[inline]
     fn mul_assign(&mut self, other: &Self) {
+        #[cfg(all(feature = "asm", target_arch = "x86_64"))]
+        if let Some(result) = Self::mul_asm(self, other) {
+            *self = result;
+            return;
+        }
+
+        // The interleaved multiplication below relies on the spare bit of the modulus and on Montgomery form,
+        // so otherwise, compute the full product and reduce it separately.
+        if Self::FULL_MODULUS || !Self::MONTGOMERY {
+            let mut r = [0u64; 8];
+            for i in 0..4 {
+                let mut carry = 0u64;
+                for j in 0..4 {
+                    r[i + j] = fa::mac_with_carry(r[i + j], (self.0).0[i], (other.0).0[j], &mut carry);
+                }
+                r[i + 4] = carry;
+            }
+            self.mont_reduce(r[0], r[1], r[2], r[3], r[4], r[5], r[6], r[7]);
+            return;
+        }
+
         let mut r = [0u64; 4];
         let mut carry1 = 0u64;
         let mut carry2 = 0u64;
@@ -19,6 +19,7 @@ use crate::{
     Field,
     FieldError,
     FieldParameters,
+    GlvDecomposition,
     LegendreSymbol,
     One,
     PoseidonDefaultField,
@@ -43,14 +44,34 @@ use std::{
     ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Sub, SubAssign},
     str::FromStr,
 };
+use subtle::{Choice, ConstantTimeEq};
 use zeroize::Zeroize;
 
 pub trait Fp384Parameters: FieldParameters<BigInteger = BigInteger> {}
 
 #[derive(Copy, Clone, Default, PartialEq, Eq, Hash, Zeroize)]
+#[repr(transparent)]
 pub struct Fp384<P: Fp384Parameters>(pub BigInteger, #[doc(hidden)] pub PhantomData<P>);
 
 impl<P: Fp384Parameters> Fp384<P> {
+    /// Whether the modulus leaves two spare bits in the top limb, which bounds the unreduced
+    /// sum of four products in `sum_of_four_products` below `2 * MODULUS`.
+    const TWO_SPARE_BITS: bool = P::MODULUS.0[5] < (1 << 62);
+
+    /// Returns the field element with the given limbs in Montgomery form, least significant limb first.
+    /// The limbs must be less than `MODULUS`, which is not checked.
+    #[inline]
+    pub const fn from_montgomery_limbs(limbs: [u64; 6]) -> Self {
+        Self(BigInteger(limbs), PhantomData)
+    }
+
+    /// Returns the field element for the given canonical integer limbs, least significant limb first.
+    /// Panics if the integer is not less than `MODULUS`.
+    pub const fn from_canonical_limbs(limbs: [u64; 6]) -> Self {
+        assert!(crate::const_helpers::lt(&limbs, &P::MODULUS.0), "The integer is not less than the modulus");
+        Self::from_montgomery_limbs(crate::const_helpers::mont_mul(&limbs, &P::R2.0, &P::MODULUS.0, P::INV))
+    }
+
     #[inline]
     pub fn is_valid(&self) -> bool {
         self.0 < P::MODULUS
@@ -63,6 +84,57 @@ impl<P: Fp384Parameters> Fp384<P> {
         }
     }
 
+    /// Returns the sum of up to four pairwise products of `a` and `b`, with a single final reduction.
+    #[inline(always)]
+    fn sum_of_four_products(a: &[Self], b: &[Self]) -> Self {
+        debug_assert!(a.len() <= 4 && a.len() == b.len());
+
+        // This follows the interleaved operand scanning in `sum_of_products`, on a fixed number of terms.
+        let mut u = [0u64; 6];
+        for j in 0..6 {
+            let mut t = u;
+            let mut t6 = 0;
+            for (a, b) in a.iter().zip(b) {
+                let mut carry = 0;
+                for (l, t) in t.iter_mut().enumerate() {
+                    *t = fa::mac_with_carry(*t, a.0.0[j], b.0.0[l], &mut carry);
+                }
+                let _ = fa::adc(&mut t6, 0, carry);
+            }
+
+            let k = t[0].wrapping_mul(P::INV);
+            let mut carry = 0;
+            let _ = fa::mac_with_carry(t[0], k, P::MODULUS.0[0], &mut carry);
+            for l in 1..6 {
+                u[l - 1] = fa::mac_with_carry(t[l], k, P::MODULUS.0[l], &mut carry);
+            }
+            let _ = fa::adc(&mut t6, 0, carry);
+            u[5] = t6;
+        }
+
+        let mut result = Self(BigInteger(u), PhantomData);
+        result.reduce();
+        result
+    }
+
+    /// Returns `a * b` using the x86-64 assembly backend, or `None` if it is unavailable on this CPU.
+    #[cfg(all(feature = "asm", target_arch = "x86_64"))]
+    #[inline(always)]
+    fn mul_asm(a: &Self, b: &Self) -> Option<Self> {
+        let modulus = [
+            P::MODULUS.0[0],
+            P::MODULUS.0[1],
+            P::MODULUS.0[2],
+            P::MODULUS.0[3],
+            P::MODULUS.0[4],
+            P::MODULUS.0[5],
+            P::INV,
+        ];
+        let mut result = Self(BigInteger(crate::asm::mul_6(&(a.0).0, &(b.0).0, &modulus)?), PhantomData);
+        result.reduce();
+        Some(result)
+    }
+
     #[inline(always)]
     #[allow(clippy::too_many_arguments)]
     fn mont_reduce(
@@ -259,6 +331,14 @@ impl<P: Fp384Parameters> Field for Fp384<P> {
         result
     }
 
+    fn sum_of_products_slice(a: &[Self], b: &[Self]) -> Self {
+        assert_eq!(a.len(), b.len(), "The slices must have the same length");
+        if !Self::TWO_SPARE_BITS {
+            return a.iter().zip(b).map(|(a, b)| *a * b).sum();
+        }
+        a.chunks(4).zip(b.chunks(4)).map(|(a, b)| Self::sum_of_four_products(a, b)).sum()
+    }
+
     #[inline]
     fn double(&self) -> Self {
         let mut temp = *self;
@@ -288,6 +368,12 @@ impl<P: Fp384Parameters> Field for Fp384<P> {
 
     #[inline]
     fn square_in_place(&mut self) -> &mut Self {
+        #[cfg(all(feature = "asm", target_arch = "x86_64"))]
+        if let Some(result) = Self::mul_asm(self, self) {
+            *self = result;
+            return self;
+        }
+
         let mut carry = 0;
         let r1 = fa::mac_with_carry(0, (self.0).0[0], (self.0).0[1], &mut carry);
         let r2 = fa::mac_with_carry(0, (self.0).0[0], (self.0).0[2], &mut carry);
@@ -428,6 +514,17 @@ impl<P: Fp384Parameters> PrimeField for Fp384<P> {
         }
     }
 
+    #[inline]
+    fn from_montgomery_bigint(r: BigInteger) -> Option<Self> {
+        let r = Fp384(r, PhantomData);
+        r.is_valid().then_some(r)
+    }
+
+    #[inline]
+    fn to_montgomery_bigint(&self) -> BigInteger {
+        self.0
+    }
+
     #[inline]
     fn to_bigint(&self) -> BigInteger {
         let mut tmp = self.0;
@@ -506,8 +603,8 @@ impl<P: Fp384Parameters> PrimeField for Fp384<P> {
         _b2: Self,
         _r128: Self,
         _half_r: &[u64; 8],
-    ) -> (Self, Self, bool, bool) {
-        unimplemented!()
+    ) -> Option<GlvDecomposition<Self>> {
+        None
     }
 }
 
@@ -533,18 +630,7 @@ impl<P: Fp384Parameters> FftField for Fp384<P> {
 impl<P: Fp384Parameters> SquareRootField for Fp384<P> {
     #[inline]
     fn legendre(&self) -> LegendreSymbol {
-        use crate::LegendreSymbol::*;
-
-        // s = self^((MODULUS - 1) // 2)
-        let s = self.pow(P::MODULUS_MINUS_ONE_DIV_TWO);
-
-        if s.is_zero() {
-            Zero
-        } else if s.is_one() {
-            QuadraticResidue
-        } else {
-            QuadraticNonResidue
-        }
+        crate::legendre_symbol(self.to_bigint(), P::MODULUS)
     }
 
     #[inline]
@@ -560,6 +646,14 @@ impl<P: Fp384Parameters> SquareRootField for Fp384<P> {
     }
 }
 
+/// The Montgomery representation is unique, so the limbs are compared directly.
+impl<P: Fp384Parameters> ConstantTimeEq for Fp384<P> {
+    #[inline]
+    fn ct_eq(&self, other: &Self) -> Choice {
+        (self.0).0[..].ct_eq(&(other.0).0[..])
+    }
+}
+
 /// `Fp` elements are ordered lexicographically.
 impl<P: Fp384Parameters> Ord for Fp384<P> {
     #[inline(always)]
@@ -768,6 +862,12 @@ impl<'a, P: Fp384Parameters> SubAssign<&'a Self> for Fp384<P> {
 impl<'a, P: Fp384Parameters> MulAssign<&'a Self> for Fp384<P> {
     #[inline]
     fn mul_assign(&mut self, other: &Self) {
+        #[cfg(all(feature = "asm", target_arch = "x86_64"))]
+        if let Some(result) = Self::mul_asm(self, other) {
+            *self = result;
+            return;
+        }
+
         let mut r = [0u64; 6];
         let mut carry1 = 0u64;
         let mut carry2 = 0u64;
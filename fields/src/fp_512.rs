@@ -0,0 +1,618 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::{
+    impl_add_sub_from_field_ref,
+    impl_mul_div_from_field_ref,
+    FftField,
+    Field,
+    FieldError,
+    FieldParameters,
+    GlvDecomposition,
+    LegendreSymbol,
+    One,
+    PoseidonDefaultField,
+    PoseidonDefaultParameters,
+    PrimeField,
+    SquareRootField,
+    Zero,
+};
+use snarkvm_utilities::{
+    biginteger::{arithmetic as fa, BigInteger as _BigInteger, BigInteger512 as BigInteger},
+    serialize::CanonicalDeserialize,
+    FromBytes,
+    ToBits,
+    ToBytes,
+};
+
+use std::{
+    cmp::{Ord, Ordering, PartialOrd},
+    fmt::{Debug, Display, Formatter, Result as FmtResult},
+    io::{Read, Result as IoResult, Write},
+    marker::PhantomData,
+    ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Sub, SubAssign},
+    str::FromStr,
+};
+use subtle::{Choice, ConstantTimeEq};
+use zeroize::Zeroize;
+
+/// The parameters of a 512-bit prime field.
+///
+/// The arithmetic skips the carry out of the top limb, so the most significant limb
+/// of `MODULUS` must be less than `2^63 - 1`, as with `Fp384Parameters`.
+pub trait Fp512Parameters: FieldParameters<BigInteger = BigInteger> {}
+
+#[derive(Copy, Clone, Default, PartialEq, Eq, Hash, Zeroize)]
+#[repr(transparent)]
+pub struct Fp512<P: Fp512Parameters>(pub BigInteger, #[doc(hidden)] pub PhantomData<P>);
+
+impl<P: Fp512Parameters> Fp512<P> {
+    /// Returns the field element with the given limbs in Montgomery form, least significant limb first.
+    /// The limbs must be less than `MODULUS`, which is not checked.
+    #[inline]
+    pub const fn from_montgomery_limbs(limbs: [u64; 8]) -> Self {
+        Self(BigInteger(limbs), PhantomData)
+    }
+
+    /// Returns the field element for the given canonical integer limbs, least significant limb first.
+    /// Panics if the integer is not less than `MODULUS`.
+    pub const fn from_canonical_limbs(limbs: [u64; 8]) -> Self {
+        assert!(crate::const_helpers::lt(&limbs, &P::MODULUS.0), "The integer is not less than the modulus");
+        Self::from_montgomery_limbs(crate::const_helpers::mont_mul(&limbs, &P::R2.0, &P::MODULUS.0, P::INV))
+    }
+
+    #[inline]
+    pub fn is_valid(&self) -> bool {
+        self.0 < P::MODULUS
+    }
+
+    #[inline]
+    fn reduce(&mut self) {
+        if !self.is_valid() {
+            self.0.sub_noborrow(&P::MODULUS);
+        }
+    }
+
+    /// Sets `self` to the Montgomery reduction of the double-width integer `r`.
+    #[inline(always)]
+    fn mont_reduce(&mut self, r: &[u64; 16]) {
+        // The Montgomery reduction here is based on Algorithm 14.32 in
+        // Handbook of Applied Cryptography
+        // <http://cacr.uwaterloo.ca/hac/about/chap14.pdf>.
+
+        let mut r = *r;
+        let mut carry2 = 0;
+        for i in 0..8 {
+            let k = r[i].wrapping_mul(P::INV);
+            let mut carry = 0;
+            fa::mac_with_carry(r[i], k, P::MODULUS.0[0], &mut carry);
+            for j in 1..8 {
+                r[i + j] = fa::mac_with_carry(r[i + j], k, P::MODULUS.0[j], &mut carry);
+            }
+            carry2 = fa::adc(&mut r[i + 8], carry2, carry);
+        }
+        (self.0).0.copy_from_slice(&r[8..]);
+        self.reduce();
+    }
+}
+
+impl<P: Fp512Parameters> Zero for Fp512<P> {
+    #[inline]
+    fn zero() -> Self {
+        Self(BigInteger::from(0), PhantomData)
+    }
+
+    #[inline]
+    fn is_zero(&self) -> bool {
+        self.0.is_zero()
+    }
+}
+
+impl<P: Fp512Parameters> One for Fp512<P> {
+    #[inline]
+    fn one() -> Self {
+        Self(P::R, PhantomData)
+    }
+
+    #[inline]
+    fn is_one(&self) -> bool {
+        self.0 == P::R
+    }
+}
+
+impl<P: Fp512Parameters> Field for Fp512<P> {
+    type BasePrimeField = Self;
+
+    // 512/64 = 8 limbs.
+    impl_field_from_random_bytes_with_flags!(8);
+
+    fn from_base_prime_field(other: Self::BasePrimeField) -> Self {
+        other
+    }
+
+    fn half() -> Self {
+        // Compute 1/2 `(p+1)/2` as `1/2`.
+        // This is cheaper than `Self::one().double().inverse()`
+        let mut two_inv = P::MODULUS;
+        two_inv.add_nocarry(&1u64.into());
+        two_inv.div2();
+        Self::from_bigint(two_inv).unwrap() // Guaranteed to be valid.
+    }
+
+    fn sum_of_products<'a>(
+        a: impl Iterator<Item = &'a Self> + Clone,
+        b: impl Iterator<Item = &'a Self> + Clone,
+    ) -> Self {
+        // The interleaved operand scanning in `Fp256` and `Fp384` relies on the spare bits
+        // of a smaller modulus to absorb the accumulated products, which a 512-bit modulus
+        // may not leave. Instead, each product is reduced before it is summed.
+        a.zip(b).map(|(a, b)| *a * b).sum()
+    }
+
+    #[inline]
+    fn double(&self) -> Self {
+        let mut temp = *self;
+        temp.double_in_place();
+        temp
+    }
+
+    #[inline]
+    fn double_in_place(&mut self) {
+        // This cannot exceed the backing capacity.
+        self.0.mul2();
+        // However, it may need to be reduced.
+        self.reduce();
+    }
+
+    #[inline]
+    fn characteristic<'a>() -> &'a [u64] {
+        P::MODULUS.as_ref()
+    }
+
+    #[inline]
+    fn square(&self) -> Self {
+        let mut temp = *self;
+        temp.square_in_place();
+        temp
+    }
+
+    #[inline]
+    fn square_in_place(&mut self) -> &mut Self {
+        let a = (self.0).0;
+        let mut r = [0u64; 16];
+
+        // Compute the products `a[i] * a[j]` for `i < j`.
+        for i in 0..7 {
+            let mut carry = 0;
+            for j in (i + 1)..8 {
+                r[i + j] = fa::mac_with_carry(r[i + j], a[i], a[j], &mut carry);
+            }
+            r[i + 8] = carry;
+        }
+
+        // Double them, as each appears twice in the square.
+        r[15] = r[14] >> 63;
+        for i in (2..15).rev() {
+            r[i] = (r[i] << 1) | (r[i - 1] >> 63);
+        }
+        r[1] <<= 1;
+
+        // Add the squares `a[i] * a[i]`.
+        let mut carry = 0;
+        for i in 0..8 {
+            r[2 * i] = fa::mac_with_carry(r[2 * i], a[i], a[i], &mut carry);
+            carry = fa::adc(&mut r[2 * i + 1], 0, carry);
+        }
+        self.mont_reduce(&r);
+        self
+    }
+
+    #[inline]
+    fn inverse(&self) -> Option<Self> {
+        if self.is_zero() {
+            None
+        } else {
+            // Guajardo Kumar Paar Pelzl
+            // Efficient Software-Implementation of Finite Fields with Applications to
+            // Cryptography
+            // Algorithm 16 (BEA for Inversion in Fp)
+
+            let one = BigInteger::from(1);
+
+            let mut u = self.0;
+            let mut v = P::MODULUS;
+            let mut b = Self(P::R2, PhantomData); // Avoids unnecessary reduction step.
+            let mut c = Self::zero();
+
+            while u != one && v != one {
+                while u.is_even() {
+                    u.div2();
+
+                    if b.0.is_even() {
+                        b.0.div2();
+                    } else {
+                        b.0.add_nocarry(&P::MODULUS);
+                        b.0.div2();
+                    }
+                }
+
+                while v.is_even() {
+                    v.div2();
+
+                    if c.0.is_even() {
+                        c.0.div2();
+                    } else {
+                        c.0.add_nocarry(&P::MODULUS);
+                        c.0.div2();
+                    }
+                }
+
+                if v < u {
+                    u.sub_noborrow(&v);
+                    b.sub_assign(&c);
+                } else {
+                    v.sub_noborrow(&u);
+                    c.sub_assign(&b);
+                }
+            }
+
+            if u == one { Some(b) } else { Some(c) }
+        }
+    }
+
+    fn inverse_in_place(&mut self) -> Option<&mut Self> {
+        if let Some(inverse) = self.inverse() {
+            *self = inverse;
+            Some(self)
+        } else {
+            None
+        }
+    }
+
+    #[inline]
+    fn frobenius_map(&mut self, _: usize) {
+        // No-op: No effect in a prime field.
+    }
+}
+
+impl<P: Fp512Parameters> PrimeField for Fp512<P> {
+    type BigInteger = BigInteger;
+    type Parameters = P;
+
+    #[inline]
+    fn from_bigint(r: BigInteger) -> Option<Self> {
+        let mut r = Fp512(r, PhantomData);
+        if r.is_zero() {
+            Some(r)
+        } else if r.is_valid() {
+            r *= &Fp512(P::R2, PhantomData);
+            Some(r)
+        } else {
+            None
+        }
+    }
+
+    #[inline]
+    fn from_montgomery_bigint(r: BigInteger) -> Option<Self> {
+        let r = Fp512(r, PhantomData);
+        r.is_valid().then_some(r)
+    }
+
+    #[inline]
+    fn to_montgomery_bigint(&self) -> BigInteger {
+        self.0
+    }
+
+    #[inline]
+    fn to_bigint(&self) -> BigInteger {
+        let mut r = [0u64; 16];
+        r[..8].copy_from_slice(&(self.0).0);
+        let mut tmp = *self;
+        tmp.mont_reduce(&r);
+        tmp.0
+    }
+
+    #[inline]
+    fn decompose(
+        &self,
+        _q1: &[u64; 4],
+        _q2: &[u64; 4],
+        _b1: Self,
+        _b2: Self,
+        _r128: Self,
+        _half_r: &[u64; 8],
+    ) -> Option<GlvDecomposition<Self>> {
+        None
+    }
+}
+
+impl<P: Fp512Parameters> FftField for Fp512<P> {
+    type FftParameters = P;
+
+    #[inline]
+    fn two_adic_root_of_unity() -> Self {
+        Self(P::TWO_ADIC_ROOT_OF_UNITY, PhantomData)
+    }
+
+    #[inline]
+    fn large_subgroup_root_of_unity() -> Option<Self> {
+        Some(Self(P::LARGE_SUBGROUP_ROOT_OF_UNITY?, PhantomData))
+    }
+
+    #[inline]
+    fn multiplicative_generator() -> Self {
+        Self(P::GENERATOR, PhantomData)
+    }
+}
+
+impl<P: Fp512Parameters> SquareRootField for Fp512<P> {
+    #[inline]
+    fn legendre(&self) -> LegendreSymbol {
+        crate::legendre_symbol(self.to_bigint(), P::MODULUS)
+    }
+
+    #[inline]
+    fn sqrt(&self) -> Option<Self> {
+        sqrt_impl!(Self, P, self)
+    }
+
+    fn sqrt_in_place(&mut self) -> Option<&mut Self> {
+        (*self).sqrt().map(|sqrt| {
+            *self = sqrt;
+            self
+        })
+    }
+}
+
+/// The Montgomery representation is unique, so the limbs are compared directly.
+impl<P: Fp512Parameters> ConstantTimeEq for Fp512<P> {
+    #[inline]
+    fn ct_eq(&self, other: &Self) -> Choice {
+        (self.0).0[..].ct_eq(&(other.0).0[..])
+    }
+}
+
+/// `Fp` elements are ordered lexicographically.
+impl<P: Fp512Parameters> Ord for Fp512<P> {
+    #[inline(always)]
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.to_bigint().cmp(&other.to_bigint())
+    }
+}
+
+impl<P: Fp512Parameters> PartialOrd for Fp512<P> {
+    #[inline(always)]
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<P: Fp512Parameters + PoseidonDefaultParameters> PoseidonDefaultField for Fp512<P> {}
+
+impl_primefield_from_int!(Fp512, u128, Fp512Parameters);
+impl_primefield_from_int!(Fp512, u64, Fp512Parameters);
+impl_primefield_from_int!(Fp512, u32, Fp512Parameters);
+impl_primefield_from_int!(Fp512, u16, Fp512Parameters);
+impl_primefield_from_int!(Fp512, u8, Fp512Parameters);
+
+impl_primefield_standard_sample!(Fp512, Fp512Parameters);
+
+impl_add_sub_from_field_ref!(Fp512, Fp512Parameters);
+impl_mul_div_from_field_ref!(Fp512, Fp512Parameters);
+
+impl<P: Fp512Parameters> ToBits for Fp512<P> {
+    fn write_bits_le(&self, vec: &mut Vec<bool>) {
+        let initial_len = vec.len();
+        self.to_bigint().write_bits_le(vec);
+        vec.truncate(initial_len + P::MODULUS_BITS as usize);
+    }
+
+    fn write_bits_be(&self, vec: &mut Vec<bool>) {
+        let initial_len = vec.len();
+        self.write_bits_le(vec);
+        vec[initial_len..].reverse();
+    }
+
+    fn num_bits() -> Option<usize> {
+        Some(512)
+    }
+}
+
+impl<P: Fp512Parameters> ToBytes for Fp512<P> {
+    #[inline]
+    fn write_le<W: Write>(&self, writer: W) -> IoResult<()> {
+        self.to_bigint().write_le(writer)
+    }
+}
+
+impl<P: Fp512Parameters> FromBytes for Fp512<P> {
+    #[inline]
+    fn read_le<R: Read>(reader: R) -> IoResult<Self> {
+        BigInteger::read_le(reader).and_then(|b| match Self::from_bigint(b) {
+            Some(f) => Ok(f),
+            None => Err(FieldError::InvalidFieldElement.into()),
+        })
+    }
+}
+
+impl<P: Fp512Parameters> FromStr for Fp512<P> {
+    type Err = FieldError;
+
+    /// Interpret a string of numbers as a (congruent) prime field element.
+    /// Does not accept unnecessary leading zeroes or a blank string.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        if s.is_empty() {
+            return Err(FieldError::ParsingEmptyString);
+        }
+
+        if s == "0" {
+            return Ok(Self::zero());
+        }
+
+        let mut res = Self::zero();
+
+        let ten =
+            Self::from_bigint(<Self as PrimeField>::BigInteger::from(10)).ok_or(FieldError::InvalidFieldElement)?;
+
+        let mut first_digit = true;
+
+        for c in s.chars() {
+            match c.to_digit(10) {
+                Some(c) => {
+                    if first_digit {
+                        if c == 0 {
+                            return Err(FieldError::InvalidString);
+                        }
+
+                        first_digit = false;
+                    }
+
+                    res.mul_assign(&ten);
+                    res.add_assign(
+                        &Self::from_bigint(<Self as PrimeField>::BigInteger::from(u64::from(c)))
+                            .ok_or(FieldError::InvalidFieldElement)?,
+                    );
+                }
+                None => return Err(FieldError::ParsingNonDigitCharacter),
+            }
+        }
+
+        if !res.is_valid() { Err(FieldError::InvalidFieldElement) } else { Ok(res) }
+    }
+}
+
+impl<P: Fp512Parameters> Debug for Fp512<P> {
+    #[inline]
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        write!(f, "{}", self.to_bigint())
+    }
+}
+
+impl<P: Fp512Parameters> Display for Fp512<P> {
+    #[inline]
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        write!(f, "{}", self.to_bigint())
+    }
+}
+
+impl<P: Fp512Parameters> Neg for Fp512<P> {
+    type Output = Self;
+
+    #[inline]
+    #[must_use]
+    fn neg(self) -> Self {
+        if !self.is_zero() {
+            let mut tmp = P::MODULUS;
+            tmp.sub_noborrow(&self.0);
+            Self(tmp, PhantomData)
+        } else {
+            self
+        }
+    }
+}
+
+impl<'a, P: Fp512Parameters> Add<&'a Fp512<P>> for Fp512<P> {
+    type Output = Self;
+
+    #[inline]
+    fn add(self, other: &Self) -> Self {
+        let mut result = self;
+        result.add_assign(other);
+        result
+    }
+}
+
+impl<'a, P: Fp512Parameters> Sub<&'a Fp512<P>> for Fp512<P> {
+    type Output = Self;
+
+    #[inline]
+    fn sub(self, other: &Self) -> Self {
+        let mut result = self;
+        result.sub_assign(other);
+        result
+    }
+}
+
+impl<'a, P: Fp512Parameters> Mul<&'a Fp512<P>> for Fp512<P> {
+    type Output = Self;
+
+    #[inline]
+    fn mul(self, other: &Self) -> Self {
+        let mut result = self;
+        result.mul_assign(other);
+        result
+    }
+}
+
+impl<'a, P: Fp512Parameters> Div<&'a Fp512<P>> for Fp512<P> {
+    type Output = Self;
+
+    #[inline]
+    fn div(self, other: &Self) -> Self {
+        let mut result = self;
+        result.mul_assign(&other.inverse().unwrap());
+        result
+    }
+}
+
+impl<'a, P: Fp512Parameters> AddAssign<&'a Self> for Fp512<P> {
+    #[inline]
+    fn add_assign(&mut self, other: &Self) {
+        // This cannot exceed the backing capacity.
+        self.0.add_nocarry(&other.0);
+        // However, it may need to be reduced.
+        self.reduce();
+    }
+}
+
+impl<'a, P: Fp512Parameters> SubAssign<&'a Self> for Fp512<P> {
+    #[inline]
+    fn sub_assign(&mut self, other: &Self) {
+        // If `other` is larger than `self`, add the modulus to self first.
+        if other.0 > self.0 {
+            self.0.add_nocarry(&P::MODULUS);
+        }
+
+        self.0.sub_noborrow(&other.0);
+    }
+}
+
+impl<'a, P: Fp512Parameters> MulAssign<&'a Self> for Fp512<P> {
+    #[inline]
+    fn mul_assign(&mut self, other: &Self) {
+        let mut r = [0u64; 8];
+        for i in 0..8 {
+            let mut carry1 = 0u64;
+            let mut carry2 = 0u64;
+            r[0] = fa::mac(r[0], (self.0).0[0], (other.0).0[i], &mut carry1);
+            let k = r[0].wrapping_mul(P::INV);
+            fa::mac_discard(r[0], k, P::MODULUS.0[0], &mut carry2);
+            for j in 1..8 {
+                r[j] = fa::mac_with_carry(r[j], (self.0).0[j], (other.0).0[i], &mut carry1);
+                r[j - 1] = fa::mac_with_carry(r[j], k, P::MODULUS.0[j], &mut carry2);
+            }
+            r[7] = carry1 + carry2;
+        }
+
+        (self.0).0 = r;
+        self.reduce();
+    }
+}
+
+impl<'a, P: Fp512Parameters> DivAssign<&'a Self> for Fp512<P> {
+    #[inline]
+    fn div_assign(&mut self, other: &Self) {
+        self.mul_assign(&other.inverse().unwrap());
+    }
+}
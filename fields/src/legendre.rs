@@ -12,6 +12,8 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use snarkvm_utilities::biginteger::BigInteger;
+
 use std::fmt::Debug;
 
 #[derive(Debug, PartialEq, Eq)]
@@ -34,3 +36,40 @@ impl LegendreSymbol {
         *self == LegendreSymbol::QuadraticResidue
     }
 }
+
+/// Returns the Legendre symbol of `a` modulo the odd prime `modulus`, where `a < modulus`.
+///
+/// This evaluates the Jacobi symbol with the binary algorithm, which only uses shifts and
+/// subtractions, and is considerably cheaper than exponentiating by `(modulus - 1) / 2`.
+pub(crate) fn legendre_symbol<B: BigInteger>(mut a: B, mut n: B) -> LegendreSymbol {
+    let mut negate = false;
+    while !a.is_zero() {
+        // Remove the factors of two from `a`, using (2 / n) = -1 iff n = 3, 5 (mod 8).
+        let zeros = trailing_zeros(&a);
+        a.divn(zeros);
+        if zeros % 2 == 1 && matches!(n.as_ref()[0] % 8, 3 | 5) {
+            negate = !negate;
+        }
+        // Apply quadratic reciprocity to keep `a >= n`, using (a / n) = -(n / a) iff a = n = 3 (mod 4).
+        if a < n {
+            core::mem::swap(&mut a, &mut n);
+            if a.as_ref()[0] % 4 == 3 && n.as_ref()[0] % 4 == 3 {
+                negate = !negate;
+            }
+        }
+        a.sub_noborrow(&n);
+    }
+
+    match (n == B::from(1u64), negate) {
+        (false, _) => LegendreSymbol::Zero,
+        (true, false) => LegendreSymbol::QuadraticResidue,
+        (true, true) => LegendreSymbol::QuadraticNonResidue,
+    }
+}
+
+/// Returns the number of trailing zero bits in the nonzero integer `a`.
+fn trailing_zeros<B: BigInteger>(a: &B) -> u32 {
+    let limbs = a.as_ref();
+    let index = limbs.iter().position(|limb| *limb != 0).unwrap_or(limbs.len() - 1);
+    index as u32 * 64 + limbs[index].trailing_zeros()
+}
@@ -13,7 +13,9 @@
 // limitations under the License.
 
 #![allow(clippy::module_inception)]
-#![forbid(unsafe_code)]
+// The assembly backend is the only module that may opt back in to `unsafe`, which `forbid` would not allow.
+#![cfg_attr(not(feature = "asm"), forbid(unsafe_code))]
+#![cfg_attr(feature = "asm", deny(unsafe_code))]
 
 #[macro_use]
 extern crate thiserror;
@@ -21,6 +23,12 @@ extern crate thiserror;
 #[macro_use]
 mod macros;
 
+#[cfg(all(feature = "asm", target_arch = "x86_64"))]
+mod asm;
+
+#[doc(hidden)]
+pub mod const_helpers;
+
 pub mod errors;
 pub use errors::*;
 
@@ -30,6 +38,9 @@ pub use fp_256::*;
 mod fp_384;
 pub use fp_384::*;
 
+mod fp_512;
+pub use fp_512::*;
+
 mod fp2;
 pub use fp2::*;
 
@@ -48,6 +59,8 @@ pub use to_field_vec::*;
 pub mod traits;
 pub use traits::*;
 
+pub use subtle::{Choice, ConstantTimeEq};
+
 use snarkvm_utilities::{
     biginteger::*,
     serialize::{CanonicalDeserialize, CanonicalDeserializeWithFlags, CanonicalSerialize, CanonicalSerializeWithFlags},
@@ -57,9 +70,11 @@ use snarkvm_utilities::{
 
 impl_field_to_biginteger!(Fp256, BigInteger256, Fp256Parameters);
 impl_field_to_biginteger!(Fp384, BigInteger384, Fp384Parameters);
+impl_field_to_biginteger!(Fp512, BigInteger512, Fp512Parameters);
 
 impl_primefield_serializer!(Fp256, Fp256Parameters, 32);
 impl_primefield_serializer!(Fp384, Fp384Parameters, 48);
+impl_primefield_serializer!(Fp512, Fp512Parameters, 64);
 
 // Given a vector of field elements {v_i}, compute the vector {v_i^(-1)}
 pub fn batch_inversion<F: Field>(v: &mut [F]) {
@@ -88,6 +103,12 @@ pub fn batch_inversion_and_mul<F: Field>(v: &mut [F], coeff: &F) {
     });
 }
 
+/// Given a vector of field elements {v_i}, compute the vector {v_i^(-1)}.
+/// This method is explicitly single-threaded.
+pub fn serial_batch_inversion<F: Field>(v: &mut [F]) {
+    serial_batch_inversion_and_mul(v, &F::one());
+}
+
 /// Given a vector of field elements {v_i}, compute the vector {coeff * v_i^(-1)}.
 /// This method is explicitly single-threaded.
 fn serial_batch_inversion_and_mul<F: Field>(v: &mut [F], coeff: &F) {
@@ -25,6 +25,16 @@ macro_rules! field {
     };
 }
 
+/// Returns the prime field element for the given decimal integer literal, evaluated at compile time.
+/// Compilation fails if the literal is not a decimal integer less than the modulus.
+#[macro_export]
+macro_rules! const_field {
+    ($name:ty, $value:literal) => {{
+        const VALUE: $name = <$name>::from_canonical_limbs($crate::const_helpers::parse_decimal($value));
+        VALUE
+    }};
+}
+
 macro_rules! impl_field_to_biginteger {
     ($field: ident, $biginteger: ident, $parameters: ident) => {
         #[allow(clippy::from_over_into)]
@@ -84,6 +94,12 @@ macro_rules! impl_primefield_from_int {
 macro_rules! sqrt_impl {
     ($Self:ident, $P:tt, $self:expr) => {{
         use crate::LegendreSymbol::*;
+        // The algorithm below requires the powers of the two-adic root of unity and a two-adicity of at least 2,
+        // so otherwise, fall back to the generic Tonelli-Shanks algorithm.
+        if $P::TWO_ADICITY == 1 || $P::POWERS_OF_ROOTS_OF_UNITY.len() + 1 != $P::TWO_ADICITY as usize {
+            return $self.tonelli_shanks_sqrt();
+        }
+
         // https://eprint.iacr.org/2020/1407.pdf (page 4, algorithm 1)
         match $self.legendre() {
             Zero => Some(*$self),
@@ -263,7 +279,8 @@ macro_rules! impl_primefield_serializer {
                 // Calculate the number of bytes required to represent a field element
                 // serialized with `flags`. If `F::BIT_SIZE < 8`,
                 // this is at most `$byte_size + 1`
-                let output_byte_size = Self::SERIALIZED_SIZE;
+                let output_byte_size =
+                    snarkvm_utilities::serialize::number_of_bits_and_bytes(P::MODULUS_BITS as usize + F::BIT_SIZE).1;
 
                 let mut masked_bytes = [0; $byte_size + 1];
                 reader.read_exact(&mut masked_bytes[..output_byte_size])?;
@@ -290,6 +307,23 @@ macro_rules! impl_primefield_serializer {
             }
         }
 
+        impl<P: $params> snarkvm_utilities::serialize::ZeroCopyLimbs for $field<P> {
+            #[inline]
+            fn as_limbs(&self) -> &[u64] {
+                self.0.as_ref()
+            }
+
+            #[inline]
+            fn is_valid_limbs(limbs: &[u64]) -> bool {
+                let mut bigint = P::BigInteger::default();
+                if limbs.len() != bigint.as_ref().len() {
+                    return false;
+                }
+                bigint.as_mut().copy_from_slice(limbs);
+                bigint < P::MODULUS
+            }
+        }
+
         impl<P: $params> CanonicalDeserialize for $field<P> {
             #[allow(unused_qualifications)]
             fn deserialize_with_mode<R: snarkvm_utilities::io::Read>(
@@ -302,25 +336,44 @@ macro_rules! impl_primefield_serializer {
             }
         }
 
+        /// Serializes the field element as a `0x`-prefixed big-endian hex string in human-readable formats,
+        /// and as its bytes otherwise.
         impl<P: $params> serde::Serialize for $field<P> {
             fn serialize<S: serde::ser::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
-                let mut bytes = Vec::with_capacity(Self::SERIALIZED_SIZE);
-                self.serialize_uncompressed(&mut bytes).map_err(serde::ser::Error::custom)?;
-
                 if serializer.is_human_readable() {
-                    serializer.collect_str(self)
+                    let mut bytes = PrimeField::to_bigint(self).to_bytes_le().map_err(serde::ser::Error::custom)?;
+                    bytes.reverse();
+                    serializer.collect_str(&format_args!("0x{}", hex::encode(bytes)))
                 } else {
+                    let mut bytes = Vec::with_capacity(Self::SERIALIZED_SIZE);
+                    self.serialize_uncompressed(&mut bytes).map_err(serde::ser::Error::custom)?;
                     snarkvm_utilities::ToBytesSerializer::serialize(&bytes, serializer)
                 }
             }
         }
 
+        /// Deserializes the field element from a `0x`-prefixed big-endian hex string, or a decimal string,
+        /// in human-readable formats, and from its bytes otherwise.
         impl<'de, P: $params> serde::Deserialize<'de> for $field<P> {
             fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
                 match deserializer.is_human_readable() {
                     true => {
                         let s: String = serde::Deserialize::deserialize(deserializer)?;
-                        core::str::FromStr::from_str(&s).map_err(serde::de::Error::custom)
+                        match s.strip_prefix("0x") {
+                            Some(hex) => {
+                                let mut bytes = hex::decode(hex).map_err(serde::de::Error::custom)?;
+                                if bytes.len() != $byte_size {
+                                    return Err(serde::de::Error::custom("invalid length of the field element"));
+                                }
+                                bytes.reverse();
+                                let bigint = FromBytes::read_le(&*bytes).map_err(serde::de::Error::custom)?;
+                                <Self as PrimeField>::from_bigint(bigint).ok_or_else(|| {
+                                    serde::de::Error::custom("the field element is not less than the modulus")
+                                })
+                            }
+                            // Decimal strings were written by earlier versions.
+                            None => core::str::FromStr::from_str(&s).map_err(serde::de::Error::custom),
+                        }
                     }
                     false => {
                         struct SerVisitor<P>(std::marker::PhantomData<P>);
@@ -375,7 +428,9 @@ macro_rules! impl_field_from_random_bytes_with_flags {
                     last_bytes_mask[..8].copy_from_slice(&last_limb_mask);
 
                     // Length of the buffer containing the field element and the flag.
-                    let output_byte_size = Self::SERIALIZED_SIZE;
+                    let output_byte_size =
+                        snarkvm_utilities::serialize::number_of_bits_and_bytes(P::MODULUS_BITS as usize + F::BIT_SIZE)
+                            .1;
                     // Location of the flag is the last byte of the serialized
                     // form of the field element.
                     let flag_location = output_byte_size - 1;
@@ -28,6 +28,63 @@ pub trait FftField: Field + From<u128> + From<u64> + From<u32> + From<u16> + Fro
     /// Returns the multiplicative generator of `char()` - 1 order.
     fn multiplicative_generator() -> Self;
 
+    /// Returns the two-adicity `s` of the multiplicative group, i.e. the largest `s` such that `2^s` divides `char() - 1`.
+    fn two_adicity() -> u32 {
+        Self::FftParameters::TWO_ADICITY
+    }
+
+    /// Returns the size of the largest radix-2 domain supported by the field, i.e. `2^two_adicity()`,
+    /// or `None` if it does not fit in a `usize`.
+    fn max_two_adic_domain_size() -> Option<usize> {
+        1usize.checked_shl(Self::two_adicity())
+    }
+
+    /// Returns `true` if the field has a root of unity of order `n`, in which case
+    /// `Self::get_root_of_unity(n)` is guaranteed to return `Some`.
+    /// If no small multiplicative subgroup is defined, `n` must be a power of 2 of at most `2^two_adicity()`.
+    /// Otherwise, `n` must be of the form `2^i * FftParams::SMALL_SUBGROUP_BASE^j`, with `i` at most
+    /// `two_adicity()` and `j` at most `FftParams::SMALL_SUBGROUP_BASE_ADICITY`.
+    fn supports_domain_size(n: usize) -> bool {
+        match (Self::FftParameters::SMALL_SUBGROUP_BASE, Self::FftParameters::SMALL_SUBGROUP_BASE_ADICITY) {
+            (Some(q), Some(small_subgroup_base_adicity)) if Self::large_subgroup_root_of_unity().is_some() => {
+                let q = q as usize;
+                let q_adicity = Self::k_adicity(q, n);
+                let two_adicity = Self::k_adicity(2, n);
+                match q.checked_pow(q_adicity).and_then(|q_part| q_part.checked_shl(two_adicity)) {
+                    Some(size) => {
+                        n == size && two_adicity <= Self::two_adicity() && q_adicity <= small_subgroup_base_adicity
+                    }
+                    None => false,
+                }
+            }
+            _ => n.is_power_of_two() && n.trailing_zeros() <= Self::two_adicity(),
+        }
+    }
+
+    /// Returns the smallest domain size that is at least `n` and supported by the field,
+    /// only considering radix-2 domains, or `None` if no such size exists.
+    fn smallest_two_adic_domain_size(n: usize) -> Option<usize> {
+        let size = n.checked_next_power_of_two()?;
+        (size.trailing_zeros() <= Self::two_adicity()).then_some(size)
+    }
+
+    /// Returns the smallest domain size that is at least `n` and supported by the field,
+    /// including the mixed-radix domains of size `2^i * FftParams::SMALL_SUBGROUP_BASE^j`,
+    /// or `None` if no such size exists.
+    fn smallest_domain_size(n: usize) -> Option<usize> {
+        match (Self::FftParameters::SMALL_SUBGROUP_BASE, Self::FftParameters::SMALL_SUBGROUP_BASE_ADICITY) {
+            (Some(q), Some(small_subgroup_base_adicity)) if Self::large_subgroup_root_of_unity().is_some() => (0
+                ..=small_subgroup_base_adicity)
+                .filter_map(|q_adicity| {
+                    let q_part = (q as usize).checked_pow(q_adicity)?;
+                    let size = n.div_ceil(q_part).checked_next_power_of_two()?.checked_mul(q_part)?;
+                    Self::supports_domain_size(size).then_some(size)
+                })
+                .min(),
+            _ => Self::smallest_two_adic_domain_size(n),
+        }
+    }
+
     /// Returns the root of unity of order n, if one exists.
     /// If no small multiplicative subgroup is defined, this is the 2-adic root of unity of order n
     /// (for n a power of 2).
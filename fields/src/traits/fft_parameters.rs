@@ -39,6 +39,7 @@ pub trait FftParameters: 'static + Send + Sync + Sized {
     /// SMALL_SUBGROUP_BASE^SMALL_SUBGROUP_BASE_ADICITY)) Used for mixed-radix FFT.
     const LARGE_SUBGROUP_ROOT_OF_UNITY: Option<Self::BigInteger> = None;
 
-    /// `TWO_ADIC_ROOT_OF_UNITY^2^i` for `i := 0..TWO_ADICITY-1`
-    const POWERS_OF_ROOTS_OF_UNITY: &'static [Self::BigInteger];
+    /// `TWO_ADIC_ROOT_OF_UNITY^2^i` for `i := 0..TWO_ADICITY-1`, used by the square root of prime fields.
+    /// If left empty, the square root falls back to the Tonelli-Shanks algorithm.
+    const POWERS_OF_ROOTS_OF_UNITY: &'static [Self::BigInteger] = &[];
 }
@@ -125,6 +125,19 @@ pub trait Field:
         a.zip(b).map(|(a, b)| *a * b).sum::<Self>()
     }
 
+    /// Returns the sum of the pairwise products of `a` and `b`.
+    /// Panics if `a` and `b` have different lengths.
+    fn sum_of_products_slice(a: &[Self], b: &[Self]) -> Self {
+        assert_eq!(a.len(), b.len(), "The slices must have the same length");
+        Self::sum_of_products(a.iter(), b.iter())
+    }
+
+    /// Adds the sum of the pairwise products of `a` and `b` into `acc`.
+    /// Panics if `a` and `b` have different lengths.
+    fn accumulate_products_into(acc: &mut Self, a: &[Self], b: &[Self]) {
+        *acc += Self::sum_of_products_slice(a, b);
+    }
+
     /// Computes the multiplicative inverse of `self` if `self` is nonzero.
     #[must_use]
     fn inverse(&self) -> Option<Self>;
@@ -132,6 +145,20 @@ pub trait Field:
     /// Sets `self` to `self`'s inverse if it exists. Otherwise it is a no-op.
     fn inverse_in_place(&mut self) -> Option<&mut Self>;
 
+    /// Inverts every nonzero element of `elements` in place with Montgomery's trick,
+    /// in parallel across the available threads. Zero elements are left unchanged.
+    fn batch_inverse(elements: &mut [Self]) {
+        crate::batch_inversion(elements)
+    }
+
+    /// Inverts every nonzero element of `elements` in place with Montgomery's trick,
+    /// and returns the indices of the zero elements, which are left unchanged.
+    fn batch_inverse_skip_zeros(elements: &mut [Self]) -> Vec<usize> {
+        let zeros = elements.iter().enumerate().filter(|(_, element)| element.is_zero()).map(|(i, _)| i).collect();
+        Self::batch_inverse(elements);
+        zeros
+    }
+
     /// Exponentiates this element by a power of the base prime modulus via
     /// the Frobenius automorphism.
     fn frobenius_map(&mut self, power: usize);
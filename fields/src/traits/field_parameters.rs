@@ -16,6 +16,17 @@ use crate::traits::{FftParameters, PoseidonDefaultParameters};
 
 use core::{fmt::Debug, hash::Hash};
 
+/// The modular reduction strategy used by the arithmetic of a prime field.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum Reduction {
+    /// Montgomery reduction, which supports any odd modulus. Elements are stored as `x * R % MODULUS`.
+    Montgomery,
+    /// Crandall reduction, for a pseudo-Mersenne modulus `2^256 - c` with `c < 2^64`, as for secp256k1.
+    /// Elements are stored in canonical form, so `R` and `R2` must be one, and all of the constants
+    /// that are otherwise encoded in Montgomery form are canonical integers. This is only supported by `Fp256`.
+    Crandall { c: u64 },
+}
+
 /// A trait that defines parameters for a prime field.
 pub trait FieldParameters:
     'static + FftParameters + PoseidonDefaultParameters + Copy + Clone + Debug + Default + PartialEq + Eq + Hash
@@ -58,4 +69,7 @@ pub trait FieldParameters:
 
     /// (Self::MODULUS - 1) / 2
     const MODULUS_MINUS_ONE_DIV_TWO: Self::BigInteger;
+
+    /// The modular reduction strategy, which determines the representation of the field elements.
+    const REDUCTION: Reduction = Reduction::Montgomery;
 }
@@ -15,9 +15,37 @@
 use crate::{FftField, FieldError, FieldParameters, PoseidonDefaultField};
 use snarkvm_utilities::{biginteger::BigInteger, cmp::min, str::FromStr};
 
+use subtle::ConstantTimeEq;
+
+/// The GLV decomposition `k = (-1)^k1_neg * k1 + (-1)^k2_neg * k2 * lambda (mod r)` of a scalar `k`,
+/// where `lambda` is the eigenvalue of an efficiently-computable curve endomorphism.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct GlvDecomposition<F: PrimeField> {
+    /// The magnitude of the first component.
+    pub k1: F,
+    /// The magnitude of the second component.
+    pub k2: F,
+    /// Whether the first component is negated.
+    pub k1_neg: bool,
+    /// Whether the second component is negated.
+    pub k2_neg: bool,
+}
+
+impl<F: PrimeField> GlvDecomposition<F> {
+    /// Returns the scalar `(-1)^k1_neg * k1 + (-1)^k2_neg * k2 * lambda` represented by this decomposition.
+    pub fn recompose(&self, lambda: F) -> F {
+        let k1 = if self.k1_neg { -self.k1 } else { self.k1 };
+        let k2 = if self.k2_neg { -self.k2 } else { self.k2 };
+        k1 + k2 * lambda
+    }
+}
+
 /// The interface for a prime field.
 pub trait PrimeField:
-    FftField<FftParameters = <Self as PrimeField>::Parameters> + PoseidonDefaultField + FromStr<Err = FieldError>
+    FftField<FftParameters = <Self as PrimeField>::Parameters>
+    + PoseidonDefaultField
+    + FromStr<Err = FieldError>
+    + ConstantTimeEq
 {
     /// Returns the field size in bits.
     const SIZE_IN_BITS: usize = Self::Parameters::MODULUS_BITS as usize;
@@ -33,7 +61,21 @@ pub trait PrimeField:
     /// Returns a human-readable `Self::BigInteger` in the range `0..(Self::MODULUS - 1)`.
     fn to_bigint(&self) -> Self::BigInteger;
 
-    /// Returns the decomposition of the scalar.
+    /// Constructs a `PrimeField` element from its internal `Self::BigInteger`, as given by
+    /// `FieldParameters::REDUCTION`, returning `None` if the representation is not less than the modulus.
+    /// This is the Montgomery form, except for fields with Crandall reduction, whose elements are canonical.
+    fn from_montgomery_bigint(repr: Self::BigInteger) -> Option<Self>;
+
+    /// Returns the internal `Self::BigInteger` of this element, as given by `FieldParameters::REDUCTION`.
+    /// This is the Montgomery form, except for fields with Crandall reduction, whose elements are canonical.
+    fn to_montgomery_bigint(&self) -> Self::BigInteger;
+
+    /// Returns the GLV decomposition of this scalar, for the lattice basis `((b1, -1), (1, b2))` with
+    /// `b1 * b2 = -1 (mod r)` and the rounding constants `q1` and `q2`, where `r128 = 2^128 - 1` and `half_r = 2^255`.
+    ///
+    /// The resulting `k1` and `k2` are at most 128 bits, and satisfy `k = ±k1 ± k2 * lambda (mod r)`,
+    /// where `lambda = b1` is the eigenvalue of the curve endomorphism. Returns `None` if the field
+    /// does not support the decomposition, in which case callers should fall back to double-and-add.
     fn decompose(
         &self,
         q1: &[u64; 4],
@@ -42,7 +84,7 @@ pub trait PrimeField:
         b2: Self,
         r128: Self,
         half_r: &[u64; 8],
-    ) -> (Self, Self, bool, bool);
+    ) -> Option<GlvDecomposition<Self>>;
 
     /// Returns the field size in bits.
     fn size_in_bits() -> usize {
@@ -74,6 +116,64 @@ pub trait PrimeField:
         Self::Parameters::T_MINUS_ONE_DIV_TWO
     }
 
+    /// Returns the multiplicative inverse of `self` as `self^(MODULUS - 2)`, or zero if `self` is zero.
+    /// Unlike `inverse`, which uses the binary extended Euclidean algorithm, the sequence of
+    /// squarings and multiplications only depends on the modulus, and not on `self`.
+    #[must_use]
+    fn inverse_ct(&self) -> Self {
+        let mut exponent = Self::modulus();
+        exponent.sub_noborrow(&2u64.into());
+        self.pow(exponent)
+    }
+
+    /// Returns the square root of `self` with the Tonelli-Shanks algorithm, or `None` if `self` is not a square.
+    /// This only relies on the two-adicity constants of the field, and when `MODULUS = 3 (mod 4)`,
+    /// it reduces to the single exponentiation `self^((MODULUS + 1) / 4)`.
+    fn tonelli_shanks_sqrt(&self) -> Option<Self> {
+        if self.is_zero() {
+            return Some(*self);
+        }
+
+        // https://eprint.iacr.org/2012/685.pdf (page 12, algorithm 5)
+        // Set `w = self^((t - 1) / 2)`, so that `x = self^((t + 1) / 2)` and `b = self^t`.
+        let mut w = self.pow(Self::trace_minus_one_div_two());
+        let mut x = w * self;
+        let mut b = x * w;
+
+        let two_adicity = Self::Parameters::TWO_ADICITY as usize;
+        if two_adicity == 1 {
+            return (x.square() == *self).then_some(x);
+        }
+
+        // Since the generator is a quadratic non-residue and `t` is odd, so is the two-adic root of unity.
+        let mut z = Self::two_adic_root_of_unity();
+        let mut v = two_adicity;
+        while !b.is_one() {
+            // Find the least `k` such that `b^(2^k) = 1`, which is less than `v` if and only if `self` is a square.
+            let mut k = 0usize;
+            let mut b2k = b;
+            while !b2k.is_one() {
+                b2k.square_in_place();
+                k += 1;
+            }
+            if k == v {
+                return None;
+            }
+
+            w = z;
+            for _ in 0..(v - k - 1) {
+                w.square_in_place();
+            }
+
+            z = w.square();
+            b *= &z;
+            x *= &w;
+            v = k;
+        }
+
+        Some(x)
+    }
+
     /// Reads bytes in big-endian, and converts them to a field element.
     /// If the bytes are larger than the modulus, it will reduce them.
     fn from_bytes_be_mod_order(bytes: &[u8]) -> Self {
@@ -13,6 +13,10 @@
 // limitations under the License.
 
 use crate::{Field, LegendreSymbol};
+use snarkvm_utilities::cfg_iter;
+
+#[cfg(not(feature = "serial"))]
+use rayon::prelude::*;
 
 /// The interface for a field that supports an efficient square-root operation.
 pub trait SquareRootField: Field {
@@ -25,4 +29,15 @@ pub trait SquareRootField: Field {
 
     /// Sets `self` to be the square root of `self`, if it exists.
     fn sqrt_in_place(&mut self) -> Option<&mut Self>;
+
+    /// Returns `true` for each element of `elements` that is a square, including zero.
+    fn is_square_batch(elements: &[Self]) -> Vec<bool> {
+        cfg_iter!(elements).map(|element| !element.legendre().is_qnr()).collect()
+    }
+
+    /// Returns the square root of each element of `elements`, or `None` for each element that is not a square.
+    /// Fields may override this to share work across the batch, such as inversions.
+    fn sqrt_batch(elements: &[Self]) -> Vec<Option<Self>> {
+        cfg_iter!(elements).map(|element| element.sqrt()).collect()
+    }
 }
@@ -14,16 +14,17 @@
 
 use super::*;
 
+impl<N: Network> Versioned for Block<N> {
+    const NAME: &'static str = "block";
+    const VERSION: u8 = 1;
+}
+
 impl<N: Network> FromBytes for Block<N> {
     /// Reads the block from the buffer.
     #[inline]
     fn read_le<R: Read>(mut reader: R) -> IoResult<Self> {
-        // Read the version.
-        let version = u8::read_le(&mut reader)?;
-        // Ensure the version is valid.
-        if version != 1 {
-            return Err(error("Invalid block version"));
-        }
+        // Read the version, and ensure it is valid.
+        Self::read_version(&mut reader)?;
 
         // Read the block hash.
         let block_hash: N::BlockHash = FromBytes::read_le(&mut reader)?;
@@ -94,7 +95,7 @@ impl<N: Network> ToBytes for Block<N> {
     #[inline]
     fn write_le<W: Write>(&self, mut writer: W) -> IoResult<()> {
         // Write the version.
-        1u8.write_le(&mut writer)?;
+        Self::write_version(&mut writer)?;
 
         // Write the block hash.
         self.block_hash.write_le(&mut writer)?;
@@ -140,6 +141,11 @@ mod tests {
             // Check the byte representation.
             let expected_bytes = expected.to_bytes_le()?;
             assert_eq!(expected, Block::read_le(&expected_bytes[..])?);
+
+            // Ensure an unsupported version is rejected.
+            let mut invalid_bytes = expected_bytes.clone();
+            invalid_bytes[0] = Block::<CurrentNetwork>::VERSION + 1;
+            assert!(Block::<CurrentNetwork>::read_le(&invalid_bytes[..]).is_err());
         }
         Ok(())
     }
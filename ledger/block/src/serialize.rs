@@ -63,7 +63,7 @@ impl<'de, N: Network> Deserialize<'de> for Block<N> {
                     false => Err(de::Error::custom(error("Mismatching block hash, possible data corruption"))),
                 }
             }
-            false => FromBytesDeserializer::<Self>::deserialize_with_size_encoding(deserializer, "block"),
+            false => FromBytesDeserializer::<Self>::deserialize_checked_with_size_encoding(deserializer, "block"),
         }
     }
 }
@@ -104,6 +104,12 @@ mod tests {
             // Deserialize
             assert_eq!(expected, Block::read_le(&expected_bytes[..])?);
             assert_eq!(expected, bincode::deserialize(&expected_bytes_with_size_encoding[..])?);
+
+            // Ensure trailing bytes after the block are rejected.
+            let mut padded_bytes = (expected_bytes.len() as u64 + 1).to_le_bytes().to_vec();
+            padded_bytes.extend_from_slice(&expected_bytes);
+            padded_bytes.push(0);
+            assert!(bincode::deserialize::<Block<CurrentNetwork>>(&padded_bytes).is_err());
         }
         Ok(())
     }
@@ -103,7 +103,7 @@ impl<'de, N: Network> Deserialize<'de> for Transaction<N> {
                     false => Err(de::Error::custom(error("Mismatching transaction ID, possible data corruption"))),
                 }
             }
-            false => FromBytesDeserializer::<Self>::deserialize_with_size_encoding(deserializer, "transaction"),
+            false => FromBytesDeserializer::<Self>::deserialize_checked_with_size_encoding(deserializer, "transaction"),
         }
     }
 }
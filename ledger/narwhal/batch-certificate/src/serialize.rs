@@ -41,7 +41,9 @@ impl<'de, N: Network> Deserialize<'de> for BatchCertificate<N> {
                 )
                 .map_err(de::Error::custom)
             }
-            false => FromBytesDeserializer::<Self>::deserialize_with_size_encoding(deserializer, "batch certificate"),
+            false => {
+                FromBytesDeserializer::<Self>::deserialize_checked_with_size_encoding(deserializer, "batch certificate")
+            }
         }
     }
 }
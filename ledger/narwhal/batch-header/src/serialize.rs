@@ -64,7 +64,9 @@ impl<'de, N: Network> Deserialize<'de> for BatchHeader<N> {
                     )))),
                 }
             }
-            false => FromBytesDeserializer::<Self>::deserialize_with_size_encoding(deserializer, "batch header"),
+            false => {
+                FromBytesDeserializer::<Self>::deserialize_checked_with_size_encoding(deserializer, "batch header")
+            }
         }
     }
 }
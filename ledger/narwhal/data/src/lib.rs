@@ -42,21 +42,23 @@ impl<T: FromBytes + ToBytes + Send + 'static> Data<T> {
         }
     }
 
+    /// Deserializes the data, only accepting the canonical encoding of `T` for untrusted buffers.
     #[cfg(feature = "async")]
     pub async fn deserialize(self) -> Result<T> {
         match self {
             Self::Object(x) => Ok(x),
-            Self::Buffer(bytes) => match task::spawn_blocking(move || T::from_bytes_le(&bytes)).await {
+            Self::Buffer(bytes) => match task::spawn_blocking(move || T::from_bytes_le_checked(&bytes)).await {
                 Ok(x) => x,
                 Err(err) => Err(err.into()),
             },
         }
     }
 
+    /// Deserializes the data, only accepting the canonical encoding of `T` for untrusted buffers.
     pub fn deserialize_blocking(self) -> Result<T> {
         match self {
             Self::Object(x) => Ok(x),
-            Self::Buffer(bytes) => T::from_bytes_le(&bytes),
+            Self::Buffer(bytes) => T::from_bytes_le_checked(&bytes),
         }
     }
 
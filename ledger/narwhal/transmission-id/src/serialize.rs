@@ -29,7 +29,9 @@ impl<'de, N: Network> Deserialize<'de> for TransmissionID<N> {
     fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
         match deserializer.is_human_readable() {
             true => FromStr::from_str(&String::deserialize(deserializer)?).map_err(de::Error::custom),
-            false => FromBytesDeserializer::<Self>::deserialize_with_size_encoding(deserializer, "transmission ID"),
+            false => {
+                FromBytesDeserializer::<Self>::deserialize_checked_with_size_encoding(deserializer, "transmission ID")
+            }
         }
     }
 }
@@ -64,7 +64,9 @@ impl<'de, N: Network> Deserialize<'de> for Transmission<N> {
                     _ => Err(de::Error::custom(error("Invalid transmission type"))),
                 }
             }
-            false => FromBytesDeserializer::<Self>::deserialize_with_size_encoding(deserializer, "transmission"),
+            false => {
+                FromBytesDeserializer::<Self>::deserialize_checked_with_size_encoding(deserializer, "transmission")
+            }
         }
     }
 }
@@ -54,7 +54,7 @@ impl<'de, N: Network> Deserialize<'de> for Solution<N> {
                     false => Err(de::Error::custom(error("Mismatching solution ID, possible data corruption"))),
                 }
             }
-            false => FromBytesDeserializer::<Self>::deserialize_with_size_encoding(deserializer, "solution"),
+            false => FromBytesDeserializer::<Self>::deserialize_checked_with_size_encoding(deserializer, "solution"),
         }
     }
 }
@@ -36,7 +36,7 @@ impl<'de, N: Network> Deserialize<'de> for PuzzleSolutions<N> {
                 let mut solutions = serde_json::Value::deserialize(deserializer)?;
                 Self::new(DeserializeExt::take_from_value::<D>(&mut solutions, "solutions")?).map_err(de::Error::custom)
             }
-            false => FromBytesDeserializer::<Self>::deserialize_with_size_encoding(deserializer, "solutions"),
+            false => FromBytesDeserializer::<Self>::deserialize_checked_with_size_encoding(deserializer, "solutions"),
         }
     }
 }
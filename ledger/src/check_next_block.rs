@@ -16,6 +16,7 @@ use super::*;
 
 impl<N: Network, C: ConsensusStorage<N>> Ledger<N, C> {
     /// Checks the given block is valid next block.
+    #[cfg_attr(feature = "instrument", tracing::instrument(level = "debug", skip_all, fields(height = block.height())))]
     pub fn check_next_block<R: CryptoRng + Rng>(&self, block: &Block<N>, rng: &mut R) -> Result<()> {
         let height = block.height();
 
@@ -1035,6 +1035,7 @@ impl<N: Network, B: BlockStorage<N>> BlockStore<N, B> {
     }
 
     /// Stores the given block into storage.
+    #[cfg_attr(feature = "instrument", tracing::instrument(level = "debug", skip_all, fields(height = block.height())))]
     pub fn insert(&self, block: &Block<N>) -> Result<()> {
         // Acquire the write lock on the block tree.
         let mut tree = self.tree.write();
@@ -1067,6 +1068,7 @@ impl<N: Network, B: BlockStorage<N>> BlockStore<N, B> {
     }
 
     /// Removes the last 'n' blocks from storage.
+    #[cfg_attr(feature = "instrument", tracing::instrument(level = "debug", skip(self)))]
     pub fn remove_last_n(&self, n: u32) -> Result<()> {
         // Ensure 'n' is non-zero.
         ensure!(n > 0, "Cannot remove zero blocks");
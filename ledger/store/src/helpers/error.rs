@@ -0,0 +1,42 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use console::prelude::ErrorCode;
+
+/// An error raised by the storage layer, for failures that callers may want to match on.
+#[derive(Debug, thiserror::Error)]
+pub enum StorageError {
+    #[error("Mismatching network ID or storage mode in the database")]
+    MismatchedStorage,
+
+    #[error("Atomic depth must be 0 to unpause atomic writes")]
+    NonZeroAtomicDepth,
+
+    #[error("Atomic writes must be paused to unpause them")]
+    AtomicWritesNotPaused,
+
+    #[error("NestedMap {0} index out of range")]
+    MalformedNestedKey(&'static str),
+}
+
+impl ErrorCode for StorageError {
+    fn error_code(&self) -> u32 {
+        match self {
+            Self::MismatchedStorage => 4000,
+            Self::NonZeroAtomicDepth => 4001,
+            Self::AtomicWritesNotPaused => 4002,
+            Self::MalformedNestedKey(..) => 4003,
+        }
+    }
+}
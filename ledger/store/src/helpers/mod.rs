@@ -12,6 +12,9 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+mod error;
+pub use error::*;
+
 pub mod memory;
 #[cfg(feature = "rocks")]
 pub mod rocksdb;
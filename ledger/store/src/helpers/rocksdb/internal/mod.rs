@@ -24,6 +24,7 @@ pub use nested_map::*;
 #[cfg(test)]
 mod tests;
 
+use crate::helpers::StorageError;
 use aleo_std_storage::StorageMode;
 use anyhow::{bail, ensure, Result};
 use once_cell::sync::OnceCell;
@@ -143,7 +144,7 @@ impl Database for RocksDB {
         // Ensure the database network ID and storage mode match.
         match database.network_id == network_id && database.storage_mode == storage.into() {
             true => Ok(database),
-            false => bail!("Mismatching network ID or storage mode in the database"),
+            false => bail!(StorageError::MismatchedStorage),
         }
     }
 
@@ -227,13 +228,13 @@ impl RocksDB {
         // Ensure the call to unpause is only performed before or after an atomic batch scope
         // - and never in the middle of one (otherwise there is a fundamental logic bug).
         // Note: In production, this `ensure` is a safety-critical invariant that never fails.
-        ensure!(self.atomic_depth.load(Ordering::SeqCst) == 0, "Atomic depth must be 0 to unpause atomic writes");
+        ensure!(self.atomic_depth.load(Ordering::SeqCst) == 0, StorageError::NonZeroAtomicDepth);
 
         // https://github.com/rust-lang/rust/issues/98485
         let currently_paused = self.atomic_writes_paused.load(Ordering::SeqCst);
         // Ensure the database is paused (otherwise there is a fundamental logic bug).
         // Note: In production, this `ensure` is a safety-critical invariant that never fails.
-        ensure!(currently_paused, "Atomic writes must be paused to unpause them");
+        ensure!(currently_paused, StorageError::AtomicWritesNotPaused);
 
         // In order to ensure that all the operations that are intended
         // to be atomic via the usual macro approach are still performed
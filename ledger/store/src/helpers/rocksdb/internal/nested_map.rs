@@ -15,8 +15,8 @@
 #![allow(clippy::type_complexity)]
 
 use super::*;
-use crate::helpers::{NestedMap, NestedMapRead};
-use console::prelude::{anyhow, cfg_into_iter, FromBytes};
+use crate::helpers::{NestedMap, NestedMapRead, StorageError};
+use console::prelude::{cfg_into_iter, FromBytes};
 
 use core::{fmt, fmt::Debug, hash::Hash, mem};
 use std::{borrow::Cow, sync::atomic::Ordering};
@@ -85,16 +85,15 @@ impl<M: Serialize + DeserializeOwned, K: Serialize + DeserializeOwned, V: Serial
 fn get_map_and_key(map_key: &[u8]) -> Result<(&[u8], &[u8])> {
     // Retrieve the map length.
     let map_len = u32::from_bytes_le(
-        map_key.get(PREFIX_LEN..PREFIX_LEN + 4).ok_or_else(|| anyhow!("NestedMap map_len index out of range"))?,
+        map_key.get(PREFIX_LEN..PREFIX_LEN + 4).ok_or_else(|| StorageError::MalformedNestedKey("map_len"))?,
     )? as usize;
 
     // Retrieve the map bytes.
-    let map = map_key
-        .get(PREFIX_LEN + 4..PREFIX_LEN + 4 + map_len)
-        .ok_or_else(|| anyhow!("NestedMap map index out of range"))?;
+    let map =
+        map_key.get(PREFIX_LEN + 4..PREFIX_LEN + 4 + map_len).ok_or_else(|| StorageError::MalformedNestedKey("map"))?;
 
     // Retrieve the key bytes.
-    let key = map_key.get(PREFIX_LEN + 4 + map_len..).ok_or_else(|| anyhow!("NestedMap key index out of range"))?;
+    let key = map_key.get(PREFIX_LEN + 4 + map_len..).ok_or_else(|| StorageError::MalformedNestedKey("key"))?;
 
     // Return the map and key bytes.
     Ok((map, key))
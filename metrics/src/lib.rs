@@ -14,17 +14,41 @@
 
 #![forbid(unsafe_code)]
 
+const COUNTER_NAMES: [&str; 4] =
+    [msm::GPU_MSMS, msm::GPU_MSM_FAILURES, snark::CONSTRAINTS_PROVEN, snark::PROOFS_VERIFIED];
 const GAUGE_NAMES: [&str; 1] = [committee::TOTAL_STAKE];
+const HISTOGRAM_NAMES: [&str; 4] =
+    [msm::GPU_MSM_BUCKET_OCCUPANCY, msm::GPU_MSM_LATENCY, msm::GPU_MSM_TERMS, msm::GPU_MSM_TRANSFER_BYTES];
 
 pub mod committee {
     pub const TOTAL_STAKE: &str = "snarkvm_ledger_committee_total_stake";
 }
 
+pub mod msm {
+    pub const GPU_MSMS: &str = "snarkvm_msm_gpu_total";
+    pub const GPU_MSM_FAILURES: &str = "snarkvm_msm_gpu_failures";
+    pub const GPU_MSM_BUCKET_OCCUPANCY: &str = "snarkvm_msm_gpu_bucket_occupancy_max";
+    pub const GPU_MSM_LATENCY: &str = "snarkvm_msm_gpu_latency_secs";
+    pub const GPU_MSM_TERMS: &str = "snarkvm_msm_gpu_terms";
+    pub const GPU_MSM_TRANSFER_BYTES: &str = "snarkvm_msm_gpu_transfer_bytes";
+}
+
+pub mod snark {
+    pub const CONSTRAINTS_PROVEN: &str = "snarkvm_snark_constraints_proven";
+    pub const PROOFS_VERIFIED: &str = "snarkvm_snark_proofs_verified";
+}
+
 /// Registers all snarkVM metrics.
 pub fn register_metrics() {
+    for name in COUNTER_NAMES {
+        register_counter(name);
+    }
     for name in GAUGE_NAMES {
         register_gauge(name);
     }
+    for name in HISTOGRAM_NAMES {
+        register_histogram(name);
+    }
 }
 
 /******** Counter ********/
@@ -52,6 +76,15 @@ pub fn increment_counter(name: &'static str) {
     counter.increment(1);
 }
 
+/// Increments a counter with the given name by the given value.
+///
+/// Counters represent a single monotonic value, which means the value can only be incremented,
+/// not decremented, and always starts out with an initial value of zero.
+pub fn increment_counter_by<V: Into<u64>>(name: &'static str, value: V) {
+    let counter = ::metrics::counter!(name);
+    counter.increment(value.into());
+}
+
 /******** Gauge ********/
 
 /// Registers a gauge with the given name.
@@ -268,6 +268,12 @@ pub fn cost_per_command<N: Network>(stack: &Stack<N>, finalize: &Finalize<N>, co
         Command::Instruction(Instruction::HashSha3_512(hash)) => {
             cost_in_size(stack, finalize, hash.operands(), HASH_PER_BYTE_COST, HASH_BASE_COST)
         }
+        Command::Instruction(Instruction::HashSha256(hash)) => {
+            cost_in_size(stack, finalize, hash.operands(), HASH_PER_BYTE_COST, HASH_BASE_COST)
+        }
+        Command::Instruction(Instruction::HashBlake2s(hash)) => {
+            cost_in_size(stack, finalize, hash.operands(), HASH_PER_BYTE_COST, HASH_BASE_COST)
+        }
         Command::Instruction(Instruction::HashManyPSD2(_)) => {
             bail!("`hash_many.psd2` is not supported in finalize")
         }
@@ -282,6 +288,27 @@ pub fn cost_per_command<N: Network>(stack: &Stack<N>, finalize: &Finalize<N>, co
         Command::Instruction(Instruction::IsNeq(_)) => Ok(500),
         Command::Instruction(Instruction::LessThan(_)) => Ok(500),
         Command::Instruction(Instruction::LessThanOrEqual(_)) => Ok(500),
+        Command::Instruction(Instruction::MerkleVerifyBHP256(merkle)) => {
+            cost_in_size(stack, finalize, merkle.operands(), HASH_BHP_PER_BYTE_COST, HASH_BHP_BASE_COST)
+        }
+        Command::Instruction(Instruction::MerkleVerifyBHP512(merkle)) => {
+            cost_in_size(stack, finalize, merkle.operands(), HASH_BHP_PER_BYTE_COST, HASH_BHP_BASE_COST)
+        }
+        Command::Instruction(Instruction::MerkleVerifyBHP768(merkle)) => {
+            cost_in_size(stack, finalize, merkle.operands(), HASH_BHP_PER_BYTE_COST, HASH_BHP_BASE_COST)
+        }
+        Command::Instruction(Instruction::MerkleVerifyBHP1024(merkle)) => {
+            cost_in_size(stack, finalize, merkle.operands(), HASH_BHP_PER_BYTE_COST, HASH_BHP_BASE_COST)
+        }
+        Command::Instruction(Instruction::MerkleVerifyPSD2(merkle)) => {
+            cost_in_size(stack, finalize, merkle.operands(), HASH_PSD_PER_BYTE_COST, HASH_PSD_BASE_COST)
+        }
+        Command::Instruction(Instruction::MerkleVerifyPSD4(merkle)) => {
+            cost_in_size(stack, finalize, merkle.operands(), HASH_PSD_PER_BYTE_COST, HASH_PSD_BASE_COST)
+        }
+        Command::Instruction(Instruction::MerkleVerifyPSD8(merkle)) => {
+            cost_in_size(stack, finalize, merkle.operands(), HASH_PSD_PER_BYTE_COST, HASH_PSD_BASE_COST)
+        }
         Command::Instruction(Instruction::Modulo(_)) => Ok(500),
         Command::Instruction(Instruction::Mul(mul)) => {
             // Ensure `mul` has exactly two operands.
@@ -435,6 +435,11 @@ impl<N: Network> StackExecute<N> for Stack<N> {
                 self.synthesize_from_assignment(function.name(), &assignment)?;
                 lap!(timer, "Synthesize the {} circuit key", function.name());
             }
+            // Otherwise, if the keys were inserted before the circuit was first synthesized, check them once.
+            else if self.needs_circuit_digest(function.name()) {
+                self.insert_circuit_digest(function.name(), assignment.digest()?)?;
+                lap!(timer, "Check the {} circuit key", function.name());
+            }
         }
         // If the circuit is in `Authorize` mode, then save the transition.
         if let CallStack::Authorize(_, _, authorization) = registers.call_stack() {
@@ -454,6 +459,9 @@ impl<N: Network> StackExecute<N> for Stack<N> {
                 num_request_constraints,
                 num_function_constraints,
                 num_response_constraints,
+                num_public: assignment.num_public(),
+                num_private: assignment.num_private(),
+                num_nonzeros: assignment.num_nonzeros(),
             };
             // Add the assignment to the assignments.
             assignments.write().push((assignment, metrics));
@@ -476,6 +484,9 @@ impl<N: Network> StackExecute<N> for Stack<N> {
                 num_request_constraints,
                 num_function_constraints,
                 num_response_constraints,
+                num_public: assignment.num_public(),
+                num_private: assignment.num_private(),
+                num_nonzeros: assignment.num_nonzeros(),
             };
 
             // Add the transition to the trace.
@@ -496,6 +507,9 @@ impl<N: Network> StackExecute<N> for Stack<N> {
                 num_request_constraints,
                 num_function_constraints,
                 num_response_constraints,
+                num_public: assignment.num_public(),
+                num_private: assignment.num_private(),
+                num_nonzeros: assignment.num_nonzeros(),
             };
             // Add the assignment to the assignments.
             assignments.write().push((assignment, metrics));
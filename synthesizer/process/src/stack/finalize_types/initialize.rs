@@ -720,6 +720,7 @@ impl<N: Network> FinalizeTypes<N> {
                 ),
                 _ => bail!("Instruction '{instruction}' is not for opcode '{opcode}'."),
             },
+            Opcode::Merkle(opcode) => RegisterTypes::check_merkle_opcode(opcode, instruction)?,
             Opcode::Sign => {
                 // Ensure the instruction has one destination register.
                 ensure!(
@@ -16,5 +16,6 @@ use super::*;
 
 mod initialize;
 mod matches;
+mod public_input_layout;
 mod sample;
 mod synthesize;
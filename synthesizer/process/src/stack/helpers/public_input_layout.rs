@@ -0,0 +1,69 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+impl<N: Network> Stack<N> {
+    /// Returns the layout of the public inputs of the circuit for the given function name.
+    /// The layout mirrors the order in which the verifier inputs of a transition are constructed.
+    pub fn public_input_layout(&self, function_name: &Identifier<N>) -> Result<PublicInputLayout<N>> {
+        // Retrieve the function.
+        let function = self.get_function(function_name)?;
+
+        // Initialize the layout.
+        let mut layout = PublicInputLayout::new(Locator::new(*self.program_id(), *function_name));
+        // [Inputs] Add the constant one, the transition public key, and the transition and signer commitments.
+        layout.push("one", "field", 1);
+        layout.push("tpk", "group", 2);
+        layout.push("tcm", "field", 1);
+        layout.push("scm", "field", 1);
+        // [Inputs] Add the input IDs.
+        for (index, input_type) in function.input_types().iter().enumerate() {
+            layout.push_input(&format!("input.{index}"), input_type);
+        }
+        // [Inputs] Add the public inputs for 'self.caller'.
+        layout.push("is_root", "boolean", 1);
+        layout.push("parent", "address", 2);
+
+        // [Inputs] Add the transition commitment, input IDs, and output IDs of each external function call.
+        for instruction in function.instructions() {
+            if let Instruction::Call(call) = instruction {
+                if let CallOperator::Locator(locator) = call.operator() {
+                    // Note: Calls to external closures do not produce a transition, and are skipped.
+                    let Ok(external_function) =
+                        self.get_external_stack(locator.program_id())?.get_function(locator.resource())
+                    else {
+                        continue;
+                    };
+                    let prefix = format!("call.{}", layout.calls().len());
+                    layout.push_call(*locator);
+                    layout.push(format!("{prefix}.tcm"), "field", 1);
+                    for (index, input_type) in external_function.input_types().iter().enumerate() {
+                        layout.push_input(&format!("{prefix}.input.{index}"), input_type);
+                    }
+                    // Note: Only the output IDs of an external call are public, including for records.
+                    for (index, output_type) in external_function.output_types().iter().enumerate() {
+                        layout.push(format!("{prefix}.output.{index}.id"), output_type.to_string(), 1);
+                    }
+                }
+            }
+        }
+
+        // [Inputs] Add the output IDs.
+        for (index, output_type) in function.output_types().iter().enumerate() {
+            layout.push_output(&format!("output.{index}"), output_type);
+        }
+        Ok(layout)
+    }
+}
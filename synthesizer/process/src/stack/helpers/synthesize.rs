@@ -97,6 +97,10 @@ impl<N: Network> Stack<N> {
 
         // Synthesize the proving and verifying key.
         let (proving_key, verifying_key) = self.universal_srs.to_circuit_key(&function_name.to_string(), assignment)?;
+        // Record the digest of the circuit, which the keys were synthesized from.
+        if let Some(circuit_digest) = proving_key.circuit_digest() {
+            self.insert_circuit_digest(function_name, circuit_digest)?;
+        }
         // Insert the proving key.
         self.insert_proving_key(function_name, proving_key)?;
         // Insert the verifying key.
@@ -24,6 +24,9 @@ pub use finalize_registers::*;
 mod finalize_types;
 pub use finalize_types::*;
 
+mod public_input_layout;
+pub use public_input_layout::*;
+
 mod register_types;
 pub use register_types::*;
 
@@ -185,6 +188,8 @@ pub struct Stack<N: Network> {
     proving_keys: Arc<RwLock<IndexMap<Identifier<N>, ProvingKey<N>>>>,
     /// The mapping of function name to verifying key.
     verifying_keys: Arc<RwLock<IndexMap<Identifier<N>, VerifyingKey<N>>>>,
+    /// The mapping of function name to the digest of its circuit, once it has been synthesized.
+    circuit_digests: Arc<RwLock<IndexMap<Identifier<N>, [u8; 32]>>>,
     /// The mapping of function names to the number of calls.
     number_of_calls: IndexMap<Identifier<N>, usize>,
     /// The mapping of function names to finalize cost.
@@ -407,6 +412,10 @@ impl<N: Network> Stack<N> {
             "Function '{function_name}' does not exist in program '{}'.",
             self.program.id()
         );
+        // Ensure the proving key matches the circuit, if its digest is known.
+        if let Some(circuit_digest) = self.circuit_digests.read().get(function_name) {
+            proving_key.ensure_circuit_matches(&format!("{}/{function_name}", self.program.id()), circuit_digest)?;
+        }
         // Insert the proving key.
         self.proving_keys.write().insert(*function_name, proving_key);
         Ok(())
@@ -421,6 +430,10 @@ impl<N: Network> Stack<N> {
             "Function '{function_name}' does not exist in program '{}'.",
             self.program.id()
         );
+        // Ensure the verifying key matches the circuit, if its digest is known.
+        if let Some(circuit_digest) = self.circuit_digests.read().get(function_name) {
+            verifying_key.ensure_circuit_matches(&format!("{}/{function_name}", self.program.id()), circuit_digest)?;
+        }
         // Insert the verifying key.
         self.verifying_keys.write().insert(*function_name, verifying_key);
         Ok(())
@@ -440,6 +453,28 @@ impl<N: Network> Stack<N> {
 }
 
 impl<N: Network> Stack<N> {
+    /// Records the digest of the circuit for the given function name, and ensures its keys match the circuit.
+    /// The keys are checked here once, and again only when they are replaced, so proofs need not check them.
+    pub(crate) fn insert_circuit_digest(&self, function_name: &Identifier<N>, circuit_digest: [u8; 32]) -> Result<()> {
+        let locator = format!("{}/{function_name}", self.program.id());
+        if let Some(proving_key) = self.proving_keys.read().get(function_name) {
+            proving_key.ensure_circuit_matches(&locator, &circuit_digest)?;
+        }
+        if let Some(verifying_key) = self.verifying_keys.read().get(function_name) {
+            verifying_key.ensure_circuit_matches(&locator, &circuit_digest)?;
+        }
+        self.circuit_digests.write().insert(*function_name, circuit_digest);
+        Ok(())
+    }
+
+    /// Returns `true` if the digest of the circuit for the given function name is needed to check its keys,
+    /// because it is not known yet, and the proving key or verifying key records the digest of its circuit.
+    pub(crate) fn needs_circuit_digest(&self, function_name: &Identifier<N>) -> bool {
+        !self.circuit_digests.read().contains_key(function_name)
+            && (self.proving_keys.read().get(function_name).is_some_and(|key| key.circuit_digest().is_some())
+                || self.verifying_keys.read().get(function_name).is_some_and(|key| key.circuit_digest().is_some()))
+    }
+
     /// Inserts the proving key if the program ID is 'credits.aleo'.
     fn try_insert_credits_function_proving_key(&self, function_name: &Identifier<N>) -> Result<()> {
         // If the program is 'credits.aleo' and it does not exist yet, load the proving key directly.
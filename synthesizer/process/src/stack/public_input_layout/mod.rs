@@ -0,0 +1,122 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+mod serialize;
+mod string;
+
+use console::{
+    network::prelude::*,
+    program::{Locator, ValueType},
+};
+
+use core::ops::Range;
+
+/// A named range of elements in the public inputs of a function circuit.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct PublicInputEntry {
+    /// The name of the entry, e.g. `tcm` or `input.0.id`.
+    name: String,
+    /// The type of the value described by the entry, e.g. `field` or `u64.public`.
+    type_name: String,
+    /// The range of indices of the entry in the public inputs.
+    range: Range<usize>,
+}
+
+impl PublicInputEntry {
+    /// Returns the name of the entry.
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// Returns the type of the value described by the entry.
+    pub fn type_name(&self) -> &str {
+        &self.type_name
+    }
+
+    /// Returns the range of indices of the entry in the public inputs.
+    pub fn range(&self) -> Range<usize> {
+        self.range.clone()
+    }
+}
+
+/// The layout of the public inputs of a function circuit, in the order they are passed to the verifier.
+#[derive(Clone, PartialEq, Eq)]
+pub struct PublicInputLayout<N: Network> {
+    /// The locator of the function.
+    locator: Locator<N>,
+    /// The locators of the external functions called by the function, in the order they are called.
+    calls: Vec<Locator<N>>,
+    /// The entries of the public inputs, in order.
+    entries: Vec<PublicInputEntry>,
+}
+
+impl<N: Network> PublicInputLayout<N> {
+    /// Initializes a new, empty layout for the given function.
+    pub(crate) fn new(locator: Locator<N>) -> Self {
+        Self { locator, calls: Vec::new(), entries: Vec::new() }
+    }
+
+    /// Returns the locator of the function.
+    pub const fn locator(&self) -> &Locator<N> {
+        &self.locator
+    }
+
+    /// Returns the locators of the external functions called by the function, in the order they are called.
+    pub fn calls(&self) -> &[Locator<N>] {
+        &self.calls
+    }
+
+    /// Returns the entries of the public inputs, in order.
+    pub fn entries(&self) -> &[PublicInputEntry] {
+        &self.entries
+    }
+
+    /// Returns the total number of public inputs, including the leading `one`.
+    pub fn num_inputs(&self) -> usize {
+        self.entries.last().map_or(0, |entry| entry.range.end)
+    }
+
+    /// Appends an entry with the given name, type, and number of elements.
+    pub(crate) fn push(&mut self, name: impl Into<String>, type_name: impl Into<String>, num_elements: usize) {
+        let start = self.num_inputs();
+        self.entries.push(PublicInputEntry {
+            name: name.into(),
+            type_name: type_name.into(),
+            range: start..start + num_elements,
+        });
+    }
+
+    /// Appends the entries of an input with the given name and type.
+    /// Note: A record input is represented by its serial number and its tag.
+    pub(crate) fn push_input(&mut self, name: &str, input_type: &ValueType<N>) {
+        self.push(format!("{name}.id"), input_type.to_string(), 1);
+        if let ValueType::Record(..) = input_type {
+            self.push(format!("{name}.tag"), input_type.to_string(), 1);
+        }
+    }
+
+    /// Appends the entries of an output with the given name and type.
+    /// Note: A record output is represented by its commitment and its checksum.
+    pub(crate) fn push_output(&mut self, name: &str, output_type: &ValueType<N>) {
+        self.push(format!("{name}.id"), output_type.to_string(), 1);
+        if let ValueType::Record(..) = output_type {
+            self.push(format!("{name}.checksum"), output_type.to_string(), 1);
+        }
+    }
+
+    /// Appends an external call to the given function.
+    pub(crate) fn push_call(&mut self, locator: Locator<N>) {
+        self.calls.push(locator);
+    }
+}
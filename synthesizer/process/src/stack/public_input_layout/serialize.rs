@@ -0,0 +1,68 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+impl Serialize for PublicInputEntry {
+    /// Serializes the entry into a JSON object.
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut entry = serializer.serialize_struct("PublicInputEntry", 4)?;
+        entry.serialize_field("name", &self.name)?;
+        entry.serialize_field("type", &self.type_name)?;
+        entry.serialize_field("start", &self.range.start)?;
+        entry.serialize_field("end", &self.range.end)?;
+        entry.end()
+    }
+}
+
+impl<'de> Deserialize<'de> for PublicInputEntry {
+    /// Deserializes the entry from a JSON object.
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        // Parse the entry from a string into a value.
+        let mut entry = serde_json::Value::deserialize(deserializer)?;
+        // Recover the entry.
+        Ok(Self {
+            name: DeserializeExt::take_from_value::<D>(&mut entry, "name")?,
+            type_name: DeserializeExt::take_from_value::<D>(&mut entry, "type")?,
+            range: DeserializeExt::take_from_value::<D>(&mut entry, "start")?
+                ..DeserializeExt::take_from_value::<D>(&mut entry, "end")?,
+        })
+    }
+}
+
+impl<N: Network> Serialize for PublicInputLayout<N> {
+    /// Serializes the layout into a JSON object.
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut layout = serializer.serialize_struct("PublicInputLayout", 4)?;
+        layout.serialize_field("locator", &self.locator)?;
+        layout.serialize_field("calls", &self.calls)?;
+        layout.serialize_field("num_inputs", &self.num_inputs())?;
+        layout.serialize_field("entries", &self.entries)?;
+        layout.end()
+    }
+}
+
+impl<'de, N: Network> Deserialize<'de> for PublicInputLayout<N> {
+    /// Deserializes the layout from a JSON object.
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        // Parse the layout from a string into a value.
+        let mut layout = serde_json::Value::deserialize(deserializer)?;
+        // Recover the layout.
+        Ok(Self {
+            locator: DeserializeExt::take_from_value::<D>(&mut layout, "locator")?,
+            calls: DeserializeExt::take_from_value::<D>(&mut layout, "calls")?,
+            entries: DeserializeExt::take_from_value::<D>(&mut layout, "entries")?,
+        })
+    }
+}
@@ -588,6 +588,7 @@ impl<N: Network> RegisterTypes<N> {
                 ),
                 _ => bail!("Instruction '{instruction}' is not for opcode '{opcode}'."),
             },
+            Opcode::Merkle(opcode) => Self::check_merkle_opcode(opcode, instruction)?,
             Opcode::Sign => {
                 // Ensure the instruction has one destination register.
                 ensure!(
@@ -783,6 +784,14 @@ impl<N: Network> RegisterTypes<N> {
                 matches!(instruction, Instruction::HashSha3_512(..)),
                 "Instruction '{instruction}' is not for opcode '{opcode}'."
             ),
+            "hash.sha256" => ensure!(
+                matches!(instruction, Instruction::HashSha256(..)),
+                "Instruction '{instruction}' is not for opcode '{opcode}'."
+            ),
+            "hash.blake2s" => ensure!(
+                matches!(instruction, Instruction::HashBlake2s(..)),
+                "Instruction '{instruction}' is not for opcode '{opcode}'."
+            ),
             "hash_many.psd2" => ensure!(
                 matches!(instruction, Instruction::HashManyPSD2(..)),
                 "Instruction '{instruction}' is not for opcode '{opcode}'."
@@ -799,4 +808,42 @@ impl<N: Network> RegisterTypes<N> {
         }
         Ok(())
     }
+
+    /// Ensures the opcode is a valid opcode and corresponds to the `merkle.verify` instruction.
+    #[inline]
+    pub(crate) fn check_merkle_opcode(opcode: &str, instruction: &Instruction<N>) -> Result<()> {
+        // Ensure the instruction is the correct one.
+        match opcode {
+            "merkle.verify.bhp256" => ensure!(
+                matches!(instruction, Instruction::MerkleVerifyBHP256(..)),
+                "Instruction '{instruction}' is not for opcode '{opcode}'."
+            ),
+            "merkle.verify.bhp512" => ensure!(
+                matches!(instruction, Instruction::MerkleVerifyBHP512(..)),
+                "Instruction '{instruction}' is not for opcode '{opcode}'."
+            ),
+            "merkle.verify.bhp768" => ensure!(
+                matches!(instruction, Instruction::MerkleVerifyBHP768(..)),
+                "Instruction '{instruction}' is not for opcode '{opcode}'."
+            ),
+            "merkle.verify.bhp1024" => ensure!(
+                matches!(instruction, Instruction::MerkleVerifyBHP1024(..)),
+                "Instruction '{instruction}' is not for opcode '{opcode}'."
+            ),
+            "merkle.verify.psd2" => ensure!(
+                matches!(instruction, Instruction::MerkleVerifyPSD2(..)),
+                "Instruction '{instruction}' is not for opcode '{opcode}'."
+            ),
+            "merkle.verify.psd4" => ensure!(
+                matches!(instruction, Instruction::MerkleVerifyPSD4(..)),
+                "Instruction '{instruction}' is not for opcode '{opcode}'."
+            ),
+            "merkle.verify.psd8" => ensure!(
+                matches!(instruction, Instruction::MerkleVerifyPSD8(..)),
+                "Instruction '{instruction}' is not for opcode '{opcode}'."
+            ),
+            _ => bail!("Instruction '{instruction}' is not for opcode '{opcode}'."),
+        }
+        Ok(())
+    }
 }
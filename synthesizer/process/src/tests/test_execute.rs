@@ -16,6 +16,7 @@ use crate::{
     traits::{StackEvaluate, StackExecute},
     CallStack,
     Process,
+    PublicInputLayout,
     Stack,
     Trace,
 };
@@ -571,6 +572,50 @@ function hello_world:
     process.synthesize_key::<CurrentAleo, _>(program.id(), &function_name, &mut TestRng::default()).unwrap();
 }
 
+#[test]
+fn test_process_rejects_keys_of_another_circuit() {
+    // Initialize a new program.
+    let program = Program::<CurrentNetwork>::from_str(
+        r#"program testing.aleo;
+
+function add_u32:
+    input r0 as u32.public;
+    input r1 as u32.private;
+    add r0 r1 into r2;
+    output r2 as u32.private;
+
+function mul_u32:
+    input r0 as u32.public;
+    input r1 as u32.private;
+    mul r0 r1 into r2;
+    output r2 as u32.private;
+"#,
+    )
+    .unwrap();
+
+    // Declare the function names.
+    let add_u32 = Identifier::from_str("add_u32").unwrap();
+    let mul_u32 = Identifier::from_str("mul_u32").unwrap();
+
+    // Construct the process, and synthesize the keys of both functions.
+    let process = crate::test_helpers::sample_process(&program);
+    let rng = &mut TestRng::default();
+    process.synthesize_key::<CurrentAleo, _>(program.id(), &add_u32, rng).unwrap();
+    process.synthesize_key::<CurrentAleo, _>(program.id(), &mul_u32, rng).unwrap();
+
+    // Ensure the keys of one function are rejected for the other, as their circuits differ.
+    let proving_key = process.get_proving_key(program.id(), mul_u32).unwrap();
+    let verifying_key = process.get_verifying_key(program.id(), mul_u32).unwrap();
+    assert!(process.insert_proving_key(program.id(), &add_u32, proving_key).is_err());
+    assert!(process.insert_verifying_key(program.id(), &add_u32, verifying_key).is_err());
+
+    // Ensure the keys of the function itself are accepted.
+    let proving_key = process.get_proving_key(program.id(), add_u32).unwrap();
+    let verifying_key = process.get_verifying_key(program.id(), add_u32).unwrap();
+    assert!(process.insert_proving_key(program.id(), &add_u32, proving_key).is_ok());
+    assert!(process.insert_verifying_key(program.id(), &add_u32, verifying_key).is_ok());
+}
+
 #[test]
 fn test_process_multirecords() {
     // Initialize a new program.
@@ -1171,12 +1216,23 @@ function transfer:
     assert_eq!(authorization.len(), 5);
 
     // Execute the request.
-    let (response, _trace) = process.execute::<CurrentAleo, _>(authorization, rng).unwrap();
+    let (response, trace) = process.execute::<CurrentAleo, _>(authorization, rng).unwrap();
     let candidate = response.outputs();
     assert_eq!(2, candidate.len());
     assert_eq!(output_a, candidate[0]);
     assert_eq!(output_b, candidate[1]);
 
+    // Ensure the public input layout of each call matches the public variables of its circuit.
+    for metrics in trace.call_metrics() {
+        let layout =
+            process.get_stack(metrics.program_id).unwrap().public_input_layout(&metrics.function_name).unwrap();
+        assert_eq!(metrics.num_public, layout.num_inputs() as u64);
+    }
+    // Ensure the layout of the root call includes its external calls to functions.
+    let layout = process.get_stack(program1.id()).unwrap().public_input_layout(&function_name).unwrap();
+    assert_eq!(4, layout.calls().len());
+    assert_eq!(layout, PublicInputLayout::from_str(&layout.to_string()).unwrap());
+
     // use circuit::Environment;
     //
     // assert_eq!(6427, CurrentAleo::num_constants());
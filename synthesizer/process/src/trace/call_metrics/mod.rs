@@ -25,4 +25,7 @@ pub struct CallMetrics<N: Network> {
     pub num_request_constraints: u64,
     pub num_function_constraints: u64,
     pub num_response_constraints: u64,
+    pub num_public: u64,
+    pub num_private: u64,
+    pub num_nonzeros: (u64, u64, u64),
 }
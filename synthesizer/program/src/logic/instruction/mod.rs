@@ -201,6 +201,33 @@ pub enum Instruction<N: Network> {
     Ternary(Ternary<N>),
     /// Performs a bitwise `xor` on `first` and `second`, storing the outcome in `destination`.
     Xor(Xor<N>),
+    /// Performs a SHA-256 hash, outputting 256 bits.
+    /// Note: This variant is appended to preserve the opcode indices of the existing instructions.
+    HashSha256(HashSha256<N>),
+    /// Performs a BLAKE2s hash, outputting 256 bits.
+    /// Note: This variant is appended to preserve the opcode indices of the existing instructions.
+    HashBlake2s(HashBlake2s<N>),
+    /// Verifies a Merkle path to `root` for `leaf` at `index` using BHP256, storing the outcome in `destination`.
+    /// Note: This variant is appended to preserve the opcode indices of the existing instructions.
+    MerkleVerifyBHP256(MerkleVerifyBHP256<N>),
+    /// Verifies a Merkle path to `root` for `leaf` at `index` using BHP512, storing the outcome in `destination`.
+    /// Note: This variant is appended to preserve the opcode indices of the existing instructions.
+    MerkleVerifyBHP512(MerkleVerifyBHP512<N>),
+    /// Verifies a Merkle path to `root` for `leaf` at `index` using BHP768, storing the outcome in `destination`.
+    /// Note: This variant is appended to preserve the opcode indices of the existing instructions.
+    MerkleVerifyBHP768(MerkleVerifyBHP768<N>),
+    /// Verifies a Merkle path to `root` for `leaf` at `index` using BHP1024, storing the outcome in `destination`.
+    /// Note: This variant is appended to preserve the opcode indices of the existing instructions.
+    MerkleVerifyBHP1024(MerkleVerifyBHP1024<N>),
+    /// Verifies a Merkle path to `root` for `leaf` at `index` using Poseidon2, storing the outcome in `destination`.
+    /// Note: This variant is appended to preserve the opcode indices of the existing instructions.
+    MerkleVerifyPSD2(MerkleVerifyPSD2<N>),
+    /// Verifies a Merkle path to `root` for `leaf` at `index` using Poseidon4, storing the outcome in `destination`.
+    /// Note: This variant is appended to preserve the opcode indices of the existing instructions.
+    MerkleVerifyPSD4(MerkleVerifyPSD4<N>),
+    /// Verifies a Merkle path to `root` for `leaf` at `index` using Poseidon8, storing the outcome in `destination`.
+    /// Note: This variant is appended to preserve the opcode indices of the existing instructions.
+    MerkleVerifyPSD8(MerkleVerifyPSD8<N>),
 }
 
 /// Creates a match statement that applies the given operation for each instruction.
@@ -300,6 +327,15 @@ macro_rules! instruction {
             SubWrapped,
             Ternary,
             Xor,
+            HashSha256,
+            HashBlake2s,
+            MerkleVerifyBHP256,
+            MerkleVerifyBHP512,
+            MerkleVerifyBHP768,
+            MerkleVerifyBHP1024,
+            MerkleVerifyPSD2,
+            MerkleVerifyPSD4,
+            MerkleVerifyPSD8,
         }}
     };
     // A variant **without** curly braces:
@@ -469,7 +505,7 @@ mod tests {
     fn test_opcodes() {
         // Sanity check the number of instructions is unchanged.
         assert_eq!(
-            68,
+            77,
             Instruction::<CurrentNetwork>::OPCODES.len(),
             "Update me if the number of instructions changes."
         );
@@ -35,6 +35,8 @@ pub enum Opcode {
     Is(&'static str),
     /// The opcode is for a literal operation (i.e. `add`).
     Literal(&'static str),
+    /// The opcode is for a Merkle path verification operation (i.e. `merkle.verify.psd4`).
+    Merkle(&'static str),
     /// The opcode is for signature verification (i.e. `sign.verify`).
     Sign,
 }
@@ -54,6 +56,7 @@ impl Deref for Opcode {
             Opcode::Hash(opcode) => opcode,
             Opcode::Is(opcode) => opcode,
             Opcode::Literal(opcode) => opcode,
+            Opcode::Merkle(opcode) => opcode,
             Opcode::Sign => &"sign.verify",
         }
     }
@@ -79,6 +82,7 @@ impl Display for Opcode {
             Self::Hash(opcode) => write!(f, "{opcode}"),
             Self::Is(opcode) => write!(f, "{opcode}"),
             Self::Literal(opcode) => write!(f, "{opcode}"),
+            Self::Merkle(opcode) => write!(f, "{opcode}"),
             Self::Sign => write!(f, "{}", self.deref()),
         }
     }
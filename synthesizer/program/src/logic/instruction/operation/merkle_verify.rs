@@ -0,0 +1,574 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+use crate::{
+    traits::{RegistersLoad, RegistersLoadCircuit, RegistersStore, RegistersStoreCircuit, StackMatches, StackProgram},
+    Opcode,
+    Operand,
+};
+use console::{
+    network::prelude::*,
+    program::{Literal, LiteralType, Plaintext, PlaintextType, Register, RegisterType, Value},
+    types::{Boolean, Field},
+};
+
+/// Verifies a Merkle path whose leaf and nodes are hashed with BHP256.
+pub type MerkleVerifyBHP256<N> = MerkleVerify<N, { MerkleVerifier::MerkleVerifyBHP256 as u8 }>;
+/// Verifies a Merkle path whose leaf and nodes are hashed with BHP512.
+pub type MerkleVerifyBHP512<N> = MerkleVerify<N, { MerkleVerifier::MerkleVerifyBHP512 as u8 }>;
+/// Verifies a Merkle path whose leaf and nodes are hashed with BHP768.
+pub type MerkleVerifyBHP768<N> = MerkleVerify<N, { MerkleVerifier::MerkleVerifyBHP768 as u8 }>;
+/// Verifies a Merkle path whose leaf and nodes are hashed with BHP1024.
+pub type MerkleVerifyBHP1024<N> = MerkleVerify<N, { MerkleVerifier::MerkleVerifyBHP1024 as u8 }>;
+
+/// Verifies a Merkle path whose leaf and nodes are hashed with Poseidon2.
+pub type MerkleVerifyPSD2<N> = MerkleVerify<N, { MerkleVerifier::MerkleVerifyPSD2 as u8 }>;
+/// Verifies a Merkle path whose leaf and nodes are hashed with Poseidon4.
+pub type MerkleVerifyPSD4<N> = MerkleVerify<N, { MerkleVerifier::MerkleVerifyPSD4 as u8 }>;
+/// Verifies a Merkle path whose leaf and nodes are hashed with Poseidon8.
+pub type MerkleVerifyPSD8<N> = MerkleVerify<N, { MerkleVerifier::MerkleVerifyPSD8 as u8 }>;
+
+enum MerkleVerifier {
+    MerkleVerifyBHP256,
+    MerkleVerifyBHP512,
+    MerkleVerifyBHP768,
+    MerkleVerifyBHP1024,
+    MerkleVerifyPSD2,
+    MerkleVerifyPSD4,
+    MerkleVerifyPSD8,
+}
+
+/// Returns the number of leaf index bits consumed by each level of a Merkle path with the given arity.
+fn bits_per_level(arity: usize) -> Result<usize> {
+    match arity {
+        2 => Ok(1),
+        4 => Ok(2),
+        8 => Ok(3),
+        _ => bail!("Merkle paths must have an arity of 2, 4, or 8, found an arity of {arity}"),
+    }
+}
+
+/// Computes whether `leaf` at `index` is included under `root`, given the `siblings` at each level of the path.
+///
+/// The siblings are given as `[[field; ARITY - 1]; DEPTH]`, ordered from the leaf level up to the root.
+/// Leaves are hashed with a `0` prefix and nodes with a `1` prefix, matching `KaryMerkleTree`.
+#[derive(Clone, PartialEq, Eq, Hash)]
+pub struct MerkleVerify<N: Network, const VARIANT: u8> {
+    /// The operands as `root`, `leaf`, `index`, and `siblings`.
+    operands: Vec<Operand<N>>,
+    /// The destination register.
+    destination: Register<N>,
+}
+
+impl<N: Network, const VARIANT: u8> MerkleVerify<N, VARIANT> {
+    /// Initializes a new `merkle.verify` instruction.
+    #[inline]
+    pub fn new(operands: Vec<Operand<N>>, destination: Register<N>) -> Result<Self> {
+        // Sanity check the number of operands.
+        ensure!(operands.len() == 4, "Instruction '{}' must have four operands", Self::opcode());
+        // Return the instruction.
+        Ok(Self { operands, destination })
+    }
+
+    /// Returns the opcode.
+    #[inline]
+    pub const fn opcode() -> Opcode {
+        match VARIANT {
+            0 => Opcode::Merkle("merkle.verify.bhp256"),
+            1 => Opcode::Merkle("merkle.verify.bhp512"),
+            2 => Opcode::Merkle("merkle.verify.bhp768"),
+            3 => Opcode::Merkle("merkle.verify.bhp1024"),
+            4 => Opcode::Merkle("merkle.verify.psd2"),
+            5 => Opcode::Merkle("merkle.verify.psd4"),
+            6 => Opcode::Merkle("merkle.verify.psd8"),
+            7.. => panic!("Invalid 'merkle.verify' instruction opcode"),
+        }
+    }
+
+    /// Returns the operands in the operation.
+    #[inline]
+    pub fn operands(&self) -> &[Operand<N>] {
+        // Sanity check that there are exactly four operands.
+        debug_assert!(self.operands.len() == 4, "Instruction '{}' must have four operands", Self::opcode());
+        // Return the operands.
+        &self.operands
+    }
+
+    /// Returns the destination register.
+    #[inline]
+    pub fn destinations(&self) -> Vec<Register<N>> {
+        vec![self.destination.clone()]
+    }
+}
+
+impl<N: Network, const VARIANT: u8> MerkleVerify<N, VARIANT> {
+    /// Evaluates the instruction.
+    #[inline]
+    pub fn evaluate(
+        &self,
+        stack: &(impl StackMatches<N> + StackProgram<N>),
+        registers: &mut (impl RegistersLoad<N> + RegistersStore<N>),
+    ) -> Result<()> {
+        // Ensure the number of operands is correct.
+        if self.operands.len() != 4 {
+            bail!("Instruction '{}' expects 4 operands, found {} operands", Self::opcode(), self.operands.len())
+        }
+
+        // Retrieve the inputs.
+        let root = match registers.load_literal(stack, &self.operands[0])? {
+            Literal::Field(root) => root,
+            _ => bail!("Expected the first operand to be a field."),
+        };
+        let leaf = registers.load(stack, &self.operands[1])?;
+        let index = match registers.load_literal(stack, &self.operands[2])? {
+            Literal::U64(index) => index,
+            _ => bail!("Expected the third operand to be a u64."),
+        };
+        let siblings = match registers.load(stack, &self.operands[3])? {
+            Value::Plaintext(Plaintext::Array(levels, _)) => levels
+                .into_iter()
+                .map(|level| match level {
+                    Plaintext::Array(nodes, _) => nodes
+                        .into_iter()
+                        .map(|node| match node {
+                            Plaintext::Literal(Literal::Field(node), _) => Ok(node),
+                            _ => bail!("Expected the Merkle path siblings to be fields."),
+                        })
+                        .collect::<Result<Vec<_>>>(),
+                    _ => bail!("Expected the fourth operand to be an array of field arrays."),
+                })
+                .collect::<Result<Vec<_>>>()?,
+            _ => bail!("Expected the fourth operand to be an array of field arrays."),
+        };
+
+        // Determine the arity of the path, and ensure every level has the same number of siblings.
+        let arity = siblings.first().map_or(0, |level| level.len() + 1);
+        ensure!(siblings.iter().all(|level| level.len() + 1 == arity), "Merkle path levels must have equal arity");
+        let bits_per_level = bits_per_level(arity)?;
+
+        // Retrieve the leaf index bits, padded to cover every level of the path.
+        let mut index_bits = index.to_bits_le();
+        index_bits.resize(index_bits.len().max(siblings.len() * bits_per_level), false);
+
+        // Hash the leaf.
+        let mut current = Self::hash_leaf(&leaf)?;
+        // Hash each level of the path, placing the current node at the position given by the leaf index.
+        for (level, level_siblings) in siblings.iter().enumerate() {
+            let position = index_bits[level * bits_per_level..(level + 1) * bits_per_level]
+                .iter()
+                .rev()
+                .fold(0usize, |position, bit| (position << 1) | *bit as usize);
+            let mut children = level_siblings.clone();
+            children.insert(position, current);
+            current = Self::hash_children(&children)?;
+        }
+
+        // Ensure the leaf index does not exceed the number of leaves in the tree.
+        let is_in_range = index_bits[siblings.len() * bits_per_level..].iter().all(|bit| !bit);
+
+        // Store the output.
+        let output = Literal::Boolean(Boolean::new(is_in_range && current == root));
+        registers.store_literal(stack, &self.destination, output)
+    }
+
+    /// Executes the instruction.
+    #[inline]
+    pub fn execute<A: circuit::Aleo<Network = N>>(
+        &self,
+        stack: &(impl StackMatches<N> + StackProgram<N>),
+        registers: &mut (impl RegistersLoadCircuit<N, A> + RegistersStoreCircuit<N, A>),
+    ) -> Result<()> {
+        use circuit::{
+            traits::{Equal, Ternary, ToBits},
+            Inject,
+        };
+
+        // Ensure the number of operands is correct.
+        if self.operands.len() != 4 {
+            bail!("Instruction '{}' expects 4 operands, found {} operands", Self::opcode(), self.operands.len())
+        }
+
+        // Retrieve the inputs.
+        let root = match registers.load_literal_circuit(stack, &self.operands[0])? {
+            circuit::Literal::Field(root) => root,
+            _ => bail!("Expected the first operand to be a field."),
+        };
+        let leaf = registers.load_circuit(stack, &self.operands[1])?;
+        let index = match registers.load_literal_circuit(stack, &self.operands[2])? {
+            circuit::Literal::U64(index) => index,
+            _ => bail!("Expected the third operand to be a u64."),
+        };
+        let siblings = match registers.load_circuit(stack, &self.operands[3])? {
+            circuit::Value::Plaintext(circuit::Plaintext::Array(levels, _)) => levels
+                .into_iter()
+                .map(|level| match level {
+                    circuit::Plaintext::Array(nodes, _) => nodes
+                        .into_iter()
+                        .map(|node| match node {
+                            circuit::Plaintext::Literal(circuit::Literal::Field(node), _) => Ok(node),
+                            _ => bail!("Expected the Merkle path siblings to be fields."),
+                        })
+                        .collect::<Result<Vec<_>>>(),
+                    _ => bail!("Expected the fourth operand to be an array of field arrays."),
+                })
+                .collect::<Result<Vec<_>>>()?,
+            _ => bail!("Expected the fourth operand to be an array of field arrays."),
+        };
+
+        // Determine the arity of the path, and ensure every level has the same number of siblings.
+        let arity = siblings.first().map_or(0, |level| level.len() + 1);
+        ensure!(siblings.iter().all(|level| level.len() + 1 == arity), "Merkle path levels must have equal arity");
+        let bits_per_level = bits_per_level(arity)?;
+
+        // Retrieve the leaf index bits, padded to cover every level of the path.
+        let mut index_bits = index.to_bits_le();
+        index_bits.resize(index_bits.len().max(siblings.len() * bits_per_level), circuit::Boolean::constant(false));
+
+        // Hash the leaf.
+        let mut current = Self::hash_leaf_circuit::<A>(&leaf)?;
+        // Hash each level of the path, placing the current node at the position given by the leaf index.
+        for (level, level_siblings) in siblings.iter().enumerate() {
+            let position_bits = &index_bits[level * bits_per_level..(level + 1) * bits_per_level];
+
+            // Select each child from the current node and the siblings, using the position bits directly
+            // rather than recovering the position as an integer.
+            let mut children = Vec::with_capacity(arity);
+            // Tracks whether the current node is at a position before the child being selected.
+            let mut is_before = circuit::Boolean::constant(false);
+            for j in 0..arity {
+                let is_position =
+                    position_bits.iter().enumerate().fold(circuit::Boolean::constant(true), |is_position, (i, bit)| {
+                        match (j >> i) & 1 == 1 {
+                            true => is_position & bit,
+                            false => is_position & !bit,
+                        }
+                    });
+                let sibling = match j {
+                    0 => level_siblings[0].clone(),
+                    j if j == arity - 1 => level_siblings[j - 1].clone(),
+                    j => circuit::Field::ternary(&is_before, &level_siblings[j - 1], &level_siblings[j]),
+                };
+                children.push(circuit::Field::ternary(&is_position, &current, &sibling));
+                is_before = is_before | is_position;
+            }
+            current = Self::hash_children_circuit::<A>(&children)?;
+        }
+
+        // Ensure the leaf index does not exceed the number of leaves in the tree.
+        let is_in_range = index_bits[siblings.len() * bits_per_level..]
+            .iter()
+            .fold(circuit::Boolean::constant(true), |is_in_range, bit| is_in_range & !bit);
+
+        // Store the output.
+        let output = circuit::Literal::Boolean(root.is_equal(&current) & is_in_range);
+        registers.store_literal_circuit(stack, &self.destination, output)
+    }
+
+    /// Finalizes the instruction.
+    #[inline]
+    pub fn finalize(
+        &self,
+        stack: &(impl StackMatches<N> + StackProgram<N>),
+        registers: &mut (impl RegistersLoad<N> + RegistersStore<N>),
+    ) -> Result<()> {
+        self.evaluate(stack, registers)
+    }
+
+    /// Returns the output type from the given program and input types.
+    #[inline]
+    pub fn output_types(
+        &self,
+        _stack: &impl StackProgram<N>,
+        input_types: &[RegisterType<N>],
+    ) -> Result<Vec<RegisterType<N>>> {
+        // Ensure the number of input types is correct.
+        if input_types.len() != 4 {
+            bail!("Instruction '{}' expects 4 inputs, found {} inputs", Self::opcode(), input_types.len())
+        }
+
+        // Ensure the first operand is a field.
+        if input_types[0] != RegisterType::Plaintext(PlaintextType::Literal(LiteralType::Field)) {
+            bail!(
+                "Instruction '{}' expects the first input to be a 'field'. Found input of type '{}'",
+                Self::opcode(),
+                input_types[0]
+            )
+        }
+
+        // Ensure the third operand is a u64.
+        if input_types[2] != RegisterType::Plaintext(PlaintextType::Literal(LiteralType::U64)) {
+            bail!(
+                "Instruction '{}' expects the third input to be a 'u64'. Found input of type '{}'",
+                Self::opcode(),
+                input_types[2]
+            )
+        }
+
+        // Ensure the fourth operand is an array of field arrays, with 1, 3, or 7 siblings per level.
+        let is_valid_siblings_type = match &input_types[3] {
+            RegisterType::Plaintext(PlaintextType::Array(levels)) => match levels.next_element_type() {
+                PlaintextType::Array(level) => {
+                    level.next_element_type() == &PlaintextType::Literal(LiteralType::Field)
+                        && bits_per_level(**level.length() as usize + 1).is_ok()
+                }
+                _ => false,
+            },
+            _ => false,
+        };
+        if !is_valid_siblings_type {
+            bail!(
+                "Instruction '{}' expects the fourth input to be '[[field; 1u32]; N]', '[[field; 3u32]; N]', or '[[field; 7u32]; N]'. Found input of type '{}'",
+                Self::opcode(),
+                input_types[3]
+            )
+        }
+
+        Ok(vec![RegisterType::Plaintext(PlaintextType::Literal(LiteralType::Boolean))])
+    }
+}
+
+impl<N: Network, const VARIANT: u8> MerkleVerify<N, VARIANT> {
+    /// Returns the hash of the given leaf, prefixed to separate it from the nodes.
+    fn hash_leaf(leaf: &Value<N>) -> Result<Field<N>> {
+        match VARIANT {
+            0..=3 => Self::hash_bits(&[vec![false], leaf.to_bits_le()].concat()),
+            4..=6 => Self::hash_fields(&[vec![Field::zero()], leaf.to_fields()?].concat()),
+            7.. => bail!("Invalid 'merkle.verify' variant: {VARIANT}"),
+        }
+    }
+
+    /// Returns the hash of the given children, prefixed to separate them from the leaves.
+    fn hash_children(children: &[Field<N>]) -> Result<Field<N>> {
+        match VARIANT {
+            0..=3 => {
+                let mut input = vec![true];
+                children.iter().for_each(|child| child.write_bits_le(&mut input));
+                Self::hash_bits(&input)
+            }
+            4..=6 => Self::hash_fields(&[&[Field::one()][..], children].concat()),
+            7.. => bail!("Invalid 'merkle.verify' variant: {VARIANT}"),
+        }
+    }
+
+    /// Returns the BHP hash of the given bits.
+    fn hash_bits(input: &[bool]) -> Result<Field<N>> {
+        match VARIANT {
+            0 => N::hash_bhp256(input),
+            1 => N::hash_bhp512(input),
+            2 => N::hash_bhp768(input),
+            3 => N::hash_bhp1024(input),
+            _ => bail!("Invalid 'merkle.verify' variant: {VARIANT}"),
+        }
+    }
+
+    /// Returns the Poseidon hash of the given field elements.
+    fn hash_fields(input: &[Field<N>]) -> Result<Field<N>> {
+        match VARIANT {
+            4 => N::hash_psd2(input),
+            5 => N::hash_psd4(input),
+            6 => N::hash_psd8(input),
+            _ => bail!("Invalid 'merkle.verify' variant: {VARIANT}"),
+        }
+    }
+
+    /// Returns the hash of the given leaf in the circuit, prefixed to separate it from the nodes.
+    fn hash_leaf_circuit<A: circuit::Aleo<Network = N>>(leaf: &circuit::Value<A>) -> Result<circuit::Field<A>> {
+        use circuit::{
+            traits::{ToBits, ToFields, Zero},
+            Inject,
+        };
+
+        match VARIANT {
+            0..=3 => {
+                Self::hash_bits_circuit::<A>(&[vec![circuit::Boolean::constant(false)], leaf.to_bits_le()].concat())
+            }
+            4..=6 => Self::hash_fields_circuit::<A>(&[vec![circuit::Field::zero()], leaf.to_fields()].concat()),
+            7.. => bail!("Invalid 'merkle.verify' variant: {VARIANT}"),
+        }
+    }
+
+    /// Returns the hash of the given children in the circuit, prefixed to separate them from the leaves.
+    fn hash_children_circuit<A: circuit::Aleo<Network = N>>(
+        children: &[circuit::Field<A>],
+    ) -> Result<circuit::Field<A>> {
+        use circuit::{
+            traits::{One, ToBits},
+            Inject,
+        };
+
+        match VARIANT {
+            0..=3 => {
+                let mut input = vec![circuit::Boolean::constant(true)];
+                children.iter().for_each(|child| child.write_bits_le(&mut input));
+                Self::hash_bits_circuit::<A>(&input)
+            }
+            4..=6 => Self::hash_fields_circuit::<A>(&[&[circuit::Field::one()][..], children].concat()),
+            7.. => bail!("Invalid 'merkle.verify' variant: {VARIANT}"),
+        }
+    }
+
+    /// Returns the BHP hash of the given bits in the circuit.
+    fn hash_bits_circuit<A: circuit::Aleo<Network = N>>(input: &[circuit::Boolean<A>]) -> Result<circuit::Field<A>> {
+        match VARIANT {
+            0 => Ok(A::hash_bhp256(input)),
+            1 => Ok(A::hash_bhp512(input)),
+            2 => Ok(A::hash_bhp768(input)),
+            3 => Ok(A::hash_bhp1024(input)),
+            _ => bail!("Invalid 'merkle.verify' variant: {VARIANT}"),
+        }
+    }
+
+    /// Returns the Poseidon hash of the given field elements in the circuit.
+    fn hash_fields_circuit<A: circuit::Aleo<Network = N>>(input: &[circuit::Field<A>]) -> Result<circuit::Field<A>> {
+        match VARIANT {
+            4 => Ok(A::hash_psd2(input)),
+            5 => Ok(A::hash_psd4(input)),
+            6 => Ok(A::hash_psd8(input)),
+            _ => bail!("Invalid 'merkle.verify' variant: {VARIANT}"),
+        }
+    }
+}
+
+impl<N: Network, const VARIANT: u8> Parser for MerkleVerify<N, VARIANT> {
+    /// Parses a string into an operation.
+    #[inline]
+    fn parse(string: &str) -> ParserResult<Self> {
+        // Parse the opcode from the string.
+        let (string, _) = tag(*Self::opcode())(string)?;
+        // Parse the whitespace from the string.
+        let (string, _) = Sanitizer::parse_whitespaces(string)?;
+        // Parse the first operand from the string.
+        let (string, first) = Operand::parse(string)?;
+        // Parse the whitespace from the string.
+        let (string, _) = Sanitizer::parse_whitespaces(string)?;
+        // Parse the second operand from the string.
+        let (string, second) = Operand::parse(string)?;
+        // Parse the whitespace from the string.
+        let (string, _) = Sanitizer::parse_whitespaces(string)?;
+        // Parse the third operand from the string.
+        let (string, third) = Operand::parse(string)?;
+        // Parse the whitespace from the string.
+        let (string, _) = Sanitizer::parse_whitespaces(string)?;
+        // Parse the fourth operand from the string.
+        let (string, fourth) = Operand::parse(string)?;
+        // Parse the whitespace from the string.
+        let (string, _) = Sanitizer::parse_whitespaces(string)?;
+        // Parse the "into" from the string.
+        let (string, _) = tag("into")(string)?;
+        // Parse the whitespace from the string.
+        let (string, _) = Sanitizer::parse_whitespaces(string)?;
+        // Parse the destination register from the string.
+        let (string, destination) = Register::parse(string)?;
+
+        Ok((string, Self { operands: vec![first, second, third, fourth], destination }))
+    }
+}
+
+impl<N: Network, const VARIANT: u8> FromStr for MerkleVerify<N, VARIANT> {
+    type Err = Error;
+
+    /// Parses a string into an operation.
+    #[inline]
+    fn from_str(string: &str) -> Result<Self> {
+        match Self::parse(string) {
+            Ok((remainder, object)) => {
+                // Ensure the remainder is empty.
+                ensure!(remainder.is_empty(), "Failed to parse string. Found invalid character in: \"{remainder}\"");
+                // Return the object.
+                Ok(object)
+            }
+            Err(error) => bail!("Failed to parse string. {error}"),
+        }
+    }
+}
+
+impl<N: Network, const VARIANT: u8> Debug for MerkleVerify<N, VARIANT> {
+    /// Prints the operation as a string.
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        Display::fmt(self, f)
+    }
+}
+
+impl<N: Network, const VARIANT: u8> Display for MerkleVerify<N, VARIANT> {
+    /// Prints the operation to a string.
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        // Ensure the number of operands is 4.
+        if self.operands.len() != 4 {
+            return Err(fmt::Error);
+        }
+        // Print the operation.
+        write!(f, "{} ", Self::opcode())?;
+        self.operands.iter().try_for_each(|operand| write!(f, "{operand} "))?;
+        write!(f, "into {}", self.destination)
+    }
+}
+
+impl<N: Network, const VARIANT: u8> FromBytes for MerkleVerify<N, VARIANT> {
+    /// Reads the operation from a buffer.
+    fn read_le<R: Read>(mut reader: R) -> IoResult<Self> {
+        // Initialize the vector for the operands.
+        let mut operands = Vec::with_capacity(4);
+        // Read the operands.
+        for _ in 0..4 {
+            operands.push(Operand::read_le(&mut reader)?);
+        }
+        // Read the destination register.
+        let destination = Register::read_le(&mut reader)?;
+
+        // Return the operation.
+        Ok(Self { operands, destination })
+    }
+}
+
+impl<N: Network, const VARIANT: u8> ToBytes for MerkleVerify<N, VARIANT> {
+    /// Writes the operation to a buffer.
+    fn write_le<W: Write>(&self, mut writer: W) -> IoResult<()> {
+        // Ensure the number of operands is 4.
+        if self.operands.len() != 4 {
+            return Err(error(format!("The number of operands must be 4, found {}", self.operands.len())));
+        }
+        // Write the operands.
+        self.operands.iter().try_for_each(|operand| operand.write_le(&mut writer))?;
+        // Write the destination register.
+        self.destination.write_le(&mut writer)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use console::network::MainnetV0;
+
+    type CurrentNetwork = MainnetV0;
+
+    #[test]
+    fn test_parse() {
+        let (string, is) = MerkleVerifyPSD4::<CurrentNetwork>::parse("merkle.verify.psd4 r0 r1 r2 r3 into r4").unwrap();
+        assert!(string.is_empty(), "Parser did not consume all of the string: '{string}'");
+        assert_eq!(is.operands.len(), 4, "The number of operands is incorrect");
+        assert_eq!(is.operands[0], Operand::Register(Register::Locator(0)), "The first operand is incorrect");
+        assert_eq!(is.operands[1], Operand::Register(Register::Locator(1)), "The second operand is incorrect");
+        assert_eq!(is.operands[2], Operand::Register(Register::Locator(2)), "The third operand is incorrect");
+        assert_eq!(is.operands[3], Operand::Register(Register::Locator(3)), "The fourth operand is incorrect");
+        assert_eq!(is.destination, Register::Locator(4), "The destination register is incorrect");
+    }
+
+    #[test]
+    fn test_bits_per_level() {
+        assert_eq!(bits_per_level(2).unwrap(), 1);
+        assert_eq!(bits_per_level(4).unwrap(), 2);
+        assert_eq!(bits_per_level(8).unwrap(), 3);
+        assert!(bits_per_level(3).is_err());
+        assert!(bits_per_level(16).is_err());
+    }
+}
@@ -38,6 +38,9 @@ pub use literals::*;
 
 mod macros;
 
+mod merkle_verify;
+pub use merkle_verify::*;
+
 mod sign_verify;
 pub use sign_verify::*;
 
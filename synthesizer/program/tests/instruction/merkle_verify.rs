@@ -0,0 +1,222 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use circuit::{AleoV0, Eject, Inject};
+use console::{
+    collections::kary_merkle_tree::{KaryMerklePath, KaryMerkleTree, LeafHash, PathHash},
+    network::{MainnetV0, BHP_512, POSEIDON_4},
+    prelude::*,
+    program::{Identifier, Literal, Plaintext, Register, Value},
+    types::{Field, U64},
+};
+use snarkvm_synthesizer_program::{
+    MerkleVerify,
+    MerkleVerifyBHP512,
+    MerkleVerifyPSD4,
+    Opcode,
+    Operand,
+    Program,
+    RegistersLoad,
+    RegistersLoadCircuit,
+    RegistersStore,
+    RegistersStoreCircuit,
+};
+use synthesizer_process::{Authorization, CallStack, Process, Registers, Stack, StackProgramTypes};
+
+type CurrentNetwork = MainnetV0;
+type CurrentAleo = AleoV0;
+
+/// The number of leaves in each sampled tree, which leaves part of the last level of the tree empty.
+const NUM_LEAVES: usize = 5;
+
+/// Samples the stack for a function that verifies a Merkle path of the given arity and depth.
+fn sample_stack(opcode: Opcode, arity: usize, depth: usize) -> Result<Stack<CurrentNetwork>> {
+    let program = Program::from_str(&format!(
+        "program testing.aleo;
+            function run:
+                input r0 as field.private;
+                input r1 as field.private;
+                input r2 as u64.private;
+                input r3 as [[field; {}u32]; {depth}u32].private;
+                {opcode} r0 r1 r2 r3 into r4;
+                output r4 as boolean.private;
+        ",
+        arity - 1
+    ))?;
+    Stack::new(&Process::load()?, &program)
+}
+
+/// Returns the siblings of a Merkle path as a plaintext array of field arrays.
+fn to_siblings_value(siblings: &[Vec<Field<CurrentNetwork>>]) -> Value<CurrentNetwork> {
+    let to_array = |elements: Vec<Plaintext<CurrentNetwork>>| Plaintext::Array(elements, Default::default());
+    Value::Plaintext(to_array(
+        siblings
+            .iter()
+            .map(|level| to_array(level.iter().map(|node| Plaintext::from(Literal::Field(*node))).collect()))
+            .collect(),
+    ))
+}
+
+/// Samples the registers with the given inputs. Note: Do not replicate this for real program use, it is insecure.
+fn sample_registers(
+    stack: &Stack<CurrentNetwork>,
+    inputs: &[Value<CurrentNetwork>],
+) -> Result<Registers<CurrentNetwork, CurrentAleo>> {
+    // Initialize the registers.
+    let mut registers = Registers::<CurrentNetwork, CurrentAleo>::new(
+        CallStack::evaluate(Authorization::try_from((vec![], vec![]))?)?,
+        stack.get_register_types(&Identifier::from_str("run")?)?.clone(),
+    );
+    // Store each input in the console and circuit registers.
+    for (locator, input) in inputs.iter().enumerate() {
+        let register = Register::Locator(locator as u64);
+        registers.store(stack, &register, input.clone())?;
+        registers.store_circuit(stack, &register, circuit::Value::new(circuit::Mode::Private, input.clone()))?;
+    }
+    Ok(registers)
+}
+
+/// Evaluates and executes the instruction on the given inputs, and returns the output,
+/// after checking that the evaluation and execution agree and that the circuit is satisfied.
+fn check_merkle_verify<const VARIANT: u8>(
+    stack: &Stack<CurrentNetwork>,
+    root: Field<CurrentNetwork>,
+    leaf: &Value<CurrentNetwork>,
+    index: u64,
+    siblings: &[Vec<Field<CurrentNetwork>>],
+) -> bool {
+    // Initialize the operation.
+    let operands = (0..4).map(|locator| Operand::Register(Register::Locator(locator))).collect();
+    let operation = MerkleVerify::<CurrentNetwork, VARIANT>::new(operands, Register::Locator(4)).unwrap();
+    // Initialize the inputs.
+    let inputs = [
+        Value::Plaintext(Plaintext::from(Literal::Field(root))),
+        leaf.clone(),
+        Value::Plaintext(Plaintext::from(Literal::U64(U64::new(index)))),
+        to_siblings_value(siblings),
+    ];
+    let destination = Operand::Register(Register::Locator(4));
+
+    // Evaluate the instruction.
+    let mut evaluate_registers = sample_registers(stack, &inputs).unwrap();
+    operation.evaluate(stack, &mut evaluate_registers).unwrap();
+    let output_a = evaluate_registers.load(stack, &destination).unwrap();
+
+    // Execute the instruction.
+    let mut execute_registers = sample_registers(stack, &inputs).unwrap();
+    operation.execute::<CurrentAleo>(stack, &mut execute_registers).unwrap();
+    let output_b = execute_registers.load_circuit(stack, &destination).unwrap();
+
+    // Check that the outputs are consistent, and that the circuit is satisfied.
+    assert_eq!(output_a, output_b.eject_value(), "The results of the evaluation and execution are inconsistent");
+    assert!(<CurrentAleo as circuit::Environment>::is_satisfied(), "The circuit of '{}' is not satisfied", operation);
+    <CurrentAleo as circuit::Environment>::reset();
+
+    match output_a {
+        Value::Plaintext(Plaintext::Literal(Literal::Boolean(output), _)) => *output,
+        _ => panic!("Expected the output of '{}' to be a boolean", operation),
+    }
+}
+
+/// Checks the instruction against `KaryMerkleTree::verify` for each leaf of a sampled tree,
+/// for an out-of-range leaf index, and for a path with a wrong sibling.
+fn check_kary_merkle_tree<LH, const VARIANT: u8, const DEPTH: u8, const ARITY: u8>(
+    hasher: &LH,
+    to_leaf: impl Fn(&Value<CurrentNetwork>) -> LH::Leaf,
+    rng: &mut TestRng,
+) where
+    LH: LeafHash<Hash = Field<CurrentNetwork>> + PathHash<Hash = Field<CurrentNetwork>>,
+{
+    println!("Checking 'merkle.verify' variant {VARIANT} with an arity of {ARITY} and a depth of {DEPTH}");
+
+    let stack =
+        sample_stack(MerkleVerify::<CurrentNetwork, VARIANT>::opcode(), ARITY as usize, DEPTH as usize).unwrap();
+
+    // Sample the tree.
+    let values = (0..NUM_LEAVES)
+        .map(|_| Value::Plaintext(Plaintext::from(Literal::Field(Uniform::rand(rng)))))
+        .collect::<Vec<_>>();
+    let leaves = values.iter().map(&to_leaf).collect::<Vec<_>>();
+    let tree = KaryMerkleTree::<LH, LH, DEPTH, ARITY>::new(hasher, hasher, &leaves).unwrap();
+    let root = *tree.root();
+
+    for (index, (value, leaf)) in values.iter().zip_eq(&leaves).enumerate() {
+        let path = tree.prove(index, leaf).unwrap();
+        let siblings = path.siblings().to_vec();
+
+        // Check a valid path.
+        assert!(tree.verify(&path, &root, leaf));
+        assert!(check_merkle_verify::<VARIANT>(&stack, root, value, index as u64, &siblings));
+
+        // Check an index past the last leaf of the tree, which selects the same positions on each level.
+        let out_of_range = index as u64 + (ARITY as u64).pow(DEPTH as u32);
+        assert!(!check_merkle_verify::<VARIANT>(&stack, root, value, out_of_range, &siblings));
+
+        // Check a path with a wrong sibling, on the leaf level and on the root level.
+        for level in [0, DEPTH as usize - 1] {
+            let mut siblings = siblings.clone();
+            siblings[level][0] += Field::one();
+            let wrong_path = KaryMerklePath::try_from((index as u64, siblings.clone())).unwrap();
+            assert!(!tree.verify(&wrong_path, &root, leaf));
+            assert!(!check_merkle_verify::<VARIANT>(&stack, root, value, index as u64, &siblings));
+        }
+    }
+
+    // Check a leaf that is not in the tree.
+    let path = tree.prove(0, &leaves[0]).unwrap();
+    let value = Value::Plaintext(Plaintext::from(Literal::Field(Uniform::rand(rng))));
+    assert!(!tree.verify(&path, &root, &to_leaf(&value)));
+    assert!(!check_merkle_verify::<VARIANT>(&stack, root, &value, 0, path.siblings()));
+}
+
+/// Returns the leaf of a Poseidon tree for the given value.
+fn to_field_leaf(value: &Value<CurrentNetwork>) -> Vec<Field<CurrentNetwork>> {
+    value.to_fields().unwrap()
+}
+
+/// Returns the leaf of a BHP tree for the given value.
+fn to_bits_leaf(value: &Value<CurrentNetwork>) -> Vec<bool> {
+    value.to_bits_le()
+}
+
+#[test]
+fn test_merkle_verify_psd4_is_consistent() {
+    const PSD4: u8 = 5;
+
+    let rng = &mut TestRng::default();
+
+    check_kary_merkle_tree::<_, PSD4, 3, 2>(&*POSEIDON_4, to_field_leaf, rng);
+    check_kary_merkle_tree::<_, PSD4, 5, 2>(&*POSEIDON_4, to_field_leaf, rng);
+    check_kary_merkle_tree::<_, PSD4, 2, 4>(&*POSEIDON_4, to_field_leaf, rng);
+    check_kary_merkle_tree::<_, PSD4, 3, 4>(&*POSEIDON_4, to_field_leaf, rng);
+    check_kary_merkle_tree::<_, PSD4, 1, 8>(&*POSEIDON_4, to_field_leaf, rng);
+    check_kary_merkle_tree::<_, PSD4, 2, 8>(&*POSEIDON_4, to_field_leaf, rng);
+
+    // Ensure the variant above is the one for `merkle.verify.psd4`.
+    assert_eq!(MerkleVerifyPSD4::<CurrentNetwork>::opcode(), MerkleVerify::<CurrentNetwork, PSD4>::opcode());
+}
+
+#[test]
+fn test_merkle_verify_bhp512_is_consistent() {
+    const BHP512: u8 = 1;
+
+    let rng = &mut TestRng::default();
+
+    check_kary_merkle_tree::<_, BHP512, 3, 2>(&*BHP_512, to_bits_leaf, rng);
+    check_kary_merkle_tree::<_, BHP512, 2, 4>(&*BHP_512, to_bits_leaf, rng);
+    check_kary_merkle_tree::<_, BHP512, 2, 8>(&*BHP_512, to_bits_leaf, rng);
+
+    // Ensure the variant above is the one for `merkle.verify.bhp512`.
+    assert_eq!(MerkleVerifyBHP512::<CurrentNetwork>::opcode(), MerkleVerify::<CurrentNetwork, BHP512>::opcode());
+}
@@ -161,6 +161,39 @@ mod test {
         assert!(!verifying_key.verify("test", &[one, one + one], &proof));
     }
 
+    #[test]
+    fn test_proving_key_circuit_digest() {
+        let assignment = crate::test_helpers::sample_assignment();
+        let (proving_key, verifying_key) = crate::test_helpers::sample_keys();
+        assert_eq!(Some(assignment.digest().unwrap()), proving_key.circuit_digest());
+        assert_eq!(Some(assignment.digest().unwrap()), verifying_key.circuit_digest());
+
+        // Ensure the circuit digest is preserved in the bytes.
+        let bytes = proving_key.to_bytes_le().unwrap();
+        let candidate = ProvingKey::<CurrentNetwork>::from_bytes_le(&bytes).unwrap();
+        assert_eq!(proving_key.circuit_digest(), candidate.circuit_digest());
+
+        // Ensure the verifying key digest is not written to its bytes, which are part of deployments.
+        let bytes = verifying_key.to_bytes_le().unwrap();
+        let candidate = VerifyingKey::<CurrentNetwork>::from_bytes_le(&bytes).unwrap();
+        assert_eq!(None, candidate.circuit_digest());
+        assert_eq!(verifying_key, candidate);
+
+        // Ensure the keys reject the digest of a different circuit.
+        let different_assignment = {
+            use circuit::{environment::Mode, types::Field, Inject};
+
+            Circuit::reset();
+            let one = console::types::Field::<CurrentNetwork>::one();
+            let _product = Field::<Circuit>::new(Mode::Private, one) * Field::<Circuit>::new(Mode::Private, one);
+            Circuit::eject_assignment_and_reset()
+        };
+        let different_digest = different_assignment.digest().unwrap();
+        assert!(proving_key.ensure_circuit_matches("test", &different_digest).is_err());
+        assert!(verifying_key.ensure_circuit_matches("test", &different_digest).is_err());
+        assert!(proving_key.ensure_circuit_matches("test", &assignment.digest().unwrap()).is_ok());
+    }
+
     #[test]
     fn test_varuna_verify_public_input_size() {
         /// Creates a simple circuit: a * b.
@@ -14,15 +14,16 @@
 
 use super::*;
 
+impl<N: Network> Versioned for Proof<N> {
+    const NAME: &'static str = "proof";
+    const VERSION: u8 = 1;
+}
+
 impl<N: Network> FromBytes for Proof<N> {
     /// Reads the proof from a buffer.
     fn read_le<R: Read>(mut reader: R) -> IoResult<Self> {
-        // Read the version.
-        let version = u8::read_le(&mut reader)?;
-        // Ensure the version is valid.
-        if version != 1 {
-            return Err(error("Invalid proof version"));
-        }
+        // Read the version, and ensure it is valid.
+        Self::read_version(&mut reader)?;
         // Read the proof.
         let proof = FromBytes::read_le(&mut reader)?;
         // Return the proof.
@@ -34,7 +35,7 @@ impl<N: Network> ToBytes for Proof<N> {
     /// Writes the proof to a buffer.
     fn write_le<W: Write>(&self, mut writer: W) -> IoResult<()> {
         // Write the version.
-        1u8.write_le(&mut writer)?;
+        Self::write_version(&mut writer)?;
         // Write the bytes.
         self.proof.write_le(&mut writer)
     }
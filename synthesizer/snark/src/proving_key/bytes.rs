@@ -14,19 +14,37 @@
 
 use super::*;
 
+impl<N: Network> Versioned for ProvingKey<N> {
+    const MIN_VERSION: u8 = 1;
+    const NAME: &'static str = "proving key";
+    const VERSION: u8 = 2;
+}
+
+impl<N: Network> Migrate for ProvingKey<N> {
+    /// Reads a proving key written before the circuit digest was introduced.
+    fn migrate<R: Read>(version: u8, mut reader: R) -> IoResult<Self> {
+        match version {
+            // Version 1 stores the proving key alone.
+            1 => Ok(Self::new(Arc::new(FromBytes::read_le(&mut reader)?))),
+            _ => Err(error(format!("Unreachable proving key version {version}"))),
+        }
+    }
+}
+
 impl<N: Network> FromBytes for ProvingKey<N> {
     /// Reads the proving key from a buffer.
-    fn read_le<R: Read>(mut reader: R) -> IoResult<Self> {
-        // Read the version.
-        let version = u8::read_le(&mut reader)?;
-        // Ensure the version is valid.
-        if version != 1 {
-            return Err(error("Invalid proving key version"));
-        }
-        // Read the proving key.
-        let proving_key = Arc::new(FromBytes::read_le(&mut reader)?);
-        // Return the proving key.
-        Ok(Self { proving_key })
+    fn read_le<R: Read>(reader: R) -> IoResult<Self> {
+        Self::read_versioned(reader, |mut reader| {
+            // Read the proving key.
+            let proving_key = Arc::new(FromBytes::read_le(&mut reader)?);
+            // Read the circuit digest, if it is known.
+            let circuit_digest = match bool::read_le(&mut reader)? {
+                true => Some(FromBytes::read_le(&mut reader)?),
+                false => None,
+            };
+            // Return the proving key.
+            Ok(Self { proving_key, circuit_digest })
+        })
     }
 }
 
@@ -34,8 +52,62 @@ impl<N: Network> ToBytes for ProvingKey<N> {
     /// Writes the proving key to a buffer.
     fn write_le<W: Write>(&self, mut writer: W) -> IoResult<()> {
         // Write the version.
-        1u8.write_le(&mut writer)?;
+        Self::write_version(&mut writer)?;
         // Write the bytes.
-        self.proving_key.write_le(&mut writer)
+        self.proving_key.write_le(&mut writer)?;
+        // Write the circuit digest, if it is known.
+        match &self.circuit_digest {
+            Some(circuit_digest) => {
+                true.write_le(&mut writer)?;
+                circuit_digest.write_le(&mut writer)
+            }
+            None => false.write_le(&mut writer),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    type CurrentNetwork = console::network::MainnetV0;
+
+    #[test]
+    fn test_bytes() -> Result<()> {
+        // Sample the proving key, which knows its circuit digest.
+        let (expected, _) = crate::test_helpers::sample_keys();
+        assert!(expected.circuit_digest().is_some());
+
+        // Check the byte representation.
+        let expected_bytes = expected.to_bytes_le()?;
+        assert_eq!(expected_bytes[0], ProvingKey::<CurrentNetwork>::VERSION);
+        let candidate = ProvingKey::<CurrentNetwork>::read_le(&expected_bytes[..])?;
+        assert_eq!(expected.circuit_digest(), candidate.circuit_digest());
+        assert_eq!(*expected.proving_key, *candidate.proving_key);
+
+        // Check the byte representation without a circuit digest.
+        let expected = ProvingKey::<CurrentNetwork>::new(expected.proving_key.clone());
+        let candidate = ProvingKey::<CurrentNetwork>::read_le(&expected.to_bytes_le()?[..])?;
+        assert_eq!(None, candidate.circuit_digest());
+        assert_eq!(*expected.proving_key, *candidate.proving_key);
+        Ok(())
+    }
+
+    #[test]
+    fn test_migrate_version_1() -> Result<()> {
+        let (expected, _) = crate::test_helpers::sample_keys();
+
+        // Write the proving key in the version 1 encoding, which has no circuit digest.
+        let mut bytes = vec![1u8];
+        expected.proving_key.write_le(&mut bytes)?;
+
+        // Ensure the proving key is migrated, and that its digest is unknown.
+        let candidate = ProvingKey::<CurrentNetwork>::read_le(&bytes[..])?;
+        assert_eq!(None, candidate.circuit_digest());
+        assert_eq!(*expected.proving_key, *candidate.proving_key);
+
+        // Ensure the migrated proving key is written in the current version.
+        assert_eq!(candidate.to_bytes_le()?[0], ProvingKey::<CurrentNetwork>::VERSION);
+        Ok(())
     }
 }
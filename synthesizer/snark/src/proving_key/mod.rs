@@ -24,12 +24,36 @@ use std::collections::BTreeMap;
 pub struct ProvingKey<N: Network> {
     /// The proving key for the function.
     proving_key: Arc<varuna::CircuitProvingKey<N::PairingCurve, varuna::VarunaHidingMode>>,
+    /// The digest of the circuit that the proving key was synthesized from, if known.
+    circuit_digest: Option<[u8; 32]>,
 }
 
 impl<N: Network> ProvingKey<N> {
     /// Initializes a new proving key.
     pub const fn new(proving_key: Arc<varuna::CircuitProvingKey<N::PairingCurve, varuna::VarunaHidingMode>>) -> Self {
-        Self { proving_key }
+        Self { proving_key, circuit_digest: None }
+    }
+
+    /// Returns the proving key, with the digest of the circuit that it was synthesized from.
+    pub fn with_circuit_digest(self, circuit_digest: [u8; 32]) -> Self {
+        Self { circuit_digest: Some(circuit_digest), ..self }
+    }
+
+    /// Returns the digest of the circuit that the proving key was synthesized from, if known.
+    pub const fn circuit_digest(&self) -> Option<[u8; 32]> {
+        self.circuit_digest
+    }
+
+    /// Ensures the proving key was synthesized from the circuit with the given digest, if its digest is known.
+    /// Otherwise, its proofs would not verify, as the circuit changed since the proving key was synthesized.
+    pub fn ensure_circuit_matches(&self, function_name: &str, circuit_digest: &[u8; 32]) -> Result<()> {
+        if let Some(expected) = &self.circuit_digest {
+            ensure!(
+                expected == circuit_digest,
+                "The circuit for '{function_name}' does not match its proving key, which must be synthesized again"
+            );
+        }
+        Ok(())
     }
 
     /// Returns a proof for the given assignment on the circuit.
@@ -40,7 +40,12 @@ impl<N: Network> UniversalSRS<N> {
         #[cfg(feature = "aleo-cli")]
         println!("{}", format!(" • Built '{function_name}' (in {} ms)", timer.elapsed().as_millis()).dimmed());
 
-        Ok((ProvingKey::new(Arc::new(proving_key)), VerifyingKey::new(Arc::new(verifying_key))))
+        // Record the digest of the circuit in the keys, to detect when the circuit changes.
+        let circuit_digest = assignment.digest()?;
+        let proving_key = ProvingKey::new(Arc::new(proving_key)).with_circuit_digest(circuit_digest);
+        let verifying_key = VerifyingKey::new(Arc::new(verifying_key)).with_circuit_digest(circuit_digest);
+
+        Ok((proving_key, verifying_key))
     }
 }
 
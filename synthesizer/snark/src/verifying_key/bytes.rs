@@ -14,19 +14,20 @@
 
 use super::*;
 
+impl<N: Network> Versioned for VerifyingKey<N> {
+    const NAME: &'static str = "verifying key";
+    const VERSION: u8 = 1;
+}
+
 impl<N: Network> FromBytes for VerifyingKey<N> {
     /// Reads the verifying key from a buffer.
     fn read_le<R: Read>(mut reader: R) -> IoResult<Self> {
-        // Read the version.
-        let version = u8::read_le(&mut reader)?;
-        // Ensure the version is valid.
-        if version != 1 {
-            return Err(error("Invalid verifying key version"));
-        }
+        // Read the version, and ensure it is valid.
+        Self::read_version(&mut reader)?;
         // Read the verifying key.
         let verifying_key = Arc::new(FromBytes::read_le(&mut reader)?);
         // Return the verifying key.
-        Ok(Self { verifying_key })
+        Ok(Self::new(verifying_key))
     }
 }
 
@@ -34,7 +35,7 @@ impl<N: Network> ToBytes for VerifyingKey<N> {
     /// Writes the verifying key to a buffer.
     fn write_le<W: Write>(&self, mut writer: W) -> IoResult<()> {
         // Write the version.
-        1u8.write_le(&mut writer)?;
+        Self::write_version(&mut writer)?;
         // Write the bytes.
         self.verifying_key.write_le(&mut writer)
     }
@@ -20,16 +20,41 @@ mod serialize;
 
 use std::collections::BTreeMap;
 
-#[derive(Clone, PartialEq, Eq)]
+#[derive(Clone)]
 pub struct VerifyingKey<N: Network> {
     /// The verifying key for the function.
     verifying_key: Arc<varuna::CircuitVerifyingKey<N::PairingCurve>>,
+    /// The digest of the circuit that the verifying key was synthesized from, if known.
+    /// It is not written to bytes, as verifying keys are part of deployments.
+    circuit_digest: Option<[u8; 32]>,
 }
 
 impl<N: Network> VerifyingKey<N> {
     /// Initializes a new verifying key.
     pub const fn new(verifying_key: Arc<varuna::CircuitVerifyingKey<N::PairingCurve>>) -> Self {
-        Self { verifying_key }
+        Self { verifying_key, circuit_digest: None }
+    }
+
+    /// Returns the verifying key, with the digest of the circuit that it was synthesized from.
+    pub fn with_circuit_digest(self, circuit_digest: [u8; 32]) -> Self {
+        Self { circuit_digest: Some(circuit_digest), ..self }
+    }
+
+    /// Returns the digest of the circuit that the verifying key was synthesized from, if known.
+    pub const fn circuit_digest(&self) -> Option<[u8; 32]> {
+        self.circuit_digest
+    }
+
+    /// Ensures the verifying key was synthesized from the circuit with the given digest, if its digest is known.
+    /// Otherwise, it would reject the proofs of the circuit, as the circuit changed since it was synthesized.
+    pub fn ensure_circuit_matches(&self, function_name: &str, circuit_digest: &[u8; 32]) -> Result<()> {
+        if let Some(expected) = &self.circuit_digest {
+            ensure!(
+                expected == circuit_digest,
+                "The circuit for '{function_name}' does not match its verifying key, which must be synthesized again"
+            );
+        }
+        Ok(())
     }
 
     /// Returns `true` if the proof is valid for the given public inputs.
@@ -98,6 +123,15 @@ impl<N: Network> VerifyingKey<N> {
     }
 }
 
+impl<N: Network> PartialEq for VerifyingKey<N> {
+    /// Returns `true` if the verifying keys are equal, regardless of whether their circuit digests are known.
+    fn eq(&self, other: &Self) -> bool {
+        self.verifying_key == other.verifying_key
+    }
+}
+
+impl<N: Network> Eq for VerifyingKey<N> {}
+
 impl<N: Network> Deref for VerifyingKey<N> {
     type Target = varuna::CircuitVerifyingKey<N::PairingCurve>;
 
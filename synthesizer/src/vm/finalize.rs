@@ -31,6 +31,7 @@ impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
     ///     `Ratify::BlockReward(block_reward)` and `Ratify::PuzzleReward(puzzle_reward)`
     ///     to the front of the `ratifications` list.
     #[inline]
+    #[cfg_attr(feature = "instrument", tracing::instrument(level = "debug", skip_all))]
     pub fn speculate<'a, R: Rng + CryptoRng>(
         &self,
         state: FinalizeGlobalState,
@@ -100,6 +101,7 @@ impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
     ///
     /// Returns the finalize operations from pre-ratify and post-ratify.
     #[inline]
+    #[cfg_attr(feature = "instrument", tracing::instrument(level = "debug", skip_all))]
     pub fn check_speculate<R: Rng + CryptoRng>(
         &self,
         state: FinalizeGlobalState,
@@ -153,6 +155,7 @@ impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
     ///
     /// Returns the finalize operations from pre-ratify and post-ratify.
     #[inline]
+    #[cfg_attr(feature = "instrument", tracing::instrument(level = "debug", skip_all))]
     pub fn finalize(
         &self,
         state: FinalizeGlobalState,
@@ -0,0 +1,178 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+mod utilities;
+
+use console::{
+    account::PrivateKey,
+    network::prelude::*,
+    program::{Identifier, Literal, ProgramID, Value},
+    types::Boolean,
+};
+use synthesizer_process::{CallMetrics, Process};
+use utilities::*;
+
+use rayon::prelude::*;
+use std::panic::AssertUnwindSafe;
+
+#[test]
+fn test_process_budget() {
+    // Load the tests.
+    let tests = load_tests::<_, BudgetTest>("./tests/process/budget", "./expectations/process/budget");
+    // Initialize a process.
+    let process = Process::<CurrentNetwork>::load().unwrap();
+
+    // Run each test and check it against its corresponding budget.
+    tests.par_iter().for_each(|test| {
+        // Run the test.
+        let output = run_test(process.clone(), test);
+        // Check against the expected budget.
+        test.check(&output).unwrap();
+        // Save the output.
+        test.save(&output).unwrap();
+    });
+}
+
+fn run_test(process: Process<CurrentNetwork>, test: &BudgetTest) -> serde_yaml::Mapping {
+    // Initialize the output.
+    let mut output = serde_yaml::Mapping::new();
+    output.insert(
+        serde_yaml::Value::String("errors".to_string()),
+        serde_yaml::Value::Sequence(serde_yaml::Sequence::new()),
+    );
+
+    // Add the programs into the process.
+    let mut process = process.clone();
+    for program in test.programs() {
+        if let Err(err) = process.add_program(program) {
+            output
+                .get_mut(&serde_yaml::Value::String("errors".to_string()))
+                .unwrap()
+                .as_sequence_mut()
+                .unwrap()
+                .push(serde_yaml::Value::String(err.to_string()));
+            output.insert(
+                serde_yaml::Value::String("outputs".to_string()),
+                serde_yaml::Value::Sequence(serde_yaml::Sequence::new()),
+            );
+            return output;
+        }
+    }
+
+    // Initialize the RNG.
+    let rng = &mut match test.randomness() {
+        None => TestRng::default(),
+        Some(randomness) => TestRng::fixed(randomness),
+    };
+
+    output.insert(
+        serde_yaml::Value::String("outputs".to_string()),
+        serde_yaml::Value::Sequence(
+            test.cases()
+                .iter()
+                .map(|value| {
+                    // Extract the function name, inputs, and optional private key.
+                    let value = value.as_mapping().expect("expected mapping for test case");
+                    let program_id = ProgramID::<CurrentNetwork>::from_str(
+                        value
+                            .get("program")
+                            .expect("expected program name for test case")
+                            .as_str()
+                            .expect("expected string for program name"),
+                    )
+                    .expect("unable to parse program name");
+                    let function_name = Identifier::<CurrentNetwork>::from_str(
+                        value
+                            .get("function")
+                            .expect("expected function name for test case")
+                            .as_str()
+                            .expect("expected string for function name"),
+                    )
+                    .expect("unable to parse function name");
+                    let inputs = value
+                        .get("inputs")
+                        .expect("expected inputs for test case")
+                        .as_sequence()
+                        .expect("expected sequence for inputs")
+                        .iter()
+                        .map(|input| match &input {
+                            serde_yaml::Value::Bool(bool) => {
+                                Value::<CurrentNetwork>::from(Literal::Boolean(Boolean::new(*bool)))
+                            }
+                            _ => Value::<CurrentNetwork>::from_str(input.as_str().expect("expected string for input"))
+                                .expect("unable to parse input"),
+                        })
+                        .collect_vec();
+                    let private_key = match value.get("private_key") {
+                        Some(private_key) => PrivateKey::<CurrentNetwork>::from_str(
+                            private_key.as_str().expect("expected string for private key"),
+                        )
+                        .expect("unable to parse private key"),
+                        None => PrivateKey::new(rng).unwrap(),
+                    };
+
+                    let mut run_test = || -> serde_yaml::Value {
+                        // Authorize the execution.
+                        let authorization = match process.authorize::<CurrentAleo, _>(
+                            &private_key,
+                            program_id,
+                            function_name,
+                            inputs.iter(),
+                            rng,
+                        ) {
+                            Ok(authorization) => authorization,
+                            Err(err) => return serde_yaml::Value::String(err.to_string()),
+                        };
+                        // Execute the authorization and extract the size of the circuit of each call as YAML.
+                        std::panic::catch_unwind(AssertUnwindSafe(|| {
+                            match process.execute::<CurrentAleo, _>(authorization, rng) {
+                                Ok((_, trace)) => serde_yaml::Value::Sequence(
+                                    trace.call_metrics().iter().map(to_budget).collect_vec(),
+                                ),
+                                Err(err) => serde_yaml::Value::String(err.to_string()),
+                            }
+                        }))
+                        .unwrap_or(serde_yaml::Value::String(
+                            "Compiler panicked when calling `Process::execute`".to_string(),
+                        ))
+                    };
+                    run_test()
+                })
+                .collect::<serde_yaml::Sequence>(),
+        ),
+    );
+
+    output
+}
+
+/// Returns the size of the circuit of the given call as YAML.
+fn to_budget(metrics: &CallMetrics<CurrentNetwork>) -> serde_yaml::Value {
+    let (num_nonzeros_a, num_nonzeros_b, num_nonzeros_c) = metrics.num_nonzeros;
+    let num_constraints =
+        metrics.num_request_constraints + metrics.num_function_constraints + metrics.num_response_constraints;
+
+    let mut budget = serde_yaml::Mapping::new();
+    budget.insert(
+        serde_yaml::Value::String("function".to_string()),
+        serde_yaml::Value::String(format!("{}/{}", metrics.program_id, metrics.function_name)),
+    );
+    budget.insert(serde_yaml::Value::String("constraints".to_string()), serde_yaml::Value::from(num_constraints));
+    budget.insert(serde_yaml::Value::String("public".to_string()), serde_yaml::Value::from(metrics.num_public));
+    budget.insert(serde_yaml::Value::String("private".to_string()), serde_yaml::Value::from(metrics.num_private));
+    budget.insert(
+        serde_yaml::Value::String("nonzeros".to_string()),
+        serde_yaml::Value::from(num_nonzeros_a + num_nonzeros_b + num_nonzeros_c),
+    );
+    serde_yaml::Value::Mapping(budget)
+}
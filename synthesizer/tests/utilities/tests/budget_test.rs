@@ -0,0 +1,124 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::{print_difference, ExpectedTest, ProgramTest};
+
+use circuit::environment::Measurement;
+
+use anyhow::{bail, Result};
+use itertools::Itertools;
+use serde_yaml::Value;
+use std::{ops::Deref, path::Path, str::FromStr};
+
+/// The metrics that are budgeted for each call, in the order they appear in the expectation file.
+const METRICS: [&str; 4] = ["constraints", "public", "private", "nonzeros"];
+
+/// A test that synthesizes the functions of a program and checks the size of each circuit against a budget.
+/// Each metric in the expectation file may be an exact count (e.g. `5`), a range (e.g. `[3, 5]`),
+/// or an inclusive upper bound (e.g. `<=5`). Rewritten expectation files contain exact counts.
+pub struct BudgetTest {
+    /// The underlying program test.
+    test: ProgramTest,
+}
+
+impl Deref for BudgetTest {
+    type Target = ProgramTest;
+
+    fn deref(&self) -> &Self::Target {
+        &self.test
+    }
+}
+
+impl ExpectedTest for BudgetTest {
+    type Output = serde_yaml::Mapping;
+
+    /// Loads the test from a given path.
+    fn load<P: AsRef<Path>>(test_path: P, expectation_dir: P) -> Self {
+        Self { test: ProgramTest::load(test_path, expectation_dir) }
+    }
+
+    fn check(&self, output: &Self::Output) -> Result<()> {
+        // Initialize space to accumulate failed tests.
+        let mut failed = Vec::new();
+        // If the expectation file should be rewritten, then there is no need to check the output.
+        if !self.rewrite() {
+            // Check that the errors match.
+            let expected_errors =
+                self.expected().get(&Value::String("errors".to_string())).unwrap().as_sequence().unwrap();
+            let actual_errors = output.get(&Value::String("errors".to_string())).unwrap().as_sequence().unwrap();
+            expected_errors.iter().zip_eq(actual_errors.iter()).for_each(|(expected, actual)| {
+                if expected != actual {
+                    let expected =
+                        serde_yaml::to_string(expected).expect("failed to serialize expected error to string");
+                    let actual = serde_yaml::to_string(actual).expect("failed to serialize actual error to string");
+                    failed.push(print_difference("errors", expected, actual));
+                }
+            });
+            // Check that the outputs are within budget.
+            let expected_outputs =
+                self.expected().get(&Value::String("outputs".to_string())).unwrap().as_sequence().unwrap();
+            let actual_outputs = output.get(&Value::String("outputs".to_string())).unwrap().as_sequence().unwrap();
+            self.cases().iter().zip_eq(expected_outputs.iter().zip_eq(actual_outputs.iter())).for_each(
+                |(test, (expected, actual))| {
+                    if !is_within_budget(expected, actual) {
+                        let test = serde_yaml::to_string(test).expect("failed to serialize test to string");
+                        let expected =
+                            serde_yaml::to_string(expected).expect("failed to serialize expected output to string");
+                        let actual =
+                            serde_yaml::to_string(actual).expect("failed to serialize actual output to string");
+                        failed.push(print_difference(test, expected, actual));
+                    }
+                },
+            );
+        };
+        // Write the errors, if any.
+        match failed.is_empty() {
+            true => Ok(()),
+            false => bail!("{}", failed.iter().join("\n\n")),
+        }
+    }
+
+    fn save(&self, output: &Self::Output) -> Result<()> {
+        self.test.save(output)
+    }
+}
+
+/// Returns `true` if the calls in `actual` are the same as those in `expected`, and each is within its budget.
+/// If either output is not a sequence of calls, e.g. if the test case failed, the outputs must be equal.
+fn is_within_budget(expected: &Value, actual: &Value) -> bool {
+    let (Some(expected_calls), Some(actual_calls)) = (expected.as_sequence(), actual.as_sequence()) else {
+        return expected == actual;
+    };
+    expected_calls.len() == actual_calls.len()
+        && expected_calls.iter().zip_eq(actual_calls).all(|(expected, actual)| {
+            // Ensure the same function is called.
+            expected.get("function") == actual.get("function")
+                // Ensure each metric is within its budget.
+                && METRICS.iter().all(|metric| {
+                    match (expected.get(metric).and_then(to_measurement), actual.get(metric).and_then(Value::as_u64)) {
+                        (Some(budget), Some(count)) => budget.matches(count),
+                        _ => false,
+                    }
+                })
+        })
+}
+
+/// Returns the measurement for the given budget, which is either a number or a string.
+fn to_measurement(budget: &Value) -> Option<Measurement<u64>> {
+    match budget {
+        Value::Number(number) => number.as_u64().map(Measurement::Exact),
+        Value::String(string) => Measurement::from_str(string).ok(),
+        _ => None,
+    }
+}
@@ -12,6 +12,9 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+pub mod budget_test;
+pub use budget_test::*;
+
 pub mod file_parse_test;
 pub use file_parse_test::*;
 
@@ -67,6 +67,16 @@ impl ProgramTest {
     pub fn keys(&self) -> &[PrivateKey<CurrentNetwork>] {
         &self.keys
     }
+
+    /// Returns the expected output of the test.
+    pub fn expected(&self) -> &serde_yaml::Mapping {
+        &self.expected
+    }
+
+    /// Returns `true` if the expectation file should be rewritten.
+    pub fn rewrite(&self) -> bool {
+        self.rewrite
+    }
 }
 
 impl ExpectedTest for ProgramTest {
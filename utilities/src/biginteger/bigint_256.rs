@@ -32,6 +32,7 @@ use rand::{
 use zeroize::Zeroize;
 
 #[derive(Copy, Clone, PartialEq, Eq, Default, Hash, Zeroize)]
+#[repr(transparent)]
 pub struct BigInteger256(pub [u64; 4]);
 
 impl BigInteger256 {
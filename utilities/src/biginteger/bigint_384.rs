@@ -32,6 +32,7 @@ use rand::{
 use zeroize::Zeroize;
 
 #[derive(Copy, Clone, PartialEq, Eq, Default, Hash, Zeroize)]
+#[repr(transparent)]
 pub struct BigInteger384(pub [u64; 6]);
 
 impl BigInteger384 {
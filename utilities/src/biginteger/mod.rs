@@ -23,6 +23,9 @@ pub use bigint_256::*;
 mod bigint_384;
 pub use bigint_384::*;
 
+mod bigint_512;
+pub use bigint_512::*;
+
 #[cfg(test)]
 mod tests;
 
@@ -124,3 +124,8 @@ fn test_biginteger256() {
 fn test_biginteger384() {
     test_biginteger(BigInteger384::new([0u64; 6]));
 }
+
+#[test]
+fn test_biginteger512() {
+    test_biginteger(BigInteger512::new([0u64; 8]));
+}
@@ -80,6 +80,28 @@ pub trait FromBytes {
     }
 }
 
+/// Reads `Self` from untrusted bytes, such as those received from the network.
+/// Unlike `FromBytes::from_bytes_le`, this only accepts the canonical encoding of `Self`,
+/// and rejects trailing bytes as well as any encoding that does not serialize back to the same bytes.
+pub trait FromBytesChecked: FromBytes + ToBytes + Sized {
+    /// Returns `Self` from a byte array in little-endian order, if the bytes are its canonical encoding.
+    fn from_bytes_le_checked(bytes: &[u8]) -> anyhow::Result<Self> {
+        let mut reader = bytes;
+        let value = Self::read_le(&mut reader)?;
+        // Ensure the entire buffer was consumed.
+        if !reader.is_empty() {
+            anyhow::bail!("Found {} trailing bytes after a valid encoding", reader.len());
+        }
+        // Ensure the encoding is canonical.
+        if value.to_bytes_le()? != bytes {
+            anyhow::bail!("Found a non-canonical encoding");
+        }
+        Ok(value)
+    }
+}
+
+impl<T: FromBytes + ToBytes> FromBytesChecked for T {}
+
 pub struct ToBytesSerializer<T: ToBytes>(PhantomData<T>);
 
 impl<T: ToBytes> ToBytesSerializer<T> {
@@ -129,6 +151,20 @@ impl<'de, T: FromBytes> FromBytesDeserializer<T> {
         FromBytes::read_le(&*buffer).map_err(de::Error::custom)
     }
 
+    /// Deserializes a dynamically-sized byte array from an untrusted source,
+    /// accepting only the canonical encoding of `T` (see `FromBytesChecked`).
+    pub fn deserialize_checked_with_size_encoding<D: Deserializer<'de>>(
+        deserializer: D,
+        name: &str,
+    ) -> Result<T, D::Error>
+    where
+        T: ToBytes,
+    {
+        let mut buffer = Vec::with_capacity(32);
+        deserializer.deserialize_bytes(FromBytesVisitor::new(&mut buffer, name))?;
+        T::from_bytes_le_checked(&buffer).map_err(de::Error::custom)
+    }
+
     /// Attempts to deserialize a byte array (without length encoding).
     ///
     /// This method does *not* fail if `deserializer` is given an insufficient `size`,
@@ -545,6 +581,22 @@ mod test {
         }
     }
 
+    #[test]
+    fn test_from_bytes_le_checked() {
+        let mut rng = TestRng::default();
+
+        for _ in 0..ITERATIONS {
+            let expected: u64 = rng.gen();
+            let mut bytes = expected.to_bytes_le().unwrap();
+            assert_eq!(u64::from_bytes_le_checked(&bytes).unwrap(), expected);
+
+            // Ensure trailing bytes are rejected, even though the permissive path accepts them.
+            bytes.push(rng.gen());
+            assert_eq!(u64::from_bytes_le(&bytes).unwrap(), expected);
+            assert!(u64::from_bytes_le_checked(&bytes).is_err());
+        }
+    }
+
     #[test]
     fn test_socketaddr_bytes() {
         fn random_ipv4_address(rng: &mut TestRng) -> Ipv4Addr {
@@ -38,6 +38,19 @@ impl Error for crate::String {}
 #[cfg(not(feature = "std"))]
 impl Error for crate::io::Error {}
 
+/// An error with a stable numeric code, so that callers can branch on failures
+/// without matching on error messages, which may change between releases.
+///
+/// Codes are never renumbered or reused. Each crate owns a range of codes:
+/// - `1000..2000`: `snarkvm-utilities` (serialization)
+/// - `2000..3000`: `snarkvm-algorithms` (synthesis, polynomial commitments, SNARKs)
+/// - `3000..4000`: `snarkvm-console` (parsing)
+/// - `4000..5000`: `snarkvm-ledger-store` (storage)
+pub trait ErrorCode {
+    /// Returns the stable code of this error.
+    fn error_code(&self) -> u32;
+}
+
 /// This macro provides a VM runtime environment which will safely halt
 /// without producing logs that look like unexpected behavior.
 /// It prints to stderr using the format: "VM safely halted at <location>: <halt message>".
@@ -71,6 +71,14 @@ pub use self::rand::*;
 pub mod serialize;
 pub use serialize::*;
 
+#[cfg(feature = "std")]
+pub mod thread_pool;
+#[cfg(feature = "std")]
+pub use thread_pool::*;
+
+pub mod versioned;
+pub use versioned::*;
+
 #[cfg(not(feature = "std"))]
 pub mod io;
 
@@ -83,3 +91,9 @@ pub fn error(_msg: &'static str) -> io::Error {
 pub fn error<S: ToString>(msg: S) -> io::Error {
     io::Error::new(io::ErrorKind::Other, msg.to_string())
 }
+
+/// Executes `f` on the current thread, as thread pools are only available with `std`.
+#[cfg(not(feature = "std"))]
+pub fn execute_in_current_thread_pool<T: Send>(f: impl FnOnce() -> T + Send) -> T {
+    f()
+}
@@ -53,7 +53,7 @@ impl<'a, T> Default for ExecutionPool<'a, T> {
     }
 }
 
-#[cfg(not(feature = "serial"))]
+#[cfg(not(any(feature = "serial", feature = "wasm")))]
 pub fn max_available_threads() -> usize {
     use aleo_std::Cpu;
     let rayon_threads = rayon::current_num_threads();
@@ -64,10 +64,23 @@ pub fn max_available_threads() -> usize {
     }
 }
 
+/// On `wasm32`, CPU detection is unavailable, so the size of the (possibly single-threaded) rayon pool is used.
+#[cfg(all(not(feature = "serial"), feature = "wasm"))]
+pub fn max_available_threads() -> usize {
+    rayon::current_num_threads()
+}
+
 #[inline(always)]
 #[cfg(not(any(feature = "serial", feature = "wasm")))]
 pub fn execute_with_max_available_threads<T: Sync + Send>(f: impl FnOnce() -> T + Send) -> T {
-    execute_with_threads(f, max_available_threads())
+    // Respect an enclosing thread pool, or one installed by the embedding application.
+    if rayon::current_thread_index().is_some() {
+        return f();
+    }
+    match crate::installed_thread_pool() {
+        Some(pool) => pool.install(f),
+        None => execute_with_threads(f, max_available_threads()),
+    }
 }
 
 #[inline(always)]
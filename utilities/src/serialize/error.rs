@@ -38,6 +38,25 @@ pub enum SerializationError {
     /// During serialization, the target was found to be incompatible
     #[error("the value was serialized on a target that is incompatible with the current target")]
     IncompatibleTarget,
+    /// During zero-copy deserialization, the buffer was not aligned for the target type.
+    #[error("the input buffer is not aligned for zero-copy access")]
+    UnalignedBuffer,
+}
+
+impl crate::ErrorCode for SerializationError {
+    fn error_code(&self) -> u32 {
+        match self {
+            Self::AnyhowError(..) => 1000,
+            Self::BincodeError(..) => 1001,
+            Self::IntError(..) => 1002,
+            Self::InvalidData => 1003,
+            Self::IoError(..) => 1004,
+            Self::NotEnoughSpace => 1005,
+            Self::UnexpectedFlags => 1006,
+            Self::IncompatibleTarget => 1007,
+            Self::UnalignedBuffer => 1008,
+        }
+    }
 }
 
 impl From<SerializationError> for crate::io::Error {
@@ -28,6 +28,9 @@ pub use flags::*;
 mod traits;
 pub use traits::*;
 
+mod zero_copy;
+pub use zero_copy::*;
+
 #[cfg(feature = "derive")]
 pub use snarkvm_utilities_derives::*;
 
@@ -0,0 +1,438 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::{
+    io::{Read, Write},
+    serialize::traits::*,
+    SerializationError,
+    Vec,
+};
+
+use core::mem::{align_of, size_of};
+
+/// The number of bytes in the header preceding a zero-copy encoded slice.
+///
+/// The header is `len (u64) || size_of::<T>() (u32) || align_of::<T>() (u16) || endianness (u8) || reserved (u8)`,
+/// which keeps the payload 8-byte aligned whenever the header itself is 8-byte aligned.
+pub const ZERO_COPY_HEADER_SIZE: usize = 16;
+
+/// The endianness marker written into the zero-copy header.
+#[cfg(target_endian = "little")]
+const ENDIANNESS: u8 = 0;
+#[cfg(target_endian = "big")]
+const ENDIANNESS: u8 = 1;
+
+/// A type whose in-memory representation can be borrowed directly from a byte buffer.
+///
+/// The zero-copy encoding of a value is its native in-memory representation (with padding zeroed).
+/// It is only portable between targets with the same endianness and layout, which is enforced
+/// by the header written by [`serialize_zero_copy`].
+///
+/// # Safety
+///
+/// Implementors must be `#[repr(C)]` or `#[repr(transparent)]` and must not contain pointers or
+/// interior mutability. `is_valid_raw` must return `false` for any byte sequence that is not a
+/// valid bit pattern of `Self`, and `write_raw` must write exactly `size_of::<Self>()` bytes
+/// such that reinterpreting them yields a value equal to `self`.
+pub unsafe trait ZeroCopy: Copy + Valid + 'static {
+    /// Returns `true` if the given bytes, of length `size_of::<Self>()`, are a valid in-memory representation of `Self`.
+    fn is_valid_raw(bytes: &[u8]) -> bool;
+
+    /// Writes the in-memory representation of `self`, with any padding bytes set to zero.
+    fn write_raw<W: Write>(&self, writer: W) -> Result<(), SerializationError>;
+}
+
+macro_rules! impl_zero_copy_uint {
+    ($type:ty) => {
+        unsafe impl ZeroCopy for $type {
+            #[inline]
+            fn is_valid_raw(bytes: &[u8]) -> bool {
+                bytes.len() == size_of::<$type>()
+            }
+
+            #[inline]
+            fn write_raw<W: Write>(&self, mut writer: W) -> Result<(), SerializationError> {
+                Ok(writer.write_all(&self.to_ne_bytes())?)
+            }
+        }
+    };
+}
+
+impl_zero_copy_uint!(u8);
+impl_zero_copy_uint!(u16);
+impl_zero_copy_uint!(u32);
+impl_zero_copy_uint!(u64);
+
+unsafe impl ZeroCopy for bool {
+    #[inline]
+    fn is_valid_raw(bytes: &[u8]) -> bool {
+        matches!(bytes, [0] | [1])
+    }
+
+    #[inline]
+    fn write_raw<W: Write>(&self, mut writer: W) -> Result<(), SerializationError> {
+        Ok(writer.write_all(&[*self as u8])?)
+    }
+}
+
+/// A value that consists only of an array of `u64` limbs, such as a prime field element,
+/// whose zero-copy encoding is the native encoding of its limbs.
+///
+/// Unlike [`ZeroCopy`], this trait is safe to implement. The [`ZeroCopy`] implementation for it
+/// checks that the limbs returned by `as_limbs` span the entire value before any bytes are reinterpreted,
+/// and rejects the bytes otherwise.
+pub trait ZeroCopyLimbs: Copy + Default + Valid + 'static {
+    /// Returns the limbs of `self`, which must be stored at the address of `self`.
+    fn as_limbs(&self) -> &[u64];
+
+    /// Returns `true` if the given limbs are a valid value of `Self`.
+    fn is_valid_limbs(limbs: &[u64]) -> bool;
+}
+
+/// Returns `true` if the limbs of `T` span the entire value, in which case every limb pattern is a valid bit pattern of `T`.
+fn limbs_span_value<T: ZeroCopyLimbs>() -> bool {
+    let value = T::default();
+    let limbs = value.as_limbs();
+    core::ptr::eq(limbs.as_ptr().cast::<u8>(), (&value as *const T).cast::<u8>())
+        && core::mem::size_of_val(limbs) == size_of::<T>()
+}
+
+// SAFETY: The bytes are only reinterpreted once `limbs_span_value` has established that `T` consists entirely
+// of the `u64` limbs returned by `as_limbs`. Safe code can only borrow such limbs from a `u64` array stored in `T`,
+// so `T` contains no pointers or interior mutability, and every bit pattern of its limbs is a valid bit pattern of `T`.
+unsafe impl<T: ZeroCopyLimbs> ZeroCopy for T {
+    #[inline]
+    fn is_valid_raw(bytes: &[u8]) -> bool {
+        if bytes.len() != size_of::<T>() || !limbs_span_value::<T>() {
+            return false;
+        }
+        // SAFETY: `bytes` spans `size_of::<T>()` bytes, and every bit pattern of the limbs is a valid `T`.
+        let value = unsafe { core::ptr::read_unaligned(bytes.as_ptr().cast::<T>()) };
+        T::is_valid_limbs(value.as_limbs())
+    }
+
+    #[inline]
+    fn write_raw<W: Write>(&self, mut writer: W) -> Result<(), SerializationError> {
+        for limb in self.as_limbs() {
+            writer.write_all(&limb.to_ne_bytes())?;
+        }
+        Ok(())
+    }
+}
+
+/// Serializes `values` in the zero-copy encoding, as `header || raw(values[0]) || ... || raw(values[n-1])`.
+pub fn serialize_zero_copy<T: ZeroCopy, W: Write>(values: &[T], mut writer: W) -> Result<(), SerializationError> {
+    let element_size = u32::try_from(size_of::<T>())?;
+    let element_align = u16::try_from(align_of::<T>())?;
+
+    (values.len() as u64).serialize_uncompressed(&mut writer)?;
+    element_size.serialize_uncompressed(&mut writer)?;
+    element_align.serialize_uncompressed(&mut writer)?;
+    writer.write_all(&[ENDIANNESS, 0u8])?;
+
+    for value in values {
+        value.write_raw(&mut writer)?;
+    }
+    Ok(())
+}
+
+/// Returns the number of bytes in the zero-copy encoding of `num_elements` elements of type `T`.
+pub const fn zero_copy_serialized_size<T: ZeroCopy>(num_elements: usize) -> usize {
+    ZERO_COPY_HEADER_SIZE + num_elements * size_of::<T>()
+}
+
+/// Borrows a slice of `T` from a zero-copy encoded buffer, without allocating or copying.
+///
+/// Every element is checked to be a valid bit pattern in place. If `validate` is set,
+/// `Valid::batch_check` is additionally run over the borrowed slice (e.g. subgroup checks for curve points).
+///
+/// Returns the borrowed slice and the remainder of the buffer following it.
+/// The payload (at offset `ZERO_COPY_HEADER_SIZE` of `bytes`) must be aligned to `align_of::<T>()`;
+/// use [`AlignedBytes`] to guarantee this for buffers loaded from disk.
+pub fn deserialize_zero_copy<T: ZeroCopy>(
+    bytes: &[u8],
+    validate: Validate,
+) -> Result<(&[T], &[u8]), SerializationError> {
+    let (len, payload) = read_zero_copy_header::<T>(bytes)?;
+    let num_bytes = len.checked_mul(size_of::<T>()).ok_or(SerializationError::InvalidData)?;
+    let (payload, remainder) = payload.split_at(num_bytes);
+    Ok((borrow_zero_copy(payload, len, validate)?, remainder))
+}
+
+/// Borrows the first `num_elements` elements of a zero-copy encoded slice of `T`, without allocating or copying.
+///
+/// Only the bytes of these elements are read, so that a memory-mapped buffer only loads the pages that hold them.
+/// The checks and the alignment requirement are those of [`deserialize_zero_copy`].
+pub fn deserialize_zero_copy_prefix<T: ZeroCopy>(
+    bytes: &[u8],
+    num_elements: usize,
+    validate: Validate,
+) -> Result<&[T], SerializationError> {
+    let (len, payload) = read_zero_copy_header::<T>(bytes)?;
+    if num_elements > len {
+        return Err(SerializationError::InvalidData);
+    }
+    borrow_zero_copy(&payload[..num_elements * size_of::<T>()], num_elements, validate)
+}
+
+/// Returns the number of elements of a zero-copy encoded slice of `T`, without reading the elements.
+pub fn zero_copy_len<T: ZeroCopy>(bytes: &[u8]) -> Result<usize, SerializationError> {
+    Ok(read_zero_copy_header::<T>(bytes)?.0)
+}
+
+/// Reads the header of a zero-copy encoded slice of `T`, and returns its number of elements and the bytes following it,
+/// which are ensured to be enough to hold the elements.
+fn read_zero_copy_header<T: ZeroCopy>(bytes: &[u8]) -> Result<(usize, &[u8]), SerializationError> {
+    if bytes.len() < ZERO_COPY_HEADER_SIZE {
+        return Err(SerializationError::InvalidData);
+    }
+    let (mut header, payload) = bytes.split_at(ZERO_COPY_HEADER_SIZE);
+
+    // Ensure the buffer was produced on a compatible target.
+    let len = u64::deserialize_uncompressed(&mut header)?;
+    let element_size = u32::deserialize_uncompressed(&mut header)?;
+    let element_align = u16::deserialize_uncompressed(&mut header)?;
+    let endianness = u8::deserialize_uncompressed(&mut header)?;
+    if element_size as usize != size_of::<T>() || element_align as usize != align_of::<T>() || endianness != ENDIANNESS
+    {
+        return Err(SerializationError::IncompatibleTarget);
+    }
+
+    // Ensure the payload is large enough to hold `len` elements.
+    let len = usize::try_from(len)?;
+    let num_bytes = len.checked_mul(size_of::<T>()).ok_or(SerializationError::InvalidData)?;
+    if payload.len() < num_bytes {
+        return Err(SerializationError::InvalidData);
+    }
+    Ok((len, payload))
+}
+
+/// Reinterprets the `payload` of exactly `len` elements as a slice of `T`, after checking them.
+fn borrow_zero_copy<T: ZeroCopy>(payload: &[u8], len: usize, validate: Validate) -> Result<&[T], SerializationError> {
+    // Ensure the payload is aligned for `T`.
+    if payload.as_ptr().align_offset(align_of::<T>()) != 0 {
+        return Err(SerializationError::UnalignedBuffer);
+    }
+
+    // Ensure every element is a valid bit pattern, before it is reinterpreted.
+    if size_of::<T>() > 0 && !payload.chunks_exact(size_of::<T>()).all(T::is_valid_raw) {
+        return Err(SerializationError::InvalidData);
+    }
+
+    // SAFETY: The payload is aligned, spans exactly `len` elements, and each element is a valid bit pattern of `T`.
+    let values = unsafe { core::slice::from_raw_parts(payload.as_ptr() as *const T, len) };
+
+    if let Validate::Yes = validate {
+        T::batch_check(values.iter())?;
+    }
+    Ok(values)
+}
+
+/// An owned byte buffer whose start is 8-byte aligned, for use with [`deserialize_zero_copy`].
+#[derive(Clone, Debug, Default)]
+pub struct AlignedBytes {
+    words: Vec<u64>,
+    len: usize,
+}
+
+impl AlignedBytes {
+    /// Copies the given bytes into a new aligned buffer.
+    pub fn from_bytes(bytes: &[u8]) -> Self {
+        let mut buffer = Self::zeroed(bytes.len());
+        buffer.as_mut_bytes().copy_from_slice(bytes);
+        buffer
+    }
+
+    /// Reads exactly `len` bytes from `reader` into a new aligned buffer.
+    pub fn from_reader<R: Read>(mut reader: R, len: usize) -> Result<Self, SerializationError> {
+        let mut buffer = Self::zeroed(len);
+        reader.read_exact(buffer.as_mut_bytes())?;
+        Ok(buffer)
+    }
+
+    /// Returns the number of bytes in the buffer.
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Returns `true` if the buffer is empty.
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Returns the buffer as a byte slice.
+    pub fn as_bytes(&self) -> &[u8] {
+        // SAFETY: `words` spans at least `len` initialized bytes.
+        unsafe { core::slice::from_raw_parts(self.words.as_ptr() as *const u8, self.len) }
+    }
+
+    /// Returns a zero-initialized buffer of `len` bytes.
+    fn zeroed(len: usize) -> Self {
+        Self { words: vec![0u64; (len + 7) / 8], len }
+    }
+
+    /// Returns the buffer as a mutable byte slice.
+    fn as_mut_bytes(&mut self) -> &mut [u8] {
+        // SAFETY: `words` spans at least `len` initialized bytes.
+        unsafe { core::slice::from_raw_parts_mut(self.words.as_mut_ptr() as *mut u8, self.len) }
+    }
+}
+
+impl AsRef<[u8]> for AlignedBytes {
+    fn as_ref(&self) -> &[u8] {
+        self.as_bytes()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn round_trip<T: ZeroCopy + PartialEq + core::fmt::Debug>(values: Vec<T>) {
+        let mut bytes = Vec::new();
+        serialize_zero_copy(&values, &mut bytes).unwrap();
+        assert_eq!(bytes.len(), zero_copy_serialized_size::<T>(values.len()));
+
+        let buffer = AlignedBytes::from_bytes(&bytes);
+        let (candidate, remainder) = deserialize_zero_copy::<T>(buffer.as_bytes(), Validate::Yes).unwrap();
+        assert_eq!(values.as_slice(), candidate);
+        assert!(remainder.is_empty());
+    }
+
+    #[test]
+    fn test_zero_copy_round_trip() {
+        round_trip(vec![1u64, 2, 3, 4, 5]);
+        round_trip(vec![7u32; 9]);
+        round_trip(vec![true, false, true]);
+        round_trip(Vec::<u64>::new());
+    }
+
+    /// A pair of limbs, whose valid values have a zero high limb.
+    #[derive(Copy, Clone, Debug, Default, PartialEq)]
+    struct SmallLimbs([u64; 2]);
+
+    /// A pair of limbs followed by a field that the limbs do not span.
+    #[derive(Copy, Clone, Debug, Default, PartialEq)]
+    struct PartialLimbs([u64; 2], u64);
+
+    impl Valid for SmallLimbs {
+        fn check(&self) -> Result<(), SerializationError> {
+            Ok(())
+        }
+    }
+
+    impl Valid for PartialLimbs {
+        fn check(&self) -> Result<(), SerializationError> {
+            Ok(())
+        }
+    }
+
+    impl ZeroCopyLimbs for SmallLimbs {
+        fn as_limbs(&self) -> &[u64] {
+            &self.0
+        }
+
+        fn is_valid_limbs(limbs: &[u64]) -> bool {
+            limbs[1] == 0
+        }
+    }
+
+    impl ZeroCopyLimbs for PartialLimbs {
+        fn as_limbs(&self) -> &[u64] {
+            &self.0
+        }
+
+        fn is_valid_limbs(_limbs: &[u64]) -> bool {
+            true
+        }
+    }
+
+    #[test]
+    fn test_zero_copy_limbs() {
+        round_trip(vec![SmallLimbs([1, 0]), SmallLimbs([u64::MAX, 0])]);
+
+        // Ensure limbs that are not a valid value are rejected.
+        let mut bytes = Vec::new();
+        serialize_zero_copy(&[SmallLimbs([1, 0])], &mut bytes).unwrap();
+        bytes[ZERO_COPY_HEADER_SIZE + 8] = 1;
+        let buffer = AlignedBytes::from_bytes(&bytes);
+        assert!(deserialize_zero_copy::<SmallLimbs>(buffer.as_bytes(), Validate::No).is_err());
+
+        // Ensure a value that is not spanned by its limbs is never reinterpreted.
+        assert!(SmallLimbs::is_valid_raw(&[0u8; 16]));
+        assert!(!PartialLimbs::is_valid_raw(&[0u8; 24]));
+    }
+
+    #[test]
+    fn test_zero_copy_prefix() {
+        let values = [1u64, 2, 3, 4, 5];
+        let mut bytes = Vec::new();
+        serialize_zero_copy(&values, &mut bytes).unwrap();
+        // An invalid element after the prefix is not read.
+        let mut flags = Vec::new();
+        serialize_zero_copy(&[true, false, true], &mut flags).unwrap();
+        flags[ZERO_COPY_HEADER_SIZE + 2] = 2;
+
+        let buffer = AlignedBytes::from_bytes(&bytes);
+        assert_eq!(zero_copy_len::<u64>(buffer.as_bytes()).unwrap(), values.len());
+        for num_elements in 0..=values.len() {
+            let candidate = deserialize_zero_copy_prefix::<u64>(buffer.as_bytes(), num_elements, Validate::Yes);
+            assert_eq!(candidate.unwrap(), &values[..num_elements]);
+        }
+        assert!(deserialize_zero_copy_prefix::<u64>(buffer.as_bytes(), values.len() + 1, Validate::Yes).is_err());
+
+        let buffer = AlignedBytes::from_bytes(&flags);
+        assert_eq!(deserialize_zero_copy_prefix::<bool>(buffer.as_bytes(), 2, Validate::No).unwrap(), &[true, false]);
+        assert!(deserialize_zero_copy_prefix::<bool>(buffer.as_bytes(), 3, Validate::No).is_err());
+    }
+
+    #[test]
+    fn test_zero_copy_rejects_invalid_bool() {
+        let mut bytes = Vec::new();
+        serialize_zero_copy(&[true, false], &mut bytes).unwrap();
+        bytes[ZERO_COPY_HEADER_SIZE] = 2;
+
+        let buffer = AlignedBytes::from_bytes(&bytes);
+        assert!(deserialize_zero_copy::<bool>(buffer.as_bytes(), Validate::No).is_err());
+    }
+
+    #[test]
+    fn test_zero_copy_rejects_mismatched_layout() {
+        let mut bytes = Vec::new();
+        serialize_zero_copy(&[1u32, 2, 3, 4], &mut bytes).unwrap();
+
+        let buffer = AlignedBytes::from_bytes(&bytes);
+        assert!(matches!(
+            deserialize_zero_copy::<u64>(buffer.as_bytes(), Validate::No),
+            Err(SerializationError::IncompatibleTarget)
+        ));
+    }
+
+    #[test]
+    fn test_zero_copy_rejects_truncated_and_unaligned() {
+        let mut bytes = Vec::new();
+        serialize_zero_copy(&[1u64, 2, 3], &mut bytes).unwrap();
+
+        let buffer = AlignedBytes::from_bytes(&bytes[..bytes.len() - 1]);
+        assert!(deserialize_zero_copy::<u64>(buffer.as_bytes(), Validate::No).is_err());
+
+        let mut shifted = vec![0u8];
+        shifted.extend_from_slice(&bytes);
+        let buffer = AlignedBytes::from_bytes(&shifted);
+        assert!(matches!(
+            deserialize_zero_copy::<u64>(&buffer.as_bytes()[1..], Validate::No),
+            Err(SerializationError::UnalignedBuffer)
+        ));
+    }
+}
@@ -0,0 +1,178 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use anyhow::Result;
+use rayon::{ThreadPool, ThreadPoolBuilder};
+use std::{
+    fmt,
+    sync::{Arc, RwLock},
+};
+
+/// The thread pool installed with `install_thread_pool`, if any.
+static THREAD_POOL: RwLock<Option<Arc<ThreadPool>>> = RwLock::new(None);
+
+/// A handler invoked on each worker thread as it starts, with the index of the thread.
+type StartHandler = Arc<dyn Fn(usize) + Send + Sync>;
+
+/// The configuration of a thread pool for the parallel sections of snarkVM
+/// (batch normalization, MSM, FFT, witness synthesis, etc.).
+#[derive(Clone, Default)]
+pub struct ThreadPoolConfig {
+    /// The number of worker threads, or `None` to let rayon decide.
+    num_threads: Option<usize>,
+    /// The prefix of each worker thread name, or `None` for unnamed threads.
+    thread_name_prefix: Option<String>,
+    /// The handler invoked on each worker thread as it starts.
+    start_handler: Option<StartHandler>,
+}
+
+impl ThreadPoolConfig {
+    /// Initializes a new thread pool configuration, with rayon's defaults.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets the number of worker threads.
+    pub fn num_threads(mut self, num_threads: usize) -> Self {
+        self.num_threads = Some(num_threads);
+        self
+    }
+
+    /// Names the worker threads `{prefix}-{index}`.
+    pub fn thread_name_prefix<S: Into<String>>(mut self, prefix: S) -> Self {
+        self.thread_name_prefix = Some(prefix.into());
+        self
+    }
+
+    /// Sets a handler that is invoked on each worker thread as it starts, with the index of the thread.
+    /// This is the hook for pinning worker threads to cores, or setting their priority.
+    pub fn start_handler<F: Fn(usize) + Send + Sync + 'static>(mut self, handler: F) -> Self {
+        self.start_handler = Some(Arc::new(handler));
+        self
+    }
+
+    /// Returns the configured number of worker threads, if set.
+    pub fn get_num_threads(&self) -> Option<usize> {
+        self.num_threads
+    }
+
+    /// Builds a new thread pool from this configuration.
+    pub fn build(&self) -> Result<ThreadPool> {
+        Ok(self.builder().build()?)
+    }
+
+    /// Builds rayon's global thread pool from this configuration.
+    /// Note: This also affects the parallel code of the embedding application, and may only be called once per process.
+    pub fn build_global(&self) -> Result<()> {
+        Ok(self.builder().build_global()?)
+    }
+
+    /// Returns a rayon thread pool builder for this configuration.
+    fn builder(&self) -> ThreadPoolBuilder {
+        let mut builder = ThreadPoolBuilder::new();
+        if let Some(num_threads) = self.num_threads {
+            builder = builder.num_threads(num_threads);
+        }
+        if let Some(prefix) = self.thread_name_prefix.clone() {
+            builder = builder.thread_name(move |index| format!("{prefix}-{index}"));
+        }
+        if let Some(handler) = self.start_handler.clone() {
+            builder = builder.start_handler(move |index| handler(index));
+        }
+        builder
+    }
+}
+
+impl fmt::Debug for ThreadPoolConfig {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("ThreadPoolConfig")
+            .field("num_threads", &self.num_threads)
+            .field("thread_name_prefix", &self.thread_name_prefix)
+            .field("start_handler", &self.start_handler.is_some())
+            .finish()
+    }
+}
+
+/// Builds a dedicated thread pool from the given configuration, and installs it
+/// as the default pool for the parallel sections of snarkVM, replacing any previously installed pool.
+pub fn install_thread_pool(config: &ThreadPoolConfig) -> Result<()> {
+    let pool = Arc::new(config.build()?);
+    *THREAD_POOL.write().unwrap_or_else(|e| e.into_inner()) = Some(pool);
+    Ok(())
+}
+
+/// Removes the installed thread pool, reverting to rayon's global pool.
+pub fn uninstall_thread_pool() {
+    *THREAD_POOL.write().unwrap_or_else(|e| e.into_inner()) = None;
+}
+
+/// Returns the installed thread pool, if any.
+pub fn installed_thread_pool() -> Option<Arc<ThreadPool>> {
+    THREAD_POOL.read().unwrap_or_else(|e| e.into_inner()).clone()
+}
+
+/// Executes `f` with the given thread pool, overriding the installed pool for this call.
+/// All parallel sections reached from `f` run on the threads of `pool`.
+pub fn execute_in_thread_pool<T: Send>(pool: &ThreadPool, f: impl FnOnce() -> T + Send) -> T {
+    pool.install(f)
+}
+
+/// Executes `f` in the thread pool that snarkVM should use from the current context:
+/// - if the caller is already running on a rayon worker thread (e.g. via `execute_in_thread_pool`), that pool is kept;
+/// - otherwise, if a pool was installed with `install_thread_pool`, `f` runs in the installed pool;
+/// - otherwise, `f` runs on rayon's global pool.
+pub fn execute_in_current_thread_pool<T: Send>(f: impl FnOnce() -> T + Send) -> T {
+    if rayon::current_thread_index().is_some() {
+        return f();
+    }
+    match installed_thread_pool() {
+        Some(pool) => pool.install(f),
+        None => f(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    #[test]
+    fn test_build_with_config() {
+        let started = Arc::new(AtomicUsize::new(0));
+        let counter = started.clone();
+        let config =
+            ThreadPoolConfig::new().num_threads(3).thread_name_prefix("snarkvm-test").start_handler(move |_| {
+                counter.fetch_add(1, Ordering::SeqCst);
+            });
+        assert_eq!(config.get_num_threads(), Some(3));
+
+        let pool = config.build().unwrap();
+        assert_eq!(pool.current_num_threads(), 3);
+
+        let name = execute_in_thread_pool(&pool, || std::thread::current().name().map(|name| name.to_string()));
+        assert!(name.unwrap().starts_with("snarkvm-test-"));
+
+        // Ensure the start handler ran on every worker thread.
+        execute_in_thread_pool(&pool, || rayon::broadcast(|_| ()));
+        assert_eq!(started.load(Ordering::SeqCst), 3);
+    }
+
+    #[test]
+    fn test_per_call_override_is_respected() {
+        let pool = ThreadPoolConfig::new().num_threads(2).build().unwrap();
+        let num_threads = execute_in_thread_pool(&pool, || execute_in_current_thread_pool(rayon::current_num_threads));
+        assert_eq!(num_threads, 2);
+    }
+}
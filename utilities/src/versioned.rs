@@ -0,0 +1,137 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::{
+    error,
+    io::{Read, Result as IoResult, Write},
+    FromBytes,
+    ToBytes,
+};
+
+/// A type whose encoding is prefixed with a `u8` version header.
+///
+/// The encoding is `version || body`. Readers accept every version in `MIN_VERSION..=VERSION`,
+/// and types that keep `MIN_VERSION < VERSION` implement [`Migrate`] to upgrade older bodies.
+pub trait Versioned {
+    /// The name of the type, used in error messages.
+    const NAME: &'static str;
+    /// The version written by the current encoding.
+    const VERSION: u8;
+    /// The oldest version that can still be read.
+    const MIN_VERSION: u8 = Self::VERSION;
+
+    /// Writes the current version header.
+    fn write_version<W: Write>(writer: W) -> IoResult<()> {
+        Self::VERSION.write_le(writer)
+    }
+
+    /// Reads a version header, ensuring it is in `MIN_VERSION..=VERSION`.
+    fn read_version<R: Read>(reader: R) -> IoResult<u8> {
+        let version = u8::read_le(reader)?;
+        match (Self::MIN_VERSION..=Self::VERSION).contains(&version) {
+            true => Ok(version),
+            false => Err(error(format!(
+                "Invalid {} version - found {version}, expected {}..={}",
+                Self::NAME,
+                Self::MIN_VERSION,
+                Self::VERSION
+            ))),
+        }
+    }
+}
+
+/// Upgrades an older encoding of a [`Versioned`] type into its current representation.
+///
+/// A `FromBytes` implementation reads the header with `Versioned::read_version`,
+/// and delegates any version below `Versioned::VERSION` to `Migrate::migrate`.
+pub trait Migrate: Versioned + Sized {
+    /// Reads the body of an encoding written with the given older `version`, and migrates it into `Self`.
+    /// The `version` is guaranteed to be in `MIN_VERSION..VERSION`.
+    fn migrate<R: Read>(version: u8, reader: R) -> IoResult<Self>;
+
+    /// Reads the version header and, if it is older than `VERSION`, migrates the body into `Self`.
+    /// Otherwise, the body is read with `read_current`.
+    fn read_versioned<R: Read>(mut reader: R, read_current: impl FnOnce(R) -> IoResult<Self>) -> IoResult<Self> {
+        match Self::read_version(&mut reader)? {
+            version if version < Self::VERSION => Self::migrate(version, reader),
+            _ => read_current(reader),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Version 1 stored a single `u32`; version 2 stores a `u64` and a flag.
+    #[derive(Debug, PartialEq, Eq)]
+    struct Record {
+        value: u64,
+        flag: bool,
+    }
+
+    impl Versioned for Record {
+        const MIN_VERSION: u8 = 1;
+        const NAME: &'static str = "record";
+        const VERSION: u8 = 2;
+    }
+
+    impl Migrate for Record {
+        fn migrate<R: Read>(version: u8, reader: R) -> IoResult<Self> {
+            match version {
+                1 => Ok(Self { value: u32::read_le(reader)? as u64, flag: false }),
+                _ => Err(error("Unreachable record version")),
+            }
+        }
+    }
+
+    impl FromBytes for Record {
+        fn read_le<R: Read>(reader: R) -> IoResult<Self> {
+            Self::read_versioned(reader, |mut reader| {
+                let value = u64::read_le(&mut reader)?;
+                let flag = bool::read_le(&mut reader)?;
+                Ok(Self { value, flag })
+            })
+        }
+    }
+
+    impl ToBytes for Record {
+        fn write_le<W: Write>(&self, mut writer: W) -> IoResult<()> {
+            Self::write_version(&mut writer)?;
+            self.value.write_le(&mut writer)?;
+            self.flag.write_le(&mut writer)
+        }
+    }
+
+    #[test]
+    fn test_current_version_round_trip() {
+        let record = Record { value: 1234567890123, flag: true };
+        let bytes = record.to_bytes_le().unwrap();
+        assert_eq!(bytes[0], Record::VERSION);
+        assert_eq!(record, Record::from_bytes_le(&bytes).unwrap());
+    }
+
+    #[test]
+    fn test_migrate_older_version() {
+        let mut bytes = vec![1u8];
+        bytes.extend_from_slice(&42u32.to_le_bytes());
+        assert_eq!(Record { value: 42, flag: false }, Record::from_bytes_le(&bytes).unwrap());
+    }
+
+    #[test]
+    fn test_reject_unsupported_versions() {
+        assert!(Record::from_bytes_le(&[0u8, 0, 0, 0, 0]).is_err());
+        assert!(Record::from_bytes_le(&[3u8, 0, 0, 0, 0, 0, 0, 0, 0, 0]).is_err());
+    }
+}
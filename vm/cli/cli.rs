@@ -12,6 +12,8 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+#[cfg(feature = "gpu-selftest")]
+use crate::cli::commands::GpuSelfTest;
 use crate::cli::commands::{Build, Clean, Execute, New, Run, Update};
 
 use anstyle::{AnsiColor, Color, Style};
@@ -44,6 +46,9 @@ pub enum Command {
     Clean(Clean),
     #[clap(name = "execute")]
     Execute(Execute),
+    #[cfg(feature = "gpu-selftest")]
+    #[clap(name = "gpu-selftest")]
+    GpuSelfTest(GpuSelfTest),
     #[clap(name = "new")]
     New(New),
     #[clap(name = "run")]
@@ -59,6 +64,8 @@ impl Command {
             Self::Build(command) => command.parse(),
             Self::Clean(command) => command.parse(),
             Self::Execute(command) => command.parse(),
+            #[cfg(feature = "gpu-selftest")]
+            Self::GpuSelfTest(command) => command.parse(),
             Self::New(command) => command.parse(),
             Self::Run(command) => command.parse(),
             Self::Update(command) => command.parse(),
@@ -27,6 +27,11 @@ pub use new::*;
 pub mod run;
 pub use run::*;
 
+#[cfg(feature = "gpu-selftest")]
+pub mod selftest;
+#[cfg(feature = "gpu-selftest")]
+pub use selftest::*;
+
 pub mod update;
 pub use update::*;
 
@@ -0,0 +1,64 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+use crate::algorithms::msm::VariableBase;
+
+use anyhow::{bail, ensure};
+use num_format::ToFormattedString;
+
+/// Checks the GPU MSM backend against the CPU, and reports its throughput
+#[derive(Debug, Parser)]
+pub struct GpuSelfTest {
+    /// The base-2 logarithm of the smallest MSM to run
+    #[clap(long, default_value = "10")]
+    min_log_size: u32,
+    /// The base-2 logarithm of the largest MSM to run
+    #[clap(long, default_value = "20")]
+    max_log_size: u32,
+}
+
+impl GpuSelfTest {
+    /// Runs the self-test, and returns an error if the GPU does not match the CPU.
+    pub fn parse(self) -> Result<String> {
+        ensure!(self.min_log_size <= self.max_log_size, "The smallest MSM size exceeds the largest");
+        ensure!(self.max_log_size <= 26, "The largest MSM size must be at most 2^26");
+        let Some(backend) = VariableBase::gpu_backend() else {
+            bail!("No GPU backend is enabled in this build");
+        };
+
+        println!("🚀 Running the {} MSM self-test...\n", backend.bold());
+        let results = VariableBase::gpu_selftest(self.min_log_size..=self.max_log_size, &mut rand::thread_rng());
+
+        let mut output = String::new();
+        for result in &results {
+            let status = match (&result.error, result.passed) {
+                (_, true) => "passed".green(),
+                (Some(error), false) => format!("failed ({error})").red(),
+                (None, false) => "mismatch".red(),
+            };
+            output += &format!(
+                "  • {:>12} terms: {status} - GPU {} terms/s, CPU {} terms/s\n",
+                result.num_terms.to_formatted_string(LOCALE),
+                (result.gpu_throughput() as u64).to_formatted_string(LOCALE),
+                (result.cpu_throughput() as u64).to_formatted_string(LOCALE),
+            );
+        }
+
+        match results.iter().all(|result| result.passed) {
+            true => Ok(format!("{output}\n✅ The {backend} MSM matches the CPU")),
+            false => bail!("{output}\nThe {backend} MSM does not match the CPU"),
+        }
+    }
+}
@@ -14,9 +14,11 @@
 
 use snarkvm_console::{
     account::{Address, PrivateKey, ViewKey},
-    network::MainnetV0,
+    network::{MainnetV0, Network},
+    program::{Plaintext, Record},
+    types::Scalar,
 };
-use snarkvm_utilities::TestRng;
+use snarkvm_utilities::{TestRng, Uniform};
 
 use core::str::FromStr;
 use wasm_bindgen_test::*;
@@ -58,3 +60,28 @@ fn test_account_sign() {
         assert!(result, "Failed to execute signature verification");
     }
 }
+
+#[wasm_bindgen_test]
+fn test_record_encrypt_and_decrypt() {
+    let mut rng = TestRng::default();
+
+    for _ in 0..ITERATIONS / 100 {
+        // Sample a new private key, view key, and address.
+        let private_key = PrivateKey::<MainnetV0>::new(&mut rng).unwrap();
+        let view_key = ViewKey::try_from(&private_key).unwrap();
+        let address = Address::try_from(&private_key).unwrap();
+
+        // Construct a record owned by the address.
+        let randomizer = Scalar::rand(&mut rng);
+        let nonce = MainnetV0::g_scalar_multiply(&randomizer);
+        let record = Record::<MainnetV0, Plaintext<MainnetV0>>::from_str(&format!(
+            "{{ owner: {address}.private, microcredits: 1500000u64.private, _nonce: {nonce}.public }}"
+        ))
+        .unwrap();
+
+        // Encrypt the record, and ensure the owner can decrypt it.
+        let ciphertext = record.encrypt(randomizer).unwrap();
+        assert!(ciphertext.is_owner(&view_key));
+        assert_eq!(record, ciphertext.decrypt(&view_key).unwrap());
+    }
+}